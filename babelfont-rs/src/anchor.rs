@@ -18,3 +18,16 @@ impl From<&norad::Anchor> for Anchor {
         }
     }
 }
+
+impl From<&Anchor> for norad::Anchor {
+    fn from(a: &Anchor) -> Self {
+        norad::Anchor::new(
+            a.x as f64,
+            a.y as f64,
+            norad::Name::new(&a.name).ok(),
+            None,
+            None,
+            None,
+        )
+    }
+}
@@ -1,5 +1,5 @@
 use crate::i18ndictionary::I18NDictionary;
-use crate::BabelfontError;
+use crate::{BabelfontError, Location};
 use fonttools::tables::fvar::VariationAxisRecord;
 use fonttools::types::Tag;
 use otmath::{normalize_value, piecewise_linear_map};
@@ -15,6 +15,47 @@ pub struct Axis {
     pub default: Option<f32>,
     pub map: Option<Vec<(f32, f32)>>,
     pub hidden: bool, // lib
+    pub labels: Vec<AxisLabel>,
+}
+
+/// A name for a value (or range of values) on an axis, used to build the
+/// `STAT` table. Values are in userspace coordinates.
+#[derive(Debug, Clone)]
+pub struct AxisLabel {
+    pub name: I18NDictionary,
+    pub value: f32,
+    /// The (minimum, maximum) of the range this label applies to, if any
+    pub range: Option<(f32, f32)>,
+    /// The value of the style-linked label (e.g. Bold for Regular)
+    pub linked_value: Option<f32>,
+    pub elidable: bool,
+    pub older_sibling: bool,
+}
+
+impl AxisLabel {
+    pub fn new<T>(name: T, value: f32) -> Self
+    where
+        T: Into<I18NDictionary>,
+    {
+        AxisLabel {
+            name: name.into(),
+            value,
+            range: None,
+            linked_value: None,
+            elidable: false,
+            older_sibling: false,
+        }
+    }
+}
+
+/// A name for a location involving more than one axis, used to build the
+/// `STAT` table. The location is in userspace coordinates.
+#[derive(Debug, Clone)]
+pub struct LocationLabel {
+    pub name: I18NDictionary,
+    pub location: Location,
+    pub elidable: bool,
+    pub older_sibling: bool,
 }
 
 impl Axis {
@@ -31,6 +72,7 @@ impl Axis {
             default: None,
             map: None,
             hidden: false,
+            labels: vec![],
         }
     }
 
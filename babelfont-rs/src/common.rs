@@ -39,6 +39,18 @@ impl From<&norad::Color> for Color {
     }
 }
 
+impl TryFrom<&Color> for norad::Color {
+    type Error = norad::error::ColorError;
+    fn try_from(c: &Color) -> Result<Self, Self::Error> {
+        norad::Color::new(
+            c.r as f64 / 255.0,
+            c.g as f64 / 255.0,
+            c.b as f64 / 255.0,
+            c.a as f64 / 255.0,
+        )
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Location(pub HashMap<String, f32>);
 impl Location {
@@ -186,11 +198,25 @@ impl From<&norad::PointType> for NodeType {
     }
 }
 
+impl From<NodeType> for norad::PointType {
+    fn from(p: NodeType) -> Self {
+        match p {
+            NodeType::Move => norad::PointType::Move,
+            NodeType::Line => norad::PointType::Line,
+            NodeType::OffCurve => norad::PointType::OffCurve,
+            NodeType::Curve => norad::PointType::Curve,
+            NodeType::QCurve => norad::PointType::QCurve,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Node {
     pub x: f32,
     pub y: f32,
     pub nodetype: NodeType,
+    /// Whether the node joins its two segments smoothly
+    pub smooth: bool,
     // userData: XXX
 }
 
@@ -206,6 +232,21 @@ impl From<&norad::ContourPoint> for Node {
             x: p.x as f32,
             y: p.y as f32,
             nodetype: (&p.typ).into(),
+            smooth: p.smooth,
         }
     }
 }
+
+impl From<&Node> for norad::ContourPoint {
+    fn from(n: &Node) -> Self {
+        norad::ContourPoint::new(
+            n.x as f64,
+            n.y as f64,
+            n.nodetype.into(),
+            n.smooth,
+            None,
+            None,
+            None,
+        )
+    }
+}
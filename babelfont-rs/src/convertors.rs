@@ -2,6 +2,8 @@
 pub mod designspace;
 /// Fontlab convertor
 pub mod fontlab;
+/// Glyphs 2 convertor
+pub mod glyphs2;
 /// Glyphs 3 convertor
 pub mod glyphs3;
 /// Bare UFO convertor
@@ -9,11 +9,21 @@ use std::path::PathBuf;
 use uuid::Uuid;
 
 use crate::convertors::ufo::{
-    load_font_info, load_glyphs, load_master_info, norad_glyph_to_babelfont_layer,
+    babelfont_layer_to_norad_glyph, load_font_info, load_glyphs, load_kern_groups, load_kerning,
+    load_master_info, master_to_ufo, norad_glyph_to_babelfont_layer, save_ufo,
+};
+use crate::names::StyleMapStyle;
+use crate::{
+    Axis, AxisLabel, BabelfontError, Condition, Font, Instance, Location, LocationLabel, Master,
+    Rule,
 };
-use crate::{Axis, BabelfontError, Font, Location, Master};
 
-use designspace::{Axis as DSAxis, Designspace, Instance as DSInstance};
+use designspace::{
+    Axes, Axis as DSAxis, AxisLabel as DSAxisLabel, AxisLabels, AxisMapping,
+    Condition as DSCondition, ConditionSet, Designspace, Dimension, Flag, Instance as DSInstance,
+    Instances, Location as DSLocation, LocationLabel as DSLocationLabel, LocationLabels, Mapping,
+    Mappings, Rule as DSRule, Rules as DSRules, Sources, Substitution, UserDimension, UserLocation,
+};
 
 pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
     let created_time = stat(&path);
@@ -29,8 +39,20 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
     let relative = path.parent();
     let mut font = Font::new();
     load_axes(&mut font, &ds.axes.axis);
+    if let Some(mappings) = &ds.axes.mappings {
+        load_mappings(&mut font, &ds.axes.axis, &mappings.mapping);
+    }
+    if let Some(labels) = &ds.labels {
+        load_location_labels(&mut font, &ds.axes.axis, &labels.label);
+    }
     if let Some(instances) = &ds.instances {
-        load_instances(&mut font, &instances.instance);
+        load_instances(&mut font, &ds, &instances.instance);
+    }
+    if let Some(rules) = &ds.rules {
+        load_rules(&mut font, &ds.axes.axis, rules);
+    }
+    if let Some(lib) = &ds.lib {
+        font.lib = lib.clone();
     }
     let default_master = ds
         .default_master()
@@ -61,6 +83,7 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
     }
     let info = default_ufo.font_info;
     load_font_info(&mut font, &info, created_time);
+    font.kern_groups = load_kern_groups(&default_ufo.groups);
     font.features = Some(default_ufo.features);
     Ok(font)
 }
@@ -68,18 +91,163 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
 fn load_axes(font: &mut Font, axes: &[DSAxis]) {
     for dsax in axes {
         let mut ax = Axis::new(dsax.name.clone(), dsax.tag.clone());
-        ax.min = Some(dsax.minimum as f32);
-        ax.max = Some(dsax.maximum as f32);
-        ax.default = Some(dsax.default as f32);
+        ax.min = Some(dsax.min_value());
+        ax.max = Some(dsax.max_value());
+        ax.default = Some(dsax.default);
         if let Some(map) = &dsax.map {
             ax.map = Some(map.iter().map(|x| (x.input, x.output)).collect());
         }
+        if let Some(labels) = &dsax.labels {
+            ax.labels = labels
+                .label
+                .iter()
+                .map(|l| AxisLabel {
+                    name: (&l.name).into(),
+                    value: l.uservalue,
+                    range: l.userminimum.zip(l.usermaximum),
+                    linked_value: l.linkeduservalue,
+                    elidable: l.elidable.unwrap_or(false),
+                    older_sibling: l.oldersibling.unwrap_or(false),
+                })
+                .collect();
+        }
         font.axes.push(ax);
     }
 }
 
-pub(crate) fn load_instances(_font: &mut Font, _instances: &[DSInstance]) {
-    // unimplemented!()
+fn load_location_labels(font: &mut Font, axes: &[DSAxis], labels: &[DSLocationLabel]) {
+    for label in labels {
+        let location = Location(
+            label
+                .location
+                .dimension
+                .iter()
+                .filter_map(|dim| {
+                    axes.iter()
+                        .find(|ax| ax.name == dim.name)
+                        .map(|ax| (ax.tag.clone(), dim.uservalue))
+                })
+                .collect(),
+        );
+        font.location_labels.push(LocationLabel {
+            name: (&label.name).into(),
+            location,
+            elidable: label.elidable.unwrap_or(false),
+            older_sibling: label.oldersibling.unwrap_or(false),
+        });
+    }
+}
+
+fn load_mappings(font: &mut Font, axes: &[DSAxis], mappings: &[AxisMapping]) {
+    let to_location = |loc: &designspace::Location| {
+        Location(
+            loc.dimension
+                .iter()
+                .filter_map(|dim| {
+                    axes.iter()
+                        .find(|ax| ax.name == dim.name)
+                        .map(|ax| (ax.tag.clone(), dim.designspace_value(ax)))
+                })
+                .collect(),
+        )
+    };
+    for mapping in mappings {
+        font.axis_mappings
+            .push((to_location(&mapping.input), to_location(&mapping.output)));
+    }
+}
+
+pub(crate) fn load_instances(font: &mut Font, ds: &Designspace, instances: &[DSInstance]) {
+    for instance in instances {
+        let location = Location(
+            ds.axes
+                .axis
+                .iter()
+                .map(|x| x.tag.clone())
+                .zip(ds.instance_location(instance))
+                .collect(),
+        );
+        let style_map_style_name = match instance.stylemapstylename.as_deref() {
+            Some("regular") => Some(StyleMapStyle::Regular),
+            Some("italic") => Some(StyleMapStyle::Italic),
+            Some("bold") => Some(StyleMapStyle::Bold),
+            Some("bold italic") => Some(StyleMapStyle::BoldItalic),
+            _ => None,
+        };
+        font.instances.push(Instance {
+            name: instance
+                .name
+                .clone()
+                .unwrap_or_else(|| {
+                    [&instance.familyname, &instance.stylename]
+                        .into_iter()
+                        .flatten()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .into(),
+            location,
+            style_name: instance
+                .stylename
+                .as_ref()
+                .map(|n| n.into())
+                .unwrap_or_default(),
+            family_name: instance
+                .familyname
+                .as_ref()
+                .map(|n| n.into())
+                .unwrap_or_default(),
+            postscript_name: instance.postscriptfontname.clone(),
+            style_map_family_name: instance
+                .stylemapfamilyname
+                .as_ref()
+                .map(|n| n.into())
+                .unwrap_or_default(),
+            style_map_style_name,
+            kerning: instance.kerning.is_some(),
+            info: instance.info.is_some(),
+            lib: instance.lib.clone().unwrap_or_default(),
+        });
+    }
+}
+
+fn load_rules(font: &mut Font, axes: &[DSAxis], rules: &DSRules) {
+    let to_conditions = |conditions: &[DSCondition]| -> Vec<Condition> {
+        conditions
+            .iter()
+            .filter_map(|c| {
+                axes.iter()
+                    .find(|ax| ax.name == c.name)
+                    .map(|ax| Condition {
+                        axis: ax.tag.clone(),
+                        min: c.minimum,
+                        max: c.maximum,
+                    })
+            })
+            .collect()
+    };
+    font.rules_processing_last = rules.processing.as_deref() == Some("last");
+    for rule in &rules.rule {
+        let mut condition_sets: Vec<Vec<Condition>> = rule
+            .conditionset
+            .iter()
+            .flatten()
+            .map(|cs| to_conditions(&cs.condition))
+            .collect();
+        if let Some(conditions) = &rule.condition {
+            condition_sets.push(to_conditions(conditions));
+        }
+        font.rules.push(Rule {
+            name: rule.name.clone(),
+            condition_sets,
+            substitutions: rule
+                .sub
+                .iter()
+                .map(|sub| (sub.name.clone(), sub.with.clone()))
+                .collect(),
+        });
+    }
 }
 
 fn load_master(
@@ -120,14 +288,7 @@ fn load_master(
         })?;
     let info = &source_font.font_info;
     load_master_info(&mut master, info);
-    let kerning = &source_font.kerning;
-    for (left, right_dict) in kerning.iter() {
-        for (right, value) in right_dict.iter() {
-            master
-                .kerning
-                .insert((left.to_string(), right.to_string()), *value as i16);
-        }
-    }
+    load_kerning(&mut master, &source_font.kerning);
     let mut bf_layer_list = vec![];
     for g in glyphs.iter() {
         let mut glyph_layer_list = vec![];
@@ -149,3 +310,515 @@ fn load_master(
     }
     Ok((master, bf_layer_list))
 }
+
+pub fn save(font: &Font, path: PathBuf) -> Result<(), BabelfontError> {
+    let default_master_ix = font
+        .default_master_index()
+        .ok_or_else(|| BabelfontError::NoDefaultMaster { path: path.clone() })?;
+    let directory = path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "master".to_string());
+
+    let mut sources: Vec<DSSource> = vec![];
+    let mut ufos: Vec<norad::Font> = vec![];
+    for (ix, master) in font.masters.iter().enumerate() {
+        let name = master
+            .name
+            .get_default()
+            .unwrap_or_else(|| master.id.clone());
+        let mut filename = format!("{}-{}.ufo", stem, sanitize_filename(&name));
+        if sources.iter().any(|s| s.filename == filename) {
+            filename = format!("{}-{}-{}.ufo", stem, sanitize_filename(&name), ix);
+        }
+        sources.push(DSSource {
+            filename,
+            name,
+            layer: None,
+            location: master.location.clone(),
+        });
+        ufos.push(master_to_ufo(font, master));
+    }
+
+    // Brace layers are stored as extra layers in the default master's UFO,
+    // and referenced as sparse sources.
+    let default_location = font.default_location();
+    let mut sparse_layers: Vec<(String, Location)> = vec![];
+    for glyph in font.glyphs.iter() {
+        for layer in glyph.layers.iter().filter(|l| !l.is_background) {
+            let location = match &layer.location {
+                Some(location) => location,
+                None => continue,
+            };
+            let layer_name = match sparse_layers.iter().find(|(_, l)| l == location) {
+                Some((name, _)) => name.clone(),
+                None => {
+                    let name = brace_layer_name(font, location);
+                    sparse_layers.push((name.clone(), location.clone()));
+                    name
+                }
+            };
+            let layers = &mut ufos[default_master_ix].layers;
+            let ufo_layer = match layers.get_mut(&layer_name) {
+                Some(ufo_layer) => ufo_layer,
+                None => layers
+                    .new_layer(&layer_name)
+                    .map_err(|e| BabelfontError::General { msg: e.to_string() })?,
+            };
+            ufo_layer.insert_glyph(babelfont_layer_to_norad_glyph(glyph, layer));
+        }
+    }
+    let default_source = &sources[default_master_ix];
+    let sparse_sources: Vec<DSSource> = sparse_layers
+        .into_iter()
+        .map(|(layer_name, location)| {
+            let mut full_location = default_location.clone();
+            full_location.0.extend(location.0);
+            DSSource {
+                filename: default_source.filename.clone(),
+                name: format!("{} {}", default_source.name, layer_name),
+                layer: Some(layer_name),
+                location: full_location,
+            }
+        })
+        .collect();
+    sources.extend(sparse_sources);
+
+    for (source, ufo) in sources.iter().zip(ufos.iter()) {
+        save_ufo(ufo, &directory.join(&source.filename))?;
+    }
+    let xml = designspace::to_string(&to_designspace(font, &sources))
+        .map_err(|e| BabelfontError::General { msg: e.to_string() })?;
+    std::fs::write(&path, xml).map_err(|source| BabelfontError::IO {
+        path: path.clone(),
+        source,
+    })
+}
+
+/// A source to be written to the designspace file
+struct DSSource {
+    filename: String,
+    name: String,
+    layer: Option<String>,
+    location: Location,
+}
+
+fn sanitize_filename(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '/' | '\\' | ':'))
+        .collect()
+}
+
+/// Names a sparse layer after its location, as Glyphs does for brace layers
+fn brace_layer_name(font: &Font, location: &Location) -> String {
+    let coords: Vec<String> = font
+        .axes
+        .iter()
+        .filter_map(|axis| location.0.get(&axis.tag))
+        .map(|v| v.to_string())
+        .collect();
+    format!("{{{}}}", coords.join(", "))
+}
+
+fn axis_name(axis: &Axis) -> String {
+    axis.name.get_default().unwrap_or_else(|| axis.tag.clone())
+}
+
+/// Converts a location to a designspace location, leaving out axes which
+/// aren't mentioned
+fn to_ds_location(font: &Font, location: &Location) -> DSLocation {
+    DSLocation {
+        dimension: font
+            .axes
+            .iter()
+            .filter_map(|axis| {
+                location.0.get(&axis.tag).map(|value| Dimension {
+                    name: axis_name(axis),
+                    xvalue: Some(*value),
+                    yvalue: None,
+                    uservalue: None,
+                })
+            })
+            .collect(),
+    }
+}
+
+fn to_ds_axis(axis: &Axis) -> DSAxis {
+    let map = axis.map.as_ref().filter(|m| !m.is_empty()).map(|map| {
+        map.iter()
+            .map(|(input, output)| Mapping {
+                input: *input,
+                output: *output,
+            })
+            .collect()
+    });
+    let labels = (!axis.labels.is_empty()).then(|| AxisLabels {
+        ordering: None,
+        label: axis
+            .labels
+            .iter()
+            .map(|label| DSAxisLabel {
+                name: label.name.get_default().unwrap_or_default(),
+                uservalue: label.value,
+                userminimum: label.range.map(|r| r.0),
+                usermaximum: label.range.map(|r| r.1),
+                linkeduservalue: label.linked_value,
+                elidable: label.elidable.then_some(true),
+                oldersibling: label.older_sibling.then_some(true),
+                labelname: None,
+            })
+            .collect(),
+    });
+    DSAxis {
+        name: axis_name(axis),
+        tag: axis.tag.clone(),
+        minimum: Some(axis.min.unwrap_or(0.0)),
+        maximum: Some(axis.max.unwrap_or(0.0)),
+        values: None,
+        default: axis.default.unwrap_or(0.0),
+        hidden: axis.hidden.then_some(true),
+        labelname: None,
+        map,
+        labels,
+    }
+}
+
+fn to_ds_rules(font: &Font) -> DSRules {
+    let rule = font
+        .rules
+        .iter()
+        .map(|rule| DSRule {
+            name: rule.name.clone(),
+            conditionset: Some(
+                rule.condition_sets
+                    .iter()
+                    .map(|conditions| ConditionSet {
+                        condition: conditions
+                            .iter()
+                            .filter_map(|c| {
+                                font.axes.iter().find(|ax| ax.tag == c.axis).map(|axis| {
+                                    DSCondition {
+                                        name: axis_name(axis),
+                                        minimum: c.min,
+                                        maximum: c.max,
+                                    }
+                                })
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            ),
+            condition: None,
+            sub: rule
+                .substitutions
+                .iter()
+                .map(|(name, with)| Substitution {
+                    name: name.clone(),
+                    with: with.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    DSRules {
+        processing: font.rules_processing_last.then(|| "last".to_string()),
+        rule,
+    }
+}
+
+fn to_ds_instance(font: &Font, instance: &Instance) -> DSInstance {
+    let family_name = instance
+        .family_name
+        .get_default()
+        .or_else(|| font.names.family_name.get_default())
+        .unwrap_or_default();
+    let style_name = instance
+        .style_name
+        .get_default()
+        .or_else(|| instance.name.get_default())
+        .unwrap_or_default();
+    let mut location = font.default_location();
+    location.0.extend(instance.location.0.clone());
+    let style_map_style_name = instance.style_map_style_name.as_ref().map(|s| {
+        match s {
+            StyleMapStyle::Regular => "regular",
+            StyleMapStyle::Italic => "italic",
+            StyleMapStyle::Bold => "bold",
+            StyleMapStyle::BoldItalic => "bold italic",
+        }
+        .to_string()
+    });
+    DSInstance {
+        name: Some(format!("{} {}", family_name, style_name)),
+        familyname: Some(family_name),
+        stylename: Some(style_name),
+        postscriptfontname: instance.postscript_name.clone(),
+        stylemapfamilyname: instance.style_map_family_name.get_default(),
+        stylemapstylename: style_map_style_name,
+        location: Some(to_ds_location(font, &location)),
+        kerning: instance.kerning.then_some(Flag {}),
+        info: instance.info.then_some(Flag {}),
+        lib: (!instance.lib.is_empty()).then(|| instance.lib.clone()),
+        ..Default::default()
+    }
+}
+
+fn to_designspace(font: &Font, sources: &[DSSource]) -> Designspace {
+    // Labels and cross-axis mappings were introduced in format 5
+    let format = if font.axis_mappings.is_empty()
+        && font.location_labels.is_empty()
+        && font.axes.iter().all(|a| a.labels.is_empty())
+    {
+        4.1
+    } else {
+        5.0
+    };
+    let mappings = (!font.axis_mappings.is_empty()).then(|| Mappings {
+        mapping: font
+            .axis_mappings
+            .iter()
+            .map(|(input, output)| AxisMapping {
+                input: to_ds_location(font, input),
+                output: to_ds_location(font, output),
+            })
+            .collect(),
+    });
+    let labels = (!font.location_labels.is_empty()).then(|| LocationLabels {
+        label: font
+            .location_labels
+            .iter()
+            .map(|label| DSLocationLabel {
+                name: label.name.get_default().unwrap_or_default(),
+                elidable: label.elidable.then_some(true),
+                oldersibling: label.older_sibling.then_some(true),
+                location: UserLocation {
+                    dimension: font
+                        .axes
+                        .iter()
+                        .filter_map(|axis| {
+                            label.location.0.get(&axis.tag).map(|value| UserDimension {
+                                name: axis_name(axis),
+                                uservalue: *value,
+                            })
+                        })
+                        .collect(),
+                },
+                labelname: None,
+            })
+            .collect(),
+    });
+    let family_name = font.names.family_name.get_default().unwrap_or_default();
+    let sources = sources
+        .iter()
+        .map(|source| designspace::Source {
+            familyname: Some(family_name.clone()),
+            stylename: Some(source.name.clone()),
+            name: Some(source.name.clone()),
+            filename: source.filename.clone(),
+            layer: source.layer.clone(),
+            localised_familyname: None,
+            location: to_ds_location(font, &source.location),
+        })
+        .collect();
+    Designspace {
+        format,
+        axes: Axes {
+            elidedfallbackname: None,
+            axis: font.axes.iter().map(to_ds_axis).collect(),
+            mappings,
+        },
+        labels,
+        rules: (!font.rules.is_empty()).then(|| to_ds_rules(font)),
+        sources: Sources { source: sources },
+        variable_fonts: None,
+        instances: (!font.instances.is_empty()).then(|| Instances {
+            instance: font
+                .instances
+                .iter()
+                .map(|instance| to_ds_instance(font, instance))
+                .collect(),
+        }),
+        lib: (!font.lib.is_empty()).then(|| font.lib.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut font = crate::convertors::glyphs3::load(
+            "../fonticulus/test/sources/SimpleTwoAxis.glyphs".into(),
+        )
+        .unwrap();
+        let wght_max = font.axes[0].max.unwrap();
+        font.rules.push(Rule {
+            name: Some("heavy".to_string()),
+            condition_sets: vec![vec![Condition {
+                axis: font.axes[0].tag.clone(),
+                min: Some(wght_max),
+                max: None,
+            }]],
+            substitutions: vec![("A".to_string(), "A.heavy".to_string())],
+        });
+        font.lib
+            .insert("com.example.test".to_string(), plist::Value::Boolean(true));
+        let mut lib = plist::Dictionary::new();
+        lib.insert("com.example.specimen".to_string(), "AAA".into());
+        font.instances.push(Instance {
+            name: "Heavy".into(),
+            style_name: "Heavy".into(),
+            location: Location(
+                font.axes
+                    .iter()
+                    .map(|ax| (ax.tag.clone(), ax.max.unwrap()))
+                    .collect(),
+            ),
+            postscript_name: Some("SimpleTwoAxis-Heavy".to_string()),
+            kerning: true,
+            lib,
+            ..Default::default()
+        });
+        let directory = std::env::temp_dir().join("babelfont-designspace-roundtrip");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("SimpleTwoAxis.designspace");
+        save(&font, path.clone()).unwrap();
+
+        let reloaded = load(path).unwrap();
+        assert_eq!(reloaded.masters.len(), font.masters.len());
+        assert_eq!(reloaded.axes.len(), font.axes.len());
+        assert_eq!(reloaded.instances.len(), font.instances.len());
+        for (instance, new_instance) in font.instances.iter().zip(reloaded.instances.iter()) {
+            assert_eq!(
+                instance.style_name.get_default(),
+                new_instance.style_name.get_default()
+            );
+            for (tag, value) in instance.location.0.iter() {
+                assert_eq!(new_instance.location.0.get(tag), Some(value));
+            }
+        }
+        assert!(reloaded.instances[0].kerning);
+        assert!(!reloaded.instances[0].info);
+        assert_eq!(
+            reloaded.instances[0].postscript_name.as_deref(),
+            Some("SimpleTwoAxis-Heavy")
+        );
+        assert_eq!(
+            reloaded.instances[0]
+                .lib
+                .get("com.example.specimen")
+                .and_then(|v| v.as_string()),
+            Some("AAA")
+        );
+        // The bracket layer rule from the source, plus the one added above
+        assert_eq!(reloaded.rules.len(), 2);
+        for (rule, new_rule) in font.rules.iter().zip(reloaded.rules.iter()) {
+            assert_eq!(new_rule.condition_sets, rule.condition_sets);
+            assert_eq!(new_rule.substitutions, rule.substitutions);
+        }
+        assert!(!reloaded.rules_processing_last);
+        assert_eq!(
+            reloaded
+                .lib
+                .get("com.example.test")
+                .and_then(|v| v.as_boolean()),
+            Some(true)
+        );
+        assert_eq!(reloaded.glyphs.len(), font.glyphs.len());
+        assert_eq!(
+            reloaded.kern_groups.get("public.kern1.Alike"),
+            font.kern_groups.get("MMK_L_Alike")
+        );
+        assert_eq!(
+            reloaded.names.family_name.get_default(),
+            font.names.family_name.get_default()
+        );
+        for (master, new_master) in font.masters.iter().zip(reloaded.masters.iter()) {
+            assert_eq!(master.location, new_master.location);
+            // Glyphs kerning classes become UFO kerning groups
+            let kerning: HashMap<(String, String), i16> = master
+                .kerning
+                .iter()
+                .map(|((l, r), v)| {
+                    (
+                        (
+                            l.replace("@MMK_L_", "@public.kern1."),
+                            r.replace("@MMK_R_", "@public.kern2."),
+                        ),
+                        *v,
+                    )
+                })
+                .collect();
+            assert_eq!(kerning, new_master.kerning);
+            for (glyph, new_glyph) in font.glyphs.iter().zip(reloaded.glyphs.iter()) {
+                assert_eq!(glyph.name, new_glyph.name);
+                let layer = glyph.get_layer(&master.id).unwrap();
+                let new_layer = new_glyph.get_layer(&new_master.id).unwrap();
+                assert_eq!(layer.width, new_layer.width);
+                assert_eq!(layer.paths().count(), new_layer.paths().count());
+                for (path, new_path) in layer.paths().zip(new_layer.paths()) {
+                    let nodes: Vec<_> = path.nodes.iter().map(|n| (n.x, n.y, n.nodetype)).collect();
+                    let new_nodes: Vec<_> = new_path
+                        .nodes
+                        .iter()
+                        .map(|n| (n.x, n.y, n.nodetype))
+                        .collect();
+                    assert_eq!(nodes, new_nodes);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_mappings_build_avar2() {
+        let ufo = std::fs::canonicalize("../fonticulus/test/sources/StupidFontBold.ufo").unwrap();
+        let xml = format!(
+            r#"<?xml version='1.0' encoding='UTF-8'?>
+<designspace format="5.0">
+  <axes>
+    <axis tag="wght" name="Weight" minimum="100" maximum="900" default="400"/>
+    <axis tag="opsz" name="Optical size" minimum="12" maximum="72" default="12"/>
+    <mappings>
+      <mapping>
+        <input>
+          <dimension name="Weight" xvalue="900"/>
+          <dimension name="Optical size" xvalue="72"/>
+        </input>
+        <output>
+          <dimension name="Weight" xvalue="800"/>
+        </output>
+      </mapping>
+    </mappings>
+  </axes>
+  <sources>
+    <source filename="{}">
+      <location>
+        <dimension name="Weight" xvalue="400"/>
+        <dimension name="Optical size" xvalue="12"/>
+      </location>
+    </source>
+  </sources>
+</designspace>
+"#,
+            ufo.display()
+        );
+        let directory = std::env::temp_dir().join("babelfont-designspace-avar2");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("Mappings.designspace");
+        std::fs::write(&path, xml).unwrap();
+
+        let font = load(path).unwrap();
+        assert_eq!(font.axis_mappings.len(), 1);
+        let mut ttfont = fonttools::font::Font::new(fonttools::font::SfntVersion::TrueType);
+        ttfont
+            .tables
+            .insert(fonttools::tables::name::name { records: vec![] });
+        font.add_variation_tables(&mut ttfont).unwrap();
+        let avar = ttfont.tables.avar().unwrap().unwrap();
+        assert!(avar.has_cross_axis_mappings());
+        let mapped = avar.map_location(&[1.0, 1.0]);
+        assert!((mapped[0] - 0.8).abs() < 0.001, "{:?}", mapped);
+    }
+}
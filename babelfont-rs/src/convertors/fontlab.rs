@@ -36,6 +36,7 @@ impl From<FontlabComponent> for Shape {
         Shape::ComponentShape(Component {
             reference: val.glyphName,
             transform: Affine::IDENTITY,
+            ..Default::default()
         })
     }
 }
@@ -65,6 +66,7 @@ fn nodestring_to_nodes(s: String) -> Vec<Node> {
                     x: mat[1].parse().unwrap(),
                     y: mat[2].parse().unwrap(),
                     nodetype,
+                    smooth: mat.get(3).is_some(),
                 })
             } else {
                 None
@@ -184,6 +186,8 @@ impl FontlabLayer {
             self.anchors.into_iter().map(|x| x.try_into()).collect();
         Ok(Layer {
             width: self.advanceWidth,
+            height: None,
+            vertical_origin: None,
             name: self.name.clone(),
             id: self.name,
             guides: vec![],
@@ -201,6 +205,8 @@ impl FontlabLayer {
             is_background: false,
             background_layer_id: None,
             location: None,
+            associated_master_id: None,
+            smart_location: Location::new(),
         })
     }
 }
@@ -229,13 +235,15 @@ impl FontlabGlyph {
             .collect();
 
         Ok(Glyph {
+            production_name: Glyph::default_production_name(&self.name),
+            category: GlyphCategory::from_glyph(&self.name, &codepoints),
             name: self.name,
-            production_name: None,
-            category: GlyphCategory::Unknown,
             codepoints,
             layers: layers?,
             exported: true,
             direction: None,
+            smart_axes: vec![],
+            lib: plist::Dictionary::new(),
         })
     }
 }
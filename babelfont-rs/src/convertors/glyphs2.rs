@@ -0,0 +1,698 @@
+use crate::convertors::glyphs3::{
+    add_axis_mappings, add_bracket_glyphs, add_instance, axis_locations, fixup_axes,
+    fixup_axis_mappings, get_custom_parameters, load_component_properties, load_custom_parameters,
+    load_features, load_kern_groups, load_kerning, load_metadata, load_smart_axes, pole_location,
+    read_plist, set_name, BracketLayer,
+};
+use crate::glyph::GlyphCategory;
+use crate::i18ndictionary::I18NDictionary;
+use crate::Shape::{ComponentShape, PathShape};
+use crate::{
+    Anchor, Axis, BabelfontError, Component, Condition, Font, Glyph, Guide, Layer, Location,
+    Master, Node, NodeType, Path, Position, Shape,
+};
+use openstep_plist::Plist;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// The axes Glyphs 2 uses when a font doesn't define its own
+const DEFAULT_AXES: [(&str, &str); 3] = [("Weight", "wght"), ("Width", "wdth"), ("Custom", "XXXX")];
+
+/// The keys holding a master's coordinates on each axis, in axis order
+const MASTER_COORDINATES: [&str; 6] = [
+    "weightValue",
+    "widthValue",
+    "customValue",
+    "customValue1",
+    "customValue2",
+    "customValue3",
+];
+
+/// The keys holding an instance's coordinates on each axis, in axis order
+const INSTANCE_COORDINATES: [&str; 6] = [
+    "interpolationWeight",
+    "interpolationWidth",
+    "interpolationCustom",
+    "interpolationCustom1",
+    "interpolationCustom2",
+    "interpolationCustom3",
+];
+
+/// Name keys stored at the top level of a Glyphs 2 file rather than as
+/// custom parameters
+const NAME_KEYS: [&str; 5] = [
+    "copyright",
+    "designer",
+    "designerURL",
+    "manufacturer",
+    "manufacturerURL",
+];
+
+pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
+    let plist = read_plist(&path)?;
+    if plist.get(".formatVersion").is_some() {
+        return Err(BabelfontError::WrongConvertor { path });
+    }
+    let font = load_plist(&plist);
+    std::mem::forget(plist);
+    font
+}
+
+pub(crate) fn load_plist(plist: &Plist) -> Result<Font, BabelfontError> {
+    log::debug!("Assembling babelfont from Glyphs 2 file");
+    let mut font = Font::new();
+
+    let custom_parameters = get_custom_parameters(plist);
+    load_axes(&mut font, plist, &custom_parameters);
+    font.kern_groups = load_kern_groups(plist);
+    load_masters(&mut font, plist)?;
+    // Glyphs 2 refers to the origin master by name
+    let default_master_id = custom_parameters
+        .get("Variation Font Origin")
+        .or_else(|| custom_parameters.get("Variable Font Origin"))
+        .and_then(|x| x.as_str())
+        .and_then(|origin| {
+            font.masters
+                .iter()
+                .find(|m| m.id == origin || m.name.get_default().as_deref() == Some(origin))
+        })
+        .or_else(|| font.masters.first())
+        .map(|m| m.id.clone());
+
+    fixup_axes(&mut font, default_master_id.as_ref());
+    load_glyphs(&mut font, plist);
+
+    if let Some(instances) = plist.get("instances").and_then(|f| f.as_array()) {
+        for instance in instances {
+            let name = instance
+                .get("name")
+                .and_then(|f| f.as_str())
+                .unwrap_or("Unnamed Instance")
+                .to_string();
+            let location = coordinates(&font, instance, &INSTANCE_COORDINATES);
+            add_instance(&mut font, instance, name, location);
+        }
+    }
+
+    fixup_axis_mappings(&mut font);
+    load_metadata(&mut font, plist);
+    load_names(&mut font, plist, &custom_parameters);
+
+    load_custom_parameters(&mut font.custom_ot_values, custom_parameters);
+    load_features(&mut font, plist);
+    Ok(font)
+}
+
+/// Reads the axes from the "Axes" custom parameter, or failing that from
+/// the font's user data. Fonts from before axes could be defined use the
+/// default axes which the masters actually vary along.
+fn load_axes(font: &mut Font, plist: &Plist, custom_parameters: &HashMap<String, &Plist>) {
+    let definitions = custom_parameters
+        .get("Axes")
+        .copied()
+        .or_else(|| plist.get("userData").and_then(|u| u.get("Axes")))
+        .and_then(|a| a.as_array());
+    if let Some(definitions) = definitions {
+        for definition in definitions {
+            let name = definition.get("Name").and_then(|n| n.as_str());
+            let tag = definition.get("Tag").and_then(|n| n.as_str());
+            if let (Some(name), Some(tag)) = (name, tag) {
+                let mut axis = Axis::new(name, tag.to_string());
+                axis.hidden = definition.get("Hidden").is_some();
+                font.axes.push(axis);
+            }
+        }
+        return;
+    }
+
+    let masters = plist
+        .get("fontMaster")
+        .and_then(|m| m.as_array())
+        .unwrap_or(&[]);
+    for (ix, (name, tag)) in DEFAULT_AXES.iter().enumerate() {
+        let default = default_coordinate(ix);
+        let values: Vec<f32> = masters
+            .iter()
+            .map(|m| {
+                m.get(MASTER_COORDINATES[ix])
+                    .and_then(|v| v.as_f32())
+                    .unwrap_or(default)
+            })
+            .collect();
+        if values.iter().any(|&v| v != values[0]) {
+            font.axes.push(Axis::new(*name, tag.to_string()));
+        }
+    }
+}
+
+/// Weight and width default to 100; the custom axes default to 0.
+fn default_coordinate(axis_index: usize) -> f32 {
+    if axis_index < 2 {
+        100.0
+    } else {
+        0.0
+    }
+}
+
+/// Reads a master or instance's designspace location from its coordinate
+/// keys. Axes beyond the sixth can't be expressed in Glyphs 2.
+fn coordinates(font: &Font, plist: &Plist, keys: &[&str]) -> Location {
+    let mut location = Location::new();
+    for (ix, (axis, key)) in font.axes.iter().zip(keys.iter()).enumerate() {
+        let value = plist
+            .get(key)
+            .and_then(|v| v.as_f32())
+            .unwrap_or_else(|| default_coordinate(ix));
+        location.0.insert(axis.tag.clone(), value);
+    }
+    location
+}
+
+/// Parses a "{x, y}" point string, as used for positions and alignment zones.
+fn parse_point(s: &str) -> Option<(f32, f32)> {
+    let mut parts = s
+        .trim_matches(|c: char| c == '{' || c == '}' || c.is_whitespace())
+        .split(',')
+        .map(|p| p.trim().parse::<f32>());
+    match (parts.next(), parts.next()) {
+        (Some(Ok(x)), Some(Ok(y))) => Some((x, y)),
+        _ => None,
+    }
+}
+
+/// Glyphs 2 masters have no name of their own; it is made from their
+/// width, weight and custom names, leaving out the regular ones.
+fn master_name(master: &Plist) -> String {
+    if let Some(name) = master.get("name").and_then(|n| n.as_str()) {
+        return name.to_string();
+    }
+    let parts: Vec<&str> = ["width", "weight", "custom"]
+        .iter()
+        .filter_map(|key| master.get(key).and_then(|n| n.as_str()))
+        .filter(|n| !matches!(*n, "Regular" | "Medium (normal)"))
+        .collect();
+    if parts.is_empty() {
+        "Regular".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn load_masters(font: &mut Font, plist: &Plist) -> Result<(), BabelfontError> {
+    if let Some(masters) = plist.get("fontMaster").and_then(|m| m.as_array()) {
+        for master in masters {
+            let location = coordinates(font, master, &MASTER_COORDINATES);
+            let id = master
+                .get("id")
+                .and_then(|n| n.as_str())
+                .ok_or(BabelfontError::General {
+                    msg: "Master has no id!".to_string(),
+                })?;
+            let mut new_master = Master::new(master_name(master), id, location);
+
+            if let Some(guides) = master.get("guideLines").and_then(|a| a.as_array()) {
+                new_master.guides = guides.iter().map(load_guide).collect();
+            }
+            load_metrics(&mut new_master, master);
+            if let Some(kerning) = plist.get("kerning").and_then(|d| d.get(id)) {
+                load_kerning(&mut new_master, kerning);
+            }
+            let custom_parameters = get_custom_parameters(master);
+            let userspace_location = axis_locations(font, &custom_parameters);
+            add_axis_mappings(font, &userspace_location, &new_master.location);
+            load_custom_parameters(&mut new_master.custom_ot_values, custom_parameters);
+            font.masters.push(new_master)
+        }
+    }
+    Ok(())
+}
+
+/// Reads the vertical metrics, which Glyphs 2 omits when they have their
+/// default values. Alignment zones which don't belong to one of these
+/// metrics are kept as extra metrics named after their position in the
+/// list.
+fn load_metrics(new_master: &mut Master, master: &Plist) {
+    let metric =
+        |key: &str, default: i32| master.get(key).and_then(|v| v.as_i32()).unwrap_or(default);
+    let metrics = &mut new_master.metrics;
+    metrics.insert("ascender".to_string(), metric("ascender", 800));
+    metrics.insert("capHeight".to_string(), metric("capHeight", 700));
+    metrics.insert("xHeight".to_string(), metric("xHeight", 500));
+    metrics.insert("baseline".to_string(), 0);
+    metrics.insert("descender".to_string(), metric("descender", -200));
+    metrics.insert("italic angle".to_string(), metric("italicAngle", 0));
+
+    if let Some(zones) = master.get("alignmentZones").and_then(|z| z.as_array()) {
+        for (ix, zone) in zones.iter().enumerate() {
+            if let Some((position, overshoot)) = zone.as_str().and_then(parse_point) {
+                let position = position as i32;
+                let name = match metrics
+                    .iter()
+                    .filter(|(name, _)| *name != "italic angle")
+                    .find(|(_, &v)| v == position)
+                {
+                    Some((name, _)) => name.clone(),
+                    None if metrics.values().any(|&v| v == position) => continue,
+                    None => {
+                        let name = format!("zone {}", ix + 1);
+                        metrics.insert(name.clone(), position);
+                        name
+                    }
+                };
+                new_master.overshoots.insert(name, overshoot as i32);
+            }
+        }
+    }
+}
+
+fn load_guide(g: &Plist) -> Guide {
+    let mut guide = Guide::new();
+    let (x, y) = g
+        .get("position")
+        .and_then(|p| p.as_str())
+        .and_then(parse_point)
+        .unwrap_or((0.0, 0.0));
+    guide.pos = Position {
+        x: x as i32,
+        y: y as i32,
+        angle: g.get("angle").and_then(|a| a.as_f32()).unwrap_or(0.0),
+    };
+    guide
+}
+
+fn load_glyphs(font: &mut Font, plist: &Plist) {
+    // Vertical origins are stored relative to the master's ascender
+    let ascenders: HashMap<String, i32> = font
+        .masters
+        .iter()
+        .map(|m| (m.id.clone(), *m.metrics.get("ascender").unwrap_or(&0)))
+        .collect();
+    let axis_tags: Vec<String> = font.axes.iter().map(|a| a.tag.clone()).collect();
+    let mut brackets = vec![];
+    if let Some(glyphs) = plist.get("glyphs").and_then(|a| a.as_array()) {
+        for g in glyphs {
+            match load_glyph(g, &ascenders, &axis_tags) {
+                Ok((glyph, glyph_brackets)) => {
+                    font.glyphs.push(glyph);
+                    brackets.extend(glyph_brackets);
+                }
+                Err(e) => log::error!("{:}", e),
+            }
+        }
+    }
+    add_bracket_glyphs(font, brackets);
+}
+
+/// Glyphs 2 stores codepoints as comma-separated hex strings, which look
+/// like integers when they have no letters or leading zeros.
+fn get_codepoints(g: &Plist) -> Vec<usize> {
+    let unicode = match g.get("unicode") {
+        Some(Plist::Integer(i)) => i.to_string(),
+        Some(Plist::String(s)) => s.clone(),
+        _ => return vec![],
+    };
+    unicode
+        .split(',')
+        .filter_map(|u| usize::from_str_radix(u.trim(), 16).ok())
+        .collect()
+}
+
+fn load_glyph(
+    g: &Plist,
+    ascenders: &HashMap<String, i32>,
+    axis_tags: &[String],
+) -> Result<(Glyph, Vec<BracketLayer>), BabelfontError> {
+    let name = g
+        .get("glyphname")
+        .and_then(|f| f.as_str())
+        .ok_or(BabelfontError::General {
+            msg: "Couldn't read a glyph name!".to_string(),
+        })?;
+    let category = g.get("category").and_then(|f| f.as_str());
+    let subcategory = g.get("subCategory").and_then(|f| f.as_str());
+    let codepoints = get_codepoints(g);
+    let gc = if subcategory == Some("Ligature") {
+        GlyphCategory::Ligature
+    } else if category == Some("Mark") {
+        GlyphCategory::Mark
+    } else if category.is_none() && subcategory.is_none() {
+        GlyphCategory::from_glyph(name, &codepoints)
+    } else {
+        GlyphCategory::Base
+    };
+    let smart_axes = load_smart_axes(g);
+    let mut layers = vec![];
+    let mut brackets = vec![];
+    if let Some(plist_layers) = g.get("layers").and_then(|l| l.as_array()) {
+        for layer in plist_layers {
+            let mut loaded = load_layer(layer, name, ascenders, axis_tags)?;
+            // Glyphs 2 keeps the poles of part layers in their user data
+            if let Some(selection) = layer.get("userData").and_then(|u| u.get("PartSelection")) {
+                loaded.smart_location = pole_location(selection, &smart_axes);
+            }
+            let master_id = layer.get("associatedMasterId").and_then(|x| x.as_str());
+            let conditions = loaded
+                .name
+                .as_ref()
+                .and_then(|n| bracket_conditions(n, axis_tags));
+            match (master_id, conditions) {
+                (Some(master_id), Some(conditions)) => brackets.push(BracketLayer {
+                    glyph: name.to_string(),
+                    master_id: master_id.to_string(),
+                    conditions,
+                    layer: loaded,
+                }),
+                _ => layers.push(loaded),
+            }
+        }
+    }
+    let glyph = Glyph {
+        name: name.to_string(),
+        category: gc,
+        production_name: g
+            .get("production")
+            .and_then(|f| f.as_str())
+            .map(|f| f.to_string())
+            .or_else(|| Glyph::default_production_name(name)),
+        codepoints,
+        layers,
+        exported: g.get("export").and_then(|e| e.as_i64()) != Some(0),
+        direction: None,
+        smart_axes,
+        lib: plist::Dictionary::new(),
+    };
+    Ok((glyph, brackets))
+}
+
+/// Brace layers are named after their location, e.g. "Medium {110}"
+fn brace_location(name: &str, axis_tags: &[String]) -> Option<Location> {
+    let start = name.find('{')?;
+    let end = start + name[start..].find('}')?;
+    let mut location = Location::new();
+    for (value, tag) in name[start + 1..end].split(',').zip(axis_tags.iter()) {
+        location
+            .0
+            .insert(tag.clone(), value.trim().parse::<f32>().ok()?);
+    }
+    Some(location)
+}
+
+/// Bracket layers are named after the start of the range on the first axis
+/// in which they apply, e.g. "Alternate [120]", or its end if the range is
+/// reversed, e.g. "Alternate ]120]".
+fn bracket_conditions(name: &str, axis_tags: &[String]) -> Option<Vec<Condition>> {
+    let end = name.rfind(']')?;
+    let start = name[..end].rfind(['[', ']'])?;
+    let value = name[start + 1..end].trim().parse::<f32>().ok()?;
+    let axis = axis_tags.first()?.clone();
+    let condition = if name[start..].starts_with(']') {
+        Condition {
+            axis,
+            min: None,
+            max: Some(value),
+        }
+    } else {
+        Condition {
+            axis,
+            min: Some(value),
+            max: None,
+        }
+    };
+    Some(vec![condition])
+}
+
+fn load_layer(
+    l: &Plist,
+    glyph_name: &str,
+    ascenders: &HashMap<String, i32>,
+    axis_tags: &[String],
+) -> Result<Layer, BabelfontError> {
+    let width = l.get("width").and_then(|x| x.as_f32()).unwrap_or(0.0);
+    let mut layer = Layer::new(width as i32);
+    layer.height = l.get("vertWidth").and_then(|x| x.as_i32());
+    if let Some(vert_origin) = l.get("vertOrigin").and_then(|x| x.as_i32()) {
+        let ascender = l
+            .get("associatedMasterId")
+            .or_else(|| l.get("layerId"))
+            .and_then(|x| x.as_str())
+            .and_then(|id| ascenders.get(id))
+            .unwrap_or(&0);
+        layer.vertical_origin = Some(ascender - vert_origin);
+    }
+    if let Some(name) = l.get("name").and_then(|l| l.as_str()) {
+        layer.name = Some(name.to_string());
+        layer.location = brace_location(name, axis_tags);
+    }
+    if let Some(id) = l.get("layerId").and_then(|l| l.as_str()) {
+        layer.id = Some(id.to_string());
+    }
+    layer.associated_master_id = l
+        .get("associatedMasterId")
+        .and_then(|x| x.as_str())
+        .map(|x| x.to_string());
+    if let Some(guides) = l.get("guideLines").and_then(|l| l.as_array()) {
+        layer.guides = guides.iter().map(load_guide).collect();
+    }
+    if let Some(anchors) = l.get("anchors").and_then(|l| l.as_array()) {
+        layer.anchors = anchors.iter().map(load_anchor).collect();
+    }
+    for path in l.get("paths").and_then(|l| l.as_array()).unwrap_or(&[]) {
+        match load_path(path, glyph_name) {
+            Ok(shape) => layer.shapes.push(shape),
+            Err(e) => log::error!("{:}", e),
+        }
+    }
+    for component in l
+        .get("components")
+        .and_then(|l| l.as_array())
+        .unwrap_or(&[])
+    {
+        match load_component(component, glyph_name) {
+            Ok(shape) => layer.shapes.push(shape),
+            Err(e) => log::error!("{:}", e),
+        }
+    }
+    Ok(layer)
+}
+
+fn load_anchor(a: &Plist) -> Anchor {
+    let (x, y) = a
+        .get("position")
+        .and_then(|p| p.as_str())
+        .and_then(parse_point)
+        .unwrap_or((0.0, 0.0));
+    Anchor {
+        x: x as i32,
+        y: y as i32,
+        name: a
+            .get("name")
+            .and_then(|x| x.as_str())
+            .unwrap_or("Unknown")
+            .to_string(),
+    }
+}
+
+/// Parses a "x y TYPE [SMOOTH]" node string.
+fn load_node(s: &str) -> Option<Node> {
+    let mut parts = s.split_whitespace();
+    let x = parts.next()?.parse::<f32>().ok()?;
+    let y = parts.next()?.parse::<f32>().ok()?;
+    let nodetype = match parts.next()? {
+        "MOVE" => NodeType::Move,
+        "OFFCURVE" => NodeType::OffCurve,
+        "CURVE" => NodeType::Curve,
+        "QCURVE" => NodeType::QCurve,
+        _ => NodeType::Line,
+    };
+    let smooth = parts.next() == Some("SMOOTH");
+    Some(Node {
+        x,
+        y,
+        nodetype,
+        smooth,
+    })
+}
+
+fn load_path(p: &Plist, glyph_name: &str) -> Result<Shape, BabelfontError> {
+    let mut path = Path {
+        nodes: vec![],
+        closed: p.get("closed").and_then(|c| c.as_i64()) == Some(1),
+        direction: crate::shape::PathDirection::Clockwise,
+    };
+    for node in p.get("nodes").and_then(|n| n.as_array()).unwrap_or(&[]) {
+        path.nodes
+            .push(
+                node.as_str()
+                    .and_then(load_node)
+                    .ok_or_else(|| BabelfontError::General {
+                        msg: format!(
+                            "Couldn't convert {:?} to nodes in glyph {:}",
+                            node, glyph_name
+                        ),
+                    })?,
+            );
+    }
+    // Open paths start with a move
+    if !path.closed {
+        if let Some(first) = path.nodes.first_mut() {
+            first.nodetype = NodeType::Move;
+        }
+    }
+    Ok(PathShape(path))
+}
+
+/// Components have a "{a, b, c, d, tx, ty}" transform string.
+fn load_component(c: &Plist, glyph_name: &str) -> Result<Shape, BabelfontError> {
+    let reference = c
+        .get("name")
+        .and_then(|f| f.as_str())
+        .ok_or(BabelfontError::General {
+            msg: format!(
+                "Couldn't understand component reference in glyph {:}",
+                glyph_name
+            ),
+        })?;
+    let mut coeffs = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+    if let Some(transform) = c.get("transform").and_then(|t| t.as_str()) {
+        let values: Vec<f64> = transform
+            .trim_matches(|c: char| c == '{' || c == '}' || c.is_whitespace())
+            .split(',')
+            .filter_map(|v| v.trim().parse().ok())
+            .collect();
+        if values.len() == 6 {
+            coeffs.copy_from_slice(&values);
+        }
+    }
+    let mut component = Component {
+        reference: reference.to_string(),
+        transform: kurbo::Affine::new(coeffs),
+        ..Default::default()
+    };
+    load_component_properties(&mut component, c);
+    Ok(ComponentShape(component))
+}
+
+/// Glyphs 2 stores some names at the top level and the rest as custom
+/// parameters with the same keys as Glyphs 3 properties.
+fn load_names(font: &mut Font, plist: &Plist, custom_parameters: &HashMap<String, &Plist>) {
+    let top_level = NAME_KEYS
+        .iter()
+        .filter_map(|&key| plist.get(key).map(|v| (key, v)));
+    let parameters = custom_parameters.iter().map(|(k, v)| (k.as_str(), *v));
+    for (key, value) in top_level.chain(parameters) {
+        if let Some(value) = value.as_str() {
+            let mut val = I18NDictionary::new();
+            val.set_default(value.to_string());
+            set_name(&mut font.names, key, val);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let font = load("data/TwoMastersG2.glyphs".into()).unwrap();
+        assert_eq!(font.axes.len(), 1);
+        let weight = &font.axes[0];
+        assert_eq!(weight.tag, "wght");
+        assert_eq!(
+            weight.map,
+            Some(vec![(300.0, 60.0), (500.0, 110.0), (700.0, 160.0)])
+        );
+        assert_eq!(weight.default, Some(300.0));
+
+        assert_eq!(font.masters.len(), 2);
+        let bold = &font.masters[1];
+        assert_eq!(bold.name.get_default(), Some("Bold".to_string()));
+        assert_eq!(bold.location.0.get("wght"), Some(&160.0));
+        assert_eq!(bold.metrics.get("ascender"), Some(&800));
+        assert_eq!(bold.metrics.get("capHeight"), Some(&720));
+        assert_eq!(bold.metrics.get("zone 6"), Some(&-100));
+        assert_eq!(bold.overshoots.get("capHeight"), Some(&16));
+        assert_eq!(bold.overshoots.get("zone 6"), Some(&-10));
+        assert_eq!(
+            bold.kerning
+                .get(&("@MMK_L_A".to_string(), "@MMK_R_A".to_string())),
+            Some(&-30)
+        );
+        assert_eq!(font.masters[0].guides[0].pos.angle, 90.0);
+
+        let a = font.glyphs.get("A").unwrap();
+        assert_eq!(a.codepoints, vec![0x41]);
+        assert_eq!(a.layers.len(), 3);
+        let path = a.layers[0].paths().nth(1).unwrap();
+        assert!(!path.closed);
+        assert_eq!(path.nodes[0].nodetype, NodeType::Move);
+        assert_eq!(path.nodes[3].nodetype, NodeType::Curve);
+        assert!(path.nodes[3].smooth);
+        assert!(!path.nodes[0].smooth);
+        assert_eq!(a.layers[0].anchors[0].y, 700);
+        let brace = a.layers[2].location.as_ref().unwrap();
+        assert_eq!(brace.0.get("wght"), Some(&110.0));
+
+        let aacute = font.glyphs.get("Aacute").unwrap();
+        assert_eq!(aacute.codepoints, vec![0xC1, 0xE1]);
+        let component = aacute.layers[1].components().nth(1).unwrap();
+        assert_eq!(
+            component.transform.as_coeffs(),
+            [-1.0, 0.0, 0.0, 1.0, 350.0, 20.0]
+        );
+
+        let acutecomb = font.glyphs.get("acutecomb").unwrap();
+        assert!(!acutecomb.exported);
+        assert!(matches!(acutecomb.category, GlyphCategory::Mark));
+        assert_eq!(acutecomb.production_name, Some("uni0301".to_string()));
+        assert_eq!(acutecomb.layers.len(), 2);
+
+        // A reversed bracket layer becomes an alternate for weights up to 120
+        let alternate = font.glyphs.get("acutecomb.BRACKET.varAlt01").unwrap();
+        assert_eq!(alternate.layers.len(), 2);
+        assert_eq!(alternate.layers[1].id.as_deref(), Some(bold.id.as_str()));
+        assert_eq!(alternate.layers[1].paths().next().unwrap().nodes[1].x, 80.0);
+        assert_eq!(font.rules.len(), 1);
+        assert_eq!(
+            font.rules[0].condition_sets,
+            vec![vec![Condition {
+                axis: "wght".to_string(),
+                min: None,
+                max: Some(120.0)
+            }]]
+        );
+
+        assert_eq!(font.instances.len(), 2);
+        assert_eq!(font.instances[1].location.0.get("wght"), Some(&110.0));
+        assert_eq!(font.version, (2, 1));
+        assert_eq!(
+            font.names.designer.get_default(),
+            Some("Jane Doe".to_string())
+        );
+        assert!(font
+            .kern_groups
+            .get("MMK_L_A")
+            .unwrap()
+            .contains(&"Aacute".to_string()));
+        let features = font.features.unwrap();
+        assert!(features.contains("@Uppercase = [A Aacute];"));
+        assert!(features.contains("feature ss01 {"));
+        assert!(!features.contains("ss02"));
+        assert!(font
+            .custom_ot_values
+            .iter()
+            .any(|v| v.table == "OS2" && v.field == "sTypoAscender"));
+    }
+
+    #[test]
+    fn test_sniff_version() {
+        assert!(matches!(
+            load("../fonticulus/test/sources/SimpleTwoAxis.glyphs".into()),
+            Err(BabelfontError::WrongConvertor { .. })
+        ));
+        let font = crate::load("data/TwoMastersG2.glyphs").unwrap();
+        assert_eq!(font.masters.len(), 2);
+        let font = crate::load("../fonticulus/test/sources/SimpleTwoAxis.glyphs").unwrap();
+        assert_eq!(font.masters.len(), 4);
+    }
+}
@@ -1,42 +1,58 @@
 use crate::common::OTValue;
 use crate::glyph::GlyphCategory;
 use crate::i18ndictionary::I18NDictionary;
+use crate::names::Names;
 use crate::OTScalar::Signed;
 use crate::Shape::{ComponentShape, PathShape};
 use crate::{
-    Anchor, Axis, BabelfontError, Component, Font, Glyph, Guide, Instance, Layer, Location, Master,
-    Node, NodeType, OTScalar, Path, Position, Shape,
+    Anchor, Axis, BabelfontError, Component, Condition, Font, Glyph, Guide, Instance, Layer,
+    Location, Master, Node, NodeType, OTScalar, Path, Position, Rule, Shape, SmartAxis,
 };
 use chrono::TimeZone;
 use fonttools::types::Tag;
+use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use openstep_plist::Plist;
-use std::collections::HashMap;
+use otmath::ot_cmp;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use uuid::Uuid;
 
 pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
+    let plist = read_plist(&path)?;
+    if plist.get(".formatVersion").is_none() {
+        return Err(BabelfontError::WrongConvertor { path });
+    }
+    let font = load_plist(&plist);
+    std::mem::forget(plist);
+    font
+}
+
+/// Reads and parses a Glyphs file of either format version.
+pub(crate) fn read_plist(path: &PathBuf) -> Result<Plist, BabelfontError> {
     log::debug!("Reading to string");
-    let s = fs::read_to_string(&path).map_err(|source| BabelfontError::IO {
+    let s = fs::read_to_string(path).map_err(|source| BabelfontError::IO {
         path: path.clone(),
         source,
     })?;
     log::debug!("Parsing PLIST");
-    let plist = Plist::parse(&s).map_err(|orig| BabelfontError::PlistParse {
+    Plist::parse(&s).map_err(|orig| BabelfontError::PlistParse {
         path: path.clone(),
         orig,
-    })?;
-    log::debug!("Assembling babelfont");
-    if plist.get(".formatVersion").is_none() {
-        return Err(BabelfontError::WrongConvertor { path });
-    }
+    })
+}
 
+pub(crate) fn load_plist(plist: &Plist) -> Result<Font, BabelfontError> {
+    log::debug!("Assembling babelfont");
     let mut font = Font::new();
 
-    let custom_parameters = get_custom_parameters(&plist);
-    load_axes(&mut font, &plist);
-    font.kern_groups = load_kern_groups(&plist);
-    load_masters(&mut font, &plist)?;
+    let custom_parameters = get_custom_parameters(plist);
+    load_axes(&mut font, plist);
+    font.kern_groups = load_kern_groups(plist);
+    load_masters(&mut font, plist)?;
     let default_master_id = custom_parameters
         .get(&"Variable Font Origin".to_string())
         .and_then(|x| x.as_str())
@@ -44,7 +60,7 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
         .or_else(|| font.masters.first().map(|m| m.id.clone()));
 
     fixup_axes(&mut font, default_master_id.as_ref());
-    load_glyphs(&mut font, &plist);
+    load_glyphs(&mut font, plist);
 
     if let Some(instances) = plist.get("instances").and_then(|f| f.as_array()) {
         for instance in instances {
@@ -53,15 +69,109 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
     }
 
     fixup_axis_mappings(&mut font);
-    load_metadata(&mut font, &plist);
+    load_metadata(&mut font, plist);
 
     load_custom_parameters(&mut font.custom_ot_values, custom_parameters);
-    std::mem::forget(plist);
-    // load_features(&mut font, &plist);
+    load_features(&mut font, plist);
+    font.lib = load_lib(plist, &FONT_KEYS);
     Ok(font)
 }
 
-fn get_custom_parameters(plist: &Plist) -> HashMap<String, &Plist> {
+/// Lib keys for the Glyphs data which babelfont doesn't model are the
+/// Glyphs keys with this prefix
+const LIB_PREFIX: &str = "com.schriftgestaltung.";
+
+/// The lib key under which an alternate glyph keeps the IDs its layers had
+/// as bracket layers, keyed by master ID
+const BRACKET_LAYER_IDS: &str = "com.schriftgestaltung.bracketLayerIds";
+
+/// Keys of the font dictionary which are loaded into the model
+const FONT_KEYS: [&str; 18] = [
+    ".formatVersion",
+    "axes",
+    "classes",
+    "customParameters",
+    "date",
+    "familyName",
+    "featurePrefixes",
+    "features",
+    "fontMaster",
+    "glyphs",
+    "instances",
+    "kerningLTR",
+    "metrics",
+    "note",
+    "properties",
+    "unitsPerEm",
+    "versionMajor",
+    "versionMinor",
+];
+
+/// Keys of a master dictionary which are loaded into the model
+const MASTER_KEYS: [&str; 6] = [
+    "axesValues",
+    "customParameters",
+    "guides",
+    "id",
+    "metricValues",
+    "name",
+];
+
+/// Keys of a glyph dictionary which are loaded into the model
+const GLYPH_KEYS: [&str; 11] = [
+    "category",
+    "export",
+    "glyphname",
+    "kernLeft",
+    "kernRight",
+    "layers",
+    "partsSettings",
+    "production",
+    "subCategory",
+    "subcategory",
+    "unicode",
+];
+
+/// Keeps the user data of a font, master or glyph in its lib, along with
+/// the keys babelfont doesn't otherwise load, so that they can be saved
+/// again.
+fn load_lib(plist: &Plist, known: &[&str]) -> plist::Dictionary {
+    let mut lib = plist::Dictionary::new();
+    if let Some(user_data) = plist.get("userData").and_then(|u| u.as_dict()) {
+        for (key, value) in user_data {
+            lib.insert(key.clone(), plist_to_value(value));
+        }
+    }
+    for (key, value) in plist.as_dict().into_iter().flatten() {
+        if key != "userData" && !known.contains(&key.as_str()) {
+            lib.insert(
+                format!("{}{}", LIB_PREFIX, key.trim_start_matches('.')),
+                plist_to_value(value),
+            );
+        }
+    }
+    lib
+}
+
+fn plist_to_value(p: &Plist) -> plist::Value {
+    match p {
+        Plist::Dictionary(d) => plist::Value::Dictionary(
+            d.iter()
+                .map(|(k, v)| (k.clone(), plist_to_value(v)))
+                .collect(),
+        ),
+        Plist::Array(a) => plist::Value::Array(a.iter().map(plist_to_value).collect()),
+        Plist::String(s) => s.clone().into(),
+        Plist::Binary(b) => plist::Value::Data(b.clone()),
+        Plist::Node((x, y, typ)) => {
+            plist::Value::Array(vec![(*x).into(), (*y).into(), typ.clone().into()])
+        }
+        Plist::Integer(i) => (*i).into(),
+        Plist::Float(f) => (*f).into(),
+    }
+}
+
+pub(crate) fn get_custom_parameters(plist: &Plist) -> HashMap<String, &Plist> {
     let mut cp: HashMap<String, &Plist> = HashMap::new();
     if let Some(param) = plist.get("customParameters") {
         for p in param.as_array().unwrap() {
@@ -78,18 +188,21 @@ fn get_custom_parameters(plist: &Plist) -> HashMap<String, &Plist> {
     cp
 }
 
-fn load_kern_groups(plist: &Plist) -> HashMap<String, Vec<String>> {
+pub(crate) fn load_kern_groups(plist: &Plist) -> HashMap<String, Vec<String>> {
     let mut groups: HashMap<String, Vec<String>> = HashMap::new();
     if let Some(glyphs) = plist.get("glyphs").and_then(|a| a.as_array()) {
         for g in glyphs {
             if let Some(glyphname) = g.get("glyphname").and_then(|s| s.as_str()) {
+                // A glyph's right-side group is used when it's first in a pair
                 let l_class = g
-                    .get("leftKerningGroup")
+                    .get("kernRight")
+                    .or_else(|| g.get("rightKerningGroup"))
                     .and_then(|s| s.as_str())
                     .map(|s| s.to_string())
                     .unwrap_or_else(|| glyphname.to_string());
                 let r_class = g
-                    .get("rightKerningGroup")
+                    .get("kernLeft")
+                    .or_else(|| g.get("leftKerningGroup"))
                     .and_then(|s| s.as_str())
                     .map(|s| s.to_string())
                     .unwrap_or_else(|| glyphname.to_string());
@@ -171,7 +284,10 @@ fn load_masters(font: &mut Font, plist: &Plist) -> Result<(), BabelfontError> {
                 load_kerning(&mut new_master, kerning);
             }
             let custom_parameters = get_custom_parameters(master);
+            let userspace_location = axis_locations(font, &custom_parameters);
+            add_axis_mappings(font, &userspace_location, &new_master.location);
             load_custom_parameters(&mut new_master.custom_ot_values, custom_parameters);
+            new_master.lib = load_lib(master, &MASTER_KEYS);
             font.masters.push(new_master)
         }
     }
@@ -197,14 +313,18 @@ fn load_metrics(new_master: &mut Master, master: &Plist, metrics: Option<&Plist>
                     new_master
                         .metrics
                         .insert(convert_metric_name(metric_name), value);
-                    // I don't care about overshoots today.
+                    if let Some(over) = metric_value.get("over").and_then(|o| o.as_i32()) {
+                        new_master
+                            .overshoots
+                            .insert(convert_metric_name(metric_name), over);
+                    }
                 }
             }
         }
     }
 }
 
-fn load_kerning(new_master: &mut Master, kerning: &Plist) {
+pub(crate) fn load_kerning(new_master: &mut Master, kerning: &Plist) {
     let mut out_kerning = HashMap::new();
     for (left, right_dict) in kerning.as_dict().unwrap().iter() {
         for (right, value) in right_dict.as_dict().unwrap().iter() {
@@ -255,7 +375,7 @@ fn load_guide(g: &Plist) -> Guide {
     guide
 }
 
-fn fixup_axes(f: &mut Font, default_master_id: Option<&String>) {
+pub(crate) fn fixup_axes(f: &mut Font, default_master_id: Option<&String>) {
     for master in &f.masters {
         for mut axis in f.axes.iter_mut() {
             let this_loc = *(master.location.0.get(&axis.tag).unwrap_or(&0.0));
@@ -273,16 +393,113 @@ fn fixup_axes(f: &mut Font, default_master_id: Option<&String>) {
 }
 
 fn load_glyphs(font: &mut Font, plist: &Plist) {
+    // Vertical origins are stored relative to the master's ascender
+    let ascenders: HashMap<String, i32> = font
+        .masters
+        .iter()
+        .map(|m| (m.id.clone(), *m.metrics.get("ascender").unwrap_or(&0)))
+        .collect();
+    let axis_tags: Vec<String> = font.axes.iter().map(|a| a.tag.clone()).collect();
+    let mut brackets = vec![];
     if let Some(glyphs) = plist.get("glyphs").and_then(|a| a.as_array()) {
         for g in glyphs {
-            if let Ok(glyph) = load_glyph(g) {
+            if let Ok((glyph, glyph_brackets)) = load_glyph(g, &ascenders, &axis_tags) {
                 font.glyphs.push(glyph);
+                brackets.extend(glyph_brackets);
             }
         }
     }
+    add_bracket_glyphs(font, brackets);
+}
+
+/// A "bracket" layer: an alternate design of a glyph for one master, which
+/// is used within a region of the designspace.
+pub(crate) struct BracketLayer {
+    pub glyph: String,
+    pub master_id: String,
+    pub conditions: Vec<Condition>,
+    pub layer: Layer,
+}
+
+/// A glyph's bracket layers for one region, as (master ID, layer) pairs
+type Alternate = (String, Vec<Condition>, Vec<(String, Layer)>);
+
+/// Moves bracket layers into alternate glyphs, named as glyphsLib does, and
+/// adds rules which substitute them within their regions. Masters without
+/// a bracket layer use the glyph's own layer for that master.
+pub(crate) fn add_bracket_glyphs(font: &mut Font, brackets: Vec<BracketLayer>) {
+    let mut alternates: Vec<Alternate> = vec![];
+    for bracket in brackets {
+        match alternates
+            .iter_mut()
+            .find(|(g, c, _)| *g == bracket.glyph && *c == bracket.conditions)
+        {
+            Some((_, _, layers)) => layers.push((bracket.master_id, bracket.layer)),
+            None => alternates.push((
+                bracket.glyph,
+                bracket.conditions,
+                vec![(bracket.master_id, bracket.layer)],
+            )),
+        }
+    }
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (glyph_name, conditions, layers) in alternates {
+        let mut alternate = match font.glyphs.get(&glyph_name) {
+            Some(g) => g.clone(),
+            None => continue,
+        };
+        let count = counts.entry(glyph_name.clone()).or_insert(0);
+        *count += 1;
+        alternate.name = format!("{}.BRACKET.varAlt{:02}", glyph_name, count);
+        alternate.codepoints = vec![];
+        alternate.production_name = None;
+        let mut layer_ids = plist::Dictionary::new();
+        alternate.layers = font
+            .masters
+            .iter()
+            .filter_map(|master| {
+                let mut layer = match layers.iter().find(|(id, _)| *id == master.id) {
+                    Some((_, layer)) => {
+                        if let Some(id) = &layer.id {
+                            layer_ids.insert(master.id.clone(), id.clone().into());
+                        }
+                        layer.clone()
+                    }
+                    None => font.master_layer_for(&glyph_name, master).cloned()?,
+                };
+                layer.id = Some(master.id.clone());
+                layer.associated_master_id = None;
+                Some(layer)
+            })
+            .collect();
+        if !layer_ids.is_empty() {
+            alternate
+                .lib
+                .insert(BRACKET_LAYER_IDS.to_string(), layer_ids.into());
+        }
+
+        let substitution = (glyph_name, alternate.name.clone());
+        match font
+            .rules
+            .iter_mut()
+            .find(|r| r.condition_sets.len() == 1 && r.condition_sets[0] == conditions)
+        {
+            Some(rule) => rule.substitutions.push(substitution),
+            None => font.rules.push(Rule {
+                name: None,
+                condition_sets: vec![conditions],
+                substitutions: vec![substitution],
+            }),
+        }
+        font.glyphs.push(alternate);
+    }
 }
 
-fn load_glyph(g: &Plist) -> Result<Glyph, BabelfontError> {
+fn load_glyph(
+    g: &Plist,
+    ascenders: &HashMap<String, i32>,
+    axis_tags: &[String],
+) -> Result<(Glyph, Vec<BracketLayer>), BabelfontError> {
     let name = g
         .get("glyphname")
         .and_then(|f| f.as_str())
@@ -290,41 +507,163 @@ fn load_glyph(g: &Plist) -> Result<Glyph, BabelfontError> {
             msg: "Couldn't read a glyph name!".to_string(),
         })?;
     let category = g.get("category").and_then(|f| f.as_str());
-    let subcategory = g.get("subcategory").and_then(|f| f.as_str());
+    let subcategory = g
+        .get("subCategory")
+        .or_else(|| g.get("subcategory"))
+        .and_then(|f| f.as_str());
     let codepoints = get_codepoints(g);
     let gc = if subcategory == Some("Ligature") {
         GlyphCategory::Ligature
     } else if category == Some("Mark") {
         GlyphCategory::Mark
+    } else if category.is_none() && subcategory.is_none() {
+        GlyphCategory::from_glyph(name, &codepoints)
     } else {
         GlyphCategory::Base
     };
+    let smart_axes = load_smart_axes(g);
     let mut layers = vec![];
+    let mut brackets = vec![];
     if let Some(plist_layers) = g.get("layers") {
         for layer in plist_layers.as_array().unwrap() {
-            layers.push(load_layer(layer, name)?);
+            let mut loaded = load_layer(layer, name, ascenders, axis_tags)?;
+            if let Some(selection) = layer.get("partSelection") {
+                loaded.smart_location = pole_location(selection, &smart_axes);
+            }
+            let master_id = layer.get("associatedMasterId").and_then(|x| x.as_str());
+            match (master_id, axis_rules(layer, axis_tags)) {
+                (Some(master_id), Some(conditions)) => brackets.push(BracketLayer {
+                    glyph: name.to_string(),
+                    master_id: master_id.to_string(),
+                    conditions,
+                    layer: loaded,
+                }),
+                _ => layers.push(loaded),
+            }
         }
     }
-    Ok(Glyph {
+    let glyph = Glyph {
         name: name.to_string(),
         category: gc,
-        production_name: None,
+        production_name: g
+            .get("production")
+            .and_then(|f| f.as_str())
+            .map(|f| f.to_string())
+            .or_else(|| Glyph::default_production_name(name)),
         codepoints,
         layers,
         exported: g.get("export").is_none(),
         direction: None,
-    })
+        smart_axes,
+        lib: load_lib(g, &GLYPH_KEYS),
+    };
+    Ok((glyph, brackets))
+}
+
+/// Smart component glyphs declare their axes in their parts settings.
+pub(crate) fn load_smart_axes(g: &Plist) -> Vec<SmartAxis> {
+    g.get("partsSettings")
+        .and_then(|p| p.as_array())
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|axis| {
+            Some(SmartAxis {
+                name: axis.get("name")?.as_str()?.to_string(),
+                bottom: axis.get("bottomValue").and_then(|v| v.as_f32())?,
+                top: axis.get("topValue").and_then(|v| v.as_f32())?,
+            })
+        })
+        .collect()
+}
+
+/// Part layers of smart component glyphs select a pole of each smart axis:
+/// 1 for the bottom value, 2 for the top.
+pub(crate) fn pole_location(selection: &Plist, smart_axes: &[SmartAxis]) -> Location {
+    let mut location = Location::new();
+    for axis in smart_axes {
+        match selection.get(&axis.name).and_then(|p| p.as_i32()) {
+            Some(1) => location.0.insert(axis.name.clone(), axis.bottom),
+            Some(2) => location.0.insert(axis.name.clone(), axis.top),
+            _ => None,
+        };
+    }
+    location
+}
+
+/// Reads the properties components have in both format versions.
+pub(crate) fn load_component_properties(component: &mut Component, c: &Plist) {
+    component.anchor = c.get("anchor").and_then(|a| a.as_str()).map(String::from);
+    component.alignment = c.get("alignment").and_then(|a| a.as_i32());
+    if let Some(piece) = c.get("piece").and_then(|p| p.as_dict()) {
+        for (name, value) in piece {
+            if let Some(value) = value.as_f32() {
+                component.smart_location.0.insert(name.clone(), value);
+            }
+        }
+    }
 }
 
-fn load_layer(l: &Plist, glyph_name: &str) -> Result<Layer, BabelfontError> {
+/// Bracket layers store the ranges in which they apply as axis rules, one
+/// (possibly empty) dictionary of designspace `min` and `max` per axis.
+fn axis_rules(l: &Plist, axis_tags: &[String]) -> Option<Vec<Condition>> {
+    let rules = l.get("attr")?.get("axisRules")?.as_array()?;
+    Some(
+        rules
+            .iter()
+            .zip(axis_tags.iter())
+            .filter_map(|(rule, tag)| {
+                let min = rule.get("min").and_then(|v| v.as_f32());
+                let max = rule.get("max").and_then(|v| v.as_f32());
+                (min.is_some() || max.is_some()).then(|| Condition {
+                    axis: tag.clone(),
+                    min,
+                    max,
+                })
+            })
+            .collect(),
+    )
+}
+
+fn load_layer(
+    l: &Plist,
+    glyph_name: &str,
+    ascenders: &HashMap<String, i32>,
+    axis_tags: &[String],
+) -> Result<Layer, BabelfontError> {
     let width = l.get("width").and_then(|x| x.as_i32()).unwrap_or(0);
     let mut layer = Layer::new(width);
+    layer.height = l.get("vertWidth").and_then(|x| x.as_i32());
+    if let Some(vert_origin) = l.get("vertOrigin").and_then(|x| x.as_i32()) {
+        let ascender = l
+            .get("associatedMasterId")
+            .or_else(|| l.get("layerId"))
+            .and_then(|x| x.as_str())
+            .and_then(|id| ascenders.get(id))
+            .unwrap_or(&0);
+        layer.vertical_origin = Some(ascender - vert_origin);
+    }
     if let Some(name) = l.get("name").and_then(|l| l.as_str()) {
         layer.name = Some(name.to_string());
     }
     if let Some(id) = l.get("layerId").and_then(|l| l.as_str()) {
         layer.id = Some(id.to_string());
     }
+    layer.associated_master_id = l
+        .get("associatedMasterId")
+        .and_then(|x| x.as_str())
+        .map(|x| x.to_string());
+    // Brace layers store their designspace location as coordinates
+    if let Some(coordinates) = l
+        .get("attr")
+        .and_then(|a| a.get("coordinates"))
+        .and_then(|c| c.as_array())
+    {
+        let mut location = Location::new();
+        for (v, tag) in coordinates.iter().zip(axis_tags.iter()) {
+            location.0.insert(tag.clone(), v.as_f32().unwrap_or(0.0));
+        }
+        layer.location = Some(location);
+    }
     if let Some(guides) = l.get("guides").and_then(|l| l.as_array()) {
         layer.guides = guides.iter().map(load_guide).collect();
     }
@@ -359,11 +698,11 @@ fn load_anchor(a: &Plist) -> Anchor {
     }
 }
 
-fn extract_node_from_array(node: &Plist) -> Option<(i64, i64, String)> {
+fn extract_node_from_array(node: &Plist) -> Option<(f64, f64, String)> {
     if let Some(a) = node.as_array() {
         if a.len() >= 3 {
-            if let Some(x) = a[0].as_i64() {
-                if let Some(y) = a[1].as_i64() {
+            if let Some(x) = a[0].as_f64() {
+                if let Some(y) = a[1].as_f64() {
                     if let Some(s) = a[2].as_str() {
                         return Some((x, y, s.to_string()));
                     }
@@ -379,7 +718,7 @@ fn load_shape(a: &Plist, glyph_name: &str) -> Result<Shape, BabelfontError> {
         // It's a path
         let mut path = Path {
             nodes: vec![],
-            closed: true,
+            closed: a.get("closed").and_then(|c| c.as_i64()) == Some(1),
             direction: crate::shape::PathDirection::Clockwise,
         };
         for node in a
@@ -400,19 +739,29 @@ fn load_shape(a: &Plist, glyph_name: &str) -> Result<Shape, BabelfontError> {
                         node, glyph_name
                     ),
                 })?;
+            // Smooth nodes have an "s" after their type
+            let smooth = typ.chars().nth(1) == Some('s');
             let typ = typ.chars().next().unwrap_or('l');
             let nodetype = match typ {
                 'l' => NodeType::Line,
                 'o' => NodeType::OffCurve,
                 'c' => NodeType::Curve,
+                'q' => NodeType::QCurve,
                 _ => NodeType::Line,
             };
             path.nodes.push(Node {
                 x: x as f32,
                 y: y as f32,
                 nodetype,
+                smooth,
             })
         }
+        // Open paths start with a move
+        if !path.closed {
+            if let Some(first) = path.nodes.first_mut() {
+                first.nodetype = NodeType::Move;
+            }
+        }
         Ok(PathShape(path))
     } else {
         // It's a component
@@ -440,19 +789,40 @@ fn load_shape(a: &Plist, glyph_name: &str) -> Result<Shape, BabelfontError> {
             .iter()
             .map(|x| x.as_f32().unwrap_or(0.0))
             .collect();
+        let slant: Vec<f64> = a
+            .get("slant")
+            .and_then(|f| f.as_array())
+            .unwrap_or(&[])
+            .iter()
+            .map(|x| x.as_f64().unwrap_or(0.0).to_radians())
+            .collect();
+        let angle = a.get("angle").and_then(|f| f.as_f64()).unwrap_or(0.0);
         let transform = kurbo::Affine::translate((
             *pos.first().unwrap_or(&0.0) as f64,
             *pos.last().unwrap_or(&0.0) as f64,
         ));
+        let rotation = kurbo::Affine::rotate(angle.to_radians());
         let scalingtransform = kurbo::Affine::scale_non_uniform(
             *scale.first().unwrap_or(&1.0) as f64,
             *scale.last().unwrap_or(&1.0) as f64,
         );
+        let slanttransform = kurbo::Affine::new([
+            1.0,
+            slant.get(1).unwrap_or(&0.0).tan(),
+            slant.first().unwrap_or(&0.0).tan(),
+            1.0,
+            0.0,
+            0.0,
+        ]);
 
-        Ok(ComponentShape(Component {
+        // Slant first, then scale, rotate and position
+        let mut component = Component {
             reference: reference.to_string(),
-            transform: transform * scalingtransform,
-        }))
+            transform: transform * rotation * scalingtransform * slanttransform,
+            ..Default::default()
+        };
+        load_component_properties(&mut component, a);
+        Ok(ComponentShape(component))
     }
 }
 
@@ -472,7 +842,7 @@ fn get_codepoints(g: &Plist) -> Vec<usize> {
     }
 }
 
-fn load_metadata(font: &mut Font, plist: &Plist) {
+pub(crate) fn load_metadata(font: &mut Font, plist: &Plist) {
     font.upm = plist
         .get("unitsPerEm")
         .and_then(|x| x.as_i32())
@@ -498,7 +868,7 @@ fn load_metadata(font: &mut Font, plist: &Plist) {
         .and_then(|x| x.as_str())
         .as_ref()
         .and_then(|x| chrono::NaiveDateTime::parse_from_str(x, "%Y-%m-%d %H:%M:%S +0000").ok())
-        .map(|x| chrono::Local.from_local_datetime(&x).unwrap())
+        .map(|x| chrono::Local.from_utc_datetime(&x))
         .unwrap_or_else(chrono::Local::now);
     font.note = plist
         .get("note")
@@ -506,10 +876,57 @@ fn load_metadata(font: &mut Font, plist: &Plist) {
         .map(|x| x.to_string());
 }
 
+/// Gathers the feature prefixes, classes and features into a single
+/// feature file.
+pub(crate) fn load_features(font: &mut Font, plist: &Plist) {
+    let mut fea = String::new();
+    for prefix in plist
+        .get("featurePrefixes")
+        .and_then(|a| a.as_array())
+        .unwrap_or(&[])
+    {
+        if let Some(code) = prefix.get("code").and_then(|c| c.as_str()) {
+            fea.push_str(code);
+            fea.push('\n');
+        }
+    }
+    for class in plist
+        .get("classes")
+        .and_then(|a| a.as_array())
+        .unwrap_or(&[])
+    {
+        let name = class.get("name").and_then(|c| c.as_str());
+        let code = class.get("code").and_then(|c| c.as_str());
+        if let (Some(name), Some(code)) = (name, code) {
+            fea.push_str(&format!("@{} = [{}];\n", name, code));
+        }
+    }
+    for feature in plist
+        .get("features")
+        .and_then(|a| a.as_array())
+        .unwrap_or(&[])
+    {
+        if feature.get("disabled").and_then(|d| d.as_i64()) == Some(1) {
+            continue;
+        }
+        let tag = feature
+            .get("tag")
+            .or_else(|| feature.get("name"))
+            .and_then(|c| c.as_str());
+        let code = feature.get("code").and_then(|c| c.as_str());
+        if let (Some(tag), Some(code)) = (tag, code) {
+            fea.push_str(&format!("feature {} {{\n{}\n}} {};\n", tag, code, tag));
+        }
+    }
+    if !fea.is_empty() {
+        font.features = Some(fea);
+    }
+}
+
 fn load_properties(font: &mut Font, plist: &Plist) {
     if let Some(props) = plist.get("properties").and_then(|d| d.as_array()) {
         for prop in props {
-            if let Some(key) = prop.get("key").map(|f| f.to_string()) {
+            if let Some(key) = prop.get("key").and_then(|f| f.as_str()) {
                 let mut val = I18NDictionary::new();
                 if let Some(pval) = prop.get("value").and_then(|f| f.as_str()) {
                     val.set_default(pval.to_string());
@@ -526,37 +943,54 @@ fn load_properties(font: &mut Font, plist: &Plist) {
                         }
                     }
                 }
-                if key == "copyright" || key == "copyrights" {
-                    font.names.copyright = val;
-                } else if key == "designer" || key == "designers" {
-                    font.names.designer = val;
-                } else if key == "designerURL" {
-                    font.names.designer_url = val;
-                } else if key == "manufacturer" || key == "manufacturers" {
-                    font.names.manufacturer = val;
-                } else if key == "manufacturerURL" {
-                    font.names.manufacturer_url = val;
-                } else if key == "license" || key == "licenses" {
-                    font.names.license = val;
-                } else if key == "licenseURL" {
-                    font.names.license_url = val;
-                } else if key == "trademark" || key == "trademarks" {
-                    font.names.trademark = val;
-                } else if key == "description" || key == "descriptions" {
-                    font.names.description = val;
-                } else if key == "sampleText" || key == "sampleTexts" {
-                    font.names.sample_text = val;
-                } else if key == "postscriptFullName" { // ??
-                } else if key == "WWSFamilyName" {
-                    font.names.w_w_s_family_name = val;
-                } else if key == "versionString" {
-                    font.names.version = val;
-                }
+                set_name(&mut font.names, key, val);
             }
         }
     }
 }
 
+/// Stores a name given by its Glyphs property or custom parameter key.
+pub(crate) fn set_name(names: &mut Names, key: &str, val: I18NDictionary) {
+    if key == "copyright" || key == "copyrights" {
+        names.copyright = val;
+    } else if key == "designer" || key == "designers" {
+        names.designer = val;
+    } else if key == "designerURL" {
+        names.designer_url = val;
+    } else if key == "manufacturer" || key == "manufacturers" {
+        names.manufacturer = val;
+    } else if key == "manufacturerURL" {
+        names.manufacturer_url = val;
+    } else if key == "license" || key == "licenses" {
+        names.license = val;
+    } else if key == "licenseURL" {
+        names.license_url = val;
+    } else if key == "trademark" || key == "trademarks" {
+        names.trademark = val;
+    } else if key == "description" || key == "descriptions" {
+        names.description = val;
+    } else if key == "sampleText" || key == "sampleTexts" {
+        names.sample_text = val;
+    } else if key == "postscriptFullName" { // ??
+    } else if key == "WWSFamilyName" {
+        names.w_w_s_family_name = val;
+    } else if key == "WWSSubfamilyName" {
+        names.w_w_s_subfamily_name = val;
+    } else if key == "versionString" {
+        names.version = val;
+    } else if key == "uniqueID" {
+        names.unique_id = val;
+    } else if key == "compatibleFullName" || key == "compatibleFullNames" {
+        names.compatible_full_name = val;
+    } else if key == "preferredFamilyName" || key == "preferredFamilyNames" {
+        names.typographic_family = val;
+    } else if key == "preferredSubfamilyName" || key == "preferredSubfamilyNames" {
+        names.typographic_subfamily = val;
+    } else if key == "styleMapFamilyName" || key == "styleMapFamilyNames" {
+        names.style_map_family_name = val;
+    }
+}
+
 lazy_static! {
     static ref UNSIGNED_CP: Vec<(&'static str, &'static str, &'static str)> =
         vec![
@@ -622,7 +1056,6 @@ lazy_static! {
         ("preferredFamilyName", "name", "preferredFamilyName"),
         ("openTypeNamePreferredFamilyName", "name", "preferredFamilyName"),
         ("preferredSubfamilyName", "name", "preferredSubfamilyName"),
-        ("openTypeHheaDescender", "hhea", "descent"),
         ("compatibleFullName", "name", "compatibleFullName"),
         ("openTypeNameCompatibleFullName", "name", "compatibleFullName"),
         ("vendorID", "OS2", "achVendID"),
@@ -636,7 +1069,10 @@ lazy_static! {
     // XXX fsType
 }
 
-fn load_custom_parameters(ot_values: &mut Vec<OTValue>, params: HashMap<String, &Plist>) {
+pub(crate) fn load_custom_parameters(
+    ot_values: &mut Vec<OTValue>,
+    params: HashMap<String, &Plist>,
+) {
     for (key, table, field) in UNSIGNED_CP.iter() {
         if let Some(v) = params.get(&key.to_string()) {
             ot_values.push(OTValue {
@@ -655,6 +1091,15 @@ fn load_custom_parameters(ot_values: &mut Vec<OTValue>, params: HashMap<String,
             });
         }
     }
+    for (key, table, field) in STRING_CP.iter() {
+        if let Some(v) = params.get(*key).and_then(|v| v.as_str()) {
+            ot_values.push(OTValue {
+                table: table.to_string(),
+                field: field.to_string(),
+                value: OTScalar::StringType(v.to_string()),
+            });
+        }
+    }
     for (key, table, field) in BOOL_CP.iter() {
         if let Some(v) = params.get(&key.to_string()) {
             ot_values.push(OTValue {
@@ -669,6 +1114,7 @@ fn load_custom_parameters(ot_values: &mut Vec<OTValue>, params: HashMap<String,
 fn load_instance(font: &mut Font, plist: &Plist) {
     let name = plist
         .get("name")
+        .and_then(|f| f.as_str())
         .map(|f| f.to_string())
         .unwrap_or_else(|| "Unnamed Instance".to_string());
     let location = if plist.get("axesValues").is_some() {
@@ -680,54 +1126,88 @@ fn load_instance(font: &mut Font, plist: &Plist) {
         );
         return;
     };
+    add_instance(font, plist, name, location);
+}
+
+/// Adds an instance at the given designspace location, recording its
+/// userspace location in the axis mappings.
+pub(crate) fn add_instance(font: &mut Font, plist: &Plist, name: String, location: Location) {
     let cp = get_custom_parameters(plist);
-    let mut userspace_location: HashMap<String, f32> = HashMap::new();
-    if let Some(axis_locs) = cp.get("Axis Location").and_then(|f| f.as_array()) {
-        for loc in axis_locs {
-            if let Some(axis_name) = loc.get("Axis").map(|f| f.to_string()) {
-                // XXX map to tag here?
-                let loc = loc.get("Location").and_then(|x| x.as_f32()).unwrap_or(0.0);
-                userspace_location.insert(axis_name, loc);
-            }
-        }
-    }
+    let mut userspace_location = axis_locations(font, &cp);
 
     // Weight and width are implicit, add them
     if !userspace_location.contains_key("wght") {
-        let weightclass = plist
-            .get("weightClass")
-            .map(|f| f.to_string())
-            .unwrap_or_else(|| "Regular".to_string());
-        userspace_location.insert("wght".to_string(), weightclass_to_css(&weightclass));
+        let weightclass = match plist.get("weightClass") {
+            Some(Plist::Integer(w)) => *w as f32,
+            Some(w) => weightclass_to_css(w.as_str().unwrap_or("Regular")),
+            None => weightclass_to_css("Regular"),
+        };
+        userspace_location.insert("wght".to_string(), weightclass);
     }
     if !userspace_location.contains_key("wdth") {
         let weightclass = plist
             .get("widthClass")
+            .and_then(|f| f.as_str())
             .map(|f| f.to_string())
             .unwrap_or_else(|| "Regular".to_string());
         userspace_location.insert("wdth".to_string(), widthclass_to_css(&weightclass));
     }
+    add_axis_mappings(font, &userspace_location, &location);
+    font.instances.push(Instance {
+        name: (&name).into(),
+        location,
+        style_name: (&name).into(),
+        // Glyphs always generates kerning and font info for instances
+        kerning: true,
+        info: true,
+        ..Default::default()
+    });
+}
+
+/// Reads an "Axis Location" custom parameter, returning userspace values
+/// keyed by axis tag.
+pub(crate) fn axis_locations(font: &Font, cp: &HashMap<String, &Plist>) -> HashMap<String, f32> {
+    let mut userspace_location: HashMap<String, f32> = HashMap::new();
+    if let Some(axis_locs) = cp.get("Axis Location").and_then(|f| f.as_array()) {
+        for loc in axis_locs {
+            if let Some(axis_name) = loc.get("Axis").and_then(|f| f.as_str()) {
+                // The parameter refers to axes by name
+                let tag = font
+                    .axes
+                    .iter()
+                    .find(|ax| ax.name.get_default().as_deref() == Some(axis_name))
+                    .map_or_else(|| axis_name.to_string(), |ax| ax.tag.clone());
+                let loc = loc.get("Location").and_then(|x| x.as_f32()).unwrap_or(0.0);
+                userspace_location.insert(tag, loc);
+            }
+        }
+    }
+    userspace_location
+}
+
+/// Adds (userspace, designspace) pairs to the axis maps.
+pub(crate) fn add_axis_mappings(
+    font: &mut Font,
+    userspace_location: &HashMap<String, f32>,
+    location: &Location,
+) {
     for (axis_name, loc) in userspace_location.iter() {
         if let Some(axis) = font.axes.iter_mut().find(|ax| ax.tag == *axis_name) {
             if let Some(designspace_value) = location.0.get(&axis.tag) {
-                if axis.map.is_none() {
-                    axis.map = Some(vec![]);
+                let map = axis.map.get_or_insert_with(Vec::new);
+                if !map.contains(&(*loc, *designspace_value)) {
+                    map.push((*loc, *designspace_value));
                 }
-                axis.map.as_mut().unwrap().push((*loc, *designspace_value));
             }
         }
     }
-    font.instances.push(Instance {
-        name: (&name).into(),
-        location,
-        style_name: (&name).into(),
-    });
 }
 
-fn fixup_axis_mappings(font: &mut Font) {
+pub(crate) fn fixup_axis_mappings(font: &mut Font) {
     for axis in font.axes.iter_mut() {
-        if axis.map.is_none() {
-            continue;
+        match axis.map.as_mut() {
+            Some(map) => map.sort_by(|a, b| ot_cmp(a.0, b.0)),
+            None => continue,
         }
         if let Some((min, default, max)) = axis.bounds() {
             axis.min = Some(axis.designspace_to_userspace(min));
@@ -771,6 +1251,895 @@ fn widthclass_to_css(s: &str) -> f32 {
         _ => 5.0,
     }
 }
+/// Saves a font as a Glyphs 3 file.
+pub fn save(font: &Font, path: PathBuf) -> Result<(), BabelfontError> {
+    let mut s = font_to_plist(font).to_string_with_inline_keys(&["pos", "scale", "slant"]);
+    s.push('\n');
+    fs::write(&path, s).map_err(|source| BabelfontError::IO {
+        path: path.clone(),
+        source,
+    })
+}
+
+const APP_VERSION: &str = "3151";
+
+/// The metrics Glyphs defines for every font, in file order
+const STANDARD_METRICS: [&str; 6] = [
+    "ascender",
+    "capHeight",
+    "xHeight",
+    "baseline",
+    "descender",
+    "italic angle",
+];
+
+fn glyphs_metric_name(n: &str) -> &str {
+    match n {
+        "xHeight" => "x-height",
+        "capHeight" => "cap height",
+        _ => n,
+    }
+}
+
+/// Whole numbers are written as integers; anything else is written with
+/// the shortest representation of its f32 value.
+fn number(v: f32) -> Plist {
+    if v.fract() == 0.0 {
+        Plist::Integer(v as i64)
+    } else {
+        Plist::Float(short_f64(v))
+    }
+}
+
+fn short_f64(v: f32) -> f64 {
+    v.to_string().parse().unwrap_or(v as f64)
+}
+
+fn custom_parameter(name: &str, value: Plist) -> Plist {
+    let mut dict = IndexMap::new();
+    dict.insert("name".to_string(), Plist::String(name.to_string()));
+    dict.insert("value".to_string(), value);
+    dict.into()
+}
+
+fn insert_nonempty(dict: &mut IndexMap<String, Plist>, key: &str, values: Vec<Plist>) {
+    if !values.is_empty() {
+        dict.insert(key.to_string(), values.into());
+    }
+}
+
+/// Writes a lib back as user data, restoring the Glyphs keys kept in it.
+/// Keys are then sorted, as Glyphs sorts them.
+fn save_lib(dict: &mut IndexMap<String, Plist>, lib: &plist::Dictionary) {
+    let mut user_data = IndexMap::new();
+    for (key, value) in lib.iter().filter(|(k, _)| k.as_str() != BRACKET_LAYER_IDS) {
+        let value = match value_to_plist(value) {
+            Some(value) => value,
+            None => continue,
+        };
+        match key.strip_prefix(LIB_PREFIX) {
+            Some("appVersion") => dict.entry(".appVersion".to_string()).or_insert(value),
+            Some(key) => dict.entry(key.to_string()).or_insert(value),
+            None => user_data.entry(key.clone()).or_insert(value),
+        };
+    }
+    if !user_data.is_empty() {
+        dict.insert("userData".to_string(), user_data.into());
+    }
+    dict.sort_keys();
+}
+
+fn value_to_plist(v: &plist::Value) -> Option<Plist> {
+    Some(match v {
+        plist::Value::Dictionary(d) => Plist::Dictionary(
+            d.iter()
+                .filter_map(|(k, v)| Some((k.clone(), value_to_plist(v)?)))
+                .collect(),
+        ),
+        plist::Value::Array(a) => Plist::Array(a.iter().filter_map(value_to_plist).collect()),
+        plist::Value::String(s) => Plist::String(s.clone()),
+        plist::Value::Data(d) => Plist::Binary(d.clone()),
+        plist::Value::Boolean(b) => Plist::Integer(*b as i64),
+        plist::Value::Integer(i) => Plist::Integer(i.as_signed()?),
+        plist::Value::Real(f) => Plist::Float(*f),
+        _ => return None,
+    })
+}
+
+fn font_to_plist(font: &Font) -> Plist {
+    let mut dict = IndexMap::new();
+    dict.insert(".formatVersion".to_string(), Plist::Integer(3));
+    insert_nonempty(&mut dict, "axes", font.axes.iter().map(save_axis).collect());
+
+    let mut custom_parameters = vec![];
+    if let Some(ix) = font.default_master_index().filter(|&ix| ix != 0) {
+        custom_parameters.push(custom_parameter(
+            "Variable Font Origin",
+            font.masters[ix].id.clone().into(),
+        ));
+    }
+    custom_parameters.extend(save_custom_parameters(&font.custom_ot_values));
+    insert_nonempty(&mut dict, "customParameters", custom_parameters);
+
+    dict.insert(
+        "date".to_string(),
+        font.date
+            .naive_utc()
+            .format("%Y-%m-%d %H:%M:%S +0000")
+            .to_string()
+            .into(),
+    );
+    if let Some(family_name) = font.names.family_name.get_default() {
+        dict.insert("familyName".to_string(), family_name.into());
+    }
+    if let Some(features) = &font.features {
+        let mut prefix = IndexMap::new();
+        prefix.insert("code".to_string(), features.clone().into());
+        prefix.insert("name".to_string(), "Prefix".to_string().into());
+        dict.insert(
+            "featurePrefixes".to_string(),
+            vec![Plist::from(prefix)].into(),
+        );
+    }
+
+    let metric_names = metric_names(font);
+    insert_nonempty(
+        &mut dict,
+        "fontMaster",
+        font.masters
+            .iter()
+            .map(|m| save_master(font, m, &metric_names))
+            .collect(),
+    );
+    insert_nonempty(&mut dict, "glyphs", save_glyphs(font));
+    insert_nonempty(
+        &mut dict,
+        "instances",
+        font.instances
+            .iter()
+            .map(|i| save_instance(font, i))
+            .collect(),
+    );
+    let kerning = save_kerning(font);
+    if !kerning.is_empty() {
+        dict.insert("kerningLTR".to_string(), kerning.into());
+    }
+    insert_nonempty(
+        &mut dict,
+        "metrics",
+        metric_names
+            .iter()
+            .map(|name| {
+                let mut metric = IndexMap::new();
+                if STANDARD_METRICS.contains(&name.as_str()) {
+                    metric.insert(
+                        "type".to_string(),
+                        glyphs_metric_name(name).to_string().into(),
+                    );
+                } else {
+                    metric.insert("name".to_string(), name.clone().into());
+                }
+                Plist::from(metric)
+            })
+            .collect(),
+    );
+    if let Some(note) = &font.note {
+        dict.insert("note".to_string(), note.clone().into());
+    }
+    insert_nonempty(&mut dict, "properties", save_properties(font));
+    dict.insert("unitsPerEm".to_string(), Plist::Integer(font.upm as i64));
+    dict.insert(
+        "versionMajor".to_string(),
+        Plist::Integer(font.version.0 as i64),
+    );
+    dict.insert(
+        "versionMinor".to_string(),
+        Plist::Integer(font.version.1 as i64),
+    );
+    save_lib(&mut dict, &font.lib);
+    dict.entry(".appVersion".to_string())
+        .or_insert_with(|| APP_VERSION.to_string().into());
+    dict.sort_keys();
+    dict.into()
+}
+
+fn save_axis(axis: &Axis) -> Plist {
+    let mut dict = IndexMap::new();
+    if axis.hidden {
+        dict.insert("hidden".to_string(), Plist::Integer(1));
+    }
+    dict.insert("name".to_string(), axis_name(axis).into());
+    dict.insert("tag".to_string(), axis.tag.clone().into());
+    dict.into()
+}
+
+fn axis_name(axis: &Axis) -> String {
+    axis.name.get_default().unwrap_or_else(|| axis.tag.clone())
+}
+
+/// The designspace coordinates of a location, in axis order
+fn axes_values(font: &Font, location: &Location) -> Plist {
+    font.axes
+        .iter()
+        .map(|axis| {
+            let default = axis.userspace_to_designspace(axis.default.unwrap_or(0.0));
+            number(*location.0.get(&axis.tag).unwrap_or(&default))
+        })
+        .collect::<Vec<Plist>>()
+        .into()
+}
+
+/// An "Axis Location" custom parameter giving the userspace coordinates of
+/// a location
+fn axis_location(font: &Font, location: &Location) -> Plist {
+    let locations: Vec<Plist> = font
+        .axes
+        .iter()
+        .map(|axis| {
+            let default = axis.userspace_to_designspace(axis.default.unwrap_or(0.0));
+            let value = *location.0.get(&axis.tag).unwrap_or(&default);
+            let mut dict = IndexMap::new();
+            dict.insert("Axis".to_string(), axis_name(axis).into());
+            dict.insert(
+                "Location".to_string(),
+                number(axis.designspace_to_userspace(value)),
+            );
+            Plist::from(dict)
+        })
+        .collect();
+    custom_parameter("Axis Location", locations.into())
+}
+
+/// Converts OpenType values back into the custom parameters they were
+/// read from, preferring the names Glyphs uses.
+fn save_custom_parameters(values: &[OTValue]) -> Vec<Plist> {
+    values
+        .iter()
+        .filter_map(|v| {
+            let (keys, value): (Vec<&(&str, &str, &str)>, Plist) = match &v.value {
+                OTScalar::Unsigned(u) => (
+                    UNSIGNED_CP.iter().chain(SIGNED_CP.iter()).collect(),
+                    Plist::Integer(*u as i64),
+                ),
+                Signed(i) => (
+                    SIGNED_CP.iter().chain(UNSIGNED_CP.iter()).collect(),
+                    Plist::Integer(*i as i64),
+                ),
+                OTScalar::Bool(b) => (BOOL_CP.iter().collect(), Plist::Integer(*b as i64)),
+                OTScalar::StringType(s) => (STRING_CP.iter().collect(), s.clone().into()),
+                _ => return None,
+            };
+            let candidates: Vec<&str> = keys
+                .into_iter()
+                .filter(|(_, table, field)| *table == v.table && *field == v.field)
+                .map(|(key, _, _)| *key)
+                .collect();
+            let key = candidates
+                .iter()
+                .find(|k| !k.starts_with("openType"))
+                .or_else(|| candidates.first())?;
+            Some(custom_parameter(key, value))
+        })
+        .collect()
+}
+
+/// The standard metrics, followed by any others used by the masters
+fn metric_names(font: &Font) -> Vec<String> {
+    let mut extras: Vec<String> = font
+        .masters
+        .iter()
+        .flat_map(|m| m.metrics.keys())
+        .filter(|k| !STANDARD_METRICS.contains(&k.as_str()))
+        .cloned()
+        .collect();
+    extras.sort();
+    extras.dedup();
+    STANDARD_METRICS
+        .iter()
+        .map(|x| x.to_string())
+        .chain(extras)
+        .collect()
+}
+
+fn save_master(font: &Font, master: &Master, metric_names: &[String]) -> Plist {
+    let mut dict = IndexMap::new();
+    if !font.axes.is_empty() {
+        dict.insert(
+            "axesValues".to_string(),
+            axes_values(font, &master.location),
+        );
+    }
+    let mut custom_parameters = vec![];
+    if font.axes.iter().any(|a| a.map.is_some()) {
+        custom_parameters.push(axis_location(font, &master.location));
+    }
+    custom_parameters.extend(save_custom_parameters(&master.custom_ot_values));
+    insert_nonempty(&mut dict, "customParameters", custom_parameters);
+    insert_nonempty(
+        &mut dict,
+        "guides",
+        master.guides.iter().map(save_guide).collect(),
+    );
+    dict.insert("id".to_string(), master.id.clone().into());
+    insert_nonempty(
+        &mut dict,
+        "metricValues",
+        metric_names
+            .iter()
+            .map(|name| {
+                let mut value = IndexMap::new();
+                if let Some(&over) = master.overshoots.get(name).filter(|&&o| o != 0) {
+                    value.insert("over".to_string(), Plist::Integer(over as i64));
+                }
+                if let Some(&pos) = master.metrics.get(name).filter(|&&p| p != 0) {
+                    value.insert("pos".to_string(), Plist::Integer(pos as i64));
+                }
+                Plist::from(value)
+            })
+            .collect(),
+    );
+    dict.insert(
+        "name".to_string(),
+        master
+            .name
+            .get_default()
+            .unwrap_or_else(|| master.id.clone())
+            .into(),
+    );
+    save_lib(&mut dict, &master.lib);
+    dict.into()
+}
+
+fn save_guide(guide: &Guide) -> Plist {
+    let mut dict = IndexMap::new();
+    if guide.pos.angle != 0.0 {
+        dict.insert("angle".to_string(), number(guide.pos.angle));
+    }
+    if guide.pos.x != 0 || guide.pos.y != 0 {
+        dict.insert(
+            "pos".to_string(),
+            vec![
+                Plist::Integer(guide.pos.x as i64),
+                Plist::Integer(guide.pos.y as i64),
+            ]
+            .into(),
+        );
+    }
+    dict.into()
+}
+
+/// Splits a kerning group name into its side (1 for the first glyph of a
+/// pair, 2 for the second) and its bare name.
+fn kern_group_side(group: &str) -> Option<(u8, &str)> {
+    if let Some(name) = group
+        .strip_prefix("MMK_L_")
+        .or_else(|| group.strip_prefix("public.kern1."))
+    {
+        Some((1, name))
+    } else {
+        group
+            .strip_prefix("MMK_R_")
+            .or_else(|| group.strip_prefix("public.kern2."))
+            .map(|name| (2, name))
+    }
+}
+
+fn glyphs_kern_name(name: &str) -> String {
+    match name.strip_prefix('@').and_then(kern_group_side) {
+        Some((1, group)) => format!("@MMK_L_{}", group),
+        Some((_, group)) => format!("@MMK_R_{}", group),
+        None => name.to_string(),
+    }
+}
+
+fn save_kerning(font: &Font) -> IndexMap<String, Plist> {
+    let mut kerning = IndexMap::new();
+    for master in font.masters.iter().filter(|m| !m.kerning.is_empty()) {
+        let mut pairs: BTreeMap<String, BTreeMap<String, i16>> = BTreeMap::new();
+        for ((left, right), value) in master.kerning.iter() {
+            pairs
+                .entry(glyphs_kern_name(left))
+                .or_default()
+                .insert(glyphs_kern_name(right), *value);
+        }
+        let pairs: IndexMap<String, Plist> = pairs
+            .into_iter()
+            .map(|(left, rights)| {
+                let rights: IndexMap<String, Plist> = rights
+                    .into_iter()
+                    .map(|(right, value)| (right, Plist::Integer(value as i64)))
+                    .collect();
+                (left, rights.into())
+            })
+            .collect();
+        kerning.insert(master.id.clone(), pairs.into());
+    }
+    kerning
+}
+
+fn save_glyphs(font: &Font) -> Vec<Plist> {
+    // Glyphs stores kerning classes on the glyphs themselves
+    let mut kern_left: HashMap<&str, &str> = HashMap::new();
+    let mut kern_right: HashMap<&str, &str> = HashMap::new();
+    for (group, members) in font.kern_groups.iter() {
+        let (side, name) = match kern_group_side(group) {
+            Some(s) => s,
+            None => continue,
+        };
+        for member in members {
+            if side == 1 {
+                kern_right.insert(member, name);
+            } else {
+                kern_left.insert(member, name);
+            }
+        }
+    }
+    let ascenders: HashMap<&str, i32> = font
+        .masters
+        .iter()
+        .map(|m| (m.id.as_str(), *m.metrics.get("ascender").unwrap_or(&0)))
+        .collect();
+    let default_master_id = font
+        .default_master()
+        .or_else(|| font.masters.first())
+        .map(|m| m.id.as_str())
+        .unwrap_or_default();
+
+    // Alternate glyphs made from bracket layers go back into their glyphs
+    let mut brackets: HashMap<&str, Vec<(&Glyph, &[Condition])>> = HashMap::new();
+    let mut alternates: HashSet<&str> = HashSet::new();
+    for rule in font.rules.iter().filter(|r| r.condition_sets.len() == 1) {
+        for (glyph, alternate) in rule.substitutions.iter() {
+            if !alternate.starts_with(&format!("{}.BRACKET.", glyph)) {
+                continue;
+            }
+            if let (Some(_), Some(alternate)) = (font.glyphs.get(glyph), font.glyphs.get(alternate))
+            {
+                brackets
+                    .entry(glyph)
+                    .or_default()
+                    .push((alternate, &rule.condition_sets[0]));
+                alternates.insert(&alternate.name);
+            }
+        }
+    }
+
+    font.glyphs
+        .iter()
+        .filter(|glyph| !alternates.contains(glyph.name.as_str()))
+        .map(|glyph| {
+            let mut dict = IndexMap::new();
+            dict.insert("glyphname".to_string(), glyph.name.clone().into());
+            // Glyphs derives categories and production names from the glyph
+            // name, so only overrides need to be stored
+            let (category, subcategory) =
+                if glyph.category == GlyphCategory::from_glyph(&glyph.name, &glyph.codepoints) {
+                    (None, None)
+                } else {
+                    match glyph.category {
+                        GlyphCategory::Mark => (Some("Mark"), None),
+                        GlyphCategory::Ligature => (None, Some("Ligature")),
+                        _ => (Some("Letter"), None),
+                    }
+                };
+            if let Some(category) = category {
+                dict.insert("category".to_string(), category.to_string().into());
+            }
+            if !glyph.exported {
+                dict.insert("export".to_string(), Plist::Integer(0));
+            }
+            let name = glyph.name.as_str();
+            if let Some(group) = kern_left.get(name).filter(|&&g| g != name) {
+                dict.insert("kernLeft".to_string(), group.to_string().into());
+            }
+            if let Some(group) = kern_right.get(name).filter(|&&g| g != name) {
+                dict.insert("kernRight".to_string(), group.to_string().into());
+            }
+            let mut seen_ids = HashSet::new();
+            let mut layers: Vec<Plist> = glyph
+                .layers
+                .iter()
+                .filter(|l| !l.is_background)
+                .filter(|l| l.id.as_ref().is_none_or(|id| seen_ids.insert(id)))
+                .map(|l| {
+                    save_layer(
+                        font,
+                        l,
+                        &ascenders,
+                        default_master_id,
+                        &glyph.smart_axes,
+                        None,
+                    )
+                })
+                .collect();
+            for (alternate, conditions) in brackets.get(name).into_iter().flatten() {
+                layers.extend(
+                    alternate
+                        .layers
+                        .iter()
+                        .filter(|l| l.id.as_deref().is_some_and(|id| ascenders.contains_key(id)))
+                        .map(|l| {
+                            let master_id = l.id.as_deref().unwrap_or_default();
+                            let layer_id = alternate
+                                .lib
+                                .get(BRACKET_LAYER_IDS)
+                                .and_then(|ids| ids.as_dictionary())
+                                .and_then(|ids| ids.get(master_id))
+                                .and_then(|id| id.as_string())
+                                .map(|id| id.to_string())
+                                .unwrap_or_else(|| bracket_layer_id(&alternate.name, master_id));
+                            let bracket = (layer_id, *conditions);
+                            save_layer(
+                                font,
+                                l,
+                                &ascenders,
+                                default_master_id,
+                                &alternate.smart_axes,
+                                Some(bracket),
+                            )
+                        }),
+                );
+            }
+            insert_nonempty(&mut dict, "layers", layers);
+            insert_nonempty(
+                &mut dict,
+                "partsSettings",
+                glyph.smart_axes.iter().map(save_smart_axis).collect(),
+            );
+            if let Some(production_name) = glyph
+                .production_name
+                .as_ref()
+                .filter(|&p| Some(p) != Glyph::default_production_name(&glyph.name).as_ref())
+            {
+                dict.insert("production".to_string(), production_name.clone().into());
+            }
+            if let Some(subcategory) = subcategory {
+                dict.insert("subCategory".to_string(), subcategory.to_string().into());
+            }
+            if glyph.codepoints.len() == 1 {
+                dict.insert(
+                    "unicode".to_string(),
+                    Plist::Integer(glyph.codepoints[0] as i64),
+                );
+            } else if !glyph.codepoints.is_empty() {
+                dict.insert(
+                    "unicode".to_string(),
+                    glyph
+                        .codepoints
+                        .iter()
+                        .map(|&c| Plist::Integer(c as i64))
+                        .collect::<Vec<Plist>>()
+                        .into(),
+                );
+            }
+            save_lib(&mut dict, &glyph.lib);
+            // Glyphs puts the glyph name first, after only the export flag
+            dict.move_index(dict.get_index_of("glyphname").unwrap_or(0), 0);
+            if let Some(ix) = dict.get_index_of("export") {
+                dict.move_index(ix, 0);
+            }
+            dict.into()
+        })
+        .collect()
+}
+
+fn save_layer(
+    font: &Font,
+    layer: &Layer,
+    ascenders: &HashMap<&str, i32>,
+    default_master_id: &str,
+    smart_axes: &[SmartAxis],
+    bracket: Option<(String, &[Condition])>,
+) -> Plist {
+    let mut dict = IndexMap::new();
+    insert_nonempty(
+        &mut dict,
+        "anchors",
+        layer.anchors.iter().map(save_anchor).collect(),
+    );
+    // Bracket layers are master layers of an alternate glyph, and get IDs
+    // of their own
+    let (layer_id, axis_rules) = match bracket {
+        Some((id, conditions)) => (id, Some(conditions)),
+        None => (
+            layer
+                .id
+                .clone()
+                .unwrap_or_else(|| Uuid::new_v4().to_string().to_uppercase()),
+            None,
+        ),
+    };
+    // Layers which aren't master layers need to be attached to a master
+    let master_id = if ascenders.contains_key(layer_id.as_str()) {
+        layer_id.as_str()
+    } else {
+        let master_id = match axis_rules {
+            Some(_) => layer.id.as_deref(),
+            None => layer.associated_master_id.as_deref(),
+        }
+        .unwrap_or(default_master_id);
+        dict.insert(
+            "associatedMasterId".to_string(),
+            master_id.to_string().into(),
+        );
+        master_id
+    };
+    let mut attr = IndexMap::new();
+    if let Some(conditions) = axis_rules {
+        attr.insert("axisRules".to_string(), save_axis_rules(font, conditions));
+    }
+    if let Some(location) = &layer.location {
+        attr.insert("coordinates".to_string(), axes_values(font, location));
+    }
+    if !attr.is_empty() {
+        dict.insert("attr".to_string(), attr.into());
+    }
+    insert_nonempty(
+        &mut dict,
+        "guides",
+        layer.guides.iter().map(save_guide).collect(),
+    );
+    dict.insert("layerId".to_string(), layer_id.clone().into());
+    if let Some(name) = &layer.name {
+        dict.insert("name".to_string(), name.clone().into());
+    }
+    if !layer.smart_location.0.is_empty() {
+        dict.insert(
+            "partSelection".to_string(),
+            save_pole_location(&layer.smart_location, smart_axes),
+        );
+    }
+    insert_nonempty(
+        &mut dict,
+        "shapes",
+        layer.shapes.iter().map(save_shape).collect(),
+    );
+    if let Some(vertical_origin) = layer.vertical_origin {
+        let ascender = ascenders.get(master_id).unwrap_or(&0);
+        dict.insert(
+            "vertOrigin".to_string(),
+            Plist::Integer((ascender - vertical_origin) as i64),
+        );
+    }
+    if let Some(height) = layer.height {
+        dict.insert("vertWidth".to_string(), Plist::Integer(height as i64));
+    }
+    dict.insert("width".to_string(), Plist::Integer(layer.width as i64));
+    dict.into()
+}
+
+/// A layer ID for the bracket layer made from an alternate glyph's master
+/// layer, which is the same each time the font is saved.
+fn bracket_layer_id(alternate: &str, master_id: &str) -> String {
+    let hash = |salt: u8| {
+        let mut hasher = DefaultHasher::new();
+        (alternate, master_id, salt).hash(&mut hasher);
+        hasher.finish() as u128
+    };
+    Uuid::from_u128(hash(0) << 64 | hash(1))
+        .to_string()
+        .to_uppercase()
+}
+
+/// The ranges of a bracket layer, as one dictionary per axis
+fn save_axis_rules(font: &Font, conditions: &[Condition]) -> Plist {
+    font.axes
+        .iter()
+        .map(|axis| {
+            let mut dict = IndexMap::new();
+            if let Some(condition) = conditions.iter().find(|c| c.axis == axis.tag) {
+                if let Some(max) = condition.max {
+                    dict.insert("max".to_string(), number(max));
+                }
+                if let Some(min) = condition.min {
+                    dict.insert("min".to_string(), number(min));
+                }
+            }
+            dict.into()
+        })
+        .collect::<Vec<Plist>>()
+        .into()
+}
+
+fn save_smart_axis(axis: &SmartAxis) -> Plist {
+    let mut dict = IndexMap::new();
+    dict.insert("bottomValue".to_string(), number(axis.bottom));
+    dict.insert("name".to_string(), axis.name.clone().into());
+    dict.insert("topValue".to_string(), number(axis.top));
+    dict.into()
+}
+
+fn save_pole_location(location: &Location, smart_axes: &[SmartAxis]) -> Plist {
+    smart_axes
+        .iter()
+        .filter_map(|axis| {
+            let value = location.0.get(&axis.name)?;
+            let pole = if *value == axis.top && axis.top != axis.bottom {
+                2
+            } else {
+                1
+            };
+            Some((axis.name.clone(), Plist::Integer(pole)))
+        })
+        .collect::<IndexMap<String, Plist>>()
+        .into()
+}
+
+fn save_anchor(anchor: &Anchor) -> Plist {
+    let mut dict = IndexMap::new();
+    dict.insert("name".to_string(), anchor.name.clone().into());
+    if anchor.x != 0 || anchor.y != 0 {
+        dict.insert(
+            "pos".to_string(),
+            vec![
+                Plist::Integer(anchor.x as i64),
+                Plist::Integer(anchor.y as i64),
+            ]
+            .into(),
+        );
+    }
+    dict.into()
+}
+
+fn save_shape(shape: &Shape) -> Plist {
+    let mut dict = IndexMap::new();
+    match shape {
+        PathShape(path) => {
+            if path.closed {
+                dict.insert("closed".to_string(), Plist::Integer(1));
+            }
+            let nodes: Vec<Plist> = path
+                .nodes
+                .iter()
+                .map(|n| {
+                    let typ = match n.nodetype {
+                        NodeType::Move | NodeType::Line => "l",
+                        NodeType::OffCurve => "o",
+                        NodeType::Curve => "c",
+                        NodeType::QCurve => "q",
+                    };
+                    let smooth = if n.smooth && n.nodetype != NodeType::OffCurve {
+                        "s"
+                    } else {
+                        ""
+                    };
+                    Plist::Node((short_f64(n.x), short_f64(n.y), format!("{}{}", typ, smooth)))
+                })
+                .collect();
+            dict.insert("nodes".to_string(), nodes.into());
+        }
+        ComponentShape(component) => {
+            let [a, b, c, d, e, f] = component.transform.as_coeffs();
+            let scale_x = a.hypot(b);
+            let (scale_y, slant) = if scale_x != 0.0 {
+                (
+                    (a * d - b * c) / scale_x,
+                    ((a * c + b * d) / (scale_x * scale_x)).atan(),
+                )
+            } else {
+                (d, 0.0)
+            };
+            if let Some(alignment) = component.alignment {
+                dict.insert("alignment".to_string(), Plist::Integer(alignment as i64));
+            }
+            if let Some(anchor) = &component.anchor {
+                dict.insert("anchor".to_string(), anchor.clone().into());
+            }
+            let angle = b.atan2(a).to_degrees();
+            if angle.abs() > 1e-6 {
+                dict.insert("angle".to_string(), number(angle as f32));
+            }
+            if !component.smart_location.0.is_empty() {
+                let mut piece: Vec<(&String, &f32)> = component.smart_location.0.iter().collect();
+                piece.sort_by(|a, b| a.0.cmp(b.0));
+                dict.insert(
+                    "piece".to_string(),
+                    piece
+                        .into_iter()
+                        .map(|(name, &value)| (name.clone(), number(value)))
+                        .collect::<IndexMap<String, Plist>>()
+                        .into(),
+                );
+            }
+            if e != 0.0 || f != 0.0 {
+                dict.insert(
+                    "pos".to_string(),
+                    vec![number(e as f32), number(f as f32)].into(),
+                );
+            }
+            dict.insert("ref".to_string(), component.reference.clone().into());
+            if (scale_x - 1.0).abs() > 1e-6 || (scale_y - 1.0).abs() > 1e-6 {
+                dict.insert(
+                    "scale".to_string(),
+                    vec![number(scale_x as f32), number(scale_y as f32)].into(),
+                );
+            }
+            if slant.abs() > 1e-6 {
+                dict.insert(
+                    "slant".to_string(),
+                    vec![number(slant.to_degrees() as f32), Plist::Integer(0)].into(),
+                );
+            }
+        }
+    }
+    dict.into()
+}
+
+fn save_instance(font: &Font, instance: &Instance) -> Plist {
+    let mut dict = IndexMap::new();
+    if !font.axes.is_empty() {
+        dict.insert(
+            "axesValues".to_string(),
+            axes_values(font, &instance.location),
+        );
+        // Without an axis location, the loader would infer one from the
+        // (absent) weight and width classes
+        dict.insert(
+            "customParameters".to_string(),
+            vec![axis_location(font, &instance.location)].into(),
+        );
+    }
+    dict.insert(
+        "name".to_string(),
+        instance
+            .name
+            .get_default()
+            .unwrap_or_else(|| "Regular".to_string())
+            .into(),
+    );
+    dict.into()
+}
+
+fn save_properties(font: &Font) -> Vec<Plist> {
+    let names = &font.names;
+    // Keys ending in "s" hold localized values
+    let properties = [
+        ("copyrights", &names.copyright),
+        ("designers", &names.designer),
+        ("designerURL", &names.designer_url),
+        ("manufacturers", &names.manufacturer),
+        ("manufacturerURL", &names.manufacturer_url),
+        ("licenses", &names.license),
+        ("licenseURL", &names.license_url),
+        ("trademarks", &names.trademark),
+        ("descriptions", &names.description),
+        ("sampleTexts", &names.sample_text),
+        ("compatibleFullNames", &names.compatible_full_name),
+        ("preferredFamilyNames", &names.typographic_family),
+        ("preferredSubfamilyNames", &names.typographic_subfamily),
+        ("styleMapFamilyNames", &names.style_map_family_name),
+        ("uniqueID", &names.unique_id),
+        ("versionString", &names.version),
+        ("WWSFamilyName", &names.w_w_s_family_name),
+        ("WWSSubfamilyName", &names.w_w_s_subfamily_name),
+    ];
+    properties
+        .iter()
+        .filter(|(_, value)| !value.0.is_empty())
+        .filter_map(|(key, value)| {
+            let mut dict = IndexMap::new();
+            dict.insert("key".to_string(), key.to_string().into());
+            if key.ends_with('s') {
+                let mut entries: Vec<(&Tag, &String)> = value.0.iter().collect();
+                entries.sort_by_key(|(tag, _)| (tag.as_str() != "dflt", **tag));
+                let values: Vec<Plist> = entries
+                    .into_iter()
+                    .map(|(tag, v)| {
+                        let mut entry = IndexMap::new();
+                        entry.insert("language".to_string(), tag.as_str().to_string().into());
+                        entry.insert("value".to_string(), v.clone().into());
+                        Plist::from(entry)
+                    })
+                    .collect();
+                dict.insert("values".to_string(), values.into());
+            } else {
+                dict.insert("value".to_string(), value.get_default()?.into());
+            }
+            Some(dict.into())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -779,4 +2148,197 @@ mod tests {
     fn do_something() {
         let _f = load("data/Nunito3.glyphs".into()).unwrap();
     }
+
+    #[test]
+    fn test_roundtrip() {
+        let font = load("../fonticulus/test/sources/SimpleTwoAxis.glyphs".into()).unwrap();
+        let directory = std::env::temp_dir().join("babelfont-glyphs3-roundtrip");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("SimpleTwoAxis.glyphs");
+        save(&font, path.clone()).unwrap();
+
+        let reloaded = load(path.clone()).unwrap();
+        assert_eq!(reloaded.axes.len(), font.axes.len());
+        assert_eq!(reloaded.instances.len(), font.instances.len());
+        assert_eq!(reloaded.kern_groups, font.kern_groups);
+        assert_eq!(
+            reloaded.names.family_name.get_default(),
+            font.names.family_name.get_default()
+        );
+        for (master, new_master) in font.masters.iter().zip(reloaded.masters.iter()) {
+            assert_eq!(master.id, new_master.id);
+            assert_eq!(master.location, new_master.location);
+            assert_eq!(master.metrics, new_master.metrics);
+            assert_eq!(master.kerning, new_master.kerning);
+        }
+        assert_eq!(reloaded.glyphs.len(), font.glyphs.len());
+        for (glyph, new_glyph) in font.glyphs.iter().zip(reloaded.glyphs.iter()) {
+            assert_eq!(glyph.name, new_glyph.name);
+            assert_eq!(glyph.codepoints, new_glyph.codepoints);
+            assert_eq!(glyph.layers.len(), new_glyph.layers.len());
+            for (layer, new_layer) in glyph.layers.iter().zip(new_glyph.layers.iter()) {
+                assert_eq!(layer.width, new_layer.width);
+                assert_eq!(layer.shapes.len(), new_layer.shapes.len());
+                assert_eq!(layer.anchors.len(), new_layer.anchors.len());
+            }
+        }
+
+        // Bracket layers become an alternate glyph and a rule, and back again
+        let alternate = font.glyphs.get("dollar.BRACKET.varAlt01").unwrap();
+        assert_eq!(alternate.layers.len(), font.masters.len());
+        assert_eq!(font.rules.len(), 1);
+        assert_eq!(
+            font.rules[0].condition_sets,
+            vec![vec![Condition {
+                axis: "wght".to_string(),
+                min: Some(500.0),
+                max: None
+            }]]
+        );
+        assert_eq!(
+            font.rules[0].substitutions,
+            vec![("dollar".to_string(), "dollar.BRACKET.varAlt01".to_string())]
+        );
+        let saved = std::fs::read_to_string(&path).unwrap();
+        // Dates are in UTC, whatever the local time zone
+        assert!(saved.contains("date = \"2021-01-15 14:37:13 +0000\";"));
+        // Nothing is lost: smooth nodes, overshoots, user data, keys which
+        // aren't loaded and bracket layer IDs all survive
+        let source =
+            std::fs::read_to_string("../fonticulus/test/sources/SimpleTwoAxis.glyphs").unwrap();
+        assert_eq!(saved, source);
+        assert_eq!(saved.matches("axisRules").count(), font.masters.len());
+        assert!(!saved.contains("BRACKET"));
+        assert_eq!(reloaded.rules[0].substitutions, font.rules[0].substitutions);
+
+        // Saving again gives an identical file
+        let path2 = directory.join("SimpleTwoAxis-2.glyphs");
+        save(&reloaded, path2.clone()).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            std::fs::read_to_string(path2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_kern_group_sides() {
+        // A glyph's right group applies when it's on the left of a pair, so
+        // kernRight is the first (MMK_L_) group and kernLeft the second.
+        let plist = Plist::parse(
+            r#"{
+glyphs = (
+{
+glyphname = V;
+kernLeft = V;
+kernRight = V_right;
+},
+{
+glyphname = A;
+leftKerningGroup = A_left;
+rightKerningGroup = A;
+}
+);
+}"#,
+        )
+        .unwrap();
+        let groups = load_kern_groups(&plist);
+        assert_eq!(groups.get("MMK_L_V_right"), Some(&vec!["V".to_string()]));
+        assert_eq!(groups.get("MMK_R_V"), Some(&vec!["V".to_string()]));
+        assert_eq!(groups.get("MMK_L_A"), Some(&vec!["A".to_string()]));
+        assert_eq!(groups.get("MMK_R_A_left"), Some(&vec!["A".to_string()]));
+        assert!(!groups.contains_key("MMK_L_V"));
+        assert!(!groups.contains_key("MMK_R_V_right"));
+    }
+
+    #[test]
+    fn test_glyph_data_defaults() {
+        let font = load("../fonticulus/test/sources/SimpleTwoAxis.glyphs".into()).unwrap();
+        let acutecomb = font.glyphs.get("acutecomb").unwrap();
+        assert_eq!(acutecomb.category, GlyphCategory::Mark);
+        // An AGL name is already a production name
+        assert_eq!(acutecomb.production_name, None);
+        let dollar = font.glyphs.get("dollar.bold").unwrap();
+        assert_eq!(dollar.category, GlyphCategory::Base);
+        assert_eq!(dollar.production_name, None);
+    }
+
+    #[test]
+    fn test_smart_components() {
+        let font = load("data/SmartComponents.glyphs".into()).unwrap();
+        let part = font.glyphs.get("_part.bar").unwrap();
+        assert_eq!(
+            part.smart_axes,
+            vec![SmartAxis {
+                name: "Width".to_string(),
+                bottom: 0.0,
+                top: 100.0
+            }]
+        );
+        let wide = &part.layers[1];
+        assert_eq!(wide.associated_master_id.as_deref(), Some("m01"));
+        assert_eq!(wide.smart_location.0.get("Width"), Some(&100.0));
+
+        let t = font.glyphs.get("T").unwrap().get_layer("m01").unwrap();
+        let components: Vec<&Component> = t.components().collect();
+        assert_eq!(components[0].alignment, Some(-1));
+        assert_eq!(components[0].smart_location.0.get("Width"), Some(&50.0));
+        assert_eq!(components[1].anchor.as_deref(), Some("top"));
+
+        let halfway = part
+            .smart_layer("m01", &components[0].smart_location)
+            .unwrap();
+        assert_eq!(halfway.width, 200);
+        assert_eq!(halfway.anchors[0].x, 100);
+        // Components without a value for an axis take the master layer's
+        let paths = t.decomposed_components(&font);
+        let xs = |path: &Path| path.nodes.iter().map(|n| n.x).collect::<Vec<f32>>();
+        assert_eq!(xs(&paths[0]), vec![10.0, 210.0, 210.0, 10.0]);
+        assert_eq!(xs(&paths[1]), vec![0.0, 100.0, 100.0, 0.0]);
+
+        // Components are rotated, scaled and slanted
+        let slanted = font
+            .glyphs
+            .get("Islanted")
+            .unwrap()
+            .get_layer("m01")
+            .unwrap();
+        let point = slanted.decomposed_components(&font)[0].nodes[2].clone();
+        assert!(point.x.abs() < 0.01);
+        assert!((point.y - 136.71).abs() < 0.01);
+
+        let directory = std::env::temp_dir().join("babelfont-glyphs3-smart");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("SmartComponents.glyphs");
+        save(&font, path.clone()).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("export = 0;\nglyphname = _part.bar;"));
+        assert!(written.contains("slant = (10,0);"));
+        assert!(!written.contains("metrics = (\n);"));
+        let reloaded = load(path).unwrap();
+        let new_part = reloaded.glyphs.get("_part.bar").unwrap();
+        assert_eq!(new_part.smart_axes, part.smart_axes);
+        assert_eq!(new_part.layers[1].smart_location, wide.smart_location);
+        assert_eq!(
+            new_part.layers[1].associated_master_id,
+            wide.associated_master_id
+        );
+        let new_slanted = reloaded.glyphs.get("Islanted").unwrap();
+        let new_transform = new_slanted.layers[0].components().next().unwrap().transform;
+        let old_transform = slanted.components().next().unwrap().transform;
+        for (new, old) in new_transform
+            .as_coeffs()
+            .iter()
+            .zip(old_transform.as_coeffs().iter())
+        {
+            assert!((new - old).abs() < 1e-4);
+        }
+        let new_t = reloaded.glyphs.get("T").unwrap();
+        let new_components: Vec<&Component> = new_t.layers[0].components().collect();
+        assert_eq!(new_components[0].alignment, Some(-1));
+        assert_eq!(
+            new_components[0].smart_location,
+            components[0].smart_location
+        );
+        assert_eq!(new_components[1].anchor.as_deref(), Some("top"));
+    }
 }
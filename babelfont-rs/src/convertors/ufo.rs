@@ -1,9 +1,12 @@
+use crate::common::OTValue;
 use crate::glyph::GlyphCategory;
+use crate::names::StyleMapStyle;
 use crate::{
-    BabelfontError, Component, Font, Glyph, Layer, Location, Master, Node, OTScalar, Path, Shape,
+    BabelfontError, Component, Font, Glyph, Layer, Location, Master, Node, NodeType, OTScalar,
+    Path, Shape,
 };
 use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -60,6 +63,17 @@ pub fn load(path: PathBuf) -> Result<Font, BabelfontError> {
 pub(crate) fn norad_glyph_to_babelfont_layer(glyph: &norad::Glyph, master_id: &str) -> Layer {
     let mut l = Layer::new(glyph.width as i32);
     l.id = Some(master_id.to_string());
+    if glyph.height != 0.0 {
+        l.height = Some(glyph.height as i32);
+    }
+    l.vertical_origin = glyph
+        .lib
+        .get("public.verticalOrigin")
+        .and_then(|x| {
+            x.as_real()
+                .or_else(|| x.as_signed_integer().map(|i| i as f64))
+        })
+        .map(|x| x as i32);
     l.guides = glyph.guidelines.iter().map(|x| x.into()).collect();
     l.anchors = glyph.anchors.iter().map(|x| x.into()).collect();
     for comp in &glyph.components {
@@ -78,6 +92,7 @@ pub(crate) fn load_component(c: &norad::Component) -> Component {
         transform: kurbo::Affine::new([
             t.x_scale, t.xy_scale, t.yx_scale, t.y_scale, t.x_offset, t.y_offset,
         ]),
+        ..Default::default()
     }
 }
 
@@ -119,16 +134,168 @@ pub(crate) fn load_master_info(master: &mut Master, info: &norad::FontInfo) {
     }
 }
 
+// UFO fontinfo fields which babelfont stores as custom OT values, along
+// with the kind of scalar used to hold them.
+macro_rules! ot_value_fields {
+    (@load Unsigned $v:ident) => { OTScalar::Unsigned(*$v) };
+    (@load Signed $v:ident) => { OTScalar::Signed(*$v) };
+    (@load Number $v:ident) => { OTScalar::Signed(*$v as i32) };
+    (@load Bool $v:ident) => { OTScalar::Bool(*$v) };
+    (@load String $v:ident) => { OTScalar::StringType($v.clone()) };
+    (@load BitField $v:ident) => { OTScalar::BitField($v.to_vec()) };
+    (@save Unsigned $v:expr) => { Some(i32::from($v) as u32) };
+    (@save Signed $v:expr) => { Some(i32::from($v)) };
+    (@save Number $v:expr) => { Some(f32::from($v) as f64) };
+    (@save Bool $v:expr) => { Some(bool::from($v)) };
+    (@save String $v:expr) => { Some(String::from($v)) };
+    (@save BitField $v:expr) => { $v.as_bitfield() };
+    ($($kind:ident $field:ident => $table:literal $otfield:literal,)*) => {
+        fn load_ot_values(font: &mut Font, info: &norad::FontInfo) {
+            $(
+                if let Some(v) = &info.$field {
+                    font.set_ot_value($table, $otfield, ot_value_fields!(@load $kind v));
+                }
+            )*
+            if let Some(v) = &info.open_type_os2_width_class {
+                font.set_ot_value("OS2", "usWidthClass", OTScalar::Unsigned(v.clone() as u32));
+            }
+        }
+
+        fn save_ot_values(info: &mut norad::FontInfo, values: &[OTValue]) {
+            for value in values {
+                match (value.table.as_str(), value.field.as_str()) {
+                    $(
+                        ($table, $otfield) => {
+                            info.$field = ot_value_fields!(@save $kind value.value.clone())
+                        }
+                    )*
+                    ("OS2", "usWidthClass") => {
+                        info.open_type_os2_width_class = os2_width_class(value.value.clone().into())
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+ot_value_fields! {
+    Unsigned open_type_head_lowest_rec_ppem => "head" "lowestRecPPEM",
+    BitField open_type_head_flags => "head" "flags",
+    Signed open_type_hhea_ascender => "hhea" "ascent",
+    Signed open_type_hhea_descender => "hhea" "descent",
+    Signed open_type_hhea_line_gap => "hhea" "lineGap",
+    Signed open_type_hhea_caret_slope_rise => "hhea" "caretSlopeRise",
+    Signed open_type_hhea_caret_slope_run => "hhea" "caretSlopeRun",
+    Signed open_type_hhea_caret_offset => "hhea" "caretOffset",
+    Signed open_type_vhea_vert_typo_ascender => "vhea" "vertTypoAscender",
+    Signed open_type_vhea_vert_typo_descender => "vhea" "vertTypoDescender",
+    Signed open_type_vhea_vert_typo_line_gap => "vhea" "vertTypoLineGap",
+    Signed open_type_vhea_caret_slope_rise => "vhea" "caretSlopeRise",
+    Signed open_type_vhea_caret_slope_run => "vhea" "caretSlopeRun",
+    Signed open_type_vhea_caret_offset => "vhea" "caretOffset",
+    BitField open_type_os2_type => "OS/2" "fsType",
+    BitField open_type_os2_selection => "OS2" "fsSelection",
+    BitField open_type_os2_code_page_ranges => "OS2" "codePageRanges",
+    BitField open_type_os2_unicode_ranges => "OS2" "unicodeRanges",
+    String open_type_os2_vendor_id => "OS2" "achVendID",
+    Unsigned open_type_os2_weight_class => "OS2" "usWeightClass",
+    Unsigned open_type_os2_win_ascent => "OS2" "usWinAscent",
+    Unsigned open_type_os2_win_descent => "OS2" "usWinDescent",
+    Signed open_type_os2_typo_ascender => "OS2" "sTypoAscender",
+    Signed open_type_os2_typo_descender => "OS2" "sTypoDescender",
+    Signed open_type_os2_typo_line_gap => "OS2" "sTypoLineGap",
+    Signed open_type_os2_strikeout_position => "OS2" "yStrikeoutPosition",
+    Signed open_type_os2_strikeout_size => "OS2" "yStrikeoutSize",
+    Signed open_type_os2_subscript_x_offset => "OS2" "ySubscriptXOffset",
+    Signed open_type_os2_subscript_x_size => "OS2" "ySubscriptXSize",
+    Signed open_type_os2_subscript_y_offset => "OS2" "ySubscriptYOffset",
+    Signed open_type_os2_subscript_y_size => "OS2" "ySubscriptYSize",
+    Signed open_type_os2_superscript_x_offset => "OS2" "ySuperscriptXOffset",
+    Signed open_type_os2_superscript_x_size => "OS2" "ySuperscriptXSize",
+    Signed open_type_os2_superscript_y_offset => "OS2" "ySuperscriptYOffset",
+    Signed open_type_os2_superscript_y_size => "OS2" "ySuperscriptYSize",
+    Number postscript_underline_position => "post" "underlinePosition",
+    Number postscript_underline_thickness => "post" "underlineThickness",
+    Bool postscript_is_fixed_pitch => "post" "isFixedPitch",
+}
+
+fn os2_width_class(value: u16) -> Option<norad::fontinfo::Os2WidthClass> {
+    use norad::fontinfo::Os2WidthClass::*;
+    Some(match value {
+        1 => UltraCondensed,
+        2 => ExtraCondensed,
+        3 => Condensed,
+        4 => SemiCondensed,
+        5 => Normal,
+        6 => SemiExpanded,
+        7 => Expanded,
+        8 => ExtraExpanded,
+        9 => UltraExpanded,
+        _ => return None,
+    })
+}
+
 pub(crate) fn load_font_info(
     font: &mut Font,
     info: &norad::FontInfo,
     created: Option<DateTime<Local>>,
 ) {
-    if let Some(v) = &info.copyright {
-        font.names.copyright = v.into();
+    let names = &mut font.names;
+    for (name, value) in [
+        (&mut names.copyright, &info.copyright),
+        (&mut names.family_name, &info.family_name),
+        (&mut names.trademark, &info.trademark),
+        (&mut names.designer, &info.open_type_name_designer),
+        (&mut names.designer_url, &info.open_type_name_designer_url),
+        (&mut names.manufacturer, &info.open_type_name_manufacturer),
+        (
+            &mut names.manufacturer_url,
+            &info.open_type_name_manufacturer_url,
+        ),
+        (&mut names.license, &info.open_type_name_license),
+        (&mut names.license_url, &info.open_type_name_license_url),
+        (&mut names.version, &info.open_type_name_version),
+        (&mut names.unique_id, &info.open_type_name_unique_id),
+        (&mut names.description, &info.open_type_name_description),
+        (
+            &mut names.typographic_family,
+            &info.open_type_name_preferred_family_name,
+        ),
+        (
+            &mut names.typographic_subfamily,
+            &info.open_type_name_preferred_subfamily_name,
+        ),
+        (
+            &mut names.compatible_full_name,
+            &info.open_type_name_compatible_full_name,
+        ),
+        (&mut names.sample_text, &info.open_type_name_sample_text),
+        (
+            &mut names.w_w_s_family_name,
+            &info.open_type_name_wws_family_name,
+        ),
+        (
+            &mut names.w_w_s_subfamily_name,
+            &info.open_type_name_wws_subfamily_name,
+        ),
+        (
+            &mut names.style_map_family_name,
+            &info.style_map_family_name,
+        ),
+        (&mut names.postscript_name, &info.postscript_font_name),
+    ] {
+        if let Some(v) = value {
+            *name = v.into();
+        }
     }
-    if let Some(v) = &info.family_name {
-        font.names.family_name = v.into();
+    if let Some(v) = &info.style_map_style_name {
+        names.style_map_style_name = Some(match v {
+            norad::fontinfo::StyleMapStyle::Regular => StyleMapStyle::Regular,
+            norad::fontinfo::StyleMapStyle::Italic => StyleMapStyle::Italic,
+            norad::fontinfo::StyleMapStyle::Bold => StyleMapStyle::Bold,
+            norad::fontinfo::StyleMapStyle::BoldItalic => StyleMapStyle::BoldItalic,
+        });
     }
     if let Some(v) = &info.note {
         font.note = Some(v.clone());
@@ -142,22 +309,7 @@ pub(crate) fn load_font_info(
             font.date = created.unwrap_or_else(chrono::Local::now);
         }
     }
-    if let Some(v) = &info.open_type_head_flags {
-        font.set_ot_value("head", "flags", OTScalar::BitField(v.to_vec()))
-    }
-    if let Some(v) = info.open_type_head_lowest_rec_ppem {
-        font.set_ot_value("head", "lowestRecPPEM", OTScalar::Unsigned(v))
-    }
-    if let Some(v) = &info.open_type_os2_type {
-        font.set_ot_value("OS/2", "fsType", OTScalar::BitField(v.to_vec()))
-    }
-    if let Some(v) = &info.postscript_underline_position {
-        font.set_ot_value("post", "underlinePosition", OTScalar::Signed(*v as i32))
-    }
-    // XXX and much more
-    if let Some(v) = &info.trademark {
-        font.names.trademark = v.into();
-    }
+    load_ot_values(font, info);
 
     if let Some(v) = info.units_per_em {
         font.upm = v.as_f64() as u16;
@@ -246,28 +398,32 @@ pub(crate) fn load_glyphs(font: &mut Font, ufo: &norad::Font) {
 
     for glyphname in order {
         if let Some(glyph) = ufo.get_glyph(glyphname.as_str()) {
-            let cat = if let Some(cats) = categories {
-                match cats.get(&glyphname).and_then(|x| x.as_string()) {
-                    Some("base") => GlyphCategory::Base,
-                    Some("mark") => GlyphCategory::Mark,
-                    Some("ligature") => GlyphCategory::Ligature,
-                    _ => GlyphCategory::Base,
-                }
-            } else {
-                GlyphCategory::Base
+            let codepoints: Vec<usize> = glyph.codepoints.iter().map(|x| *x as usize).collect();
+            let cat = match categories
+                .and_then(|cats| cats.get(&glyphname))
+                .and_then(|x| x.as_string())
+            {
+                Some("base") => GlyphCategory::Base,
+                Some("mark") => GlyphCategory::Mark,
+                Some("ligature") => GlyphCategory::Ligature,
+                Some(_) => GlyphCategory::Base,
+                None => GlyphCategory::from_glyph(&glyphname, &codepoints),
             };
             let production_name = psnames
                 .and_then(|x| x.get(&glyphname))
                 .and_then(|x| x.as_string())
-                .map(|x| x.to_string());
+                .map(|x| x.to_string())
+                .or_else(|| Glyph::default_production_name(&glyphname));
             font.glyphs.push(Glyph {
                 name: glyphname.to_string(),
                 category: cat,
                 production_name,
-                codepoints: glyph.codepoints.iter().map(|x| *x as usize).collect(),
+                codepoints,
                 layers: vec![],
                 exported: !skipped.contains(&glyphname),
                 direction: None,
+                smart_axes: vec![],
+                lib: plist::Dictionary::new(),
             })
         }
     }
@@ -297,3 +453,339 @@ fn add_uvs_sequences(font: &mut Font, ufo: &norad::Font) {
         }
     }
 }
+
+pub fn save(font: &Font, path: PathBuf) -> Result<(), BabelfontError> {
+    let master = font
+        .default_master()
+        .or_else(|| font.masters.first())
+        .ok_or_else(|| BabelfontError::NoDefaultMaster { path: path.clone() })?;
+    if font.masters.len() > 1 {
+        log::warn!(
+            "Only the default master can be saved to {}; save to a .designspace file to keep the others",
+            path.display()
+        );
+    }
+    save_ufo(&master_to_ufo(font, master), &path)
+}
+
+pub(crate) fn save_ufo(ufo: &norad::Font, path: &std::path::Path) -> Result<(), BabelfontError> {
+    ufo.save(path).map_err(|e| BabelfontError::SavingUFO {
+        orig: Box::new(e),
+        path: path.display().to_string(),
+    })
+}
+
+/// Builds a UFO containing the given master's layers, metrics and kerning,
+/// along with the font-wide information.
+pub(crate) fn master_to_ufo(font: &Font, master: &Master) -> norad::Font {
+    let mut ufo = norad::Font::new();
+    ufo.font_info = save_font_info(font, master);
+    save_kerning(&mut ufo, font, master);
+    save_lib(&mut ufo, font);
+    ufo.features = font.features.clone().unwrap_or_default();
+    let default_layer = ufo.default_layer_mut();
+    for glyph in font.glyphs.iter() {
+        if let Some(layer) = glyph.get_layer(&master.id) {
+            default_layer.insert_glyph(babelfont_layer_to_norad_glyph(glyph, layer));
+        }
+    }
+    ufo
+}
+
+pub(crate) fn babelfont_layer_to_norad_glyph(glyph: &Glyph, layer: &Layer) -> norad::Glyph {
+    let mut g = norad::Glyph::new(&glyph.name);
+    g.width = layer.width as f64;
+    g.height = layer.height.unwrap_or(0) as f64;
+    g.codepoints = glyph
+        .codepoints
+        .iter()
+        .filter_map(|&c| char::from_u32(c as u32))
+        .collect();
+    if let Some(v) = layer.vertical_origin {
+        g.lib.insert(
+            "public.verticalOrigin".to_string(),
+            plist::Value::Integer(v.into()),
+        );
+    }
+    g.guidelines = layer.guides.iter().map(|x| x.into()).collect();
+    g.anchors = layer.anchors.iter().map(|x| x.into()).collect();
+    for shape in &layer.shapes {
+        match shape {
+            Shape::ComponentShape(c) => match save_component(c) {
+                Some(c) => g.components.push(c),
+                None => log::warn!(
+                    "Dropping component '{}' in glyph {}: not a valid UFO glyph name",
+                    c.reference,
+                    glyph.name
+                ),
+            },
+            Shape::PathShape(p) => g.contours.push(save_path(p)),
+        }
+    }
+    g
+}
+
+pub(crate) fn save_component(c: &Component) -> Option<norad::Component> {
+    let [x_scale, xy_scale, yx_scale, y_scale, x_offset, y_offset] = c.transform.as_coeffs();
+    Some(norad::Component::new(
+        norad::Name::new(&c.reference).ok()?,
+        norad::AffineTransform {
+            x_scale,
+            xy_scale,
+            yx_scale,
+            y_scale,
+            x_offset,
+            y_offset,
+        },
+        None,
+        None,
+    ))
+}
+
+pub(crate) fn save_path(p: &Path) -> norad::Contour {
+    let mut nodes = p.nodes.clone();
+    // Undo the rotation performed in load_path. Open paths which didn't come
+    // from a UFO already start at their first node.
+    if !nodes.is_empty() && (p.closed || nodes.last().map(|n| n.nodetype) == Some(NodeType::Move)) {
+        nodes.rotate_right(1);
+    }
+    let mut points: Vec<norad::ContourPoint> = nodes.iter().map(|n| n.into()).collect();
+    if !p.closed {
+        if let Some(first) = points.first_mut() {
+            first.typ = norad::PointType::Move;
+        }
+    }
+    norad::Contour::new(points, None, None)
+}
+
+pub(crate) fn save_font_info(font: &Font, master: &Master) -> norad::FontInfo {
+    let names = &font.names;
+    let mut info = norad::FontInfo {
+        family_name: names.family_name.get_default(),
+        style_name: master.name.get_default(),
+        copyright: names.copyright.get_default(),
+        trademark: names.trademark.get_default(),
+        open_type_name_designer: names.designer.get_default(),
+        open_type_name_designer_url: names.designer_url.get_default(),
+        open_type_name_manufacturer: names.manufacturer.get_default(),
+        open_type_name_manufacturer_url: names.manufacturer_url.get_default(),
+        open_type_name_license: names.license.get_default(),
+        open_type_name_license_url: names.license_url.get_default(),
+        open_type_name_version: names.version.get_default(),
+        open_type_name_unique_id: names.unique_id.get_default(),
+        open_type_name_description: names.description.get_default(),
+        open_type_name_preferred_family_name: names.typographic_family.get_default(),
+        open_type_name_preferred_subfamily_name: names.typographic_subfamily.get_default(),
+        open_type_name_compatible_full_name: names.compatible_full_name.get_default(),
+        open_type_name_sample_text: names.sample_text.get_default(),
+        open_type_name_wws_family_name: names.w_w_s_family_name.get_default(),
+        open_type_name_wws_subfamily_name: names.w_w_s_subfamily_name.get_default(),
+        style_map_family_name: names.style_map_family_name.get_default(),
+        postscript_font_name: names.postscript_name.get_default(),
+        style_map_style_name: names.style_map_style_name.as_ref().map(|s| match s {
+            StyleMapStyle::Regular => norad::fontinfo::StyleMapStyle::Regular,
+            StyleMapStyle::Italic => norad::fontinfo::StyleMapStyle::Italic,
+            StyleMapStyle::Bold => norad::fontinfo::StyleMapStyle::Bold,
+            StyleMapStyle::BoldItalic => norad::fontinfo::StyleMapStyle::BoldItalic,
+        }),
+        note: font.note.clone(),
+        open_type_head_created: Some(font.date.format("%Y/%m/%d %H:%M:%S").to_string()),
+        units_per_em: norad::fontinfo::NonNegativeIntegerOrFloat::new(font.upm as f64),
+        version_major: Some(font.version.0 as i32),
+        version_minor: Some(font.version.1 as u32),
+        ..Default::default()
+    };
+
+    let metric = |name: &str| master.metrics.get(name).map(|&v| v as f64);
+    info.ascender = metric("ascender");
+    info.cap_height = metric("capHeight");
+    info.descender = metric("descender");
+    info.italic_angle = metric("italic angle");
+    info.x_height = metric("xHeight");
+    if !master.guides.is_empty() {
+        info.guidelines = Some(master.guides.iter().map(|g| g.into()).collect());
+    }
+
+    // Master-specific values override font-wide ones
+    save_ot_values(&mut info, &font.custom_ot_values);
+    save_ot_values(&mut info, &master.custom_ot_values);
+    info
+}
+
+/// Converts a babelfont kern group name to a UFO kerning group name for the
+/// given side of a pair (1 for the first glyph, 2 for the second).
+fn ufo_group_name(group: &str, side: u8) -> String {
+    let glyphs_prefix = if side == 1 { "MMK_L_" } else { "MMK_R_" };
+    if group.starts_with("public.kern") {
+        group.to_string()
+    } else if let Some(name) = group.strip_prefix(glyphs_prefix) {
+        format!("public.kern{}.{}", side, name)
+    } else {
+        format!("public.kern{}.{}", side, group)
+    }
+}
+
+fn ufo_kern_name(name: &str, side: u8) -> String {
+    match name.strip_prefix('@') {
+        Some(group) => ufo_group_name(group, side),
+        None => name.to_string(),
+    }
+}
+
+pub(crate) fn save_kerning(ufo: &mut norad::Font, font: &Font, master: &Master) {
+    for ((left, right), value) in master.kerning.iter() {
+        if let (Ok(left), Ok(right)) = (
+            norad::Name::new(&ufo_kern_name(left, 1)),
+            norad::Name::new(&ufo_kern_name(right, 2)),
+        ) {
+            ufo.kerning
+                .entry(left)
+                .or_default()
+                .insert(right, *value as f64);
+        }
+    }
+
+    // All UFOs get the same groups, so that they remain compatible
+    let to_names = |members: &[String]| -> Vec<norad::Name> {
+        members
+            .iter()
+            .filter_map(|m| norad::Name::new(m).ok())
+            .collect()
+    };
+    for (left, right) in font.masters.iter().flat_map(|m| m.kerning.keys()) {
+        for (group, side) in [(left, 1), (right, 2)] {
+            let group = match group.strip_prefix('@') {
+                Some(group) => group,
+                None => continue,
+            };
+            if let (Some(members), Ok(name)) = (
+                font.kern_groups.get(group),
+                norad::Name::new(&ufo_group_name(group, side)),
+            ) {
+                ufo.groups.entry(name).or_insert_with(|| to_names(members));
+            }
+        }
+    }
+    // Groups which didn't come from Glyphs kerning classes are kept as they are
+    for (group, members) in font.kern_groups.iter() {
+        if group.starts_with("MMK_") {
+            continue;
+        }
+        if let Ok(name) = norad::Name::new(group) {
+            ufo.groups.entry(name).or_insert_with(|| to_names(members));
+        }
+    }
+}
+
+pub(crate) fn save_lib(ufo: &mut norad::Font, font: &Font) {
+    let lib = &mut ufo.lib;
+    lib.insert(
+        "public.glyphOrder".to_string(),
+        plist::Value::Array(font.glyphs.iter().map(|g| g.name.clone().into()).collect()),
+    );
+    let categories: plist::Dictionary = font
+        .glyphs
+        .iter()
+        .map(|g| {
+            let category = match g.category {
+                GlyphCategory::Base => "base",
+                GlyphCategory::Mark => "mark",
+                GlyphCategory::Ligature => "ligature",
+                GlyphCategory::Unknown => "unassigned",
+            };
+            (g.name.clone(), plist::Value::from(category))
+        })
+        .collect();
+    lib.insert("public.openTypeCategories".to_string(), categories.into());
+    let psnames: plist::Dictionary = font
+        .glyphs
+        .iter()
+        .filter_map(|g| {
+            g.production_name
+                .as_ref()
+                .filter(|&p| Some(p) != Glyph::default_production_name(&g.name).as_ref())
+                .map(|p| (g.name.clone(), plist::Value::from(p.clone())))
+        })
+        .collect();
+    if !psnames.is_empty() {
+        lib.insert("public.postscriptNames".to_string(), psnames.into());
+    }
+    let skipped: Vec<plist::Value> = font
+        .glyphs
+        .iter()
+        .filter(|g| !g.exported)
+        .map(|g| g.name.clone().into())
+        .collect();
+    if !skipped.is_empty() {
+        lib.insert("public.skipExportGlyphs".to_string(), skipped.into());
+    }
+    if !font.variation_sequences.is_empty() {
+        let mut uvs: BTreeMap<String, plist::Dictionary> = BTreeMap::new();
+        for ((selector, codepoint), glyphname) in font.variation_sequences.iter() {
+            uvs.entry(format!("{:04X}", selector))
+                .or_default()
+                .insert(format!("{:04X}", codepoint), glyphname.clone().into());
+        }
+        let uvs: plist::Dictionary = uvs
+            .into_iter()
+            .map(|(k, v)| (k, plist::Value::from(v)))
+            .collect();
+        lib.insert("public.unicodeVariationSequences".to_string(), uvs.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_ufo() {
+        let font = crate::convertors::glyphs3::load(
+            "../fonticulus/test/sources/SimpleTwoAxis.glyphs".into(),
+        )
+        .unwrap();
+        let directory = std::env::temp_dir().join("babelfont-ufo-save");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("SimpleTwoAxis.ufo");
+        let _ = std::fs::remove_dir_all(&path);
+        save(&font, path.clone()).unwrap();
+
+        // Glyphs kerning groups become UFO kerning groups
+        let ufo = norad::Font::load(&path).unwrap();
+        let group = ufo.groups.get("public.kern1.Alike").unwrap();
+        assert!(group.iter().any(|g| g.as_str() == "A"));
+        assert_eq!(ufo.kerning.get("public.kern1.Alike").unwrap()["V"], -80.0);
+
+        // Only the default master is saved
+        let reloaded = load(path).unwrap();
+        let master = font.default_master().unwrap();
+        let new_master = &reloaded.masters[0];
+        assert_eq!(reloaded.masters.len(), 1);
+        // UFOs have no baseline; it's always zero
+        for (name, value) in master.metrics.iter().filter(|(k, _)| *k != "baseline") {
+            assert_eq!(new_master.metrics.get(name), Some(value));
+        }
+        assert_eq!(new_master.kerning.len(), master.kerning.len());
+        assert_eq!(
+            new_master
+                .kerning
+                .get(&("@public.kern1.Alike".to_string(), "V".to_string())),
+            Some(&-80)
+        );
+        assert_eq!(
+            reloaded.names.family_name.get_default(),
+            font.names.family_name.get_default()
+        );
+        assert_eq!(reloaded.glyphs.len(), font.glyphs.len());
+        for (glyph, new_glyph) in font.glyphs.iter().zip(reloaded.glyphs.iter()) {
+            assert_eq!(glyph.name, new_glyph.name);
+            assert_eq!(glyph.codepoints, new_glyph.codepoints);
+            assert_eq!(glyph.exported, new_glyph.exported);
+            let layer = glyph.get_layer(&master.id).unwrap();
+            let new_layer = new_glyph.get_layer(&new_master.id).unwrap();
+            assert_eq!(layer.width, new_layer.width);
+            assert_eq!(layer.shapes.len(), new_layer.shapes.len());
+            assert_eq!(layer.anchors.len(), new_layer.anchors.len());
+        }
+    }
+}
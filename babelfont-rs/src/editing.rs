@@ -0,0 +1,1029 @@
+//! Operations which change the outlines and metrics of glyphs.
+//!
+//! Operations on a [`Glyph`] apply to all of its layers (other than
+//! background layers), and keep layers which are interpolation-compatible
+//! compatible: where an operation depends on the shape of the outline, the
+//! same change is made to every layer.
+use crate::common::{Node, NodeType};
+use crate::{BabelfontError, Glyph, Layer, Path, Shape};
+use kurbo::{
+    Affine, CubicBez, ParamCurve, ParamCurveExtrema, ParamCurveNearest, Point, Shape as _,
+};
+use otmath::ot_round;
+
+/// Extremes closer than this to the ends of a curve don't get a new node
+const EXTREME_MARGIN: f64 = 0.001;
+
+/// The indices of the nodes of a segment of a path: the on-curve nodes at
+/// which it starts and ends, and the off-curve points between them.
+struct Segment {
+    start: usize,
+    offcurves: Vec<usize>,
+    end: usize,
+}
+
+fn point(node: &Node) -> Point {
+    Point::new(node.x as f64, node.y as f64)
+}
+
+fn node_at(point: Point, nodetype: NodeType) -> Node {
+    Node {
+        x: point.x as f32,
+        y: point.y as f32,
+        nodetype,
+        smooth: false,
+    }
+}
+
+impl Path {
+    /// Applies a transformation to the path's nodes
+    pub fn transform(&mut self, transform: Affine) {
+        for node in self.nodes.iter_mut() {
+            let new_point = transform * point(node);
+            node.x = new_point.x as f32;
+            node.y = new_point.y as f32;
+        }
+    }
+
+    /// Rounds the path's coordinates to integers
+    pub fn round(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.x = ot_round(node.x) as f32;
+            node.y = ot_round(node.y) as f32;
+        }
+    }
+
+    /// Reverses the direction of the path, keeping its start node.
+    pub fn reverse(&mut self) {
+        if !self.has_oncurve() {
+            return;
+        }
+        let rotation = self.normalize();
+        self.nodes.reverse();
+        // Each segment's type belongs to the node at which it ends, which is
+        // now the node at which it starts
+        let oncurves: Vec<usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.nodetype != NodeType::OffCurve)
+            .map(|(ix, _)| ix)
+            .collect();
+        let types: Vec<NodeType> = oncurves.iter().map(|&ix| self.nodes[ix].nodetype).collect();
+        for (k, &ix) in oncurves.iter().enumerate() {
+            self.nodes[ix].nodetype = types[(k + types.len() - 1) % types.len()];
+        }
+        if self.closed {
+            // The start node is the last one
+            self.nodes.rotate_left(1);
+        } else {
+            self.nodes.rotate_left(rotation);
+        }
+    }
+
+    /// Makes the given node the start node of a closed path. If it is an
+    /// off-curve point, the next on-curve node becomes the start node.
+    /// Open paths always start at their first node, so are unchanged.
+    ///
+    /// Panics if the index is out of range.
+    pub fn set_start_node(&mut self, index: usize) {
+        assert!(index < self.nodes.len(), "Node index out of range");
+        if !self.closed {
+            return;
+        }
+        let count = self.nodes.len();
+        if let Some(start) = (0..count)
+            .map(|k| (index + k) % count)
+            .find(|&ix| self.nodes[ix].nodetype != NodeType::OffCurve)
+        {
+            // The start node is the last one
+            self.nodes.rotate_left((start + 1) % count);
+        }
+    }
+
+    /// Splits cubic curves at their horizontal and vertical extremes, where
+    /// they don't already have nodes.
+    pub fn add_extremes(&mut self) {
+        let splits = self.extremes();
+        self.split_segments(&splits);
+    }
+
+    /// Removes nodes at the horizontal and vertical extremes of smooth
+    /// cubic curves, where the two curves on either side of the node can be
+    /// merged into one which stays within `tolerance` units of them. The
+    /// start node is kept.
+    pub fn remove_extremes(&mut self, tolerance: f64) {
+        let removable = self.removable_extremes(tolerance);
+        self.merge_segments(&removable);
+    }
+
+    /// Whether the path has any on-curve nodes; paths without them have no
+    /// segments, and so no direction
+    fn has_oncurve(&self) -> bool {
+        self.nodes.iter().any(|n| n.nodetype != NodeType::OffCurve)
+    }
+
+    /// Rotates the nodes so that the path's first node comes first and no
+    /// segment wraps around the end of the list of nodes. (The start node
+    /// of a closed path is the last node.) Returns the rotation, for
+    /// [`Path::denormalize`].
+    fn normalize(&mut self) -> usize {
+        let rotation = if self.closed {
+            self.nodes
+                .iter()
+                .rev()
+                .take_while(|n| n.nodetype == NodeType::OffCurve)
+                .count()
+                % self.nodes.len().max(1)
+        } else {
+            // Open paths from UFOs keep their move node last
+            usize::from(self.nodes.last().map(|n| n.nodetype) == Some(NodeType::Move))
+        };
+        self.nodes.rotate_right(rotation);
+        rotation
+    }
+
+    fn denormalize(&mut self, rotation: usize) {
+        if rotation == 0 {
+            return;
+        }
+        if self.closed {
+            let leading = self
+                .nodes
+                .iter()
+                .take_while(|n| n.nodetype == NodeType::OffCurve)
+                .count();
+            self.nodes.rotate_left(leading);
+        } else {
+            self.nodes.rotate_left(rotation);
+        }
+    }
+
+    /// The segments of a path whose nodes are normalized
+    fn segments(&self) -> Vec<Segment> {
+        let last_oncurve = match self
+            .nodes
+            .iter()
+            .rposition(|n| n.nodetype != NodeType::OffCurve)
+        {
+            Some(ix) => ix,
+            None => return vec![],
+        };
+        let (mut start, first) = if self.closed {
+            (last_oncurve, 0)
+        } else {
+            (0, 1)
+        };
+        let mut segments = vec![];
+        let mut offcurves = vec![];
+        for ix in first..self.nodes.len() {
+            if self.nodes[ix].nodetype == NodeType::OffCurve {
+                offcurves.push(ix);
+            } else {
+                segments.push(Segment {
+                    start,
+                    offcurves: std::mem::take(&mut offcurves),
+                    end: ix,
+                });
+                start = ix;
+            }
+        }
+        segments
+    }
+
+    fn cubic(&self, segment: &Segment) -> Option<CubicBez> {
+        if self.nodes[segment.end].nodetype != NodeType::Curve || segment.offcurves.len() != 2 {
+            return None;
+        }
+        Some(CubicBez::new(
+            point(&self.nodes[segment.start]),
+            point(&self.nodes[segment.offcurves[0]]),
+            point(&self.nodes[segment.offcurves[1]]),
+            point(&self.nodes[segment.end]),
+        ))
+    }
+
+    /// The parameters of the extremes of each segment, in the order of
+    /// [`Path::segments`]
+    pub(crate) fn extremes(&self) -> Vec<Vec<f64>> {
+        let mut path = self.clone();
+        path.normalize();
+        path.segments()
+            .iter()
+            .map(|segment| match path.cubic(segment) {
+                Some(cubic) => {
+                    let mut ts: Vec<f64> = cubic
+                        .extrema()
+                        .into_iter()
+                        .filter(|t| (EXTREME_MARGIN..1.0 - EXTREME_MARGIN).contains(t))
+                        .collect();
+                    ts.sort_by(|a, b| a.total_cmp(b));
+                    ts.dedup_by(|a, b| (*a - *b).abs() < EXTREME_MARGIN);
+                    ts
+                }
+                None => vec![],
+            })
+            .collect()
+    }
+
+    /// Splits each cubic segment at the given parameters
+    pub(crate) fn split_segments(&mut self, splits: &[Vec<f64>]) {
+        let rotation = self.normalize();
+        let mut nodes = vec![];
+        let mut next = 0;
+        for (segment, ts) in self.segments().iter().zip(splits) {
+            let first = segment.offcurves.first().copied().unwrap_or(segment.end);
+            nodes.extend_from_slice(&self.nodes[next..first]);
+            next = segment.end + 1;
+            let cubic = match self.cubic(segment) {
+                Some(cubic) if !ts.is_empty() => cubic,
+                _ => {
+                    nodes.extend_from_slice(&self.nodes[first..next]);
+                    continue;
+                }
+            };
+            let mut previous = 0.0;
+            for &t in ts.iter().chain([1.0].iter()) {
+                let part = cubic.subsegment(previous..t);
+                nodes.push(node_at(part.p1, NodeType::OffCurve));
+                nodes.push(node_at(part.p2, NodeType::OffCurve));
+                if t < 1.0 {
+                    nodes.push(node_at(part.p3, NodeType::Curve));
+                }
+                previous = t;
+            }
+            nodes.push(self.nodes[segment.end].clone());
+        }
+        nodes.extend_from_slice(&self.nodes[next..]);
+        self.nodes = nodes;
+        self.denormalize(rotation);
+    }
+
+    /// Whether the node at the end of each segment, in the order of
+    /// [`Path::segments`], can be removed by merging the segment with the
+    /// next one
+    pub(crate) fn removable_extremes(&self, tolerance: f64) -> Vec<bool> {
+        let mut path = self.clone();
+        path.normalize();
+        let segments = path.segments();
+        (0..segments.len())
+            .map(|ix| {
+                segments
+                    .get(ix + 1)
+                    .is_some_and(|next| path.merged(&segments[ix], next, tolerance).is_some())
+            })
+            .collect()
+    }
+
+    /// Merges each segment for which `removable` is true with the next one,
+    /// unless the segment was itself just merged into its predecessor
+    pub(crate) fn merge_segments(&mut self, removable: &[bool]) {
+        let rotation = self.normalize();
+        let segments = self.segments();
+        let mut nodes = vec![];
+        let mut next = 0;
+        let mut ix = 0;
+        while ix < segments.len() {
+            let segment = &segments[ix];
+            let first = segment.offcurves.first().copied().unwrap_or(segment.end);
+            nodes.extend_from_slice(&self.nodes[next..first]);
+            let merged = if removable.get(ix) == Some(&true) {
+                segments
+                    .get(ix + 1)
+                    .and_then(|following| self.merged(segment, following, f64::INFINITY))
+            } else {
+                None
+            };
+            match merged {
+                Some(cubic) => {
+                    let end = segments[ix + 1].end;
+                    nodes.push(node_at(cubic.p1, NodeType::OffCurve));
+                    nodes.push(node_at(cubic.p2, NodeType::OffCurve));
+                    nodes.push(self.nodes[end].clone());
+                    next = end + 1;
+                    ix += 2;
+                }
+                None => {
+                    nodes.extend_from_slice(&self.nodes[first..=segment.end]);
+                    next = segment.end + 1;
+                    ix += 1;
+                }
+            }
+        }
+        nodes.extend_from_slice(&self.nodes[next..]);
+        self.nodes = nodes;
+        self.denormalize(rotation);
+    }
+
+    /// Merges two cubic segments which meet at a smooth horizontal or
+    /// vertical extreme into one, keeping the directions of the outer
+    /// handles, if the result stays within `tolerance` units of them.
+    fn merged(&self, first: &Segment, second: &Segment, tolerance: f64) -> Option<CubicBez> {
+        let (a, b) = (self.cubic(first)?, self.cubic(second)?);
+        let (incoming, outgoing) = (a.p3 - a.p2, b.p1 - b.p0);
+        let horizontal = incoming.y.abs() < 1e-3 && outgoing.y.abs() < 1e-3;
+        let vertical = incoming.x.abs() < 1e-3 && outgoing.x.abs() < 1e-3;
+        if !(horizontal || vertical) || incoming.dot(outgoing) <= 0.0 {
+            return None;
+        }
+        // Where the node would be on a curve which was split into these two,
+        // and so how much longer that curve's outer handles would be
+        let t = incoming.hypot() / (incoming.hypot() + outgoing.hypot());
+        let merged = CubicBez::new(
+            a.p0,
+            a.p0 + (a.p1 - a.p0) / t,
+            b.p3 + (b.p2 - b.p3) / (1.0 - t),
+            b.p3,
+        );
+        let stays_close = |curve: &CubicBez| {
+            (1..8).all(|i| {
+                merged.nearest(curve.eval(i as f64 / 8.0), 1e-3).distance_sq
+                    <= tolerance * tolerance
+            })
+        };
+        if stays_close(&a) && stays_close(&b) {
+            Some(merged)
+        } else {
+            None
+        }
+    }
+}
+
+impl Layer {
+    /// Iterates mutably over the layer's paths, skipping its components
+    pub fn paths_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut Path> {
+        self.shapes.iter_mut().filter_map(|x| {
+            if let Shape::PathShape(p) = x {
+                Some(p)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Applies a transformation to the layer's paths, components, anchors
+    /// and guides, and scales its advance width horizontally.
+    ///
+    /// If `transform_components` is true, the components' outlines are
+    /// transformed along with their positions. Otherwise only their
+    /// positions change, as for when the glyphs they refer to get the same
+    /// transformation. A transformation which can't be inverted, such as a
+    /// scale of zero, can't be taken back out of the components, so they
+    /// are then transformed as if `transform_components` were true.
+    pub fn transform(&mut self, transform: Affine, transform_components: bool) {
+        let invertible = transform.determinant() != 0.0;
+        for shape in self.shapes.iter_mut() {
+            match shape {
+                Shape::PathShape(path) => path.transform(transform),
+                Shape::ComponentShape(component) => {
+                    component.transform = if transform_components || !invertible {
+                        transform * component.transform
+                    } else {
+                        transform * component.transform * transform.inverse()
+                    }
+                }
+            }
+        }
+        for anchor in self.anchors.iter_mut() {
+            let new_point = transform * Point::new(anchor.x as f64, anchor.y as f64);
+            anchor.x = ot_round(new_point.x);
+            anchor.y = ot_round(new_point.y);
+        }
+        let [xx, xy, yx, yy, _, _] = transform.as_coeffs();
+        for guide in self.guides.iter_mut() {
+            let new_point = transform * Point::new(guide.pos.x as f64, guide.pos.y as f64);
+            guide.pos.x = ot_round(new_point.x);
+            guide.pos.y = ot_round(new_point.y);
+            let (sin, cos) = (guide.pos.angle as f64).to_radians().sin_cos();
+            let angle = (xy * cos + yy * sin).atan2(xx * cos + yx * sin);
+            guide.pos.angle = angle.to_degrees() as f32;
+        }
+        self.width = ot_round(self.width as f64 * xx);
+    }
+
+    /// Moves the layer's outline so that it has the given left sidebearing,
+    /// adjusting the width to keep the right sidebearing.
+    pub fn set_lsb(&mut self, lsb: f32) -> Result<(), BabelfontError> {
+        let delta = ot_round(lsb - self.lsb()?);
+        self.transform(Affine::translate((delta as f64, 0.0)), true);
+        self.width += delta;
+        Ok(())
+    }
+
+    /// Adjusts the layer's width so that it has the given right sidebearing
+    pub fn set_rsb(&mut self, rsb: f32) -> Result<(), BabelfontError> {
+        self.width += ot_round(rsb - self.rsb()?);
+        Ok(())
+    }
+
+    /// Sets the layer's width. If `keep_centered` is true, the change is
+    /// shared between the sidebearings; otherwise the left sidebearing is
+    /// kept.
+    pub fn set_width(&mut self, width: i32, keep_centered: bool) {
+        if keep_centered {
+            let delta = ot_round((width - self.width) as f32 / 2.0);
+            self.transform(Affine::translate((delta as f64, 0.0)), true);
+        }
+        self.width = width;
+    }
+
+    /// Rounds the coordinates of the layer's paths and the positions of
+    /// its components to integers
+    pub fn round(&mut self) {
+        for shape in self.shapes.iter_mut() {
+            match shape {
+                Shape::PathShape(path) => path.round(),
+                Shape::ComponentShape(component) => {
+                    let mut coeffs = component.transform.as_coeffs();
+                    coeffs[4] = coeffs[4].round();
+                    coeffs[5] = coeffs[5].round();
+                    component.transform = Affine::new(coeffs);
+                }
+            }
+        }
+    }
+
+    /// Reorders the layer's paths, so that the path at `order[i]` becomes
+    /// the `i`th path. Components keep their places among the shapes.
+    ///
+    /// Panics if `order` does not contain each path's index exactly once.
+    pub fn reorder_paths(&mut self, order: &[usize]) {
+        let paths: Vec<Path> = self.paths().cloned().collect();
+        let mut sorted = order.to_vec();
+        sorted.sort_unstable();
+        assert!(
+            sorted.into_iter().eq(0..paths.len()),
+            "Path order must contain each path's index once"
+        );
+        for (path, &ix) in self.paths_mut().zip(order) {
+            *path = paths[ix].clone();
+        }
+    }
+
+    /// Reverses paths as needed so that outer paths run anticlockwise, the
+    /// paths inside them clockwise, and so on.
+    pub fn correct_path_direction(&mut self) {
+        let misdirected = self.misdirected_paths();
+        for (ix, path) in self.paths_mut().enumerate() {
+            if misdirected.contains(&ix) {
+                path.reverse();
+            }
+        }
+    }
+
+    /// The indices of the closed paths which run the wrong way
+    pub(crate) fn misdirected_paths(&self) -> Vec<usize> {
+        let outlines: Vec<Option<kurbo::BezPath>> = self
+            .paths()
+            .map(|p| {
+                (p.closed && p.has_oncurve())
+                    .then(|| p.to_kurbo().ok())
+                    .flatten()
+            })
+            .collect();
+        let paths: Vec<&Path> = self.paths().collect();
+        outlines
+            .iter()
+            .enumerate()
+            .filter(|(ix, outline)| {
+                let outline = match outline {
+                    Some(o) if o.area() != 0.0 => o,
+                    _ => return false,
+                };
+                let inside = match paths[*ix].nodes.first() {
+                    Some(node) => point(node),
+                    None => return false,
+                };
+                let depth = outlines
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, o)| {
+                        other != ix && o.as_ref().is_some_and(|o| o.winding(inside) != 0)
+                    })
+                    .count();
+                (outline.area() > 0.0) != (depth % 2 == 0)
+            })
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    /// Splits the cubic curves of the layer's paths at their extremes
+    pub fn add_extremes(&mut self) {
+        for path in self.paths_mut() {
+            path.add_extremes();
+        }
+    }
+
+    /// Removes nodes at the extremes of the layer's paths, where the curves
+    /// on either side can be merged within `tolerance` units
+    pub fn remove_extremes(&mut self, tolerance: f64) {
+        for path in self.paths_mut() {
+            path.remove_extremes(tolerance);
+        }
+    }
+
+    /// Removes the overlaps between the layer's closed paths, and corrects
+    /// the direction of the resulting paths. Components and open paths are
+    /// kept as they are.
+    #[cfg(feature = "skia")]
+    pub fn remove_overlaps(&mut self) -> Result<(), BabelfontError> {
+        use kurbo::PathEl;
+        let mut outline = skia_safe::PathBuilder::new();
+        let to_skia = |p: Point| skia_safe::Point::new(p.x as f32, p.y as f32);
+        for path in self.paths().filter(|p| p.closed) {
+            for element in path.to_kurbo()?.elements() {
+                match *element {
+                    PathEl::MoveTo(p) => {
+                        outline.move_to(to_skia(p));
+                    }
+                    PathEl::LineTo(p) => {
+                        outline.line_to(to_skia(p));
+                    }
+                    PathEl::QuadTo(p1, p2) => {
+                        outline.quad_to(to_skia(p1), to_skia(p2));
+                    }
+                    PathEl::CurveTo(p1, p2, p3) => {
+                        outline.cubic_to(to_skia(p1), to_skia(p2), to_skia(p3));
+                    }
+                    PathEl::ClosePath => {
+                        outline.close();
+                    }
+                }
+            }
+        }
+        let simplified = skia_safe::simplify(&outline.detach()).ok_or(BabelfontError::BadPath)?;
+        self.shapes
+            .retain(|s| !matches!(s, Shape::PathShape(p) if p.closed));
+        self.shapes
+            .extend(skia_to_paths(&simplified).into_iter().map(Shape::PathShape));
+        self.correct_path_direction();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "skia")]
+fn skia_to_paths(outline: &skia_safe::Path) -> Vec<Path> {
+    /// Each conic is approximated by 2^CONIC_POW2 quadratic curves
+    const CONIC_POW2: usize = 2;
+
+    let mut points = vec![skia_safe::Point::default(); outline.count_points()];
+    outline.get_points(&mut points);
+    let mut verbs = vec![0_u8; outline.count_verbs()];
+    outline.get_verbs(&mut verbs);
+
+    let mut points = points.into_iter();
+    let mut weights = outline.conic_weights().iter();
+    let mut paths = vec![];
+    let mut nodes: Vec<Node> = vec![];
+    let node = |p: skia_safe::Point, nodetype| Node {
+        x: p.x,
+        y: p.y,
+        nodetype,
+        smooth: false,
+    };
+    let mut previous = skia_safe::Point::default();
+    for verb in verbs {
+        // Move, line, quadratic, conic, cubic and close verbs
+        let (offcurves, nodetype) = match verb {
+            0 | 1 => (0, NodeType::Line),
+            2 => (1, NodeType::QCurve),
+            3 => {
+                let (Some(p1), Some(p2), Some(&w)) = (points.next(), points.next(), weights.next())
+                else {
+                    break;
+                };
+                let mut quads = vec![skia_safe::Point::default(); 1 + 2 * (1 << CONIC_POW2)];
+                let count = skia_safe::Path::convert_conic_to_quads(
+                    previous, p1, p2, w, &mut quads, CONIC_POW2,
+                )
+                .unwrap_or(0);
+                for quad in quads[1..].chunks(2).take(count) {
+                    nodes.push(node(quad[0], NodeType::OffCurve));
+                    nodes.push(node(quad[1], NodeType::QCurve));
+                }
+                previous = p2;
+                continue;
+            }
+            4 => (2, NodeType::Curve),
+            _ => {
+                // The move node ends the closing segment, unless the last
+                // segment already returns to it
+                if nodes.len() > 1 {
+                    let (first, last) = (&nodes[0], &nodes[nodes.len() - 1]);
+                    if first.x == last.x && first.y == last.y {
+                        nodes.remove(0);
+                    }
+                }
+                paths.push(Path {
+                    nodes: std::mem::take(&mut nodes),
+                    closed: true,
+                    ..Default::default()
+                });
+                continue;
+            }
+        };
+        for _ in 0..offcurves {
+            if let Some(p) = points.next() {
+                nodes.push(node(p, NodeType::OffCurve));
+            }
+        }
+        if let Some(p) = points.next() {
+            nodes.push(node(p, nodetype));
+            previous = p;
+        }
+    }
+    paths
+}
+
+/// Whether the layers have the same paths, with the same types of nodes
+fn compatible(layers: &[&mut Layer]) -> bool {
+    let structure = |layer: &Layer| -> Vec<(bool, Vec<NodeType>)> {
+        layer
+            .paths()
+            .map(|p| (p.closed, p.nodes.iter().map(|n| n.nodetype).collect()))
+            .collect()
+    };
+    layers
+        .windows(2)
+        .all(|pair| structure(pair[0]) == structure(pair[1]))
+}
+
+impl Glyph {
+    fn editable_layers(&mut self) -> Vec<&mut Layer> {
+        self.layers
+            .iter_mut()
+            .filter(|l| !l.is_background)
+            .collect()
+    }
+
+    /// Applies an operation, such as [`Layer::transform`] or
+    /// [`Layer::set_lsb`], to each of the glyph's layers other than
+    /// background layers. Operations which change the structure of the
+    /// outline should use the glyph's own methods instead.
+    pub fn edit_layers<F>(&mut self, mut edit: F) -> Result<(), BabelfontError>
+    where
+        F: FnMut(&mut Layer) -> Result<(), BabelfontError>,
+    {
+        for layer in self.editable_layers() {
+            edit(layer)?;
+        }
+        Ok(())
+    }
+
+    /// Corrects the direction of the paths in each layer. If the layers are
+    /// compatible, the first layer decides which paths are reversed.
+    pub fn correct_path_direction(&mut self) {
+        let mut layers = self.editable_layers();
+        if !compatible(&layers) {
+            for layer in layers {
+                layer.correct_path_direction();
+            }
+            return;
+        }
+        let misdirected = match layers.first() {
+            Some(layer) => layer.misdirected_paths(),
+            None => return,
+        };
+        for layer in layers.iter_mut() {
+            for (ix, path) in layer.paths_mut().enumerate() {
+                if misdirected.contains(&ix) {
+                    path.reverse();
+                }
+            }
+        }
+    }
+
+    /// Adds nodes at the extremes of the paths in each layer. If the layers
+    /// are compatible, curves are only split where they have the same
+    /// number of extremes in every layer.
+    pub fn add_extremes(&mut self) {
+        let name = self.name.clone();
+        let mut layers = self.editable_layers();
+        if !compatible(&layers) {
+            for layer in layers {
+                layer.add_extremes();
+            }
+            return;
+        }
+        let extremes: Vec<Vec<Vec<Vec<f64>>>> = layers
+            .iter()
+            .map(|l| l.paths().map(|p| p.extremes()).collect())
+            .collect();
+        let mut skipped = false;
+        for (layer_extremes, layer) in extremes.iter().zip(layers.iter_mut()) {
+            for (path_ix, path) in layer.paths_mut().enumerate() {
+                let splits: Vec<Vec<f64>> = layer_extremes[path_ix]
+                    .iter()
+                    .enumerate()
+                    .map(|(segment_ix, ts)| {
+                        if extremes
+                            .iter()
+                            .all(|other| other[path_ix][segment_ix].len() == ts.len())
+                        {
+                            ts.clone()
+                        } else {
+                            skipped = true;
+                            vec![]
+                        }
+                    })
+                    .collect();
+                path.split_segments(&splits);
+            }
+        }
+        if skipped {
+            log::warn!(
+                "Some curves of glyph {} have different extremes in different layers, so were not split",
+                name
+            );
+        }
+    }
+
+    /// Removes nodes at the extremes of the paths in each layer, where the
+    /// curves on either side can be merged within `tolerance` units. If the
+    /// layers are compatible, nodes are only removed where they can be in
+    /// every layer.
+    pub fn remove_extremes(&mut self, tolerance: f64) {
+        let mut layers = self.editable_layers();
+        if !compatible(&layers) {
+            for layer in layers {
+                layer.remove_extremes(tolerance);
+            }
+            return;
+        }
+        let removable: Vec<Vec<Vec<bool>>> = layers
+            .iter()
+            .map(|l| l.paths().map(|p| p.removable_extremes(tolerance)).collect())
+            .collect();
+        for layer in layers.iter_mut() {
+            for (path_ix, path) in layer.paths_mut().enumerate() {
+                let segments = removable.first().map_or(0, |r| r[path_ix].len());
+                let everywhere: Vec<bool> = (0..segments)
+                    .map(|segment_ix| removable.iter().all(|r| r[path_ix][segment_ix]))
+                    .collect();
+                path.merge_segments(&everywhere);
+            }
+        }
+    }
+
+    /// Removes overlaps in each layer. If the layers were compatible and
+    /// the results are not, the layers are left unchanged and an error is
+    /// returned.
+    #[cfg(feature = "skia")]
+    pub fn remove_overlaps(&mut self) -> Result<(), BabelfontError> {
+        let original = self.layers.clone();
+        let was_compatible = compatible(&self.editable_layers());
+        self.edit_layers(|layer| layer.remove_overlaps())?;
+        if was_compatible && !compatible(&self.editable_layers()) {
+            self.layers = original;
+            return Err(BabelfontError::IncompatibleLayers {
+                glyph_name: self.name.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Anchor, Component};
+
+    fn node(x: f32, y: f32, nodetype: NodeType) -> Node {
+        Node {
+            x,
+            y,
+            nodetype,
+            smooth: false,
+        }
+    }
+
+    /// A closed path which starts at the origin and curves up to y=75
+    fn arch() -> Path {
+        Path {
+            nodes: vec![
+                node(0.0, 100.0, NodeType::OffCurve),
+                node(100.0, 100.0, NodeType::OffCurve),
+                node(100.0, 0.0, NodeType::Curve),
+                node(0.0, 0.0, NodeType::Line),
+            ],
+            closed: true,
+            ..Default::default()
+        }
+    }
+
+    fn square(size: f32, clockwise: bool) -> Path {
+        let mut corners = vec![(0.0, 0.0), (0.0, size), (size, size), (size, 0.0)];
+        if !clockwise {
+            corners.reverse();
+        }
+        Path {
+            nodes: corners
+                .into_iter()
+                .map(|(x, y)| node(x, y, NodeType::Line))
+                .collect(),
+            closed: true,
+            ..Default::default()
+        }
+    }
+
+    fn coordinates(path: &Path) -> Vec<(f32, f32, NodeType)> {
+        path.nodes.iter().map(|n| (n.x, n.y, n.nodetype)).collect()
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut path = arch();
+        path.reverse();
+        assert_eq!(
+            coordinates(&path),
+            vec![
+                (100.0, 0.0, NodeType::Line),
+                (100.0, 100.0, NodeType::OffCurve),
+                (0.0, 100.0, NodeType::OffCurve),
+                (0.0, 0.0, NodeType::Curve),
+            ]
+        );
+        path.reverse();
+        assert_eq!(coordinates(&path), coordinates(&arch()));
+
+        path.set_start_node(0);
+        assert_eq!(path.nodes.last().unwrap().x, 100.0);
+        assert_eq!(path.nodes[0].nodetype, NodeType::Line);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut path = arch();
+        path.add_extremes();
+        assert_eq!(path.nodes.len(), 7);
+        assert_eq!((path.nodes[2].x, path.nodes[2].y), (50.0, 75.0));
+        assert_eq!(path.nodes[2].nodetype, NodeType::Curve);
+
+        path.remove_extremes(0.5);
+        for (removed, original) in path.nodes.iter().zip(arch().nodes.iter()) {
+            assert!((removed.x - original.x).abs() < 1e-3);
+            assert!((removed.y - original.y).abs() < 1e-3);
+        }
+        assert_eq!(path.nodes.len(), 4);
+
+        // Removing the node of a semicircle would change its shape too much
+        let mut semicircle = Path {
+            nodes: vec![
+                node(0.0, 28.0, NodeType::OffCurve),
+                node(22.0, 50.0, NodeType::OffCurve),
+                node(50.0, 50.0, NodeType::Curve),
+                node(78.0, 50.0, NodeType::OffCurve),
+                node(100.0, 28.0, NodeType::OffCurve),
+                node(100.0, 0.0, NodeType::Curve),
+                node(0.0, 0.0, NodeType::Line),
+            ],
+            closed: true,
+            ..Default::default()
+        };
+        semicircle.remove_extremes(1.0);
+        assert_eq!(semicircle.nodes.len(), 7);
+    }
+
+    #[test]
+    fn test_correct_path_direction() {
+        let mut layer = Layer::new(100);
+        layer.push_path(square(100.0, true));
+        let mut counter = square(50.0, true);
+        counter.transform(Affine::translate((25.0, 25.0)));
+        layer.push_path(counter);
+        layer.correct_path_direction();
+        let outlines: Vec<kurbo::BezPath> = layer.paths().map(|p| p.to_kurbo().unwrap()).collect();
+        assert!(outlines[0].area() > 0.0);
+        assert!(outlines[1].area() < 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "skia")]
+    fn test_remove_overlaps() {
+        let mut layer = Layer::new(200);
+        layer.push_path(square(100.0, true));
+        let mut other = square(100.0, true);
+        other.transform(Affine::translate((50.0, 50.0)));
+        layer.push_path(other);
+        layer.remove_overlaps().unwrap();
+        let paths: Vec<&Path> = layer.paths().collect();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].nodes.len(), 8);
+        assert!(paths[0].nodes.iter().all(|n| n.nodetype == NodeType::Line));
+        assert_eq!(paths[0].to_kurbo().unwrap().area(), 17500.0);
+    }
+
+    #[test]
+    fn test_paths_without_oncurve_nodes() {
+        let empty = Path {
+            closed: true,
+            ..Default::default()
+        };
+        let offcurves = Path {
+            nodes: vec![
+                node(0.0, 0.0, NodeType::OffCurve),
+                node(0.0, 100.0, NodeType::OffCurve),
+            ],
+            closed: true,
+            ..Default::default()
+        };
+        let mut path = empty.clone();
+        path.reverse();
+        assert!(path.nodes.is_empty());
+
+        let mut layer = Layer::new(100);
+        layer.push_path(empty);
+        layer.push_path(offcurves.clone());
+        layer.push_path(square(100.0, true));
+        assert_eq!(layer.misdirected_paths(), vec![2]);
+        layer.correct_path_direction();
+        let paths: Vec<&Path> = layer.paths().collect();
+        assert!(paths[0].nodes.is_empty());
+        assert_eq!(coordinates(paths[1]), coordinates(&offcurves));
+        assert!(paths[2].to_kurbo().unwrap().area() > 0.0);
+    }
+
+    #[test]
+    fn test_metrics_and_transform() {
+        let mut layer = Layer::new(200);
+        layer.push_path(square(100.0, false));
+        layer.anchors.push(Anchor {
+            x: 50,
+            y: 100,
+            name: "top".to_string(),
+        });
+        layer.set_lsb(30.0).unwrap();
+        assert_eq!(layer.width, 230);
+        assert_eq!(layer.anchors[0].x, 80);
+        layer.set_rsb(20.0).unwrap();
+        assert_eq!(layer.width, 150);
+        layer.set_width(250, true);
+        assert_eq!(layer.lsb().unwrap(), 80.0);
+
+        layer.push_component(Component {
+            reference: "acutecomb".to_string(),
+            transform: Affine::translate((100.0, 0.0)),
+            ..Default::default()
+        });
+        let mut scaled = layer.clone();
+        scaled.transform(Affine::scale(2.0), false);
+        assert_eq!(scaled.width, 500);
+        assert_eq!(scaled.anchors[0].x, 260);
+        let component = scaled.components().next().unwrap();
+        assert_eq!(component.transform, Affine::translate((200.0, 0.0)));
+        // Flattening can't be undone, so the component is flattened too
+        let mut flattened = layer.clone();
+        flattened.transform(Affine::scale_non_uniform(1.0, 0.0), false);
+        let component = flattened.components().next().unwrap();
+        assert_eq!(
+            component.transform.as_coeffs(),
+            [1.0, 0.0, 0.0, 0.0, 100.0, 0.0]
+        );
+        layer.transform(Affine::scale(2.0), true);
+        let component = layer.components().next().unwrap();
+        assert_eq!(
+            component.transform.as_coeffs(),
+            [2.0, 0.0, 0.0, 2.0, 200.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_glyph_stays_compatible() {
+        let mut glyph = Glyph {
+            name: "arch".to_string(),
+            production_name: None,
+            category: crate::GlyphCategory::Base,
+            codepoints: vec![],
+            layers: vec![],
+            exported: true,
+            direction: None,
+            smart_axes: vec![],
+            lib: plist::Dictionary::new(),
+        };
+        let mut light = Layer::new(100);
+        light.push_path(arch());
+        light.push_path(square(10.0, true));
+        // A bolder arch whose curve is flat, so has no extreme
+        let mut bold = light.clone();
+        bold.paths_mut().next().unwrap().nodes[0].y = 0.0;
+        bold.paths_mut().next().unwrap().nodes[1].y = 0.0;
+        glyph.layers = vec![light, bold];
+
+        glyph.add_extremes();
+        assert!(glyph
+            .layers
+            .iter()
+            .all(|l| l.paths().next().unwrap().nodes.len() == 4));
+        glyph.correct_path_direction();
+        let structure =
+            |layer: &Layer| -> Vec<usize> { layer.paths().map(|p| p.nodes.len()).collect() };
+        assert_eq!(structure(&glyph.layers[0]), structure(&glyph.layers[1]));
+        glyph
+            .edit_layers(|layer| {
+                layer.transform(Affine::translate((10.0, 0.0)), true);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(glyph.layers[1].paths().nth(1).unwrap().nodes[0].x, 10.0);
+    }
+}
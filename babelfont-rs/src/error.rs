@@ -26,9 +26,15 @@ pub enum BabelfontError {
         path: String,
     },
 
+    #[snafu(display("Error saving UFO {}: {:?}", path, orig))]
+    SavingUFO {
+        orig: Box<norad::error::FontWriteError>,
+        path: String,
+    },
+
     #[snafu(display("Could not parse XML file {}: {:?}", path.display(), orig))]
     XMLParse {
-        orig: serde_xml_rs::Error,
+        orig: designspace::DeError,
         path: PathBuf,
     },
 
@@ -41,6 +47,9 @@ pub enum BabelfontError {
     #[snafu(display("Ill-constructed path"))]
     BadPath,
 
+    #[snafu(display("Layers of glyph {} are not compatible", glyph_name))]
+    IncompatibleLayers { glyph_name: String },
+
     #[snafu(display(
         "Called a method which requires a decomposed layer on a layer which had components"
     ))]
@@ -1,17 +1,24 @@
-use crate::axis::Axis;
+use crate::axis::{Axis, AxisLabel, LocationLabel};
 use crate::common::{OTScalar, OTValue};
 use crate::glyph::GlyphList;
+use crate::i18ndictionary::I18NDictionary;
 use crate::instance::Instance;
 use crate::master::Master;
 use crate::names::Names;
+use crate::rule::Rule;
 use crate::{BabelfontError, Layer, Location};
 use chrono::Local;
 use fonttools::font::Font as FTFont;
-use fonttools::otvar::{Location as OTVarLocation, NormalizedLocation, VariationModel};
+use fonttools::otvar::{
+    ItemVariationStore, Location as OTVarLocation, NormalizedLocation, RegionAxisCoordinates,
+    VariationModel,
+};
 use fonttools::tables::avar::{avar, SegmentMap};
 use fonttools::tables::fvar::{fvar, InstanceRecord, VariationAxisRecord};
-use fonttools::tables::name::NameRecord;
-use otmath::ot_cmp;
+use fonttools::tables::name::{name, NameRecord};
+use fonttools::tables::STAT::{AxisRecord, AxisValue, AxisValueFlags, STAT};
+use otmath::{ot_cmp, ot_round, to_f2dot14};
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone)]
@@ -20,6 +27,8 @@ pub struct Font {
     pub version: (u16, u16),
     pub axes: Vec<Axis>,
     pub instances: Vec<Instance>,
+    /// Names for locations involving several axes (`STAT` format 4)
+    pub location_labels: Vec<LocationLabel>,
     pub masters: Vec<Master>,
     pub glyphs: GlyphList,
     pub note: Option<String>,
@@ -27,6 +36,12 @@ pub struct Font {
     pub names: Names,
     pub custom_ot_values: Vec<OTValue>,
     pub variation_sequences: BTreeMap<(u32, u32), String>,
+    /// Cross-axis mappings between (input, output) designspace locations
+    pub axis_mappings: Vec<(Location, Location)>,
+    pub rules: Vec<Rule>,
+    /// Whether rules are applied after other substitutions rather than before
+    pub rules_processing_last: bool,
+    pub lib: plist::Dictionary,
     // features: ????
     // The below is temporary
     pub features: Option<String>,
@@ -45,6 +60,7 @@ impl Font {
             version: (1, 0),
             axes: vec![],
             instances: vec![],
+            location_labels: vec![],
             masters: vec![],
             glyphs: GlyphList(vec![]),
             note: None,
@@ -52,6 +68,10 @@ impl Font {
             names: Names::default(),
             custom_ot_values: vec![],
             variation_sequences: BTreeMap::new(),
+            axis_mappings: vec![],
+            rules: vec![],
+            rules_processing_last: false,
+            lib: plist::Dictionary::new(),
             kern_groups: HashMap::new(),
             features: None,
         }
@@ -138,10 +158,29 @@ impl Font {
             .copied()
     }
 
-    /// Normalizes a location between -1.0 and 1.0
+    /// Normalizes a location between -1.0 and 1.0, applying any cross-axis
+    /// mappings.
     pub fn normalize_location(
         &self,
         loc: &Location,
+    ) -> Result<NormalizedLocation, Box<BabelfontError>> {
+        let normalized = self.normalize_designspace_location(loc)?;
+        if let Some(var_store) = self.cross_axis_variation_store()? {
+            let mapping = avar {
+                maps: vec![],
+                axis_index_map: None,
+                var_store: Some(var_store),
+            };
+            return Ok(NormalizedLocation(mapping.map_cross_axis(&normalized.0)));
+        }
+        Ok(normalized)
+    }
+
+    /// Normalizes a location between -1.0 and 1.0, without cross-axis mappings.
+    /// (Master locations are expressed in this space.)
+    fn normalize_designspace_location(
+        &self,
+        loc: &Location,
     ) -> Result<NormalizedLocation, Box<BabelfontError>> {
         let mut v: Vec<f32> = vec![];
         for axis in self.axes.iter() {
@@ -157,22 +196,170 @@ impl Font {
 
     /// Constructs a fonttools variation model for this designspace
     pub fn variation_model(&self) -> Result<VariationModel<String>, Box<BabelfontError>> {
-        let mut locations: Vec<OTVarLocation<String>> = vec![];
-        for master in self.masters.iter() {
-            let source_loc = self.normalize_location(&master.location)?;
+        self.variation_model_for(self.masters.iter().map(|m| &m.location))
+    }
+
+    /// Returns the layers of a glyph which act as intermediate masters
+    /// ("brace layers"): layers with a location of their own, other than
+    /// that of one of the font's masters.
+    pub fn intermediate_layers(&self, glyphname: &str) -> Vec<&Layer> {
+        let glyph = match self.glyphs.get(glyphname) {
+            Some(g) => g,
+            None => return vec![],
+        };
+        let default = self.default_location();
+        let value = |loc: &Location, tag: &String| {
+            loc.0
+                .get(tag)
+                .or_else(|| default.0.get(tag))
+                .copied()
+                .unwrap_or(0.0)
+        };
+        let same_location = |a: &Location, b: &Location| {
+            self.axes
+                .iter()
+                .all(|ax| ot_cmp(value(a, &ax.tag), value(b, &ax.tag)).is_eq())
+        };
+        glyph
+            .layers
+            .iter()
+            .filter(|l| !l.is_background)
+            .filter(|l| {
+                l.location.as_ref().is_some_and(|loc| {
+                    !self.masters.iter().any(|m| same_location(loc, &m.location))
+                })
+            })
+            .collect()
+    }
+
+    /// Constructs a variation model for a single glyph, in which its
+    /// intermediate layers are additional sparse masters. The model's
+    /// masters are the font's masters followed by the layers returned by
+    /// [`Font::intermediate_layers`].
+    pub fn glyph_variation_model(
+        &self,
+        glyphname: &str,
+    ) -> Result<VariationModel<String>, Box<BabelfontError>> {
+        let intermediates = self.intermediate_layers(glyphname);
+        self.variation_model_for(
+            self.masters.iter().map(|m| &m.location).chain(
+                intermediates
+                    .iter()
+                    .flat_map(|layer| layer.location.as_ref()),
+            ),
+        )
+    }
+
+    fn variation_model_for<'a>(
+        &self,
+        locations: impl Iterator<Item = &'a Location>,
+    ) -> Result<VariationModel<String>, Box<BabelfontError>> {
+        let mut ot_locations: Vec<OTVarLocation<String>> = vec![];
+        for location in locations {
+            let source_loc = self.normalize_designspace_location(location)?;
             let mut loc = OTVarLocation::new();
             for (ax, iter_l) in self.axes.iter().zip(source_loc.0.iter()) {
                 loc.insert(ax.tag.clone(), *iter_l);
             }
-            locations.push(loc);
+            ot_locations.push(loc);
         }
-        Ok(VariationModel::new(locations, self.axis_order()))
+        Ok(VariationModel::new(ot_locations, self.axis_order()))
     }
 
     fn axis_order(&self) -> Vec<String> {
         self.axes.iter().map(|ax| ax.tag.clone()).collect()
     }
 
+    /// Normalizes the axes given in a (possibly sparse) location, leaving
+    /// the remaining axes out.
+    fn normalize_sparse_location(
+        &self,
+        loc: &Location,
+    ) -> Result<OTVarLocation<String>, Box<BabelfontError>> {
+        let mut out = OTVarLocation::new();
+        for (tag, &value) in loc.0.iter() {
+            let axis = self.axes.iter().find(|ax| &ax.tag == tag).ok_or_else(|| {
+                BabelfontError::General {
+                    msg: format!("Unknown axis {} in axis mapping", tag),
+                }
+            })?;
+            out.insert(tag.clone(), axis.normalize_designspace_value(value)?);
+        }
+        Ok(out)
+    }
+
+    /// Builds an item variation store (as used in `avar` version 2) from the
+    /// cross-axis mappings, or returns `None` if there are no mappings.
+    /// Deltas are in F2DOT14 units, one item per axis.
+    pub fn cross_axis_variation_store(
+        &self,
+    ) -> Result<Option<ItemVariationStore>, Box<BabelfontError>> {
+        if self.axis_mappings.is_empty() {
+            return Ok(None);
+        }
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        for (input, output) in &self.axis_mappings {
+            inputs.push(self.normalize_sparse_location(input)?);
+            outputs.push(self.normalize_sparse_location(output)?);
+        }
+        // The model needs a master at the default location
+        if !inputs.iter().any(|l| l.values().all(|&v| v == 0.0)) {
+            inputs.insert(0, OTVarLocation::new());
+            outputs.insert(0, OTVarLocation::new());
+        }
+        let axis_order = self.axis_order();
+        let model = VariationModel::new(inputs.clone(), axis_order.clone());
+
+        let mut regions: Vec<Vec<RegionAxisCoordinates>> = vec![];
+        let mut deltas: Vec<Vec<i16>> = vec![];
+        for tag in &axis_order {
+            let master_values: Vec<Option<f32>> = inputs
+                .iter()
+                .zip(outputs.iter())
+                .map(|(input, output)| {
+                    Some(output.get(tag).map_or(0.0, |&out| {
+                        to_f2dot14(out - input.get(tag).copied().unwrap_or(0.0)) as f32
+                    }))
+                })
+                .collect();
+            let deltas_and_supports = model.get_deltas_and_supports(&master_values);
+            // The first delta is at the default location, which stays put
+            if regions.is_empty() {
+                regions = deltas_and_supports
+                    .iter()
+                    .skip(1)
+                    .map(|(_, support)| {
+                        axis_order
+                            .iter()
+                            .map(|t| {
+                                let (start, peak, end) =
+                                    support.get(t).copied().unwrap_or((0.0, 0.0, 0.0));
+                                RegionAxisCoordinates {
+                                    startCoord: start,
+                                    peakCoord: peak,
+                                    endCoord: end,
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect();
+            }
+            deltas.push(
+                deltas_and_supports
+                    .iter()
+                    .skip(1)
+                    .map(|(delta, _)| ot_round(*delta) as i16)
+                    .collect(),
+            );
+        }
+        Ok(Some(ItemVariationStore::new(
+            self.axes.len() as u16,
+            regions,
+            deltas,
+        )))
+    }
+
     /// Add information to a fonttools Font object (fvar and avar tables)
     /// expressed by this design space.
     pub fn add_variation_tables(&self, font: &mut FTFont) -> Result<(), Box<BabelfontError>> {
@@ -245,14 +432,130 @@ impl Font {
             // }
             instances.push(ir)
         }
+        let stat = self.stat_table(&axes, &mut name, &mut ix)?;
         font.tables.insert(fvar { axes, instances });
 
-        font.tables.insert(avar { maps });
+        font.tables.insert(avar {
+            maps,
+            axis_index_map: None,
+            var_store: self.cross_axis_variation_store()?,
+        });
+        font.tables.insert(stat);
         font.tables.insert(name);
 
         Ok(())
     }
 
+    /// Builds a `STAT` table from the axis and location labels, adding
+    /// their names to the name table. Axes without labels get labels from
+    /// the instances which only differ from the default along that axis.
+    fn stat_table(
+        &self,
+        axis_records: &[VariationAxisRecord],
+        name: &mut name,
+        next_name_id: &mut u16,
+    ) -> Result<STAT, Box<BabelfontError>> {
+        let mut axis_values = vec![];
+        for (axis_index, axis) in self.axes.iter().enumerate() {
+            let labels = if axis.labels.is_empty() {
+                self.labels_from_instances(axis)
+            } else {
+                axis.labels.clone()
+            };
+            for label in labels {
+                let name_id = stat_name_id(name, next_name_id, &label.name)?;
+                let flags = stat_flags(label.elidable, label.older_sibling);
+                let axis_index = axis_index as u16;
+                axis_values.push(if let Some((min, max)) = label.range {
+                    AxisValue::new_format2(axis_index, flags, name_id, label.value, min, max)
+                } else if let Some(linked_value) = label.linked_value {
+                    AxisValue::new_format3(axis_index, flags, name_id, label.value, linked_value)
+                } else {
+                    AxisValue::new_format1(axis_index, flags, name_id, label.value)
+                });
+            }
+        }
+
+        for label in &self.location_labels {
+            let name_id = stat_name_id(name, next_name_id, &label.name)?;
+            let flags = stat_flags(label.elidable, label.older_sibling);
+            let mapping: BTreeMap<u16, f32> = self
+                .axes
+                .iter()
+                .enumerate()
+                .filter_map(|(ix, axis)| label.location.0.get(&axis.tag).map(|&v| (ix as u16, v)))
+                .collect();
+            axis_values.push(AxisValue::new_format4(flags, name_id, mapping));
+        }
+
+        Ok(STAT {
+            elided_fallback_name_id: Some(2),
+            design_axes: axis_records
+                .iter()
+                .enumerate()
+                .map(|(ordering, record)| AxisRecord {
+                    axisTag: record.axisTag,
+                    axisNameID: record.axisNameID,
+                    axisOrdering: ordering as u16,
+                })
+                .collect(),
+            axis_values,
+        })
+    }
+
+    /// Derives labels for an axis from the instances which sit at the default
+    /// location of all the other axes. The label at the axis default is
+    /// elidable, and Regular and Bold (or Upright and Italic) are linked.
+    fn labels_from_instances(&self, axis: &Axis) -> Vec<AxisLabel> {
+        let default_location = self.default_location();
+        let mut labels: Vec<AxisLabel> = vec![];
+        for instance in &self.instances {
+            let only_this_axis =
+                self.axes
+                    .iter()
+                    .filter(|other| other.tag != axis.tag)
+                    .all(|other| {
+                        let value = instance.location.0.get(&other.tag);
+                        value.is_none() || value == default_location.0.get(&other.tag)
+                    });
+            let design_value = match instance.location.0.get(&axis.tag) {
+                Some(&v) if only_this_axis => v,
+                _ => continue,
+            };
+            let style_name = match instance.style_name.get_default() {
+                Some(n) => n,
+                None => continue,
+            };
+            let value = axis.designspace_to_userspace(design_value);
+            if labels
+                .iter()
+                .any(|l| ot_cmp(l.value, value) == Ordering::Equal)
+            {
+                continue;
+            }
+            let mut label = AxisLabel::new(style_name, value);
+            label.elidable = axis
+                .default
+                .is_some_and(|d| ot_cmp(d, value) == Ordering::Equal);
+            labels.push(label);
+        }
+        labels.sort_by(|a, b| ot_cmp(a.value, b.value));
+
+        let link = match axis.tag.as_str() {
+            "wght" => Some((400.0, 700.0)),
+            "ital" => Some((0.0, 1.0)),
+            _ => None,
+        };
+        if let Some((from, to)) = link {
+            if labels.iter().any(|l| l.value == to) {
+                if let Some(label) = labels.iter_mut().find(|l| l.value == from) {
+                    label.linked_value = Some(to);
+                }
+            }
+        }
+        labels
+    }
+
     pub fn location_to_tuple(&self, loc: &Location) -> Vec<f32> {
         let mut tuple = vec![];
         for (axis, default) in self.axes.iter().zip(self.default_location().0.iter()) {
@@ -266,3 +569,186 @@ impl Font {
         tuple
     }
 }
+
+fn stat_flags(elidable: bool, older_sibling: bool) -> AxisValueFlags {
+    let mut flags = AxisValueFlags::empty();
+    if elidable {
+        flags |= AxisValueFlags::ELIDABLE_AXIS_VALUE_NAME;
+    }
+    if older_sibling {
+        flags |= AxisValueFlags::OLDER_SIBLING_FONT_ATTRIBUTE;
+    }
+    flags
+}
+
+/// Finds the ID of a font-specific name with the given string, adding it to
+/// the name table if it is not already there.
+fn stat_name_id(
+    name: &mut name,
+    next_name_id: &mut u16,
+    label: &I18NDictionary,
+) -> Result<u16, Box<BabelfontError>> {
+    let string = label.get_default().ok_or_else(|| BabelfontError::General {
+        msg: "STAT label has no name".to_string(),
+    })?;
+    if let Some(record) = name
+        .records
+        .iter()
+        .find(|r| r.nameID >= 256 && r.string == string)
+    {
+        return Ok(record.nameID);
+    }
+    name.records
+        .push(NameRecord::windows_unicode(*next_name_id, string));
+    *next_name_id += 1;
+    Ok(*next_name_id - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(items: &[(&str, f32)]) -> Location {
+        Location(items.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+    }
+
+    #[test]
+    fn test_cross_axis_mappings() {
+        let mut font = Font::new();
+        let mut wght = Axis::new("Weight".to_string(), "wght".to_string());
+        wght.min = Some(100.0);
+        wght.default = Some(400.0);
+        wght.max = Some(900.0);
+        let mut opsz = Axis::new("Optical size".to_string(), "opsz".to_string());
+        opsz.min = Some(12.0);
+        opsz.default = Some(12.0);
+        opsz.max = Some(72.0);
+        font.axes = vec![wght, opsz];
+        font.axis_mappings.push((
+            location(&[("wght", 900.0), ("opsz", 72.0)]),
+            location(&[("wght", 800.0)]),
+        ));
+
+        // Results are quantized to F2DOT14
+        let assert_maps_to = |loc, expected: [f32; 2]| {
+            let got = font.normalize_location(&location(loc)).unwrap().0;
+            for (g, e) in got.iter().zip(expected.iter()) {
+                assert!((g - e).abs() < 0.001, "{:?} != {:?}", got, expected);
+            }
+        };
+        assert_maps_to(&[("wght", 900.0), ("opsz", 12.0)], [1.0, 0.0]);
+        assert_maps_to(&[("wght", 900.0), ("opsz", 72.0)], [0.8, 1.0]);
+        assert_maps_to(&[("wght", 650.0), ("opsz", 42.0)], [0.45, 0.5]);
+    }
+
+    #[test]
+    fn test_stat_table() {
+        let mut font = Font::new();
+        let mut wght = Axis::new("Weight".to_string(), "wght".to_string());
+        wght.min = Some(300.0);
+        wght.default = Some(400.0);
+        wght.max = Some(700.0);
+        let mut ital = Axis::new("Italic".to_string(), "ital".to_string());
+        ital.min = Some(0.0);
+        ital.default = Some(0.0);
+        ital.max = Some(1.0);
+        let mut upright = AxisLabel::new("Upright", 0.0);
+        upright.elidable = true;
+        ital.labels = vec![upright];
+        font.axes = vec![wght, ital];
+        for (style_name, loc) in [
+            ("Light", location(&[("wght", 300.0)])),
+            ("Regular", location(&[("wght", 400.0), ("ital", 0.0)])),
+            ("Bold", location(&[("wght", 700.0)])),
+            ("Bold Italic", location(&[("wght", 700.0), ("ital", 1.0)])),
+        ] {
+            font.instances.push(Instance {
+                name: style_name.into(),
+                location: loc,
+                style_name: style_name.into(),
+                ..Default::default()
+            });
+        }
+        font.location_labels.push(LocationLabel {
+            name: "Bold Italic".into(),
+            location: location(&[("wght", 700.0), ("ital", 1.0)]),
+            elidable: false,
+            older_sibling: true,
+        });
+
+        let mut ttfont = FTFont::new(fonttools::font::SfntVersion::TrueType);
+        ttfont.tables.insert(name { records: vec![] });
+        font.add_variation_tables(&mut ttfont).unwrap();
+        let stat = ttfont.tables.STAT().unwrap().unwrap();
+        let names = ttfont.tables.name().unwrap().unwrap();
+        let name_of = |id: u16| {
+            names
+                .records
+                .iter()
+                .find(|r| r.nameID == id)
+                .map(|r| r.string.clone())
+                .unwrap()
+        };
+
+        assert_eq!(stat.design_axes.len(), 2);
+        assert_eq!(name_of(stat.design_axes[1].axisNameID), "Italic");
+        let values: Vec<_> = stat
+            .axis_values
+            .iter()
+            .map(|v| {
+                (
+                    name_of(v.name_id),
+                    v.axis_index,
+                    v.nominal_value,
+                    v.linked_value,
+                    v.flags,
+                )
+            })
+            .collect();
+        let elidable = AxisValueFlags::ELIDABLE_AXIS_VALUE_NAME;
+        assert_eq!(
+            values,
+            vec![
+                (
+                    "Light".to_string(),
+                    Some(0),
+                    Some(300.0),
+                    None,
+                    AxisValueFlags::empty()
+                ),
+                (
+                    "Regular".to_string(),
+                    Some(0),
+                    Some(400.0),
+                    Some(700.0),
+                    elidable
+                ),
+                (
+                    "Bold".to_string(),
+                    Some(0),
+                    Some(700.0),
+                    None,
+                    AxisValueFlags::empty()
+                ),
+                ("Upright".to_string(), Some(1), Some(0.0), None, elidable),
+                (
+                    "Bold Italic".to_string(),
+                    None,
+                    None,
+                    None,
+                    AxisValueFlags::OLDER_SIBLING_FONT_ATTRIBUTE
+                ),
+            ]
+        );
+        // Names are shared with the fvar instances
+        let fvar = ttfont.tables.fvar().unwrap().unwrap();
+        assert_eq!(
+            stat.axis_values[2].name_id,
+            fvar.instances[2].subfamilyNameID
+        );
+        assert_eq!(
+            stat.axis_values[4].locations,
+            Some(BTreeMap::from([(0, 700.0), (1, 1.0)]))
+        );
+    }
+}
@@ -1,5 +1,11 @@
 use crate::common::Direction;
 use crate::layer::Layer;
+use crate::{Location, Shape};
+use fonttools::otvar::VariationModel;
+use kurbo::Affine;
+use otmath::{normalize_value, ot_round};
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Sub};
 
 #[derive(Debug, Shrinkwrap, Clone)]
 #[shrinkwrap(mutable)]
@@ -20,7 +26,7 @@ impl GlyphList {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GlyphCategory {
     Base,
     Mark,
@@ -28,6 +34,30 @@ pub enum GlyphCategory {
     Ligature,
 }
 
+impl GlyphCategory {
+    /// The category the glyph database gives a glyph name, for sources which
+    /// don't specify one.
+    pub fn from_glyph_name(name: &str) -> GlyphCategory {
+        GlyphCategory::from_glyph(name, &[])
+    }
+
+    /// The category the glyph database gives a glyph, for sources which
+    /// don't specify one. Glyphs whose names it doesn't know take the
+    /// category of their first code point.
+    pub fn from_glyph(name: &str, codepoints: &[usize]) -> GlyphCategory {
+        let info = glyphdata::glyph_info(name).or_else(|| {
+            codepoints
+                .first()
+                .and_then(|&c| glyphdata::info_for_codepoint(c as u32))
+        });
+        match info {
+            Some(info) if info.is_mark() => GlyphCategory::Mark,
+            Some(info) if info.is_ligature() => GlyphCategory::Ligature,
+            _ => GlyphCategory::Base,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Glyph {
     pub name: String,
@@ -37,13 +67,232 @@ pub struct Glyph {
     pub layers: Vec<Layer>,
     pub exported: bool,
     pub direction: Option<Direction>,
+    /// For smart component glyphs, the axes along which the glyph's part
+    /// layers vary
+    pub smart_axes: Vec<SmartAxis>,
+    pub lib: plist::Dictionary,
+}
+
+/// An axis of a smart component glyph, whose part layers are placed at
+/// either of its poles
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmartAxis {
+    pub name: String,
+    pub bottom: f32,
+    pub top: f32,
 }
 
 impl Glyph {
+    /// The production name the glyph database gives a glyph name, if it
+    /// differs from the name itself.
+    pub fn default_production_name(name: &str) -> Option<String> {
+        Some(glyphdata::production_name(name)).filter(|p| p != name)
+    }
+
     pub fn get_layer(&self, id: &str) -> Option<&Layer> {
         self.layers.iter().find(|l| l.id.as_deref() == Some(id))
     }
     pub fn get_layer_mut(&mut self, id: &str) -> Option<&mut Layer> {
         self.layers.iter_mut().find(|l| l.id.as_deref() == Some(id))
     }
+
+    /// Whether the glyph is a smart component glyph
+    pub fn is_smart(&self) -> bool {
+        !self.smart_axes.is_empty()
+    }
+
+    /// Interpolates a smart component glyph's layers for the given master at
+    /// a location given in values of its smart axes, as used by a component.
+    ///
+    /// Axes for which no value is given stay at the master layer's value.
+    /// If the part layers are not compatible, the master layer is returned
+    /// unchanged.
+    pub fn smart_layer(&self, master_id: &str, location: &Location) -> Option<Layer> {
+        let master_layer = self.get_layer(master_id)?;
+        let mut layers = vec![master_layer];
+        for layer in self.layers.iter().filter(|l| {
+            !l.is_background
+                && !l.smart_location.0.is_empty()
+                && l.associated_master_id.as_deref() == Some(master_id)
+        }) {
+            if !layers
+                .iter()
+                .any(|l| l.smart_location == layer.smart_location)
+            {
+                layers.push(layer);
+            }
+        }
+        let master_value = |axis: &SmartAxis| {
+            master_layer
+                .smart_location
+                .0
+                .get(&axis.name)
+                .copied()
+                .unwrap_or(axis.bottom)
+        };
+        // Normalize around the master layer's pole, so that it is the default
+        let normalize = |axis: &SmartAxis, value: f32| {
+            let (min, max) = (axis.bottom.min(axis.top), axis.bottom.max(axis.top));
+            if min == max {
+                return 0.0;
+            }
+            normalize_value(value, min, max, master_value(axis))
+        };
+        let normalized = |location: &Location| -> BTreeMap<String, f32> {
+            self.smart_axes
+                .iter()
+                .map(|axis| {
+                    let value = location
+                        .0
+                        .get(&axis.name)
+                        .copied()
+                        .unwrap_or_else(|| master_value(axis));
+                    (axis.name.clone(), normalize(axis, value))
+                })
+                .collect()
+        };
+
+        let master_coordinates = Coordinates::from_layer(master_layer);
+        let mut values = vec![];
+        for layer in &layers {
+            let coordinates = Coordinates::from_layer(layer);
+            if !master_coordinates.is_compatible(&coordinates) {
+                log::warn!(
+                    "Part layers of smart glyph {} are not compatible",
+                    self.name
+                );
+                return Some(master_layer.clone());
+            }
+            values.push(Some(coordinates));
+        }
+        let model = VariationModel::new(
+            layers
+                .iter()
+                .map(|l| normalized(&l.smart_location))
+                .collect(),
+            self.smart_axes.iter().map(|a| a.name.clone()).collect(),
+        );
+        let deltas: Vec<Coordinates> = model
+            .get_deltas_and_supports(&values)
+            .into_iter()
+            .map(|(delta, _)| delta)
+            .collect();
+        let scalars = model.get_scalars(&normalized(location));
+        let coordinates = model.interpolate_from_deltas_and_scalars(&deltas, &scalars)?;
+        Some(coordinates.apply_to(master_layer))
+    }
+}
+
+/// The interpolatable values of a layer: its width, the coordinates of its
+/// nodes, its components' transformations and its anchors' coordinates,
+/// with the number of nodes in each path and the components' glyphs.
+#[derive(Debug, Clone)]
+pub(crate) struct Coordinates {
+    structure: Vec<usize>,
+    components: Vec<String>,
+    anchors: Vec<String>,
+    values: Vec<f32>,
+}
+
+impl Coordinates {
+    pub(crate) fn from_layer(layer: &Layer) -> Self {
+        let mut values = vec![layer.width as f32];
+        let mut structure = vec![];
+        for path in layer.paths() {
+            structure.push(path.nodes.len());
+            for node in &path.nodes {
+                values.extend([node.x, node.y]);
+            }
+        }
+        for component in layer.components() {
+            values.extend(component.transform.as_coeffs().iter().map(|&c| c as f32));
+        }
+        let mut anchors: Vec<&crate::Anchor> = layer.anchors.iter().collect();
+        anchors.sort_by(|a, b| a.name.cmp(&b.name));
+        for anchor in &anchors {
+            values.extend([anchor.x as f32, anchor.y as f32]);
+        }
+        Coordinates {
+            structure,
+            components: layer.components().map(|c| c.reference.clone()).collect(),
+            anchors: anchors.iter().map(|a| a.name.clone()).collect(),
+            values,
+        }
+    }
+
+    /// Drops the anchors' coordinates, leaving the outline's
+    pub(crate) fn without_anchors(mut self) -> Self {
+        self.values
+            .truncate(self.values.len() - self.anchors.len() * 2);
+        self.anchors.clear();
+        self
+    }
+
+    pub(crate) fn is_compatible(&self, other: &Coordinates) -> bool {
+        self.structure == other.structure
+            && self.components == other.components
+            && self.anchors == other.anchors
+    }
+
+    /// Returns a copy of the layer with these coordinates
+    pub(crate) fn apply_to(&self, layer: &Layer) -> Layer {
+        let mut layer = layer.clone();
+        let mut values = self.values.iter().copied();
+        layer.width = ot_round(values.next().unwrap_or(0.0));
+        for shape in layer.shapes.iter_mut() {
+            if let Shape::PathShape(path) = shape {
+                for node in path.nodes.iter_mut() {
+                    node.x = values.next().unwrap_or(node.x);
+                    node.y = values.next().unwrap_or(node.y);
+                }
+            }
+        }
+        for shape in layer.shapes.iter_mut() {
+            if let Shape::ComponentShape(component) = shape {
+                let mut coeffs = component.transform.as_coeffs();
+                for coeff in coeffs.iter_mut() {
+                    *coeff = values.next().map_or(*coeff, |v| v as f64);
+                }
+                component.transform = Affine::new(coeffs);
+            }
+        }
+        let anchor_values: Vec<f32> = values.collect();
+        for anchor in layer.anchors.iter_mut() {
+            if let Some(ix) = self.anchors.iter().position(|a| *a == anchor.name) {
+                anchor.x = ot_round(anchor_values[ix * 2]);
+                anchor.y = ot_round(anchor_values[ix * 2 + 1]);
+            }
+        }
+        layer
+    }
+}
+
+impl Sub for Coordinates {
+    type Output = Coordinates;
+    fn sub(mut self, other: Coordinates) -> Coordinates {
+        for (a, b) in self.values.iter_mut().zip(other.values) {
+            *a -= b;
+        }
+        self
+    }
+}
+
+impl Add for Coordinates {
+    type Output = Coordinates;
+    fn add(mut self, other: Coordinates) -> Coordinates {
+        for (a, b) in self.values.iter_mut().zip(other.values) {
+            *a += b;
+        }
+        self
+    }
+}
+
+impl Mul<f32> for Coordinates {
+    type Output = Coordinates;
+    fn mul(mut self, scalar: f32) -> Coordinates {
+        for a in self.values.iter_mut() {
+            *a *= scalar;
+        }
+        self
+    }
 }
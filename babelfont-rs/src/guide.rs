@@ -45,3 +45,27 @@ impl From<&norad::Guideline> for Guide {
         out
     }
 }
+
+impl From<&Guide> for norad::Guideline {
+    fn from(g: &Guide) -> Self {
+        let Position { x, y, angle } = g.pos;
+        let line = if x == 0 && angle == 0.0 {
+            norad::Line::Horizontal(y as f64)
+        } else if y == 0 && angle == 90.0 {
+            norad::Line::Vertical(x as f64)
+        } else {
+            norad::Line::Angle {
+                x: x as f64,
+                y: y as f64,
+                degrees: angle as f64,
+            }
+        };
+        norad::Guideline::new(
+            line,
+            g.name.as_ref().and_then(|x| norad::Name::new(x).ok()),
+            g.color.as_ref().and_then(|x| x.try_into().ok()),
+            None,
+            None,
+        )
+    }
+}
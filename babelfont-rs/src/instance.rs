@@ -1,10 +1,19 @@
 use crate::common::Location;
 use crate::i18ndictionary::I18NDictionary;
+use crate::names::StyleMapStyle;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Instance {
     pub name: I18NDictionary,
     pub location: Location,
     pub style_name: I18NDictionary,
-    // lib
+    pub family_name: I18NDictionary,
+    pub postscript_name: Option<String>,
+    pub style_map_family_name: I18NDictionary,
+    pub style_map_style_name: Option<StyleMapStyle>,
+    /// Whether kerning should be generated for this instance
+    pub kerning: bool,
+    /// Whether font info should be generated for this instance
+    pub info: bool,
+    pub lib: plist::Dictionary,
 }
@@ -0,0 +1,492 @@
+use crate::common::OTValue;
+use crate::glyph::{Coordinates, GlyphList};
+use crate::{BabelfontError, Font, Glyph, Layer, Location, Master, OTScalar};
+use fonttools::otvar::{support_scalar, Location as OTVarLocation, VariationModel};
+use otmath::ot_round;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::{Add, Mul, Sub};
+
+// Interpolating an instance works by giving the variation model each
+// master's version of a value (a glyph layer's coordinates, a metric, a kern
+// pair), or `None` where a master doesn't have one, and letting the model
+// do the rest. The default master must always have a value.
+
+impl Font {
+    /// Interpolates the font at a location, given in designspace coordinates,
+    /// returning a font with a single master and no axes, which can be saved
+    /// or compiled as a static font. Axes missing from the location are at
+    /// their default.
+    ///
+    /// Glyph layers (including intermediate layers and smart component part
+    /// layers), anchors, kerning, metrics and numeric OpenType values are
+    /// interpolated. Glyphs which are not compatible across masters keep
+    /// their default master layer. Rules which apply at the location swap
+    /// the layers of the glyphs they substitute.
+    pub fn instantiate(&self, location: &Location) -> Result<Font, Box<BabelfontError>> {
+        let (instancer, full_location) = self.instancer(location)?;
+        let default_master = &self.masters[instancer.default_ix];
+
+        let mut master = Master::new(
+            default_master.name.clone(),
+            default_master.id.clone(),
+            Location::new(),
+        );
+        master.guides = default_master.guides.clone();
+        master.metrics = instancer.metrics();
+        master.kerning = instancer.kerning();
+        master.custom_ot_values = instancer.ot_values();
+
+        let mut glyphs = vec![];
+        for glyph in self.glyphs.iter() {
+            let mut instance_glyph = glyph.clone();
+            instance_glyph.layers = instancer.glyph_layers(glyph)?;
+            glyphs.push(instance_glyph);
+        }
+
+        let mut output = self.clone();
+        output.glyphs = GlyphList(glyphs);
+        output.masters = vec![master];
+        for rule in &self.rules {
+            if self.rule_applies(rule, &full_location) {
+                for (glyph, alternate) in &rule.substitutions {
+                    output.swap_layers(glyph, alternate);
+                }
+            }
+        }
+        output.axes = vec![];
+        output.instances = vec![];
+        output.location_labels = vec![];
+        output.axis_mappings = vec![];
+        output.rules = vec![];
+
+        if let Some(axis) = self.axes.iter().find(|ax| ax.tag == "wght") {
+            if let Some(&value) = full_location.0.get(&axis.tag) {
+                let weight_class = ot_round(axis.designspace_to_userspace(value)).clamp(1, 1000);
+                output
+                    .custom_ot_values
+                    .retain(|v| !(v.table == "OS2" && v.field == "usWeightClass"));
+                output.set_ot_value(
+                    "OS2",
+                    "usWeightClass",
+                    OTScalar::Unsigned(weight_class as u32),
+                );
+            }
+        }
+        Ok(output)
+    }
+
+    fn rule_applies(&self, rule: &crate::Rule, location: &Location) -> bool {
+        rule.condition_sets.iter().any(|conditions| {
+            conditions.iter().all(|condition| {
+                location.0.get(&condition.axis).is_some_and(|&value| {
+                    condition.min.is_none_or(|min| value >= min)
+                        && condition.max.is_none_or(|max| value <= max)
+                })
+            })
+        })
+    }
+
+    /// Interpolates a glyph at a location, given in designspace coordinates,
+    /// from its master and intermediate layers. Its components' transforms
+    /// are interpolated along with its outline; the components are not
+    /// decomposed.
+    pub fn interpolate_glyph(
+        &self,
+        glyphname: &str,
+        location: &Location,
+    ) -> Result<Option<Layer>, Box<BabelfontError>> {
+        let glyph = match self.glyphs.get(glyphname) {
+            Some(g) => g,
+            None => return Ok(None),
+        };
+        let (instancer, _) = self.instancer(location)?;
+        Ok(instancer.glyph_layers(glyph)?.into_iter().next())
+    }
+
+    /// An instancer for a location, along with the location with any
+    /// missing axes at their default.
+    fn instancer(
+        &self,
+        location: &Location,
+    ) -> Result<(Instancer<'_>, Location), Box<BabelfontError>> {
+        let default_ix = self
+            .default_master_index()
+            .ok_or_else(|| BabelfontError::General {
+                msg: "Could not find default master".to_string(),
+            })?;
+        let mut full_location = self.default_location();
+        full_location
+            .0
+            .extend(location.0.iter().map(|(k, &v)| (k.clone(), v)));
+        let normalized = self.normalize_location(&full_location)?;
+        let instancer = Instancer {
+            font: self,
+            model: self.variation_model()?,
+            location: self
+                .axes
+                .iter()
+                .map(|axis| axis.tag.clone())
+                .zip(normalized.0.iter().copied())
+                .collect(),
+            default_ix,
+        };
+        Ok((instancer, full_location))
+    }
+
+    /// Swaps the layers of two glyphs, as a substitution would
+    fn swap_layers(&mut self, glyph: &str, alternate: &str) {
+        let position = |name: &str| self.glyphs.iter().position(|g| g.name == name);
+        if let (Some(a), Some(b)) = (position(glyph), position(alternate)) {
+            let layers = std::mem::take(&mut self.glyphs[a].layers);
+            self.glyphs[a].layers = std::mem::replace(&mut self.glyphs[b].layers, layers);
+        }
+    }
+}
+
+struct Instancer<'a> {
+    font: &'a Font,
+    model: VariationModel<String>,
+    location: OTVarLocation<String>,
+    default_ix: usize,
+}
+
+impl Instancer<'_> {
+    /// Interpolates a set of (possibly sparse) master values at the location.
+    /// Returns `None` if the default master has no value.
+    fn interpolate<U>(&self, model: &VariationModel<String>, values: &[Option<U>]) -> Option<U>
+    where
+        U: Sub<Output = U> + Mul<f32, Output = U> + Add<Output = U> + Clone + std::fmt::Debug,
+    {
+        values[self.default_ix].as_ref()?;
+        let (deltas, scalars): (Vec<U>, Vec<f32>) = model
+            .get_deltas_and_supports(values)
+            .into_iter()
+            .map(|(delta, support)| (delta, support_scalar(&self.location, &support)))
+            .unzip();
+        model.interpolate_from_deltas_and_scalars(&deltas, &scalars)
+    }
+
+    fn metrics(&self) -> HashMap<String, i32> {
+        let mut metrics = HashMap::new();
+        for name in self.font.masters[self.default_ix].metrics.keys() {
+            let values: Vec<Option<f32>> = self
+                .font
+                .masters
+                .iter()
+                .map(|m| m.metrics.get(name).map(|&v| v as f32))
+                .collect();
+            if let Some(value) = self.interpolate(&self.model, &values) {
+                metrics.insert(name.clone(), ot_round(value));
+            }
+        }
+        metrics
+    }
+
+    fn kerning(&self) -> HashMap<(String, String), i16> {
+        let pairs: BTreeSet<&(String, String)> = self
+            .font
+            .masters
+            .iter()
+            .flat_map(|m| m.kerning.keys())
+            .collect();
+        // The group each glyph is in on either side of a pair, going by the
+        // groups which are used on that side
+        let mut left_groups: HashMap<&str, &str> = HashMap::new();
+        let mut right_groups: HashMap<&str, &str> = HashMap::new();
+        for (left, right) in pairs.iter() {
+            for (group, groups) in [(left, &mut left_groups), (right, &mut right_groups)] {
+                let members = group
+                    .strip_prefix('@')
+                    .and_then(|g| self.font.kern_groups.get(g));
+                for member in members.into_iter().flatten() {
+                    groups.entry(member).or_insert(group);
+                }
+            }
+        }
+        let mut kerning = HashMap::new();
+        for &pair in pairs.iter() {
+            // A pair missing from a master means that its value comes from
+            // the glyphs' groups, or that it isn't kerned there, unless the
+            // master has no kerning at all
+            let values: Vec<Option<f32>> = self
+                .font
+                .masters
+                .iter()
+                .enumerate()
+                .map(|(ix, m)| {
+                    if m.kerning.is_empty() && ix != self.default_ix {
+                        None
+                    } else {
+                        Some(kern_value(&m.kerning, pair, &left_groups, &right_groups) as f32)
+                    }
+                })
+                .collect();
+            if let Some(value) = self.interpolate(&self.model, &values) {
+                let value = ot_round(value) as i16;
+                if value != 0 {
+                    kerning.insert(pair.clone(), value);
+                }
+            }
+        }
+        kerning
+    }
+
+    /// Interpolates the numeric OpenType values set in the default master;
+    /// any others are copied from it.
+    fn ot_values(&self) -> Vec<OTValue> {
+        self.font.masters[self.default_ix]
+            .custom_ot_values
+            .iter()
+            .map(|default| {
+                let values: Vec<Option<f32>> = self
+                    .font
+                    .masters
+                    .iter()
+                    .map(|m| match m.ot_value(&default.table, &default.field) {
+                        Some(
+                            v @ (OTScalar::Unsigned(_) | OTScalar::Signed(_) | OTScalar::Float(_)),
+                        ) => Some(f32::from(v)),
+                        _ => None,
+                    })
+                    .collect();
+                let value = match (&default.value, self.interpolate(&self.model, &values)) {
+                    (OTScalar::Unsigned(_), Some(v)) => {
+                        OTScalar::Unsigned(ot_round(v).max(0) as u32)
+                    }
+                    (OTScalar::Signed(_), Some(v)) => OTScalar::Signed(ot_round(v)),
+                    (OTScalar::Float(_), Some(v)) => OTScalar::Float(v),
+                    (value, _) => value.clone(),
+                };
+                OTValue {
+                    table: default.table.clone(),
+                    field: default.field.clone(),
+                    value,
+                }
+            })
+            .collect()
+    }
+
+    /// Interpolates a glyph's master layer, using its intermediate layers
+    /// too, and the part layers of smart component glyphs.
+    fn glyph_layers(&self, glyph: &Glyph) -> Result<Vec<Layer>, Box<BabelfontError>> {
+        let mut layers: Vec<Option<&Layer>> = self
+            .font
+            .masters
+            .iter()
+            .map(|m| glyph.get_layer(&m.id))
+            .collect();
+        let intermediates = self.font.intermediate_layers(&glyph.name);
+        let glyph_model;
+        let model = if intermediates.is_empty() {
+            &self.model
+        } else {
+            glyph_model = self.font.glyph_variation_model(&glyph.name)?;
+            layers.extend(intermediates.into_iter().map(Some));
+            &glyph_model
+        };
+        let mut output: Vec<Layer> = self
+            .interpolate_layer(&layers, model, &glyph.name)
+            .into_iter()
+            .collect();
+
+        // Part layers are matched up across masters by their location
+        let default_id = &self.font.masters[self.default_ix].id;
+        let is_part_of = |layer: &Layer, master_id: &str| {
+            !layer.is_background
+                && !layer.smart_location.0.is_empty()
+                && layer.associated_master_id.as_deref() == Some(master_id)
+        };
+        for part in glyph.layers.iter().filter(|l| is_part_of(l, default_id)) {
+            let parts: Vec<Option<&Layer>> =
+                self.font
+                    .masters
+                    .iter()
+                    .map(|m| {
+                        glyph.layers.iter().find(|l| {
+                            is_part_of(l, &m.id) && l.smart_location == part.smart_location
+                        })
+                    })
+                    .collect();
+            output.extend(self.interpolate_layer(&parts, &self.model, &glyph.name));
+        }
+        Ok(output)
+    }
+
+    /// Interpolates a layer's outline, then each of its anchors, so that
+    /// layers without an anchor count as sparse for that anchor.
+    fn interpolate_layer(
+        &self,
+        layers: &[Option<&Layer>],
+        model: &VariationModel<String>,
+        glyph_name: &str,
+    ) -> Option<Layer> {
+        let default_layer = layers[self.default_ix]?;
+        let outline = |layer: &Layer| Coordinates::from_layer(layer).without_anchors();
+        let default_outline = outline(default_layer);
+        let outlines: Vec<Option<Coordinates>> = layers.iter().map(|l| l.map(outline)).collect();
+        if outlines
+            .iter()
+            .flatten()
+            .any(|c| !default_outline.is_compatible(c))
+        {
+            log::warn!(
+                "Glyph {} is not compatible across masters; using the default master",
+                glyph_name
+            );
+            return Some(default_layer.clone());
+        }
+        let mut layer = match self.interpolate(model, &outlines) {
+            Some(coordinates) => coordinates.apply_to(default_layer),
+            None => default_layer.clone(),
+        };
+        for anchor in layer.anchors.iter_mut() {
+            let position = |layer: Option<&Layer>| {
+                layer?
+                    .anchors
+                    .iter()
+                    .find(|a| a.name == anchor.name)
+                    .map(|a| (a.x as f32, a.y as f32))
+            };
+            let xs: Vec<Option<f32>> = layers.iter().map(|&l| position(l).map(|p| p.0)).collect();
+            let ys: Vec<Option<f32>> = layers.iter().map(|&l| position(l).map(|p| p.1)).collect();
+            if let (Some(x), Some(y)) = (self.interpolate(model, &xs), self.interpolate(model, &ys))
+            {
+                anchor.x = ot_round(x);
+                anchor.y = ot_round(y);
+            }
+        }
+        Some(layer)
+    }
+}
+
+/// The value of a pair in a master's kerning. Pairs which aren't in it are
+/// looked up through the glyphs' groups, as fontMath does: glyph against
+/// glyph, then glyph against group, group against glyph, and group against
+/// group.
+fn kern_value(
+    kerning: &HashMap<(String, String), i16>,
+    (left, right): &(String, String),
+    left_groups: &HashMap<&str, &str>,
+    right_groups: &HashMap<&str, &str>,
+) -> i16 {
+    let lefts = [Some(left.as_str()), left_groups.get(left.as_str()).copied()];
+    let rights = [
+        Some(right.as_str()),
+        right_groups.get(right.as_str()).copied(),
+    ];
+    lefts
+        .iter()
+        .flatten()
+        .flat_map(|l| rights.iter().flatten().map(move |r| (*l, *r)))
+        .find_map(|(l, r)| kerning.get(&(l.to_string(), r.to_string())))
+        .copied()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::convertors::glyphs2::load;
+    use crate::{Layer, OTScalar};
+
+    #[test]
+    fn test_instantiate() {
+        let font = load("data/TwoMastersG2.glyphs".into()).unwrap();
+        let location = crate::Location([("wght".to_string(), 110.0)].into_iter().collect());
+        let instance = font.instantiate(&location).unwrap();
+        assert!(instance.axes.is_empty());
+        assert_eq!(instance.masters.len(), 1);
+        assert_eq!(instance.default_master().unwrap().id, font.masters[0].id);
+        assert_eq!(
+            instance.masters[0]
+                .kerning
+                .get(&("@MMK_L_A".to_string(), "@MMK_R_A".to_string())),
+            Some(&-25)
+        );
+        assert!(instance
+            .custom_ot_values
+            .iter()
+            .any(|v| v.field == "usWeightClass" && matches!(v.value, OTScalar::Unsigned(500))));
+
+        // The brace layer is at this location
+        let a = instance.glyphs.get("A").unwrap();
+        assert_eq!(a.layers.len(), 1);
+        let brace = &font.glyphs.get("A").unwrap().layers[2];
+        let nodes = |layer: &Layer| -> Vec<(f32, f32)> {
+            layer
+                .paths()
+                .flat_map(|p| p.nodes.iter().map(|n| (n.x, n.y)))
+                .collect()
+        };
+        assert_eq!(nodes(&a.layers[0]), nodes(brace));
+        // which has no anchors, so they come from the masters
+        assert_eq!(a.layers[0].anchors[0].y, 710);
+
+        // The bracket layer's rule applies, swapping in the alternate
+        let acutecomb = &instance.glyphs.get("acutecomb").unwrap().layers[0];
+        assert_eq!(acutecomb.paths().next().unwrap().nodes[1].x, 65.0);
+        let heavier = font
+            .instantiate(&crate::Location(
+                [("wght".to_string(), 140.0)].into_iter().collect(),
+            ))
+            .unwrap();
+        let acutecomb = &heavier.glyphs.get("acutecomb").unwrap().layers[0];
+        assert_eq!(acutecomb.paths().next().unwrap().nodes[1].x, 58.0);
+    }
+
+    #[test]
+    fn test_component_at_intermediate_location() {
+        let font = load("data/TwoMastersG2.glyphs".into()).unwrap();
+        let location = crate::Location([("wght".to_string(), 110.0)].into_iter().collect());
+        // A brace layer whose acutecomb component has no layer there
+        let mut brace = font.glyphs.get("Aacute").unwrap().layers[0].clone();
+        brace.id = Some("brace".to_string());
+        brace.associated_master_id = Some(font.masters[0].id.clone());
+        brace.location = Some(location.clone());
+        let nodes = |layer: &Layer| -> Vec<(f32, f32)> {
+            layer
+                .paths()
+                .flat_map(|p| p.nodes.iter().map(|n| (n.x, n.y)))
+                .collect()
+        };
+
+        let component = brace.components().nth(1).unwrap();
+        assert_eq!(component.reference, "acutecomb");
+        let layer = brace.component_layer(&font, component).unwrap();
+        let interpolated = font
+            .interpolate_glyph("acutecomb", &location)
+            .unwrap()
+            .unwrap();
+        assert_eq!(nodes(&layer), nodes(&interpolated));
+        for master_layer in &font.glyphs.get("acutecomb").unwrap().layers[..2] {
+            assert_ne!(nodes(&layer), nodes(master_layer));
+        }
+        // Nothing is dropped when decomposing
+        let a = &font.glyphs.get("A").unwrap().layers[2];
+        assert_eq!(
+            brace.decomposed_components(&font).len(),
+            a.paths().count() + interpolated.paths().count()
+        );
+    }
+
+    #[test]
+    fn test_kerning_exception_in_one_master() {
+        let mut font = load("data/TwoMastersG2.glyphs".into()).unwrap();
+        font.kern_groups
+            .insert("MMK_L_A".to_string(), vec!["A".to_string()]);
+        font.kern_groups
+            .insert("MMK_R_V".to_string(), vec!["V".to_string()]);
+        let groups = ("@MMK_L_A".to_string(), "@MMK_R_V".to_string());
+        let exception = ("A".to_string(), "V".to_string());
+        font.masters[0].kerning = [(groups.clone(), -50)].into_iter().collect();
+        font.masters[1].kerning = [(groups.clone(), -70), (exception.clone(), -80)]
+            .into_iter()
+            .collect();
+
+        let location = crate::Location([("wght".to_string(), 110.0)].into_iter().collect());
+        let instance = font.instantiate(&location).unwrap();
+        let kerning = &instance.masters[0].kerning;
+        assert_eq!(kerning.get(&groups), Some(&-60));
+        // The light master kerns A V through its groups
+        assert_eq!(kerning.get(&exception), Some(&-65));
+    }
+}
@@ -4,10 +4,13 @@ use crate::guide::Guide;
 use crate::shape::Shape;
 use crate::{BabelfontError, Component, Font, Node, Path};
 use kurbo::Shape as KurboShape;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub width: i32,
+    pub height: Option<i32>,
+    pub vertical_origin: Option<i32>,
     pub name: Option<String>,
     pub id: Option<String>,
     pub guides: Vec<Guide>,
@@ -18,12 +21,19 @@ pub struct Layer {
     pub is_background: bool,
     pub background_layer_id: Option<String>,
     pub location: Option<Location>,
+    /// For layers which are not master layers, the master they belong to
+    pub associated_master_id: Option<String>,
+    /// For part layers of smart component glyphs, the values of the
+    /// glyph's smart axes at which the layer applies
+    pub smart_location: Location,
 }
 
 impl Layer {
     pub fn new(width: i32) -> Layer {
         Layer {
             width,
+            height: None,
+            vertical_origin: None,
             name: None,
             id: None,
             guides: vec![],
@@ -34,6 +44,8 @@ impl Layer {
             is_background: false,
             background_layer_id: None,
             location: None,
+            associated_master_id: None,
+            smart_location: Location::new(),
         }
     }
 
@@ -97,6 +109,8 @@ impl Layer {
             .map(Shape::PathShape);
         Layer {
             width: self.width,
+            height: self.height,
+            vertical_origin: self.vertical_origin,
             name: self.name.clone(),
             id: self.id.clone(),
             guides: self.guides.clone(),
@@ -106,6 +120,8 @@ impl Layer {
             is_background: self.is_background,
             background_layer_id: self.background_layer_id.clone(),
             location: self.location.clone(),
+            associated_master_id: self.associated_master_id.clone(),
+            smart_location: self.smart_location.clone(),
             shapes: self
                 .shapes
                 .iter()
@@ -118,44 +134,97 @@ impl Layer {
 
     pub fn decomposed_components(&self, font: &Font) -> Vec<Path> {
         let mut contours = Vec::new();
-
-        let mut stack: Vec<(&Component, kurbo::Affine)> = Vec::new();
         for component in self.components() {
-            stack.push((component, component.transform));
-            while let Some((component, transform)) = stack.pop() {
-                let referenced_glyph = match font.glyphs.get(&component.reference) {
-                    Some(g) => g,
-                    None => continue,
-                };
-                let new_outline = match referenced_glyph.get_layer(self.id.as_ref().unwrap()) {
-                    Some(g) => g,
-                    None => continue,
-                };
-
-                for contour in new_outline.paths() {
-                    let mut decomposed_contour = Path::default();
-                    for node in &contour.nodes {
-                        let new_point = transform * kurbo::Point::new(node.x as f64, node.y as f64);
-                        decomposed_contour.nodes.push(Node {
-                            x: new_point.x as f32,
-                            y: new_point.y as f32,
-                            nodetype: node.nodetype,
-                        })
-                    }
-                    decomposed_contour.closed = contour.closed;
-                    contours.push(decomposed_contour);
-                }
-
-                // Depth-first decomposition means we need to extend the stack reversed, so
-                // the first component is taken out next.
-                for new_component in new_outline.components().rev() {
-                    let new_transform: kurbo::Affine = new_component.transform;
-                    stack.push((new_component, transform * new_transform));
-                }
+            self.decompose_component(font, component, component.transform, &mut contours);
+        }
+        contours
+    }
+
+    // Depth-first, so that the contours of each component come before those
+    // of the components it contains
+    fn decompose_component(
+        &self,
+        font: &Font,
+        component: &Component,
+        transform: kurbo::Affine,
+        contours: &mut Vec<Path>,
+    ) {
+        let new_outline = match self.component_layer(font, component) {
+            Some(l) => l,
+            None => return,
+        };
+        for contour in new_outline.paths() {
+            let mut decomposed_contour = Path::default();
+            for node in &contour.nodes {
+                let new_point = transform * kurbo::Point::new(node.x as f64, node.y as f64);
+                decomposed_contour.nodes.push(Node {
+                    x: new_point.x as f32,
+                    y: new_point.y as f32,
+                    nodetype: node.nodetype,
+                    smooth: node.smooth,
+                })
             }
+            decomposed_contour.closed = contour.closed;
+            contours.push(decomposed_contour);
+        }
+        for new_component in new_outline.components() {
+            self.decompose_component(
+                font,
+                new_component,
+                transform * new_component.transform,
+                contours,
+            );
         }
+    }
 
-        contours
+    /// The layer of a component's glyph which corresponds to this layer:
+    /// the layer with the same ID, or for layers which are not master
+    /// layers, the one at the same location. If the glyph has no layer at
+    /// that location, it is interpolated there. Smart components are
+    /// interpolated at the component's smart location.
+    pub fn component_layer<'a>(
+        &self,
+        font: &'a Font,
+        component: &Component,
+    ) -> Option<Cow<'a, Layer>> {
+        let glyph = font.glyphs.get(&component.reference)?;
+        if glyph.is_smart() {
+            let master_id = self
+                .associated_master_id
+                .as_deref()
+                .or(self.id.as_deref())?;
+            return glyph
+                .smart_layer(master_id, &component.smart_location)
+                .map(Cow::Owned);
+        }
+        if let Some(layer) = self.id.as_deref().and_then(|id| glyph.get_layer(id)) {
+            return Some(Cow::Borrowed(layer));
+        }
+        let location = match self.location.as_ref() {
+            Some(location) => location,
+            None => {
+                log::warn!(
+                    "Component glyph {} has no layer matching layer {:?}",
+                    glyph.name,
+                    self.id
+                );
+                return None;
+            }
+        };
+        if let Some(layer) = glyph
+            .layers
+            .iter()
+            .find(|l| l.location.as_ref() == Some(location))
+        {
+            return Some(Cow::Borrowed(layer));
+        }
+        match font.interpolate_glyph(&glyph.name, location) {
+            Ok(layer) => layer.map(Cow::Owned),
+            Err(e) => {
+                log::warn!("Couldn't interpolate component glyph {}: {}", glyph.name, e);
+                None
+            }
+        }
     }
 
     pub fn bounds(&self) -> Result<kurbo::Rect, BabelfontError> {
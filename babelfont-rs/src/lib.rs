@@ -5,27 +5,31 @@ mod anchor;
 mod axis;
 mod common;
 pub mod convertors;
+mod editing;
 mod error;
 mod font;
 mod glyph;
 mod guide;
 mod i18ndictionary;
 mod instance;
+mod instantiate;
 mod layer;
 mod master;
 pub mod names;
+mod rule;
 mod shape;
 
 pub use crate::anchor::Anchor;
-pub use crate::axis::Axis;
+pub use crate::axis::{Axis, AxisLabel, LocationLabel};
 pub use crate::common::{Location, Node, NodeType, OTScalar, Position};
 pub use crate::error::BabelfontError;
 pub use crate::font::Font;
-pub use crate::glyph::{Glyph, GlyphCategory, GlyphList};
+pub use crate::glyph::{Glyph, GlyphCategory, GlyphList, SmartAxis};
 pub use crate::guide::Guide;
 pub use crate::instance::Instance;
 pub use crate::layer::Layer;
 pub use crate::master::Master;
+pub use crate::rule::{Condition, Rule};
 pub use crate::shape::{Component, Path, PathDirection, Shape};
 use std::path::PathBuf;
 
@@ -38,7 +42,33 @@ pub fn load(filename: &str) -> Result<Font, BabelfontError> {
     } else if filename.ends_with(".ufo") {
         crate::convertors::ufo::load(pb)
     } else if filename.ends_with(".glyphs") {
-        crate::convertors::glyphs3::load(pb)
+        // Only Glyphs 3 files declare a format version
+        let plist = crate::convertors::glyphs3::read_plist(&pb)?;
+        let font = if plist.get(".formatVersion").is_some() {
+            crate::convertors::glyphs3::load_plist(&plist)
+        } else {
+            crate::convertors::glyphs2::load_plist(&plist)
+        };
+        std::mem::forget(plist);
+        font
+    } else {
+        Err(BabelfontError::UnknownFileType { path: pb })
+    }
+}
+
+/// Saves a font to a UFO, designspace or Glyphs 3 file, based on the file
+/// extension.
+///
+/// Saving to a designspace file writes a UFO for each master alongside it;
+/// saving a multi-master font to a single UFO only keeps the default master.
+pub fn save(font: &Font, filename: &str) -> Result<(), BabelfontError> {
+    let pb = PathBuf::from(filename);
+    if filename.ends_with(".designspace") {
+        crate::convertors::designspace::save(font, pb)
+    } else if filename.ends_with(".ufo") {
+        crate::convertors::ufo::save(font, pb)
+    } else if filename.ends_with(".glyphs") {
+        crate::convertors::glyphs3::save(font, pb)
     } else {
         Err(BabelfontError::UnknownFileType { path: pb })
     }
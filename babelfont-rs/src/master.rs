@@ -11,9 +11,11 @@ pub struct Master {
     pub location: Location,
     pub guides: Vec<Guide>,
     pub metrics: HashMap<String, i32>,
+    /// The overshoots of the alignment zones at some of the metrics
+    pub overshoots: HashMap<String, i32>,
     pub kerning: HashMap<(String, String), i16>,
     pub custom_ot_values: Vec<OTValue>,
-    // lib
+    pub lib: plist::Dictionary,
 }
 
 impl Master {
@@ -28,8 +30,10 @@ impl Master {
             location,
             guides: vec![],
             metrics: HashMap::new(),
+            overshoots: HashMap::new(),
             kerning: HashMap::new(),
             custom_ot_values: vec![],
+            lib: plist::Dictionary::new(),
         }
     }
 
@@ -30,4 +30,6 @@ pub struct Names {
     pub style_map_family_name: I18NDictionary,
     pub style_map_style_name: Option<StyleMapStyle>,
     pub trademark: I18NDictionary,
+    /// The PostScript name, if it isn't derived from the family and style
+    pub postscript_name: I18NDictionary,
 }
@@ -0,0 +1,19 @@
+/// A set of glyph substitutions which apply within a region of the
+/// designspace, such as a designspace `<rule>`.
+#[derive(Debug, Clone, Default)]
+pub struct Rule {
+    pub name: Option<String>,
+    /// The rule applies if all the conditions in any one set are met
+    pub condition_sets: Vec<Vec<Condition>>,
+    /// Pairs of (glyph, replacement glyph)
+    pub substitutions: Vec<(String, String)>,
+}
+
+/// A range on an axis, in designspace coordinates. A missing end of the
+/// range is unbounded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub axis: String,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
@@ -1,4 +1,4 @@
-use crate::common::{Node, NodeType};
+use crate::common::{Location, Node, NodeType};
 use crate::BabelfontError;
 
 #[derive(Debug, Clone, Copy)]
@@ -11,10 +11,17 @@ impl Default for PathDirection {
         PathDirection::Clockwise
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Component {
     pub reference: String,
     pub transform: kurbo::Affine,
+    /// The anchor of the base glyph to which the component is attached
+    pub anchor: Option<String>,
+    /// How the component is automatically aligned, as stored by Glyphs
+    /// (-1 if alignment is disabled)
+    pub alignment: Option<i32>,
+    /// For smart components, the values of the referenced glyph's smart axes
+    pub smart_location: Location,
 }
 
 #[derive(Debug, Clone, Default)]
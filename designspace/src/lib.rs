@@ -1,38 +1,97 @@
-//! A library for parsing variable font designspace files
+//! A library for reading and writing variable font designspace files
 #![warn(missing_docs, rustdoc::missing_crate_level_docs)]
 
 use fonttools::font::Font;
-use fonttools::otvar::NormalizedLocation;
+use fonttools::otvar::merger::merge_masters;
+use fonttools::otvar::{Location as FontLocation, NormalizedLocation};
 use fonttools::tables::avar::{avar, SegmentMap};
 use fonttools::tables::fvar::{fvar, InstanceRecord, VariationAxisRecord};
 use fonttools::tables::name::NameRecord;
+use fonttools::tables::STAT::{AxisRecord, STAT};
 use fonttools::types::Tag;
 use otmath::{normalize_value, piecewise_linear_map, Location as OTVarLocation, VariationModel};
-use serde::{Deserialize, Serialize};
-pub use serde_xml_rs::from_reader;
+use quick_xml::events::Event;
+pub use quick_xml::DeError;
+use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
+use std::error::Error;
 use std::fs::File;
+use std::io::{BufReader, Read};
 #[cfg(feature = "norad")]
 use std::path::Path;
 
+mod plist_lib;
+
 /// Loads and parses a designspace file
-pub fn from_file(filename: &str) -> Result<Designspace, serde_xml_rs::Error> {
-    from_reader(File::open(filename).unwrap())
+pub fn from_file(filename: &str) -> Result<Designspace, DeError> {
+    let file = File::open(filename).map_err(|e| DeError::InvalidXml(e.into()))?;
+    from_reader(file)
+}
+
+/// Parses a designspace file from a reader
+pub fn from_reader<R: Read>(reader: R) -> Result<Designspace, DeError> {
+    quick_xml::de::from_reader(BufReader::new(reader))
+}
+
+/// Serializes a designspace to a string of XML
+pub fn to_string(designspace: &Designspace) -> Result<String, DeError> {
+    let mut compact = String::new();
+    designspace.serialize(quick_xml::se::Serializer::with_root(
+        &mut compact,
+        Some("designspace"),
+    )?)?;
+    // The serializer's own indentation puts text content on a line of its
+    // own, which would change the names in <labelname> and friends, so we
+    // indent with a writer instead.
+    let mut reader = quick_xml::Reader::from_str(&compact);
+    let mut writer = quick_xml::Writer::new_with_indent(vec![], b' ', 2);
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+            event => writer.write_event(event)?,
+        }
+    }
+    let xml = String::from_utf8(writer.into_inner()).map_err(|e| DeError::Custom(e.to_string()))?;
+    Ok(format!("<?xml version='1.0' encoding='UTF-8'?>\n{}\n", xml))
+}
+
+/// Writes a designspace file
+pub fn to_file(designspace: &Designspace, filename: &str) -> Result<(), DeError> {
+    std::fs::write(filename, to_string(designspace)?).map_err(|e| DeError::InvalidXml(e.into()))
+}
+
+fn serialize_format<S: Serializer>(format: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    // Readers compare the version as a tuple, so always write a minor version
+    serializer.serialize_str(&format!("{:.1}", format))
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename = "designspace")]
 /// A designspace object
 pub struct Designspace {
-    /// The format of this designspace file (we support 2 and 3)
+    /// The format of this designspace file (we support 2 to 5)
+    #[serde(rename = "@format", serialize_with = "serialize_format")]
     pub format: f32,
     /// An axes element (contains individual axes)
     pub axes: Axes,
+    /// A labels element (optional, format 5; contains location labels)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<LocationLabels>,
+    /// A rules element (optional, contains glyph substitution rules)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Rules>,
     /// An sources element (contains individual sources)
     pub sources: Sources,
+    /// A variable-fonts element (optional, format 5; describes the variable
+    /// fonts to be built from subsets of the designspace)
+    #[serde(rename = "variable-fonts", skip_serializing_if = "Option::is_none")]
+    pub variable_fonts: Option<VariableFonts>,
     /// An instance element (optional, contains individual instances)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub instances: Option<Instances>,
-    // pub rules: Rules,
+    /// Arbitrary custom data
+    #[serde(default, with = "plist_lib", skip_serializing_if = "Option::is_none")]
+    pub lib: Option<plist::Dictionary>,
 }
 
 impl Designspace {
@@ -80,11 +139,13 @@ impl Designspace {
         let mut instances: Vec<InstanceRecord> = vec![];
         if let Some(i) = &self.instances {
             for instance in &i.instance {
-                name.records
-                    .push(NameRecord::windows_unicode(ix, instance.stylename.clone()));
+                name.records.push(NameRecord::windows_unicode(
+                    ix,
+                    instance.stylename.clone().unwrap_or_default(),
+                ));
                 let mut ir = InstanceRecord {
                     subfamilyNameID: ix,
-                    coordinates: self.location_to_tuple(&instance.location),
+                    coordinates: self.instance_location(instance),
                     postscriptNameID: None,
                     flags: 0,
                 };
@@ -104,12 +165,61 @@ impl Designspace {
         font.tables.insert(name);
 
         // Handle avar here
-        let avar_table = avar { maps };
+        let avar_table = avar {
+            maps,
+            axis_index_map: None,
+            var_store: None,
+        };
         font.tables.insert(avar_table);
 
         Ok(())
     }
 
+    /// Builds a variable font from a set of compiled master fonts, one for
+    /// each source in this designspace (and in the same order).
+    ///
+    /// The masters are merged into `gvar`, `HVAR`, `MVAR` and `GPOS`
+    /// variations, and then the `fvar`, `avar` and `STAT` tables are added.
+    pub fn build_variable_font(&self, masters: Vec<Font>) -> Result<Font, Box<dyn Error>> {
+        let axis_order: Vec<Tag> = self.axes.axis.iter().map(|ax| ax.tag_as_tag()).collect();
+        let locations: Vec<FontLocation<Tag>> = self
+            .sources
+            .source
+            .iter()
+            .map(|source| {
+                let loc = self.normalize_location(self.source_location(source));
+                axis_order.iter().copied().zip(loc.0).collect()
+            })
+            .collect();
+        let mut font = merge_masters(masters, locations, axis_order)?;
+        self.add_to_font(&mut font)?;
+
+        let design_axes = font
+            .tables
+            .fvar()?
+            .ok_or("No fvar table?")?
+            .axes
+            .iter()
+            .enumerate()
+            .zip(self.axes.axis.iter())
+            .map(|((ordering, axis), ds_axis)| AxisRecord {
+                axisTag: axis.axisTag,
+                axisNameID: axis.axisNameID,
+                axisOrdering: ds_axis
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.ordering)
+                    .unwrap_or(ordering as u16),
+            })
+            .collect();
+        font.tables.insert(STAT {
+            elided_fallback_name_id: Some(2),
+            design_axes,
+            axis_values: vec![],
+        });
+        Ok(font)
+    }
+
     /// Returns a mapping between axis tags and their names
     pub fn tag_to_name(&self) -> HashMap<Tag, String> {
         let mut hm = HashMap::new();
@@ -120,40 +230,71 @@ impl Designspace {
     }
 
     /// Returns the default master location in userspace coordinates
-    pub fn default_location(&self) -> Vec<i32> {
+    pub fn default_location(&self) -> Vec<f32> {
         self.axes.axis.iter().map(|ax| ax.default).collect()
     }
 
     /// Returns the default master location in designspace coordinates
-    pub fn default_designspace_location(&self) -> Vec<i32> {
+    pub fn default_designspace_location(&self) -> Vec<f32> {
         self.axes
             .axis
             .iter()
-            .map(|ax| ax.userspace_to_designspace(ax.default) as i32)
+            .map(|ax| ax.userspace_to_designspace(ax.default))
             .collect()
     }
 
     /// Returns the location of a given source object in design space coordinates
-    pub fn source_location(&self, source: &Source) -> Vec<i32> {
+    pub fn source_location(&self, source: &Source) -> Vec<f32> {
         self.location_to_tuple(&source.location)
-            .iter()
-            .map(|x| *x as i32)
-            .collect()
     }
 
-    /// Converts a location to a tuple
-    pub fn location_to_tuple(&self, loc: &Location) -> Vec<f32> {
-        let mut tuple = vec![];
-        for (axis, default) in self.axes.axis.iter().zip(self.default_location().iter()) {
-            let name = &axis.name;
-            let dim = loc.dimension.iter().find(|d| d.name == *name);
-            if let Some(dim) = dim {
-                tuple.push(dim.xvalue);
-            } else {
-                tuple.push(*default as f32);
-            }
+    /// Returns the location of a given instance in design space coordinates.
+    ///
+    /// In format 5, an instance may refer to a location label by name instead
+    /// of giving its location directly.
+    pub fn instance_location(&self, instance: &Instance) -> Vec<f32> {
+        if let Some(location) = &instance.location {
+            return self.location_to_tuple(location);
         }
-        tuple
+        let label = instance.location_label.as_ref().and_then(|name| {
+            self.labels
+                .as_ref()
+                .and_then(|labels| labels.label.iter().find(|l| &l.name == name))
+        });
+        if let Some(label) = label {
+            return self
+                .axes
+                .axis
+                .iter()
+                .map(|axis| {
+                    let value = label
+                        .location
+                        .dimension
+                        .iter()
+                        .find(|d| d.name == axis.name)
+                        .map_or(axis.default, |d| d.uservalue);
+                    axis.userspace_to_designspace(value)
+                })
+                .collect();
+        }
+        self.default_designspace_location()
+    }
+
+    /// Converts a location to a tuple of designspace coordinates
+    pub fn location_to_tuple(&self, loc: &Location) -> Vec<f32> {
+        self.axes
+            .axis
+            .iter()
+            .map(|axis| {
+                loc.dimension
+                    .iter()
+                    .find(|d| d.name == axis.name)
+                    .map_or_else(
+                        || axis.userspace_to_designspace(axis.default),
+                        |dim| dim.designspace_value(axis),
+                    )
+            })
+            .collect()
     }
 
     /// Returns the Source object for the master at default axis coordinates,
@@ -167,13 +308,15 @@ impl Designspace {
     }
 
     /// Normalizes a location between -1.0 and 1.0
-    pub fn normalize_location(&self, loc: Vec<i32>) -> NormalizedLocation {
-        let mut v: Vec<f32> = vec![];
-        for (ax, iter_l) in self.axes.axis.iter().zip(loc.iter()) {
-            let l = *iter_l;
-            v.push(ax.normalize_designspace_value(l as f32));
-        }
-        NormalizedLocation(v)
+    pub fn normalize_location(&self, loc: Vec<f32>) -> NormalizedLocation {
+        NormalizedLocation(
+            self.axes
+                .axis
+                .iter()
+                .zip(loc.iter())
+                .map(|(ax, l)| ax.normalize_designspace_value(*l))
+                .collect(),
+        )
     }
 
     /// Constructs a fonttools variation model for this designspace
@@ -198,8 +341,17 @@ impl Designspace {
 #[serde(rename = "axes")]
 /// A collection of axes
 pub struct Axes {
+    /// The style name to use when all labels are elidable (format 5)
+    #[serde(
+        rename = "@elidedfallbackname",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub elidedfallbackname: Option<String>,
     /// A vector of axes
     pub axis: Vec<Axis>,
+    /// A mappings element (optional, format 5; contains cross-axis mappings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mappings: Option<Mappings>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -207,21 +359,35 @@ pub struct Axes {
 /// A single axis
 pub struct Axis {
     /// Axis name (user-facing)
+    #[serde(rename = "@name")]
     pub name: String,
     /// Axis tag (internal; four bytes)
+    #[serde(rename = "@tag")]
     pub tag: String,
-    /// Axis minimum value
-    pub minimum: i32,
-    /// Axis maximum value
-    pub maximum: i32,
+    /// Axis minimum value (absent for discrete axes)
+    #[serde(rename = "@minimum", skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f32>,
+    /// Axis maximum value (absent for discrete axes)
+    #[serde(rename = "@maximum", skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f32>,
+    /// The values of a discrete axis (format 5)
+    #[serde(rename = "@values", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<f32>>,
     /// Axis default value
-    pub default: i32,
+    #[serde(rename = "@default")]
+    pub default: f32,
     /// Whether the axis should be exposed to the user
+    #[serde(rename = "@hidden", skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
     /// Internationalized name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub labelname: Option<Vec<LabelName>>,
     /// Mapping between userspace and designspace values
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<Vec<Mapping>>,
+    /// A labels element (optional, format 5; contains axis value labels)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<AxisLabels>,
 }
 
 impl Axis {
@@ -231,25 +397,43 @@ impl Axis {
         }
         Ok(VariationAxisRecord {
             axisTag: Tag::from_raw(&self.tag).unwrap(),
-            defaultValue: self.default as f32,
-            maxValue: self.maximum as f32,
-            minValue: self.minimum as f32,
+            defaultValue: self.default,
+            maxValue: self.max_value(),
+            minValue: self.min_value(),
             flags: u16::from(self.hidden.unwrap_or(false)),
             axisNameID: name_id,
         })
     }
 
+    /// Whether this is a discrete axis, with values rather than a range
+    pub fn is_discrete(&self) -> bool {
+        self.values.is_some()
+    }
+
+    /// The minimum value of the axis in userspace coordinates
+    pub fn min_value(&self) -> f32 {
+        self.minimum
+            .or_else(|| self.values.as_ref()?.iter().copied().reduce(f32::min))
+            .unwrap_or(self.default)
+    }
+
+    /// The maximum value of the axis in userspace coordinates
+    pub fn max_value(&self) -> f32 {
+        self.maximum
+            .or_else(|| self.values.as_ref()?.iter().copied().reduce(f32::max))
+            .unwrap_or(self.default)
+    }
+
     fn default_map(&self) -> Vec<(f32, f32)> {
-        // These things should be f32 anyway
         vec![
-            (self.minimum as f32, self.minimum as f32),
-            (self.default as f32, self.default as f32),
-            (self.maximum as f32, self.maximum as f32),
+            (self.min_value(), self.min_value()),
+            (self.default, self.default),
+            (self.max_value(), self.max_value()),
         ]
     }
 
     /// Converts a position on this axis in userspace coordinates to designspace coordinates
-    pub fn userspace_to_designspace(&self, l: i32) -> f32 {
+    pub fn userspace_to_designspace(&self, l: f32) -> f32 {
         let mapping: Vec<(f32, f32)> = self.map.as_ref().map_or_else(
             || self.default_map(),
             |map| {
@@ -258,11 +442,11 @@ impl Axis {
                     .collect()
             },
         );
-        piecewise_linear_map(&mapping, l as f32)
+        piecewise_linear_map(&mapping, l)
     }
 
     /// Converts a position on this axis from designspace coordinates to userspace coordinates
-    pub fn designspace_to_userspace(&self, l: i32) -> f32 {
+    pub fn designspace_to_userspace(&self, l: f32) -> f32 {
         let mapping: Vec<(f32, f32)> = self.map.as_ref().map_or_else(
             || self.default_map(),
             |map| {
@@ -272,17 +456,12 @@ impl Axis {
             },
         );
 
-        piecewise_linear_map(&mapping, l as f32)
+        piecewise_linear_map(&mapping, l)
     }
 
     /// Normalize user space value to the range [-1.0, 1.0].
     pub fn normalize_userspace_value(&self, l: f32) -> f32 {
-        normalize_value(
-            l,
-            self.minimum as f32,
-            self.maximum as f32,
-            self.default as f32,
-        )
+        normalize_value(l, self.min_value(), self.max_value(), self.default)
     }
 
     fn tag_as_tag(&self) -> Tag {
@@ -296,21 +475,23 @@ impl Axis {
         }
 
         normalize_value(
-            self.designspace_to_userspace(l as i32),
-            self.minimum as f32,
-            self.maximum as f32,
-            self.default as f32,
+            self.designspace_to_userspace(l),
+            self.min_value(),
+            self.max_value(),
+            self.default,
         )
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-/// A name record for internationalization of an axis
+/// A name record for internationalization of an axis, label or instance
 pub struct LabelName {
     /// A language string
+    // quick-xml drops the namespace prefix when reading
+    #[serde(rename = "@xml:lang", alias = "@lang")]
     pub lang: String,
-    /// The axis's name in that language
-    #[serde(rename = "$value")]
+    /// The name in that language
+    #[serde(rename = "$text")]
     pub value: String,
 }
 
@@ -318,11 +499,177 @@ pub struct LabelName {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Mapping {
     /// The value in userspace coordinates
+    #[serde(rename = "@input")]
     pub input: f32,
     /// Its equivalent in userspace coordinates
+    #[serde(rename = "@output")]
     pub output: f32,
 }
 
+/// A collection of axis value labels
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AxisLabels {
+    /// The position of the axis in the `STAT` table's axis ordering
+    #[serde(rename = "@ordering", skip_serializing_if = "Option::is_none")]
+    pub ordering: Option<u16>,
+    /// A vector of labels
+    pub label: Vec<AxisLabel>,
+}
+
+/// A name for a value or range on an axis, used to build the `STAT` table.
+/// All values are in userspace coordinates.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AxisLabel {
+    /// The (English) name of this label
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The value on the axis
+    #[serde(rename = "@uservalue")]
+    pub uservalue: f32,
+    /// The minimum of the range this label applies to
+    #[serde(rename = "@userminimum", skip_serializing_if = "Option::is_none")]
+    pub userminimum: Option<f32>,
+    /// The maximum of the range this label applies to
+    #[serde(rename = "@usermaximum", skip_serializing_if = "Option::is_none")]
+    pub usermaximum: Option<f32>,
+    /// The value of the style-linked label (e.g. Bold for Regular)
+    #[serde(rename = "@linkeduservalue", skip_serializing_if = "Option::is_none")]
+    pub linkeduservalue: Option<f32>,
+    /// Whether the name can be omitted when composing a style name
+    #[serde(rename = "@elidable", skip_serializing_if = "Option::is_none")]
+    pub elidable: Option<bool>,
+    /// Whether this label also applies to older fonts in the family
+    #[serde(rename = "@oldersibling", skip_serializing_if = "Option::is_none")]
+    pub oldersibling: Option<bool>,
+    /// Internationalized names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labelname: Option<Vec<LabelName>>,
+}
+
+/// A collection of location labels
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LocationLabels {
+    /// A vector of labels
+    pub label: Vec<LocationLabel>,
+}
+
+/// A name for a location involving several axes, used to build the `STAT`
+/// table.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LocationLabel {
+    /// The (English) name of this label
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// Whether the name can be omitted when composing a style name
+    #[serde(rename = "@elidable", skip_serializing_if = "Option::is_none")]
+    pub elidable: Option<bool>,
+    /// Whether this label also applies to older fonts in the family
+    #[serde(rename = "@oldersibling", skip_serializing_if = "Option::is_none")]
+    pub oldersibling: Option<bool>,
+    /// The location, in userspace coordinates
+    pub location: UserLocation,
+    /// Internationalized names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labelname: Option<Vec<LabelName>>,
+}
+
+/// A location element given in userspace coordinates
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserLocation {
+    /// A vector of location components (dimensions)
+    pub dimension: Vec<UserDimension>,
+}
+
+/// An individual location component given in userspace coordinates
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserDimension {
+    /// The name of the axis (not the axis tag!)
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The value on the axis
+    #[serde(rename = "@uservalue")]
+    pub uservalue: f32,
+}
+
+/// A collection of cross-axis mappings
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Mappings {
+    /// A vector of mappings
+    pub mapping: Vec<AxisMapping>,
+}
+
+/// A mapping from one designspace location to another, used to build an
+/// `avar` version 2 table. Both locations are in designspace coordinates;
+/// axes not mentioned are at their default.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AxisMapping {
+    /// The input location
+    pub input: Location,
+    /// The location it is mapped to
+    pub output: Location,
+}
+
+/// A collection of glyph substitution rules
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Rules {
+    /// Whether the rules should be applied "first" (before other
+    /// substitutions; the default) or "last"
+    #[serde(rename = "@processing", skip_serializing_if = "Option::is_none")]
+    pub processing: Option<String>,
+    /// A vector of rules
+    pub rule: Vec<Rule>,
+}
+
+/// A set of glyph substitutions which apply within a region of the designspace
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Rule {
+    /// The name of this rule
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The regions of the designspace in which the rule applies; the rule
+    /// applies if any one of the condition sets is met
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditionset: Option<Vec<ConditionSet>>,
+    /// Conditions given directly inside the rule (format 3 and earlier),
+    /// treated as a single condition set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Vec<Condition>>,
+    /// The substitutions to make
+    pub sub: Vec<Substitution>,
+}
+
+/// A set of conditions, all of which must be met
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConditionSet {
+    /// A vector of conditions
+    pub condition: Vec<Condition>,
+}
+
+/// A range on an axis, in designspace coordinates
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Condition {
+    /// The name of the axis (not the axis tag!)
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The minimum of the range (unbounded if absent)
+    #[serde(rename = "@minimum", skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f32>,
+    /// The maximum of the range (unbounded if absent)
+    #[serde(rename = "@maximum", skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f32>,
+}
+
+/// A single glyph substitution
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Substitution {
+    /// The glyph to substitute
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The glyph to substitute it with
+    #[serde(rename = "@with")]
+    pub with: String,
+}
+
 /// A collection of source descriptors
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Sources {
@@ -334,15 +681,23 @@ pub struct Sources {
 /// An individual source descriptor
 pub struct Source {
     /// The family name for this source
+    #[serde(rename = "@familyname", skip_serializing_if = "Option::is_none")]
     pub familyname: Option<String>,
     /// The stylename for this source
+    #[serde(rename = "@stylename", skip_serializing_if = "Option::is_none")]
     pub stylename: Option<String>,
     /// The complete name for this source
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// The filename for this source
+    #[serde(rename = "@filename")]
     pub filename: String,
     /// The name of the layer in the source to look for outline data
+    #[serde(rename = "@layer", skip_serializing_if = "Option::is_none")]
     pub layer: Option<String>,
+    /// Internationalized family names
+    #[serde(rename = "familyname", skip_serializing_if = "Option::is_none")]
+    pub localised_familyname: Option<Vec<LabelName>>,
     /// The location of this source within the coordinates
     pub location: Location,
 }
@@ -361,9 +716,10 @@ impl Source {
 }
 
 /// A location element
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Location {
     /// A vector of location components (dimensions)
+    #[serde(default)]
     pub dimension: Vec<Dimension>,
 }
 
@@ -371,11 +727,83 @@ pub struct Location {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Dimension {
     /// The name of the axis (not the axis tag!)
+    #[serde(rename = "@name")]
     pub name: String,
-    /// The value on the axis
-    pub xvalue: f32,
+    /// The value on the axis in designspace coordinates
+    #[serde(rename = "@xvalue", skip_serializing_if = "Option::is_none")]
+    pub xvalue: Option<f32>,
     /// Separate value for anisotropic interpolations
+    #[serde(rename = "@yvalue", skip_serializing_if = "Option::is_none")]
     pub yvalue: Option<f32>,
+    /// The value on the axis in userspace coordinates (format 5; used if
+    /// there is no `xvalue`)
+    #[serde(rename = "@uservalue", skip_serializing_if = "Option::is_none")]
+    pub uservalue: Option<f32>,
+}
+
+impl Dimension {
+    /// Returns the value of this dimension on the given axis in designspace
+    /// coordinates
+    pub fn designspace_value(&self, axis: &Axis) -> f32 {
+        self.xvalue.unwrap_or_else(|| {
+            axis.userspace_to_designspace(self.uservalue.unwrap_or(axis.default))
+        })
+    }
+}
+
+/// A collection of variable font descriptors
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VariableFonts {
+    /// A vector of variable font descriptors
+    #[serde(rename = "variable-font")]
+    pub variable_font: Vec<VariableFont>,
+}
+
+/// A variable font to be built from part of the designspace
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VariableFont {
+    /// The name of this variable font
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The filename for this variable font
+    #[serde(rename = "@filename", skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// The axes (or parts of axes) included in this font
+    #[serde(rename = "axis-subsets")]
+    pub axis_subsets: AxisSubsets,
+    /// Arbitrary custom data
+    #[serde(default, with = "plist_lib", skip_serializing_if = "Option::is_none")]
+    pub lib: Option<plist::Dictionary>,
+}
+
+/// A collection of axis subsets
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AxisSubsets {
+    /// A vector of axis subsets
+    #[serde(rename = "axis-subset")]
+    pub axis_subset: Vec<AxisSubset>,
+}
+
+/// The part of an axis included in a variable font. Either a range (which
+/// defaults to the whole axis) or, for discrete axes, a single value. All
+/// values are in userspace coordinates.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AxisSubset {
+    /// The name of the axis (not the axis tag!)
+    #[serde(rename = "@name")]
+    pub name: String,
+    /// The minimum of the range
+    #[serde(rename = "@userminimum", skip_serializing_if = "Option::is_none")]
+    pub userminimum: Option<f32>,
+    /// The default of the range
+    #[serde(rename = "@userdefault", skip_serializing_if = "Option::is_none")]
+    pub userdefault: Option<f32>,
+    /// The maximum of the range
+    #[serde(rename = "@usermaximum", skip_serializing_if = "Option::is_none")]
+    pub usermaximum: Option<f32>,
+    /// A single value on a discrete axis
+    #[serde(rename = "@uservalue", skip_serializing_if = "Option::is_none")]
+    pub uservalue: Option<f32>,
 }
 
 /// A collection of instances
@@ -386,30 +814,72 @@ pub struct Instances {
 }
 
 /// An individual instance descriptor
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Instance {
     /// The family name of this instance
-    pub familyname: String,
+    #[serde(rename = "@familyname", skip_serializing_if = "Option::is_none")]
+    pub familyname: Option<String>,
     /// The style name of this instance
-    pub stylename: String,
+    #[serde(rename = "@stylename", skip_serializing_if = "Option::is_none")]
+    pub stylename: Option<String>,
     /// The full name of this instance
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// The filename for this instance
+    #[serde(rename = "@filename", skip_serializing_if = "Option::is_none")]
     pub filename: Option<String>,
     /// The PostScript family name for this instance
+    #[serde(
+        rename = "@postscriptfontname",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub postscriptfontname: Option<String>,
     /// The style map family name for this instance
+    #[serde(
+        rename = "@stylemapfamilyname",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub stylemapfamilyname: Option<String>,
     /// The style map style name for this instance
+    #[serde(rename = "@stylemapstylename", skip_serializing_if = "Option::is_none")]
     pub stylemapstylename: Option<String>,
+    /// The name of a location label giving this instance's location (format
+    /// 5; used instead of a location element)
+    #[serde(rename = "@location", skip_serializing_if = "Option::is_none")]
+    pub location_label: Option<String>,
+    /// Internationalized family names
+    #[serde(rename = "familyname", skip_serializing_if = "Option::is_none")]
+    pub localised_familyname: Option<Vec<LabelName>>,
+    /// Internationalized style names
+    #[serde(rename = "stylename", skip_serializing_if = "Option::is_none")]
+    pub localised_stylename: Option<Vec<LabelName>>,
+    /// Internationalized style map family names
+    #[serde(rename = "stylemapfamilyname", skip_serializing_if = "Option::is_none")]
+    pub localised_stylemapfamilyname: Option<Vec<LabelName>>,
+    /// Internationalized style map style names
+    #[serde(rename = "stylemapstylename", skip_serializing_if = "Option::is_none")]
+    pub localised_stylemapstylename: Option<Vec<LabelName>>,
     /// The location of this instance in the designspace
-    pub location: Location,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Present if kerning should be generated for this instance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kerning: Option<Flag>,
+    /// Present if font info should be generated for this instance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<Flag>,
+    /// Arbitrary custom data
+    #[serde(default, with = "plist_lib", skip_serializing_if = "Option::is_none")]
+    pub lib: Option<plist::Dictionary>,
 }
 
+/// An empty element whose presence switches something on
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Flag {}
+
 #[cfg(test)]
 mod tests {
-    use crate::Designspace;
-    use serde_xml_rs::from_reader;
+    use crate::{from_reader, Designspace};
     #[test]
     fn test_de() {
         let s = r##"
@@ -463,10 +933,10 @@ mod tests {
     "##;
         let designspace: Designspace = from_reader(s.as_bytes()).unwrap();
         println!("{:#?}", designspace);
-        assert_eq!(designspace.default_location(), vec![1, 100]);
+        assert_eq!(designspace.default_location(), vec![1.0, 100.0]);
         assert_eq!(
             designspace.source_location(&designspace.sources.source[0]),
-            vec![1, 150]
+            vec![1.0, 150.0]
         );
         let dm = designspace.default_master();
         assert!(dm.is_some());
@@ -533,8 +1003,11 @@ mod tests {
         println!("{:#?}", designspace);
         let dm = designspace.default_master();
         assert!(dm.is_some());
-        assert_eq!(designspace.default_designspace_location(), vec![78, 100]);
-        assert_eq!(designspace.default_location(), vec![400, 100]);
+        assert_eq!(
+            designspace.default_designspace_location(),
+            vec![78.0, 100.0]
+        );
+        assert_eq!(designspace.default_location(), vec![400.0, 100.0]);
         let model = designspace.variation_model();
 
         let wght = designspace.axes.axis.get(0).unwrap();
@@ -561,4 +1034,352 @@ mod tests {
         assert_eq!(regular.get("wght"), Some(&0.0));
         assert_eq!(regular.get("wdth"), Some(&0.0));
     }
+
+    #[test]
+    fn test_mappings() {
+        let s = r##"
+<designspace format="5.0">
+  <axes>
+    <axis tag="wght" name="Weight" minimum="100" maximum="900" default="400">
+      <labels ordering="1">
+        <label uservalue="400" name="Regular"/>
+      </labels>
+    </axis>
+    <axis tag="opsz" name="Optical size" minimum="8" maximum="72" default="12"/>
+    <mappings>
+      <mapping>
+        <input>
+          <dimension name="Weight" xvalue="900"/>
+          <dimension name="Optical size" xvalue="72"/>
+        </input>
+        <output>
+          <dimension name="Weight" xvalue="800"/>
+        </output>
+      </mapping>
+    </mappings>
+  </axes>
+  <sources>
+    <source filename="Regular.ufo">
+      <location>
+        <dimension name="Weight" xvalue="400"/>
+        <dimension name="Optical size" xvalue="12"/>
+      </location>
+    </source>
+  </sources>
+</designspace>
+        "##;
+        let designspace: Designspace = from_reader(s.as_bytes()).unwrap();
+        let check = |designspace: &Designspace| {
+            let mappings = &designspace.axes.mappings.as_ref().unwrap().mapping;
+            assert_eq!(mappings.len(), 1);
+            assert_eq!(mappings[0].input.dimension.len(), 2);
+            assert_eq!(mappings[0].output.dimension[0].name, "Weight");
+            assert_eq!(mappings[0].output.dimension[0].xvalue, Some(800.0));
+            let labels = designspace.axes.axis[0].labels.as_ref().unwrap();
+            assert_eq!(labels.ordering, Some(1));
+        };
+        check(&designspace);
+
+        let xml = crate::to_string(&designspace).unwrap();
+        assert!(xml.contains(r#"<labels ordering="1">"#));
+        let axes = &xml[xml.find("<axes>").unwrap()..xml.find("</axes>").unwrap()];
+        assert!(axes.contains("<mappings>"));
+        check(&from_reader(xml.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn test_labels() {
+        let s = r##"
+<designspace format="5.0">
+  <axes>
+    <axis tag="wght" name="Weight" minimum="100" maximum="900" default="400">
+      <labels>
+        <label uservalue="400" name="Regular" elidable="true" linkeduservalue="700"/>
+        <label uservalue="700" userminimum="600" usermaximum="900" name="Bold">
+          <labelname xml:lang="fr">Gras</labelname>
+        </label>
+      </labels>
+    </axis>
+    <axis tag="ital" name="Italic" minimum="0" maximum="1" default="0"/>
+  </axes>
+  <sources>
+    <source filename="Regular.ufo">
+      <location>
+        <dimension name="Weight" xvalue="400"/>
+      </location>
+    </source>
+  </sources>
+  <labels>
+    <label name="Bold Italic" oldersibling="true">
+      <location>
+        <dimension name="Weight" uservalue="700"/>
+        <dimension name="Italic" uservalue="1"/>
+      </location>
+    </label>
+  </labels>
+</designspace>
+        "##;
+        let designspace: Designspace = from_reader(s.as_bytes()).unwrap();
+        let labels = &designspace.axes.axis[0].labels.as_ref().unwrap().label;
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].elidable, Some(true));
+        assert_eq!(labels[0].linkeduservalue, Some(700.0));
+        assert_eq!(labels[1].userminimum, Some(600.0));
+        assert_eq!(labels[1].labelname.as_ref().unwrap()[0].value, "Gras");
+        assert!(designspace.axes.axis[1].labels.is_none());
+        let location_labels = designspace.labels.unwrap().label;
+        assert_eq!(location_labels[0].name, "Bold Italic");
+        assert_eq!(location_labels[0].oldersibling, Some(true));
+        assert_eq!(location_labels[0].location.dimension[1].uservalue, 1.0);
+    }
+
+    #[test]
+    fn test_rules_and_lib() {
+        let s = r##"
+<designspace format="4.1">
+  <axes>
+    <axis tag="wght" name="Weight" minimum="100" maximum="900" default="400"/>
+  </axes>
+  <rules processing="last">
+    <rule name="BRACKET.dollar">
+      <conditionset>
+        <condition name="Weight" minimum="600"/>
+      </conditionset>
+      <sub name="dollar" with="dollar.bold"/>
+      <sub name="cent" with="cent.bold"/>
+    </rule>
+    <rule name="legacy">
+      <condition name="Weight" minimum="100" maximum="200"/>
+      <sub name="a" with="a.thin"/>
+    </rule>
+  </rules>
+  <sources>
+    <source filename="Regular.ufo">
+      <location>
+        <dimension name="Weight" xvalue="400"/>
+      </location>
+    </source>
+  </sources>
+  <instances>
+    <instance familyname="Test" stylename="Bold">
+      <location>
+        <dimension name="Weight" xvalue="700"/>
+      </location>
+      <kerning/>
+      <lib>
+        <dict>
+          <key>com.example.specimen</key>
+          <string>Hamburgefonts</string>
+        </dict>
+      </lib>
+    </instance>
+  </instances>
+  <lib>
+    <dict>
+      <key>public.glyphOrder</key>
+      <array>
+        <string>dollar</string>
+        <string>cent</string>
+      </array>
+    </dict>
+  </lib>
+</designspace>
+        "##;
+        let designspace = from_reader(s.as_bytes()).unwrap();
+        let rules = designspace.rules.unwrap();
+        assert_eq!(rules.processing.as_deref(), Some("last"));
+        assert_eq!(rules.rule.len(), 2);
+        let conditions = &rules.rule[0].conditionset.as_ref().unwrap()[0].condition;
+        assert_eq!(conditions[0].minimum, Some(600.0));
+        assert_eq!(conditions[0].maximum, None);
+        assert_eq!(rules.rule[0].sub[1].with, "cent.bold");
+        assert_eq!(
+            rules.rule[1].condition.as_ref().unwrap()[0].maximum,
+            Some(200.0)
+        );
+
+        let instance = &designspace.instances.unwrap().instance[0];
+        assert!(instance.kerning.is_some());
+        assert!(instance.info.is_none());
+        let instance_lib = instance.lib.as_ref().unwrap();
+        assert_eq!(
+            instance_lib
+                .get("com.example.specimen")
+                .and_then(|v| v.as_string()),
+            Some("Hamburgefonts")
+        );
+        let glyph_order = designspace
+            .lib
+            .unwrap()
+            .get("public.glyphOrder")
+            .and_then(|v| v.as_array())
+            .unwrap()
+            .len();
+        assert_eq!(glyph_order, 2);
+    }
+
+    const FORMAT5: &str = r##"<?xml version='1.0' encoding='UTF-8'?>
+<designspace format="5.0">
+  <axes elidedfallbackname="Regular">
+    <axis tag="wght" name="Weight" minimum="100" maximum="900" default="400">
+      <labelname xml:lang="fr">Graisse</labelname>
+      <map input="100" output="20.5"/>
+      <map input="400" output="66"/>
+      <map input="900" output="190"/>
+    </axis>
+    <axis tag="ital" name="Italic" values="0 1" default="0"/>
+  </axes>
+  <labels>
+    <label name="Bold Italic">
+      <location>
+        <dimension name="Weight" uservalue="700"/>
+        <dimension name="Italic" uservalue="1"/>
+      </location>
+    </label>
+  </labels>
+  <sources>
+    <source filename="Regular.ufo" name="Regular">
+      <location>
+        <dimension name="Weight" xvalue="66"/>
+        <dimension name="Italic" xvalue="0"/>
+      </location>
+    </source>
+    <source filename="Italic.ufo" name="Italic">
+      <familyname xml:lang="fr">Italique</familyname>
+      <location>
+        <dimension name="Weight" uservalue="400"/>
+        <dimension name="Italic" uservalue="1"/>
+      </location>
+    </source>
+  </sources>
+  <variable-fonts>
+    <variable-font name="Test-Upright" filename="Test-Upright.ttf">
+      <axis-subsets>
+        <axis-subset name="Weight" userminimum="100" usermaximum="700"/>
+        <axis-subset name="Italic" uservalue="0"/>
+      </axis-subsets>
+      <lib>
+        <dict>
+          <key>com.example.weight</key>
+          <real>1.5</real>
+        </dict>
+      </lib>
+    </variable-font>
+  </variable-fonts>
+  <instances>
+    <instance familyname="Test" stylename="Bold Italic" location="Bold Italic">
+      <stylename xml:lang="fr">Gras Italique</stylename>
+    </instance>
+    <instance familyname="Test" stylename="Light" postscriptfontname="Test-Light">
+      <location>
+        <dimension name="Weight" xvalue="20.5"/>
+      </location>
+      <info/>
+    </instance>
+  </instances>
+  <lib>
+    <dict>
+      <key>com.example.flag</key>
+      <true/>
+      <key>com.example.list</key>
+      <array>
+        <integer>-3</integer>
+        <string>three</string>
+        <dict>
+          <key>nested</key>
+          <false/>
+        </dict>
+      </array>
+    </dict>
+  </lib>
+</designspace>
+"##;
+
+    fn check_format5(designspace: &Designspace) {
+        assert_eq!(designspace.format, 5.0);
+        assert_eq!(
+            designspace.axes.elidedfallbackname.as_deref(),
+            Some("Regular")
+        );
+        let wght = &designspace.axes.axis[0];
+        let ital = &designspace.axes.axis[1];
+        assert_eq!(wght.labelname.as_ref().unwrap()[0].lang, "fr");
+        assert_eq!(wght.userspace_to_designspace(100.0), 20.5);
+        assert!(!wght.is_discrete());
+        assert!(ital.is_discrete());
+        assert_eq!(ital.values, Some(vec![0.0, 1.0]));
+        assert_eq!((ital.min_value(), ital.max_value()), (0.0, 1.0));
+
+        assert_eq!(designspace.default_designspace_location(), vec![66.0, 0.0]);
+        let sources = &designspace.sources.source;
+        assert_eq!(
+            designspace.default_master().unwrap().filename,
+            "Regular.ufo"
+        );
+        assert_eq!(designspace.source_location(&sources[1]), vec![66.0, 1.0]);
+        assert_eq!(
+            sources[1].localised_familyname.as_ref().unwrap()[0].value,
+            "Italique"
+        );
+
+        let vf = &designspace.variable_fonts.as_ref().unwrap().variable_font[0];
+        assert_eq!(vf.name, "Test-Upright");
+        assert_eq!(vf.axis_subsets.axis_subset[0].usermaximum, Some(700.0));
+        assert_eq!(vf.axis_subsets.axis_subset[1].uservalue, Some(0.0));
+        assert_eq!(
+            vf.lib.as_ref().unwrap().get("com.example.weight"),
+            Some(&plist::Value::Real(1.5))
+        );
+
+        let instances = &designspace.instances.as_ref().unwrap().instance;
+        let bold_italic = &instances[0];
+        assert!(bold_italic.location.is_none());
+        assert_eq!(bold_italic.location_label.as_deref(), Some("Bold Italic"));
+        assert_eq!(
+            bold_italic.localised_stylename.as_ref().unwrap()[0].value,
+            "Gras Italique"
+        );
+        let wght_bold = wght.userspace_to_designspace(700.0);
+        assert_eq!(
+            designspace.instance_location(bold_italic),
+            vec![wght_bold, 1.0]
+        );
+        assert_eq!(
+            designspace.instance_location(&instances[1]),
+            vec![20.5, 0.0]
+        );
+        assert!(instances[1].info.is_some());
+        assert!(instances[1].kerning.is_none());
+
+        let lib = designspace.lib.as_ref().unwrap();
+        assert_eq!(
+            lib.get("com.example.flag"),
+            Some(&plist::Value::Boolean(true))
+        );
+        let list = lib.get("com.example.list").unwrap().as_array().unwrap();
+        assert_eq!(list[0], plist::Value::Integer((-3).into()));
+        assert_eq!(list[1], plist::Value::String("three".to_string()));
+        assert_eq!(
+            list[2].as_dictionary().unwrap().get("nested"),
+            Some(&plist::Value::Boolean(false))
+        );
+    }
+
+    #[test]
+    fn test_format5() {
+        let designspace = from_reader(FORMAT5.as_bytes()).unwrap();
+        check_format5(&designspace);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let designspace = from_reader(FORMAT5.as_bytes()).unwrap();
+        let xml = crate::to_string(&designspace).unwrap();
+        assert!(xml.contains(r#"<designspace format="5.0">"#));
+        assert!(xml.contains(r#"values="0 1""#));
+        assert!(xml.contains(r#"<stylename xml:lang="fr">Gras Italique</stylename>"#));
+        let reloaded = from_reader(xml.as_bytes()).unwrap();
+        check_format5(&reloaded);
+        assert_eq!(crate::to_string(&reloaded).unwrap(), xml);
+    }
 }
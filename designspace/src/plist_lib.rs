@@ -0,0 +1,127 @@
+//! Serde support for `<lib>` elements, which contain an XML property list
+//! dictionary. The elements are read into an intermediate form which
+//! mirrors the XML structure, and then converted to `plist` values.
+use plist::{Dictionary, Value};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Lib {
+    #[serde(default)]
+    dict: Items,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Items {
+    #[serde(rename = "$value", default)]
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+enum Item {
+    #[serde(rename = "key")]
+    Key(String),
+    #[serde(rename = "string")]
+    String(String),
+    #[serde(rename = "integer")]
+    Integer(i64),
+    #[serde(rename = "real")]
+    Real(f64),
+    #[serde(rename = "true")]
+    True,
+    #[serde(rename = "false")]
+    False,
+    #[serde(rename = "date")]
+    Date(String),
+    #[serde(rename = "data")]
+    Data(String),
+    #[serde(rename = "array")]
+    Array(Items),
+    #[serde(rename = "dict")]
+    Dict(Items),
+}
+
+fn to_value(item: Item) -> Result<Value, String> {
+    Ok(match item {
+        Item::Key(k) => return Err(format!("unexpected key {} in lib", k)),
+        Item::String(s) => Value::String(s),
+        Item::Integer(i) => Value::Integer(i.into()),
+        Item::Real(r) => Value::Real(r),
+        Item::True => Value::Boolean(true),
+        Item::False => Value::Boolean(false),
+        Item::Date(d) => Value::Date(plist::Date::from_xml_format(&d).map_err(|e| e.to_string())?),
+        Item::Data(d) => Value::Data(
+            plist::Data::from_xml_format(d.trim())
+                .map_err(|e| e.to_string())?
+                .into(),
+        ),
+        Item::Array(a) => Value::Array(
+            a.items
+                .into_iter()
+                .map(to_value)
+                .collect::<Result<_, _>>()?,
+        ),
+        Item::Dict(d) => Value::Dictionary(to_dictionary(d)?),
+    })
+}
+
+fn to_dictionary(dict: Items) -> Result<Dictionary, String> {
+    let mut out = Dictionary::new();
+    let mut items = dict.items.into_iter();
+    while let Some(item) = items.next() {
+        let key = match item {
+            Item::Key(k) => k,
+            _ => return Err("expected a key in lib dictionary".to_string()),
+        };
+        let value = items
+            .next()
+            .ok_or_else(|| format!("no value for key {} in lib", key))?;
+        out.insert(key, to_value(value)?);
+    }
+    Ok(out)
+}
+
+fn from_value(value: &Value) -> Result<Item, String> {
+    Ok(match value {
+        Value::String(s) => Item::String(s.clone()),
+        Value::Integer(i) => Item::Integer(
+            i.as_signed()
+                .ok_or_else(|| format!("integer {} out of range", i))?,
+        ),
+        Value::Real(r) => Item::Real(*r),
+        Value::Boolean(true) => Item::True,
+        Value::Boolean(false) => Item::False,
+        Value::Date(d) => Item::Date(d.to_xml_format()),
+        Value::Data(d) => Item::Data(plist::Data::new(d.clone()).to_xml_format()),
+        Value::Array(a) => Item::Array(Items {
+            items: a.iter().map(from_value).collect::<Result<_, _>>()?,
+        }),
+        Value::Dictionary(d) => Item::Dict(from_dictionary(d)?),
+        _ => return Err("unsupported value in lib".to_string()),
+    })
+}
+
+fn from_dictionary(dict: &Dictionary) -> Result<Items, String> {
+    let mut items = vec![];
+    for (key, value) in dict {
+        items.push(Item::Key(key.clone()));
+        items.push(from_value(value)?);
+    }
+    Ok(Items { items })
+}
+
+pub(crate) fn serialize<S>(lib: &Option<Dictionary>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let dict =
+        from_dictionary(lib.as_ref().unwrap_or(&Dictionary::new())).map_err(ser::Error::custom)?;
+    Lib { dict }.serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Dictionary>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let lib = Lib::deserialize(deserializer)?;
+    to_dictionary(lib.dict).map(Some).map_err(de::Error::custom)
+}
@@ -35,7 +35,7 @@ pub(crate) fn check_designspace(ds: &Designspace) -> impl Iterator<Item = Proble
 
 fn check_ds_axis(axis: &Axis) -> impl Iterator<Item = Problem> {
     let mut problems: Vec<Problem> = vec![];
-    if axis.default < axis.minimum {
+    if axis.default < axis.min_value() {
         problems.push(Problem {
             area: "designspace".to_string(),
             glyph: None,
@@ -43,11 +43,12 @@ fn check_ds_axis(axis: &Axis) -> impl Iterator<Item = Problem> {
             location: Some(format!("axis {}", axis.tag)),
             description: format!(
                 "default {} is less than minimum {}",
-                axis.default, axis.minimum
+                axis.default,
+                axis.min_value()
             ),
         })
     }
-    if axis.default > axis.maximum {
+    if axis.default > axis.max_value() {
         problems.push(Problem {
             area: "designspace".to_string(),
             glyph: None,
@@ -55,7 +56,8 @@ fn check_ds_axis(axis: &Axis) -> impl Iterator<Item = Problem> {
             location: Some(format!("{} axis", axis.tag)),
             description: format!(
                 "default {} is more than maximum {}",
-                axis.default, axis.maximum
+                axis.default,
+                axis.max_value()
             ),
         })
     }
@@ -82,31 +84,37 @@ fn check_map(map: &[Mapping], axis: &Axis) -> impl Iterator<Item = Problem> {
     // Mapping should contain min/default/max values
     if !inputs
         .iter()
-        .any(|i| (i - axis.minimum as f32).abs() < f32::EPSILON)
+        .any(|i| (i - axis.min_value()).abs() < f32::EPSILON)
     {
         problems.push(Problem {
             area: "designspace".to_string(),
             glyph: None,
             master: None,
             location: Some(format!("{} axis", axis.tag)),
-            description: format!("mapping does not contain minimum value {}", axis.minimum),
+            description: format!(
+                "mapping does not contain minimum value {}",
+                axis.min_value()
+            ),
         })
     }
     if !inputs
         .iter()
-        .any(|i| (i - axis.maximum as f32).abs() < f32::EPSILON)
+        .any(|i| (i - axis.max_value()).abs() < f32::EPSILON)
     {
         problems.push(Problem {
             area: "designspace".to_string(),
             glyph: None,
             master: None,
             location: Some(format!("{} axis", axis.tag)),
-            description: format!("mapping does not contain maximum value {}", axis.maximum),
+            description: format!(
+                "mapping does not contain maximum value {}",
+                axis.max_value()
+            ),
         })
     }
     if !inputs
         .iter()
-        .any(|i| (i - axis.default as f32).abs() < f32::EPSILON)
+        .any(|i| (i - axis.default).abs() < f32::EPSILON)
     {
         problems.push(Problem {
             area: "designspace".to_string(),
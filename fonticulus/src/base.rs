@@ -0,0 +1,174 @@
+//! Builds a `BASE` table from the custom OpenType values in the source.
+//!
+//! The values use the same syntax as the feature file: the `BASE` table's
+//! `HorizAxis.BaseTagList` value lists the baseline tags (e.g. `"ideo romn"`)
+//! and `HorizAxis.BaseScriptList` gives each script's default baseline and
+//! the coordinate of each baseline (e.g. `"latn romn -120 0, hani ideo -120 0"`).
+//! `VertAxis.BaseTagList` and `VertAxis.BaseScriptList` do the same for
+//! vertical text. Script lists may be set per master, in which case the
+//! coordinates are made variable.
+use babelfont::{Font, Master};
+use fonttools::otvar::{ItemVariationStoreBuilder, RegionAxisCoordinates, VariationModel};
+use fonttools::tables::BASE::{BaseCoord, BaseScript, BASE};
+use fonttools::types::Tag;
+use otmath::ot_round;
+use std::collections::BTreeMap;
+
+type ScriptRecords = BTreeMap<Tag, (Tag, Vec<i16>)>;
+
+fn parse_script_record(tags: &[Tag], record: &str) -> Option<(Tag, (Tag, Vec<i16>))> {
+    let words: Vec<&str> = record.split_whitespace().collect();
+    let script = Tag::from_raw(words.first()?).ok()?;
+    let default_baseline = Tag::from_raw(words.get(1)?).ok()?;
+    let coordinates: Vec<i16> = words[2..]
+        .iter()
+        .map(|w| w.parse().ok())
+        .collect::<Option<_>>()?;
+    if coordinates.len() != tags.len() {
+        return None;
+    }
+    Some((script, (default_baseline, coordinates)))
+}
+
+fn parse_script_list(tags: &[Tag], value: &str) -> ScriptRecords {
+    value
+        .split(',')
+        .filter(|record| !record.trim().is_empty())
+        .filter_map(|record| {
+            let parsed = parse_script_record(tags, record);
+            if parsed.is_none() {
+                log::warn!("Couldn't parse BASE script record '{}'", record.trim());
+            }
+            parsed
+        })
+        .collect()
+}
+
+fn base_coord(
+    values: &[Option<f32>],
+    default: f32,
+    model: Option<&VariationModel<String>>,
+    store: &mut ItemVariationStoreBuilder,
+) -> BaseCoord {
+    let model = match model {
+        Some(model) if values.iter().flatten().any(|&v| v != default) => model,
+        _ => return BaseCoord::new(ot_round(default) as i16),
+    };
+    let deltas: Vec<(Vec<RegionAxisCoordinates>, i16)> = model
+        .get_deltas_and_supports(values)
+        .into_iter()
+        .filter(|(_, support)| !support.is_empty())
+        .map(|(delta, support)| {
+            let region = model
+                .axis_order
+                .iter()
+                .map(|axis| {
+                    let &(start, peak, end) = support.get(axis).unwrap_or(&(0.0, 0.0, 0.0));
+                    RegionAxisCoordinates {
+                        startCoord: start,
+                        peakCoord: peak,
+                        endCoord: end,
+                    }
+                })
+                .collect();
+            (region, ot_round(delta) as i16)
+        })
+        .collect();
+    let var_idx = store.add(deltas);
+    BaseCoord::variable(
+        ot_round(default) as i16,
+        (var_idx >> 16) as u16,
+        (var_idx & 0xFFFF) as u16,
+    )
+}
+
+fn build_axis(
+    font: &Font,
+    masters: &[&Master],
+    default_master_ix: usize,
+    model: Option<&VariationModel<String>>,
+    store: &mut ItemVariationStoreBuilder,
+    axis: &str,
+) -> BTreeMap<Tag, BaseScript> {
+    let tags: Vec<Tag> = match font.ot_value("BASE", &format!("{}.BaseTagList", axis), true) {
+        Some(value) => String::from(value)
+            .split_whitespace()
+            .filter_map(|t| Tag::from_raw(t).ok())
+            .collect(),
+        None => return BTreeMap::new(),
+    };
+    let field = format!("{}.BaseScriptList", axis);
+    let master_records: Vec<Option<ScriptRecords>> = masters
+        .iter()
+        .map(|master| {
+            master
+                .ot_value("BASE", &field)
+                .or_else(|| font.ot_value("BASE", &field, false))
+                .map(|value| parse_script_list(&tags, &String::from(value)))
+        })
+        .collect();
+    let default_records = match &master_records[default_master_ix] {
+        Some(records) => records,
+        None => return BTreeMap::new(),
+    };
+
+    let mut scripts = BTreeMap::new();
+    for (script, (default_baseline, coordinates)) in default_records {
+        let mut baselines = BTreeMap::new();
+        for (ix, (tag, default)) in tags.iter().zip(coordinates.iter()).enumerate() {
+            let values: Vec<Option<f32>> = master_records
+                .iter()
+                .map(|records| Some(*records.as_ref()?.get(script)?.1.get(ix)? as f32))
+                .collect();
+            baselines.insert(*tag, base_coord(&values, *default as f32, model, store));
+        }
+        scripts.insert(
+            *script,
+            BaseScript {
+                default_baseline: Some(*default_baseline),
+                baselines,
+                ..Default::default()
+            },
+        );
+    }
+    scripts
+}
+
+/// Builds a `BASE` table for the given masters, or returns `None` if the
+/// source doesn't define any baselines.
+pub fn build_base(
+    font: &Font,
+    masters: &[&Master],
+    default_master_ix: usize,
+    model: Option<&VariationModel<String>>,
+) -> Option<BASE> {
+    let mut store = ItemVariationStoreBuilder::new(font.axes.len() as u16);
+    let horizontal = build_axis(
+        font,
+        masters,
+        default_master_ix,
+        model,
+        &mut store,
+        "HorizAxis",
+    );
+    let vertical = build_axis(
+        font,
+        masters,
+        default_master_ix,
+        model,
+        &mut store,
+        "VertAxis",
+    );
+    if horizontal.is_empty() && vertical.is_empty() {
+        return None;
+    }
+    Some(BASE {
+        horizontal,
+        vertical,
+        item_variation_store: if store.is_empty() {
+            None
+        } else {
+            Some(store.finish())
+        },
+    })
+}
@@ -1,16 +1,16 @@
 use crate::fontinfo::*;
 use crate::utils::adjust_offset;
-use babelfont::OTScalar;
+use babelfont::{Glyph, OTScalar};
 use fonttools::font::{self, Font};
 use fonttools::tables::head::head;
 use fonttools::tables::name::{name, NameRecord, NameRecordID};
 use fonttools::tables::os2::os2;
 use fonttools::tables::post::post;
-use fonttools::tables::{cmap, glyf, hhea, hmtx};
+use fonttools::tables::{cmap, glyf, hhea, hmtx, vhea, vmtx, VORG};
 use fonttools::tag;
 use fonttools::types::Tag;
 use otspec::utils::filtered_bitset_to_num;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
 // This takes a babelfont font, and creates most of the output fonttools-rs font.
 pub fn fill_tables(
@@ -37,6 +37,8 @@ pub fn fill_tables(
     hhea_table.numberOfHMetrics = num_h_metrics;
 
     let maxp_table = glyf_table.as_maxp10();
+    let vertical_tables =
+        compile_vertical(input, &glyph_names, &glyf_table, os2_table.sTypoAscender);
 
     font.tables.insert(head_table);
     font.tables.insert(hhea_table);
@@ -47,6 +49,13 @@ pub fn fill_tables(
     font.tables.insert(glyf_table);
     font.tables.insert(name_table);
     font.tables.insert(post_table);
+    if let Some((vhea_table, vmtx_table, vorg_table)) = vertical_tables {
+        font.tables.insert(vhea_table);
+        font.tables.insert(vmtx_table);
+        if !vorg_table.vertOriginYMetrics.is_empty() {
+            font.tables.insert(vorg_table);
+        }
+    }
 
     // Don't worry, this will get filled in on `font.save`.
     font.tables.insert_raw(tag!("loca"), vec![0]);
@@ -102,6 +111,34 @@ pub fn compile_head(font: &babelfont::Font, glyf: &glyf::glyf) -> head {
     }
 }
 
+/// The glyphs' production names, which come from the source or the glyph
+/// database. Glyphs keep their own names where production names would clash.
+fn production_names(font: &babelfont::Font, glyph_names: &[String]) -> Vec<String> {
+    let mut used = HashSet::new();
+    glyph_names
+        .iter()
+        .map(|name| {
+            let production_name = font
+                .glyphs
+                .get(name)
+                .and_then(|g| g.production_name.clone())
+                .or_else(|| Glyph::default_production_name(name))
+                .unwrap_or_else(|| name.clone());
+            if used.insert(production_name.clone()) {
+                production_name
+            } else {
+                log::warn!(
+                    "Production name {} of glyph {} is already used",
+                    production_name,
+                    name
+                );
+                used.insert(name.clone());
+                name.clone()
+            }
+        })
+        .collect()
+}
+
 pub fn compile_post(font: &babelfont::Font, glyph_names: &[String]) -> post {
     let italic_angle = *font
         .default_master()
@@ -121,7 +158,7 @@ pub fn compile_post(font: &babelfont::Font, glyph_names: &[String]) -> post {
         underline_position,
         postscript_underline_thickness(font), // in fontinfo
         is_fixed_pitch,
-        Some(glyph_names.to_vec()),
+        Some(production_names(font, glyph_names)),
     )
 }
 
@@ -266,6 +303,113 @@ pub fn compile_hhea(
     }
 }
 
+// Builds the vertical metrics tables, but only if the default master has any
+// vertical metrics data: most fonts are horizontal-only.
+#[allow(non_snake_case)]
+pub fn compile_vertical(
+    input: &babelfont::Font,
+    glyph_names: &[String],
+    glyf: &glyf::glyf,
+    default_origin: i16,
+) -> Option<(vhea::vhea, vmtx::vmtx, VORG::VORG)> {
+    let master = input.default_master()?;
+    let layers: Vec<Option<&babelfont::Layer>> = glyph_names
+        .iter()
+        .map(|n| input.master_layer_for(n, master))
+        .collect();
+    if !layers
+        .iter()
+        .flatten()
+        .any(|l| l.height.is_some() || l.vertical_origin.is_some())
+    {
+        return None;
+    }
+
+    let mut metrics = vec![];
+    let mut vert_origins = vec![];
+    for (gid, (layer, glyph)) in layers.iter().zip(&glyf.glyphs).enumerate() {
+        let advanceHeight = layer.and_then(|l| l.height).map_or(input.upm, |h| h as u16);
+        let origin = layer
+            .and_then(|l| l.vertical_origin)
+            .map_or(default_origin, |o| o as i16);
+        if origin != default_origin {
+            vert_origins.push(VORG::VertOriginYMetrics {
+                glyphIndex: gid as u16,
+                vertOriginY: origin,
+            });
+        }
+        // The top side bearing is measured down from the vertical origin
+        let tsb = if glyph.is_empty() {
+            0
+        } else {
+            origin - glyph.yMax
+        };
+        metrics.push(vmtx::Metric { advanceHeight, tsb });
+    }
+
+    let filtered_metrics = metrics
+        .iter()
+        .zip(&glyf.glyphs)
+        .filter(|(_m, g)| !g.is_empty());
+    let advanceHeightMax = metrics.iter().map(|m| m.advanceHeight).max().unwrap_or(0);
+    let minTopSideBearing = filtered_metrics
+        .clone()
+        .map(|(m, _g)| m.tsb)
+        .min()
+        .unwrap_or(0);
+    let minBottomSideBearing = filtered_metrics
+        .clone()
+        .map(|(m, g)| m.advanceHeight as i16 - m.tsb - (g.yMax - g.yMin))
+        .min()
+        .unwrap_or(0);
+    let yMaxExtent = filtered_metrics
+        .map(|(m, g)| m.tsb + (g.yMax - g.yMin))
+        .max()
+        .unwrap_or(0);
+
+    let upm = input.upm as f32;
+    let vmtx_table = vmtx::vmtx { metrics };
+    let vhea_table = vhea::vhea {
+        majorVersion: 1,
+        minorVersion: 0x1000,
+        vertTypoAscender: input
+            .ot_value("vhea", "vertTypoAscender", true)
+            .map_or((upm * 0.5) as i16, i16::from),
+        vertTypoDescender: input
+            .ot_value("vhea", "vertTypoDescender", true)
+            .map_or((-upm * 0.5) as i16, i16::from),
+        vertTypoLineGap: input
+            .ot_value("vhea", "vertTypoLineGap", true)
+            .map_or(0, i16::from),
+        advanceHeightMax,
+        minTopSideBearing,
+        minBottomSideBearing,
+        yMaxExtent,
+        caretSlopeRise: input
+            .ot_value("vhea", "caretSlopeRise", true)
+            .map_or(0, i16::from),
+        caretSlopeRun: input
+            .ot_value("vhea", "caretSlopeRun", true)
+            .map_or(1, i16::from),
+        caretOffset: input
+            .ot_value("vhea", "caretOffset", true)
+            .map_or(0, i16::from),
+        reserved0: 0,
+        reserved1: 0,
+        reserved2: 0,
+        reserved3: 0,
+        metricDataFormat: 0,
+        numOfLongVerMetrics: vmtx_table.number_of_vmetrics(),
+    };
+    let vorg_table = VORG::VORG {
+        majorVersion: 1,
+        minorVersion: 0,
+        defaultVertOriginY: default_origin,
+        vertOriginYMetrics: vert_origins,
+    };
+    Some((vhea_table, vmtx_table, vorg_table))
+}
+
 #[allow(non_snake_case)]
 pub fn compile_os2(
     input: &babelfont::Font,
@@ -444,12 +588,17 @@ pub fn compile_name(input: &babelfont::Font) -> name {
     }
 
     let family_name = style_map_family_name(input);
-    // let style_name = style_map_style_name(input);
+    let style_name = match style_map_style_name(input).as_str() {
+        "bold italic" => "Bold Italic",
+        "bold" => "Bold",
+        "italic" => "Italic",
+        _ => "Regular",
+    };
     let pfn = preferred_family_name(input);
     let psfn = preferred_subfamily_name(input);
     records.extend(vec![
         (NameRecordID::FontFamilyName, family_name.clone()),
-        (NameRecordID::FontSubfamilyName, psfn.clone()),
+        (NameRecordID::FontSubfamilyName, style_name.to_string()),
         (NameRecordID::UniqueID, unique_id(input)),
         (NameRecordID::FullFontName, format!("{0} {1}", pfn, psfn)),
         (
@@ -1,8 +1,11 @@
+use crate::base::build_base;
 use crate::basictables::fill_tables;
+use crate::gdef::build_gdef;
 use crate::glyph::layers_to_glyph;
-use crate::kerning::build_kerning;
+use crate::kerning::{build_kern_table, build_kerning, flatten_kerning};
 use crate::notdef::add_notdef;
-use babelfont::{Component, Font, Layer, Node, Path};
+use crate::rules::build_rules;
+use babelfont::{Font, Layer, Master};
 use fonttools::tables::gvar::GlyphVariationData;
 use fonttools::tables::{glyf, hmtx};
 use fonttools::{font, tag};
@@ -58,6 +61,7 @@ pub fn build_font(
     subset: Option<&HashSet<&str>>,
     just_one_master: Option<usize>,
     skip_layout: bool,
+    kern_table: bool,
 ) -> font::Font {
     preprocess_font(input, subset);
 
@@ -109,13 +113,26 @@ pub fn build_font(
                 return None;
             }
 
+            let mut glyph_model = None;
             let all_layers: Vec<Option<&Layer>> = if just_one_master.is_none() {
                 // Find all layers for this glyph across the designspace
-                input
+                let mut layers: Vec<Option<&Layer>> = input
                     .masters
                     .iter()
                     .map(|master| input.master_layer_for(&glif.name, master))
-                    .collect()
+                    .collect();
+                // Intermediate layers are extra, sparse masters in a model of
+                // the glyph's own
+                let intermediates = input.intermediate_layers(&glif.name);
+                if !intermediates.is_empty() {
+                    glyph_model = Some(
+                        input
+                            .glyph_variation_model(&glif.name)
+                            .expect("Couldn't get glyph variation model"),
+                    );
+                    layers.extend(intermediates.into_iter().map(Some));
+                }
+                layers
             } else {
                 // Nobody here but us chickens
                 vec![input.master_layer_for(&glif.name, base_master)]
@@ -126,7 +143,7 @@ pub fn build_font(
                 default_master_ix,
                 &name_to_id,
                 &all_layers,
-                variation_model,
+                glyph_model.as_ref().or(variation_model),
                 &glif.name,
             );
 
@@ -167,8 +184,27 @@ pub fn build_font(
 
     // Feature writers (temporary hack)
     if !skip_layout {
-        let gpos_table = build_kerning(input, &name_to_id);
-        font.tables.insert(gpos_table);
+        let pairs = flatten_kerning(input, &name_to_id);
+        font.tables.insert(build_kerning(&pairs));
+        if kern_table {
+            font.tables.insert(build_kern_table(&pairs));
+        }
+        let base_masters: Vec<&Master> = if just_one_master.is_none() {
+            input.masters.iter().collect()
+        } else {
+            vec![base_master]
+        };
+        if let Some(base) = build_base(input, &base_masters, default_master_ix, variation_model) {
+            font.tables.insert(base);
+        }
+        if let Some(gdef) = build_gdef(input, &name_to_id) {
+            font.tables.insert(gdef);
+        }
+        if just_one_master.is_none() {
+            if let Some(gsub) = build_rules(input, &name_to_id) {
+                font.tables.insert(gsub);
+            }
+        }
     }
 
     if just_one_master.is_none() && variations.iter().any(|x| x.is_some()) {
@@ -185,6 +221,16 @@ pub fn build_font(
 /// Runs various filters on the source to prepare them for compilation into a TrueType
 /// font.
 fn preprocess_font(input: &mut Font, subset: Option<&HashSet<&str>>) {
+    // Smart components only take their intended shape once interpolated, so
+    // glyphs which use them are decomposed.
+    let glyphs_to_decompose = mark_smart_component_users(input);
+    decompose_glyph_indices(&glyphs_to_decompose, input, &|name| {
+        log::info!(
+            "Decomposed glyph {:?} because it uses smart components",
+            name
+        )
+    });
+
     // First, prune all non-export glyphs. This requires that glyphs that use them are
     // decomposed.
     let glyphs_to_decompose = mark_skipped_glyphs_dependents(input, subset);
@@ -214,48 +260,6 @@ fn preprocess_font(input: &mut Font, subset: Option<&HashSet<&str>>) {
     });
 }
 
-fn decomposed_components(layer: &Layer, font: &Font) -> Vec<Path> {
-    let mut contours = Vec::new();
-
-    let mut stack: Vec<(&Component, kurbo::Affine)> = Vec::new();
-    for component in layer.components() {
-        stack.push((component, component.transform));
-        while let Some((component, transform)) = stack.pop() {
-            let referenced_glyph = match font.glyphs.get(&component.reference) {
-                Some(g) => g,
-                None => continue,
-            };
-            let new_outline = match referenced_glyph.get_layer(layer.id.as_ref().unwrap()) {
-                Some(g) => g,
-                None => continue,
-            };
-
-            for contour in new_outline.paths() {
-                let mut decomposed_contour = Path::default();
-                for node in &contour.nodes {
-                    let new_point = transform * kurbo::Point::new(node.x as f64, node.y as f64);
-                    decomposed_contour.nodes.push(Node {
-                        x: new_point.x as f32,
-                        y: new_point.y as f32,
-                        nodetype: node.nodetype,
-                    })
-                }
-                decomposed_contour.closed = contour.closed;
-                contours.push(decomposed_contour);
-            }
-
-            // Depth-first decomposition means we need to extend the stack reversed, so
-            // the first component is taken out next.
-            for new_component in new_outline.components().rev() {
-                let new_transform: kurbo::Affine = new_component.transform;
-                stack.push((new_component, transform * new_transform));
-            }
-        }
-    }
-
-    contours
-}
-
 /// Decomposes glyphs in-place by their index and calls logger with the processed glyph
 /// name.
 //
@@ -269,7 +273,7 @@ fn decompose_glyph_indices(glyphs_to_decompose: &[usize], font: &mut Font, logge
         let mut decomposed_layers = Vec::new();
         if let Some(glyph) = font.glyphs.get_by_index(glyph_index) {
             for layer in glyph.layers.iter() {
-                let decomposed_layer = decomposed_components(layer, font);
+                let decomposed_layer = layer.decomposed_components(font);
                 decomposed_layers.push(decomposed_layer);
             }
         } else {
@@ -334,6 +338,25 @@ fn mark_skipped_glyphs_dependents(
     glyphs_to_decompose
 }
 
+/// Returns the indices of glyphs that need to be decomposed because they use smart
+/// components.
+fn mark_smart_component_users(input: &babelfont::Font) -> Vec<usize> {
+    let mut glyphs_to_decompose = Vec::new();
+    'next_glyph: for (index, glyph) in input.glyphs.iter().enumerate() {
+        for layer in &glyph.layers {
+            if layer
+                .components()
+                .any(|c| input.glyphs.get(&c.reference).is_some_and(|g| g.is_smart()))
+            {
+                glyphs_to_decompose.push(index);
+                continue 'next_glyph;
+            }
+        }
+    }
+
+    glyphs_to_decompose
+}
+
 /// Returns the indices of glyphs that need to be decomposed because they have both
 /// paths and components.
 fn mark_mixed_glyphs(input: &babelfont::Font) -> Vec<usize> {
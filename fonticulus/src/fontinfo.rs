@@ -101,6 +101,9 @@ pub fn style_map_style_name(input: &babelfont::Font) -> String {
 }
 
 pub fn postscript_font_name(input: &babelfont::Font) -> String {
+    if let Some(name) = input.names.postscript_name.get_default() {
+        return name;
+    }
     format!(
         "{0}-{1}",
         preferred_family_name(input),
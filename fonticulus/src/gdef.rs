@@ -0,0 +1,37 @@
+//! Builds a `GDEF` table from the glyphs' categories, which the source either
+//! specifies or which come from the glyph database.
+use babelfont::{Font, GlyphCategory};
+use fonttools::tables::GDEF::{GlyphClass, GDEF};
+use std::collections::BTreeMap;
+
+/// Builds a `GDEF` table with glyph class definitions. Returns `None` if the
+/// font has no mark or ligature glyphs, as everything would be a base glyph.
+pub fn build_gdef(font: &Font, mapping: &BTreeMap<String, u16>) -> Option<GDEF> {
+    let glyph_class: BTreeMap<u16, GlyphClass> = font
+        .glyphs
+        .iter()
+        .filter_map(|glyph| {
+            let class = match glyph.category {
+                GlyphCategory::Base => GlyphClass::BaseGlyph,
+                GlyphCategory::Ligature => GlyphClass::LigatureGlyph,
+                GlyphCategory::Mark => GlyphClass::MarkGlyph,
+                GlyphCategory::Unknown => return None,
+            };
+            Some((*mapping.get(&glyph.name)?, class))
+        })
+        .collect();
+    if glyph_class
+        .values()
+        .all(|class| *class == GlyphClass::BaseGlyph)
+    {
+        return None;
+    }
+    Some(GDEF {
+        glyph_class,
+        attachment_point_list: BTreeMap::new(),
+        ligature_caret_list: BTreeMap::new(),
+        mark_attachment_class: BTreeMap::new(),
+        mark_glyph_sets: None,
+        item_variation_store: None,
+    })
+}
@@ -0,0 +1,82 @@
+use babelfont::names::StyleMapStyle;
+use babelfont::{Font, Instance};
+
+/// Interpolates a source font at the location of the given instance,
+/// returning a single-master font named after the instance. Instances
+/// without kerning get none, and instances without font info keep the
+/// default master's OpenType values.
+pub fn instantiate(input: &Font, instance: &Instance) -> Font {
+    let mut output = input
+        .instantiate(&instance.location)
+        .expect("Couldn't interpolate instance");
+    let style_name = instance
+        .style_name
+        .get_default()
+        .or_else(|| instance.name.get_default())
+        .unwrap_or_else(|| "Regular".to_string());
+    output.masters[0].name = style_name.as_str().into();
+    set_instance_names(&mut output, instance, &style_name);
+    if !instance.kerning {
+        output.masters[0].kerning.clear();
+    }
+    if !instance.info {
+        if let Some(master) = input.default_master() {
+            output.masters[0].custom_ot_values = master.custom_ot_values.clone();
+        }
+    }
+    output
+}
+
+/// Sets the naming and style-linking fields for an instance, preferring
+/// those the instance gives. Otherwise, following fontmake, "Bold" and
+/// "Italic" are linked within the family and any other part of the style
+/// name becomes part of the style-map family name.
+fn set_instance_names(font: &mut Font, instance: &Instance, style_name: &str) {
+    if !instance.family_name.0.is_empty() {
+        font.names.family_name = instance.family_name.clone();
+    }
+    let family_name = font
+        .names
+        .family_name
+        .get_default()
+        .unwrap_or_else(|| "New Font".to_string());
+    let italic = style_name
+        .split_whitespace()
+        .any(|word| word == "Italic" || word == "Oblique");
+    let mut rest: Vec<&str> = style_name
+        .split_whitespace()
+        .filter(|&word| !matches!(word, "Italic" | "Oblique" | "Regular"))
+        .collect();
+    // "Bold" is only a linked style on its own, not e.g. in "Bold Condensed"
+    let bold = rest == ["Bold"];
+    if bold {
+        rest.clear();
+    }
+    let mut style_map_family_name = family_name;
+    if !rest.is_empty() {
+        style_map_family_name.push(' ');
+        style_map_family_name.push_str(&rest.join(" "));
+    }
+
+    font.names.typographic_subfamily = style_name.into();
+    font.names.style_map_family_name = if instance.style_map_family_name.0.is_empty() {
+        style_map_family_name.into()
+    } else {
+        instance.style_map_family_name.clone()
+    };
+    font.names.style_map_style_name = Some(instance.style_map_style_name.clone().unwrap_or(
+        match (bold, italic) {
+            (true, true) => StyleMapStyle::BoldItalic,
+            (true, false) => StyleMapStyle::Bold,
+            (false, true) => StyleMapStyle::Italic,
+            (false, false) => StyleMapStyle::Regular,
+        },
+    ));
+    font.names.postscript_name = instance
+        .postscript_name
+        .as_ref()
+        .map(|n| n.into())
+        .unwrap_or_default();
+    font.names.unique_id = Default::default();
+    font.names.compatible_full_name = Default::default();
+}
@@ -5,14 +5,17 @@ use fonttools::layout::common::{
     FeatureList, LanguageSystem, Lookup, LookupFlags, Script, ScriptList,
 };
 use fonttools::layout::gpos2::{PairPos, PairPositioningMap};
+use fonttools::tables::kern::kern;
 use fonttools::tables::GPOS::{Positioning, GPOS};
 use fonttools::tag;
 use otspec::layout::valuerecord::ValueRecord;
 use otspec::valuerecord;
 
-pub fn build_kerning(font: &Font, mapping: &BTreeMap<String, u16>) -> GPOS {
+/// Flattens the default master's kerning into glyph ID pairs, expanding
+/// kerning groups into their member glyphs.
+pub fn flatten_kerning(font: &Font, mapping: &BTreeMap<String, u16>) -> BTreeMap<(u16, u16), i16> {
     let master = font.default_master().unwrap();
-    let mut kerntable: PairPositioningMap = BTreeMap::new();
+    let mut pairs = BTreeMap::new();
     for ((l, r), value) in master.kerning.iter() {
         let l_array: Vec<String> = if let Some(stripped) = l.strip_prefix('@') {
             font.kern_groups.get(stripped).unwrap_or(&vec![]).to_vec()
@@ -27,10 +30,18 @@ pub fn build_kerning(font: &Font, mapping: &BTreeMap<String, u16>) -> GPOS {
 
         for l in &l_array {
             for r in &r_array {
-                add_single_kern(&mut kerntable, l, r, *value, mapping);
+                add_single_kern(&mut pairs, l, r, *value, mapping);
             }
         }
     }
+    pairs
+}
+
+pub fn build_kerning(pairs: &BTreeMap<(u16, u16), i16>) -> GPOS {
+    let kerntable: PairPositioningMap = pairs
+        .iter()
+        .map(|(&pair, &value)| (pair, (valuerecord!(xAdvance = value), valuerecord!())))
+        .collect();
     let pairpos = PairPos { mapping: kerntable };
     GPOS {
         lookups: vec![Lookup {
@@ -51,11 +62,27 @@ pub fn build_kerning(font: &Font, mapping: &BTreeMap<String, u16>) -> GPOS {
             )]),
         },
         features: FeatureList::new(vec![(tag!("kern"), vec![0], None)]),
+        feature_variations: vec![],
+    }
+}
+
+/// Builds a legacy `kern` table of format 0 subtables. The 16-bit subtable length
+/// limits each subtable to around 11k pairs, and many applications only look at
+/// the first one.
+pub fn build_kern_table(pairs: &BTreeMap<(u16, u16), i16>) -> kern {
+    let table = kern::from_pairs(pairs);
+    if table.subtables.len() > 1 {
+        log::warn!(
+            "{} kerning pairs need {} kern subtables; some applications only read the first",
+            pairs.len(),
+            table.subtables.len()
+        );
     }
+    table
 }
 
 fn add_single_kern(
-    kerntable: &mut PairPositioningMap,
+    pairs: &mut BTreeMap<(u16, u16), i16>,
     l: &str,
     r: &str,
     value: i16,
@@ -73,10 +100,7 @@ fn add_single_kern(
         return;
     }
     let r_gid = r_gid.unwrap();
-    kerntable.insert(
-        (*l_gid, *r_gid),
-        (valuerecord!(xAdvance = value), valuerecord!()),
-    );
+    pairs.insert((*l_gid, *r_gid), value);
 }
 /*
 PairPos {
@@ -1,10 +1,14 @@
 //! A fonticulously fast variable font builder
+mod base;
 mod basictables;
 mod buildbasic;
 mod fontinfo;
+mod gdef;
 mod glyph;
+mod instance;
 mod kerning;
 mod notdef;
+mod rules;
 mod utils;
 
 // use rayon::prelude::*;
@@ -26,10 +30,22 @@ struct Args {
     #[clap(long)]
     skip_layout: bool,
 
+    /// Also emit a legacy format 0 `kern` table for applications which don't read GPOS
+    #[clap(long)]
+    kern_table: bool,
+
     /// Don't make a variable font, make a static font for each master
     #[clap(long)]
     masters: bool,
 
+    /// Don't make a variable font, make a static font for each instance
+    #[clap(long, conflicts_with = "masters")]
+    instances: bool,
+
+    /// Emit an avar version 2 table from any cross-axis mappings in the source
+    #[clap(long)]
+    avar2: bool,
+
     /// Increase logging
     #[clap(short, long, parse(from_occurrences))]
     verbose: usize,
@@ -49,7 +65,7 @@ struct Args {
     3a) fontinfo.rs works out what some of the stuff in those tables should be.
     4) glyph.rs handles Babelfont->OT glyph conversion, creating the glyf and gvar
        table entries for each glyph.
-    5) babelfont-rs creates the variable metadata tables (fvar,avar).
+    5) babelfont-rs creates the variable metadata tables (fvar,avar,STAT).
     6) We come back here and save the files at the end.
 */
 
@@ -76,15 +92,30 @@ fn main() {
 
     let mut in_font = babelfont::load(&args.input).expect("Couldn't load font");
 
+    // Cross-axis mappings need avar2-aware renderers, so only use them on request
+    if !args.avar2 && !in_font.axis_mappings.is_empty() {
+        log::warn!("Ignoring cross-axis mappings; use --avar2 to emit them");
+        in_font.axis_mappings.clear();
+    }
+
     // --masters means we produce a TTF for each master and don't do interpolation
     if args.masters {
-        create_ttf_per_master(&mut in_font, subset.as_ref(), args.skip_layout);
+        create_ttf_per_master(
+            &mut in_font,
+            subset.as_ref(),
+            args.skip_layout,
+            args.kern_table,
+        );
+    } else if args.instances {
+        // --instances interpolates each instance and produces a TTF for it
+        create_ttf_per_instance(&in_font, subset.as_ref(), args.skip_layout, args.kern_table);
     } else {
         create_variable_font(
             &mut in_font,
             subset.as_ref(),
             &args.output,
             args.skip_layout,
+            args.kern_table,
         );
     }
 }
@@ -93,6 +124,7 @@ fn create_ttf_per_master(
     in_font: &mut babelfont::Font,
     subset: Option<&HashSet<&str>>,
     skip_layout: bool,
+    kern_table: bool,
 ) {
     let family_name = in_font
         .names
@@ -116,7 +148,7 @@ fn create_ttf_per_master(
         })
         .collect();
     for (ix, master_name) in master_names.iter().enumerate() {
-        let mut out_font = build_font(in_font, subset, Some(ix), skip_layout);
+        let mut out_font = build_font(in_font, subset, Some(ix), skip_layout, kern_table);
         log::info!("Building {}", master_name);
         out_font
             .save(format!("{}-{}.ttf", family_name, master_name))
@@ -124,21 +156,42 @@ fn create_ttf_per_master(
     }
 }
 
+fn create_ttf_per_instance(
+    in_font: &babelfont::Font,
+    subset: Option<&HashSet<&str>>,
+    skip_layout: bool,
+    kern_table: bool,
+) {
+    if in_font.instances.is_empty() {
+        log::warn!("No instances found in the source");
+    }
+    for instance in &in_font.instances {
+        let mut instance_font = instance::instantiate(in_font, instance);
+        let postscript_name = fontinfo::postscript_font_name(&instance_font);
+        log::info!("Building {}", postscript_name);
+        let mut out_font = build_font(&mut instance_font, subset, Some(0), skip_layout, kern_table);
+        out_font
+            .save(format!("{}.ttf", postscript_name))
+            .expect("Could not write font");
+    }
+}
+
 fn create_variable_font(
     in_font: &mut babelfont::Font,
     subset: Option<&HashSet<&str>>,
     output: &Option<String>,
     skip_layout: bool,
+    kern_table: bool,
 ) {
     let mut out_font;
     if in_font.masters.len() > 1 {
-        out_font = build_font(in_font, subset, None, skip_layout);
+        out_font = build_font(in_font, subset, None, skip_layout, kern_table);
         // Ask babelfont to make fvar/avar
         in_font
             .add_variation_tables(&mut out_font)
             .expect("Couldn't add variation tables");
     } else {
-        out_font = build_font(in_font, subset, Some(0), skip_layout);
+        out_font = build_font(in_font, subset, Some(0), skip_layout, kern_table);
     }
 
     match output {
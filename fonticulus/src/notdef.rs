@@ -1,4 +1,6 @@
-use babelfont::{Font, Glyph, GlyphCategory, Layer, Node, NodeType, Path, PathDirection, Shape};
+use babelfont::{
+    Font, Glyph, GlyphCategory, Layer, Location, Node, NodeType, Path, PathDirection, Shape,
+};
 use otmath::ot_round;
 
 fn make_box<T>(x_min: T, y_min: T, x_max: T, y_max: T, reverse: bool) -> Vec<Node>
@@ -11,21 +13,25 @@ where
                 x: x_max.into(),
                 y: y_min.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_max.into(),
                 y: y_max.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_min.into(),
                 y: y_max.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_min.into(),
                 y: y_min.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
         ]
     } else {
@@ -34,21 +40,25 @@ where
                 x: x_min.into(),
                 y: y_max.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_max.into(),
                 y: y_max.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_max.into(),
                 y: y_min.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
             Node {
                 x: x_min.into(),
                 y: y_min.into(),
                 nodetype: NodeType::Line,
+                smooth: false,
             },
         ]
     }
@@ -70,6 +80,8 @@ pub(crate) fn add_notdef(input: &mut Font) {
         layers: vec![],
         exported: true,
         direction: None,
+        smart_axes: vec![],
+        lib: Default::default(),
     };
     for master in &input.masters {
         let ascender = master
@@ -97,6 +109,8 @@ pub(crate) fn add_notdef(input: &mut Font) {
 
         let mut l = Layer {
             width: width as i32,
+            height: None,
+            vertical_origin: None,
             name: None,
             id: Some(master.id.clone()),
             guides: vec![],
@@ -107,6 +121,8 @@ pub(crate) fn add_notdef(input: &mut Font) {
             is_background: false,
             background_layer_id: None,
             location: None,
+            associated_master_id: None,
+            smart_location: Location::new(),
         };
         l.shapes.push(Shape::PathShape(Path {
             nodes: p1,
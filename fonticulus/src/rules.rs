@@ -0,0 +1,139 @@
+//! Builds a `GSUB` table from the source's rules, which substitute alternate
+//! glyphs within regions of the designspace, using `FeatureVariations`.
+//!
+//! Only the first matching feature variation record is applied, so where
+//! the regions of several rules overlap, the overlap gets a record of its
+//! own which applies all of their lookups.
+use babelfont::{Condition, Font};
+use fonttools::layout::common::{
+    FeatureList, FeatureVariation, LanguageSystem, Lookup, LookupFlags, Script, ScriptList,
+};
+use fonttools::layout::gsub1::SingleSubst;
+use fonttools::tables::GSUB::{Substitution, GSUB};
+use fonttools::tag;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A (minimum, maximum) range on each axis, in normalized coordinates
+type Region = Vec<(f32, f32)>;
+
+fn condition_set_region(font: &Font, conditions: &[Condition]) -> Option<Region> {
+    let mut region: Region = vec![(-1.0, 1.0); font.axes.len()];
+    for condition in conditions {
+        let ix = match font.axes.iter().position(|a| a.tag == condition.axis) {
+            Some(ix) => ix,
+            None => {
+                log::warn!("Unknown axis {} in rule condition", condition.axis);
+                return None;
+            }
+        };
+        let axis = &font.axes[ix];
+        let normalize = |value: Option<f32>, unbounded: f32| match value {
+            Some(v) => axis.normalize_designspace_value(v).ok(),
+            None => Some(unbounded),
+        };
+        let (min, max) = region[ix];
+        region[ix] = (
+            min.max(normalize(condition.min, -1.0)?),
+            max.min(normalize(condition.max, 1.0)?),
+        );
+    }
+    intersect(&region, &region)
+}
+
+fn intersect(a: &Region, b: &Region) -> Option<Region> {
+    let region: Region = a
+        .iter()
+        .zip(b.iter())
+        .map(|(&(a_min, a_max), &(b_min, b_max))| (a_min.max(b_min), a_max.min(b_max)))
+        .collect();
+    if region.iter().any(|(min, max)| min > max) {
+        None
+    } else {
+        Some(region)
+    }
+}
+
+/// Builds a `GSUB` table with one single substitution lookup per rule,
+/// applied through the `rvrn` feature (or `rclt`, if rules are processed
+/// last). Returns `None` if no rule applies to the glyphs in the font.
+pub fn build_rules(font: &Font, mapping: &BTreeMap<String, u16>) -> Option<GSUB> {
+    let mut lookups = vec![];
+    // Each region, and the lookups which apply within it
+    let mut regions: Vec<(Region, BTreeSet<usize>)> = vec![];
+    for rule in &font.rules {
+        let substitutions: BTreeMap<u16, u16> = rule
+            .substitutions
+            .iter()
+            .filter_map(|(glyph, alternate)| Some((*mapping.get(glyph)?, *mapping.get(alternate)?)))
+            .collect();
+        if substitutions.is_empty() {
+            continue;
+        }
+        let lookup_ix = lookups.len();
+        lookups.push(Lookup {
+            flags: LookupFlags::empty(),
+            mark_filtering_set: None,
+            rule: Substitution::Single(vec![SingleSubst {
+                mapping: substitutions,
+            }]),
+        });
+        for conditions in &rule.condition_sets {
+            let region = match condition_set_region(font, conditions) {
+                Some(r) => r,
+                None => continue,
+            };
+            let overlaps: Vec<(Region, BTreeSet<usize>)> = regions
+                .iter()
+                .filter_map(|(other, other_lookups)| {
+                    let overlap = intersect(&region, other)?;
+                    let mut lookups = other_lookups.clone();
+                    lookups.insert(lookup_ix);
+                    Some((overlap, lookups))
+                })
+                .collect();
+            regions.extend(overlaps);
+            regions.push((region, BTreeSet::from([lookup_ix])));
+        }
+    }
+    if regions.is_empty() {
+        return None;
+    }
+    // Overlaps must come before the regions they are part of
+    regions.sort_by_key(|(_, lookups)| Reverse(lookups.len()));
+
+    let feature_variations = regions
+        .into_iter()
+        .map(|(region, lookups)| FeatureVariation {
+            conditions: region
+                .into_iter()
+                .enumerate()
+                .filter(|(_, (min, max))| *min > -1.0 || *max < 1.0)
+                .map(|(ix, (min, max))| (ix as u16, min, max))
+                .collect(),
+            substitutions: BTreeMap::from([(0, lookups.into_iter().collect())]),
+        })
+        .collect();
+    let feature = if font.rules_processing_last {
+        tag!("rclt")
+    } else {
+        tag!("rvrn")
+    };
+    Some(GSUB {
+        lookups,
+        scripts: ScriptList {
+            scripts: BTreeMap::from([(
+                tag!("DFLT"),
+                Script {
+                    default_language_system: Some(LanguageSystem {
+                        required_feature: None,
+                        feature_indices: vec![0],
+                    }),
+                    language_systems: BTreeMap::new(),
+                },
+            )]),
+        },
+        features: FeatureList::new(vec![(feature, vec![], None)]),
+        feature_variations,
+    })
+}
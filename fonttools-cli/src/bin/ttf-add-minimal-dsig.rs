@@ -1,4 +1,4 @@
-use fonttools::tag;
+use fonttools::tables::DSIG::{DSIG, TAG};
 use fonttools_cli::{open_font, read_args, save_font};
 
 fn main() {
@@ -8,11 +8,8 @@ fn main() {
     );
     let mut infont = open_font(&matches);
 
-    if !infont.tables.contains(&tag!("DSIG")) {
-        infont.tables.insert_raw(
-            tag!("DSIG"),
-            vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00],
-        );
+    if !infont.tables.contains(&TAG) {
+        infont.tables.insert(DSIG::default());
     }
     save_font(infont, &matches);
 }
@@ -0,0 +1,66 @@
+use clap::{App, Arg};
+use fonttools::types::Tag;
+use fonttools_cli::open_font;
+use std::fs::File;
+use std::io::{self, Write};
+
+fn main() {
+    env_logger::init();
+    let matches = App::new("ttf-dump-json")
+        .about("Dumps font tables as JSON or YAML")
+        .arg(
+            Arg::with_name("table")
+                .short("t")
+                .long("table")
+                .help("Only dump the given table (can be repeated)")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("yaml")
+                .long("yaml")
+                .help("Write YAML instead of JSON"),
+        )
+        .arg(
+            Arg::with_name("INPUT")
+                .help("Sets the input file to use")
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("OUTPUT")
+                .help("Sets the output file to use")
+                .required(false),
+        )
+        .get_matches();
+    let mut font = open_font(&matches);
+    // Tables depend on each other for deserialization, so load everything
+    // before throwing anything away.
+    font.fully_deserialize();
+    if let Some(tags) = matches.values_of("table") {
+        let wanted: Vec<Tag> = tags
+            .map(|tag| Tag::from_raw(tag).expect("Invalid table tag"))
+            .collect();
+        let unwanted: Vec<Tag> = font
+            .tables
+            .keys()
+            .filter(|tag| !wanted.contains(tag))
+            .collect();
+        for tag in unwanted {
+            font.tables.remove(tag);
+        }
+    }
+
+    let output = matches.value_of("OUTPUT");
+    let yaml = matches.is_present("yaml")
+        || output.is_some_and(|path| path.ends_with(".yaml") || path.ends_with(".yml"));
+    let writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(File::create(path).expect("Could not create output file")),
+        None => Box::new(io::stdout()),
+    };
+    if yaml {
+        serde_yaml::to_writer(writer, &font.tables).expect("Could not write YAML");
+    } else {
+        serde_json::to_writer_pretty(writer, &font.tables).expect("Could not write JSON");
+    }
+}
@@ -6,126 +6,6 @@ use serde::Serialize;
 use std::collections::{BTreeMap, BTreeSet};
 use std::io;
 
-fn get_math_record<S>(mvr: &MathValueRecord, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_i16(mvr.value)
-}
-
-#[derive(Serialize)]
-#[serde(remote = "MathConstants")]
-#[allow(non_snake_case)]
-struct MathConstantsDef {
-    scriptPercentScaleDown: int16,
-    scriptScriptPercentScaleDown: int16,
-    delimitedSubFormulaMinHeight: UFWORD,
-    displayOperatorMinHeight: UFWORD,
-    #[serde(serialize_with = "get_math_record")]
-    mathLeading: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    axisHeight: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    accentBaseHeight: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    flattenedAccentBaseHeight: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    subscriptShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    subscriptTopMax: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    subscriptBaselineDropMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    superscriptShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    superscriptShiftUpCramped: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    superscriptBottomMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    superscriptBaselineDropMax: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    subSuperscriptGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    superscriptBottomMaxWithSubscript: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    spaceAfterScript: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    upperLimitGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    upperLimitBaselineRiseMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    lowerLimitGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    lowerLimitBaselineDropMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackTopShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackTopDisplayStyleShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackBottomShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackBottomDisplayStyleShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stackDisplayStyleGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stretchStackTopShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stretchStackBottomShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stretchStackGapAboveMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    stretchStackGapBelowMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionNumeratorShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionNumeratorDisplayStyleShiftUp: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionDenominatorShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionDenominatorDisplayStyleShiftDown: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionNumeratorGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionNumDisplayStyleGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionRuleThickness: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionDenominatorGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    fractionDenomDisplayStyleGapMin: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    skewedFractionHorizontalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    skewedFractionVerticalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    overbarVerticalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    overbarRuleThickness: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    overbarExtraAscender: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    underbarVerticalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    underbarRuleThickness: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    underbarExtraDescender: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalVerticalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalDisplayStyleVerticalGap: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalRuleThickness: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalExtraAscender: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalKernBeforeDegree: MathValueRecord,
-    #[serde(serialize_with = "get_math_record")]
-    radicalKernAfterDegree: MathValueRecord,
-    radicalDegreeBottomRaisePercent: int16,
-}
-
 #[derive(Serialize)]
 struct SimpleKernRecord {
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -176,39 +56,15 @@ impl SimpleMathKern {
     }
 }
 
-fn get_mgc_map<S>(
-    _mgc: &BTreeMap<String, MathGlyphConstruction>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_i16(1)
-}
-
-#[allow(dead_code)]
-fn get_mvr_map<S>(
-    _mgc: &BTreeMap<String, MathValueRecord>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_i16(1)
-}
-
 #[derive(Serialize)]
 pub struct MathEvenEasier {
-    #[serde(with = "MathConstantsDef")]
     pub constants: MathConstants,
     pub italic_correction: BTreeMap<String, int16>,
     pub top_accent_attachment: BTreeMap<String, int16>,
     pub extended_shapes: BTreeSet<String>,
     pub kerning: BTreeMap<String, SimpleMathKern>,
     pub min_overlap: Option<UFWORD>,
-    #[serde(serialize_with = "get_mgc_map")]
     pub vertical_extensions: BTreeMap<String, MathGlyphConstruction>,
-    #[serde(serialize_with = "get_mgc_map")]
     pub horizontal_extensions: BTreeMap<String, MathGlyphConstruction>,
 }
 
@@ -266,8 +122,32 @@ fn simplify(math: &MATH, glyph_names: Vec<String>) -> MathEvenEasier {
                 )
             })
             .collect(),
-        horizontal_extensions: BTreeMap::new(),
-        vertical_extensions: BTreeMap::new(),
+        horizontal_extensions: math
+            .horizontal_extensions
+            .iter()
+            .map(|(gid, construction)| {
+                (
+                    glyph_names
+                        .get(*gid as usize)
+                        .cloned()
+                        .unwrap_or_else(|| format!("\\{:}", gid)),
+                    construction.clone(),
+                )
+            })
+            .collect(),
+        vertical_extensions: math
+            .vertical_extensions
+            .iter()
+            .map(|(gid, construction)| {
+                (
+                    glyph_names
+                        .get(*gid as usize)
+                        .cloned()
+                        .unwrap_or_else(|| format!("\\{:}", gid)),
+                    construction.clone(),
+                )
+            })
+            .collect(),
     }
 }
 
@@ -24,7 +24,7 @@ fn main() {
                 .required(true),
         ).arg(
             Arg::with_name("loc-args")
-                .help("List of space separated locations. A location consists of the tag of a variation axis, followed by '=' and one of number, number:number or the literal string 'drop'. E.g.: wdth=100 or wght=75.0:125.0 or wght=drop")
+                .help("List of space separated locations. A location consists of the tag of a variation axis, followed by '=' and one of number, number:number, number:number:number (minimum, maximum and new default) or the literal string 'drop'. E.g.: wdth=100 or wght=75.0:125.0 or wght=300:500:400 or wght=drop")
                  .multiple(true)
                 .required(true),
         )
@@ -52,20 +52,22 @@ fn main() {
     }
 
     log::debug!("Axis limits = {:?}", limits);
-    if instantiate_variable_font(&mut infont, limits) {
-        if let Some(out_fn) = matches.value_of("output") {
-            log::info!("Saving on {}", out_fn);
-            infont.save(out_fn)
-        } else {
-            let input_filename = matches.value_of("INPUT").unwrap();
-            let out_fn = Path::new(input_filename)
-                .with_extension("")
-                .with_extension("partial.ttf");
-            log::info!("Saving on {}", out_fn.to_str().unwrap());
-            infont.save(out_fn)
-        }
-        .unwrap();
+    if let Err(e) = instantiate_variable_font(&mut infont, limits) {
+        println!("Couldn't instantiate font: {}", e);
+        return;
+    }
+    if let Some(out_fn) = matches.value_of("output") {
+        log::info!("Saving on {}", out_fn);
+        infont.save(out_fn)
+    } else {
+        let input_filename = matches.value_of("INPUT").unwrap();
+        let out_fn = Path::new(input_filename)
+            .with_extension("")
+            .with_extension("partial.ttf");
+        log::info!("Saving on {}", out_fn.to_str().unwrap());
+        infont.save(out_fn)
     }
+    .unwrap();
 }
 
 fn str_to_fixed_to_float(s: &str) -> f32 {
@@ -74,7 +76,8 @@ fn str_to_fixed_to_float(s: &str) -> f32 {
 
 fn parse_locargs(locargs: Vec<&str>) -> UserAxisLimits {
     let mut res = BTreeMap::new();
-    let matcher = Regex::new(r"^(\w{1,4})=(?:(drop)|(?:([^:]+)(?:[:](.+))?))$").unwrap();
+    let matcher =
+        Regex::new(r"^(\w{1,4})=(?:(drop)|(?:([^:]+)(?:[:]([^:]+))?(?:[:]([^:]+))?))$").unwrap();
     for limit_string in locargs {
         let captures = matcher
             .captures(limit_string)
@@ -89,7 +92,15 @@ fn parse_locargs(locargs: Vec<&str>) -> UserAxisLimits {
         if let Some(ustr) = captures.get(4) {
             upper = Some(str_to_fixed_to_float(ustr.as_str()));
         }
-        if upper != lower {
+        let default = captures
+            .get(5)
+            .map(|dstr| str_to_fixed_to_float(dstr.as_str()));
+        if let Some(d) = default {
+            res.insert(
+                btag,
+                UserAxisLimit::Partial(AxisRange::with_default(lower.unwrap(), upper.unwrap(), d)),
+            );
+        } else if upper != lower {
             res.insert(
                 btag,
                 UserAxisLimit::Partial(AxisRange::new(lower.unwrap(), upper.unwrap())),
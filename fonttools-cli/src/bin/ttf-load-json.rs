@@ -0,0 +1,38 @@
+use clap::{App, Arg};
+use fonttools::table_store::TableSet;
+use fonttools_cli::{open_font, save_font};
+use std::fs::File;
+use std::io::BufReader;
+
+fn main() {
+    env_logger::init();
+    let matches = App::new("ttf-load-json")
+        .about("Replaces font tables with tables loaded from JSON or YAML")
+        .arg(
+            Arg::with_name("TABLES")
+                .help("A JSON or YAML file of tables, as written by ttf-dump-json")
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("INPUT")
+                .help("Sets the input file to use")
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("OUTPUT")
+                .help("Sets the output file to use")
+                .required(false),
+        )
+        .get_matches();
+    let path = matches.value_of("TABLES").unwrap();
+    let reader = BufReader::new(File::open(path).expect("Could not open tables file"));
+    let tables: TableSet = if path.ends_with(".yaml") || path.ends_with(".yml") {
+        serde_yaml::from_reader(reader).expect("Could not parse YAML")
+    } else {
+        serde_json::from_reader(reader).expect("Could not parse JSON")
+    };
+
+    let mut font = open_font(&matches);
+    font.tables.update(tables);
+    save_font(font, &matches);
+}
@@ -0,0 +1,62 @@
+use clap::{App, Arg};
+use fonttools::font::Font;
+
+fn main() {
+    let matches = App::new("ttf-merge-masters")
+        .about("Merges compiled master TTFs into a variable font")
+        .arg(Arg::from_usage(
+            "-o, --output=[FILE]  Output variable TTF file",
+        ))
+        .arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .multiple(true)
+                .required(false)
+                .help("Run more verbosely"),
+        )
+        .arg(
+            Arg::with_name("DESIGNSPACE")
+                .help("The designspace file describing the masters")
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("MASTERS")
+                .help("Master TTF files, one for each source in the designspace, in the same order")
+                .multiple(true)
+                .required(true),
+        )
+        .get_matches();
+
+    if matches.is_present("verbose") {
+        simple_logger::init_with_level(log::Level::Debug).unwrap();
+    } else {
+        simple_logger::init_with_level(log::Level::Warn).unwrap();
+    }
+
+    let ds = designspace::from_file(matches.value_of("DESIGNSPACE").unwrap())
+        .expect("Couldn't parse designspace file");
+    let masters: Vec<Font> = matches
+        .values_of("MASTERS")
+        .unwrap()
+        .map(|filename| Font::load(filename).expect("Could not parse font"))
+        .collect();
+    if masters.len() != ds.sources.source.len() {
+        println!(
+            "Designspace has {} sources but {} masters were given",
+            ds.sources.source.len(),
+            masters.len()
+        );
+        return;
+    }
+
+    let mut font = match ds.build_variable_font(masters) {
+        Ok(font) => font,
+        Err(e) => {
+            println!("Couldn't merge masters: {}", e);
+            return;
+        }
+    };
+    let out_fn = matches.value_of("output").unwrap_or("merged-VF.ttf");
+    log::info!("Saving on {}", out_fn);
+    font.save(out_fn).unwrap();
+}
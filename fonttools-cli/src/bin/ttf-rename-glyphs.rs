@@ -5,16 +5,12 @@ use itertools::Itertools;
 use std::collections::{BTreeMap, HashSet};
 
 fn build_production_name(name: &str, unicodes: Option<&HashSet<u32>>) -> String {
-    if unicodes.is_none() {
-        return name.to_string();
-    }
-    let first: u32 = *unicodes.unwrap().iter().sorted().next().unwrap();
-    if first == 0x20 {
-        "space".to_string()
-    } else if first > 0xFFFF {
-        format!("u{:04X}", first)
+    if let Some(&first) = unicodes.and_then(|u| u.iter().sorted().next()) {
+        glyphdata::info_for_codepoint(first)
+            .map(|info| info.production_name)
+            .unwrap_or_else(|| glyphdata::uni_name(first))
     } else {
-        format!("uni{:04X}", first)
+        glyphdata::production_name(name)
     }
 }
 
@@ -59,10 +55,16 @@ fn main() {
                 .glyphnames
                 .as_mut()
                 .expect("post table didn't have any names");
+            let mut used = HashSet::new();
             for i in 0..num_glyphs {
-                let prod_name =
-                    build_production_name(&glyphnames[i as usize], reversed_map.get(&i));
-                glyphnames[i as usize] = prod_name;
+                let name = &glyphnames[i as usize];
+                let prod_name = build_production_name(name, reversed_map.get(&i));
+                // Glyphs can't share a name, so any later ones keep their own
+                if used.insert(prod_name.clone()) {
+                    glyphnames[i as usize] = prod_name;
+                } else {
+                    used.insert(name.clone());
+                }
             }
         }
         infont.tables.insert(post);
@@ -0,0 +1,45 @@
+use clap::{App, Arg};
+use fonttools::{font::Font, ttx};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+fn main() {
+    let matches = App::new("ttf-ttx")
+        .about("Dumps a font to TTX-style XML, or compiles TTX back to a font")
+        .arg(
+            Arg::with_name("INPUT")
+                .help("The input font or .ttx file")
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("OUTPUT")
+                .help("Sets the output file to use")
+                .required(false),
+        )
+        .get_matches();
+    simple_logger::init_with_level(log::Level::Warn).unwrap();
+
+    let input = matches.value_of("INPUT").unwrap();
+    let output = matches.value_of("OUTPUT");
+    if Path::new(input)
+        .extension()
+        .map_or(false, |ext| ext == "ttx")
+    {
+        let xml = fs::read_to_string(input).expect("Could not read TTX file");
+        let mut font = ttx::from_xml(&xml).expect("Could not compile TTX file");
+        match output {
+            Some(path) => font.save(path),
+            None => font.write(io::stdout()),
+        }
+        .expect("Could not save font");
+    } else {
+        let mut font = Font::load(input).expect("Could not parse font");
+        let xml = ttx::to_xml(&mut font).expect("Could not dump font");
+        match output {
+            Some(path) => fs::write(path, xml),
+            None => io::stdout().write_all(xml.as_bytes()),
+        }
+        .expect("Could not write TTX file");
+    }
+}
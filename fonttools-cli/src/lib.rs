@@ -9,12 +9,16 @@
 //!
 //!  * `fontcrunch` - A Rust port of https://github.com/googlefonts/fontcrunch
 //!  * `ttf-add-minimal-dsig` - Adds a minimal DSIG table if one is not present
+//!  * `ttf-dump-json` - Dumps font tables as JSON or YAML
 //!  * `ttf-fix-checksum` - Ensures TTF files have correct checksum
 //!  * `ttf-fix-non-hinted` - Adds a `gasp` and `prep` table which is set to smooth for all sizes
 //!  * `ttf-flatten-components` - Flattens components
+//!  * `ttf-load-json` - Replaces font tables with tables loaded from JSON or YAML
+//!  * `ttf-merge-masters` - Merges compiled master TTFs into a variable font
 //!  * `ttf-optimize-gvar` - Optimizes the gvar table by omitting points which can be inferred
 //!  * `ttf-remove-overlap` - Removes overlap from TTF files
 //!  * `ttf-rename-glyphs` - Renames glyphs to production names
+//!  * `ttf-ttx` - Dumps a font to TTX-style XML, or compiles TTX back to a font
 
 use clap::{App, Arg};
 use fonttools::font::Font;
@@ -0,0 +1,242 @@
+//! Reading and writing TrueType and OpenType font collections.
+//!
+//! A collection (`.ttc` or `.otc` file) holds several fonts in one file,
+//! with tables which are identical between the fonts stored only once.
+use crate::font::{read_table_directory, Font};
+use crate::woff::{block, pad4};
+use otspec::types::*;
+use otspec::{DeserializationError, Deserialize, Deserializer, ReaderContext};
+use otspec_macros::tables;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+/// The tag at the start of a font collection file
+pub const TAG: Tag = crate::tag!("ttcf");
+const DSIG: Tag = crate::tag!("DSIG");
+
+tables!(
+    TTCHeader {
+        Tag ttcTag
+        uint16 majorVersion
+        uint16 minorVersion
+        uint32 numFonts
+    }
+    DSIGRecord {
+        uint32 dsigTag
+        uint32 dsigLength
+        uint32 dsigOffset
+    }
+);
+
+/// A TrueType or OpenType font collection
+#[derive(Debug, PartialEq)]
+pub struct FontCollection {
+    /// The fonts in the collection
+    pub fonts: Vec<Font>,
+    /// The collection's digital signature (the contents of a `DSIG` table).
+    /// If present, the collection is written with a version 2.0 header.
+    pub dsig: Option<Vec<u8>>,
+}
+
+impl FontCollection {
+    /// Creates a collection of the given fonts.
+    pub fn new(fonts: Vec<Font>) -> Self {
+        FontCollection { fonts, dsig: None }
+    }
+
+    /// Attempt to load a font collection from disk.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let bytes = std::fs::read(path.as_ref())?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Attempt to load a font collection from a raw byte slice.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        otspec::de::from_bytes(bytes).map_err(|e| e.into())
+    }
+
+    /// Attempt to load a font collection from any reader.
+    pub fn from_reader(mut reader: impl std::io::Read) -> Result<Self, Box<dyn Error>> {
+        let mut buf = Vec::new();
+        let _ = reader.read_to_end(&mut buf)?;
+        Self::from_bytes(&buf)
+    }
+
+    /// Attempt to save the collection to the provided path.
+    pub fn save(&mut self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        let file = std::fs::File::create(path)?;
+        self.write(file)
+    }
+
+    /// Attempt to write the collection into the provided [`Writer`][std::io::Write].
+    ///
+    /// Tables which are byte-for-byte identical between fonts are written
+    /// once and shared.
+    pub fn write(&mut self, mut writer: impl std::io::Write) -> Result<(), Box<dyn Error>> {
+        let mut members = Vec::with_capacity(self.fonts.len());
+        for font in self.fonts.iter_mut() {
+            // Each font is compiled on its own first, so that its checksums
+            // are those of a standalone font.
+            font.compile();
+            members.push(otspec::ser::to_bytes(font)?);
+        }
+        let bytes = compile_collection(&members, self.dsig.as_deref())?;
+        writer.write_all(&bytes).map_err(Into::into)
+    }
+}
+
+/// Assembles binary sfnt fonts into a collection file.
+fn compile_collection(
+    members: &[Vec<u8>],
+    dsig: Option<&[u8]>,
+) -> Result<Vec<u8>, DeserializationError> {
+    let directories = members
+        .iter()
+        .map(|sfnt| read_table_directory(sfnt))
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    let header_size = 12 + 4 * members.len() + if dsig.is_some() { 12 } else { 0 };
+    let directories_size: usize = directories
+        .iter()
+        .map(|(_, records)| 12 + 16 * records.len())
+        .sum();
+    let data_start = header_size + directories_size;
+
+    let mut offsets = vec![];
+    let mut directory_data = vec![];
+    let mut table_data = vec![];
+    let mut shared: HashMap<&[u8], usize> = HashMap::new();
+    for (sfnt, (_, records)) in members.iter().zip(directories.iter()) {
+        offsets.push((header_size + directory_data.len()) as uint32);
+        directory_data.extend_from_slice(&sfnt[0..12]);
+        for record in records {
+            let data = &sfnt[record.offset as usize..(record.offset + record.length) as usize];
+            let offset = *shared.entry(data).or_insert_with(|| {
+                let offset = data_start + table_data.len();
+                table_data.extend_from_slice(data);
+                pad4(&mut table_data);
+                offset
+            });
+            directory_data.extend(record.tag.as_bytes());
+            directory_data.extend(record.checksum.to_be_bytes());
+            directory_data.extend((offset as uint32).to_be_bytes());
+            directory_data.extend(record.length.to_be_bytes());
+        }
+    }
+
+    let mut out = vec![];
+    out.extend(TAG.as_bytes());
+    out.extend(if dsig.is_some() { 2_u16 } else { 1 }.to_be_bytes());
+    out.extend(0_u16.to_be_bytes());
+    out.extend((members.len() as uint32).to_be_bytes());
+    for offset in offsets {
+        out.extend(offset.to_be_bytes());
+    }
+    if let Some(dsig) = dsig {
+        let dsig_offset = data_start + table_data.len();
+        out.extend(DSIG.as_bytes());
+        out.extend((dsig.len() as uint32).to_be_bytes());
+        out.extend((dsig_offset as uint32).to_be_bytes());
+    }
+    out.extend(directory_data);
+    out.extend(table_data);
+    if let Some(dsig) = dsig {
+        out.extend_from_slice(dsig);
+    }
+    Ok(out)
+}
+
+impl Deserialize for FontCollection {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let header: TTCHeader = c.de()?;
+        if header.ttcTag != TAG {
+            return Err(DeserializationError(
+                "Font collection must begin with 'ttcf'".to_string(),
+            ));
+        }
+        let offsets: Vec<uint32> = c.de_counted(header.numFonts as usize)?;
+        let mut dsig = None;
+        if header.majorVersion >= 2 {
+            let record: DSIGRecord = c.de()?;
+            if record.dsigTag == u32::from_be_bytes(*DSIG.as_bytes()) && record.dsigLength > 0 {
+                dsig = Some(block(&c.input, record.dsigOffset, record.dsigLength)?.to_vec());
+            }
+        }
+        let mut fonts = Vec::with_capacity(offsets.len());
+        for offset in offsets {
+            c.ptr = offset as usize;
+            fonts.push(c.de()?);
+        }
+        Ok(FontCollection { fonts, dsig })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::SfntVersion;
+    use crate::tag;
+
+    fn test_font(own_data: u8) -> Font {
+        let mut font = Font::new(SfntVersion::TrueType);
+        font.tables
+            .insert(crate::tables::head::new(1.0, 1000, 0, 0, 0, 0));
+        font.tables.insert(crate::tables::maxp::maxp::new05(0));
+        font.tables.insert_raw(tag!("SHRD"), vec![0x61; 30]);
+        font.tables.insert_raw(tag!("OWN "), vec![own_data; 20]);
+        font
+    }
+
+    fn table_offset(bytes: &[u8], directory: usize, tag: Tag) -> Option<u32> {
+        let num_tables = u16::from_be_bytes([bytes[directory + 4], bytes[directory + 5]]);
+        (0..num_tables as usize)
+            .map(|i| &bytes[directory + 12 + 16 * i..directory + 28 + 16 * i])
+            .find(|record| record[0..4] == *tag.as_bytes())
+            .map(|record| u32::from_be_bytes(record[8..12].try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_collection_roundtrip() {
+        let mut collection = FontCollection::new(vec![test_font(1), test_font(2)]);
+        let mut bytes = vec![];
+        collection.write(&mut bytes).unwrap();
+        assert_eq!(&bytes[0..12], b"ttcf\x00\x01\x00\x00\x00\x00\x00\x02");
+
+        let first = u32::from_be_bytes(bytes[12..16].try_into().unwrap()) as usize;
+        let second = u32::from_be_bytes(bytes[16..20].try_into().unwrap()) as usize;
+        assert_eq!(
+            table_offset(&bytes, first, tag!("SHRD")),
+            table_offset(&bytes, second, tag!("SHRD"))
+        );
+        assert_ne!(
+            table_offset(&bytes, first, tag!("OWN ")),
+            table_offset(&bytes, second, tag!("OWN "))
+        );
+
+        let loaded = FontCollection::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.fonts.len(), 2);
+        assert_eq!(loaded.dsig, None);
+        for (font, own_data) in loaded.fonts.iter().zip([1, 2]) {
+            let mut data = vec![];
+            font.tables.write_table(tag!("OWN "), &mut data).unwrap();
+            assert_eq!(data, vec![own_data; 20]);
+            assert!(font.contains_table(tag!("SHRD")));
+            assert!(font.contains_table(tag!("head")));
+        }
+        assert!(Font::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_collection_dsig() {
+        let mut collection = FontCollection::new(vec![test_font(1)]);
+        collection.dsig = Some(vec![0, 0, 0, 1, 0, 0, 0, 0]);
+        let mut bytes = vec![];
+        collection.write(&mut bytes).unwrap();
+        assert_eq!(&bytes[4..6], &[0, 2]);
+        assert_eq!(&bytes[16..20], b"DSIG");
+
+        let loaded = FontCollection::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.dsig, collection.dsig);
+        assert_eq!(loaded.fonts.len(), 1);
+    }
+}
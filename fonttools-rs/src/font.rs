@@ -34,13 +34,48 @@ impl TryFrom<u32> for SfntVersion {
     }
 }
 
+/// Web font container formats which a font can be read from and written to
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Flavor {
+    /// WOFF 1.0 (zlib-compressed tables)
+    Woff,
+    /// WOFF 2.0 (Brotli-compressed, with transformed `glyf`/`loca`/`hmtx` tables)
+    Woff2,
+}
+
+/// Extra data carried by a WOFF or WOFF2 container
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct FlavorData {
+    /// Major version of the WOFF file
+    pub major_version: u16,
+    /// Minor version of the WOFF file
+    pub minor_version: u16,
+    /// Extended metadata block (uncompressed XML)
+    pub metadata: Option<Vec<u8>>,
+    /// Private data block
+    pub private_data: Option<Vec<u8>>,
+}
+
+impl FlavorData {
+    /// Creates flavor data with the WOFF version taken from the font revision,
+    /// as fontTools does.
+    fn from_font_revision(revision: f32) -> Self {
+        let version = (revision * 1000.0).round() as u32;
+        FlavorData {
+            major_version: (version / 1000) as u16,
+            minor_version: (version % 1000) as u16,
+            ..Default::default()
+        }
+    }
+}
+
 /// Low-level structure used for serializing/deserializing entries in the table directory
 #[derive(Serialize, Deserialize, Debug)]
-struct TableRecord {
-    tag: Tag,
-    checksum: uint32,
-    offset: uint32,
-    length: uint32,
+pub(crate) struct TableRecord {
+    pub(crate) tag: Tag,
+    pub(crate) checksum: uint32,
+    pub(crate) offset: uint32,
+    pub(crate) length: uint32,
 }
 /// The header of the font's table directory
 #[derive(Deserialize)]
@@ -61,6 +96,12 @@ pub struct Font {
     sfntVersion: SfntVersion,
     /// Dictionary of tables in the font
     pub tables: super::table_store::TableSet,
+    /// The web font container the font was read from, and will be written to.
+    /// `None` means a plain sfnt file.
+    pub flavor: Option<Flavor>,
+    /// Version, metadata and private data of the web font container. If this is
+    /// `None` when writing a web font, the version is taken from the `head` table.
+    pub flavor_data: Option<FlavorData>,
     _numGlyphs: Option<u16>,
 }
 
@@ -72,8 +113,28 @@ impl Font {
     }
 
     /// Attempt to load a font from a raw byte slice.
+    ///
+    /// WOFF and WOFF2 files are decompressed transparently; the font's
+    /// `flavor` records the container it came from.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
-        otspec::de::from_bytes(bytes).map_err(|e| e.into())
+        let (flavor, sfnt, flavor_data) = match bytes.get(0..4) {
+            Some(b"wOFF") => {
+                let (sfnt, data) = crate::woff::decompress(bytes)?;
+                (Flavor::Woff, sfnt, data)
+            }
+            Some(b"wOF2") => {
+                let (sfnt, data) = crate::woff2::decompress(bytes)?;
+                (Flavor::Woff2, sfnt, data)
+            }
+            Some(b"ttcf") => {
+                return Err("Font collections must be loaded with FontCollection".into());
+            }
+            _ => return otspec::de::from_bytes(bytes).map_err(|e| e.into()),
+        };
+        let mut font: Font = otspec::de::from_bytes(&sfnt)?;
+        font.flavor = Some(flavor);
+        font.flavor_data = Some(flavor_data);
+        Ok(font)
     }
 
     /// Attempt to load a font from any reader.
@@ -88,10 +149,17 @@ impl Font {
         Self {
             sfntVersion: sfnt_version,
             tables: Default::default(),
+            flavor: None,
+            flavor_data: None,
             _numGlyphs: None,
         }
     }
 
+    /// The font's version (TrueType/OpenType)
+    pub fn sfnt_version(&self) -> SfntVersion {
+        self.sfntVersion
+    }
+
     //FIXME: do we want to keep this? do we want top-level methods generally?
     /// Returns `true` if the font contains a table with this `Tag`.
     pub fn contains_table(&self, tag: Tag) -> bool {
@@ -114,14 +182,42 @@ impl Font {
     }
 
     /// Attempt to write the font into the provided [`Writer`][std::io::Write];
+    ///
+    /// If the font has a `flavor`, it is written as a WOFF or WOFF2 file.
     pub fn write(&mut self, mut writer: impl std::io::Write) -> Result<(), Box<dyn Error>> {
-        self.tables.compile_glyf_loca_maxp();
-        self.tables.compile_gsub_gpos();
+        self.compile();
+        let mut flavor_data = self.flavor_data.clone();
+        if self.flavor.is_some() && flavor_data.is_none() {
+            let revision = self.tables.head()?.map_or(1.0, |head| head.fontRevision);
+            flavor_data = Some(FlavorData::from_font_revision(revision));
+        }
+        if self.flavor == Some(Flavor::Woff2) {
+            // Bit 11 signals that the font has been through a lossless transform
+            if let Some(mut head) = self.tables.head()? {
+                head.flags |= 1 << 11;
+                self.tables.insert(head);
+            }
+        }
         let mut bytes = Vec::new();
         self.to_bytes(&mut bytes)?;
+        let bytes = match (self.flavor, flavor_data) {
+            (Some(Flavor::Woff), Some(data)) => crate::woff::compress(&bytes, &data)?,
+            (Some(Flavor::Woff2), Some(data)) => crate::woff2::compress(&bytes, &data)?,
+            _ => bytes,
+        };
         writer.write_all(&bytes).map_err(Into::into)
     }
 
+    /// Compiles the tables which depend on each other (`glyf`/`loca`/`maxp`,
+    /// `hmtx`/`hhea`, `vmtx`/`vhea`, `gvar`, the bitmap tables and the layout
+    /// tables) ready for serialization.
+    pub(crate) fn compile(&mut self) {
+        self.tables.compile_glyf_loca_maxp();
+        self.tables.compile_hmtx_gvar();
+        self.tables.compile_bitmaps();
+        self.tables.compile_gsub_gpos();
+    }
+
     /// Total number of glyphs in the font, from the maxp table.
     ///
     /// Deserializes the maxp table if this is not already done.
@@ -184,49 +280,80 @@ pub fn get_search_range(n: u16, itemsize: u16) -> (u16, u16, u16) {
     (search_range, max_pow2, range_shift)
 }
 
+/// Reads the sfnt version and the table directory of a binary font.
+pub(crate) fn read_table_directory(
+    bytes: &[u8],
+) -> Result<(u32, Vec<TableRecord>), DeserializationError> {
+    let mut c = ReaderContext::new(bytes.to_vec());
+    let header: TableHeader = c.de()?;
+    let records = (0..header.numTables)
+        .map(|_| c.de())
+        .collect::<Result<Vec<TableRecord>, DeserializationError>>()?;
+    for record in &records {
+        if bytes.len() < (record.offset as usize + record.length as usize) {
+            return Err(DeserializationError(format!(
+                "Table {} extends beyond the end of the font",
+                record.tag
+            )));
+        }
+    }
+    Ok((header.sfntVersion, records))
+}
+
+/// Assembles binary tables into an sfnt file, computing the checksums.
+pub(crate) fn compile_sfnt(sfnt_version: u32, tables: &[(Tag, Vec<u8>)]) -> Vec<u8> {
+    let mut tables: Vec<&(Tag, Vec<u8>)> = tables.iter().collect();
+    tables.sort_by_key(|(tag, _)| *tag);
+    let lenu16: u16 = tables.len().try_into().unwrap();
+    let (search_range, max_pow2, range_shift) = get_search_range(lenu16, 16);
+
+    let mut output: Vec<u8> = vec![];
+    let mut output_tables: Vec<u8> = vec![];
+
+    output.extend(sfnt_version.to_be_bytes());
+    output.extend(lenu16.to_be_bytes());
+    output.extend(search_range.to_be_bytes());
+    output.extend(max_pow2.to_be_bytes());
+    output.extend(range_shift.to_be_bytes());
+    let mut pos = 16 * tables.len() + 12;
+    let mut head_pos: Option<usize> = None;
+    for (tag, data) in tables {
+        let mut temp = data.clone();
+        if *tag == tables::head::TAG && temp.len() >= 12 {
+            head_pos = Some(pos);
+            temp[8..12].fill(0);
+        }
+        let orig_len = temp.len();
+        let orig_checksum = checksum(&temp);
+        while (temp.len() % 4) != 0 {
+            temp.push(0);
+        }
+        output.extend(tag.as_bytes());
+        output.extend(orig_checksum.to_be_bytes());
+        output.extend((pos as u32).to_be_bytes());
+        output.extend((orig_len as u32).to_be_bytes());
+        pos += temp.len();
+        output_tables.extend_from_slice(&temp);
+    }
+    output.extend(output_tables);
+    // Compute full checksum and update head here.
+    let full_checksum = (Wrapping(0xB1B0AFBA) - Wrapping(checksum(&output))).0;
+    if let Some(head_pos) = head_pos {
+        let start = head_pos + 8;
+        output[start..start + 4].copy_from_slice(&full_checksum.to_be_bytes());
+    }
+    output
+}
+
 impl Serialize for Font {
     fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
-        let lenu16: u16 = self.tables.len().try_into().unwrap();
-        let (search_range, max_pow2, range_shift) = get_search_range(lenu16, 16);
-
-        let mut output: Vec<u8> = vec![];
-        let mut output_tables: Vec<u8> = vec![];
-        let mut temp = Vec::new();
-
-        output.extend((self.sfntVersion as u32).to_be_bytes());
-        output.extend(lenu16.to_be_bytes());
-        output.extend(search_range.to_be_bytes());
-        output.extend(max_pow2.to_be_bytes());
-        output.extend(range_shift.to_be_bytes());
-        let mut pos = 16 * self.tables.len() + 12;
-        let mut head_pos: Option<usize> = None;
+        let mut tables = vec![];
         for tag in self.tables.keys() {
-            temp.clear();
+            let mut temp = Vec::new();
             self.tables.write_table(tag, &mut temp)?;
-            if tag == tables::head::TAG {
-                head_pos = Some(pos);
-                temp[8..12].fill(0);
-            }
-            let orig_len = temp.len();
-            let orig_checksum = checksum(&temp);
-            while (temp.len() % 4) != 0 {
-                temp.push(0);
-            }
-            output.extend(tag.as_bytes());
-            output.extend((orig_checksum as u32).to_be_bytes());
-            output.extend((pos as u32).to_be_bytes());
-            output.extend((orig_len as u32).to_be_bytes());
-            pos += temp.len();
-            output_tables.extend_from_slice(&temp);
-        }
-        output.extend(output_tables);
-        // Compute full checksum and update head here.
-        let full_checksum = (Wrapping(0xB1B0AFBA) - Wrapping(checksum(&output))).0;
-        if let Some(head_pos) = head_pos {
-            let start = head_pos + 8;
-            output[start..start + 4].copy_from_slice(&full_checksum.to_be_bytes());
+            tables.push((tag, temp));
         }
-        data.put(output)
+        data.put(compile_sfnt(self.sfntVersion as u32, &tables))
     }
 }
 
@@ -255,6 +382,8 @@ impl Deserialize for Font {
         Ok(Font {
             sfntVersion: version,
             tables: raw_tables.finish()?,
+            flavor: None,
+            flavor_data: None,
             _numGlyphs: None,
         })
     }
@@ -1,5 +1,7 @@
 use otspec::layout::common::{
-    FeatureList as FeatureListLowLevel, FeatureParams, LangSys, LangSysRecord,
+    ConditionFormat1, ConditionSet, FeatureList as FeatureListLowLevel, FeatureParams,
+    FeatureTable, FeatureTableSubstitution, FeatureTableSubstitutionRecord, FeatureVariationRecord,
+    FeatureVariations as FeatureVariationsLowLevel, LangSys, LangSysRecord,
     Script as ScriptLowLevel, ScriptList as ScriptListLowLevel, ScriptRecord,
 };
 use otspec::layout::coverage::Coverage;
@@ -33,6 +35,7 @@ pub(crate) fn coverage_or_nah(off: Offset16<Coverage>) -> Vec<GlyphID> {
 
 /// A script list
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScriptList {
     /// A mapping between script tags and `Script` tables.
     pub scripts: BTreeMap<Tag, Script>,
@@ -40,6 +43,7 @@ pub struct ScriptList {
 
 /// A Script table, containing information about language systems for a certain script.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Script {
     /// Optionally, a default language system to be used when no specific
     /// language is selected.
@@ -51,6 +55,7 @@ pub struct Script {
 /// A LanguageSystem table, selecting which features should be applied in the
 /// current script/language combination.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LanguageSystem {
     /// Each language system can define a required feature which must be processed
     /// for this script/language combination.
@@ -157,6 +162,7 @@ impl From<ScriptListLowLevel> for ScriptList {
 
 /// A general lookup rule, of whatever type
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lookup<T> {
     /// Lookup flags
     pub flags: LookupFlags,
@@ -168,6 +174,7 @@ pub struct Lookup<T> {
 
 // GPOS and GSUB tables
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A list of features within a GPOS or GSUB table
 ///
 /// Associates a feature tag with a set of lookup IDs, and optional feature
@@ -245,7 +252,121 @@ impl From<&FeatureList> for FeatureListLowLevel {
     }
 }
 
+/// A set of alternate feature lookups which apply within a region of the
+/// design space.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureVariation {
+    /// The region in which this variation applies, as a list of
+    /// (axis index, minimum, maximum) ranges in normalized coordinates. All
+    /// of the conditions must be met for the variation to apply.
+    pub conditions: Vec<(uint16, f32, f32)>,
+    /// A mapping between indices into the feature list and the lookup
+    /// indices which replace those of the feature within the region.
+    pub substitutions: BTreeMap<usize, Vec<usize>>,
+}
+
+impl From<FeatureVariationRecord> for FeatureVariation {
+    fn from(val: FeatureVariationRecord) -> Self {
+        let conditions = val
+            .conditionSet
+            .link
+            .map(|cs| {
+                cs.conditions
+                    .v
+                    .into_iter()
+                    .flat_map(|c| c.link)
+                    .map(|c| (c.axisIndex, c.filterRangeMinValue, c.filterRangeMaxValue))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let substitutions = val
+            .featureTableSubstitution
+            .link
+            .map(|fts| {
+                fts.substitutions
+                    .into_iter()
+                    .map(|sub| {
+                        let lookups = sub
+                            .alternateFeature
+                            .link
+                            .map(|f| f.lookupListIndices.iter().map(|x| *x as usize).collect())
+                            .unwrap_or_default();
+                        (sub.featureIndex as usize, lookups)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        FeatureVariation {
+            conditions,
+            substitutions,
+        }
+    }
+}
+
+impl From<&FeatureVariation> for FeatureVariationRecord {
+    fn from(val: &FeatureVariation) -> Self {
+        let conditions: Vec<Offset32<ConditionFormat1>> = val
+            .conditions
+            .iter()
+            .map(|(axis, min, max)| {
+                Offset32::to(ConditionFormat1 {
+                    format: 1,
+                    axisIndex: *axis,
+                    filterRangeMinValue: *min,
+                    filterRangeMaxValue: *max,
+                })
+            })
+            .collect();
+        let substitutions = val
+            .substitutions
+            .iter()
+            .map(|(feature, lookups)| FeatureTableSubstitutionRecord {
+                featureIndex: *feature as uint16,
+                alternateFeature: Offset32::to(FeatureTable {
+                    featureParamsOffset: 0,
+                    lookupListIndices: lookups.iter().map(|x| *x as uint16).collect(),
+                }),
+            })
+            .collect();
+        FeatureVariationRecord {
+            conditionSet: Offset32::to(ConditionSet {
+                conditions: conditions.into(),
+            }),
+            featureTableSubstitution: Offset32::to(FeatureTableSubstitution {
+                majorVersion: 1,
+                minorVersion: 0,
+                substitutions,
+            }),
+        }
+    }
+}
+
+pub(crate) fn feature_variations_from_lowlevel(
+    val: Offset32<FeatureVariationsLowLevel>,
+) -> Vec<FeatureVariation> {
+    val.link
+        .map(|fv| {
+            fv.featureVariationRecords
+                .into_iter()
+                .map(|r| r.into())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn feature_variations_to_lowlevel(
+    val: &[FeatureVariation],
+) -> Offset32<FeatureVariationsLowLevel> {
+    Offset32::to(FeatureVariationsLowLevel {
+        majorVersion: 1,
+        minorVersion: 0,
+        featureVariationRecords: val.iter().map(|fv| fv.into()).collect(),
+    })
+}
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::upper_case_acronyms)]
 /// The Glyph Positioning table
 pub struct GPOSGSUB<T> {
@@ -256,6 +377,10 @@ pub struct GPOSGSUB<T> {
     /// The association between feature tags and the list of indices into the
     /// lookup table used to process this feature, together with any feature parameters.
     pub features: FeatureList,
+    /// Alternate feature lookups to be used in particular regions of the
+    /// design space.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub feature_variations: Vec<FeatureVariation>,
 }
 
 impl<T> Default for GPOSGSUB<T> {
@@ -264,6 +389,7 @@ impl<T> Default for GPOSGSUB<T> {
             lookups: Default::default(),
             scripts: Default::default(),
             features: Default::default(),
+            feature_variations: Default::default(),
         }
     }
 }
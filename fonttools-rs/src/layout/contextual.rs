@@ -18,6 +18,7 @@ pub type SequenceContextRule = Vec<(Slot, Vec<LookupID>)>;
 
 /* This struct is the user-facing representation of sequence context. */
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A contextual substitution/positioning table (GSUB5/GPOS7).
 pub struct SequenceContext {
     /// A set of sequence context rules
@@ -191,6 +192,7 @@ impl SequenceContext {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A chained contextual rule, with backtrack and lookahead
 pub struct ChainedSequenceContextRule {
     /// Glyphs which must appear before the input sequence
@@ -206,6 +208,7 @@ pub struct ChainedSequenceContextRule {
 
 /* This struct is the user-facing representation of chained sequence context. */
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A chained contextual substitution/positioning table (GSUB6/GPOS8).
 pub struct ChainedSequenceContext {
     /// A set of sequence context rules
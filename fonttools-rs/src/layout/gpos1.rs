@@ -10,6 +10,7 @@ use std::collections::BTreeMap;
 /* This struct is the user-facing representation of single-pos. A mapping of
 GID -> valuerecord is a friendly way to represent what's going on. */
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A single positioning subtable.
 pub struct SinglePos {
     /// The mapping of input glyph IDs to value records.
@@ -12,9 +12,11 @@ pub type PairPositioningMap = BTreeMap<(GlyphID, GlyphID), (ValueRecord, ValueRe
 pub type SplitPairPositioningMap = BTreeMap<GlyphID, BTreeMap<GlyphID, (ValueRecord, ValueRecord)>>;
 
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A pair positioning subtable.
 pub struct PairPos {
     /// The mapping of pair glyph IDs to pairs of value records.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::map_as_pairs"))]
     pub mapping: PairPositioningMap,
 }
 
@@ -120,10 +122,14 @@ impl PairPos {
         for left in &coverage.glyphs {
             let mut pair_value_records: Vec<PairValueRecord> = vec![];
             for (right, (vr1, vr2)) in split_mapping.get(left).unwrap() {
+                let mut vr1 = vr1.clone();
+                let mut vr2 = vr2.clone();
+                vr1.coerce_to_format(value_format_1);
+                vr2.coerce_to_format(value_format_2);
                 pair_value_records.push(PairValueRecord {
                     secondGlyph: *right,
-                    valueRecord1: vr1.clone(),
-                    valueRecord2: vr2.clone(),
+                    valueRecord1: vr1,
+                    valueRecord2: vr2,
                 })
             }
             pair_sets.push(Offset16::to(PairSet {
@@ -8,6 +8,7 @@ use otspec::types::*;
 use std::collections::BTreeMap;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A cursive positioning subtable.
 pub struct CursivePos {
     /// The mapping of glyph IDs to entry and exit anchor records.
@@ -23,8 +24,8 @@ impl FromLowlevel<GPOSSubtable> for CursivePos {
                     .iter()
                     .zip(cursivepos1.entryExitRecord.iter())
                 {
-                    let entry = anchors.entryAnchor.link;
-                    let exit = anchors.exitAnchor.link;
+                    let entry = anchors.entryAnchor.link.clone();
+                    let exit = anchors.exitAnchor.link.clone();
                     mapping.insert(*input, (entry, exit));
                 }
             }
@@ -42,8 +43,14 @@ impl ToLowlevel<GPOSSubtable> for CursivePos {
         let mut anchors = vec![];
         for right in self.mapping.values() {
             let entry_exit = EntryExitRecord {
-                entryAnchor: right.0.map_or_else(Offset16::to_nothing, Offset16::to),
-                exitAnchor: right.1.map_or_else(Offset16::to_nothing, Offset16::to),
+                entryAnchor: right
+                    .0
+                    .clone()
+                    .map_or_else(Offset16::to_nothing, Offset16::to),
+                exitAnchor: right
+                    .1
+                    .clone()
+                    .map_or_else(Offset16::to_nothing, Offset16::to),
             };
             anchors.push(entry_exit);
         }
@@ -89,11 +96,11 @@ mod tests {
             mark_filtering_set: None,
             rule: Positioning::Cursive(vec![CursivePos {
                 mapping: btreemap!(
-                    34 => (Some(Anchor { xCoordinate: 100, yCoordinate: 200, anchorPoint: None }), None),
+                    34 => (Some(Anchor::new(100, 200)), None),
                     35 => (None, None),
-                    36 => (None, Some(Anchor { xCoordinate: -300, yCoordinate: -400, anchorPoint: None })),
-                    37 => (Some(Anchor { xCoordinate: 1, yCoordinate: 2, anchorPoint: None }),
-                           Some(Anchor { xCoordinate: 3, yCoordinate: 4, anchorPoint: None }))
+                    36 => (None, Some(Anchor::new(-300, -400))),
+                    37 => (Some(Anchor::new(1, 2)),
+                           Some(Anchor::new(3, 4)))
                 ),
             }]),
         }]);
@@ -9,6 +9,7 @@ use otspec::types::*;
 use std::collections::BTreeMap;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A mark-to-base subtable.
 pub struct MarkBasePos {
     /// Base glyphs to be attached in this subtable
@@ -36,7 +37,7 @@ impl FromLowlevel<GPOSSubtable> for MarkBasePos {
                         mark_glyph,
                         (
                             mark_record.markClass,
-                            mark_record.markAnchor.link.unwrap_or_default(),
+                            mark_record.markAnchor.link.clone().unwrap_or_default(),
                         ),
                     );
                 }
@@ -44,8 +45,11 @@ impl FromLowlevel<GPOSSubtable> for MarkBasePos {
                     base_glyphs.iter().zip(base_array.baseRecords.iter())
                 {
                     let mut anchor_list: BTreeMap<uint16, Anchor> = BTreeMap::new();
-                    for (class, base_anchor) in
-                        base_record.baseAnchors.iter().map(|x| x.link).enumerate()
+                    for (class, base_anchor) in base_record
+                        .baseAnchors
+                        .iter()
+                        .map(|x| x.link.clone())
+                        .enumerate()
                     {
                         if let Some(anchor) = base_anchor {
                             anchor_list.insert(class as u16, anchor);
@@ -66,13 +70,13 @@ impl ToLowlevel<GPOSSubtable> for MarkBasePos {
             markRecords: self
                 .marks
                 .values()
-                .map(|&(class, anchor)| {
+                .map(|&(class, ref anchor)| {
                     if class + 1 > mark_class_count {
                         mark_class_count = class + 1;
                     }
                     MarkRecord {
                         markClass: class,
-                        markAnchor: Offset16::to(anchor),
+                        markAnchor: Offset16::to(anchor.clone()),
                     }
                 })
                 .collect(),
@@ -85,7 +89,7 @@ impl ToLowlevel<GPOSSubtable> for MarkBasePos {
                 baseAnchors: (0..mark_class_count)
                     .map(|i| {
                         base.get(&i)
-                            .copied()
+                            .cloned()
                             .map(Offset16::to)
                             .unwrap_or_else(Offset16::to_nothing)
                     })
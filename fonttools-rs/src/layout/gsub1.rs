@@ -9,6 +9,7 @@ use std::collections::BTreeMap;
 GID -> GID is a friendly way to represent what's going on. */
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A single substitution subtable.
 pub struct SingleSubst {
     /// The mapping of input glyph IDs to replacement glyph IDs.
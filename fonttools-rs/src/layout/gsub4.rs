@@ -6,9 +6,11 @@ use otspec::types::*;
 use std::collections::BTreeMap;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A ligature substitution (many-to-one) subtable.
 pub struct LigatureSubst {
     /// The mapping of sequences of input glyphs IDs to replacement glyph IDs.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::map_as_pairs"))]
     pub mapping: BTreeMap<Vec<GlyphID>, GlyphID>,
 }
 
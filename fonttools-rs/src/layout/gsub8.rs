@@ -7,6 +7,7 @@ use otspec::types::*;
 use std::collections::BTreeMap;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A reverse chaining substitution subtable.
 pub struct ReverseChainSubst {
     /// The mapping of input glyph IDs to substitute.
@@ -35,16 +35,28 @@
 //! each specific OpenType table, see the modules below. See
 //! the [font] module as the entry point to creating, parsing and
 //! saving an OpenType font.
+//!
+//! With the `serde` feature enabled, the high-level table types and the
+//! [`TableSet`](table_store::TableSet) implement serde's `Serialize` and
+//! `Deserialize` traits, so tables can be dumped to and loaded from formats
+//! such as JSON.
 
+pub mod collection;
 /// The main font object. Start here.
 pub mod font;
 /// OpenType Layout common tables
 pub mod layout;
 /// OpenType Variations common tables
 pub mod otvar;
+#[cfg(feature = "serde")]
+mod serde_helpers;
 pub mod table_store;
 /// OpenType table definitions.
 pub mod tables;
+/// Dumping fonts to and compiling fonts from TTX-style XML
+pub mod ttx;
+pub mod woff;
+pub mod woff2;
 
 pub use otspec::types;
 pub use otspec_macros::tag;
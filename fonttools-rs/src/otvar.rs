@@ -1,15 +1,21 @@
 ///! OpenType Variations common tables
 
+/// Delta-set index maps (used in `avar`, `HVAR`, etc.)
+mod deltasetindexmap;
 /// Item Variation Store (used in `MVAR`, etc.)
 mod itemvariationstore;
 /// Utilities for Interpolation of Unreferenced Points
 pub mod iup;
 /// Structs to store locations (user and normalized)
 mod locations;
+/// Merging compiled master fonts into a variable font
+pub mod merger;
 /// Structs for storing packed deltas within a tuple variation store
 mod packeddeltas;
 /// Structs for storing packed points
 mod packedpoints;
+/// Rebasing tuple variation regions onto restricted axis limits
+pub mod solver;
 /// Headers locating variation data within a tuple variation store
 mod tuplevariationheader;
 /// Tuple Variation Store
@@ -17,7 +23,10 @@ mod tuplevariationstore;
 
 pub mod instancer;
 
-pub use itemvariationstore::{ItemVariationData, ItemVariationStore, RegionAxisCoordinates};
+pub use deltasetindexmap::DeltaSetIndexMap;
+pub use itemvariationstore::{
+    ItemVariationData, ItemVariationStore, ItemVariationStoreBuilder, RegionAxisCoordinates,
+};
 pub use locations::NormalizedLocation;
 pub use otmath::{support_scalar, Location, VariationModel};
 use otspec::types::int16;
@@ -92,5 +101,49 @@ mod tests {
 
         let binary_ser = otspec::ser::to_bytes(&fivs).unwrap();
         assert_eq!(binary_ser, binary_ivs);
+
+        assert_eq!(fivs.get_delta(2, &[0.5]), 50.0);
+        assert_eq!(fivs.get_delta(3, &[-0.5]), 0.0);
+        assert_eq!(fivs.get_delta(0xFFFFFFFF, &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn otvar_ivs_builder() {
+        let region = |peak: f32| {
+            vec![RegionAxisCoordinates {
+                startCoord: peak.min(0.0),
+                peakCoord: peak,
+                endCoord: peak.max(0.0),
+            }]
+        };
+        let mut builder = ItemVariationStoreBuilder::new(1);
+        assert_eq!(builder.add(vec![(region(1.0), 10)]), 0);
+        assert_eq!(builder.add(vec![(region(1.0), 20), (region(-1.0), 0)]), 1);
+        assert_eq!(builder.add(vec![(region(1.0), 10)]), 0);
+        assert_eq!(
+            builder.add(vec![(region(-1.0), 5), (region(1.0), 5)]),
+            0x10000
+        );
+        assert_eq!(builder.add(vec![(region(1.0), 0)]), 0x20000);
+        let store = builder.finish();
+        assert_eq!(store.variationRegions, vec![region(1.0), region(-1.0)]);
+        assert_eq!(
+            store.variationData,
+            vec![
+                ItemVariationData {
+                    region_indexes: vec![0],
+                    delta_values: vec![vec![10], vec![20]],
+                },
+                ItemVariationData {
+                    region_indexes: vec![0, 1],
+                    delta_values: vec![vec![5, 5]],
+                },
+                ItemVariationData {
+                    region_indexes: vec![],
+                    delta_values: vec![vec![]],
+                },
+            ]
+        );
+        assert_eq!(store.get_delta(0x10000, &[-0.5]), 2.5);
     }
 }
@@ -0,0 +1,107 @@
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+    Serializer,
+};
+
+const INNER_INDEX_BIT_COUNT_MASK: u8 = 0x0F;
+const MAP_ENTRY_SIZE_MASK: u8 = 0x30;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A mapping from item indices (glyph IDs, axis indices, ...) to delta-set
+/// indices within an item variation store.
+pub struct DeltaSetIndexMap {
+    /// The variation indices, stored as `outer << 16 | inner`.
+    pub entries: Vec<uint32>,
+}
+
+impl DeltaSetIndexMap {
+    /// Returns the variation index for a given item. Items beyond the end of
+    /// the map use the last entry in the map.
+    pub fn get(&self, item: usize) -> Option<uint32> {
+        self.entries
+            .get(item)
+            .or_else(|| self.entries.last())
+            .copied()
+    }
+}
+
+impl Deserialize for DeltaSetIndexMap {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let format: uint8 = c.de()?;
+        let entry_format: uint8 = c.de()?;
+        let map_count: uint32 = match format {
+            0 => {
+                let count: uint16 = c.de()?;
+                count.into()
+            }
+            1 => c.de()?,
+            _ => {
+                return Err(DeserializationError(format!(
+                    "Unknown DeltaSetIndexMap format {}",
+                    format
+                )))
+            }
+        };
+        let entry_size = ((entry_format & MAP_ENTRY_SIZE_MASK) >> 4) + 1;
+        let inner_bits = (entry_format & INNER_INDEX_BIT_COUNT_MASK) + 1;
+        let mut entries = Vec::with_capacity(map_count as usize);
+        for _ in 0..map_count {
+            let mut entry: u32 = 0;
+            for _ in 0..entry_size {
+                let byte: uint8 = c.de()?;
+                entry = (entry << 8) | u32::from(byte);
+            }
+            let outer = entry >> inner_bits;
+            let inner = entry & ((1 << inner_bits) - 1);
+            entries.push((outer << 16) | inner);
+        }
+        Ok(DeltaSetIndexMap { entries })
+    }
+}
+
+impl Serialize for DeltaSetIndexMap {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        // Work out the smallest entry format which fits all entries
+        let ored = self.entries.iter().fold(0, |acc, e| acc | e);
+        let inner_bits = (32 - (ored & 0xFFFF).leading_zeros()).max(1);
+        let outer_bits = 32 - (ored >> 16).leading_zeros();
+        let entry_size = (inner_bits + outer_bits).div_ceil(8).clamp(1, 4);
+        let entry_format = (((entry_size - 1) << 4) | (inner_bits - 1)) as u8;
+
+        if self.entries.len() > 0xFFFF {
+            data.put(1_u8)?;
+            data.put(entry_format)?;
+            data.put(self.entries.len() as u32)?;
+        } else {
+            data.put(0_u8)?;
+            data.put(entry_format)?;
+            data.put(self.entries.len() as u16)?;
+        }
+        for &entry in &self.entries {
+            let packed = ((entry >> 16) << inner_bits) | (entry & 0xFFFF);
+            for byte in (0..entry_size).rev() {
+                data.put((packed >> (byte * 8)) as u8)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_set_index_map_serde() {
+        let binary_map = vec![0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x06];
+        let map = DeltaSetIndexMap {
+            entries: vec![0, 1, 0x10002],
+        };
+        let deserialized: DeltaSetIndexMap = otspec::de::from_bytes(&binary_map).unwrap();
+        assert_eq!(deserialized, map);
+        assert_eq!(otspec::ser::to_bytes(&map).unwrap(), binary_map);
+        assert_eq!(map.get(7), Some(0x10002));
+    }
+}
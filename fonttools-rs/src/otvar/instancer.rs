@@ -1,13 +1,16 @@
 #![allow(missing_docs)]
 use std::collections::BTreeMap;
+use std::error::Error;
 
 use crate::font::Font;
+use crate::otvar::solver::{rebase_tent, renormalize_value, AxisDistances, AxisTriple, Tent};
+use crate::otvar::{ItemVariationStore, RegionAxisCoordinates};
 use crate::tables::avar::{self, SegmentMap};
 use crate::tables::gvar::{self, Coords, DeltaSet, GlyphVariationData};
-use crate::tables::{fvar, glyf};
+use crate::tables::{fvar, glyf, HVAR};
 use crate::tag;
 use crate::types::*;
-use otmath::support_scalar;
+use otmath::{normalize_value, ot_round, support_scalar};
 
 type Location = BTreeMap<Tag, f32>;
 
@@ -15,20 +18,46 @@ type Location = BTreeMap<Tag, f32>;
 pub struct AxisRange {
     minimum: f32,
     maximum: f32,
+    default: Option<f32>,
 }
 
 impl AxisRange {
+    /// Restricts an axis to a range, keeping the existing default (clamped
+    /// to the new range).
     pub fn new(minimum: f32, maximum: f32) -> Self {
         if maximum < minimum {
             panic!("Range minimum must be more than maximum")
         }
-        AxisRange { minimum, maximum }
+        AxisRange {
+            minimum,
+            maximum,
+            default: None,
+        }
+    }
+
+    /// Restricts an axis to a range, moving the axis default to a new value.
+    pub fn with_default(minimum: f32, maximum: f32, default: f32) -> Self {
+        let mut range = AxisRange::new(minimum, maximum);
+        if default < minimum || default > maximum {
+            panic!("Range default must be between minimum and maximum")
+        }
+        range.default = Some(default);
+        range
+    }
+
+    /// The new default value of the axis, if one was specified.
+    pub fn default(&self) -> Option<f32> {
+        self.default
     }
 }
 #[derive(Debug, Clone, PartialEq)]
 struct NormalizedAxisRange {
     minimum: f32,
+    default: f32,
     maximum: f32,
+    /// The userspace distances from the original default to the original
+    /// minimum and maximum
+    distances: AxisDistances,
 }
 
 #[derive(Debug, Clone)]
@@ -40,7 +69,7 @@ enum NormalizedAxisLimit {
 #[derive(Debug)]
 pub struct NormalizedAxisLimits(BTreeMap<Tag, NormalizedAxisLimit>);
 type FullNormalizedAxisLimits = Location;
-type PartialNormalizedAxisLimits = BTreeMap<Tag, (f32, f32)>;
+type PartialNormalizedAxisLimits = BTreeMap<Tag, (AxisTriple, AxisDistances)>;
 
 impl NormalizedAxisLimits {
     pub fn split_up(&self) -> (FullNormalizedAxisLimits, PartialNormalizedAxisLimits) {
@@ -51,8 +80,13 @@ impl NormalizedAxisLimits {
                 NormalizedAxisLimit::Full(loc) => {
                     full.insert(tag, *loc);
                 }
-                NormalizedAxisLimit::Partial(NormalizedAxisRange { minimum, maximum }) => {
-                    partial.insert(tag, (*minimum, *maximum));
+                NormalizedAxisLimit::Partial(NormalizedAxisRange {
+                    minimum,
+                    default,
+                    maximum,
+                    distances,
+                }) => {
+                    partial.insert(tag, ((*minimum, *default, *maximum), *distances));
                 }
             };
         }
@@ -70,7 +104,7 @@ pub enum UserAxisLimit {
 #[derive(Debug)]
 pub struct UserAxisLimits(pub BTreeMap<Tag, UserAxisLimit>);
 type FullUserAxisLimits = Location;
-type PartialUserAxisLimits = BTreeMap<Tag, (f32, f32)>;
+type PartialUserAxisLimits = BTreeMap<Tag, (f32, f32, f32)>;
 
 impl UserAxisLimits {
     pub fn len(&self) -> usize {
@@ -80,6 +114,8 @@ impl UserAxisLimits {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// Splits the limits into pinned locations and (minimum, default, maximum)
+    /// ranges. Range defaults must have been populated from the font first.
     pub fn split_up(&self) -> (FullUserAxisLimits, PartialUserAxisLimits) {
         let mut full: FullUserAxisLimits = BTreeMap::new();
         let mut partial: PartialUserAxisLimits = BTreeMap::new();
@@ -88,8 +124,13 @@ impl UserAxisLimits {
                 UserAxisLimit::Full(loc) => {
                     full.insert(tag, *loc);
                 }
-                UserAxisLimit::Partial(AxisRange { minimum, maximum }) => {
-                    partial.insert(tag, (*minimum, *maximum));
+                UserAxisLimit::Partial(AxisRange {
+                    minimum,
+                    maximum,
+                    default,
+                }) => {
+                    let default = default.expect("Axis range default was not populated");
+                    partial.insert(tag, (*minimum, default, *maximum));
                 }
                 UserAxisLimit::Drop => {}
             };
@@ -106,101 +147,245 @@ impl UserAxisLimits {
 //     RemoveAndIgnoreErrors,
 // }
 
-fn instantiate_gvar_data(
-    variations: &mut GlyphVariationData,
+/// A region of the designspace as a (start, peak, end) triple for each axis
+/// of the original font. Axes which have been pinned are `None`.
+type Region = Vec<Option<Tent>>;
+
+/// A region of the instanced font, with a triple for each remaining axis.
+type TentKey = Vec<(F2DOT14, F2DOT14, F2DOT14)>;
+
+/// Pins and limits a region to the new axis limits, returning the regions
+/// which replace it and the scalars to apply to its deltas.
+fn instantiate_region(
+    region: Region,
     axis_tags: &[Tag],
     axis_limits: &NormalizedAxisLimits,
-) -> Coords {
-    let mut new_variations = variations.clone();
+) -> Vec<(f32, Region)> {
     let (pinned, axis_ranges): (FullNormalizedAxisLimits, PartialNormalizedAxisLimits) =
         axis_limits.split_up();
-
+    let mut solutions = vec![(1.0, region)];
     if !pinned.is_empty() {
-        new_variations = pin_tuple_variation_axes(&mut new_variations, &pinned, axis_tags)
+        solutions = pin_tuple_variation_axes(solutions, &pinned, axis_tags);
     }
     if !axis_ranges.is_empty() {
-        new_variations = limit_tuple_variation_axis_ranges(new_variations, axis_ranges)
+        solutions = limit_tuple_variation_axis_ranges(solutions, &axis_ranges, axis_tags);
     }
-    let mut merged_variations: BTreeMap<Vec<(Tag, F2DOT14, F2DOT14, F2DOT14)>, DeltaSet> =
-        BTreeMap::new();
-    for deltaset in &new_variations.deltasets {
+    solutions
+}
+
+/// The triples of the axes which still participate in a region. Axes which
+/// no longer participate are stored as all-zero.
+fn tent_key(region: &Region) -> TentKey {
+    region
+        .iter()
+        .flatten()
+        .map(|&(start, peak, end)| {
+            if peak == 0.0 {
+                (F2DOT14(0.0), F2DOT14(0.0), F2DOT14(0.0))
+            } else {
+                (F2DOT14(start), F2DOT14(peak), F2DOT14(end))
+            }
+        })
+        .collect()
+}
+
+fn round_deltas(deltas: &[(f32, f32)]) -> Coords {
+    deltas
+        .iter()
+        .map(|&(x, y)| (ot_round(x) as i16, ot_round(y) as i16))
+        .collect()
+}
+
+fn instantiate_gvar_data(
+    variations: &mut GlyphVariationData,
+    axis_tags: &[Tag],
+    axis_limits: &NormalizedAxisLimits,
+) -> Coords {
+    // Deltas are only rounded once all the solutions for a region have been
+    // summed, so that rounding errors don't accumulate.
+    let mut merged_variations: BTreeMap<TentKey, Vec<(f32, f32)>> = BTreeMap::new();
+    for deltaset in &variations.deltasets {
+        let region: Region = (0..axis_tags.len())
+            .map(|ix| Some((deltaset.start[ix], deltaset.peak[ix], deltaset.end[ix])))
+            .collect();
+
         // We don't need to IUP here as Python does, because we're working on "cooked" delta sets
-        let mut tent = vec![];
-        for (ix, ax) in axis_tags.iter().enumerate() {
-            let peak = deltaset.peak.get(ix).expect("Where'd my axis go?");
-            if pinned.contains_key(ax) {
-                continue;
+        for (scalar, region) in instantiate_region(region, axis_tags, axis_limits) {
+            let merged = merged_variations
+                .entry(tent_key(&region))
+                .or_insert_with(|| vec![(0.0, 0.0); deltaset.deltas.len()]);
+            if merged.len() != deltaset.deltas.len() {
+                panic!("Tried to add deltas with different lengths")
+            }
+            for (sum, &(x, y)) in merged.iter_mut().zip(deltaset.deltas.iter()) {
+                sum.0 += x as f32 * scalar;
+                sum.1 += y as f32 * scalar;
             }
-            let start = deltaset.start.get(ix).expect("Where'd my axis go?");
-            let end = deltaset.end.get(ix).expect("Where'd my axis go?");
-            tent.push((*ax, F2DOT14(*start), F2DOT14(*peak), F2DOT14(*end)))
         }
-
-        let new_var = match merged_variations.get(&tent) {
-            Some(var) => var.combine(deltaset),
-            None => deltaset.to_owned(),
-        };
-        merged_variations.insert(tent, new_var);
     }
 
-    println!("Merged variations: {:?}", merged_variations);
-    // XXX - wait - axis_tags has the old set of axes...
-    let default_tent: Vec<(Tag, F2DOT14, F2DOT14, F2DOT14)> = vec![];
-    let default_var = merged_variations.remove(&default_tent);
+    log::debug!("Merged variations: {:?}", merged_variations);
+    // Variations for which all remaining axes have dropped out are applied
+    // to the default coordinates.
+    let default_tent: Option<TentKey> = merged_variations
+        .keys()
+        .find(|tent| tent.iter().all(|t| t.1 .0 == 0.0))
+        .cloned();
+    let default_var = default_tent.and_then(|tent| merged_variations.remove(&tent));
 
-    // Our deltas are i32, that seems bad for this?
+    variations.deltasets = merged_variations
+        .into_iter()
+        .map(|(tent, deltas)| DeltaSet {
+            start: tent.iter().map(|t| t.0 .0).collect(),
+            peak: tent.iter().map(|t| t.1 .0).collect(),
+            end: tent.iter().map(|t| t.2 .0).collect(),
+            deltas: round_deltas(&deltas),
+        })
+        .collect();
+    default_var.map_or_else(Vec::new, |deltas| round_deltas(&deltas))
+}
 
-    // for v in merged_variations.values_mut() {
-    // v.round_deltas();
-    // }
+/// Instantiates the regions of an item variation store, returning the
+/// deltas which now apply at the default location, by outer and inner index.
+fn instantiate_item_variation_store(
+    store: &mut ItemVariationStore,
+    axis_tags: &[Tag],
+    axis_limits: &NormalizedAxisLimits,
+) -> Vec<Vec<f32>> {
+    let solutions: Vec<Vec<(f32, TentKey)>> = store
+        .variationRegions
+        .iter()
+        .map(|region| {
+            let region: Region = region
+                .iter()
+                .map(|c| Some((c.startCoord, c.peakCoord, c.endCoord)))
+                .collect();
+            instantiate_region(region, axis_tags, axis_limits)
+                .into_iter()
+                .map(|(scalar, region)| (scalar, tent_key(&region)))
+                .collect()
+        })
+        .collect();
 
-    variations.deltasets = merged_variations.values().cloned().collect();
-    if let Some(default) = default_var {
-        default.deltas
-    } else {
-        vec![]
+    let mut regions: Vec<TentKey> = vec![];
+    let mut defaults = vec![];
+    for data in store.variationData.iter_mut() {
+        let mut region_indexes: Vec<usize> = vec![];
+        let mut rows: Vec<Vec<f32>> = vec![vec![]; data.delta_values.len()];
+        let mut default_row = vec![0.0; data.delta_values.len()];
+        for (column, &region_ix) in data.region_indexes.iter().enumerate() {
+            for (scalar, tent) in &solutions[region_ix as usize] {
+                let deltas = data
+                    .delta_values
+                    .iter()
+                    .map(|row| row[column] as f32 * scalar);
+                if tent.iter().all(|t| t.1 .0 == 0.0) {
+                    for (sum, delta) in default_row.iter_mut().zip(deltas) {
+                        *sum += delta;
+                    }
+                    continue;
+                }
+                let new_region_ix = regions.iter().position(|r| r == tent).unwrap_or_else(|| {
+                    regions.push(tent.clone());
+                    regions.len() - 1
+                });
+                let new_column = region_indexes
+                    .iter()
+                    .position(|&ix| ix == new_region_ix)
+                    .unwrap_or_else(|| {
+                        region_indexes.push(new_region_ix);
+                        rows.iter_mut().for_each(|row| row.push(0.0));
+                        region_indexes.len() - 1
+                    });
+                for (row, delta) in rows.iter_mut().zip(deltas) {
+                    row[new_column] += delta;
+                }
+            }
+        }
+        data.region_indexes = region_indexes.iter().map(|&ix| ix as u16).collect();
+        data.delta_values = rows
+            .iter()
+            .map(|row| row.iter().map(|&d| ot_round(d) as i16).collect())
+            .collect();
+        defaults.push(default_row);
     }
+
+    let (pinned, _) = axis_limits.split_up();
+    store.axisCount = (axis_tags.len() - pinned.len()) as u16;
+    store.variationRegions = regions
+        .into_iter()
+        .map(|tent| {
+            tent.into_iter()
+                .map(|(start, peak, end)| RegionAxisCoordinates {
+                    startCoord: start.0,
+                    peakCoord: peak.0,
+                    endCoord: end.0,
+                })
+                .collect()
+        })
+        .collect();
+    defaults
 }
 
 fn pin_tuple_variation_axes(
-    variations: &mut GlyphVariationData,
+    solutions: Vec<(f32, Region)>,
     location: &FullNormalizedAxisLimits,
     axis_tags: &[Tag],
-) -> GlyphVariationData {
-    let mut new_deltas: Vec<gvar::DeltaSet> = vec![];
-    for var in variations.deltasets.iter_mut() {
-        println!("Deltaset : {:?}", var);
-
-        // Deltaset is a set of tuples using the font's existing axes
+) -> Vec<(f32, Region)> {
+    let mut new_solutions = vec![];
+    for (scalar, mut region) in solutions {
+        // Region is a set of tuples using the font's existing axes
         let mut support = BTreeMap::new();
         for tag in location.keys() {
             let index = axis_tags
                 .iter()
                 .position(|t| t == tag)
                 .expect("Axis in location wasn't in font");
-            let support_for_this_axis = (var.start[index], var.peak[index], var.end[index]);
-            println!("Support for {}: {:?}", tag, support_for_this_axis);
-            support.insert(*tag, support_for_this_axis);
+            if let Some(support_for_this_axis) = region[index].take() {
+                support.insert(*tag, support_for_this_axis);
+            }
         }
-        let scalar = support_scalar(location, &support);
-        println!("Support scalar for {:?}: {:?}", location, scalar);
-        if scalar == 0.0 {
+        let pinned_scalar = support_scalar(location, &support);
+        if pinned_scalar == 0.0 {
             continue;
         }
-        var.scale_deltas(scalar);
-        new_deltas.push(var.clone());
-    }
-    println!("Pinned deltas: {:?}", new_deltas);
-    GlyphVariationData {
-        deltasets: new_deltas,
+        new_solutions.push((scalar * pinned_scalar, region));
     }
+    new_solutions
 }
 
 fn limit_tuple_variation_axis_ranges(
-    _tvs: GlyphVariationData,
-    _axis_ranges: PartialNormalizedAxisLimits,
-) -> GlyphVariationData {
-    unimplemented!()
+    mut solutions: Vec<(f32, Region)>,
+    axis_ranges: &PartialNormalizedAxisLimits,
+    axis_tags: &[Tag],
+) -> Vec<(f32, Region)> {
+    for (tag, &(axis_limit, distances)) in axis_ranges {
+        let index = axis_tags
+            .iter()
+            .position(|t| t == tag)
+            .expect("Axis in range wasn't in font");
+        let mut new_solutions = vec![];
+        for (scalar, region) in solutions {
+            let (lower, peak, upper) = match region[index] {
+                Some(tent) if tent.1 != 0.0 => tent,
+                _ => {
+                    new_solutions.push((scalar, region));
+                    continue;
+                }
+            };
+            // Drop the variation if its tent isn't well-formed
+            if lower > peak || peak > upper || (lower < 0.0 && upper > 0.0) {
+                continue;
+            }
+            for (tent_scalar, tent) in rebase_tent((lower, peak, upper), axis_limit, distances) {
+                let mut new_region = region.clone();
+                new_region[index] = Some(tent.unwrap_or((0.0, 0.0, 0.0)));
+                new_solutions.push((scalar * tent_scalar, new_region));
+            }
+        }
+        solutions = new_solutions;
+    }
+    solutions
 }
 
 fn sanity_check(font: &Font) {
@@ -212,32 +397,44 @@ fn sanity_check(font: &Font) {
     }
 }
 
+/// Applies the default deltas to a glyph, returning the deltas of its
+/// horizontal phantom points.
 fn instantiate_gvar_glyph(
     ix: usize,
     axis_tags: &[Tag],
     glyf: &mut glyf::glyf,
     gvar: &mut gvar::gvar,
     axis_limits: &NormalizedAxisLimits,
-) {
+) -> Option<(i16, i16)> {
     let glyph = glyf.glyphs.get_mut(ix).unwrap();
-    println!("Handling glyph {:?}", ix);
-
-    if let Some(var) = gvar.variations.get_mut(ix).unwrap() {
-        let mut deltas = instantiate_gvar_data(var, axis_tags, axis_limits).into_iter();
-        println!("New deltas: {:?}", deltas);
-        for contour in glyph.contours.iter_mut() {
-            for point in contour.iter_mut() {
-                let delta = deltas.next().expect("Not enough deltas for glyph");
-                point.x += delta.0;
-                point.y += delta.1;
-            }
-        }
-        // XXX phantom points
-        if var.deltasets.is_empty() {
-            log::info!("No delta sets left, dropping variation");
-            gvar.variations[ix] = None;
+    log::debug!("Handling glyph {:?}", ix);
+
+    let var = gvar.variations.get_mut(ix).unwrap().as_mut()?;
+    let mut deltas = instantiate_gvar_data(var, axis_tags, axis_limits).into_iter();
+    if var.deltasets.is_empty() {
+        log::info!("No delta sets left, dropping variation");
+        gvar.variations[ix] = None;
+    }
+    // All deltas were pinned away to nothing
+    if deltas.len() == 0 {
+        return None;
+    }
+    for contour in glyph.contours.iter_mut() {
+        for point in contour.iter_mut() {
+            let delta = deltas.next().expect("Not enough deltas for glyph");
+            point.x += delta.0;
+            point.y += delta.1;
         }
     }
+    for component in glyph.components.iter_mut() {
+        let delta = deltas.next().expect("Not enough deltas for glyph");
+        component.transformation =
+            kurbo::Affine::translate((delta.0 as f64, delta.1 as f64)) * component.transformation;
+    }
+    // Phantom points: left, right, top, bottom
+    let left = deltas.next().map(|d| d.0).unwrap_or(0);
+    let right = deltas.next().map(|d| d.0).unwrap_or(0);
+    Some((left, right))
 }
 
 fn instantiate_gvar(font: &mut Font, axis_limits: &NormalizedAxisLimits) {
@@ -255,22 +452,83 @@ fn instantiate_gvar(font: &mut Font, axis_limits: &NormalizedAxisLimits) {
     let mut gvar = font.tables.gvar().unwrap().unwrap();
     let mut glyf = font.tables.glyf().unwrap().unwrap();
 
+    let mut phantom_deltas: BTreeMap<usize, (i16, i16)> = BTreeMap::new();
     for gid in 0..glyf.glyphs.len() {
-        instantiate_gvar_glyph(gid, &axis_tags, &mut glyf, &mut gvar, axis_limits)
+        if let Some(phantom) =
+            instantiate_gvar_glyph(gid, &axis_tags, &mut glyf, &mut gvar, axis_limits)
+        {
+            phantom_deltas.insert(gid, phantom);
+        }
+    }
+
+    // Recompute bounds, without flattening the components in the real table
+    let old_x_mins: Vec<i16> = glyf.glyphs.iter().map(|g| g.xMin).collect();
+    let mut bounds = glyf.clone().into_owned();
+    bounds.recalc_bounds();
+    for (glyph, bound) in glyf.glyphs.iter_mut().zip(bounds.glyphs.iter()) {
+        glyph.xMin = bound.xMin;
+        glyph.xMax = bound.xMax;
+        glyph.yMin = bound.yMin;
+        glyph.yMax = bound.yMax;
+    }
+
+    // Moving the default may have changed the advance widths
+    if let Some(mut hmtx) = font.tables.hmtx().unwrap() {
+        for (&gid, &(left, right)) in &phantom_deltas {
+            if let Some(metric) = hmtx.metrics.get_mut(gid) {
+                let left_side_x = old_x_mins[gid] as i32 - metric.lsb as i32 + left as i32;
+                metric.advanceWidth =
+                    (metric.advanceWidth as i32 + right as i32 - left as i32).max(0) as u16;
+                metric.lsb = (glyf.glyphs[gid].xMin as i32 - left_side_x) as i16;
+            }
+        }
+        let (hmtx_bytes, num_h_metrics) = hmtx.to_bytes();
+        font.tables.insert_raw(tag!("hmtx"), hmtx_bytes);
+        if let Some(mut hhea) = font.tables.hhea().unwrap() {
+            hhea.numberOfHMetrics = num_h_metrics;
+            font.tables.insert(hhea);
+        }
     }
+
     if !gvar.variations.iter().any(|x| x.is_some()) {
         log::info!("Dropping gvar table");
         font.tables.remove(gvar::TAG);
     } else {
-        font.tables.insert(gvar);
+        // No gvar optimization here (use ttf-optimize-gvar for IUP)
+        font.tables.insert_raw(gvar::TAG, gvar.to_bytes(None));
     }
     font.tables.insert(glyf);
 }
 
-fn instantiate_avar(font: &mut Font, axis_limits: &UserAxisLimits) {
+#[allow(non_snake_case)]
+fn instantiate_HVAR(font: &mut Font, axis_limits: &NormalizedAxisLimits) {
+    let axis_tags: Vec<Tag> = font
+        .tables
+        .fvar()
+        .unwrap()
+        .unwrap()
+        .axes
+        .iter()
+        .map(|x| x.axisTag)
+        .collect();
+    let (pinned, _) = axis_limits.split_up();
+    if axis_tags.iter().all(|tag| pinned.contains_key(tag)) {
+        log::info!("Dropping HVAR table");
+        font.tables.remove(HVAR::TAG);
+        return;
+    }
+    log::info!("Instantiating HVAR table");
+    let mut hvar = font.tables.HVAR().unwrap().unwrap();
+    // The advance widths at the new default come from the gvar phantom
+    // points, so the default deltas are not needed here.
+    instantiate_item_variation_store(&mut hvar.item_variation_store, &axis_tags, axis_limits);
+    font.tables.insert(hvar);
+}
+
+fn instantiate_avar(font: &mut Font, axis_limits: &UserAxisLimits) -> Result<(), String> {
     let (location, _axis_ranges): (FullUserAxisLimits, PartialUserAxisLimits) =
         axis_limits.split_up();
-    let (_, normalized_ranges) = normalize_axis_limits(font, axis_limits, false).split_up();
+    let (_, normalized_ranges) = normalize_axis_limits(font, axis_limits, false)?.split_up();
 
     // Drop avar if we instantiate everything
     let fvar = font.tables.fvar().unwrap().unwrap();
@@ -282,7 +540,7 @@ fn instantiate_avar(font: &mut Font, axis_limits: &UserAxisLimits) {
     {
         log::info!("Dropping avar table");
         font.tables.remove(avar::TAG);
-        return;
+        return Ok(());
     }
     for ax in &fvar.axes {
         axis_tags.push(ax.axisTag)
@@ -306,38 +564,32 @@ fn instantiate_avar(font: &mut Font, axis_limits: &UserAxisLimits) {
         if !segment.is_valid() {
             continue;
         }
-        if let Some(&(minimum, maximum)) = normalized_ranges.get(&axis_tag) {
-            let mapped_min = F2DOT14::round(segment.piecewise_linear_map(minimum));
-            let mapped_max = F2DOT14::round(segment.piecewise_linear_map(maximum));
+        if let Some(&(axis_range, distances)) = normalized_ranges.get(&axis_tag) {
+            let (minimum, default, maximum) = axis_range;
+            // The "from" coordinates are in default normalized space, whereas
+            // the "to" coordinates are mapped forward through the segment map.
+            let mapped_range = (
+                F2DOT14::round(segment.piecewise_linear_map(minimum)),
+                F2DOT14::round(segment.piecewise_linear_map(default)),
+                F2DOT14::round(segment.piecewise_linear_map(maximum)),
+            );
             let mut new_mapping: Vec<(f32, f32)> = vec![];
-            for avm in &segment.0 {
-                let (mut from_coord, mut to_coord) = (avm.0, avm.1);
-                if from_coord < 0.0 {
-                    if minimum == 0.0 || from_coord < minimum {
-                        continue;
-                    } else {
-                        from_coord /= minimum.abs();
-                    }
-                } else if from_coord > 0.0 {
-                    if maximum == 0.0 || from_coord > maximum {
-                        continue;
-                    } else {
-                        from_coord /= maximum;
-                    }
+            for &(from_coord, to_coord) in &segment.0 {
+                if from_coord < minimum || from_coord > maximum {
+                    continue;
                 }
-                if to_coord < 0.0 {
-                    assert!(mapped_min != 0.0);
-                    assert!(to_coord >= mapped_min);
-                    to_coord /= mapped_min.abs()
-                } else if to_coord > 0.0 {
-                    assert!(mapped_max != 0.0);
-                    assert!(to_coord <= mapped_max);
-                    to_coord /= mapped_max.abs()
+                assert!(mapped_range.0 <= to_coord && to_coord <= mapped_range.2);
+                let from_coord =
+                    F2DOT14::round(renormalize_value(from_coord, axis_range, distances, false));
+                let to_coord =
+                    F2DOT14::round(renormalize_value(to_coord, mapped_range, distances, false));
+                if [-1.0, 0.0, 1.0].contains(&from_coord) {
+                    continue;
                 }
-                from_coord = F2DOT14::round(from_coord);
-                to_coord = F2DOT14::round(to_coord);
                 new_mapping.push((from_coord, to_coord));
             }
+            new_mapping.extend([(-1.0, -1.0), (0.0, 0.0), (1.0, 1.0)]);
+            new_mapping.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
             new_segments.insert(axis_tag, avar::SegmentMap::new(new_mapping));
         } else {
             new_segments.insert(axis_tag, segment);
@@ -349,11 +601,12 @@ fn instantiate_avar(font: &mut Font, axis_limits: &UserAxisLimits) {
         .map(|tag| new_segments.get(tag).unwrap().clone())
         .collect();
     font.tables.insert(avar_table);
+    Ok(())
 }
 
 fn is_instance_within_axis_ranges(loc: &Location, axis_ranges: &PartialUserAxisLimits) -> bool {
     for (tag, coord) in loc {
-        if let Some((min, max)) = axis_ranges.get(tag) {
+        if let Some((min, _, max)) = axis_ranges.get(tag) {
             if coord < min || coord > max {
                 return false;
             }
@@ -386,8 +639,9 @@ fn instantiate_fvar(font: &mut Font, axis_limits: &UserAxisLimits) {
         if location.contains_key(&axis_tag) {
             continue;
         }
-        if let Some(&(minimum, maximum)) = axis_ranges.get(&axis_tag) {
+        if let Some(&(minimum, default, maximum)) = axis_ranges.get(&axis_tag) {
             axis.minValue = minimum;
+            axis.defaultValue = default;
             axis.maxValue = maximum;
         }
         new_axes.push(axis.clone());
@@ -459,7 +713,7 @@ fn instantiate_STAT(font: &mut Font, axis_limits: &UserAxisLimits) {
                 return true;
             }
         }
-        if let Some(&(minimum, maximum)) = axis_ranges.get(tag) {
+        if let Some(&(minimum, _, maximum)) = axis_ranges.get(tag) {
             if value < minimum || value > maximum {
                 return true;
             }
@@ -505,26 +759,41 @@ fn set_mac_overlap_flags(glyf: &mut glyf::glyf) {
     }
 }
 
-fn populate_axis_defaults(font: &mut Font, mut limits: UserAxisLimits) -> UserAxisLimits {
+fn populate_axis_defaults(
+    font: &mut Font,
+    mut limits: UserAxisLimits,
+) -> Result<UserAxisLimits, String> {
     let fvar = font.tables.fvar().unwrap().unwrap();
-    let defaults: Location = fvar
+    let triples: BTreeMap<Tag, (f32, f32, f32)> = fvar
         .axes
         .iter()
-        .map(|ax| (ax.axisTag, ax.defaultValue))
+        .map(|ax| (ax.axisTag, (ax.minValue, ax.defaultValue, ax.maxValue)))
         .collect();
-    for (k, v) in limits
-        .0
-        .iter_mut()
-        .filter(|(_k, v)| matches!(v, UserAxisLimit::Drop))
-    {
-        *v = UserAxisLimit::Full(*defaults.get(k).expect("Unknown axis"));
+    for (k, v) in limits.0.iter_mut() {
+        let &(axis_min, axis_default, axis_max) = triples
+            .get(k)
+            .ok_or_else(|| format!("Can't limit {} - axis not in font", k))?;
+        match v {
+            UserAxisLimit::Drop => *v = UserAxisLimit::Full(axis_default),
+            UserAxisLimit::Partial(range) => {
+                // Clamp the range to the existing axis, and the default to the range
+                range.minimum = range.minimum.max(axis_min);
+                range.maximum = range.maximum.min(axis_max);
+                if range.maximum < range.minimum {
+                    return Err(format!("Range {} is outside the axis limits", k));
+                }
+                let default = range.default.unwrap_or(axis_default);
+                range.default = Some(default.clamp(range.minimum, range.maximum));
+            }
+            UserAxisLimit::Full(_) => {}
+        }
     }
-    limits
+    Ok(limits)
 }
 
 fn normalize(value: f32, triple: (f32, f32, f32), avar_segment: Option<&SegmentMap>) -> f32 {
-    let (minv, _default, maxv) = triple;
-    let mut value = (value.clamp(minv, maxv) - minv) / (maxv - minv);
+    let (minv, default, maxv) = triple;
+    let mut value = normalize_value(value, minv, maxv, default);
     if let Some(map) = avar_segment {
         value = map.piecewise_linear_map(value);
     }
@@ -535,12 +804,12 @@ fn normalize_axis_limits(
     font: &mut Font,
     limits: &UserAxisLimits,
     use_avar: bool,
-) -> NormalizedAxisLimits {
+) -> Result<NormalizedAxisLimits, String> {
     let fvar = font.tables.fvar().unwrap().unwrap();
     let all_axes: Vec<Tag> = fvar.axes.iter().map(|x| x.axisTag).collect();
     for ax in limits.0.keys() {
         if !all_axes.contains(ax) {
-            panic!("Can't limit {} - axis not in font", ax,)
+            return Err(format!("Can't limit {} - axis not in font", ax));
         }
     }
     let axes: BTreeMap<Tag, (f32, f32, f32)> = fvar
@@ -551,6 +820,36 @@ fn normalize_axis_limits(
         .collect();
 
     let avar = font.tables.avar().unwrap();
+    if let Some(avar) = avar
+        .as_ref()
+        .filter(|a| use_avar && a.has_cross_axis_mappings())
+    {
+        // Cross-axis mappings move every axis at once, so we can only
+        // resolve them at a single point in the designspace.
+        let coords: Vec<f32> = fvar
+            .axes
+            .iter()
+            .map(|ax| match limits.0.get(&ax.axisTag) {
+                Some(UserAxisLimit::Full(v)) => Ok(normalize_value(
+                    *v,
+                    ax.minValue,
+                    ax.maxValue,
+                    ax.defaultValue,
+                )),
+                _ => Err(format!(
+                    "Can't partially instance {} - only full instancing of fonts with avar version 2 is supported",
+                    ax.axisTag
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        return Ok(NormalizedAxisLimits(
+            all_axes
+                .iter()
+                .zip(avar.map_location(&coords))
+                .map(|(&tag, v)| (tag, NormalizedAxisLimit::Full(F2DOT14::round(v))))
+                .collect(),
+        ));
+    }
     let avar_segs: BTreeMap<Tag, &SegmentMap> = if use_avar && avar.is_some() {
         all_axes
             .iter()
@@ -561,28 +860,24 @@ fn normalize_axis_limits(
         BTreeMap::new()
     };
 
-    for (tag, &(_, default, _)) in &axes {
-        if let Some(&UserAxisLimit::Partial(AxisRange { minimum, maximum })) = limits.0.get(tag) {
-            if minimum > default || maximum < default {
-                panic!(
-                    "Unsupported range {}:={}:{}; default position is {}",
-                    tag, minimum, maximum, default
-                )
-            }
-        }
-    }
-
     let mut normalized_limits = BTreeMap::new();
     for (tag, tuple) in axes {
         let avar_mapping = avar_segs.get(&tag).copied();
         let value = limits.0.get(&tag).unwrap();
         match value {
-            UserAxisLimit::Partial(AxisRange { minimum, maximum }) => {
+            UserAxisLimit::Partial(AxisRange {
+                minimum,
+                maximum,
+                default,
+            }) => {
+                let default = default.unwrap_or(tuple.1).clamp(*minimum, *maximum);
                 normalized_limits.insert(
                     tag,
                     NormalizedAxisLimit::Partial(NormalizedAxisRange {
                         minimum: normalize(*minimum, tuple, avar_mapping),
+                        default: normalize(default, tuple, avar_mapping),
                         maximum: normalize(*maximum, tuple, avar_mapping),
+                        distances: (tuple.1 - tuple.0, tuple.2 - tuple.1),
                     }),
                 );
             }
@@ -597,14 +892,23 @@ fn normalize_axis_limits(
             }
         }
     }
-    NormalizedAxisLimits(normalized_limits)
+    Ok(NormalizedAxisLimits(normalized_limits))
 }
 
-pub fn instantiate_variable_font(font: &mut Font, limits: UserAxisLimits) -> bool {
+/// Instantiates a variable font in place, pinning or restricting its axes
+/// to the given limits.
+///
+/// Returns an error if the limits can't be applied to the font: if they
+/// refer to axes the font doesn't have, fall outside the axes' ranges, or
+/// only partially instance a font with avar version 2 cross-axis mappings.
+pub fn instantiate_variable_font(
+    font: &mut Font,
+    limits: UserAxisLimits,
+) -> Result<(), Box<dyn Error>> {
     sanity_check(font);
-    let limits = populate_axis_defaults(font, limits);
+    let limits = populate_axis_defaults(font, limits)?;
     log::debug!("Full limits: {:?}", limits);
-    let normalized_limits = normalize_axis_limits(font, &limits, true);
+    let normalized_limits = normalize_axis_limits(font, &limits, true)?;
     log::debug!("Normalized limits: {:?}", normalized_limits);
     font.tables.fvar().expect("Can't open fvar");
     font.tables.glyf().expect("Can't open glyf");
@@ -621,7 +925,7 @@ pub fn instantiate_variable_font(font: &mut Font, limits: UserAxisLimits) -> boo
         // instantiate_MVAR(font, normalized_limits);
     }
     if font.tables.contains(b"HVAR") {
-        // instantiate_HVAR(font, normalized_limits);
+        instantiate_HVAR(font, &normalized_limits);
     }
     if font.tables.contains(b"VVAR") {
         // instantiate_VVAR(font, normalized_limits);
@@ -630,7 +934,7 @@ pub fn instantiate_variable_font(font: &mut Font, limits: UserAxisLimits) -> boo
     // instantiate_feature_variations(font, normalized_limits);
     if font.tables.contains(b"avar") {
         font.tables.avar().expect("Can't open avar");
-        instantiate_avar(font, &limits);
+        instantiate_avar(font, &limits)?;
     }
     if font.tables.contains(b"STAT") {
         instantiate_STAT(font, &limits);
@@ -643,5 +947,369 @@ pub fn instantiate_variable_font(font: &mut Font, limits: UserAxisLimits) -> boo
     }
     // let (full, _) = limits.split_up();
     // set_default_weight_width_slant(font, full);
-    true
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::SfntVersion;
+    use crate::tables::glyf::{Glyph, Point};
+    use crate::tables::hmtx::{hmtx, Metric};
+    use crate::tables::{head, hhea::hhea, maxp::maxp};
+
+    /// The deltas of the test glyph's four points and four phantom points
+    /// at each tent of the weight axis
+    const TENTS: [(Tent, [(i16, i16); 8]); 3] = [
+        (
+            (-1.0, -1.0, 0.0),
+            [
+                (-31, -17),
+                (-33, 7),
+                (9, -13),
+                (-21, 3),
+                (0, 0),
+                (-47, 0),
+                (0, 0),
+                (0, 0),
+            ],
+        ),
+        (
+            (0.0, 0.5, 1.0),
+            [
+                (15, -9),
+                (7, 11),
+                (-3, 5),
+                (13, 1),
+                (0, 0),
+                (13, 0),
+                (0, 0),
+                (0, 0),
+            ],
+        ),
+        (
+            (0.0, 1.0, 1.0),
+            [
+                (73, 41),
+                (-19, 37),
+                (55, 29),
+                (101, -5),
+                (0, 0),
+                (101, 0),
+                (0, 0),
+                (0, 0),
+            ],
+        ),
+    ];
+
+    /// A font with a weight axis from 100 to 900 (default 400), a
+    /// non-linear avar map and one varying glyph
+    fn variable_font() -> Font {
+        let mut font = Font::new(SfntVersion::TrueType);
+        font.tables.insert(head::new(1.0, 1000, 0, 0, 0, 0));
+        font.tables.insert(hhea {
+            majorVersion: 1,
+            minorVersion: 0,
+            ascender: 800,
+            descender: -200,
+            lineGap: 0,
+            advanceWidthMax: 500,
+            minLeftSideBearing: 0,
+            minRightSideBearing: 0,
+            xMaxExtent: 350,
+            caretSlopeRise: 1,
+            caretSlopeRun: 0,
+            caretOffset: 0,
+            reserved0: 0,
+            reserved1: 0,
+            reserved2: 0,
+            reserved3: 0,
+            metricDataFormat: 0,
+            numberOfHMetrics: 2,
+        });
+        font.tables.insert(maxp::new05(2));
+        font.tables.insert(fvar::fvar {
+            axes: vec![fvar::VariationAxisRecord {
+                axisTag: tag!("wght"),
+                minValue: 100.0,
+                defaultValue: 400.0,
+                maxValue: 900.0,
+                flags: 0,
+                axisNameID: 256,
+            }],
+            instances: vec![],
+        });
+        font.tables.insert(avar::avar {
+            maps: vec![SegmentMap::new(vec![
+                (-1.0, -1.0),
+                (-0.5, -0.25),
+                (0.0, 0.0),
+                (0.5, 0.625),
+                (1.0, 1.0),
+            ])],
+            axis_index_map: None,
+            var_store: None,
+        });
+        let point = |x, y, on_curve| Point { x, y, on_curve };
+        let glyf_table = glyf::glyf {
+            glyphs: vec![
+                Glyph {
+                    xMin: 0,
+                    yMin: 0,
+                    xMax: 0,
+                    yMax: 0,
+                    contours: vec![],
+                    instructions: vec![],
+                    components: vec![],
+                    overlap: false,
+                },
+                Glyph {
+                    xMin: 50,
+                    yMin: 0,
+                    xMax: 350,
+                    yMax: 300,
+                    contours: vec![vec![
+                        point(50, 0, true),
+                        point(50, 300, false),
+                        point(350, 300, true),
+                        point(350, 0, true),
+                    ]],
+                    instructions: vec![],
+                    components: vec![],
+                    overlap: false,
+                },
+            ],
+        };
+        let variations = gvar::gvar {
+            variations: vec![
+                None,
+                Some(GlyphVariationData {
+                    deltasets: TENTS
+                        .iter()
+                        .map(|&((start, peak, end), deltas)| DeltaSet {
+                            start: vec![start],
+                            peak: vec![peak],
+                            end: vec![end],
+                            deltas: deltas.to_vec(),
+                        })
+                        .collect(),
+                }),
+            ],
+        };
+        font.tables
+            .insert_raw(gvar::TAG, variations.to_bytes(Some(&glyf_table)));
+        font.tables.insert(glyf_table);
+        let (hmtx_bytes, _) = hmtx {
+            metrics: vec![
+                Metric {
+                    advanceWidth: 500,
+                    lsb: 0,
+                },
+                Metric {
+                    advanceWidth: 400,
+                    lsb: 50,
+                },
+            ],
+        }
+        .to_bytes();
+        font.tables.insert_raw(crate::tables::hmtx::TAG, hmtx_bytes);
+        // Glyph 1's advance width varies as its right phantom point does
+        font.tables.insert(HVAR::HVAR {
+            item_variation_store: ItemVariationStore::new(
+                1,
+                TENTS
+                    .iter()
+                    .map(|&((start, peak, end), _)| {
+                        vec![RegionAxisCoordinates {
+                            startCoord: start,
+                            peakCoord: peak,
+                            endCoord: end,
+                        }]
+                    })
+                    .collect(),
+                vec![
+                    vec![0, 0, 0],
+                    TENTS.iter().map(|(_, deltas)| deltas[5].0).collect(),
+                ],
+            ),
+            advance_mapping: None,
+            lsb_mapping: None,
+            rsb_mapping: None,
+        });
+        font
+    }
+
+    fn instantiate(mut font: Font, limit: UserAxisLimit) -> Font {
+        let limits = UserAxisLimits(BTreeMap::from([(tag!("wght"), limit)]));
+        instantiate_variable_font(&mut font, limits).unwrap();
+        font
+    }
+
+    /// The test glyph's points and advance width
+    fn glyph_metrics(font: &Font) -> (Vec<(i16, i16)>, u16) {
+        let glyf = font.tables.glyf().unwrap().unwrap();
+        let points = glyf.glyphs[1].contours[0]
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect();
+        let advance = font.tables.hmtx().unwrap().unwrap().metrics[1].advanceWidth;
+        (points, advance)
+    }
+
+    fn assert_close(got: (Vec<(i16, i16)>, u16), expected: (Vec<(i16, i16)>, u16), location: f32) {
+        let close = |a: i32, b: i32| (a - b).abs() <= 1;
+        assert!(
+            close(got.1.into(), expected.1.into())
+                && got.0.iter().zip(expected.0.iter()).all(|(g, e)| {
+                    close(g.0.into(), e.0.into()) && close(g.1.into(), e.1.into())
+                }),
+            "at wght={}: {:?} != {:?}",
+            location,
+            got,
+            expected
+        );
+    }
+
+    /// Checks that pinning a font which has been restricted to a range
+    /// gives the same results as pinning the original font, and that the
+    /// restricted font's HVAR table agrees with its gvar table. The
+    /// restricted font stores rounded deltas, so rounding it again when
+    /// pinning can be a unit out.
+    fn assert_range_matches_pinning(range: AxisRange, locations: &[f32]) {
+        let ranged = instantiate(variable_font(), UserAxisLimit::Partial(range.clone()));
+        let axis = &ranged.tables.fvar().unwrap().unwrap().axes[0];
+        assert_eq!(axis.minValue, range.minimum);
+        assert_eq!(axis.maxValue, range.maximum);
+        assert_eq!(
+            axis.defaultValue,
+            range
+                .default()
+                .unwrap_or(400.0)
+                .clamp(range.minimum, range.maximum)
+        );
+        let triple = (axis.minValue, axis.defaultValue, axis.maxValue);
+        let avar = ranged.tables.avar().unwrap().unwrap();
+        let hvar = ranged.tables.HVAR().unwrap().unwrap();
+        let (_, default_advance) = glyph_metrics(&ranged);
+
+        for &location in locations {
+            let expected =
+                glyph_metrics(&instantiate(variable_font(), UserAxisLimit::Full(location)));
+            let pinned = instantiate(
+                instantiate(variable_font(), UserAxisLimit::Partial(range.clone())),
+                UserAxisLimit::Full(location),
+            );
+            assert_close(glyph_metrics(&pinned), expected.clone(), location);
+            assert!(!pinned.tables.contains(b"avar"));
+            assert!(!pinned.tables.contains(b"HVAR"));
+
+            let coord = normalize(location, triple, Some(&avar.maps[0]));
+            let advance = ot_round(default_advance as f32 + hvar.advance_delta(1, &[coord]));
+            assert!(
+                (advance - expected.1 as i32).abs() <= 1,
+                "HVAR at wght={}: {} != {}",
+                location,
+                advance,
+                expected.1
+            );
+        }
+    }
+
+    #[test]
+    fn test_deltas_are_rounded_once() {
+        // At the peak of one tent and half way down another, each point
+        // moves by half of two odd deltas, which only add up to a whole
+        // number if they are summed before rounding.
+        let mut variations = GlyphVariationData {
+            deltasets: [(0.0, 1.0, 1.0), (0.25, 0.75, 1.0)]
+                .iter()
+                .map(|&(start, peak, end)| DeltaSet {
+                    start: vec![start],
+                    peak: vec![peak],
+                    end: vec![end],
+                    deltas: vec![(1, 3), (-1, 5)],
+                })
+                .collect(),
+        };
+        let limits = NormalizedAxisLimits(BTreeMap::from([(
+            tag!("wght"),
+            NormalizedAxisLimit::Full(0.5),
+        )]));
+        let default = instantiate_gvar_data(&mut variations, &[tag!("wght")], &limits);
+        assert_eq!(default, vec![(1, 3), (-1, 5)]);
+        assert!(variations.deltasets.is_empty());
+    }
+
+    #[test]
+    fn test_limit_axis_range() {
+        assert_range_matches_pinning(
+            AxisRange::new(300.0, 700.0),
+            &[300.0, 350.0, 400.0, 550.0, 650.0, 700.0],
+        );
+    }
+
+    #[test]
+    fn test_limit_axis_range_with_new_default() {
+        assert_range_matches_pinning(
+            AxisRange::with_default(100.0, 900.0, 650.0),
+            &[100.0, 250.0, 400.0, 500.0, 650.0, 800.0, 900.0],
+        );
+        assert_range_matches_pinning(
+            AxisRange::with_default(200.0, 600.0, 300.0),
+            &[200.0, 300.0, 450.0, 600.0],
+        );
+    }
+
+    fn avar2_font() -> Font {
+        let axis = |tag: Tag, name_id| fvar::VariationAxisRecord {
+            axisTag: tag,
+            minValue: 100.0,
+            defaultValue: 400.0,
+            maxValue: 900.0,
+            flags: 0,
+            axisNameID: name_id,
+        };
+        let identity = SegmentMap::new(vec![(-1.0, -1.0), (0.0, 0.0), (1.0, 1.0)]);
+        let region = vec![
+            RegionAxisCoordinates {
+                startCoord: 0.0,
+                peakCoord: 1.0,
+                endCoord: 1.0,
+            },
+            RegionAxisCoordinates {
+                startCoord: 0.0,
+                peakCoord: 0.0,
+                endCoord: 0.0,
+            },
+        ];
+        let mut font = Font::new(SfntVersion::TrueType);
+        font.tables.insert(fvar::fvar {
+            axes: vec![axis(tag!("wght"), 256), axis(tag!("XOPQ"), 257)],
+            instances: vec![],
+        });
+        font.tables.insert(avar::avar {
+            maps: vec![identity.clone(), identity],
+            axis_index_map: None,
+            var_store: Some(ItemVariationStore::new(
+                2,
+                vec![region],
+                vec![vec![0], vec![-4096]],
+            )),
+        });
+        font
+    }
+
+    #[test]
+    fn test_partial_avar2_is_an_error() {
+        let mut font = avar2_font();
+        let limits = UserAxisLimits(BTreeMap::from([(
+            tag!("wght"),
+            UserAxisLimit::Partial(AxisRange::new(400.0, 700.0)),
+        )]));
+        assert!(instantiate_variable_font(&mut font, limits).is_err());
+
+        let mut font = avar2_font();
+        let limits = UserAxisLimits(BTreeMap::from([(tag!("wdth"), UserAxisLimit::Full(100.0))]));
+        assert!(instantiate_variable_font(&mut font, limits).is_err());
+    }
 }
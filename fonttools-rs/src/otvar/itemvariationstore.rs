@@ -3,9 +3,10 @@ use otspec::{
     DeserializationError, Deserialize, Deserializer, ReaderContext, Serialize, Serializer,
 };
 use otspec_macros::tables;
+use std::collections::BTreeMap;
 
 tables!(
-    RegionAxisCoordinates {
+    RegionAxisCoordinates [serde] {
         F2DOT14	startCoord
         F2DOT14	peakCoord
         F2DOT14	endCoord
@@ -16,6 +17,7 @@ tables!(
         Counted(uint16) regionIndexes
     }
     ItemVariationStoreInternal {
+        [offset_base]
         uint16 format
         Offset32(VariationRegionList) variationRegionList
         CountedOffset32(ItemVariationData) itemVariationData
@@ -24,6 +26,7 @@ tables!(
 );
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents variation data inside an item variation store
 pub struct ItemVariationData {
     /// Indices into the IVS's region array.
@@ -119,6 +122,7 @@ impl Serialize for VariationRegionList {
 
 #[allow(non_snake_case, non_camel_case_types)]
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An item variation store, collecting a set of variation data for scalar values.
 pub struct ItemVariationStore {
     /// Format - set to 1
@@ -131,6 +135,75 @@ pub struct ItemVariationStore {
     pub variationData: Vec<ItemVariationData>,
 }
 
+impl ItemVariationStore {
+    /// Creates an item variation store with a single subtable, given a list
+    /// of regions and a row of deltas (one per region) for each item.
+    pub fn new(
+        axis_count: uint16,
+        regions: Vec<Vec<RegionAxisCoordinates>>,
+        deltas: Vec<Vec<int16>>,
+    ) -> Self {
+        ItemVariationStore {
+            format: 1,
+            axisCount: axis_count,
+            variationData: vec![ItemVariationData {
+                region_indexes: (0..regions.len() as u16).collect(),
+                delta_values: deltas,
+            }],
+            variationRegions: regions,
+        }
+    }
+
+    /// Returns the interpolated delta for the item with the given variation
+    /// index (`outer << 16 | inner`) at a normalized location.
+    pub fn get_delta(&self, var_idx: uint32, coords: &[f32]) -> f32 {
+        if var_idx == 0xFFFFFFFF {
+            return 0.0;
+        }
+        let outer = (var_idx >> 16) as usize;
+        let inner = (var_idx & 0xFFFF) as usize;
+        let data = match self.variationData.get(outer) {
+            Some(data) => data,
+            None => return 0.0,
+        };
+        let deltas = match data.delta_values.get(inner) {
+            Some(deltas) => deltas,
+            None => return 0.0,
+        };
+        data.region_indexes
+            .iter()
+            .zip(deltas.iter())
+            .map(|(&region_ix, &delta)| {
+                self.region_scalar(region_ix as usize, coords) * f32::from(delta)
+            })
+            .sum()
+    }
+
+    /// Returns the scalar of a variation region at a normalized location.
+    fn region_scalar(&self, region_ix: usize, coords: &[f32]) -> f32 {
+        let mut scalar = 1.0;
+        for (axis, coord) in self.variationRegions[region_ix].iter().enumerate() {
+            let (start, peak, end) = (coord.startCoord, coord.peakCoord, coord.endCoord);
+            if peak == 0.0 || start > peak || peak > end || (start < 0.0 && end > 0.0) {
+                continue;
+            }
+            let v = coords.get(axis).copied().unwrap_or(0.0);
+            if v == peak {
+                continue;
+            }
+            if v <= start || v >= end {
+                return 0.0;
+            }
+            if v < peak {
+                scalar *= (v - start) / (peak - start);
+            } else {
+                scalar *= (end - v) / (end - peak);
+            }
+        }
+        scalar
+    }
+}
+
 impl Deserialize for ItemVariationStore {
     fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
         let internal: ItemVariationStoreInternal = c.de()?;
@@ -169,3 +242,84 @@ impl Serialize for ItemVariationStore {
         .to_bytes(data)
     }
 }
+
+/// Incrementally builds an item variation store.
+///
+/// Regions and identical rows of deltas are shared, and rows which use the
+/// same set of regions are grouped into the same subtable.
+#[derive(Debug, Default)]
+pub struct ItemVariationStoreBuilder {
+    axis_count: uint16,
+    regions: Vec<Vec<RegionAxisCoordinates>>,
+    variation_data: Vec<ItemVariationData>,
+    // The subtable currently being filled for each set of region indices
+    open_subtables: BTreeMap<Vec<uint16>, usize>,
+    seen_rows: BTreeMap<(Vec<uint16>, Vec<int16>), uint32>,
+}
+
+impl ItemVariationStoreBuilder {
+    /// Creates a new, empty builder for a font with the given number of axes.
+    pub fn new(axis_count: uint16) -> Self {
+        ItemVariationStoreBuilder {
+            axis_count,
+            ..Default::default()
+        }
+    }
+
+    fn region_index(&mut self, region: Vec<RegionAxisCoordinates>) -> uint16 {
+        if let Some(ix) = self.regions.iter().position(|r| *r == region) {
+            return ix as uint16;
+        }
+        self.regions.push(region);
+        (self.regions.len() - 1) as uint16
+    }
+
+    /// Adds a row of deltas, each paired with the region to which it applies,
+    /// and returns its variation index (`outer << 16 | inner`).
+    ///
+    /// Zero deltas are dropped, and identical rows share a variation index.
+    pub fn add(&mut self, deltas: Vec<(Vec<RegionAxisCoordinates>, int16)>) -> uint32 {
+        let mut row: Vec<(uint16, int16)> = deltas
+            .into_iter()
+            .filter(|(_, delta)| *delta != 0)
+            .map(|(region, delta)| (self.region_index(region), delta))
+            .collect();
+        row.sort_unstable();
+        let key: (Vec<uint16>, Vec<int16>) = row.into_iter().unzip();
+        if let Some(&var_idx) = self.seen_rows.get(&key) {
+            return var_idx;
+        }
+        let outer = match self.open_subtables.get(&key.0) {
+            Some(&outer) if self.variation_data[outer].delta_values.len() < 0xFFFF => outer,
+            _ => {
+                self.variation_data.push(ItemVariationData {
+                    region_indexes: key.0.clone(),
+                    delta_values: vec![],
+                });
+                let outer = self.variation_data.len() - 1;
+                self.open_subtables.insert(key.0.clone(), outer);
+                outer
+            }
+        };
+        let data = &mut self.variation_data[outer];
+        let var_idx = ((outer as uint32) << 16) | data.delta_values.len() as uint32;
+        data.delta_values.push(key.1.clone());
+        self.seen_rows.insert(key, var_idx);
+        var_idx
+    }
+
+    /// Returns true if no rows have been added to the store.
+    pub fn is_empty(&self) -> bool {
+        self.seen_rows.is_empty()
+    }
+
+    /// Consumes the builder, returning the item variation store.
+    pub fn finish(self) -> ItemVariationStore {
+        ItemVariationStore {
+            format: 1,
+            axisCount: self.axis_count,
+            variationRegions: self.regions,
+            variationData: self.variation_data,
+        }
+    }
+}
@@ -0,0 +1,761 @@
+use crate::font::Font;
+use crate::otvar::{
+    DeltaSetIndexMap, ItemVariationStore, ItemVariationStoreBuilder, Location,
+    RegionAxisCoordinates, VariationModel,
+};
+use crate::table_store::CowPtr;
+use crate::tables::glyf::{glyf, Glyph};
+use crate::tables::gvar::{gvar, DeltaSet, GlyphVariationData};
+use crate::tables::hmtx::Metric;
+use crate::tables::GDEF::GDEF;
+use crate::tables::GPOS::{Positioning, GPOS};
+use crate::tables::HVAR::HVAR;
+use crate::tables::MVAR::MVAR;
+use crate::tables::{self};
+use crate::tag;
+use otmath::{ot_round, Support};
+use otspec::layout::anchor::Anchor;
+use otspec::layout::device::Device;
+use otspec::layout::valuerecord::ValueRecord;
+use otspec::types::*;
+use otspec::DeserializationError;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Mul, Sub};
+
+/// Errors which can occur when merging masters into a variable font
+#[derive(Debug)]
+pub enum MergeError {
+    /// The number of masters did not match the number of locations
+    LocationCountMismatch,
+    /// None of the masters was at the default location
+    NoDefaultMaster,
+    /// A master (given by index) was missing a required table
+    MissingTable(usize, Tag),
+    /// A table in one of the masters could not be read
+    Deserialization(DeserializationError),
+    /// A master had a different glyph order to the default master
+    IncompatibleGlyphOrder(usize),
+    /// A glyph's outlines were not compatible between the default master and
+    /// another master
+    IncompatibleGlyph(usize, String),
+    /// A master's GPOS table had a different structure to the default master's
+    IncompatibleLayout(usize, String),
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::LocationCountMismatch => {
+                write!(f, "Number of masters does not match number of locations")
+            }
+            MergeError::NoDefaultMaster => write!(f, "No master at the default location"),
+            MergeError::MissingTable(ix, tag) => write!(f, "Master {} has no {} table", ix, tag),
+            MergeError::Deserialization(e) => write!(f, "Could not read master: {}", e),
+            MergeError::IncompatibleGlyphOrder(ix) => {
+                write!(f, "Master {} has a different glyph order", ix)
+            }
+            MergeError::IncompatibleGlyph(ix, name) => {
+                write!(f, "Glyph {} is not compatible in master {}", name, ix)
+            }
+            MergeError::IncompatibleLayout(ix, msg) => {
+                write!(f, "GPOS table of master {} is not compatible: {}", ix, msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+impl From<DeserializationError> for MergeError {
+    fn from(e: DeserializationError) -> Self {
+        MergeError::Deserialization(e)
+    }
+}
+
+// Flattened glyph coordinates (including phantom points), as floats so that
+// they can be fed to the variation model.
+#[derive(Debug, Clone, PartialEq)]
+struct GlyphCoords(Vec<(f32, f32)>);
+
+impl Sub for GlyphCoords {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        GlyphCoords(
+            self.0
+                .iter()
+                .zip(other.0.iter())
+                .map(|(a, b)| (a.0 - b.0, a.1 - b.1))
+                .collect(),
+        )
+    }
+}
+
+impl Mul<f32> for GlyphCoords {
+    type Output = Self;
+    fn mul(self, scalar: f32) -> Self {
+        GlyphCoords(
+            self.0
+                .iter()
+                .map(|(x, y)| (x * scalar, y * scalar))
+                .collect(),
+        )
+    }
+}
+
+impl GlyphCoords {
+    fn new(glyph: &Glyph, metric: &Metric) -> Self {
+        let mut coords: Vec<(f32, f32)> = glyph
+            .contours
+            .iter()
+            .flatten()
+            .map(|pt| (pt.x as f32, pt.y as f32))
+            .collect();
+        for comp in &glyph.components {
+            let [_, _, _, _, translate_x, translate_y] = comp.transformation.as_coeffs();
+            coords.push((translate_x as f32, translate_y as f32));
+        }
+        // Phantom points
+        let left_side_x = glyph.xMin as f32 - metric.lsb as f32;
+        coords.push((left_side_x, 0.0));
+        coords.push((left_side_x + metric.advanceWidth as f32, 0.0));
+        coords.push((0.0, 0.0));
+        coords.push((0.0, 0.0));
+        GlyphCoords(coords)
+    }
+}
+
+fn compatible(a: &Glyph, b: &Glyph) -> bool {
+    a.contours.len() == b.contours.len()
+        && a.contours
+            .iter()
+            .zip(b.contours.iter())
+            .all(|(c1, c2)| c1.len() == c2.len())
+        && a.components.len() == b.components.len()
+        && a.components
+            .iter()
+            .zip(b.components.iter())
+            .all(|(c1, c2)| c1.glyph_index == c2.glyph_index)
+}
+
+fn variation_index(var_idx: uint32) -> Device {
+    Device::variation_index((var_idx >> 16) as uint16, (var_idx & 0xFFFF) as uint16)
+}
+
+fn all_tables<T: Clone>(
+    masters: &[Font],
+    tag: Tag,
+    get: impl Fn(&Font) -> Result<Option<CowPtr<T>>, DeserializationError>,
+) -> Result<Vec<CowPtr<T>>, MergeError> {
+    masters
+        .iter()
+        .enumerate()
+        .map(|(ix, master)| get(master)?.ok_or(MergeError::MissingTable(ix, tag)))
+        .collect()
+}
+
+// The font-wide metrics which can be varied in MVAR
+fn metrics(font: &Font) -> Result<BTreeMap<Tag, f32>, MergeError> {
+    let mut metrics = BTreeMap::new();
+    if let Some(os2) = font.tables.os2()? {
+        for (value_tag, value) in [
+            (tag!("hasc"), os2.sTypoAscender),
+            (tag!("hdsc"), os2.sTypoDescender),
+            (tag!("hlgp"), os2.sTypoLineGap),
+            (tag!("sbxs"), os2.ySubscriptXSize),
+            (tag!("sbys"), os2.ySubscriptYSize),
+            (tag!("sbxo"), os2.ySubscriptXOffset),
+            (tag!("sbyo"), os2.ySubscriptYOffset),
+            (tag!("spxs"), os2.ySuperscriptXSize),
+            (tag!("spys"), os2.ySuperscriptYSize),
+            (tag!("spxo"), os2.ySuperscriptXOffset),
+            (tag!("spyo"), os2.ySuperscriptYOffset),
+            (tag!("strs"), os2.yStrikeoutSize),
+            (tag!("stro"), os2.yStrikeoutPosition),
+        ] {
+            metrics.insert(value_tag, value as f32);
+        }
+        metrics.insert(tag!("hcla"), os2.usWinAscent as f32);
+        metrics.insert(tag!("hcld"), os2.usWinDescent as f32);
+        if let Some(x_height) = os2.sxHeight {
+            metrics.insert(tag!("xhgt"), x_height as f32);
+        }
+        if let Some(cap_height) = os2.sCapHeight {
+            metrics.insert(tag!("cpht"), cap_height as f32);
+        }
+    }
+    if let Some(hhea) = font.tables.hhea()? {
+        metrics.insert(tag!("hcrs"), hhea.caretSlopeRise as f32);
+        metrics.insert(tag!("hcrn"), hhea.caretSlopeRun as f32);
+        metrics.insert(tag!("hcof"), hhea.caretOffset as f32);
+    }
+    if let Some(post) = font.tables.post()? {
+        metrics.insert(tag!("undo"), post.underlinePosition as f32);
+        metrics.insert(tag!("unds"), post.underlineThickness as f32);
+    }
+    Ok(metrics)
+}
+
+// Gathers the subtables of a given lookup type from each master, returning the
+// index of the first master whose lookup has a different type or number of
+// subtables.
+macro_rules! master_subtables {
+    ($rules:expr, $variant:path, $count:expr) => {{
+        let mut subtables = vec![];
+        for (ix, rule) in $rules.iter().enumerate() {
+            match rule {
+                $variant(st) if st.len() == $count => subtables.push(st),
+                _ => return Err(ix),
+            }
+        }
+        subtables
+    }};
+}
+
+struct Merger {
+    model: VariationModel<Tag>,
+    axis_order: Vec<Tag>,
+    default_ix: usize,
+}
+
+type AnchorMap = BTreeMap<GlyphID, BTreeMap<uint16, Anchor>>;
+type MarkMap = BTreeMap<GlyphID, (uint16, Anchor)>;
+
+impl Merger {
+    fn region(&self, support: &Support<Tag>) -> Vec<RegionAxisCoordinates> {
+        self.axis_order
+            .iter()
+            .map(|axis| {
+                let &(start, peak, end) = support.get(axis).unwrap_or(&(0.0, 0.0, 0.0));
+                RegionAxisCoordinates {
+                    startCoord: start,
+                    peakCoord: peak,
+                    endCoord: end,
+                }
+            })
+            .collect()
+    }
+
+    fn store_builder(&self) -> ItemVariationStoreBuilder {
+        ItemVariationStoreBuilder::new(self.axis_order.len() as uint16)
+    }
+
+    /// Adds the deltas for a value to a store, returning its variation index,
+    /// or `None` if the value does not vary.
+    fn store_deltas(
+        &self,
+        store: &mut ItemVariationStoreBuilder,
+        values: &[Option<f32>],
+    ) -> Option<uint32> {
+        let default = values[self.default_ix]?;
+        if values.iter().flatten().all(|&v| v == default) {
+            return None;
+        }
+        let deltas: Vec<(Vec<RegionAxisCoordinates>, int16)> = self
+            .model
+            .get_deltas_and_supports(values)
+            .into_iter()
+            .filter(|(_, support)| !support.is_empty())
+            .map(|(delta, support)| (self.region(&support), ot_round(delta) as int16))
+            .filter(|(_, delta)| *delta != 0)
+            .collect();
+        if deltas.is_empty() {
+            None
+        } else {
+            Some(store.add(deltas))
+        }
+    }
+
+    fn glyph_variations(&self, coords: &[Option<GlyphCoords>]) -> Option<GlyphVariationData> {
+        let default = coords[self.default_ix].as_ref()?;
+        if coords.iter().flatten().all(|c| c == default) {
+            return None;
+        }
+        let deltasets: Vec<DeltaSet> = self
+            .model
+            .get_deltas_and_supports(coords)
+            .into_iter()
+            .filter(|(_, support)| !support.is_empty())
+            .map(|(delta, support)| {
+                let region = self.region(&support);
+                DeltaSet {
+                    start: region.iter().map(|r| r.startCoord).collect(),
+                    peak: region.iter().map(|r| r.peakCoord).collect(),
+                    end: region.iter().map(|r| r.endCoord).collect(),
+                    deltas: delta
+                        .0
+                        .iter()
+                        .map(|&(x, y)| (ot_round(x) as int16, ot_round(y) as int16))
+                        .collect(),
+                }
+            })
+            .filter(|ds| ds.deltas.iter().any(|&d| d != (0, 0)))
+            .collect();
+        if deltasets.is_empty() {
+            None
+        } else {
+            Some(GlyphVariationData { deltasets })
+        }
+    }
+
+    /// Builds the (serialized) `gvar` table and the `HVAR` table
+    fn merge_glyphs(&self, masters: &[Font]) -> Result<(Option<Vec<u8>>, HVAR), MergeError> {
+        let glyfs = all_tables(masters, tables::glyf::TAG, |f| f.tables.glyf())?;
+        let hmtxs = all_tables(masters, tables::hmtx::TAG, |f| f.tables.hmtx())?;
+        let default_glyf: &glyf = &glyfs[self.default_ix];
+        let glyph_names: Vec<Option<Vec<String>>> = masters
+            .iter()
+            .map(|m| Ok(m.tables.post()?.and_then(|p| p.glyphnames.clone())))
+            .collect::<Result<_, DeserializationError>>()?;
+        let default_names = &glyph_names[self.default_ix];
+        for (ix, glyf) in glyfs.iter().enumerate() {
+            let names_differ = matches!(
+                (&glyph_names[ix], default_names),
+                (Some(names), Some(default)) if names != default
+            );
+            if glyf.glyphs.len() != default_glyf.glyphs.len()
+                || hmtxs[ix].metrics.len() != glyf.glyphs.len()
+                || names_differ
+            {
+                return Err(MergeError::IncompatibleGlyphOrder(ix));
+            }
+        }
+
+        let mut variations = Vec::with_capacity(default_glyf.glyphs.len());
+        let mut advance_store = self.store_builder();
+        let mut advance_map = Vec::with_capacity(default_glyf.glyphs.len());
+        for (gid, default_glyph) in default_glyf.glyphs.iter().enumerate() {
+            let mut coords = vec![];
+            for (ix, glyf) in glyfs.iter().enumerate() {
+                let glyph = &glyf.glyphs[gid];
+                if !compatible(default_glyph, glyph) {
+                    let name = default_names
+                        .as_ref()
+                        .and_then(|names| names.get(gid).cloned())
+                        .unwrap_or_else(|| format!("#{}", gid));
+                    return Err(MergeError::IncompatibleGlyph(ix, name));
+                }
+                coords.push(Some(GlyphCoords::new(glyph, &hmtxs[ix].metrics[gid])));
+            }
+            variations.push(self.glyph_variations(&coords));
+
+            let advances: Vec<Option<f32>> = hmtxs
+                .iter()
+                .map(|hmtx| Some(hmtx.metrics[gid].advanceWidth as f32))
+                .collect();
+            let var_idx = self
+                .store_deltas(&mut advance_store, &advances)
+                .unwrap_or_else(|| advance_store.add(vec![]));
+            advance_map.push(var_idx);
+        }
+
+        let gvar_bytes = if variations.iter().any(|v| v.is_some()) {
+            Some(gvar { variations }.to_bytes(Some(default_glyf)))
+        } else {
+            None
+        };
+        let hvar = HVAR {
+            item_variation_store: advance_store.finish(),
+            advance_mapping: Some(DeltaSetIndexMap {
+                entries: advance_map,
+            }),
+            lsb_mapping: None,
+            rsb_mapping: None,
+        };
+        Ok((gvar_bytes, hvar))
+    }
+
+    /// Builds the `MVAR` table, if any font-wide metrics vary
+    fn merge_metrics(&self, masters: &[Font]) -> Result<Option<MVAR>, MergeError> {
+        let all_metrics: Vec<BTreeMap<Tag, f32>> =
+            masters.iter().map(metrics).collect::<Result<_, _>>()?;
+        let mut store = self.store_builder();
+        let mut records = BTreeMap::new();
+        for &value_tag in all_metrics[self.default_ix].keys() {
+            let values: Vec<Option<f32>> = all_metrics
+                .iter()
+                .map(|m| m.get(&value_tag).copied())
+                .collect();
+            if let Some(var_idx) = self.store_deltas(&mut store, &values) {
+                records.insert(value_tag, var_idx);
+            }
+        }
+        if records.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(MVAR {
+            item_variation_store: Some(store.finish()),
+            records,
+        }))
+    }
+
+    /// Adds VariationIndex tables to the default master's `GPOS` table,
+    /// returning it along with the item variation store for `GDEF`
+    fn merge_layout(
+        &self,
+        masters: &[Font],
+    ) -> Result<Option<(GPOS, ItemVariationStore)>, MergeError> {
+        if !masters[self.default_ix].tables.contains(&tables::GPOS::TAG) {
+            return Ok(None);
+        }
+        let all_gpos = all_tables(masters, tables::GPOS::TAG, |f| f.tables.GPOS())?;
+        let mut merged: GPOS = (*all_gpos[self.default_ix]).clone();
+        for (ix, gpos) in all_gpos.iter().enumerate() {
+            if gpos.lookups.len() != merged.lookups.len() {
+                return Err(MergeError::IncompatibleLayout(
+                    ix,
+                    "different number of lookups".to_string(),
+                ));
+            }
+        }
+        let mut store = self.store_builder();
+        for (lookup_ix, lookup) in merged.lookups.iter_mut().enumerate() {
+            let rules: Vec<&Positioning> = all_gpos
+                .iter()
+                .map(|g| &g.lookups[lookup_ix].rule)
+                .collect();
+            self.merge_positioning(&mut lookup.rule, &rules, &mut store)
+                .map_err(|ix| {
+                    MergeError::IncompatibleLayout(
+                        ix,
+                        format!(
+                            "lookup {} has a different type or number of subtables",
+                            lookup_ix
+                        ),
+                    )
+                })?;
+        }
+        if store.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some((merged, store.finish())))
+    }
+
+    fn merge_positioning(
+        &self,
+        rule: &mut Positioning,
+        rules: &[&Positioning],
+        store: &mut ItemVariationStoreBuilder,
+    ) -> Result<(), usize> {
+        match rule {
+            Positioning::Single(subtables) => {
+                let masters = master_subtables!(rules, Positioning::Single, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    for (glyph, record) in subtable.mapping.iter_mut() {
+                        let records: Vec<Option<&ValueRecord>> = masters
+                            .iter()
+                            .map(|m| m[st_ix].mapping.get(glyph))
+                            .collect();
+                        self.vary_value_record(record, &records, store);
+                    }
+                }
+            }
+            Positioning::Pair(subtables) => {
+                let masters = master_subtables!(rules, Positioning::Pair, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    for (pair, (record1, record2)) in subtable.mapping.iter_mut() {
+                        let pairs: Vec<Option<&(ValueRecord, ValueRecord)>> =
+                            masters.iter().map(|m| m[st_ix].mapping.get(pair)).collect();
+                        let records: Vec<Option<&ValueRecord>> =
+                            pairs.iter().map(|p| p.map(|p| &p.0)).collect();
+                        self.vary_value_record(record1, &records, store);
+                        let records: Vec<Option<&ValueRecord>> =
+                            pairs.iter().map(|p| p.map(|p| &p.1)).collect();
+                        self.vary_value_record(record2, &records, store);
+                    }
+                }
+            }
+            Positioning::Cursive(subtables) => {
+                let masters = master_subtables!(rules, Positioning::Cursive, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    for (glyph, (entry, exit)) in subtable.mapping.iter_mut() {
+                        let anchors: Vec<Option<&(Option<Anchor>, Option<Anchor>)>> = masters
+                            .iter()
+                            .map(|m| m[st_ix].mapping.get(glyph))
+                            .collect();
+                        if let Some(entry) = entry {
+                            let entries: Vec<Option<&Anchor>> = anchors
+                                .iter()
+                                .map(|a| a.and_then(|a| a.0.as_ref()))
+                                .collect();
+                            self.vary_anchor(entry, &entries, store);
+                        }
+                        if let Some(exit) = exit {
+                            let exits: Vec<Option<&Anchor>> = anchors
+                                .iter()
+                                .map(|a| a.and_then(|a| a.1.as_ref()))
+                                .collect();
+                            self.vary_anchor(exit, &exits, store);
+                        }
+                    }
+                }
+            }
+            Positioning::MarkToBase(subtables) => {
+                let masters = master_subtables!(rules, Positioning::MarkToBase, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    let marks: Vec<&MarkMap> = masters.iter().map(|m| &m[st_ix].marks).collect();
+                    self.vary_marks(&mut subtable.marks, &marks, store);
+                    let bases: Vec<&AnchorMap> = masters.iter().map(|m| &m[st_ix].bases).collect();
+                    self.vary_anchor_map(&mut subtable.bases, &bases, store);
+                }
+            }
+            Positioning::MarkToLig(subtables) => {
+                let masters = master_subtables!(rules, Positioning::MarkToLig, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    let marks: Vec<&MarkMap> = masters.iter().map(|m| &m[st_ix].marks).collect();
+                    self.vary_marks(&mut subtable.marks, &marks, store);
+                    for (glyph, components) in subtable.ligatures.iter_mut() {
+                        for (component_ix, component) in components.iter_mut().enumerate() {
+                            for (class, anchor) in component.iter_mut() {
+                                let anchors: Vec<Option<&Anchor>> = masters
+                                    .iter()
+                                    .map(|m| {
+                                        m[st_ix]
+                                            .ligatures
+                                            .get(glyph)
+                                            .and_then(|l| l.get(component_ix))
+                                            .and_then(|c| c.get(class))
+                                    })
+                                    .collect();
+                                self.vary_anchor(anchor, &anchors, store);
+                            }
+                        }
+                    }
+                }
+            }
+            Positioning::MarkToMark(subtables) => {
+                let masters = master_subtables!(rules, Positioning::MarkToMark, subtables.len());
+                for (st_ix, subtable) in subtables.iter_mut().enumerate() {
+                    let marks: Vec<&MarkMap> =
+                        masters.iter().map(|m| &m[st_ix].combining_marks).collect();
+                    self.vary_marks(&mut subtable.combining_marks, &marks, store);
+                    let bases: Vec<&AnchorMap> =
+                        masters.iter().map(|m| &m[st_ix].base_marks).collect();
+                    self.vary_anchor_map(&mut subtable.base_marks, &bases, store);
+                }
+            }
+            // Contextual lookups have no values of their own
+            Positioning::Contextual(_) | Positioning::ChainedContextual(_) => {}
+        }
+        Ok(())
+    }
+
+    fn vary_value_record(
+        &self,
+        record: &mut ValueRecord,
+        masters: &[Option<&ValueRecord>],
+        store: &mut ItemVariationStoreBuilder,
+    ) {
+        // A value missing from a master's value record is an adjustment of zero
+        macro_rules! vary {
+            ($value:ident, $device:ident) => {
+                let values: Vec<Option<f32>> = masters
+                    .iter()
+                    .map(|vr| Some(f32::from(vr.and_then(|vr| vr.$value).unwrap_or(0))))
+                    .collect();
+                if let Some(var_idx) = self.store_deltas(store, &values) {
+                    record.$value.get_or_insert(0);
+                    record.$device = Some(Offset16::to(variation_index(var_idx)));
+                }
+            };
+        }
+        vary!(xPlacement, xPlaDevice);
+        vary!(yPlacement, yPlaDevice);
+        vary!(xAdvance, xAdvDevice);
+        vary!(yAdvance, yAdvDevice);
+    }
+
+    fn vary_anchor(
+        &self,
+        anchor: &mut Anchor,
+        masters: &[Option<&Anchor>],
+        store: &mut ItemVariationStoreBuilder,
+    ) {
+        let xs: Vec<Option<f32>> = masters
+            .iter()
+            .map(|a| a.map(|a| f32::from(a.xCoordinate)))
+            .collect();
+        if let Some(var_idx) = self.store_deltas(store, &xs) {
+            anchor.xDevice = Some(variation_index(var_idx));
+        }
+        let ys: Vec<Option<f32>> = masters
+            .iter()
+            .map(|a| a.map(|a| f32::from(a.yCoordinate)))
+            .collect();
+        if let Some(var_idx) = self.store_deltas(store, &ys) {
+            anchor.yDevice = Some(variation_index(var_idx));
+        }
+    }
+
+    fn vary_marks(
+        &self,
+        marks: &mut MarkMap,
+        masters: &[&MarkMap],
+        store: &mut ItemVariationStoreBuilder,
+    ) {
+        for (glyph, (_, anchor)) in marks.iter_mut() {
+            let anchors: Vec<Option<&Anchor>> = masters
+                .iter()
+                .map(|m| m.get(glyph).map(|(_, a)| a))
+                .collect();
+            self.vary_anchor(anchor, &anchors, store);
+        }
+    }
+
+    fn vary_anchor_map(
+        &self,
+        bases: &mut AnchorMap,
+        masters: &[&AnchorMap],
+        store: &mut ItemVariationStoreBuilder,
+    ) {
+        for (glyph, classes) in bases.iter_mut() {
+            for (class, anchor) in classes.iter_mut() {
+                let anchors: Vec<Option<&Anchor>> = masters
+                    .iter()
+                    .map(|m| m.get(glyph).and_then(|b| b.get(class)))
+                    .collect();
+                self.vary_anchor(anchor, &anchors, store);
+            }
+        }
+    }
+}
+
+/// Merges a set of compiled master fonts into a variable font.
+///
+/// `locations` gives the normalized location of each master, and `axis_order`
+/// the order of the axes in the `fvar` table. The master at the default
+/// location provides the default outlines, metrics and layout; the `gvar`,
+/// `HVAR` and `MVAR` tables and the `GPOS` variation deltas (stored in `GDEF`)
+/// are built from the differences between it and the other masters.
+///
+/// The `fvar`, `avar`, `STAT` and `name` entries describing the axes are not
+/// added here; see e.g. `designspace::Designspace::build_variable_font`.
+pub fn merge_masters(
+    mut masters: Vec<Font>,
+    locations: Vec<Location<Tag>>,
+    axis_order: Vec<Tag>,
+) -> Result<Font, MergeError> {
+    if masters.len() != locations.len() {
+        return Err(MergeError::LocationCountMismatch);
+    }
+    let default_ix = locations
+        .iter()
+        .position(|loc| loc.values().all(|&v| v == 0.0))
+        .ok_or(MergeError::NoDefaultMaster)?;
+    let merger = Merger {
+        model: VariationModel::new(locations, axis_order.clone()),
+        axis_order,
+        default_ix,
+    };
+
+    let (gvar_bytes, hvar) = merger.merge_glyphs(&masters)?;
+    let mvar = merger.merge_metrics(&masters)?;
+    let layout = merger.merge_layout(&masters)?;
+
+    let mut font = masters.swap_remove(default_ix);
+    if let Some(gvar_bytes) = gvar_bytes {
+        font.tables.insert_raw(tables::gvar::TAG, gvar_bytes);
+    }
+    font.tables.insert(hvar);
+    if let Some(mvar) = mvar {
+        font.tables.insert(mvar);
+    }
+    if let Some((gpos, store)) = layout {
+        let mut gdef = font.tables.GDEF()?.map_or_else(
+            || GDEF {
+                glyph_class: BTreeMap::new(),
+                attachment_point_list: BTreeMap::new(),
+                ligature_caret_list: BTreeMap::new(),
+                mark_attachment_class: BTreeMap::new(),
+                mark_glyph_sets: None,
+                item_variation_store: None,
+            },
+            |gdef| gdef.into_owned(),
+        );
+        gdef.item_variation_store = Some(store);
+        font.tables.insert(gdef);
+        font.tables.insert(gpos);
+    }
+    Ok(font)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otspec::{btreemap, valuerecord};
+
+    fn two_master_merger() -> Merger {
+        let wght = tag!("wght");
+        Merger {
+            model: VariationModel::new(
+                vec![btreemap!(wght => 0.0), btreemap!(wght => 1.0)],
+                vec![wght],
+            ),
+            axis_order: vec![wght],
+            default_ix: 0,
+        }
+    }
+
+    #[test]
+    fn merger_store_deltas() {
+        let merger = two_master_merger();
+        let mut store = merger.store_builder();
+        assert_eq!(
+            merger.store_deltas(&mut store, &[Some(10.0), Some(10.0)]),
+            None
+        );
+        assert_eq!(merger.store_deltas(&mut store, &[None, Some(10.0)]), None);
+        assert_eq!(
+            merger.store_deltas(&mut store, &[Some(10.0), Some(30.0)]),
+            Some(0)
+        );
+        assert_eq!(
+            merger.store_deltas(&mut store, &[Some(10.0), Some(20.2)]),
+            Some(1)
+        );
+        let store = store.finish();
+        assert_eq!(store.get_delta(0, &[0.5]), 10.0);
+        assert_eq!(store.get_delta(1, &[1.0]), 10.0);
+    }
+
+    #[test]
+    fn merger_vary_value_record() {
+        let merger = two_master_merger();
+        let mut store = merger.store_builder();
+        let light = valuerecord!(xAdvance = -20);
+        let bold = valuerecord!(xAdvance = -60, xPlacement = 5);
+        let mut merged = light.clone();
+        merger.vary_value_record(&mut merged, &[Some(&light), Some(&bold)], &mut store);
+        assert_eq!(merged.xPlacement, Some(0));
+        assert_eq!(merged.xAdvance, Some(-20));
+        assert!(merged.yPlaDevice.is_none());
+        let device = |d: &Option<Offset16<Device>>| d.as_ref().unwrap().link.clone().unwrap();
+        assert_eq!(
+            device(&merged.xPlaDevice).as_variation_index(),
+            Some((0, 0))
+        );
+        assert_eq!(
+            device(&merged.xAdvDevice).as_variation_index(),
+            Some((0, 1))
+        );
+        let store = store.finish();
+        assert_eq!(store.get_delta(0, &[1.0]), 5.0);
+        assert_eq!(store.get_delta(1, &[1.0]), -40.0);
+    }
+
+    #[test]
+    fn merger_vary_anchor() {
+        let merger = two_master_merger();
+        let mut store = merger.store_builder();
+        let light = Anchor::new(100, 500);
+        let bold = Anchor::new(120, 500);
+        let mut merged = light.clone();
+        merger.vary_anchor(&mut merged, &[Some(&light), Some(&bold)], &mut store);
+        assert_eq!(
+            merged.xDevice.and_then(|d| d.as_variation_index()),
+            Some((0, 0))
+        );
+        assert_eq!(merged.yDevice, None);
+        assert_eq!(store.finish().get_delta(0, &[0.5]), 10.0);
+    }
+}
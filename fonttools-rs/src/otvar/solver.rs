@@ -0,0 +1,285 @@
+use otmath::{support_scalar, Location, Support};
+
+const EPSILON: f32 = 1.0 / (1 << 14) as f32;
+
+/// A normalized `(minimum, default, maximum)` triple describing new limits for an axis.
+pub type AxisTriple = (f32, f32, f32);
+
+/// A `(lower, peak, upper)` region of influence along a single axis.
+pub type Tent = (f32, f32, f32);
+
+fn reverse_negate(v: (f32, f32, f32)) -> (f32, f32, f32) {
+    (-v.2, -v.1, -v.0)
+}
+
+fn scalar_at(value: f32, tent: Tent) -> f32 {
+    let mut location: Location<()> = Location::new();
+    location.insert((), value);
+    let mut support: Support<()> = Support::new();
+    support.insert((), tent);
+    support_scalar(&location, &support)
+}
+
+/// The distances, in userspace units, from an axis's original default to its
+/// original minimum and maximum.
+pub type AxisDistances = (f32, f32);
+
+/// Renormalizes a value from the old -1/0/1 coordinate system into the
+/// coordinate system defined by the axis triple.
+///
+/// When the new range straddles the old default, its two sides had
+/// different lengths in userspace, so the distances are needed to put the
+/// value where the new default's normalization will put it.
+pub fn renormalize_value(
+    v: f32,
+    triple: AxisTriple,
+    distances: AxisDistances,
+    extrapolate: bool,
+) -> f32 {
+    let (lower, default, upper) = triple;
+    assert!(lower <= default && default <= upper);
+    let v = if extrapolate {
+        v
+    } else {
+        v.clamp(lower, upper)
+    };
+    if (v - default).abs() < f32::EPSILON || (upper - lower).abs() < f32::EPSILON {
+        return 0.0;
+    }
+    if default < 0.0 {
+        let (distance_negative, distance_positive) = distances;
+        return -renormalize_value(
+            -v,
+            reverse_negate(triple),
+            (distance_positive, distance_negative),
+            extrapolate,
+        );
+    }
+    // The default is now on the positive side of the old default
+    if v > default {
+        let extent = if upper == default {
+            default - lower
+        } else {
+            upper - default
+        };
+        return (v - default) / extent;
+    }
+    if lower == default {
+        return (v - default) / (upper - default);
+    }
+    if lower >= 0.0 {
+        return (v - default) / (default - lower);
+    }
+    let (distance_negative, distance_positive) = distances;
+    let total_distance = distance_negative * -lower + distance_positive * default;
+    let v_distance = if v >= 0.0 {
+        (default - v) * distance_positive
+    } else {
+        -v * distance_negative + distance_positive * default
+    };
+    -v_distance / total_distance
+}
+
+fn solve(tent: Tent, axis_limit: AxisTriple) -> Vec<(f32, Option<Tent>)> {
+    let (axis_min, axis_def, axis_max) = axis_limit;
+    let (lower, peak, upper) = tent;
+
+    // Mirror the problem such that axis_def <= peak
+    if axis_def > peak {
+        return solve(reverse_negate(tent), reverse_negate(axis_limit))
+            .into_iter()
+            .map(|(scalar, t)| (scalar, t.map(reverse_negate)))
+            .collect();
+    }
+
+    // Case 1: the whole region falls outside axis_max
+    if axis_max <= lower && axis_max < peak {
+        return vec![];
+    }
+
+    // Case 2: only the peak and the outermost bound fall outside axis_max
+    if axis_max < peak {
+        let mult = scalar_at(axis_max, tent);
+        return solve((lower, axis_max, axis_max), axis_limit)
+            .into_iter()
+            .map(|(scalar, t)| (scalar * mult, t))
+            .collect();
+    }
+
+    // lower <= axis_def <= peak <= axis_max
+    let gain = scalar_at(axis_def, tent);
+    let mut out = vec![(gain, None)];
+
+    // First, the positive side. out_gain is the scalar of axis_max at the tent.
+    let out_gain = scalar_at(axis_max, tent);
+
+    if gain >= out_gain {
+        // Case 3a: the tent's down-slope crosses the axis into negative, so
+        // we have to split it into multiple regions.
+        let crossing = peak + (1.0 - gain) * (upper - peak);
+        out.push((1.0 - gain, Some((lower.max(axis_def), peak, crossing))));
+
+        if upper >= axis_max {
+            // Case 3a1: one region is enough.
+            out.push((out_gain - gain, Some((crossing, axis_max, axis_max))));
+        } else {
+            // Case 3a2: two regions are needed to keep it down to eternity.
+            // A region's peak cannot fall on the axis default, so nudge it.
+            let upper = if upper == axis_def {
+                upper + EPSILON
+            } else {
+                upper
+            };
+            out.push((-gain, Some((crossing, upper, axis_max))));
+            out.push((-gain, Some((upper, axis_max, axis_max))));
+        }
+    } else {
+        // Case 4: the shape of a triangle with part of one side cut off
+        // cannot be represented as a triangle itself, so chop it in two.
+        out.push((1.0 - gain, Some((axis_def.max(lower), peak, axis_max))));
+        // Don't add a dirac delta!
+        if peak < axis_max {
+            out.push((out_gain - gain, Some((peak, axis_max, axis_max))));
+        }
+    }
+
+    // Now, the negative side.
+    if lower <= axis_min {
+        // Case 1neg: lower extends beyond axis_min, so we chop.
+        let scalar = scalar_at(axis_min, tent);
+        out.push((scalar - gain, Some((axis_min, axis_min, axis_def))));
+    } else {
+        // Case 2neg: lower is between axis_min and axis_def; we add two
+        // regions to keep it down all the way to eternity.
+        let lower = if lower == axis_def {
+            lower - EPSILON
+        } else {
+            lower
+        };
+        out.push((-gain, Some((axis_min, lower, axis_def))));
+        out.push((-gain, Some((axis_min, axis_min, lower))));
+    }
+
+    out
+}
+
+/// Expresses a region of a tuple variation in terms of new axis limits.
+///
+/// Given a `(lower, peak, upper)` tent and new `(minimum, default, maximum)`
+/// limits for the axis (all in normalized coordinates) and the axis's
+/// userspace distances, returns a list of `(scalar, tent)` pairs. Each pair
+/// describes a new region whose deltas are the original deltas multiplied
+/// by the scalar. A tent of `None` means that
+/// the region no longer depends on this axis; its deltas are always applied.
+pub fn rebase_tent(
+    tent: Tent,
+    axis_limit: AxisTriple,
+    distances: AxisDistances,
+) -> Vec<(f32, Option<Tent>)> {
+    let (axis_min, axis_def, axis_max) = axis_limit;
+    assert!(-1.0 <= axis_min && axis_min <= axis_def && axis_def <= axis_max && axis_max <= 1.0);
+    let (lower, peak, upper) = tent;
+    assert!(-2.0 <= lower && lower <= peak && peak <= upper && upper <= 2.0);
+    assert!(peak != 0.0);
+
+    let n = |v| renormalize_value(v, axis_limit, distances, true);
+    solve(tent, axis_limit)
+        .into_iter()
+        .filter(|(scalar, _)| *scalar != 0.0)
+        .map(|(scalar, t)| (scalar, t.map(|(l, p, u)| (n(l), n(p), n(u)))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn assert_solutions_eq(got: Vec<(f32, Option<Tent>)>, expected: Vec<(f32, Option<Tent>)>) {
+        assert_eq!(got.len(), expected.len(), "{:?} != {:?}", got, expected);
+        for ((s1, t1), (s2, t2)) in got.iter().zip(expected.iter()) {
+            assert_approx_eq!(s1, s2);
+            match (t1, t2) {
+                (Some(t1), Some(t2)) => {
+                    assert_approx_eq!(t1.0, t2.0);
+                    assert_approx_eq!(t1.1, t2.1);
+                    assert_approx_eq!(t1.2, t2.2);
+                }
+                (None, None) => {}
+                _ => panic!("{:?} != {:?}", t1, t2),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rebase_restrict_max() {
+        assert_solutions_eq(
+            rebase_tent((0.0, 1.0, 1.0), (-1.0, 0.0, 0.5), (1.0, 1.0)),
+            vec![(0.5, Some((0.0, 1.0, 1.0)))],
+        );
+        assert_solutions_eq(
+            rebase_tent((0.5, 1.0, 1.0), (-1.0, 0.0, 0.5), (1.0, 1.0)),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_rebase_new_default() {
+        assert_solutions_eq(
+            rebase_tent((0.0, 1.0, 1.0), (0.0, 0.5, 1.0), (1.0, 1.0)),
+            vec![
+                (0.5, None),
+                (0.5, Some((0.0, 1.0, 1.0))),
+                (-0.5, Some((-1.0, -1.0, 0.0))),
+            ],
+        );
+        assert_solutions_eq(
+            rebase_tent((-1.0, -1.0, 0.0), (-1.0, -0.5, 0.0), (1.0, 1.0)),
+            vec![
+                (0.5, None),
+                (0.5, Some((-1.0, -1.0, 0.0))),
+                (-0.5, Some((0.0, 1.0, 1.0))),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_renormalize_value() {
+        assert_approx_eq!(
+            renormalize_value(0.5, (0.0, 0.5, 1.0), (1.0, 1.0), false),
+            0.0
+        );
+        assert_approx_eq!(
+            renormalize_value(0.75, (0.0, 0.5, 1.0), (1.0, 1.0), false),
+            0.5
+        );
+        assert_approx_eq!(
+            renormalize_value(0.25, (0.0, 0.5, 1.0), (1.0, 1.0), false),
+            -0.5
+        );
+        assert_approx_eq!(
+            renormalize_value(-1.0, (0.0, 0.5, 1.0), (1.0, 1.0), false),
+            -1.0
+        );
+        assert_approx_eq!(
+            renormalize_value(-1.0, (0.0, 0.5, 1.0), (1.0, 1.0), true),
+            -3.0
+        );
+        // An axis from 100 to 900 with its default moved from 400 to 650:
+        // 250 was at -0.5, and is now 400/550 of the way to the minimum
+        let triple = (-1.0, 0.5, 1.0);
+        assert_approx_eq!(
+            renormalize_value(-0.5, triple, (300.0, 500.0), false),
+            -8.0 / 11.0
+        );
+        assert_approx_eq!(
+            renormalize_value(0.0, triple, (300.0, 500.0), false),
+            -5.0 / 11.0
+        );
+        assert_approx_eq!(renormalize_value(0.75, triple, (300.0, 500.0), false), 0.5);
+        assert_approx_eq!(
+            renormalize_value(-0.25, (-1.0, -0.5, 0.5), (500.0, 300.0), false),
+            5.0 / 16.0
+        );
+    }
+}
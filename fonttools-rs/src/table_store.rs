@@ -73,10 +73,26 @@ pub struct Table {
 pub enum LoadedTable {
     /// Contains an axis variations table.
     avar(Rc<tables::avar::avar>),
+    /// Contains a baseline table.
+    BASE(Rc<tables::BASE::BASE>),
+    /// Contains a color bitmap data table.
+    CBDT(Rc<tables::CBDT::CBDT>),
+    /// Contains a color bitmap location table.
+    CBLC(Rc<tables::CBLC::CBLC>),
     /// Contains a character to glyph index mapping table.
     cmap(Rc<tables::cmap::cmap>),
+    /// Contains a color table.
+    COLR(Rc<tables::COLR::COLR>),
+    /// Contains a color palette table.
+    CPAL(Rc<tables::CPAL::CPAL>),
     /// Contains a control value table.
     cvt(Rc<tables::cvt::cvt>),
+    /// Contains a digital signature table.
+    DSIG(Rc<tables::DSIG::DSIG>),
+    /// Contains an embedded bitmap data table.
+    EBDT(Rc<tables::EBDT::EBDT>),
+    /// Contains an embedded bitmap location table.
+    EBLC(Rc<tables::EBLC::EBLC>),
     /// Contains a font program table.
     fpgm(Rc<tables::fpgm::fpgm>),
     /// Contains a font variations table.
@@ -97,24 +113,52 @@ pub enum LoadedTable {
     head(Rc<tables::head::head>),
     /// Contains a horizontal header table.
     hhea(Rc<tables::hhea::hhea>),
+    /// Contains a horizontal device metrics table.
+    hdmx(Rc<tables::hdmx::hdmx>),
     /// Contains a horizontal metrics table.
     hmtx(Rc<tables::hmtx::hmtx>),
+    /// Contains a horizontal metrics variations table.
+    HVAR(Rc<tables::HVAR::HVAR>),
+    /// Contains a justification table.
+    JSTF(Rc<tables::JSTF::JSTF>),
+    /// Contains a kerning table.
+    kern(Rc<tables::kern::kern>),
+    /// Contains a linear threshold table.
+    LTSH(Rc<tables::LTSH::LTSH>),
     /// Contains an index-to-location table.
     loca(Rc<tables::loca::loca>),
     /// Contains a math typesetting table.
     MATH(Rc<tables::MATH::MATH>),
+    /// Contains a metadata table.
+    meta(Rc<tables::meta::meta>),
+    /// Contains a metrics variations table.
+    MVAR(Rc<tables::MVAR::MVAR>),
     /// Contains a maximum profile table.
     maxp(Rc<tables::maxp::maxp>),
     /// Contains a naming table.
     name(Rc<tables::name::name>),
     /// Contains an OS/2 and Windows metrics table.
     os2(Rc<tables::os2::os2>),
+    /// Contains a PCL 5 table.
+    PCLT(Rc<tables::PCLT::PCLT>),
     /// Contains a postscript table.
     post(Rc<tables::post::post>),
     /// Contains a control value program table.
     prep(Rc<tables::prep::prep>),
+    /// Contains a standard bitmap graphics table.
+    sbix(Rc<tables::sbix::sbix>),
     /// Contains a style attributes table.
     STAT(Rc<tables::STAT::STAT>),
+    /// Contains a scalable vector graphics table.
+    SVG(Rc<tables::SVG::SVG>),
+    /// Contains a vertical device metrics table.
+    VDMX(Rc<tables::VDMX::VDMX>),
+    /// Contains a vertical header table.
+    vhea(Rc<tables::vhea::vhea>),
+    /// Contains a vertical metrics table.
+    vmtx(Rc<tables::vmtx::vmtx>),
+    /// Contains a vertical origin table.
+    VORG(Rc<tables::VORG::VORG>),
     /// Any unknown table.
     Unknown(Rc<[u8]>),
 }
@@ -307,6 +351,35 @@ impl TableSet {
             .insert(tag, RefCell::new(LazyItem::Unloaded(data.into())));
     }
 
+    /// Insert all the tables from another set into this one, replacing any
+    /// existing tables with the same tags.
+    ///
+    /// Tables which are equal to the existing table are skipped, so that
+    /// unchanged tables keep their original binary representation.
+    pub fn update(&mut self, other: TableSet) {
+        for (tag, item) in other.tables {
+            let item = item.into_inner();
+            if let LazyItem::Loaded(_) = item {
+                if let Err(e) = self.load_if_needed(tag) {
+                    log::warn!("Couldn't deserialize {}: '{}'", tag, e);
+                }
+            }
+            let unchanged =
+                self.tables
+                    .get(&tag)
+                    .is_some_and(|existing| match (&*existing.borrow(), &item) {
+                        (
+                            LazyItem::Loaded(Table { raw: Some(raw), .. }),
+                            LazyItem::Unloaded(data),
+                        ) => raw == data,
+                        (existing, item) => existing == item,
+                    });
+            if !unchanged {
+                self.tables.insert(tag, RefCell::new(item));
+            }
+        }
+    }
+
     fn load_if_needed(&self, tag: Tag) -> Result<(), DeserializationError> {
         let item = match self.tables.get(&tag) {
             Some(item) => item,
@@ -326,6 +399,7 @@ impl TableSet {
     fn deserialize_table(&self, tag: Tag, data: Rc<[u8]>) -> Result<Table, DeserializationError> {
         let typed_data: LoadedTable = match tag.as_bytes() {
             b"avar" => otspec::de::from_bytes::<tables::avar::avar>(&data)?.into(),
+            b"BASE" => otspec::de::from_bytes::<tables::BASE::BASE>(&data)?.into(),
             b"cmap" => otspec::de::from_bytes::<tables::cmap::cmap>(&data)?.into(),
             b"cvt " => otspec::de::from_bytes::<tables::cvt::cvt>(&data)?.into(),
             b"fpgm" => otspec::de::from_bytes::<tables::fpgm::fpgm>(&data)?.into(),
@@ -346,15 +420,37 @@ impl TableSet {
                     .ok_or_else(|| DeserializationError("deserialize head before loca".into()))?;
                 tables::GSUB::from_bytes(&mut ReaderContext::new(data.to_vec()), num_glyphs)?.into()
             }
+            b"JSTF" => {
+                let num_glyphs = self
+                    .maxp()?
+                    .map(|maxp| maxp.num_glyphs())
+                    .ok_or_else(|| DeserializationError("deserialize maxp before JSTF".into()))?;
+                tables::JSTF::from_bytes(&mut ReaderContext::new(data.to_vec()), num_glyphs)?.into()
+            }
+            b"COLR" => otspec::de::from_bytes::<tables::COLR::COLR>(&data)?.into(),
+            b"CPAL" => otspec::de::from_bytes::<tables::CPAL::CPAL>(&data)?.into(),
+            b"CBLC" => otspec::de::from_bytes::<tables::CBLC::CBLC>(&data)?.into(),
+            b"DSIG" => otspec::de::from_bytes::<tables::DSIG::DSIG>(&data)?.into(),
+            b"EBLC" => otspec::de::from_bytes::<tables::EBLC::EBLC>(&data)?.into(),
             b"head" => otspec::de::from_bytes::<tables::head::head>(&data)?.into(),
             b"hhea" => otspec::de::from_bytes::<tables::hhea::hhea>(&data)?.into(),
+            b"HVAR" => otspec::de::from_bytes::<tables::HVAR::HVAR>(&data)?.into(),
+            b"kern" => otspec::de::from_bytes::<tables::kern::kern>(&data)?.into(),
+            b"LTSH" => otspec::de::from_bytes::<tables::LTSH::LTSH>(&data)?.into(),
             b"MATH" => otspec::de::from_bytes::<tables::MATH::MATH>(&data)?.into(),
+            b"meta" => otspec::de::from_bytes::<tables::meta::meta>(&data)?.into(),
+            b"MVAR" => otspec::de::from_bytes::<tables::MVAR::MVAR>(&data)?.into(),
             b"maxp" => otspec::de::from_bytes::<tables::maxp::maxp>(&data)?.into(),
             b"name" => otspec::de::from_bytes::<tables::name::name>(&data)?.into(),
             b"OS/2" => otspec::de::from_bytes::<tables::os2::os2>(&data)?.into(),
+            b"PCLT" => otspec::de::from_bytes::<tables::PCLT::PCLT>(&data)?.into(),
             b"post" => otspec::de::from_bytes::<tables::post::post>(&data)?.into(),
             b"prep" => otspec::de::from_bytes::<tables::prep::prep>(&data)?.into(),
             b"STAT" => otspec::de::from_bytes::<tables::STAT::STAT>(&data)?.into(),
+            b"SVG " => otspec::de::from_bytes::<tables::SVG::SVG>(&data)?.into(),
+            b"VDMX" => otspec::de::from_bytes::<tables::VDMX::VDMX>(&data)?.into(),
+            b"vhea" => otspec::de::from_bytes::<tables::vhea::vhea>(&data)?.into(),
+            b"VORG" => otspec::de::from_bytes::<tables::VORG::VORG>(&data)?.into(),
             b"hmtx" => {
                 let number_of_hmetrics = self
                     //TODO: dear reviewer: this loads the table if missing. do
@@ -370,6 +466,18 @@ impl TableSet {
                 )?
                 .into()
             }
+            b"vmtx" => {
+                let num_of_long_ver_metrics = self
+                    .vhea()?
+                    .map(|vhea| vhea.numOfLongVerMetrics)
+                    .ok_or_else(|| DeserializationError("deserialize vhea before vmtx".into()))?;
+
+                tables::vmtx::from_bytes(
+                    &mut ReaderContext::new(data.to_vec()),
+                    num_of_long_ver_metrics,
+                )?
+                .into()
+            }
             b"loca" => {
                 let is_32bit = self
                     .head()?
@@ -383,6 +491,32 @@ impl TableSet {
                     .ok_or_else(|| DeserializationError("deserialize loca before glyf".into()))?;
                 tables::glyf::from_bytes(&data, &loca.indices)?.into()
             }
+            b"CBDT" => {
+                let cblc = self
+                    .CBLC()?
+                    .ok_or_else(|| DeserializationError("deserialize CBLC before CBDT".into()))?;
+                tables::CBDT::from_bytes(&data, &cblc)?.into()
+            }
+            b"EBDT" => {
+                let eblc = self
+                    .EBLC()?
+                    .ok_or_else(|| DeserializationError("deserialize EBLC before EBDT".into()))?;
+                tables::EBDT::from_bytes(&data, &eblc)?.into()
+            }
+            b"sbix" => {
+                let num_glyphs = self
+                    .maxp()?
+                    .map(|maxp| maxp.num_glyphs())
+                    .ok_or_else(|| DeserializationError("deserialize maxp before sbix".into()))?;
+                tables::sbix::from_bytes(&mut ReaderContext::new(data.to_vec()), num_glyphs)?.into()
+            }
+            b"hdmx" => {
+                let num_glyphs = self
+                    .maxp()?
+                    .map(|maxp| maxp.num_glyphs())
+                    .ok_or_else(|| DeserializationError("deserialize maxp before hdmx".into()))?;
+                tables::hdmx::from_bytes(&mut ReaderContext::new(data.to_vec()), num_glyphs)?.into()
+            }
             b"gvar" => {
                 let glyf = self
                     .glyf()?
@@ -479,6 +613,12 @@ impl TableSet {
                 self.insert(hhea);
             }
         }
+        if let Some(vmetric_count) = self.vmtx().unwrap().map(|t| t.number_of_vmetrics()) {
+            if let Some(mut vhea) = self.vhea().unwrap() {
+                vhea.numOfLongVerMetrics = vmetric_count;
+                self.insert(vhea);
+            }
+        }
     }
 
     pub(crate) fn compile_gsub_gpos(&mut self) {
@@ -501,6 +641,62 @@ impl TableSet {
                 self.insert_raw(tables::GSUB::TAG, gsub_data)
             }
         }
+        if !self.is_serialized(tables::JSTF::TAG).unwrap_or(true) {
+            if let Some(jstf) = self.JSTF().unwrap() {
+                let mut jstf_data = vec![];
+                if tables::JSTF::to_bytes(&jstf, &mut jstf_data, num_glyphs).is_err() {
+                    log::error!("JSTF table overflow");
+                }
+                self.insert_raw(tables::JSTF::TAG, jstf_data)
+            }
+        }
+    }
+
+    pub(crate) fn compile_bitmaps(&mut self) {
+        if !self.is_serialized(tables::EBDT::TAG).unwrap_or(true) {
+            if let (Some(ebdt), Some(mut eblc)) = (self.EBDT().unwrap(), self.EBLC().unwrap()) {
+                if let Err(e) = eblc.update_locations(&ebdt) {
+                    log::error!("Couldn't compile EBLC table: {}", e.0);
+                }
+                self.insert(eblc);
+            }
+        }
+        if !self.is_serialized(tables::CBDT::TAG).unwrap_or(true) {
+            if let (Some(cbdt), Some(mut cblc)) = (self.CBDT().unwrap(), self.CBLC().unwrap()) {
+                if let Err(e) = cblc.update_locations(&cbdt) {
+                    log::error!("Couldn't compile CBLC table: {}", e.0);
+                }
+                self.insert(cblc);
+            }
+        }
+    }
+
+    pub(crate) fn compile_hmtx_gvar(&mut self) {
+        if !self.is_serialized(tables::hmtx::TAG).unwrap_or(true) {
+            if let Some(hmtx) = self.hmtx().unwrap() {
+                let (hmtx_data, hmetric_count) = hmtx.to_bytes();
+                self.insert_raw(tables::hmtx::TAG, hmtx_data);
+                if let Some(mut hhea) = self.hhea().unwrap() {
+                    hhea.numberOfHMetrics = hmetric_count;
+                    self.insert(hhea);
+                }
+            }
+        }
+        if !self.is_serialized(tables::vmtx::TAG).unwrap_or(true) {
+            if let Some(vmtx) = self.vmtx().unwrap() {
+                let (vmtx_data, vmetric_count) = vmtx.to_bytes();
+                self.insert_raw(tables::vmtx::TAG, vmtx_data);
+                if let Some(mut vhea) = self.vhea().unwrap() {
+                    vhea.numOfLongVerMetrics = vmetric_count;
+                    self.insert(vhea);
+                }
+            }
+        }
+        if !self.is_serialized(tables::gvar::TAG).unwrap_or(true) {
+            if let Some(gvar) = self.gvar().unwrap() {
+                self.insert_raw(tables::gvar::TAG, gvar.to_bytes(None));
+            }
+        }
     }
 
     pub(crate) fn write_table(
@@ -612,6 +808,7 @@ macro_rules! table_boilerplate {
     };
 }
 
+table_boilerplate!(tables::BASE::BASE, BASE);
 table_boilerplate!(tables::GDEF::GDEF, GDEF);
 table_boilerplate!(tables::GPOS::GPOS, GPOS);
 table_boilerplate!(tables::GSUB::GSUB, GSUB);
@@ -627,6 +824,7 @@ table_boilerplate!(tables::gvar::gvar, gvar);
 table_boilerplate!(tables::head::head, head);
 table_boilerplate!(tables::hhea::hhea, hhea);
 table_boilerplate!(tables::hmtx::hmtx, hmtx);
+table_boilerplate!(tables::kern::kern, kern);
 table_boilerplate!(tables::loca::loca, loca);
 table_boilerplate!(tables::maxp::maxp, maxp);
 table_boilerplate!(tables::name::name, name);
@@ -634,14 +832,42 @@ table_boilerplate!(tables::os2::os2, os2);
 table_boilerplate!(tables::post::post, post);
 table_boilerplate!(tables::prep::prep, prep);
 table_boilerplate!(tables::MATH::MATH, MATH);
+table_boilerplate!(tables::HVAR::HVAR, HVAR);
+table_boilerplate!(tables::JSTF::JSTF, JSTF);
+table_boilerplate!(tables::MVAR::MVAR, MVAR);
+table_boilerplate!(tables::vhea::vhea, vhea);
+table_boilerplate!(tables::vmtx::vmtx, vmtx);
+table_boilerplate!(tables::VORG::VORG, VORG);
+table_boilerplate!(tables::COLR::COLR, COLR);
+table_boilerplate!(tables::CPAL::CPAL, CPAL);
+table_boilerplate!(tables::CBDT::CBDT, CBDT);
+table_boilerplate!(tables::CBLC::CBLC, CBLC);
+table_boilerplate!(tables::EBDT::EBDT, EBDT);
+table_boilerplate!(tables::EBLC::EBLC, EBLC);
+table_boilerplate!(tables::sbix::sbix, sbix);
+table_boilerplate!(tables::SVG::SVG, SVG);
+table_boilerplate!(tables::DSIG::DSIG, DSIG);
+table_boilerplate!(tables::hdmx::hdmx, hdmx);
+table_boilerplate!(tables::LTSH::LTSH, LTSH);
+table_boilerplate!(tables::meta::meta, meta);
+table_boilerplate!(tables::PCLT::PCLT, PCLT);
+table_boilerplate!(tables::VDMX::VDMX, VDMX);
 
 impl Serialize for LoadedTable {
     fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), otspec::SerializationError> {
         match self {
             LoadedTable::Unknown(expr) => expr.to_bytes(data),
             LoadedTable::avar(expr) => expr.to_bytes(data),
+            LoadedTable::BASE(expr) => expr.to_bytes(data),
+            LoadedTable::CBDT(expr) => expr.to_bytes(data),
+            LoadedTable::CBLC(expr) => expr.to_bytes(data),
             LoadedTable::cmap(expr) => expr.to_bytes(data),
+            LoadedTable::COLR(expr) => expr.to_bytes(data),
+            LoadedTable::CPAL(expr) => expr.to_bytes(data),
             LoadedTable::cvt(expr) => expr.to_bytes(data),
+            LoadedTable::DSIG(expr) => expr.to_bytes(data),
+            LoadedTable::EBDT(expr) => expr.to_bytes(data),
+            LoadedTable::EBLC(expr) => expr.to_bytes(data),
             LoadedTable::fpgm(expr) => expr.to_bytes(data),
             LoadedTable::fvar(expr) => expr.to_bytes(data),
             LoadedTable::gasp(expr) => expr.to_bytes(data),
@@ -651,16 +877,302 @@ impl Serialize for LoadedTable {
             LoadedTable::gvar(_) => unimplemented!(),
             LoadedTable::head(expr) => expr.to_bytes(data),
             LoadedTable::hhea(expr) => expr.to_bytes(data),
+            LoadedTable::hdmx(expr) => expr.to_bytes(data),
             LoadedTable::hmtx(_) => unimplemented!(),
+            LoadedTable::HVAR(expr) => expr.to_bytes(data),
+            LoadedTable::JSTF(_) => unimplemented!(),
             LoadedTable::glyf(_) => unimplemented!(),
+            LoadedTable::kern(expr) => expr.to_bytes(data),
+            LoadedTable::LTSH(expr) => expr.to_bytes(data),
             LoadedTable::loca(_) => unimplemented!(),
             LoadedTable::maxp(expr) => expr.to_bytes(data),
             LoadedTable::MATH(_) => unimplemented!(),
+            LoadedTable::meta(expr) => expr.to_bytes(data),
+            LoadedTable::MVAR(expr) => expr.to_bytes(data),
             LoadedTable::name(expr) => expr.to_bytes(data),
             LoadedTable::os2(expr) => expr.to_bytes(data),
+            LoadedTable::PCLT(expr) => expr.to_bytes(data),
             LoadedTable::post(expr) => expr.to_bytes(data),
             LoadedTable::prep(expr) => expr.to_bytes(data),
+            LoadedTable::sbix(expr) => expr.to_bytes(data),
             LoadedTable::STAT(expr) => expr.to_bytes(data),
+            LoadedTable::SVG(expr) => expr.to_bytes(data),
+            LoadedTable::VDMX(expr) => expr.to_bytes(data),
+            LoadedTable::vhea(expr) => expr.to_bytes(data),
+            LoadedTable::vmtx(_) => unimplemented!(),
+            LoadedTable::VORG(expr) => expr.to_bytes(data),
+        }
+    }
+}
+
+/// Tables are represented in serde formats as a map from tag to table.
+///
+/// Known tables are stored in their high-level form and unknown tables are
+/// stored as arrays of bytes. Tables which fail to deserialize are skipped.
+#[cfg(feature = "serde")]
+impl serde::Serialize for TableSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeMap};
+        self.fully_deserialize()
+            .map_err(|e| S::Error::custom(e.0))?;
+        let mut map = serializer.serialize_map(None)?;
+        for (tag, item) in &self.tables {
+            // Anything still unloaded failed to deserialize, and has been warned about.
+            if let LazyItem::Loaded(table) = &*item.borrow() {
+                map.serialize_entry(tag, &table.loaded)?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LoadedTable {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            LoadedTable::Unknown(expr) => expr.serialize(serializer),
+            LoadedTable::avar(expr) => expr.serialize(serializer),
+            LoadedTable::BASE(expr) => expr.serialize(serializer),
+            LoadedTable::CBDT(expr) => expr.serialize(serializer),
+            LoadedTable::CBLC(expr) => expr.serialize(serializer),
+            LoadedTable::cmap(expr) => expr.serialize(serializer),
+            LoadedTable::COLR(expr) => expr.serialize(serializer),
+            LoadedTable::CPAL(expr) => expr.serialize(serializer),
+            LoadedTable::cvt(expr) => expr.serialize(serializer),
+            LoadedTable::DSIG(expr) => expr.serialize(serializer),
+            LoadedTable::EBDT(expr) => expr.serialize(serializer),
+            LoadedTable::EBLC(expr) => expr.serialize(serializer),
+            LoadedTable::fpgm(expr) => expr.serialize(serializer),
+            LoadedTable::fvar(expr) => expr.serialize(serializer),
+            LoadedTable::gasp(expr) => expr.serialize(serializer),
+            LoadedTable::GDEF(expr) => expr.serialize(serializer),
+            LoadedTable::GPOS(expr) => expr.serialize(serializer),
+            LoadedTable::GSUB(expr) => expr.serialize(serializer),
+            LoadedTable::gvar(expr) => expr.serialize(serializer),
+            LoadedTable::head(expr) => expr.serialize(serializer),
+            LoadedTable::hhea(expr) => expr.serialize(serializer),
+            LoadedTable::hdmx(expr) => expr.serialize(serializer),
+            LoadedTable::hmtx(expr) => expr.serialize(serializer),
+            LoadedTable::HVAR(expr) => expr.serialize(serializer),
+            LoadedTable::JSTF(expr) => expr.serialize(serializer),
+            LoadedTable::glyf(expr) => expr.serialize(serializer),
+            LoadedTable::kern(expr) => expr.serialize(serializer),
+            LoadedTable::LTSH(expr) => expr.serialize(serializer),
+            LoadedTable::loca(expr) => expr.serialize(serializer),
+            LoadedTable::maxp(expr) => expr.serialize(serializer),
+            LoadedTable::MATH(expr) => expr.serialize(serializer),
+            LoadedTable::meta(expr) => expr.serialize(serializer),
+            LoadedTable::MVAR(expr) => expr.serialize(serializer),
+            LoadedTable::name(expr) => expr.serialize(serializer),
+            LoadedTable::os2(expr) => expr.serialize(serializer),
+            LoadedTable::PCLT(expr) => expr.serialize(serializer),
+            LoadedTable::post(expr) => expr.serialize(serializer),
+            LoadedTable::prep(expr) => expr.serialize(serializer),
+            LoadedTable::sbix(expr) => expr.serialize(serializer),
+            LoadedTable::STAT(expr) => expr.serialize(serializer),
+            LoadedTable::SVG(expr) => expr.serialize(serializer),
+            LoadedTable::VDMX(expr) => expr.serialize(serializer),
+            LoadedTable::vhea(expr) => expr.serialize(serializer),
+            LoadedTable::vmtx(expr) => expr.serialize(serializer),
+            LoadedTable::VORG(expr) => expr.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct TableSetVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for TableSetVisitor {
+    type Value = TableSet;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of table tags to tables")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<TableSet, A::Error> {
+        let mut tables = TableSet::default();
+        while let Some(tag) = map.next_key::<Tag>()? {
+            match tag.as_bytes() {
+                b"avar" => tables.insert(map.next_value::<tables::avar::avar>()?),
+                b"BASE" => tables.insert(map.next_value::<tables::BASE::BASE>()?),
+                b"CBDT" => tables.insert(map.next_value::<tables::CBDT::CBDT>()?),
+                b"CBLC" => tables.insert(map.next_value::<tables::CBLC::CBLC>()?),
+                b"cmap" => tables.insert(map.next_value::<tables::cmap::cmap>()?),
+                b"cvt " => tables.insert(map.next_value::<tables::cvt::cvt>()?),
+                b"DSIG" => tables.insert(map.next_value::<tables::DSIG::DSIG>()?),
+                b"EBDT" => tables.insert(map.next_value::<tables::EBDT::EBDT>()?),
+                b"EBLC" => tables.insert(map.next_value::<tables::EBLC::EBLC>()?),
+                b"fpgm" => tables.insert(map.next_value::<tables::fpgm::fpgm>()?),
+                b"fvar" => tables.insert(map.next_value::<tables::fvar::fvar>()?),
+                b"gasp" => tables.insert(map.next_value::<tables::gasp::gasp>()?),
+                b"COLR" => tables.insert(map.next_value::<tables::COLR::COLR>()?),
+                b"CPAL" => tables.insert(map.next_value::<tables::CPAL::CPAL>()?),
+                b"GDEF" => tables.insert(map.next_value::<tables::GDEF::GDEF>()?),
+                b"GPOS" => tables.insert(map.next_value::<tables::GPOS::GPOS>()?),
+                b"GSUB" => tables.insert(map.next_value::<tables::GSUB::GSUB>()?),
+                b"glyf" => tables.insert(map.next_value::<tables::glyf::glyf>()?),
+                b"gvar" => tables.insert(map.next_value::<tables::gvar::gvar>()?),
+                b"head" => tables.insert(map.next_value::<tables::head::head>()?),
+                b"hhea" => tables.insert(map.next_value::<tables::hhea::hhea>()?),
+                b"hdmx" => tables.insert(map.next_value::<tables::hdmx::hdmx>()?),
+                b"hmtx" => tables.insert(map.next_value::<tables::hmtx::hmtx>()?),
+                b"HVAR" => tables.insert(map.next_value::<tables::HVAR::HVAR>()?),
+                b"JSTF" => tables.insert(map.next_value::<tables::JSTF::JSTF>()?),
+                b"kern" => tables.insert(map.next_value::<tables::kern::kern>()?),
+                b"LTSH" => tables.insert(map.next_value::<tables::LTSH::LTSH>()?),
+                b"loca" => tables.insert(map.next_value::<tables::loca::loca>()?),
+                b"MATH" => tables.insert(map.next_value::<tables::MATH::MATH>()?),
+                b"meta" => tables.insert(map.next_value::<tables::meta::meta>()?),
+                b"MVAR" => tables.insert(map.next_value::<tables::MVAR::MVAR>()?),
+                b"maxp" => tables.insert(map.next_value::<tables::maxp::maxp>()?),
+                b"name" => tables.insert(map.next_value::<tables::name::name>()?),
+                b"OS/2" => tables.insert(map.next_value::<tables::os2::os2>()?),
+                b"PCLT" => tables.insert(map.next_value::<tables::PCLT::PCLT>()?),
+                b"post" => tables.insert(map.next_value::<tables::post::post>()?),
+                b"prep" => tables.insert(map.next_value::<tables::prep::prep>()?),
+                b"sbix" => tables.insert(map.next_value::<tables::sbix::sbix>()?),
+                b"STAT" => tables.insert(map.next_value::<tables::STAT::STAT>()?),
+                b"SVG " => tables.insert(map.next_value::<tables::SVG::SVG>()?),
+                b"VDMX" => tables.insert(map.next_value::<tables::VDMX::VDMX>()?),
+                b"vhea" => tables.insert(map.next_value::<tables::vhea::vhea>()?),
+                b"vmtx" => tables.insert(map.next_value::<tables::vmtx::vmtx>()?),
+                b"VORG" => tables.insert(map.next_value::<tables::VORG::VORG>()?),
+                _ => tables.insert_raw(tag, map.next_value::<Vec<u8>>()?),
+            }
+        }
+        Ok(tables)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TableSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(TableSetVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::hhea::hhea;
+
+    fn test_hhea() -> hhea {
+        hhea {
+            majorVersion: 1,
+            minorVersion: 0,
+            ascender: 705,
+            descender: -180,
+            lineGap: 0,
+            advanceWidthMax: 1311,
+            minLeftSideBearing: -382,
+            minRightSideBearing: -382,
+            xMaxExtent: 1245,
+            caretSlopeRise: 1,
+            caretSlopeRun: 0,
+            caretOffset: 0,
+            reserved0: 0,
+            reserved1: 0,
+            reserved2: 0,
+            reserved3: 0,
+            metricDataFormat: 0,
+            numberOfHMetrics: 1117,
         }
     }
+
+    #[test]
+    fn test_update() {
+        let mut tables = TableSet::default();
+        tables.insert_raw(
+            tables::hhea::TAG,
+            otspec::ser::to_bytes(&test_hhea()).unwrap(),
+        );
+
+        let mut same = TableSet::default();
+        same.insert(test_hhea());
+        tables.update(same);
+        assert_eq!(tables.is_serialized(tables::hhea::TAG), Some(true));
+
+        let mut changed = TableSet::default();
+        let mut new_hhea = test_hhea();
+        new_hhea.ascender = 800;
+        changed.insert(new_hhea);
+        changed.insert_raw(Tag::from_raw("TEST").unwrap(), vec![1, 2, 3]);
+        tables.update(changed);
+        assert_eq!(tables.is_serialized(tables::hhea::TAG), Some(false));
+        assert_eq!(tables.hhea().unwrap().unwrap().ascender, 800);
+        assert_eq!(tables.len(), 2);
+    }
+
+    #[test]
+    fn test_compile_bitmaps() {
+        use crate::tables::EBDT::BitmapGlyph;
+        use crate::tables::EBLC::{BitmapSize, SmallGlyphMetrics};
+
+        let mut tables = TableSet::default();
+        tables.insert(tables::CBLC::CBLC {
+            strikes: vec![BitmapSize {
+                color_ref: 0,
+                hori: Default::default(),
+                vert: Default::default(),
+                ppem_x: 109,
+                ppem_y: 109,
+                bit_depth: 32,
+                flags: 1,
+                index_subtables: vec![],
+            }],
+        });
+        let mut cbdt = tables::CBDT::CBDT {
+            strikes: vec![Default::default()],
+        };
+        for glyph_id in [3, 4, 7] {
+            let png = vec![0x89, 0x50, 0x4e, 0x47, glyph_id as u8];
+            cbdt.set_glyph(
+                0,
+                glyph_id,
+                BitmapGlyph::png(SmallGlyphMetrics::default(), png),
+            );
+        }
+        tables.insert(cbdt);
+        tables.compile_bitmaps();
+
+        let cblc = tables.CBLC().unwrap().unwrap();
+        let ranges: Vec<_> = cblc.strikes[0]
+            .index_subtables
+            .iter()
+            .map(|st| (st.first_glyph_index, st.last_glyph_index))
+            .collect();
+        assert_eq!(ranges, vec![(3, 4), (7, 7)]);
+
+        let mut cblc_data = vec![];
+        let mut cbdt_data = vec![];
+        tables
+            .write_table(tables::CBLC::TAG, &mut cblc_data)
+            .unwrap();
+        tables
+            .write_table(tables::CBDT::TAG, &mut cbdt_data)
+            .unwrap();
+        let mut reloaded = TableSet::default();
+        reloaded.insert_raw(tables::CBLC::TAG, cblc_data);
+        reloaded.insert_raw(tables::CBDT::TAG, cbdt_data);
+        let cbdt = reloaded.CBDT().unwrap().unwrap();
+        assert_eq!(cbdt.glyph(0, 7).unwrap().image().unwrap()[4], 7);
+        assert_eq!(*cbdt, *tables.CBDT().unwrap().unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut tables = TableSet::default();
+        tables.insert(test_hhea());
+        tables.insert_raw(Tag::from_raw("TEST").unwrap(), vec![1, 2, 3]);
+
+        let json = serde_json::to_value(&tables).unwrap();
+        assert_eq!(json["hhea"]["ascender"], 705);
+        assert_eq!(json["TEST"], serde_json::json!([1, 2, 3]));
+
+        let deserialized: TableSet = serde_json::from_value(json).unwrap();
+        deserialized.fully_deserialize().unwrap();
+        assert_eq!(deserialized, tables);
+    }
 }
@@ -1,3 +1,27 @@
+/// The `BASE` (Baseline) table
+#[allow(non_snake_case)]
+pub mod BASE;
+/// The `CBDT` (Color bitmap data) table
+#[allow(non_snake_case)]
+pub mod CBDT;
+/// The `CBLC` (Color bitmap location) table
+#[allow(non_snake_case)]
+pub mod CBLC;
+/// The `COLR` (Color) table
+#[allow(non_snake_case)]
+pub mod COLR;
+/// The `CPAL` (Color palette) table
+#[allow(non_snake_case)]
+pub mod CPAL;
+/// The `DSIG` (Digital signature) table
+#[allow(non_snake_case)]
+pub mod DSIG;
+/// The `EBDT` (Embedded bitmap data) table
+#[allow(non_snake_case)]
+pub mod EBDT;
+/// The `EBLC` (Embedded bitmap location) table
+#[allow(non_snake_case)]
+pub mod EBLC;
 /// The `GDEF` (Glyph definition) table
 #[allow(non_snake_case)]
 pub mod GDEF;
@@ -7,12 +31,36 @@ pub mod GPOS;
 /// The `GSUB` (Glyph substitution) table
 #[allow(non_snake_case)]
 pub mod GSUB;
+/// The `HVAR` (Horizontal metrics variations) table
+#[allow(non_snake_case)]
+pub mod HVAR;
+/// The `JSTF` (Justification) table
+#[allow(non_snake_case)]
+pub mod JSTF;
+/// The `LTSH` (Linear threshold) table
+#[allow(non_snake_case)]
+pub mod LTSH;
 /// The `MATH` (Mathematical typesetting) table
 #[allow(non_snake_case)]
 pub mod MATH;
+/// The `MVAR` (Metrics variations) table
+#[allow(non_snake_case)]
+pub mod MVAR;
+/// The `PCLT` (PCL 5) table
+#[allow(non_snake_case)]
+pub mod PCLT;
 /// The `STAT` (Style attributes) table
 #[allow(non_snake_case)]
 pub mod STAT;
+/// The `SVG ` (Scalable vector graphics) table
+#[allow(non_snake_case)]
+pub mod SVG;
+/// The `VDMX` (Vertical device metrics) table
+#[allow(non_snake_case)]
+pub mod VDMX;
+/// The `VORG` (Vertical origin) table
+#[allow(non_snake_case)]
+pub mod VORG;
 /// The `avar` (Axis variations) table
 pub mod avar;
 /// The `cmap` (Character To Glyph Index Mapping) table
@@ -29,16 +77,22 @@ pub mod gasp;
 pub mod glyf;
 /// The `gvar` (Glyph variations) table
 pub mod gvar;
+/// The `hdmx` (Horizontal device metrics) table
+pub mod hdmx;
 /// The `head` (Header) table
 pub mod head;
 /// The `hhea` (Horizontal header) table
 pub mod hhea;
 /// The `hmtx` (Horizontal metrics) table
 pub mod hmtx;
+/// The `kern` (Kerning) table
+pub mod kern;
 /// The 'loca' (Index to Location) table
 pub mod loca;
 /// The `maxp` (Maximum profile) table
 pub mod maxp;
+/// The `meta` (Metadata) table
+pub mod meta;
 /// The `name` (Naming) table
 pub mod name;
 /// The `OS/2` (OS/2 and Windows Metrics) table
@@ -47,6 +101,12 @@ pub mod os2;
 pub mod post;
 /// The `prep` (Control Value Program) table
 pub mod prep;
+/// The `sbix` (Standard bitmap graphics) table
+pub mod sbix;
+/// The `vhea` (Vertical header) table
+pub mod vhea;
+/// The `vmtx` (Vertical metrics) table
+pub mod vmtx;
 
 #[macro_export]
 /// A macro that allows a high-level table structure to delegate serialization and
@@ -0,0 +1,586 @@
+use crate::otvar::ItemVariationStore;
+use otspec::layout::device::Device;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+    Serializer,
+};
+use otspec_macros::tables;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The 'BASE' OpenType tag.
+pub const TAG: Tag = crate::tag!("BASE");
+
+tables!(
+    basecore10 {
+        uint16 majorVersion
+        uint16 minorVersion
+        Offset16(Axis) horizAxis
+        Offset16(Axis) vertAxis
+    }
+
+    basecore11 {
+        uint16 majorVersion
+        uint16 minorVersion
+        Offset16(Axis) horizAxis
+        Offset16(Axis) vertAxis
+        Offset32(ItemVariationStore) itemVarStore
+    }
+
+    Axis {
+        [offset_base]
+        Offset16(BaseTagList) baseTagList
+        Offset16(BaseScriptList) baseScriptList
+    }
+
+    BaseTagList {
+        Counted(Tag) baselineTags
+    }
+
+    BaseScriptList {
+        [offset_base]
+        [embed]
+        Counted(BaseScriptRecord) baseScriptRecords
+    }
+
+    BaseScriptRecord [embedded] {
+        Tag baseScriptTag
+        Offset16(BaseScriptInternal) baseScript
+    }
+
+    BaseScriptInternal {
+        [offset_base]
+        Offset16(BaseValues) baseValues
+        Offset16(MinMaxInternal) defaultMinMax
+        [embed]
+        Counted(BaseLangSysRecord) baseLangSysRecords
+    }
+
+    BaseLangSysRecord [embedded] {
+        Tag baseLangSysTag
+        Offset16(MinMaxInternal) minMax
+    }
+
+    BaseValues {
+        [offset_base]
+        uint16 defaultBaselineIndex
+        CountedOffset16(BaseCoord) baseCoords
+    }
+
+    MinMaxInternal {
+        [offset_base]
+        Offset16(BaseCoord) minCoord
+        Offset16(BaseCoord) maxCoord
+        [embed]
+        Counted(FeatMinMaxRecord) featMinMaxRecords
+    }
+
+    FeatMinMaxRecord [embedded] {
+        Tag featureTableTag
+        Offset16(BaseCoord) minCoord
+        Offset16(BaseCoord) maxCoord
+    }
+);
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A baseline or extent coordinate in a BASE table
+pub enum BaseCoord {
+    /// A format 1 coordinate
+    Format1 {
+        /// X or Y value, in design units
+        coordinate: int16,
+    },
+    /// A format 2 coordinate, which is adjusted by hinting
+    Format2 {
+        /// X or Y value, in design units
+        coordinate: int16,
+        /// Glyph ID of the control glyph
+        referenceGlyph: uint16,
+        /// Index of the contour point on the reference glyph
+        baseCoordPoint: uint16,
+    },
+    /// A format 3 coordinate
+    Format3 {
+        /// X or Y value, in design units
+        coordinate: int16,
+        /// Device table (non-variable font) / Variation Index table (variable font) for X or Y value
+        device: Offset16<Device>,
+    },
+}
+
+impl BaseCoord {
+    /// Creates a new format 1 coordinate
+    pub fn new(coordinate: int16) -> Self {
+        BaseCoord::Format1 { coordinate }
+    }
+
+    /// Creates a coordinate which varies according to a delta-set in the
+    /// BASE table's item variation store.
+    pub fn variable(coordinate: int16, outer: uint16, inner: uint16) -> Self {
+        BaseCoord::Format3 {
+            coordinate,
+            device: Offset16::to(Device::variation_index(outer, inner)),
+        }
+    }
+
+    /// The coordinate's (default) value, in design units
+    pub fn coordinate(&self) -> int16 {
+        match self {
+            BaseCoord::Format1 { coordinate }
+            | BaseCoord::Format2 { coordinate, .. }
+            | BaseCoord::Format3 { coordinate, .. } => *coordinate,
+        }
+    }
+}
+
+impl Serialize for BaseCoord {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        match &self {
+            Self::Format1 { coordinate } => {
+                data.put(1_u16)?;
+                data.put(coordinate)
+            }
+            Self::Format2 {
+                coordinate,
+                referenceGlyph,
+                baseCoordPoint,
+            } => {
+                data.put(2_u16)?;
+                data.put(coordinate)?;
+                data.put(referenceGlyph)?;
+                data.put(baseCoordPoint)
+            }
+            Self::Format3 { coordinate, device } => {
+                data.put(3_u16)?;
+                data.put(coordinate)?;
+                device.to_bytes(data)
+            }
+        }
+    }
+
+    // The default implementation serializes the coordinate, which can't be
+    // done while the device table offset is still unresolved.
+    fn ot_binary_size(&self) -> usize {
+        match &self {
+            Self::Format1 { .. } => 4,
+            Self::Format2 { .. } => 8,
+            Self::Format3 { .. } => 6,
+        }
+    }
+
+    fn offset_fields(&self) -> Vec<&dyn OffsetMarkerTrait> {
+        match &self {
+            Self::Format1 { .. } | Self::Format2 { .. } => vec![],
+            Self::Format3 { device, .. } => vec![device],
+        }
+    }
+}
+
+impl Deserialize for BaseCoord {
+    #[allow(non_snake_case)]
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        c.push();
+        let format: uint16 = c.de()?;
+        let coordinate: int16 = c.de()?;
+        let result = match format {
+            1 => Ok(BaseCoord::Format1 { coordinate }),
+            2 => {
+                let referenceGlyph: uint16 = c.de()?;
+                let baseCoordPoint: uint16 = c.de()?;
+                Ok(BaseCoord::Format2 {
+                    coordinate,
+                    referenceGlyph,
+                    baseCoordPoint,
+                })
+            }
+            3 => {
+                let device: Offset16<Device> = c.de()?;
+                Ok(BaseCoord::Format3 { coordinate, device })
+            }
+            _ => Err(DeserializationError(format!(
+                "Bad base coord format {:}",
+                format
+            ))),
+        };
+        c.pop();
+        result
+    }
+}
+
+/// Minimum and maximum extents for a script or language system
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinMax {
+    /// The minimum extent
+    pub min: Option<BaseCoord>,
+    /// The maximum extent
+    pub max: Option<BaseCoord>,
+    /// Extents which apply when a given feature is enabled, as (min, max) pairs
+    pub features: BTreeMap<Tag, (Option<BaseCoord>, Option<BaseCoord>)>,
+}
+
+/// Baseline and extent information for a script
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseScript {
+    /// The baseline used by this script when none is otherwise requested
+    pub default_baseline: Option<Tag>,
+    /// The position of each baseline for this script.
+    ///
+    /// If any script on an axis defines baselines, each script on that axis
+    /// must define the same set of baselines.
+    pub baselines: BTreeMap<Tag, BaseCoord>,
+    /// The default extents of the script
+    pub default_min_max: Option<MinMax>,
+    /// Extents for specific language systems
+    pub language_min_max: BTreeMap<Tag, MinMax>,
+}
+
+/// A BASE (Baseline) table
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BASE {
+    /// Baseline information for horizontal text, by script tag
+    pub horizontal: BTreeMap<Tag, BaseScript>,
+    /// Baseline information for vertical text, by script tag
+    pub vertical: BTreeMap<Tag, BaseScript>,
+    /// Item variation store, used by variable coordinates
+    pub item_variation_store: Option<ItemVariationStore>,
+}
+
+impl BASE {
+    /// Returns the default position of a baseline for a given script.
+    pub fn baseline(&self, vertical: bool, script: Tag, baseline: Tag) -> Option<int16> {
+        let axis = if vertical {
+            &self.vertical
+        } else {
+            &self.horizontal
+        };
+        axis.get(&script)
+            .and_then(|s| s.baselines.get(&baseline))
+            .map(|coord| coord.coordinate())
+    }
+}
+
+fn min_max_from_lowlevel(mm: MinMaxInternal) -> MinMax {
+    MinMax {
+        min: mm.minCoord.link,
+        max: mm.maxCoord.link,
+        features: mm
+            .featMinMaxRecords
+            .into_iter()
+            .map(|r| (r.featureTableTag, (r.minCoord.link, r.maxCoord.link)))
+            .collect(),
+    }
+}
+
+fn min_max_to_lowlevel(mm: &MinMax) -> MinMaxInternal {
+    let coord = |c: &Option<BaseCoord>| c.clone().map_or_else(Offset16::to_nothing, Offset16::to);
+    MinMaxInternal {
+        minCoord: coord(&mm.min),
+        maxCoord: coord(&mm.max),
+        featMinMaxRecords: mm
+            .features
+            .iter()
+            .map(|(tag, (min, max))| FeatMinMaxRecord {
+                featureTableTag: *tag,
+                minCoord: coord(min),
+                maxCoord: coord(max),
+            })
+            .collect(),
+    }
+}
+
+fn axis_from_lowlevel(axis: Axis) -> BTreeMap<Tag, BaseScript> {
+    let tags = axis
+        .baseTagList
+        .link
+        .map_or_else(Vec::new, |l| l.baselineTags);
+    let mut scripts = BTreeMap::new();
+    for record in axis
+        .baseScriptList
+        .link
+        .map_or_else(Vec::new, |l| l.baseScriptRecords)
+    {
+        let script = match record.baseScript.link {
+            Some(script) => script,
+            None => continue,
+        };
+        let mut base_script = BaseScript {
+            default_min_max: script.defaultMinMax.link.map(min_max_from_lowlevel),
+            language_min_max: script
+                .baseLangSysRecords
+                .into_iter()
+                .filter_map(|r| Some((r.baseLangSysTag, min_max_from_lowlevel(r.minMax.link?))))
+                .collect(),
+            ..Default::default()
+        };
+        if let Some(values) = script.baseValues.link {
+            base_script.default_baseline = tags.get(values.defaultBaselineIndex as usize).copied();
+            base_script.baselines = tags
+                .iter()
+                .zip(values.baseCoords.v)
+                .filter_map(|(tag, coord)| Some((*tag, coord.link?)))
+                .collect();
+        }
+        scripts.insert(record.baseScriptTag, base_script);
+    }
+    scripts
+}
+
+fn axis_to_lowlevel(
+    scripts: &BTreeMap<Tag, BaseScript>,
+) -> Result<Offset16<Axis>, SerializationError> {
+    if scripts.is_empty() {
+        return Ok(Offset16::to_nothing());
+    }
+    // Baseline tags must be sorted, which the set gives us for free.
+    let tags: Vec<Tag> = scripts
+        .values()
+        .flat_map(|s| s.baselines.keys().copied())
+        .collect::<BTreeSet<Tag>>()
+        .into_iter()
+        .collect();
+    let mut records = vec![];
+    for (script_tag, script) in scripts {
+        let base_values = if script.baselines.is_empty() {
+            Offset16::to_nothing()
+        } else {
+            let coords = tags
+                .iter()
+                .map(|tag| {
+                    script
+                        .baselines
+                        .get(tag)
+                        .cloned()
+                        .map(Offset16::to)
+                        .ok_or_else(|| {
+                            SerializationError(format!(
+                                "Script {} has no coordinate for baseline {}",
+                                script_tag, tag
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let default_index = script
+                .default_baseline
+                .and_then(|d| tags.iter().position(|t| *t == d))
+                .unwrap_or(0);
+            Offset16::to(BaseValues {
+                defaultBaselineIndex: default_index as uint16,
+                baseCoords: coords.into(),
+            })
+        };
+        records.push(BaseScriptRecord {
+            baseScriptTag: *script_tag,
+            baseScript: Offset16::to(BaseScriptInternal {
+                baseValues: base_values,
+                defaultMinMax: script
+                    .default_min_max
+                    .as_ref()
+                    .map_or_else(Offset16::to_nothing, |mm| {
+                        Offset16::to(min_max_to_lowlevel(mm))
+                    }),
+                baseLangSysRecords: script
+                    .language_min_max
+                    .iter()
+                    .map(|(tag, mm)| BaseLangSysRecord {
+                        baseLangSysTag: *tag,
+                        minMax: Offset16::to(min_max_to_lowlevel(mm)),
+                    })
+                    .collect(),
+            }),
+        });
+    }
+    Ok(Offset16::to(Axis {
+        baseTagList: if tags.is_empty() {
+            Offset16::to_nothing()
+        } else {
+            Offset16::to(BaseTagList { baselineTags: tags })
+        },
+        baseScriptList: Offset16::to(BaseScriptList {
+            baseScriptRecords: records,
+        }),
+    }))
+}
+
+impl Deserialize for BASE {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let core: basecore10 = c.de()?;
+        let ivs = if core.minorVersion > 0 {
+            let internal: Offset32<ItemVariationStore> = c.de()?;
+            internal.link
+        } else {
+            None
+        };
+        Ok(BASE {
+            horizontal: core
+                .horizAxis
+                .link
+                .map_or_else(BTreeMap::new, axis_from_lowlevel),
+            vertical: core
+                .vertAxis
+                .link
+                .map_or_else(BTreeMap::new, axis_from_lowlevel),
+            item_variation_store: ivs,
+        })
+    }
+}
+
+impl Serialize for BASE {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let horiz_axis = axis_to_lowlevel(&self.horizontal)?;
+        let vert_axis = axis_to_lowlevel(&self.vertical)?;
+        if let Some(ivs) = &self.item_variation_store {
+            basecore11 {
+                majorVersion: 1,
+                minorVersion: 1,
+                horizAxis: horiz_axis,
+                vertAxis: vert_axis,
+                itemVarStore: Offset32::to(ivs.clone()),
+            }
+            .to_bytes(data)
+        } else {
+            basecore10 {
+                majorVersion: 1,
+                minorVersion: 0,
+                horizAxis: horiz_axis,
+                vertAxis: vert_axis,
+            }
+            .to_bytes(data)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag;
+    use otspec::btreemap;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_base_deser() {
+        /*
+            table BASE {
+                HorizAxis.BaseTagList ideo romn;
+                HorizAxis.BaseScriptList latn romn -120 0, hani ideo -120 0;
+            } BASE;
+        */
+        let binary_base = vec![
+            0x00, 0x01, 0x00, 0x00, // BASE 1.0
+            0x00, 0x08, // horizAxisOffset
+            0x00, 0x00, // vertAxisOffset
+            /* 0x08 */ 0x00, 0x04, // Axis.baseTagListOffset
+            0x00, 0x0e, // Axis.baseScriptListOffset
+            /* 0x0c */ 0x00, 0x02, // BaseTagList.baseTagCount
+            0x69, 0x64, 0x65, 0x6f, // ideo
+            0x72, 0x6f, 0x6d, 0x6e, // romn
+            /* 0x16 */ 0x00, 0x02, // BaseScriptList.baseScriptCount
+            0x68, 0x61, 0x6e, 0x69, 0x00, 0x0e, // hani
+            0x6c, 0x61, 0x74, 0x6e, 0x00, 0x24, // latn
+            /* 0x24 */ 0x00, 0x06, // BaseScript.baseValuesOffset
+            0x00, 0x00, // BaseScript.defaultMinMaxOffset
+            0x00, 0x00, // BaseScript.baseLangSysCount
+            0x00, 0x00, // BaseValues.defaultBaselineIndex
+            0x00, 0x02, // BaseValues.baseCoordCount
+            0x00, 0x08, 0x00, 0x0c, // BaseValues.baseCoordOffsets
+            0x00, 0x01, 0xff, 0x88, // BaseCoord format 1, -120
+            0x00, 0x01, 0x00, 0x00, // BaseCoord format 1, 0
+            /* 0x3a */ 0x00, 0x06, // BaseScript.baseValuesOffset
+            0x00, 0x00, // BaseScript.defaultMinMaxOffset
+            0x00, 0x00, // BaseScript.baseLangSysCount
+            0x00, 0x01, // BaseValues.defaultBaselineIndex
+            0x00, 0x02, // BaseValues.baseCoordCount
+            0x00, 0x08, 0x00, 0x0c, // BaseValues.baseCoordOffsets
+            0x00, 0x01, 0xff, 0x88, // BaseCoord format 1, -120
+            0x00, 0x01, 0x00, 0x00, // BaseCoord format 1, 0
+        ];
+        let base: BASE = otspec::de::from_bytes(&binary_base).unwrap();
+        let expected = BASE {
+            horizontal: btreemap!(
+                tag!("hani") => BaseScript {
+                    default_baseline: Some(tag!("ideo")),
+                    baselines: btreemap!(
+                        tag!("ideo") => BaseCoord::new(-120),
+                        tag!("romn") => BaseCoord::new(0)
+                    ),
+                    ..Default::default()
+                },
+                tag!("latn") => BaseScript {
+                    default_baseline: Some(tag!("romn")),
+                    baselines: btreemap!(
+                        tag!("ideo") => BaseCoord::new(-120),
+                        tag!("romn") => BaseCoord::new(0)
+                    ),
+                    ..Default::default()
+                }
+            ),
+            vertical: BTreeMap::new(),
+            item_variation_store: None,
+        };
+        assert_eq!(base, expected);
+        assert_eq!(base.baseline(false, tag!("hani"), tag!("ideo")), Some(-120));
+
+        let binary = otspec::ser::to_bytes(&expected).unwrap();
+        let base2: BASE = otspec::de::from_bytes(&binary).unwrap();
+        assert_eq!(base2, expected);
+    }
+
+    #[test]
+    fn test_base_minmax_variable() {
+        let base = BASE {
+            horizontal: BTreeMap::new(),
+            vertical: btreemap!(
+                tag!("kana") => BaseScript {
+                    default_baseline: Some(tag!("ideo")),
+                    baselines: btreemap!(tag!("ideo") => BaseCoord::variable(0, 0, 0)),
+                    default_min_max: Some(MinMax {
+                        min: Some(BaseCoord::new(-200)),
+                        max: Some(BaseCoord::new(1000)),
+                        features: BTreeMap::new(),
+                    }),
+                    language_min_max: btreemap!(
+                        tag!("JAN ") => MinMax {
+                            min: None,
+                            max: Some(BaseCoord::new(1100)),
+                            features: btreemap!(
+                                tag!("vrt2") => (Some(BaseCoord::new(-250)), None)
+                            ),
+                        }
+                    ),
+                }
+            ),
+            item_variation_store: Some(ItemVariationStore {
+                format: 1,
+                axisCount: 1,
+                variationRegions: vec![],
+                variationData: vec![],
+            }),
+        };
+        let binary = otspec::ser::to_bytes(&base).unwrap();
+        assert_eq!(&binary[0..4], &[0x00, 0x01, 0x00, 0x01]);
+        let base2: BASE = otspec::de::from_bytes(&binary).unwrap();
+        assert_eq!(base2, base);
+    }
+
+    #[test]
+    fn test_base_missing_baseline() {
+        let base = BASE {
+            horizontal: btreemap!(
+                tag!("latn") => BaseScript {
+                    baselines: btreemap!(tag!("romn") => BaseCoord::new(0)),
+                    ..Default::default()
+                },
+                tag!("hani") => BaseScript {
+                    baselines: btreemap!(tag!("ideo") => BaseCoord::new(-120)),
+                    ..Default::default()
+                }
+            ),
+            ..Default::default()
+        };
+        assert!(otspec::ser::to_bytes(&base).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::tables::CBLC::CBLC;
+use crate::tables::EBDT::{read_strikes, write_strikes, BitmapGlyph, BitmapStrike};
+use otspec::types::*;
+use otspec::{DeserializationError, SerializationError, Serialize};
+
+/// The 'CBDT' OpenType tag.
+pub const TAG: Tag = crate::tag!("CBDT");
+
+/// Color Bitmap Data table
+///
+/// This has the same structure as the `EBDT` table, and adds the PNG image
+/// formats 17, 18 and 19. The strikes correspond to the strikes of the
+/// `CBLC` table, which is updated to locate the images when the font is
+/// saved.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CBDT {
+    /// The glyph images of each strike
+    pub strikes: Vec<BitmapStrike>,
+}
+
+impl CBDT {
+    /// Returns a glyph's image in the given strike.
+    pub fn glyph(&self, strike: usize, glyph_id: GlyphID) -> Option<&BitmapGlyph> {
+        self.strikes.get(strike)?.get(&glyph_id)
+    }
+
+    /// Sets a glyph's image in the given strike, returning the old image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strike does not exist.
+    pub fn set_glyph(
+        &mut self,
+        strike: usize,
+        glyph_id: GlyphID,
+        glyph: BitmapGlyph,
+    ) -> Option<BitmapGlyph> {
+        self.strikes[strike].insert(glyph_id, glyph)
+    }
+
+    /// Removes a glyph's image from the given strike.
+    pub fn remove_glyph(&mut self, strike: usize, glyph_id: GlyphID) -> Option<BitmapGlyph> {
+        self.strikes.get_mut(strike)?.remove(&glyph_id)
+    }
+}
+
+impl Serialize for CBDT {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        write_strikes(3, &self.strikes, data)
+    }
+}
+
+/// Deserializes a Color Bitmap Data table given a binary vector and the
+/// `CBLC` table locating its images.
+pub fn from_bytes(data: &[u8], cblc: &CBLC) -> Result<CBDT, DeserializationError> {
+    Ok(CBDT {
+        strikes: read_strikes(data, &cblc.strikes)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::EBDT::{BitmapData, GlyphMetrics};
+    use crate::tables::EBLC::{BigGlyphMetrics, BitmapSize, SbitLineMetrics, SmallGlyphMetrics};
+
+    #[test]
+    fn cbdt_cblc_roundtrip() {
+        let png = vec![0x89, b'P', b'N', b'G'];
+        let big = BigGlyphMetrics {
+            height: 16,
+            width: 16,
+            horiAdvance: 16,
+            vertAdvance: 16,
+            ..Default::default()
+        };
+        let mut strike = BitmapStrike::new();
+        strike.insert(
+            1,
+            BitmapGlyph::png(
+                SmallGlyphMetrics {
+                    height: 16,
+                    width: 16,
+                    bearingX: 0,
+                    bearingY: 12,
+                    advance: 16,
+                },
+                png.clone(),
+            ),
+        );
+        for glyph_id in 2..=3 {
+            strike.insert(
+                glyph_id,
+                BitmapGlyph {
+                    image_format: 19,
+                    metrics: GlyphMetrics::Big(big.clone()),
+                    data: BitmapData::Image(png.clone()),
+                },
+            );
+        }
+        let cbdt = CBDT {
+            strikes: vec![strike],
+        };
+        let mut cblc = CBLC {
+            strikes: vec![BitmapSize {
+                color_ref: 0,
+                hori: SbitLineMetrics::default(),
+                vert: SbitLineMetrics::default(),
+                ppem_x: 16,
+                ppem_y: 16,
+                bit_depth: 32,
+                flags: 1,
+                index_subtables: vec![],
+            }],
+        };
+        cblc.update_locations(&cbdt).unwrap();
+        let formats: Vec<_> = cblc.strikes[0]
+            .index_subtables
+            .iter()
+            .map(|st| (st.first_glyph_index, st.index_format, st.image_format))
+            .collect();
+        assert_eq!(formats, vec![(1, 3, 17), (2, 2, 19)]);
+
+        let binary_cblc = otspec::ser::to_bytes(&cblc).unwrap();
+        let binary_cbdt = otspec::ser::to_bytes(&cbdt).unwrap();
+        assert_eq!(&binary_cblc[0..4], &[0x00, 0x03, 0x00, 0x00]);
+        assert_eq!(&binary_cbdt[0..4], &[0x00, 0x03, 0x00, 0x00]);
+        let cblc2: CBLC = otspec::de::from_bytes(&binary_cblc).unwrap();
+        assert_eq!(cblc2, cblc);
+        let cbdt2 = from_bytes(&binary_cbdt, &cblc2).unwrap();
+        assert_eq!(cbdt2, cbdt);
+        assert_eq!(cbdt2.glyph(0, 3).unwrap().image(), Some(&png[..]));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::tables::CBDT::CBDT;
+use crate::tables::EBLC::{locate_glyphs, read_strikes, write_strikes, BitmapSize};
+use otspec::types::*;
+use otspec::{DeserializationError, Deserialize, ReaderContext, SerializationError, Serialize};
+
+/// The 'CBLC' OpenType tag.
+pub const TAG: Tag = crate::tag!("CBLC");
+
+/// Color Bitmap Location table
+///
+/// This has the same structure as the `EBLC` table.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CBLC {
+    /// The strikes in the font
+    pub strikes: Vec<BitmapSize>,
+}
+
+impl CBLC {
+    /// Rebuilds the index subtables to locate the glyph images in the given
+    /// data table.
+    ///
+    /// This is done automatically when a font with a modified `CBDT` table
+    /// is saved.
+    pub fn update_locations(&mut self, cbdt: &CBDT) -> Result<(), SerializationError> {
+        locate_glyphs(&mut self.strikes, &cbdt.strikes)
+    }
+}
+
+impl Deserialize for CBLC {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        Ok(CBLC {
+            strikes: read_strikes(c)?,
+        })
+    }
+}
+
+impl Serialize for CBLC {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        write_strikes(3, &self.strikes, data)
+    }
+}
@@ -0,0 +1,1100 @@
+use crate::otvar::{DeltaSetIndexMap, ItemVariationStore};
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::{tables, Serialize};
+use std::collections::BTreeMap;
+
+/// The 'COLR' OpenType tag.
+pub const TAG: Tag = crate::tag!("COLR");
+
+tables!(
+    BaseGlyphRecord [serde] {
+        uint16 glyphID
+        uint16 firstLayerIndex
+        uint16 numLayers
+    }
+    LayerRecord [serde] {
+        uint16 glyphID
+        uint16 paletteIndex
+    }
+    BaseGlyphList [serde] {
+        [offset_base]
+        [embed]
+        Counted32(BaseGlyphPaintRecord) baseGlyphPaintRecords
+    }
+    BaseGlyphPaintRecord [embedded] [serde] {
+        uint16 glyphID
+        Offset32(Paint) paint
+    }
+    LayerList [serde] {
+        [offset_base]
+        Counted32Offset32(Paint) paints
+    }
+    ClipList [serde] {
+        [offset_base]
+        uint8 format
+        [embed]
+        Counted32(Clip) clips
+    }
+    Clip [embedded] [serde] {
+        uint16 startGlyphID
+        uint16 endGlyphID
+        Offset24(ClipBox) clipBox
+    }
+    ColorStop [serde] {
+        F2DOT14 stopOffset
+        uint16 paletteIndex
+        F2DOT14 alpha
+    }
+    VarColorStop [serde] {
+        F2DOT14 stopOffset
+        uint16 paletteIndex
+        F2DOT14 alpha
+        uint32 varIndexBase
+    }
+    ColorLine [serde] {
+        uint8 extend
+        Counted(ColorStop) colorStops
+    }
+    VarColorLine [serde] {
+        uint8 extend
+        Counted(VarColorStop) colorStops
+    }
+    Affine2x3 [serde] {
+        Fixed xx
+        Fixed yx
+        Fixed xy
+        Fixed yy
+        Fixed dx
+        Fixed dy
+    }
+    VarAffine2x3 [serde] {
+        Fixed xx
+        Fixed yx
+        Fixed xy
+        Fixed yy
+        Fixed dx
+        Fixed dy
+        uint32 varIndexBase
+    }
+
+    PaintColrLayers [serde] {
+        uint8 format
+        uint8 numLayers
+        uint32 firstLayerIndex
+    }
+    PaintSolid [serde] {
+        uint8 format
+        uint16 paletteIndex
+        F2DOT14 alpha
+    }
+    PaintVarSolid [serde] {
+        uint8 format
+        uint16 paletteIndex
+        F2DOT14 alpha
+        uint32 varIndexBase
+    }
+    PaintLinearGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(ColorLine) colorLine
+        FWORD x0
+        FWORD y0
+        FWORD x1
+        FWORD y1
+        FWORD x2
+        FWORD y2
+    }
+    PaintVarLinearGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(VarColorLine) colorLine
+        FWORD x0
+        FWORD y0
+        FWORD x1
+        FWORD y1
+        FWORD x2
+        FWORD y2
+        uint32 varIndexBase
+    }
+    PaintRadialGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(ColorLine) colorLine
+        FWORD x0
+        FWORD y0
+        UFWORD radius0
+        FWORD x1
+        FWORD y1
+        UFWORD radius1
+    }
+    PaintVarRadialGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(VarColorLine) colorLine
+        FWORD x0
+        FWORD y0
+        UFWORD radius0
+        FWORD x1
+        FWORD y1
+        UFWORD radius1
+        uint32 varIndexBase
+    }
+    PaintSweepGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(ColorLine) colorLine
+        FWORD centerX
+        FWORD centerY
+        F2DOT14 startAngle
+        F2DOT14 endAngle
+    }
+    PaintVarSweepGradient [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(VarColorLine) colorLine
+        FWORD centerX
+        FWORD centerY
+        F2DOT14 startAngle
+        F2DOT14 endAngle
+        uint32 varIndexBase
+    }
+    PaintGlyph [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        uint16 glyphID
+    }
+    PaintColrGlyph [serde] {
+        uint8 format
+        uint16 glyphID
+    }
+    PaintTransform [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        Offset24(Affine2x3) transform
+    }
+    PaintVarTransform [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        Offset24(VarAffine2x3) transform
+    }
+    PaintTranslate [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        FWORD dx
+        FWORD dy
+    }
+    PaintVarTranslate [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        FWORD dx
+        FWORD dy
+        uint32 varIndexBase
+    }
+    PaintScale [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scaleX
+        F2DOT14 scaleY
+    }
+    PaintVarScale [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scaleX
+        F2DOT14 scaleY
+        uint32 varIndexBase
+    }
+    PaintScaleAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scaleX
+        F2DOT14 scaleY
+        FWORD centerX
+        FWORD centerY
+    }
+    PaintVarScaleAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scaleX
+        F2DOT14 scaleY
+        FWORD centerX
+        FWORD centerY
+        uint32 varIndexBase
+    }
+    PaintScaleUniform [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scale
+    }
+    PaintVarScaleUniform [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scale
+        uint32 varIndexBase
+    }
+    PaintScaleUniformAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scale
+        FWORD centerX
+        FWORD centerY
+    }
+    PaintVarScaleUniformAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 scale
+        FWORD centerX
+        FWORD centerY
+        uint32 varIndexBase
+    }
+    PaintRotate [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 angle
+    }
+    PaintVarRotate [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 angle
+        uint32 varIndexBase
+    }
+    PaintRotateAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 angle
+        FWORD centerX
+        FWORD centerY
+    }
+    PaintVarRotateAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 angle
+        FWORD centerX
+        FWORD centerY
+        uint32 varIndexBase
+    }
+    PaintSkew [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 xSkewAngle
+        F2DOT14 ySkewAngle
+    }
+    PaintVarSkew [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 xSkewAngle
+        F2DOT14 ySkewAngle
+        uint32 varIndexBase
+    }
+    PaintSkewAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 xSkewAngle
+        F2DOT14 ySkewAngle
+        FWORD centerX
+        FWORD centerY
+    }
+    PaintVarSkewAroundCenter [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) paint
+        F2DOT14 xSkewAngle
+        F2DOT14 ySkewAngle
+        FWORD centerX
+        FWORD centerY
+        uint32 varIndexBase
+    }
+    PaintComposite [serde] {
+        [offset_base]
+        uint8 format
+        Offset24(Paint) sourcePaint
+        uint8 compositeMode
+        Offset24(Paint) backdropPaint
+    }
+);
+
+/// Color line extend mode: use the nearest color stop.
+pub const EXTEND_PAD: uint8 = 0;
+/// Color line extend mode: repeat the color line.
+pub const EXTEND_REPEAT: uint8 = 1;
+/// Color line extend mode: repeat the color line, reflecting every other repetition.
+pub const EXTEND_REFLECT: uint8 = 2;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A node in a COLRv1 paint graph
+///
+/// Each variant wraps the paint table of the corresponding format; the
+/// `format` field of the wrapped table must agree with the variant.
+#[allow(missing_docs)]
+pub enum Paint {
+    ColrLayers(Box<PaintColrLayers>),
+    Solid(Box<PaintSolid>),
+    VarSolid(Box<PaintVarSolid>),
+    LinearGradient(Box<PaintLinearGradient>),
+    VarLinearGradient(Box<PaintVarLinearGradient>),
+    RadialGradient(Box<PaintRadialGradient>),
+    VarRadialGradient(Box<PaintVarRadialGradient>),
+    SweepGradient(Box<PaintSweepGradient>),
+    VarSweepGradient(Box<PaintVarSweepGradient>),
+    Glyph(Box<PaintGlyph>),
+    ColrGlyph(Box<PaintColrGlyph>),
+    Transform(Box<PaintTransform>),
+    VarTransform(Box<PaintVarTransform>),
+    Translate(Box<PaintTranslate>),
+    VarTranslate(Box<PaintVarTranslate>),
+    Scale(Box<PaintScale>),
+    VarScale(Box<PaintVarScale>),
+    ScaleAroundCenter(Box<PaintScaleAroundCenter>),
+    VarScaleAroundCenter(Box<PaintVarScaleAroundCenter>),
+    ScaleUniform(Box<PaintScaleUniform>),
+    VarScaleUniform(Box<PaintVarScaleUniform>),
+    ScaleUniformAroundCenter(Box<PaintScaleUniformAroundCenter>),
+    VarScaleUniformAroundCenter(Box<PaintVarScaleUniformAroundCenter>),
+    Rotate(Box<PaintRotate>),
+    VarRotate(Box<PaintVarRotate>),
+    RotateAroundCenter(Box<PaintRotateAroundCenter>),
+    VarRotateAroundCenter(Box<PaintVarRotateAroundCenter>),
+    Skew(Box<PaintSkew>),
+    VarSkew(Box<PaintVarSkew>),
+    SkewAroundCenter(Box<PaintSkewAroundCenter>),
+    VarSkewAroundCenter(Box<PaintVarSkewAroundCenter>),
+    Composite(Box<PaintComposite>),
+}
+
+fn smash_it(p: &Paint) -> &dyn Serialize {
+    match p {
+        Paint::ColrLayers(x) => x.as_ref(),
+        Paint::Solid(x) => x.as_ref(),
+        Paint::VarSolid(x) => x.as_ref(),
+        Paint::LinearGradient(x) => x.as_ref(),
+        Paint::VarLinearGradient(x) => x.as_ref(),
+        Paint::RadialGradient(x) => x.as_ref(),
+        Paint::VarRadialGradient(x) => x.as_ref(),
+        Paint::SweepGradient(x) => x.as_ref(),
+        Paint::VarSweepGradient(x) => x.as_ref(),
+        Paint::Glyph(x) => x.as_ref(),
+        Paint::ColrGlyph(x) => x.as_ref(),
+        Paint::Transform(x) => x.as_ref(),
+        Paint::VarTransform(x) => x.as_ref(),
+        Paint::Translate(x) => x.as_ref(),
+        Paint::VarTranslate(x) => x.as_ref(),
+        Paint::Scale(x) => x.as_ref(),
+        Paint::VarScale(x) => x.as_ref(),
+        Paint::ScaleAroundCenter(x) => x.as_ref(),
+        Paint::VarScaleAroundCenter(x) => x.as_ref(),
+        Paint::ScaleUniform(x) => x.as_ref(),
+        Paint::VarScaleUniform(x) => x.as_ref(),
+        Paint::ScaleUniformAroundCenter(x) => x.as_ref(),
+        Paint::VarScaleUniformAroundCenter(x) => x.as_ref(),
+        Paint::Rotate(x) => x.as_ref(),
+        Paint::VarRotate(x) => x.as_ref(),
+        Paint::RotateAroundCenter(x) => x.as_ref(),
+        Paint::VarRotateAroundCenter(x) => x.as_ref(),
+        Paint::Skew(x) => x.as_ref(),
+        Paint::VarSkew(x) => x.as_ref(),
+        Paint::SkewAroundCenter(x) => x.as_ref(),
+        Paint::VarSkewAroundCenter(x) => x.as_ref(),
+        Paint::Composite(x) => x.as_ref(),
+    }
+}
+
+impl Paint {
+    /// The paint table format of this node.
+    pub fn format(&self) -> uint8 {
+        match self {
+            Paint::ColrLayers(_) => 1,
+            Paint::Solid(_) => 2,
+            Paint::VarSolid(_) => 3,
+            Paint::LinearGradient(_) => 4,
+            Paint::VarLinearGradient(_) => 5,
+            Paint::RadialGradient(_) => 6,
+            Paint::VarRadialGradient(_) => 7,
+            Paint::SweepGradient(_) => 8,
+            Paint::VarSweepGradient(_) => 9,
+            Paint::Glyph(_) => 10,
+            Paint::ColrGlyph(_) => 11,
+            Paint::Transform(_) => 12,
+            Paint::VarTransform(_) => 13,
+            Paint::Translate(_) => 14,
+            Paint::VarTranslate(_) => 15,
+            Paint::Scale(_) => 16,
+            Paint::VarScale(_) => 17,
+            Paint::ScaleAroundCenter(_) => 18,
+            Paint::VarScaleAroundCenter(_) => 19,
+            Paint::ScaleUniform(_) => 20,
+            Paint::VarScaleUniform(_) => 21,
+            Paint::ScaleUniformAroundCenter(_) => 22,
+            Paint::VarScaleUniformAroundCenter(_) => 23,
+            Paint::Rotate(_) => 24,
+            Paint::VarRotate(_) => 25,
+            Paint::RotateAroundCenter(_) => 26,
+            Paint::VarRotateAroundCenter(_) => 27,
+            Paint::Skew(_) => 28,
+            Paint::VarSkew(_) => 29,
+            Paint::SkewAroundCenter(_) => 30,
+            Paint::VarSkewAroundCenter(_) => 31,
+            Paint::Composite(_) => 32,
+        }
+    }
+
+    /// The base variation index of a variable paint, if any.
+    ///
+    /// Variable color stops and transforms carry their own base index on
+    /// the color line or affine table.
+    pub fn var_index_base(&self) -> Option<uint32> {
+        match self {
+            Paint::VarSolid(x) => Some(x.varIndexBase),
+            Paint::VarLinearGradient(x) => Some(x.varIndexBase),
+            Paint::VarRadialGradient(x) => Some(x.varIndexBase),
+            Paint::VarSweepGradient(x) => Some(x.varIndexBase),
+            Paint::VarTranslate(x) => Some(x.varIndexBase),
+            Paint::VarScale(x) => Some(x.varIndexBase),
+            Paint::VarScaleAroundCenter(x) => Some(x.varIndexBase),
+            Paint::VarScaleUniform(x) => Some(x.varIndexBase),
+            Paint::VarScaleUniformAroundCenter(x) => Some(x.varIndexBase),
+            Paint::VarRotate(x) => Some(x.varIndexBase),
+            Paint::VarRotateAroundCenter(x) => Some(x.varIndexBase),
+            Paint::VarSkew(x) => Some(x.varIndexBase),
+            Paint::VarSkewAroundCenter(x) => Some(x.varIndexBase),
+            _ => None,
+        }
+    }
+
+    /// The child paints of this node.
+    pub fn children(&self) -> Vec<&Paint> {
+        let offsets: Vec<&Offset24<Paint>> = match self {
+            Paint::Glyph(x) => vec![&x.paint],
+            Paint::Transform(x) => vec![&x.paint],
+            Paint::VarTransform(x) => vec![&x.paint],
+            Paint::Translate(x) => vec![&x.paint],
+            Paint::VarTranslate(x) => vec![&x.paint],
+            Paint::Scale(x) => vec![&x.paint],
+            Paint::VarScale(x) => vec![&x.paint],
+            Paint::ScaleAroundCenter(x) => vec![&x.paint],
+            Paint::VarScaleAroundCenter(x) => vec![&x.paint],
+            Paint::ScaleUniform(x) => vec![&x.paint],
+            Paint::VarScaleUniform(x) => vec![&x.paint],
+            Paint::ScaleUniformAroundCenter(x) => vec![&x.paint],
+            Paint::VarScaleUniformAroundCenter(x) => vec![&x.paint],
+            Paint::Rotate(x) => vec![&x.paint],
+            Paint::VarRotate(x) => vec![&x.paint],
+            Paint::RotateAroundCenter(x) => vec![&x.paint],
+            Paint::VarRotateAroundCenter(x) => vec![&x.paint],
+            Paint::Skew(x) => vec![&x.paint],
+            Paint::VarSkew(x) => vec![&x.paint],
+            Paint::SkewAroundCenter(x) => vec![&x.paint],
+            Paint::VarSkewAroundCenter(x) => vec![&x.paint],
+            Paint::Composite(x) => vec![&x.sourcePaint, &x.backdropPaint],
+            _ => vec![],
+        };
+        offsets.into_iter().flat_map(|o| o.link.as_ref()).collect()
+    }
+}
+
+impl Serialize for Paint {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        smash_it(self).to_bytes(data)
+    }
+
+    fn offset_fields(&self) -> Vec<&dyn OffsetMarkerTrait> {
+        smash_it(self).offset_fields()
+    }
+
+    fn ot_binary_size(&self) -> usize {
+        smash_it(self).ot_binary_size()
+    }
+
+    fn to_bytes_shallow(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        smash_it(self).to_bytes_shallow(data)
+    }
+}
+
+impl Deserialize for Paint {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let format: uint8 = c.de()?;
+        c.ptr -= 1;
+        Ok(match format {
+            1 => Paint::ColrLayers(Box::new(c.de()?)),
+            2 => Paint::Solid(Box::new(c.de()?)),
+            3 => Paint::VarSolid(Box::new(c.de()?)),
+            4 => Paint::LinearGradient(Box::new(c.de()?)),
+            5 => Paint::VarLinearGradient(Box::new(c.de()?)),
+            6 => Paint::RadialGradient(Box::new(c.de()?)),
+            7 => Paint::VarRadialGradient(Box::new(c.de()?)),
+            8 => Paint::SweepGradient(Box::new(c.de()?)),
+            9 => Paint::VarSweepGradient(Box::new(c.de()?)),
+            10 => Paint::Glyph(Box::new(c.de()?)),
+            11 => Paint::ColrGlyph(Box::new(c.de()?)),
+            12 => Paint::Transform(Box::new(c.de()?)),
+            13 => Paint::VarTransform(Box::new(c.de()?)),
+            14 => Paint::Translate(Box::new(c.de()?)),
+            15 => Paint::VarTranslate(Box::new(c.de()?)),
+            16 => Paint::Scale(Box::new(c.de()?)),
+            17 => Paint::VarScale(Box::new(c.de()?)),
+            18 => Paint::ScaleAroundCenter(Box::new(c.de()?)),
+            19 => Paint::VarScaleAroundCenter(Box::new(c.de()?)),
+            20 => Paint::ScaleUniform(Box::new(c.de()?)),
+            21 => Paint::VarScaleUniform(Box::new(c.de()?)),
+            22 => Paint::ScaleUniformAroundCenter(Box::new(c.de()?)),
+            23 => Paint::VarScaleUniformAroundCenter(Box::new(c.de()?)),
+            24 => Paint::Rotate(Box::new(c.de()?)),
+            25 => Paint::VarRotate(Box::new(c.de()?)),
+            26 => Paint::RotateAroundCenter(Box::new(c.de()?)),
+            27 => Paint::VarRotateAroundCenter(Box::new(c.de()?)),
+            28 => Paint::Skew(Box::new(c.de()?)),
+            29 => Paint::VarSkew(Box::new(c.de()?)),
+            30 => Paint::SkewAroundCenter(Box::new(c.de()?)),
+            31 => Paint::VarSkewAroundCenter(Box::new(c.de()?)),
+            32 => Paint::Composite(Box::new(c.de()?)),
+            _ => {
+                return Err(DeserializationError(format!(
+                    "Invalid paint format {}",
+                    format
+                )))
+            }
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A clip box for a COLRv1 glyph
+///
+/// A clip box with a `varIndexBase` is written as a variable (format 2)
+/// clip box.
+pub struct ClipBox {
+    /// Minimum x of the clip box
+    pub xMin: FWORD,
+    /// Minimum y of the clip box
+    pub yMin: FWORD,
+    /// Maximum x of the clip box
+    pub xMax: FWORD,
+    /// Maximum y of the clip box
+    pub yMax: FWORD,
+    /// Base index into the variation index map for the four coordinates
+    pub varIndexBase: Option<uint32>,
+}
+
+impl Serialize for ClipBox {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let format: uint8 = if self.varIndexBase.is_some() { 2 } else { 1 };
+        format.to_bytes(data)?;
+        self.xMin.to_bytes(data)?;
+        self.yMin.to_bytes(data)?;
+        self.xMax.to_bytes(data)?;
+        self.yMax.to_bytes(data)?;
+        self.varIndexBase.to_bytes(data)
+    }
+}
+
+impl Deserialize for ClipBox {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let format: uint8 = c.de()?;
+        if format != 1 && format != 2 {
+            return Err(DeserializationError(format!(
+                "Invalid clip box format {}",
+                format
+            )));
+        }
+        Ok(ClipBox {
+            xMin: c.de()?,
+            yMin: c.de()?,
+            xMax: c.de()?,
+            yMax: c.de()?,
+            varIndexBase: if format == 2 { Some(c.de()?) } else { None },
+        })
+    }
+}
+
+// The record arrays need a count from elsewhere in the header, so the
+// header is read by hand, but written with the help of the offset manager.
+#[allow(non_snake_case)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
+struct COLRcore {
+    version: uint16,
+    numBaseGlyphRecords: uint16,
+    baseGlyphRecords: Offset32<Vec<BaseGlyphRecord>>,
+    layerRecords: Offset32<Vec<LayerRecord>>,
+    numLayerRecords: uint16,
+    baseGlyphList: Option<Offset32<BaseGlyphList>>,
+    layerList: Option<Offset32<LayerList>>,
+    clipList: Option<Offset32<ClipList>>,
+    varIndexMap: Option<Offset32<DeltaSetIndexMap>>,
+    itemVariationStore: Option<Offset32<ItemVariationStore>>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Color table
+///
+/// A version 1 table is written if any of the COLRv1 fields are non-empty.
+/// Identical paints reachable along several paths of the paint graph are
+/// written once and share an offset.
+pub struct COLR {
+    /// COLRv0 layers (glyph ID and palette index) for each base glyph.
+    pub base_glyphs: BTreeMap<GlyphID, Vec<LayerRecord>>,
+    /// The root of the COLRv1 paint graph for each base glyph.
+    pub base_glyph_paints: BTreeMap<GlyphID, Paint>,
+    /// The COLRv1 layer list, referred to by `PaintColrLayers`.
+    pub layers: Vec<Paint>,
+    /// COLRv1 clip boxes for each base glyph.
+    pub clips: BTreeMap<GlyphID, ClipBox>,
+    /// Maps variation indices to delta-set indices. If not present, the
+    /// variation index is used as the delta-set index.
+    pub var_index_map: Option<DeltaSetIndexMap>,
+    /// The deltas for variable paints, color stops, transforms and clip boxes.
+    pub item_variation_store: Option<ItemVariationStore>,
+}
+
+impl COLR {
+    /// Returns true if this table needs to be written as COLRv1.
+    pub fn is_v1(&self) -> bool {
+        !self.base_glyph_paints.is_empty()
+            || !self.layers.is_empty()
+            || !self.clips.is_empty()
+            || self.var_index_map.is_some()
+            || self.item_variation_store.is_some()
+    }
+
+    /// Returns the deltas for `count` consecutive variable values starting
+    /// at `var_index_base`, at a normalized location.
+    ///
+    /// Deltas are in the units of the binary representation of each value,
+    /// so deltas for `F2DOT14` values need dividing by 16384.
+    pub fn deltas(&self, var_index_base: uint32, count: usize, coords: &[f32]) -> Vec<f32> {
+        let store = match &self.item_variation_store {
+            Some(store) if var_index_base != 0xFFFFFFFF => store,
+            _ => return vec![0.0; count],
+        };
+        (0..count as u32)
+            .map(|i| {
+                let var_idx = var_index_base + i;
+                let var_idx = match &self.var_index_map {
+                    Some(map) => map.get(var_idx as usize).unwrap_or(0xFFFFFFFF),
+                    None => var_idx,
+                };
+                store.get_delta(var_idx, coords)
+            })
+            .collect()
+    }
+}
+
+fn read_records<T: Deserialize>(
+    c: &mut ReaderContext,
+    offset: uint32,
+    count: uint16,
+) -> Result<Vec<T>, DeserializationError> {
+    if offset == 0 || count == 0 {
+        return Ok(vec![]);
+    }
+    c.ptr = c.top_of_table() + offset as usize;
+    c.de_counted(count.into())
+}
+
+impl Deserialize for COLR {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        c.push();
+        let version: uint16 = c.de()?;
+        let num_base_glyph_records: uint16 = c.de()?;
+        let base_glyph_records_offset: uint32 = c.de()?;
+        let layer_records_offset: uint32 = c.de()?;
+        let num_layer_records: uint16 = c.de()?;
+        let mut colr = COLR::default();
+        if version > 0 {
+            let base_glyph_list: Offset32<BaseGlyphList> = c.de()?;
+            let layer_list: Offset32<LayerList> = c.de()?;
+            let clip_list: Offset32<ClipList> = c.de()?;
+            let var_index_map: Offset32<DeltaSetIndexMap> = c.de()?;
+            let item_variation_store: Offset32<ItemVariationStore> = c.de()?;
+            if let Some(list) = base_glyph_list.link {
+                for record in list.baseGlyphPaintRecords {
+                    let paint = record.paint.link.ok_or_else(|| {
+                        DeserializationError("Base glyph paint record with no paint".to_string())
+                    })?;
+                    colr.base_glyph_paints.insert(record.glyphID, paint);
+                }
+            }
+            if let Some(list) = layer_list.link {
+                colr.layers = list.paints.try_into()?;
+            }
+            if let Some(list) = clip_list.link {
+                for clip in list.clips {
+                    if let Some(clip_box) = clip.clipBox.link {
+                        for glyph in clip.startGlyphID..=clip.endGlyphID {
+                            colr.clips.insert(glyph, clip_box);
+                        }
+                    }
+                }
+            }
+            colr.var_index_map = var_index_map.link;
+            colr.item_variation_store = item_variation_store.link;
+        }
+        let base_glyph_records: Vec<BaseGlyphRecord> =
+            read_records(c, base_glyph_records_offset, num_base_glyph_records)?;
+        let layer_records: Vec<LayerRecord> =
+            read_records(c, layer_records_offset, num_layer_records)?;
+        for record in base_glyph_records {
+            let start = record.firstLayerIndex as usize;
+            let layers = layer_records
+                .get(start..start + record.numLayers as usize)
+                .ok_or_else(|| {
+                    DeserializationError("COLR base glyph runs off layer records".to_string())
+                })?;
+            colr.base_glyphs.insert(record.glyphID, layers.to_vec());
+        }
+        c.pop();
+        Ok(colr)
+    }
+}
+
+fn list_offset<T>(present: bool, list: impl FnOnce() -> T) -> Offset32<T> {
+    if present {
+        Offset32::to(list())
+    } else {
+        Offset32::to_nothing()
+    }
+}
+
+impl Serialize for COLR {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let mut base_glyph_records: Vec<BaseGlyphRecord> = vec![];
+        let mut layer_records: Vec<LayerRecord> = vec![];
+        for (&glyph, layers) in &self.base_glyphs {
+            base_glyph_records.push(BaseGlyphRecord {
+                glyphID: glyph,
+                firstLayerIndex: layer_records.len() as uint16,
+                numLayers: layers.len() as uint16,
+            });
+            layer_records.extend(layers.iter().cloned());
+        }
+
+        // Runs of consecutive glyphs with the same clip box share a record
+        let mut clips: Vec<Clip> = vec![];
+        for (&glyph, clip_box) in &self.clips {
+            if let Some(last) = clips.last_mut() {
+                if last.endGlyphID + 1 == glyph && last.clipBox.link.as_ref() == Some(clip_box) {
+                    last.endGlyphID = glyph;
+                    continue;
+                }
+            }
+            clips.push(Clip {
+                startGlyphID: glyph,
+                endGlyphID: glyph,
+                clipBox: Offset24::to(*clip_box),
+            });
+        }
+
+        let v1 = self.is_v1();
+        COLRcore {
+            version: if v1 { 1 } else { 0 },
+            numBaseGlyphRecords: base_glyph_records.len() as uint16,
+            numLayerRecords: layer_records.len() as uint16,
+            baseGlyphRecords: list_offset(!base_glyph_records.is_empty(), || base_glyph_records),
+            layerRecords: list_offset(!layer_records.is_empty(), || layer_records),
+            baseGlyphList: v1.then(|| {
+                list_offset(!self.base_glyph_paints.is_empty(), || BaseGlyphList {
+                    baseGlyphPaintRecords: self
+                        .base_glyph_paints
+                        .iter()
+                        .map(|(&glyph, paint)| BaseGlyphPaintRecord {
+                            glyphID: glyph,
+                            paint: Offset32::to(paint.clone()),
+                        })
+                        .collect(),
+                })
+            }),
+            layerList: v1.then(|| {
+                list_offset(!self.layers.is_empty(), || LayerList {
+                    paints: self
+                        .layers
+                        .iter()
+                        .map(|paint| Offset32::to(paint.clone()))
+                        .collect::<Vec<_>>()
+                        .into(),
+                })
+            }),
+            clipList: v1.then(|| list_offset(!clips.is_empty(), || ClipList { format: 1, clips })),
+            varIndexMap: v1.then(|| {
+                list_offset(self.var_index_map.is_some(), || {
+                    self.var_index_map.clone().unwrap()
+                })
+            }),
+            itemVariationStore: v1.then(|| {
+                list_offset(self.item_variation_store.is_some(), || {
+                    self.item_variation_store.clone().unwrap()
+                })
+            }),
+        }
+        .to_bytes(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colr_v0_serde() {
+        let binary_colr = vec![
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02,
+            0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01,
+        ];
+        let colr: COLR = otspec::de::from_bytes(&binary_colr).unwrap();
+        let mut expected = COLR::default();
+        expected.base_glyphs.insert(
+            5,
+            vec![
+                LayerRecord {
+                    glyphID: 6,
+                    paletteIndex: 0,
+                },
+                LayerRecord {
+                    glyphID: 7,
+                    paletteIndex: 1,
+                },
+            ],
+        );
+        assert_eq!(colr, expected);
+        assert_eq!(otspec::ser::to_bytes(&colr).unwrap(), binary_colr);
+    }
+
+    #[test]
+    fn colr_v1_serde() {
+        // A base glyph list pointing to PaintGlyph(glyph 6, PaintSolid)
+        let binary_colr = vec![
+            0x00, 0x01, // version
+            0x00, 0x00, // numBaseGlyphRecords
+            0x00, 0x00, 0x00, 0x00, // baseGlyphRecordsOffset
+            0x00, 0x00, 0x00, 0x00, // layerRecordsOffset
+            0x00, 0x00, // numLayerRecords
+            0x00, 0x00, 0x00, 0x22, // baseGlyphListOffset
+            0x00, 0x00, 0x00, 0x00, // layerListOffset
+            0x00, 0x00, 0x00, 0x37, // clipListOffset
+            0x00, 0x00, 0x00, 0x00, // varIndexMapOffset
+            0x00, 0x00, 0x00, 0x00, // itemVariationStoreOffset
+            // BaseGlyphList @ 0x22
+            0x00, 0x00, 0x00, 0x01, // numBaseGlyphPaintRecords
+            0x00, 0x05, 0x00, 0x00, 0x00, 0x0a, // glyph 5, paint @ +10
+            // PaintGlyph @ 0x2c
+            0x0a, 0x00, 0x00, 0x06, 0x00, 0x06, // format 10, paint @ +6, glyph 6
+            // PaintSolid @ 0x32
+            0x02, 0x00, 0x03, 0x40, 0x00, // format 2, palette 3, alpha 1.0
+            // ClipList @ 0x37
+            0x01, // format
+            0x00, 0x00, 0x00, 0x01, // numClips
+            0x00, 0x05, 0x00, 0x06, 0x00, 0x00, 0x0c, // glyphs 5-6, clip box @ +12
+            0x01, 0x00, 0x00, 0xff, 0x38, 0x01, 0xf4, 0x03, 0xe8, // ClipBox format 1
+        ];
+        let colr: COLR = otspec::de::from_bytes(&binary_colr).unwrap();
+        let clip_box = ClipBox {
+            xMin: 0,
+            yMin: -200,
+            xMax: 500,
+            yMax: 1000,
+            varIndexBase: None,
+        };
+        let mut expected = COLR::default();
+        expected.base_glyph_paints.insert(
+            5,
+            Paint::Glyph(Box::new(PaintGlyph {
+                format: 10,
+                paint: Offset24::to(Paint::Solid(Box::new(PaintSolid {
+                    format: 2,
+                    paletteIndex: 3,
+                    alpha: 1.0,
+                }))),
+                glyphID: 6,
+            })),
+        );
+        expected.clips.insert(5, clip_box);
+        expected.clips.insert(6, clip_box);
+        assert_eq!(colr, expected);
+
+        assert_eq!(otspec::ser::to_bytes(&colr).unwrap(), binary_colr);
+    }
+
+    #[test]
+    fn colr_v1_shared_paint() {
+        // Two PaintGlyphs sharing one PaintSolid
+        let binary_colr = vec![
+            0x00, 0x01, // version
+            0x00, 0x00, // numBaseGlyphRecords
+            0x00, 0x00, 0x00, 0x00, // baseGlyphRecordsOffset
+            0x00, 0x00, 0x00, 0x00, // layerRecordsOffset
+            0x00, 0x00, // numLayerRecords
+            0x00, 0x00, 0x00, 0x22, // baseGlyphListOffset
+            0x00, 0x00, 0x00, 0x00, // layerListOffset
+            0x00, 0x00, 0x00, 0x00, // clipListOffset
+            0x00, 0x00, 0x00, 0x00, // varIndexMapOffset
+            0x00, 0x00, 0x00, 0x00, // itemVariationStoreOffset
+            // BaseGlyphList @ 0x22
+            0x00, 0x00, 0x00, 0x02, // numBaseGlyphPaintRecords
+            0x00, 0x05, 0x00, 0x00, 0x00, 0x10, // glyph 5, paint @ +16
+            0x00, 0x07, 0x00, 0x00, 0x00, 0x16, // glyph 7, paint @ +22
+            // PaintGlyph @ 0x32
+            0x0a, 0x00, 0x00, 0x0c, 0x00, 0x06, // format 10, paint @ +12, glyph 6
+            // PaintGlyph @ 0x38
+            0x0a, 0x00, 0x00, 0x06, 0x00, 0x08, // format 10, paint @ +6, glyph 8
+            // PaintSolid @ 0x3e
+            0x02, 0x00, 0x03, 0x40, 0x00, // format 2, palette 3, alpha 1.0
+        ];
+        let colr: COLR = otspec::de::from_bytes(&binary_colr).unwrap();
+        let glyph = |glyph_id| {
+            Paint::Glyph(Box::new(PaintGlyph {
+                format: 10,
+                paint: Offset24::to(Paint::Solid(Box::new(PaintSolid {
+                    format: 2,
+                    paletteIndex: 3,
+                    alpha: 1.0,
+                }))),
+                glyphID: glyph_id,
+            }))
+        };
+        let mut expected = COLR::default();
+        expected.base_glyph_paints.insert(5, glyph(6));
+        expected.base_glyph_paints.insert(7, glyph(8));
+        assert_eq!(colr, expected);
+
+        assert_eq!(otspec::ser::to_bytes(&colr).unwrap(), binary_colr);
+    }
+
+    #[test]
+    fn colr_v1_roundtrip() {
+        let solid = |palette_index| {
+            Paint::Solid(Box::new(PaintSolid {
+                format: 2,
+                paletteIndex: palette_index,
+                alpha: 1.0,
+            }))
+        };
+        let gradient = Paint::VarLinearGradient(Box::new(PaintVarLinearGradient {
+            format: 5,
+            colorLine: Offset24::to(VarColorLine {
+                extend: EXTEND_REFLECT,
+                colorStops: vec![
+                    VarColorStop {
+                        stopOffset: 0.0,
+                        paletteIndex: 0,
+                        alpha: 1.0,
+                        varIndexBase: 0xFFFFFFFF,
+                    },
+                    VarColorStop {
+                        stopOffset: 1.0,
+                        paletteIndex: 1,
+                        alpha: 0.5,
+                        varIndexBase: 0xFFFFFFFF,
+                    },
+                ],
+            }),
+            x0: 0,
+            y0: 0,
+            x1: 500,
+            y1: 0,
+            x2: 0,
+            y2: 500,
+            varIndexBase: 0,
+        }));
+        let composite = Paint::Composite(Box::new(PaintComposite {
+            format: 32,
+            sourcePaint: Offset24::to(Paint::Glyph(Box::new(PaintGlyph {
+                format: 10,
+                paint: Offset24::to(gradient),
+                glyphID: 7,
+            }))),
+            compositeMode: 3,
+            backdropPaint: Offset24::to(Paint::Transform(Box::new(PaintTransform {
+                format: 12,
+                paint: Offset24::to(Paint::Glyph(Box::new(PaintGlyph {
+                    format: 10,
+                    paint: Offset24::to(solid(2)),
+                    glyphID: 8,
+                }))),
+                transform: Offset24::to(Affine2x3 {
+                    xx: 1.0,
+                    yx: 0.0,
+                    xy: 0.5,
+                    yy: 1.0,
+                    dx: 10.0,
+                    dy: -10.0,
+                }),
+            }))),
+        }));
+        let mut colr = COLR::default();
+        colr.base_glyphs.insert(
+            3,
+            vec![LayerRecord {
+                glyphID: 4,
+                paletteIndex: 0,
+            }],
+        );
+        colr.base_glyph_paints.insert(
+            5,
+            Paint::ColrLayers(Box::new(PaintColrLayers {
+                format: 1,
+                numLayers: 2,
+                firstLayerIndex: 0,
+            })),
+        );
+        colr.base_glyph_paints.insert(
+            9,
+            Paint::ColrGlyph(Box::new(PaintColrGlyph {
+                format: 11,
+                glyphID: 5,
+            })),
+        );
+        colr.layers = vec![composite, solid(1)];
+        colr.clips.insert(
+            5,
+            ClipBox {
+                xMin: 0,
+                yMin: 0,
+                xMax: 500,
+                yMax: 500,
+                varIndexBase: Some(0),
+            },
+        );
+        colr.var_index_map = Some(DeltaSetIndexMap {
+            entries: vec![
+                0, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF,
+            ],
+        });
+        colr.item_variation_store = Some(ItemVariationStore::new(
+            1,
+            vec![vec![crate::otvar::RegionAxisCoordinates {
+                startCoord: 0.0,
+                peakCoord: 1.0,
+                endCoord: 1.0,
+            }]],
+            vec![vec![100]],
+        ));
+        let binary_colr = otspec::ser::to_bytes(&colr).unwrap();
+        let deserialized: COLR = otspec::de::from_bytes(&binary_colr).unwrap();
+        assert_eq!(deserialized, colr);
+        assert_eq!(deserialized.deltas(0, 2, &[0.5]), vec![50.0, 0.0]);
+        assert_eq!(deserialized.layers[0].children().len(), 2);
+    }
+}
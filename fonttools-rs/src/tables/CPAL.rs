@@ -0,0 +1,273 @@
+use bitflags::bitflags;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::{tables, Deserialize, Serialize};
+
+/// The 'CPAL' OpenType tag.
+pub const TAG: Tag = crate::tag!("CPAL");
+
+tables!(
+    ColorRecord [serde] {
+        uint8 blue
+        uint8 green
+        uint8 red
+        uint8 alpha
+    }
+);
+
+impl ColorRecord {
+    /// Creates a color from its red, green, blue and alpha components.
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        ColorRecord {
+            blue,
+            green,
+            red,
+            alpha,
+        }
+    }
+}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
+    /// Flags describing the intended use of a palette
+    pub struct PaletteType: u32 {
+        /// Palette is appropriate for use on a light background
+        const USABLE_WITH_LIGHT_BACKGROUND = 0x0001;
+        /// Palette is appropriate for use on a dark background
+        const USABLE_WITH_DARK_BACKGROUND = 0x0002;
+    }
+}
+
+/// A name ID used to mark a palette or palette entry as having no label.
+pub const NO_LABEL: uint16 = 0xFFFF;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Color Palette table
+///
+/// If any of the palette types or labels are present, a version 1 table is
+/// written; otherwise a version 0 table is written.
+pub struct CPAL {
+    /// The palettes in this table. Each palette must have the same number
+    /// of entries.
+    pub palettes: Vec<Vec<ColorRecord>>,
+    /// The type of each palette.
+    pub palette_types: Option<Vec<PaletteType>>,
+    /// A `name` table ID for each palette, or `NO_LABEL`.
+    pub palette_labels: Option<Vec<uint16>>,
+    /// A `name` table ID for each palette entry, or `NO_LABEL`.
+    pub palette_entry_labels: Option<Vec<uint16>>,
+}
+
+impl CPAL {
+    /// The number of entries in each palette.
+    pub fn num_palette_entries(&self) -> usize {
+        self.palettes.first().map_or(0, |p| p.len())
+    }
+
+    /// Returns the color at the given index of a palette.
+    pub fn color(&self, palette: usize, index: usize) -> Option<&ColorRecord> {
+        self.palettes.get(palette).and_then(|p| p.get(index))
+    }
+}
+
+fn read_array<T: Deserialize>(
+    c: &mut ReaderContext,
+    offset: uint32,
+    count: usize,
+) -> Result<Option<Vec<T>>, DeserializationError> {
+    if offset == 0 {
+        return Ok(None);
+    }
+    c.ptr = c.top_of_table() + offset as usize;
+    Ok(Some(c.de_counted(count)?))
+}
+
+impl Deserialize for CPAL {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        c.push();
+        let version: uint16 = c.de()?;
+        let num_palette_entries: uint16 = c.de()?;
+        let num_palettes: uint16 = c.de()?;
+        let num_color_records: uint16 = c.de()?;
+        let color_records_offset: uint32 = c.de()?;
+        let color_record_indices: Vec<uint16> = c.de_counted(num_palettes.into())?;
+        let (types_offset, labels_offset, entry_labels_offset) = if version > 0 {
+            (c.de()?, c.de()?, c.de()?)
+        } else {
+            (0, 0, 0)
+        };
+        let color_records: Vec<ColorRecord> =
+            read_array(c, color_records_offset, num_color_records.into())?.unwrap_or_default();
+        let palettes = color_record_indices
+            .iter()
+            .map(|&start| {
+                let start = start as usize;
+                color_records
+                    .get(start..start + num_palette_entries as usize)
+                    .map(|p| p.to_vec())
+                    .ok_or_else(|| {
+                        DeserializationError("CPAL palette runs off color records".to_string())
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let palette_types = read_array(c, types_offset, num_palettes.into())?;
+        let palette_labels = read_array(c, labels_offset, num_palettes.into())?;
+        let palette_entry_labels = read_array(c, entry_labels_offset, num_palette_entries.into())?;
+        c.pop();
+        Ok(CPAL {
+            palettes,
+            palette_types,
+            palette_labels,
+            palette_entry_labels,
+        })
+    }
+}
+
+impl Serialize for CPAL {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let num_entries = self.num_palette_entries();
+        if self.palettes.iter().any(|p| p.len() != num_entries) {
+            return Err(SerializationError(
+                "CPAL palettes have differing numbers of entries".to_string(),
+            ));
+        }
+        // Identical palettes share their color records
+        let mut color_records: Vec<ColorRecord> = vec![];
+        let mut indices: Vec<uint16> = vec![];
+        for (ix, palette) in self.palettes.iter().enumerate() {
+            let start = match self.palettes[..ix].iter().position(|p| p == palette) {
+                Some(earlier) => indices[earlier],
+                None => {
+                    color_records.extend(palette.iter().cloned());
+                    (color_records.len() - num_entries) as uint16
+                }
+            };
+            indices.push(start);
+        }
+
+        let version: uint16 = if self.palette_types.is_some()
+            || self.palette_labels.is_some()
+            || self.palette_entry_labels.is_some()
+        {
+            1
+        } else {
+            0
+        };
+        let header_size = 12 + 2 * indices.len() + if version > 0 { 12 } else { 0 };
+        let mut offset = header_size + 4 * color_records.len();
+        let mut next_offset = |present: bool, size: usize| -> uint32 {
+            if !present {
+                return 0;
+            }
+            let this = offset;
+            offset += size;
+            this as uint32
+        };
+        let types_offset = next_offset(self.palette_types.is_some(), 4 * self.palettes.len());
+        let labels_offset = next_offset(self.palette_labels.is_some(), 2 * self.palettes.len());
+        let entry_labels_offset = next_offset(self.palette_entry_labels.is_some(), 2 * num_entries);
+
+        version.to_bytes(data)?;
+        (num_entries as uint16).to_bytes(data)?;
+        (self.palettes.len() as uint16).to_bytes(data)?;
+        (color_records.len() as uint16).to_bytes(data)?;
+        (header_size as uint32).to_bytes(data)?;
+        indices.to_bytes(data)?;
+        if version > 0 {
+            types_offset.to_bytes(data)?;
+            labels_offset.to_bytes(data)?;
+            entry_labels_offset.to_bytes(data)?;
+        }
+        color_records.to_bytes(data)?;
+        if let Some(types) = &self.palette_types {
+            if types.len() != self.palettes.len() {
+                return Err(SerializationError(
+                    "CPAL needs one palette type per palette".to_string(),
+                ));
+            }
+            types.to_bytes(data)?;
+        }
+        if let Some(labels) = &self.palette_labels {
+            if labels.len() != self.palettes.len() {
+                return Err(SerializationError(
+                    "CPAL needs one label per palette".to_string(),
+                ));
+            }
+            labels.to_bytes(data)?;
+        }
+        if let Some(labels) = &self.palette_entry_labels {
+            if labels.len() != num_entries {
+                return Err(SerializationError(
+                    "CPAL needs one label per palette entry".to_string(),
+                ));
+            }
+            labels.to_bytes(data)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpal_serde_v0() {
+        let binary_cpal = vec![
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0xff, 0xff, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xff,
+            0x00, 0xff, 0x00, 0x80,
+        ];
+        let cpal: CPAL = otspec::de::from_bytes(&binary_cpal).unwrap();
+        let expected = CPAL {
+            palettes: vec![
+                vec![
+                    ColorRecord::new(255, 0, 0, 255),
+                    ColorRecord::new(0, 0, 255, 255),
+                ],
+                vec![
+                    ColorRecord::new(0, 0, 0, 255),
+                    ColorRecord::new(0, 255, 0, 128),
+                ],
+            ],
+            palette_types: None,
+            palette_labels: None,
+            palette_entry_labels: None,
+        };
+        assert_eq!(cpal, expected);
+        assert_eq!(otspec::ser::to_bytes(&cpal).unwrap(), binary_cpal);
+    }
+
+    #[test]
+    fn cpal_serde_v1() {
+        let red = ColorRecord::new(255, 0, 0, 255);
+        let cpal = CPAL {
+            palettes: vec![vec![red.clone()], vec![red]],
+            palette_types: Some(vec![
+                PaletteType::USABLE_WITH_LIGHT_BACKGROUND,
+                PaletteType::USABLE_WITH_DARK_BACKGROUND,
+            ]),
+            palette_labels: None,
+            palette_entry_labels: Some(vec![256]),
+        };
+        let binary_cpal = otspec::ser::to_bytes(&cpal).unwrap();
+        assert_eq!(
+            binary_cpal,
+            vec![
+                0x00, 0x01, 0x00, 0x01, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+                0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00
+            ]
+        );
+        let deserialized: CPAL = otspec::de::from_bytes(&binary_cpal).unwrap();
+        assert_eq!(deserialized, cpal);
+    }
+}
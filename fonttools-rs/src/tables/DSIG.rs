@@ -0,0 +1,125 @@
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+
+/// The 'DSIG' OpenType tag.
+pub const TAG: Tag = crate::tag!("DSIG");
+
+/// A digital signature over the font
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signature {
+    /// The format of the signature (currently always 1)
+    pub format: uint32,
+    /// The PKCS#7 packet containing the signature
+    pub signature: Vec<u8>,
+}
+
+/// Digital Signature Table
+///
+/// The default table contains no signatures; some older applications expect
+/// such a "minimal" table to be present.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct DSIG {
+    /// Permission flags; bit 0 prohibits resigning the table
+    pub flags: uint16,
+    /// The signatures
+    pub signatures: Vec<Signature>,
+}
+
+impl Deserialize for DSIG {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let version: uint32 = c.de()?;
+        if version != 1 {
+            return Err(DeserializationError(format!(
+                "Unknown DSIG version {}",
+                version
+            )));
+        }
+        let num_signatures: uint16 = c.de()?;
+        let flags: uint16 = c.de()?;
+        let mut records = Vec::with_capacity(num_signatures as usize);
+        for _ in 0..num_signatures {
+            let format: uint32 = c.de()?;
+            let _length: uint32 = c.de()?;
+            let offset: uint32 = c.de()?;
+            records.push((format, offset));
+        }
+        let mut signatures = Vec::with_capacity(records.len());
+        for (format, offset) in records {
+            c.ptr = offset as usize;
+            let _reserved1: uint16 = c.de()?;
+            let _reserved2: uint16 = c.de()?;
+            let signature_length: uint32 = c.de()?;
+            signatures.push(Signature {
+                format,
+                signature: c.de_counted(signature_length as usize)?,
+            });
+        }
+        Ok(DSIG { flags, signatures })
+    }
+}
+
+impl Serialize for DSIG {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        1_u32.to_bytes(data)?;
+        (self.signatures.len() as uint16).to_bytes(data)?;
+        self.flags.to_bytes(data)?;
+        let mut offset = 8 + 12 * self.signatures.len();
+        for signature in &self.signatures {
+            let length = 8 + signature.signature.len();
+            signature.format.to_bytes(data)?;
+            (length as uint32).to_bytes(data)?;
+            (offset as uint32).to_bytes(data)?;
+            offset += length;
+        }
+        for signature in &self.signatures {
+            0_u16.to_bytes(data)?;
+            0_u16.to_bytes(data)?;
+            (signature.signature.len() as uint32).to_bytes(data)?;
+            data.extend(&signature.signature);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dsig_minimal() {
+        let binary_dsig = vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00];
+        let fdsig: DSIG = otspec::de::from_bytes(&binary_dsig).unwrap();
+        assert_eq!(fdsig, DSIG::default());
+        assert_eq!(otspec::ser::to_bytes(&fdsig).unwrap(), binary_dsig);
+    }
+
+    #[test]
+    fn dsig_serde() {
+        let binary_dsig = vec![
+            0x00, 0x00, 0x00, 0x01, // version
+            0x00, 0x01, // numSignatures
+            0x00, 0x01, // flags
+            0x00, 0x00, 0x00, 0x01, // format
+            0x00, 0x00, 0x00, 0x0b, // length
+            0x00, 0x00, 0x00, 0x14, // offset
+            0x00, 0x00, 0x00, 0x00, // reserved
+            0x00, 0x00, 0x00, 0x03, // signatureLength
+            0x30, 0x82, 0x01, // signature
+        ];
+        let fdsig: DSIG = otspec::de::from_bytes(&binary_dsig).unwrap();
+        let expected = DSIG {
+            flags: 1,
+            signatures: vec![Signature {
+                format: 1,
+                signature: vec![0x30, 0x82, 0x01],
+            }],
+        };
+        assert_eq!(fdsig, expected);
+        assert_eq!(otspec::ser::to_bytes(&fdsig).unwrap(), binary_dsig);
+    }
+}
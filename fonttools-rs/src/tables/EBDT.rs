@@ -0,0 +1,379 @@
+use crate::tables::EBLC::{BigGlyphMetrics, BitmapSize, SmallGlyphMetrics, EBLC};
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serialize};
+use otspec_macros::tables;
+use std::collections::BTreeMap;
+
+/// The 'EBDT' OpenType tag.
+pub const TAG: Tag = crate::tag!("EBDT");
+
+tables!(
+    EbdtComponent [serde] {
+        uint16 glyphID
+        i8 xOffset
+        i8 yOffset
+    }
+);
+
+/// The metrics of a bitmap glyph
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GlyphMetrics {
+    /// Metrics for one text direction
+    Small(SmallGlyphMetrics),
+    /// Metrics for both horizontal and vertical text
+    Big(BigGlyphMetrics),
+}
+
+/// The contents of a bitmap glyph
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitmapData {
+    /// Image data: a bitmap for image formats 1-7, PNG data for 17-19
+    Image(Vec<u8>),
+    /// A glyph made up of other bitmap glyphs (image formats 8 and 9)
+    Components(Vec<EbdtComponent>),
+}
+
+/// A glyph image in a bitmap data table
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitmapGlyph {
+    /// The image format: 1-9 in `EBDT`, 17-19 in `CBDT`
+    pub image_format: uint16,
+    /// The glyph's metrics. Image formats 5 and 19 store their metrics in
+    /// the location table, and must have big metrics.
+    pub metrics: GlyphMetrics,
+    /// The image data or components
+    pub data: BitmapData,
+}
+
+impl BitmapGlyph {
+    /// Creates a glyph holding PNG data with small metrics (image format 17).
+    pub fn png(metrics: SmallGlyphMetrics, data: Vec<u8>) -> Self {
+        BitmapGlyph {
+            image_format: 17,
+            metrics: GlyphMetrics::Small(metrics),
+            data: BitmapData::Image(data),
+        }
+    }
+
+    /// The image data of this glyph, unless it is a composite.
+    pub fn image(&self) -> Option<&[u8]> {
+        match &self.data {
+            BitmapData::Image(data) => Some(data),
+            BitmapData::Components(_) => None,
+        }
+    }
+
+    /// The metrics which are stored in the location table rather than with
+    /// the image.
+    pub(crate) fn shared_metrics(&self) -> Option<BigGlyphMetrics> {
+        match (self.image_format, &self.metrics) {
+            (5 | 19, GlyphMetrics::Big(metrics)) => Some(metrics.clone()),
+            _ => None,
+        }
+    }
+
+    fn from_bytes(
+        c: &mut ReaderContext,
+        image_format: uint16,
+        length: usize,
+        shared_metrics: Option<&BigGlyphMetrics>,
+    ) -> Result<Self, DeserializationError> {
+        let end = c.ptr + length;
+        let metrics = match image_format {
+            1 | 2 | 8 | 17 => GlyphMetrics::Small(c.de()?),
+            6 | 7 | 9 | 18 => GlyphMetrics::Big(c.de()?),
+            5 | 19 => GlyphMetrics::Big(shared_metrics.cloned().ok_or_else(|| {
+                DeserializationError(format!(
+                    "Image format {} needs metrics in the index subtable",
+                    image_format
+                ))
+            })?),
+            _ => {
+                return Err(DeserializationError(format!(
+                    "Unsupported bitmap image format {}",
+                    image_format
+                )))
+            }
+        };
+        let data = match image_format {
+            8 | 9 => {
+                if image_format == 8 {
+                    let _pad: uint8 = c.de()?;
+                }
+                let num_components: uint16 = c.de()?;
+                BitmapData::Components(c.de_counted(num_components as usize)?)
+            }
+            17..=19 => {
+                let data_len: uint32 = c.de()?;
+                BitmapData::Image(c.de_counted(data_len as usize)?)
+            }
+            _ => {
+                let remaining = end.checked_sub(c.ptr).ok_or_else(|| {
+                    DeserializationError("Bitmap glyph record too short".to_string())
+                })?;
+                BitmapData::Image(c.de_counted(remaining)?)
+            }
+        };
+        Ok(BitmapGlyph {
+            image_format,
+            metrics,
+            data,
+        })
+    }
+}
+
+impl Serialize for BitmapGlyph {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let wrong_metrics = || {
+            SerializationError(format!(
+                "Wrong kind of metrics for image format {}",
+                self.image_format
+            ))
+        };
+        match (self.image_format, &self.metrics) {
+            (1 | 2 | 8 | 17, GlyphMetrics::Small(metrics)) => metrics.to_bytes(data)?,
+            (6 | 7 | 9 | 18, GlyphMetrics::Big(metrics)) => metrics.to_bytes(data)?,
+            (5 | 19, GlyphMetrics::Big(_)) => {}
+            (1 | 2 | 5..=9 | 17..=19, _) => return Err(wrong_metrics()),
+            _ => {
+                return Err(SerializationError(format!(
+                    "Unsupported bitmap image format {}",
+                    self.image_format
+                )))
+            }
+        }
+        match (self.image_format, &self.data) {
+            (8 | 9, BitmapData::Components(components)) => {
+                if self.image_format == 8 {
+                    0_u8.to_bytes(data)?;
+                }
+                (components.len() as uint16).to_bytes(data)?;
+                components.to_bytes(data)
+            }
+            (17..=19, BitmapData::Image(image)) => {
+                (image.len() as uint32).to_bytes(data)?;
+                data.extend(image);
+                Ok(())
+            }
+            (1 | 2 | 5..=7, BitmapData::Image(image)) => {
+                data.extend(image);
+                Ok(())
+            }
+            _ => Err(SerializationError(format!(
+                "Wrong kind of data for image format {}",
+                self.image_format
+            ))),
+        }
+    }
+}
+
+/// The glyph images of a strike, by glyph ID
+pub type BitmapStrike = BTreeMap<GlyphID, BitmapGlyph>;
+
+/// Embedded Bitmap Data table
+///
+/// The strikes correspond to the strikes of the `EBLC` table, which is
+/// updated to locate the images when the font is saved.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EBDT {
+    /// The glyph images of each strike
+    pub strikes: Vec<BitmapStrike>,
+}
+
+impl EBDT {
+    /// Returns a glyph's image in the given strike.
+    pub fn glyph(&self, strike: usize, glyph_id: GlyphID) -> Option<&BitmapGlyph> {
+        self.strikes.get(strike)?.get(&glyph_id)
+    }
+
+    /// Sets a glyph's image in the given strike, returning the old image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strike does not exist.
+    pub fn set_glyph(
+        &mut self,
+        strike: usize,
+        glyph_id: GlyphID,
+        glyph: BitmapGlyph,
+    ) -> Option<BitmapGlyph> {
+        self.strikes[strike].insert(glyph_id, glyph)
+    }
+
+    /// Removes a glyph's image from the given strike.
+    pub fn remove_glyph(&mut self, strike: usize, glyph_id: GlyphID) -> Option<BitmapGlyph> {
+        self.strikes.get_mut(strike)?.remove(&glyph_id)
+    }
+}
+
+impl Serialize for EBDT {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        write_strikes(2, &self.strikes, data)
+    }
+}
+
+/// Deserializes an Embedded Bitmap Data table given a binary vector and the
+/// `EBLC` table locating its images.
+pub fn from_bytes(data: &[u8], eblc: &EBLC) -> Result<EBDT, DeserializationError> {
+    Ok(EBDT {
+        strikes: read_strikes(data, &eblc.strikes)?,
+    })
+}
+
+/// Reads the glyph images of an `EBDT` or `CBDT` table.
+pub(crate) fn read_strikes(
+    data: &[u8],
+    sizes: &[BitmapSize],
+) -> Result<Vec<BitmapStrike>, DeserializationError> {
+    let mut c = ReaderContext::new(data.to_vec());
+    let mut strikes = Vec::with_capacity(sizes.len());
+    for size in sizes {
+        let mut strike = BitmapStrike::new();
+        for st in &size.index_subtables {
+            for location in &st.glyphs {
+                c.ptr = location.offset as usize;
+                let glyph = BitmapGlyph::from_bytes(
+                    &mut c,
+                    st.image_format,
+                    location.length as usize,
+                    st.metrics.as_ref(),
+                )?;
+                strike.insert(location.glyph_id, glyph);
+            }
+        }
+        strikes.push(strike);
+    }
+    Ok(strikes)
+}
+
+/// Writes an `EBDT` or `CBDT` table with the given major version.
+///
+/// The images are laid out in strike and glyph ID order; see
+/// [`crate::tables::EBLC::locate_glyphs`].
+pub(crate) fn write_strikes(
+    major_version: uint16,
+    strikes: &[BitmapStrike],
+    data: &mut Vec<u8>,
+) -> Result<(), SerializationError> {
+    major_version.to_bytes(data)?;
+    0_u16.to_bytes(data)?;
+    for glyph in strikes.iter().flat_map(|s| s.values()) {
+        glyph.to_bytes(data)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::EBLC::SbitLineMetrics;
+
+    fn line_metrics() -> SbitLineMetrics {
+        SbitLineMetrics {
+            ascender: 12,
+            descender: -4,
+            widthMax: 10,
+            caretSlopeNumerator: 1,
+            caretSlopeDenominator: 0,
+            caretOffset: 0,
+            minOriginSB: 0,
+            minAdvanceSB: 0,
+            maxBeforeBL: 0,
+            minAfterBL: 0,
+            pad1: 0,
+            pad2: 0,
+        }
+    }
+
+    fn big_metrics() -> BigGlyphMetrics {
+        BigGlyphMetrics {
+            height: 2,
+            width: 8,
+            horiBearingX: 0,
+            horiBearingY: 2,
+            horiAdvance: 8,
+            vertBearingX: -4,
+            vertBearingY: 0,
+            vertAdvance: 2,
+        }
+    }
+
+    #[test]
+    fn ebdt_eblc_roundtrip() {
+        let small = SmallGlyphMetrics {
+            height: 1,
+            width: 8,
+            bearingX: 0,
+            bearingY: 1,
+            advance: 8,
+        };
+        let mut strike = BitmapStrike::new();
+        strike.insert(
+            1,
+            BitmapGlyph {
+                image_format: 1,
+                metrics: GlyphMetrics::Small(small),
+                data: BitmapData::Image(vec![0xff]),
+            },
+        );
+        for glyph_id in 2..=3 {
+            strike.insert(
+                glyph_id,
+                BitmapGlyph {
+                    image_format: 5,
+                    metrics: GlyphMetrics::Big(big_metrics()),
+                    data: BitmapData::Image(vec![0xaa, glyph_id as u8]),
+                },
+            );
+        }
+        strike.insert(
+            5,
+            BitmapGlyph {
+                image_format: 9,
+                metrics: GlyphMetrics::Big(big_metrics()),
+                data: BitmapData::Components(vec![EbdtComponent {
+                    glyphID: 1,
+                    xOffset: 0,
+                    yOffset: 1,
+                }]),
+            },
+        );
+        let ebdt = EBDT {
+            strikes: vec![strike],
+        };
+        let mut eblc = EBLC {
+            strikes: vec![BitmapSize {
+                color_ref: 0,
+                hori: line_metrics(),
+                vert: line_metrics(),
+                ppem_x: 16,
+                ppem_y: 16,
+                bit_depth: 1,
+                flags: 1,
+                index_subtables: vec![],
+            }],
+        };
+        eblc.update_locations(&ebdt).unwrap();
+        let formats: Vec<_> = eblc.strikes[0]
+            .index_subtables
+            .iter()
+            .map(|st| (st.first_glyph_index, st.index_format, st.image_format))
+            .collect();
+        assert_eq!(formats, vec![(1, 3, 1), (2, 2, 5), (5, 3, 9)]);
+        let (_, location) = eblc.strikes[0].locate(3).unwrap();
+        assert_eq!((location.offset, location.length), (12, 2));
+
+        let binary_eblc = otspec::ser::to_bytes(&eblc).unwrap();
+        let binary_ebdt = otspec::ser::to_bytes(&ebdt).unwrap();
+        let eblc2: EBLC = otspec::de::from_bytes(&binary_eblc).unwrap();
+        assert_eq!(eblc2, eblc);
+        let ebdt2 = from_bytes(&binary_ebdt, &eblc2).unwrap();
+        assert_eq!(ebdt2, ebdt);
+        assert_eq!(ebdt2.glyph(0, 2).unwrap().image(), Some(&[0xaa, 0x02][..]));
+        assert!(ebdt2.glyph(0, 4).is_none());
+    }
+}
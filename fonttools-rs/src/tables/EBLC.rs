@@ -0,0 +1,752 @@
+use crate::tables::EBDT::{BitmapStrike, EBDT};
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::tables;
+
+/// The 'EBLC' OpenType tag.
+pub const TAG: Tag = crate::tag!("EBLC");
+
+tables!(
+    SbitLineMetrics [serde] [default] {
+        i8 ascender
+        i8 descender
+        uint8 widthMax
+        i8 caretSlopeNumerator
+        i8 caretSlopeDenominator
+        i8 caretOffset
+        i8 minOriginSB
+        i8 minAdvanceSB
+        i8 maxBeforeBL
+        i8 minAfterBL
+        i8 pad1
+        i8 pad2
+    }
+
+    BigGlyphMetrics [serde] [default] {
+        uint8 height
+        uint8 width
+        i8 horiBearingX
+        i8 horiBearingY
+        uint8 horiAdvance
+        i8 vertBearingX
+        i8 vertBearingY
+        uint8 vertAdvance
+    }
+
+    SmallGlyphMetrics [serde] [default] {
+        uint8 height
+        uint8 width
+        i8 bearingX
+        i8 bearingY
+        uint8 advance
+    }
+
+    BitmapSizeRecord {
+        uint32 indexSubTableArrayOffset
+        uint32 indexTablesSize
+        uint32 numberOfIndexSubTables
+        uint32 colorRef
+        SbitLineMetrics hori
+        SbitLineMetrics vert
+        uint16 startGlyphIndex
+        uint16 endGlyphIndex
+        uint8 ppemX
+        uint8 ppemY
+        uint8 bitDepth
+        i8 flags
+    }
+
+    IndexSubTableRecord {
+        uint16 firstGlyphIndex
+        uint16 lastGlyphIndex
+        uint32 additionalOffsetToIndexSubtable
+    }
+
+    GlyphIdOffsetPair {
+        uint16 glyphID
+        uint16 sbitOffset
+    }
+);
+
+/// The location of a glyph image in the bitmap data table
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlyphLocation {
+    /// The glyph ID
+    pub glyph_id: GlyphID,
+    /// The offset of the image from the start of the data table
+    pub offset: uint32,
+    /// The length of the image data in bytes
+    pub length: uint32,
+}
+
+/// A range of glyphs whose images share an index format and image format
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexSubTable {
+    /// The first glyph ID in the range
+    pub first_glyph_index: GlyphID,
+    /// The last glyph ID in the range
+    pub last_glyph_index: GlyphID,
+    /// The format of this index subtable (1-5)
+    pub index_format: uint16,
+    /// The format of the glyph images in the data table
+    pub image_format: uint16,
+    /// Metrics shared by every glyph in the range (index formats 2 and 5)
+    pub metrics: Option<BigGlyphMetrics>,
+    /// The glyphs in the range which have images, in glyph ID order
+    pub glyphs: Vec<GlyphLocation>,
+}
+
+/// A strike: a set of bitmaps for one size and bit depth
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitmapSize {
+    /// Unused; should be zero
+    pub color_ref: uint32,
+    /// Line metrics for horizontal text
+    pub hori: SbitLineMetrics,
+    /// Line metrics for vertical text
+    pub vert: SbitLineMetrics,
+    /// Horizontal pixels per em
+    pub ppem_x: uint8,
+    /// Vertical pixels per em
+    pub ppem_y: uint8,
+    /// Bits per pixel: 1, 2, 4 or 8 (or 32 for color bitmaps)
+    pub bit_depth: uint8,
+    /// Bit 0 marks horizontal metrics, bit 1 vertical metrics
+    pub flags: i8,
+    /// The index subtables locating this strike's glyph images
+    pub index_subtables: Vec<IndexSubTable>,
+}
+
+impl BitmapSize {
+    /// Finds the index subtable and location of a glyph's image.
+    pub fn locate(&self, glyph_id: GlyphID) -> Option<(&IndexSubTable, &GlyphLocation)> {
+        self.index_subtables.iter().find_map(|st| {
+            st.glyphs
+                .iter()
+                .find(|g| g.glyph_id == glyph_id)
+                .map(|location| (st, location))
+        })
+    }
+}
+
+/// Embedded Bitmap Location table
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EBLC {
+    /// The strikes in the font
+    pub strikes: Vec<BitmapSize>,
+}
+
+impl EBLC {
+    /// Rebuilds the index subtables to locate the glyph images in the given
+    /// data table.
+    ///
+    /// This is done automatically when a font with a modified `EBDT` table
+    /// is saved.
+    pub fn update_locations(&mut self, ebdt: &EBDT) -> Result<(), SerializationError> {
+        locate_glyphs(&mut self.strikes, &ebdt.strikes)
+    }
+}
+
+impl Deserialize for EBLC {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        Ok(EBLC {
+            strikes: read_strikes(c)?,
+        })
+    }
+}
+
+impl Serialize for EBLC {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        write_strikes(2, &self.strikes, data)
+    }
+}
+
+/// Reads the strikes of an `EBLC` or `CBLC` table.
+pub(crate) fn read_strikes(c: &mut ReaderContext) -> Result<Vec<BitmapSize>, DeserializationError> {
+    let _major_version: uint16 = c.de()?;
+    let _minor_version: uint16 = c.de()?;
+    let num_sizes: uint32 = c.de()?;
+    let records: Vec<BitmapSizeRecord> = c.de_counted(num_sizes as usize)?;
+    let mut strikes = Vec::with_capacity(records.len());
+    for record in records {
+        let array_start = record.indexSubTableArrayOffset as usize;
+        c.ptr = array_start;
+        let entries: Vec<IndexSubTableRecord> =
+            c.de_counted(record.numberOfIndexSubTables as usize)?;
+        let mut index_subtables = Vec::with_capacity(entries.len());
+        for entry in entries {
+            c.ptr = array_start + entry.additionalOffsetToIndexSubtable as usize;
+            index_subtables.push(read_index_subtable(
+                c,
+                entry.firstGlyphIndex,
+                entry.lastGlyphIndex,
+            )?);
+        }
+        strikes.push(BitmapSize {
+            color_ref: record.colorRef,
+            hori: record.hori,
+            vert: record.vert,
+            ppem_x: record.ppemX,
+            ppem_y: record.ppemY,
+            bit_depth: record.bitDepth,
+            flags: record.flags,
+            index_subtables,
+        });
+    }
+    Ok(strikes)
+}
+
+fn read_index_subtable(
+    c: &mut ReaderContext,
+    first: GlyphID,
+    last: GlyphID,
+) -> Result<IndexSubTable, DeserializationError> {
+    if last < first {
+        return Err(DeserializationError(format!(
+            "Index subtable range {}-{} is backwards",
+            first, last
+        )));
+    }
+    let index_format: uint16 = c.de()?;
+    let image_format: uint16 = c.de()?;
+    let image_data_offset: uint32 = c.de()?;
+    let count = (last - first) as usize + 1;
+    let located = |glyph_id, offset, length| GlyphLocation {
+        glyph_id,
+        offset: image_data_offset + offset,
+        length,
+    };
+    let mut metrics = None;
+    let glyphs: Vec<GlyphLocation> = match index_format {
+        1 | 3 => {
+            let offsets: Vec<uint32> = if index_format == 1 {
+                c.de_counted(count + 1)?
+            } else {
+                let offsets: Vec<uint16> = c.de_counted(count + 1)?;
+                offsets.into_iter().map(|o| o as uint32).collect()
+            };
+            (first..=last)
+                .zip(offsets.windows(2))
+                .filter(|(_, w)| w[1] > w[0])
+                .map(|(glyph_id, w)| located(glyph_id, w[0], w[1] - w[0]))
+                .collect()
+        }
+        2 => {
+            let image_size: uint32 = c.de()?;
+            metrics = Some(c.de()?);
+            (first..=last)
+                .zip(0..)
+                .map(|(glyph_id, ix)| located(glyph_id, ix * image_size, image_size))
+                .collect()
+        }
+        4 => {
+            let num_glyphs: uint32 = c.de()?;
+            let pairs: Vec<GlyphIdOffsetPair> = c.de_counted(num_glyphs as usize + 1)?;
+            pairs
+                .windows(2)
+                .filter(|w| w[1].sbitOffset > w[0].sbitOffset)
+                .map(|w| {
+                    located(
+                        w[0].glyphID,
+                        w[0].sbitOffset as uint32,
+                        (w[1].sbitOffset - w[0].sbitOffset) as uint32,
+                    )
+                })
+                .collect()
+        }
+        5 => {
+            let image_size: uint32 = c.de()?;
+            metrics = Some(c.de()?);
+            let num_glyphs: uint32 = c.de()?;
+            let glyph_ids: Vec<GlyphID> = c.de_counted(num_glyphs as usize)?;
+            glyph_ids
+                .into_iter()
+                .zip(0..)
+                .map(|(glyph_id, ix)| located(glyph_id, ix * image_size, image_size))
+                .collect()
+        }
+        _ => {
+            return Err(DeserializationError(format!(
+                "Unknown index subtable format {}",
+                index_format
+            )))
+        }
+    };
+    Ok(IndexSubTable {
+        first_glyph_index: first,
+        last_glyph_index: last,
+        index_format,
+        image_format,
+        metrics,
+        glyphs,
+    })
+}
+
+/// Writes an `EBLC` or `CBLC` table with the given major version.
+pub(crate) fn write_strikes(
+    major_version: uint16,
+    strikes: &[BitmapSize],
+    data: &mut Vec<u8>,
+) -> Result<(), SerializationError> {
+    let arrays = strikes
+        .iter()
+        .map(write_index_subtable_array)
+        .collect::<Result<Vec<_>, _>>()?;
+    major_version.to_bytes(data)?;
+    0_u16.to_bytes(data)?;
+    (strikes.len() as uint32).to_bytes(data)?;
+    let mut offset = 8 + 48 * strikes.len();
+    for (strike, array) in strikes.iter().zip(arrays.iter()) {
+        BitmapSizeRecord {
+            indexSubTableArrayOffset: offset as uint32,
+            indexTablesSize: array.len() as uint32,
+            numberOfIndexSubTables: strike.index_subtables.len() as uint32,
+            colorRef: strike.color_ref,
+            hori: strike.hori.clone(),
+            vert: strike.vert.clone(),
+            startGlyphIndex: strike
+                .index_subtables
+                .iter()
+                .map(|st| st.first_glyph_index)
+                .min()
+                .unwrap_or(0),
+            endGlyphIndex: strike
+                .index_subtables
+                .iter()
+                .map(|st| st.last_glyph_index)
+                .max()
+                .unwrap_or(0),
+            ppemX: strike.ppem_x,
+            ppemY: strike.ppem_y,
+            bitDepth: strike.bit_depth,
+            flags: strike.flags,
+        }
+        .to_bytes(data)?;
+        offset += array.len();
+    }
+    for array in arrays {
+        data.extend(array);
+    }
+    Ok(())
+}
+
+fn write_index_subtable_array(strike: &BitmapSize) -> Result<Vec<u8>, SerializationError> {
+    let subtables = strike
+        .index_subtables
+        .iter()
+        .map(write_index_subtable)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut out = vec![];
+    let mut offset = 8 * subtables.len();
+    for (st, bytes) in strike.index_subtables.iter().zip(subtables.iter()) {
+        IndexSubTableRecord {
+            firstGlyphIndex: st.first_glyph_index,
+            lastGlyphIndex: st.last_glyph_index,
+            additionalOffsetToIndexSubtable: offset as uint32,
+        }
+        .to_bytes(&mut out)?;
+        offset += bytes.len();
+    }
+    for bytes in subtables {
+        out.extend(bytes);
+    }
+    Ok(out)
+}
+
+fn write_index_subtable(st: &IndexSubTable) -> Result<Vec<u8>, SerializationError> {
+    let mut out = vec![];
+    let image_data_offset = st.glyphs.first().map_or(0, |g| g.offset);
+    st.index_format.to_bytes(&mut out)?;
+    st.image_format.to_bytes(&mut out)?;
+    image_data_offset.to_bytes(&mut out)?;
+    match st.index_format {
+        1 | 3 => {
+            let offsets = sbit_offsets(st, image_data_offset)?;
+            if st.index_format == 1 {
+                offsets.to_bytes(&mut out)?;
+            } else {
+                for offset in offsets {
+                    uint16::try_from(offset)
+                        .map_err(|_| {
+                            SerializationError("Index format 3 offset overflow".to_string())
+                        })?
+                        .to_bytes(&mut out)?;
+                }
+            }
+        }
+        2 | 5 => {
+            let image_size = st.glyphs.first().map_or(0, |g| g.length);
+            let evenly_spaced = st.glyphs.iter().zip(0..).all(|(g, ix)| {
+                g.length == image_size && g.offset == image_data_offset + ix * image_size
+            });
+            let complete = st.glyphs.len()
+                == (st.last_glyph_index - st.first_glyph_index) as usize + 1
+                && st
+                    .glyphs
+                    .iter()
+                    .zip(st.first_glyph_index..)
+                    .all(|(g, glyph_id)| g.glyph_id == glyph_id);
+            if !evenly_spaced || (st.index_format == 2 && !complete) {
+                return Err(SerializationError(format!(
+                    "Glyphs in index format {} must be contiguous and the same size",
+                    st.index_format
+                )));
+            }
+            image_size.to_bytes(&mut out)?;
+            st.metrics
+                .as_ref()
+                .ok_or_else(|| {
+                    SerializationError(format!(
+                        "Index format {} needs glyph metrics",
+                        st.index_format
+                    ))
+                })?
+                .to_bytes(&mut out)?;
+            if st.index_format == 5 {
+                (st.glyphs.len() as uint32).to_bytes(&mut out)?;
+                for glyph in &st.glyphs {
+                    glyph.glyph_id.to_bytes(&mut out)?;
+                }
+            }
+        }
+        4 => {
+            (st.glyphs.len() as uint32).to_bytes(&mut out)?;
+            let mut end = image_data_offset;
+            let mut pairs = vec![];
+            for glyph in &st.glyphs {
+                if glyph.offset != end {
+                    return Err(not_contiguous());
+                }
+                pairs.push((glyph.glyph_id, end - image_data_offset));
+                end += glyph.length;
+            }
+            pairs.push((0, end - image_data_offset));
+            for (glyph_id, offset) in pairs {
+                GlyphIdOffsetPair {
+                    glyphID: glyph_id,
+                    sbitOffset: uint16::try_from(offset).map_err(|_| {
+                        SerializationError("Index format 4 offset overflow".to_string())
+                    })?,
+                }
+                .to_bytes(&mut out)?;
+            }
+        }
+        _ => {
+            return Err(SerializationError(format!(
+                "Unknown index subtable format {}",
+                st.index_format
+            )))
+        }
+    }
+    crate::woff::pad4(&mut out);
+    Ok(out)
+}
+
+fn not_contiguous() -> SerializationError {
+    SerializationError("Glyph images in an index subtable must be contiguous".to_string())
+}
+
+/// Computes the offset of every glyph in an index subtable's range relative
+/// to the subtable's image data offset, plus the offset of the end of the
+/// last image.
+fn sbit_offsets(
+    st: &IndexSubTable,
+    image_data_offset: uint32,
+) -> Result<Vec<uint32>, SerializationError> {
+    let mut offsets = vec![];
+    let mut end = image_data_offset;
+    let mut glyphs = st.glyphs.iter().peekable();
+    for glyph_id in st.first_glyph_index..=st.last_glyph_index {
+        offsets.push(end - image_data_offset);
+        if let Some(glyph) = glyphs.next_if(|g| g.glyph_id == glyph_id) {
+            if glyph.offset != end {
+                return Err(not_contiguous());
+            }
+            end += glyph.length;
+        }
+    }
+    if glyphs.next().is_some() {
+        return Err(SerializationError(
+            "Glyph outside of index subtable range".to_string(),
+        ));
+    }
+    offsets.push(end - image_data_offset);
+    Ok(offsets)
+}
+
+/// Rebuilds the index subtables of each strike to locate the glyph images as
+/// they are laid out when the data table is serialized.
+///
+/// Runs of consecutive glyphs with the same image format share an index
+/// subtable. Image formats which keep their metrics in the location table
+/// use index format 2; the others use 16-bit offsets where they fit.
+pub(crate) fn locate_glyphs(
+    sizes: &mut [BitmapSize],
+    strikes: &[BitmapStrike],
+) -> Result<(), SerializationError> {
+    if sizes.len() != strikes.len() {
+        return Err(SerializationError(format!(
+            "{} strikes in the location table but {} in the data table",
+            sizes.len(),
+            strikes.len()
+        )));
+    }
+    // Glyph data starts after the data table's version number
+    let mut offset: uint32 = 4;
+    for (size, glyphs) in sizes.iter_mut().zip(strikes.iter()) {
+        let mut subtables: Vec<IndexSubTable> = vec![];
+        for (&glyph_id, glyph) in glyphs {
+            let mut bytes = vec![];
+            glyph.to_bytes(&mut bytes)?;
+            let location = GlyphLocation {
+                glyph_id,
+                offset,
+                length: bytes.len() as uint32,
+            };
+            offset += location.length;
+            let metrics = glyph.shared_metrics();
+            let extends_current = subtables.last().is_some_and(|st| {
+                st.last_glyph_index.checked_add(1) == Some(glyph_id)
+                    && st.image_format == glyph.image_format
+                    && st.metrics == metrics
+                    && (metrics.is_none() || st.glyphs[0].length == location.length)
+            });
+            if extends_current {
+                let st = subtables.last_mut().unwrap();
+                st.last_glyph_index = glyph_id;
+                st.glyphs.push(location);
+            } else {
+                subtables.push(IndexSubTable {
+                    first_glyph_index: glyph_id,
+                    last_glyph_index: glyph_id,
+                    index_format: if metrics.is_some() { 2 } else { 1 },
+                    image_format: glyph.image_format,
+                    metrics,
+                    glyphs: vec![location],
+                });
+            }
+        }
+        for st in subtables.iter_mut() {
+            let span = st.glyphs.last().map_or(0, |g| g.offset + g.length)
+                - st.glyphs.first().map_or(0, |g| g.offset);
+            if st.index_format == 1 && span <= uint16::MAX as uint32 {
+                st.index_format = 3;
+            }
+        }
+        size.index_subtables = subtables;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::EBDT::{BitmapData, BitmapGlyph, GlyphMetrics};
+
+    fn big_metrics() -> BigGlyphMetrics {
+        BigGlyphMetrics {
+            height: 2,
+            width: 8,
+            horiBearingX: 0,
+            horiBearingY: 2,
+            horiAdvance: 8,
+            vertBearingX: -4,
+            vertBearingY: 0,
+            vertAdvance: 2,
+        }
+    }
+
+    const BIG_METRICS: [u8; 8] = [0x02, 0x08, 0x00, 0x02, 0x08, 0xfc, 0x00, 0x02];
+
+    fn location(glyph_id: GlyphID, offset: uint32, length: uint32) -> GlyphLocation {
+        GlyphLocation {
+            glyph_id,
+            offset,
+            length,
+        }
+    }
+
+    fn assert_roundtrip(binary: Vec<u8>, first: GlyphID, last: GlyphID, expected: IndexSubTable) {
+        let mut c = ReaderContext::new(binary.clone());
+        let subtable = read_index_subtable(&mut c, first, last).unwrap();
+        assert_eq!(subtable, expected);
+        assert_eq!(write_index_subtable(&subtable).unwrap(), binary);
+    }
+
+    #[test]
+    fn index_format_1() {
+        let binary = vec![
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, // header
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, // glyph 3 @ 0, glyph 4 @ 2
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, // glyph 5 @ 2, end @ 5
+        ];
+        let expected = IndexSubTable {
+            first_glyph_index: 3,
+            last_glyph_index: 5,
+            index_format: 1,
+            image_format: 1,
+            metrics: None,
+            glyphs: vec![location(3, 4, 2), location(5, 6, 3)],
+        };
+        assert_roundtrip(binary, 3, 5, expected);
+    }
+
+    #[test]
+    fn index_format_2() {
+        let mut binary = vec![
+            0x00, 0x02, 0x00, 0x05, 0x00, 0x00, 0x00, 0x0a, // header
+            0x00, 0x00, 0x00, 0x02, // imageSize
+        ];
+        binary.extend(BIG_METRICS);
+        let expected = IndexSubTable {
+            first_glyph_index: 7,
+            last_glyph_index: 9,
+            index_format: 2,
+            image_format: 5,
+            metrics: Some(big_metrics()),
+            glyphs: vec![location(7, 10, 2), location(8, 12, 2), location(9, 14, 2)],
+        };
+        assert_roundtrip(binary, 7, 9, expected);
+    }
+
+    #[test]
+    fn index_format_3() {
+        let binary = vec![
+            0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, // header
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x05, // offsets
+            0x00, 0x00, // padding
+        ];
+        let expected = IndexSubTable {
+            first_glyph_index: 3,
+            last_glyph_index: 4,
+            index_format: 3,
+            image_format: 1,
+            metrics: None,
+            glyphs: vec![location(3, 4, 2), location(4, 6, 3)],
+        };
+        assert_roundtrip(binary, 3, 4, expected);
+    }
+
+    #[test]
+    fn index_format_4() {
+        let binary = vec![
+            0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, // header
+            0x00, 0x00, 0x00, 0x02, // numGlyphs
+            0x00, 0x0a, 0x00, 0x00, // glyph 10 @ 0
+            0x00, 0x0c, 0x00, 0x03, // glyph 12 @ 3
+            0x00, 0x00, 0x00, 0x07, // end @ 7
+        ];
+        let expected = IndexSubTable {
+            first_glyph_index: 10,
+            last_glyph_index: 12,
+            index_format: 4,
+            image_format: 1,
+            metrics: None,
+            glyphs: vec![location(10, 20, 3), location(12, 23, 4)],
+        };
+        assert_roundtrip(binary, 10, 12, expected);
+    }
+
+    #[test]
+    fn index_format_5() {
+        let mut binary = vec![
+            0x00, 0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x1e, // header
+            0x00, 0x00, 0x00, 0x02, // imageSize
+        ];
+        binary.extend(BIG_METRICS);
+        binary.extend([
+            0x00, 0x00, 0x00, 0x02, // numGlyphs
+            0x00, 0x14, 0x00, 0x19, // glyphs 20 and 25
+        ]);
+        let expected = IndexSubTable {
+            first_glyph_index: 20,
+            last_glyph_index: 25,
+            index_format: 5,
+            image_format: 5,
+            metrics: Some(big_metrics()),
+            glyphs: vec![location(20, 30, 2), location(25, 32, 2)],
+        };
+        assert_roundtrip(binary, 20, 25, expected);
+    }
+
+    #[test]
+    fn index_format_errors() {
+        // Format 2 can't skip glyphs, and format 3 offsets must fit in 16 bits
+        let gappy = IndexSubTable {
+            first_glyph_index: 7,
+            last_glyph_index: 9,
+            index_format: 2,
+            image_format: 5,
+            metrics: Some(big_metrics()),
+            glyphs: vec![location(7, 10, 2), location(9, 12, 2)],
+        };
+        assert!(write_index_subtable(&gappy).is_err());
+        let long = IndexSubTable {
+            first_glyph_index: 1,
+            last_glyph_index: 1,
+            index_format: 3,
+            image_format: 1,
+            metrics: None,
+            glyphs: vec![location(1, 4, 70000)],
+        };
+        assert!(write_index_subtable(&long).is_err());
+    }
+
+    #[test]
+    fn locate_glyphs_chooses_formats() {
+        let image = |image_format, length| BitmapGlyph {
+            image_format,
+            metrics: GlyphMetrics::Big(big_metrics()),
+            data: BitmapData::Image(vec![0; length]),
+        };
+        let mut strike = BitmapStrike::new();
+        // Too much data for 16-bit offsets
+        strike.insert(1, image(6, 70000));
+        strike.insert(2, image(6, 1));
+        // Shared metrics and equal sizes
+        strike.insert(3, image(5, 2));
+        strike.insert(4, image(5, 2));
+        // A different size needs a new subtable
+        strike.insert(5, image(5, 3));
+        // A gap in the glyph IDs needs a new subtable
+        strike.insert(7, image(6, 1));
+        let mut sizes = vec![BitmapSize {
+            color_ref: 0,
+            hori: SbitLineMetrics::default(),
+            vert: SbitLineMetrics::default(),
+            ppem_x: 16,
+            ppem_y: 16,
+            bit_depth: 1,
+            flags: 1,
+            index_subtables: vec![],
+        }];
+        locate_glyphs(&mut sizes, &[strike]).unwrap();
+        let formats: Vec<_> = sizes[0]
+            .index_subtables
+            .iter()
+            .map(|st| {
+                (
+                    st.first_glyph_index,
+                    st.last_glyph_index,
+                    st.index_format,
+                    st.image_format,
+                )
+            })
+            .collect();
+        assert_eq!(
+            formats,
+            vec![(1, 2, 1, 6), (3, 4, 2, 5), (5, 5, 2, 5), (7, 7, 3, 6)]
+        );
+
+        let eblc = EBLC { strikes: sizes };
+        let binary = otspec::ser::to_bytes(&eblc).unwrap();
+        let deserialized: EBLC = otspec::de::from_bytes(&binary).unwrap();
+        assert_eq!(deserialized, eblc);
+    }
+}
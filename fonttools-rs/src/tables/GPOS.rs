@@ -1,4 +1,7 @@
-use crate::layout::common::{FromLowlevel, Lookup, ToLowlevel, GPOSGSUB};
+use crate::layout::common::{
+    feature_variations_from_lowlevel, feature_variations_to_lowlevel, FromLowlevel, Lookup,
+    ToLowlevel, GPOSGSUB,
+};
 use crate::layout::contextual::{ChainedSequenceContext, SequenceContext};
 use crate::layout::gpos1::SinglePos;
 use crate::layout::gpos2::PairPos;
@@ -7,7 +10,7 @@ use crate::layout::gpos4::MarkBasePos;
 use crate::layout::gpos5::MarkLigPos;
 use crate::layout::gpos6::MarkMarkPos;
 use otspec::tables::GPOS::{
-    ExtensionPosFormat1, GPOSLookup as GPOSLookupLowlevel, GPOSSubtable, GPOS10,
+    ExtensionPosFormat1, GPOSLookup as GPOSLookupLowlevel, GPOSSubtable, GPOS10, GPOS11,
 };
 use otspec::types::*;
 use otspec::utils::is_all_the_same;
@@ -20,6 +23,7 @@ pub const TAG: Tag = crate::tag!("GPOS");
 ///
 /// Each rule is expressed as a vector of subtables.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Positioning {
     /// Contains a single positioning rule.
     Single(Vec<SinglePos>),
@@ -84,10 +88,10 @@ pub(crate) fn from_bytes(
             let internal: GPOS10 = c.de()?;
             Ok(GPOS::from_lowlevel(internal, max_glyph_id))
         }
-        // [0x00, 0x01, 0x00, 0x01] => {
-        //     let internal: GPOS11 = c.de()?;
-        //     Ok(internal.into())
-        // }
+        [0x00, 0x01, 0x00, 0x01] => {
+            let internal: GPOS11 = c.de()?;
+            Ok(GPOS::from_lowlevel(internal, max_glyph_id))
+        }
         _ => Err(DeserializationError(
             "Invalid GPOS table version".to_string(),
         )),
@@ -175,37 +179,58 @@ fn subtables_from_lowlevel(
     }
 }
 
+impl FromLowlevel<GPOSLookupLowlevel> for Lookup<Positioning> {
+    fn from_lowlevel(lookup_lowlevel: GPOSLookupLowlevel, max_glyph_id: GlyphID) -> Self {
+        let subtables: Vec<GPOSSubtable> = lookup_lowlevel
+            .subtables
+            .v
+            .iter()
+            .flat_map(|x| x.link.clone())
+            .collect();
+        let theirs = subtables_from_lowlevel(lookup_lowlevel.lookupType, subtables, max_glyph_id);
+
+        Lookup {
+            flags: lookup_lowlevel.lookupFlag,
+            mark_filtering_set: lookup_lowlevel.markFilteringSet,
+            rule: theirs,
+        }
+    }
+}
+
 impl FromLowlevel<GPOS10> for GPOS {
     fn from_lowlevel(val: GPOS10, max_glyph_id: GlyphID) -> Self {
         let lookup_list_lowlevel = val.lookupList.link.unwrap_or_default();
-        let mut lookups: Vec<Lookup<Positioning>> = vec![];
-        for lookup_off in lookup_list_lowlevel.lookups.v {
-            if let Some(lookup_lowlevel) = lookup_off.link {
-                let subtables: Vec<GPOSSubtable> = lookup_lowlevel
-                    .subtables
-                    .v
-                    .iter()
-                    .flat_map(|x| x.link.clone())
-                    .collect();
-                let theirs =
-                    subtables_from_lowlevel(lookup_lowlevel.lookupType, subtables, max_glyph_id);
-
-                let lookup_highlevel: Lookup<Positioning> = Lookup {
-                    flags: lookup_lowlevel.lookupFlag,
-                    mark_filtering_set: lookup_lowlevel.markFilteringSet,
-                    rule: theirs,
-                };
-                lookups.push(lookup_highlevel)
-            }
-        }
+        let lookups: Vec<Lookup<Positioning>> = lookup_list_lowlevel
+            .lookups
+            .v
+            .into_iter()
+            .flat_map(|lookup_off| lookup_off.link)
+            .map(|lookup_lowlevel| Lookup::from_lowlevel(lookup_lowlevel, max_glyph_id))
+            .collect();
         GPOS {
             lookups,
             scripts: val.scriptList.link.unwrap_or_default().into(),
             features: val.featureList.link.unwrap_or_default().into(),
+            feature_variations: vec![],
         }
     }
 }
 
+impl FromLowlevel<GPOS11> for GPOS {
+    fn from_lowlevel(val: GPOS11, max_glyph_id: GlyphID) -> Self {
+        let gpos10 = GPOS10 {
+            majorVersion: 1,
+            minorVersion: 0,
+            scriptList: val.scriptList,
+            featureList: val.featureList,
+            lookupList: val.lookupList,
+        };
+        let mut gpos = GPOS::from_lowlevel(gpos10, max_glyph_id);
+        gpos.feature_variations = feature_variations_from_lowlevel(val.featureVariations);
+        gpos
+    }
+}
+
 impl ToLowlevel<GPOSLookupLowlevel> for Lookup<Positioning> {
     fn to_lowlevel(&self, max_glyph_id: GlyphID) -> GPOSLookupLowlevel {
         let subtables: Vec<Offset16<GPOSSubtable>> = match &self.rule {
@@ -288,8 +313,19 @@ pub(crate) fn to_bytes(
     data: &mut Vec<u8>,
     max_glyph_id: GlyphID,
 ) -> Result<(), SerializationError> {
-    let gpos10 = gpos.to_lowlevel(max_glyph_id);
-    gpos10.to_bytes(data)
+    let gpos10: GPOS10 = gpos.to_lowlevel(max_glyph_id);
+    if gpos.feature_variations.is_empty() {
+        return gpos10.to_bytes(data);
+    }
+    GPOS11 {
+        majorVersion: 1,
+        minorVersion: 1,
+        scriptList: gpos10.scriptList,
+        featureList: gpos10.featureList,
+        lookupList: gpos10.lookupList,
+        featureVariations: feature_variations_to_lowlevel(&gpos.feature_variations),
+    }
+    .to_bytes(data)
 }
 
 #[cfg(test)]
@@ -319,6 +355,7 @@ pub(crate) mod tests {
                 ),
             },
             features: FeatureList::new(vec![(tag!("test"), vec![0], None)]),
+            feature_variations: vec![],
         }
     }
 
@@ -1,4 +1,7 @@
-use crate::layout::common::{FromLowlevel, Lookup, ToLowlevel, GPOSGSUB};
+use crate::layout::common::{
+    feature_variations_from_lowlevel, feature_variations_to_lowlevel, FromLowlevel, Lookup,
+    ToLowlevel, GPOSGSUB,
+};
 use crate::layout::contextual::{ChainedSequenceContext, SequenceContext};
 use crate::layout::gsub1::SingleSubst;
 use crate::layout::gsub2::MultipleSubst;
@@ -6,7 +9,7 @@ use crate::layout::gsub3::AlternateSubst;
 use crate::layout::gsub4::LigatureSubst;
 use crate::layout::gsub8::ReverseChainSubst;
 use otspec::tables::GSUB::{
-    ExtensionSubstFormat1, GSUBLookup as GSUBLookupLowlevel, GSUBSubtable, GSUB10,
+    ExtensionSubstFormat1, GSUBLookup as GSUBLookupLowlevel, GSUBSubtable, GSUB10, GSUB11,
 };
 use otspec::types::*;
 use otspec::utils::is_all_the_same;
@@ -19,6 +22,7 @@ pub const TAG: Tag = crate::tag!("GSUB");
 ///
 /// Each rule is expressed as a vector of subtables.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Substitution {
     /// Contains a single substitution rule.
     Single(Vec<SingleSubst>),
@@ -80,10 +84,10 @@ pub(crate) fn from_bytes(
             let internal: GSUB10 = c.de()?;
             Ok(GSUB::from_lowlevel(internal, max_glyph_id))
         }
-        // [0x00, 0x01, 0x00, 0x01] => {
-        //     let internal: GSUB11 = c.de()?;
-        //     Ok(internal.into())
-        // }
+        [0x00, 0x01, 0x00, 0x01] => {
+            let internal: GSUB11 = c.de()?;
+            Ok(GSUB::from_lowlevel(internal, max_glyph_id))
+        }
         _ => Err(DeserializationError(
             "Invalid GSUB table version".to_string(),
         )),
@@ -196,10 +200,26 @@ impl FromLowlevel<GSUB10> for GSUB {
             lookups,
             scripts: val.scriptList.link.unwrap_or_default().into(),
             features: val.featureList.link.unwrap_or_default().into(),
+            feature_variations: vec![],
         }
     }
 }
 
+impl FromLowlevel<GSUB11> for GSUB {
+    fn from_lowlevel(val: GSUB11, max_glyph_id: GlyphID) -> Self {
+        let gsub10 = GSUB10 {
+            majorVersion: 1,
+            minorVersion: 0,
+            scriptList: val.scriptList,
+            featureList: val.featureList,
+            lookupList: val.lookupList,
+        };
+        let mut gsub = GSUB::from_lowlevel(gsub10, max_glyph_id);
+        gsub.feature_variations = feature_variations_from_lowlevel(val.featureVariations);
+        gsub
+    }
+}
+
 impl ToLowlevel<GSUBLookupLowlevel> for Lookup<Substitution> {
     fn to_lowlevel(&self, max_glyph_id: GlyphID) -> GSUBLookupLowlevel {
         let subtables: Vec<Offset16<GSUBSubtable>> = match &self.rule {
@@ -273,14 +293,27 @@ pub(crate) fn to_bytes(
     data: &mut Vec<u8>,
     max_glyph_id: GlyphID,
 ) -> Result<(), SerializationError> {
-    let gsub10 = gsub.to_lowlevel(max_glyph_id);
-    gsub10.to_bytes(data)
+    let gsub10: GSUB10 = gsub.to_lowlevel(max_glyph_id);
+    if gsub.feature_variations.is_empty() {
+        return gsub10.to_bytes(data);
+    }
+    GSUB11 {
+        majorVersion: 1,
+        minorVersion: 1,
+        scriptList: gsub10.scriptList,
+        featureList: gsub10.featureList,
+        lookupList: gsub10.lookupList,
+        featureVariations: feature_variations_to_lowlevel(&gsub.feature_variations),
+    }
+    .to_bytes(data)
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::layout::common::{FeatureList, LanguageSystem, LookupFlags, Script, ScriptList};
+    use crate::layout::common::{
+        FeatureList, FeatureVariation, LanguageSystem, LookupFlags, Script, ScriptList,
+    };
     use crate::tag;
     use otspec::btreemap;
     use std::collections::BTreeMap;
@@ -302,6 +335,7 @@ pub(crate) mod tests {
                 ),
             },
             features: FeatureList::new(vec![(tag!("test"), vec![0], None)]),
+            feature_variations: vec![],
         }
     }
 
@@ -341,4 +375,33 @@ pub(crate) mod tests {
         }]);
         assert_can_deserialize(binary_gsub, &expected);
     }
+
+    #[test]
+    fn test_feature_variations_roundtrip() {
+        // Version 1.1, with the FeatureVariations table at offset 0x46
+        let binary_gsub = vec![
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x0e, 0x00, 0x22, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x46,
+            0x00, 0x01, 0x44, 0x46, 0x4c, 0x54, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+            0xff, 0xff, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x72, 0x76, 0x72, 0x6e, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x08, 0x00, 0x01, 0x00, 0x06, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x42,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x20, 0x00,
+            0x40, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ];
+        let mut expected = expected_gsub(vec![Lookup {
+            flags: LookupFlags::empty(),
+            mark_filtering_set: None,
+            rule: Substitution::Single(vec![SingleSubst {
+                mapping: btreemap!(66 => 67),
+            }]),
+        }]);
+        expected.features = FeatureList::new(vec![(tag!("rvrn"), vec![], None)]);
+        expected.feature_variations = vec![FeatureVariation {
+            conditions: vec![(0, 0.5, 1.0)],
+            substitutions: btreemap!(0 => vec![0]),
+        }];
+        assert_can_roundtrip(binary_gsub, &expected);
+    }
 }
@@ -0,0 +1,118 @@
+use crate::otvar::{DeltaSetIndexMap, ItemVariationStore};
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::tables;
+
+/// The 'HVAR' OpenType tag.
+pub const TAG: Tag = crate::tag!("HVAR");
+
+tables!(
+    HVARcore {
+        uint16 majorVersion
+        uint16 minorVersion
+        Offset32(ItemVariationStore) itemVariationStore
+        Offset32(DeltaSetIndexMap) advanceWidthMapping
+        Offset32(DeltaSetIndexMap) lsbMapping
+        Offset32(DeltaSetIndexMap) rsbMapping
+    }
+);
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Horizontal Metrics Variations table
+pub struct HVAR {
+    /// The deltas for advance widths and (optionally) side bearings.
+    pub item_variation_store: ItemVariationStore,
+    /// Maps glyph IDs to delta-set indices for advance widths. If not
+    /// present, the glyph ID is used as the inner delta-set index.
+    pub advance_mapping: Option<DeltaSetIndexMap>,
+    /// Maps glyph IDs to delta-set indices for left side bearings.
+    pub lsb_mapping: Option<DeltaSetIndexMap>,
+    /// Maps glyph IDs to delta-set indices for right side bearings.
+    pub rsb_mapping: Option<DeltaSetIndexMap>,
+}
+
+impl HVAR {
+    /// Returns the advance width delta for a glyph at a normalized location.
+    pub fn advance_delta(&self, glyph: GlyphID, coords: &[f32]) -> f32 {
+        let var_idx = match &self.advance_mapping {
+            Some(map) => map.get(glyph.into()).unwrap_or(0xFFFFFFFF),
+            None => glyph.into(),
+        };
+        self.item_variation_store.get_delta(var_idx, coords)
+    }
+}
+
+impl Deserialize for HVAR {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let core: HVARcore = c.de()?;
+        Ok(HVAR {
+            item_variation_store: core.itemVariationStore.link.ok_or_else(|| {
+                DeserializationError("HVAR table has no item variation store".to_string())
+            })?,
+            advance_mapping: core.advanceWidthMapping.link,
+            lsb_mapping: core.lsbMapping.link,
+            rsb_mapping: core.rsbMapping.link,
+        })
+    }
+}
+
+fn mapping_offset(mapping: &Option<DeltaSetIndexMap>) -> Offset32<DeltaSetIndexMap> {
+    mapping
+        .as_ref()
+        .map_or_else(Offset32::to_nothing, |m| Offset32::to(m.clone()))
+}
+
+impl Serialize for HVAR {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        HVARcore {
+            majorVersion: 1,
+            minorVersion: 0,
+            itemVariationStore: Offset32::to(self.item_variation_store.clone()),
+            advanceWidthMapping: mapping_offset(&self.advance_mapping),
+            lsbMapping: mapping_offset(&self.lsb_mapping),
+            rsbMapping: mapping_offset(&self.rsb_mapping),
+        }
+        .to_bytes(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otvar::RegionAxisCoordinates;
+
+    #[test]
+    fn hvar_serde() {
+        let hvar = HVAR {
+            item_variation_store: ItemVariationStore::new(
+                1,
+                vec![vec![RegionAxisCoordinates {
+                    startCoord: 0.0,
+                    peakCoord: 1.0,
+                    endCoord: 1.0,
+                }]],
+                vec![vec![0], vec![40]],
+            ),
+            advance_mapping: Some(DeltaSetIndexMap {
+                entries: vec![0, 1, 1],
+            }),
+            lsb_mapping: None,
+            rsb_mapping: None,
+        };
+        let binary_hvar = otspec::ser::to_bytes(&hvar).unwrap();
+        assert_eq!(
+            binary_hvar[0..20],
+            [
+                0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        );
+        let deserialized: HVAR = otspec::de::from_bytes(&binary_hvar).unwrap();
+        assert_eq!(deserialized, hvar);
+        assert_eq!(deserialized.advance_delta(2, &[0.5]), 20.0);
+        assert_eq!(deserialized.advance_delta(0, &[0.5]), 0.0);
+    }
+}
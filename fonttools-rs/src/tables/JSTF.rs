@@ -0,0 +1,371 @@
+use crate::layout::common::{FromLowlevel, Lookup, ToLowlevel};
+use crate::tables::GPOS::Positioning;
+use otspec::tables::GPOS::GPOSLookup;
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serialize};
+use otspec_macros::tables;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The 'JSTF' OpenType tag.
+pub const TAG: Tag = crate::tag!("JSTF");
+
+tables!(
+    JSTF10 {
+        uint16 majorVersion
+        uint16 minorVersion
+        [embed]
+        Counted(JstfScriptRecord) jstfScriptRecords
+    }
+
+    JstfScriptRecord [embedded] {
+        Tag jstfScriptTag
+        Offset16(JstfScriptInternal) jstfScript
+    }
+
+    JstfScriptInternal {
+        [offset_base]
+        Offset16(ExtenderGlyph) extenderGlyph
+        Offset16(JstfLangSys) defJstfLangSys
+        [embed]
+        Counted(JstfLangSysRecord) jstfLangSysRecords
+    }
+
+    JstfLangSysRecord [embedded] {
+        Tag jstfLangSysTag
+        Offset16(JstfLangSys) jstfLangSys
+    }
+
+    ExtenderGlyph {
+        Counted(uint16) extenderGlyphs
+    }
+
+    JstfLangSys {
+        [offset_base]
+        CountedOffset16(JstfPriorityInternal) jstfPriorities
+    }
+
+    JstfPriorityInternal {
+        [offset_base]
+        Offset16(JstfModList) shrinkageEnableGSUB
+        Offset16(JstfModList) shrinkageDisableGSUB
+        Offset16(JstfModList) shrinkageEnableGPOS
+        Offset16(JstfModList) shrinkageDisableGPOS
+        Offset16(JstfMax) shrinkageJstfMax
+        Offset16(JstfModList) extensionEnableGSUB
+        Offset16(JstfModList) extensionDisableGSUB
+        Offset16(JstfModList) extensionEnableGPOS
+        Offset16(JstfModList) extensionDisableGPOS
+        Offset16(JstfMax) extensionJstfMax
+    }
+
+    JstfModList {
+        Counted(uint16) lookupIndices
+    }
+
+    JstfMax {
+        [offset_base]
+        CountedOffset16(GPOSLookup) lookups
+    }
+);
+
+/// A set of modifications to try when justifying a line
+///
+/// Lookup indices refer to the lookup lists of the font's `GSUB` and `GPOS`
+/// tables.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JstfPriority {
+    /// GSUB lookups to enable when shrinking a line
+    pub shrinkage_enable_gsub: Vec<uint16>,
+    /// GSUB lookups to disable when shrinking a line
+    pub shrinkage_disable_gsub: Vec<uint16>,
+    /// GPOS lookups to enable when shrinking a line
+    pub shrinkage_enable_gpos: Vec<uint16>,
+    /// GPOS lookups to disable when shrinking a line
+    pub shrinkage_disable_gpos: Vec<uint16>,
+    /// Positioning lookups which give the maximum shrinkage
+    pub shrinkage_max: Vec<Lookup<Positioning>>,
+    /// GSUB lookups to enable when extending a line
+    pub extension_enable_gsub: Vec<uint16>,
+    /// GSUB lookups to disable when extending a line
+    pub extension_disable_gsub: Vec<uint16>,
+    /// GPOS lookups to enable when extending a line
+    pub extension_enable_gpos: Vec<uint16>,
+    /// GPOS lookups to disable when extending a line
+    pub extension_disable_gpos: Vec<uint16>,
+    /// Positioning lookups which give the maximum extension
+    pub extension_max: Vec<Lookup<Positioning>>,
+}
+
+/// Justification information for a script
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JstfScript {
+    /// Glyphs, such as kashida, which may be inserted to extend a line
+    pub extender_glyphs: BTreeSet<GlyphID>,
+    /// Optionally, the priorities to be used when no specific language is
+    /// selected, in order of preference.
+    pub default_language_system: Option<Vec<JstfPriority>>,
+    /// A mapping between language tags and their priorities.
+    pub language_systems: BTreeMap<Tag, Vec<JstfPriority>>,
+}
+
+/// A JSTF (Justification) table
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JSTF {
+    /// A mapping between script tags and their justification information
+    pub scripts: BTreeMap<Tag, JstfScript>,
+}
+
+fn mod_list_from_lowlevel(off: Offset16<JstfModList>) -> Vec<uint16> {
+    off.link.map_or_else(Vec::new, |l| l.lookupIndices)
+}
+
+fn mod_list_to_lowlevel(indices: &[uint16]) -> Offset16<JstfModList> {
+    if indices.is_empty() {
+        Offset16::to_nothing()
+    } else {
+        Offset16::to(JstfModList {
+            lookupIndices: indices.to_vec(),
+        })
+    }
+}
+
+fn max_from_lowlevel(off: Offset16<JstfMax>, max_glyph_id: GlyphID) -> Vec<Lookup<Positioning>> {
+    off.link.map_or_else(Vec::new, |m| {
+        m.lookups
+            .v
+            .into_iter()
+            .flat_map(|lookup_off| lookup_off.link)
+            .map(|lookup| Lookup::from_lowlevel(lookup, max_glyph_id))
+            .collect()
+    })
+}
+
+fn max_to_lowlevel(lookups: &[Lookup<Positioning>], max_glyph_id: GlyphID) -> Offset16<JstfMax> {
+    if lookups.is_empty() {
+        Offset16::to_nothing()
+    } else {
+        let lookups: Vec<Offset16<GPOSLookup>> = lookups
+            .iter()
+            .map(|l| Offset16::to(l.to_lowlevel(max_glyph_id)))
+            .collect();
+        Offset16::to(JstfMax {
+            lookups: lookups.into(),
+        })
+    }
+}
+
+impl FromLowlevel<JstfPriorityInternal> for JstfPriority {
+    fn from_lowlevel(p: JstfPriorityInternal, max_glyph_id: GlyphID) -> Self {
+        JstfPriority {
+            shrinkage_enable_gsub: mod_list_from_lowlevel(p.shrinkageEnableGSUB),
+            shrinkage_disable_gsub: mod_list_from_lowlevel(p.shrinkageDisableGSUB),
+            shrinkage_enable_gpos: mod_list_from_lowlevel(p.shrinkageEnableGPOS),
+            shrinkage_disable_gpos: mod_list_from_lowlevel(p.shrinkageDisableGPOS),
+            shrinkage_max: max_from_lowlevel(p.shrinkageJstfMax, max_glyph_id),
+            extension_enable_gsub: mod_list_from_lowlevel(p.extensionEnableGSUB),
+            extension_disable_gsub: mod_list_from_lowlevel(p.extensionDisableGSUB),
+            extension_enable_gpos: mod_list_from_lowlevel(p.extensionEnableGPOS),
+            extension_disable_gpos: mod_list_from_lowlevel(p.extensionDisableGPOS),
+            extension_max: max_from_lowlevel(p.extensionJstfMax, max_glyph_id),
+        }
+    }
+}
+
+impl ToLowlevel<JstfPriorityInternal> for JstfPriority {
+    fn to_lowlevel(&self, max_glyph_id: GlyphID) -> JstfPriorityInternal {
+        JstfPriorityInternal {
+            shrinkageEnableGSUB: mod_list_to_lowlevel(&self.shrinkage_enable_gsub),
+            shrinkageDisableGSUB: mod_list_to_lowlevel(&self.shrinkage_disable_gsub),
+            shrinkageEnableGPOS: mod_list_to_lowlevel(&self.shrinkage_enable_gpos),
+            shrinkageDisableGPOS: mod_list_to_lowlevel(&self.shrinkage_disable_gpos),
+            shrinkageJstfMax: max_to_lowlevel(&self.shrinkage_max, max_glyph_id),
+            extensionEnableGSUB: mod_list_to_lowlevel(&self.extension_enable_gsub),
+            extensionDisableGSUB: mod_list_to_lowlevel(&self.extension_disable_gsub),
+            extensionEnableGPOS: mod_list_to_lowlevel(&self.extension_enable_gpos),
+            extensionDisableGPOS: mod_list_to_lowlevel(&self.extension_disable_gpos),
+            extensionJstfMax: max_to_lowlevel(&self.extension_max, max_glyph_id),
+        }
+    }
+}
+
+fn lang_sys_from_lowlevel(ls: JstfLangSys, max_glyph_id: GlyphID) -> Vec<JstfPriority> {
+    ls.jstfPriorities
+        .v
+        .into_iter()
+        .map(|p| {
+            p.link.map_or_else(JstfPriority::default, |p| {
+                JstfPriority::from_lowlevel(p, max_glyph_id)
+            })
+        })
+        .collect()
+}
+
+fn lang_sys_to_lowlevel(priorities: &[JstfPriority], max_glyph_id: GlyphID) -> JstfLangSys {
+    let priorities: Vec<Offset16<JstfPriorityInternal>> = priorities
+        .iter()
+        .map(|p| Offset16::to(p.to_lowlevel(max_glyph_id)))
+        .collect();
+    JstfLangSys {
+        jstfPriorities: priorities.into(),
+    }
+}
+
+impl FromLowlevel<JSTF10> for JSTF {
+    fn from_lowlevel(val: JSTF10, max_glyph_id: GlyphID) -> Self {
+        let mut scripts = BTreeMap::new();
+        for record in val.jstfScriptRecords {
+            let script = match record.jstfScript.link {
+                Some(script) => script,
+                None => continue,
+            };
+            scripts.insert(
+                record.jstfScriptTag,
+                JstfScript {
+                    extender_glyphs: script
+                        .extenderGlyph
+                        .link
+                        .map_or_else(BTreeSet::new, |e| e.extenderGlyphs.into_iter().collect()),
+                    default_language_system: script
+                        .defJstfLangSys
+                        .link
+                        .map(|ls| lang_sys_from_lowlevel(ls, max_glyph_id)),
+                    language_systems: script
+                        .jstfLangSysRecords
+                        .into_iter()
+                        .filter_map(|r| {
+                            Some((
+                                r.jstfLangSysTag,
+                                lang_sys_from_lowlevel(r.jstfLangSys.link?, max_glyph_id),
+                            ))
+                        })
+                        .collect(),
+                },
+            );
+        }
+        JSTF { scripts }
+    }
+}
+
+impl ToLowlevel<JSTF10> for JSTF {
+    fn to_lowlevel(&self, max_glyph_id: GlyphID) -> JSTF10 {
+        JSTF10 {
+            majorVersion: 1,
+            minorVersion: 0,
+            jstfScriptRecords: self
+                .scripts
+                .iter()
+                .map(|(tag, script)| JstfScriptRecord {
+                    jstfScriptTag: *tag,
+                    jstfScript: Offset16::to(JstfScriptInternal {
+                        extenderGlyph: if script.extender_glyphs.is_empty() {
+                            Offset16::to_nothing()
+                        } else {
+                            Offset16::to(ExtenderGlyph {
+                                extenderGlyphs: script.extender_glyphs.iter().copied().collect(),
+                            })
+                        },
+                        defJstfLangSys: script
+                            .default_language_system
+                            .as_ref()
+                            .map_or_else(Offset16::to_nothing, |ls| {
+                                Offset16::to(lang_sys_to_lowlevel(ls, max_glyph_id))
+                            }),
+                        jstfLangSysRecords: script
+                            .language_systems
+                            .iter()
+                            .map(|(tag, ls)| JstfLangSysRecord {
+                                jstfLangSysTag: *tag,
+                                jstfLangSys: Offset16::to(lang_sys_to_lowlevel(ls, max_glyph_id)),
+                            })
+                            .collect(),
+                    }),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn from_bytes(
+    c: &mut ReaderContext,
+    max_glyph_id: GlyphID,
+) -> Result<JSTF, DeserializationError> {
+    match c.peek(4)? {
+        [0x00, 0x01, 0x00, 0x00] => {
+            let internal: JSTF10 = c.de()?;
+            Ok(JSTF::from_lowlevel(internal, max_glyph_id))
+        }
+        _ => Err(DeserializationError(
+            "Invalid JSTF table version".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn to_bytes(
+    jstf: &JSTF,
+    data: &mut Vec<u8>,
+    max_glyph_id: GlyphID,
+) -> Result<(), SerializationError> {
+    jstf.to_lowlevel(max_glyph_id).to_bytes(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::common::{LookupFlags, ValueRecord};
+    use crate::layout::gpos1::SinglePos;
+    use crate::tag;
+    use otspec::{btreemap, valuerecord};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_jstf_roundtrip() {
+        let binary_jstf = vec![
+            0x00, 0x01, 0x00, 0x00, // JSTF 1.0
+            0x00, 0x01, // jstfScriptCount
+            0x61, 0x72, 0x61, 0x62, 0x00, 0x0c, // arab
+            /* 0x0c */ 0x00, 0x06, // JstfScript.extenderGlyphOffset
+            0x00, 0x0c, // JstfScript.defJstfLangSysOffset
+            0x00, 0x00, // JstfScript.jstfLangSysCount
+            0x00, 0x02, 0x00, 0x07, 0x00, 0x08, // ExtenderGlyph
+            /* 0x18 */ 0x00, 0x01, 0x00, 0x04, // JstfLangSys
+            /* 0x1c */ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, // shrinkage
+            0x00, 0x14, // JstfPriority.extensionEnableGSUB
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // extensionDisableGSUB etc.
+            0x00, 0x18, // JstfPriority.extensionJstfMax
+            /* 0x30 */ 0x00, 0x01, 0x00, 0x03, // JstfModList
+            /* 0x34 */ 0x00, 0x01, 0x00, 0x04, // JstfMax
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x08, // Lookup
+            0x00, 0x01, 0x00, 0x08, 0x00, 0x04, 0x00, 0x64, // SinglePosFormat1
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x07, // Coverage
+        ];
+        let expected = JSTF {
+            scripts: btreemap!(tag!("arab") => JstfScript {
+                extender_glyphs: BTreeSet::from([7, 8]),
+                default_language_system: Some(vec![JstfPriority {
+                    extension_enable_gsub: vec![3],
+                    extension_max: vec![Lookup {
+                        flags: LookupFlags::empty(),
+                        mark_filtering_set: None,
+                        rule: Positioning::Single(vec![SinglePos {
+                            mapping: btreemap!(7 => valuerecord!(xAdvance = 100)),
+                        }]),
+                    }],
+                    ..Default::default()
+                }]),
+                language_systems: BTreeMap::new(),
+            }),
+        };
+        let mut rc = ReaderContext::new(binary_jstf.clone());
+        let jstf = from_bytes(&mut rc, 200).unwrap();
+        assert_eq!(jstf, expected);
+
+        let mut data = vec![];
+        to_bytes(&expected, &mut data, 200).unwrap();
+        let mut rc = ReaderContext::new(data);
+        assert_eq!(from_bytes(&mut rc, 200).unwrap(), expected);
+    }
+}
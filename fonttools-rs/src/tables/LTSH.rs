@@ -0,0 +1,76 @@
+use crate::tables::hdmx::{hdmx, linear_width};
+use crate::tables::hmtx::hmtx;
+use otspec::types::*;
+use otspec::Deserializer;
+use otspec_macros::tables;
+
+/// The 'LTSH' OpenType tag.
+pub const TAG: Tag = crate::tag!("LTSH");
+
+tables!(
+LTSH [serde] {
+    uint16 version
+    Counted(uint8) yPels
+}
+);
+
+impl LTSH {
+    /// Computes linear thresholds from the advance widths in a `hmtx` table
+    /// and the device metrics in a `hdmx` table.
+    ///
+    /// A glyph's threshold is the size above which each of its recorded
+    /// device widths matches its linearly scaled advance width. Glyphs with
+    /// no such discrepancies scale linearly at every size.
+    pub fn from_metrics(hmtx: &hmtx, units_per_em: uint16, hdmx: &hdmx) -> LTSH {
+        let y_pels = hmtx
+            .metrics
+            .iter()
+            .enumerate()
+            .map(|(glyph_id, metric)| {
+                hdmx.records
+                    .iter()
+                    .filter(|(&ppem, widths)| {
+                        widths.get(glyph_id).is_some_and(|&width| {
+                            width != linear_width(metric.advanceWidth, ppem, units_per_em)
+                        })
+                    })
+                    .map(|(&ppem, _)| ppem.saturating_add(1))
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect();
+        LTSH {
+            version: 0,
+            yPels: y_pels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::hmtx::Metric;
+
+    #[test]
+    fn ltsh_serde() {
+        let binary_ltsh = vec![0x00, 0x00, 0x00, 0x03, 0x01, 0x01, 0x0d];
+        let fltsh: LTSH = otspec::de::from_bytes(&binary_ltsh).unwrap();
+        assert_eq!(fltsh.yPels, vec![1, 1, 13]);
+        assert_eq!(otspec::ser::to_bytes(&fltsh).unwrap(), binary_ltsh);
+
+        let hmtx = hmtx {
+            metrics: [500, 0, 600]
+                .iter()
+                .map(|&width| Metric {
+                    advanceWidth: width,
+                    lsb: 0,
+                })
+                .collect(),
+        };
+        let mut hdmx = hdmx::from_metrics(&hmtx, 1000, &[11, 12, 13]);
+        // Pretend hinting widened the last glyph at 11 and 12 ppem
+        hdmx.records.get_mut(&11).unwrap()[2] = 8;
+        hdmx.records.get_mut(&12).unwrap()[2] = 8;
+        assert_eq!(LTSH::from_metrics(&hmtx, 1000, &hdmx), fltsh);
+    }
+}
@@ -10,7 +10,7 @@ use std::collections::{BTreeMap, BTreeSet};
 pub const TAG: Tag = crate::tag!("MATH");
 
 tables!(
-    MathValueRecord [embedded] {
+    MathValueRecord [embedded] [serde] {
         FWORD value
         Offset16(Device) device
     }
@@ -21,7 +21,7 @@ tables!(
         Offset16(MathGlyphInfo) mathGlyphInfo
         Offset16(MathVariants) mathVariants
     }
-    MathConstants {
+    MathConstants [serde] {
         int16   scriptPercentScaleDown
         int16   scriptScriptPercentScaleDown
         UFWORD  delimitedSubFormulaMinHeight
@@ -100,26 +100,26 @@ tables!(
         [embed]
         Counted(MathKernInfoRecord) mathKernInfoRecords
     }
-    MathKernInfoRecord [embedded] {
+    MathKernInfoRecord [embedded] [serde] {
         Offset16(MathKern) topRightMathKern
         Offset16(MathKern) topLeftMathKern
         Offset16(MathKern) bottomRightMathKern
         Offset16(MathKern) bottomLeftMathKern
     }
-    MathGlyphConstruction {
+    MathGlyphConstruction [serde] {
         [offset_base]
         Offset16(GlyphAssembly) glyphAssembly
         Counted(MathGlyphVariantRecord) mathGlyphVariantRecord
     }
-    MathGlyphVariantRecord {
+    MathGlyphVariantRecord [serde] {
         uint16  variantGlyph
         UFWORD  advanceMeasurement
     }
-    GlyphAssembly {
+    GlyphAssembly [serde] {
         MathValueRecord italicsCorrection
         Counted(GlyphPartRecord) partRecords
     }
-    GlyphPartRecord {
+    GlyphPartRecord [serde] {
         uint16  glyphID
         UFWORD  startConnectorLength
         UFWORD  endConnectorLength
@@ -202,6 +202,7 @@ impl Serialize for MathVariants {
 // Needs to be handled manually because of n+1 count in kernValues...
 #[allow(missing_docs, non_snake_case)]
 #[derive(Debug, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MathKern {
     pub heightCount: uint16,
     pub correctionHeight: Vec<MathValueRecord>,
@@ -223,6 +224,7 @@ impl Deserialize for MathKern {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The Mathematical Typesetting table
 pub struct MATH {
     /// Global constants for the mathematical typesetter
@@ -0,0 +1,149 @@
+use crate::otvar::ItemVariationStore;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+    Serializer,
+};
+use otspec_macros::tables;
+use std::collections::BTreeMap;
+
+/// The 'MVAR' OpenType tag.
+pub const TAG: Tag = crate::tag!("MVAR");
+
+tables!(
+    MVARcore {
+        uint16 majorVersion
+        uint16 minorVersion
+        uint16 reserved
+        uint16 valueRecordSize
+        uint16 valueRecordCount
+        uint16 itemVariationStoreOffset
+    }
+    ValueRecord {
+        Tag valueTag
+        uint16 deltaSetOuterIndex
+        uint16 deltaSetInnerIndex
+    }
+);
+
+const VALUE_RECORD_SIZE: uint16 = 8;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Metrics Variations table
+pub struct MVAR {
+    /// The deltas for the font-wide metrics. This is only absent if there
+    /// are no value records.
+    pub item_variation_store: Option<ItemVariationStore>,
+    /// A mapping between metric value tags (e.g. `xhgt`) and variation
+    /// indices, stored as `outer << 16 | inner`.
+    pub records: BTreeMap<Tag, uint32>,
+}
+
+impl MVAR {
+    /// Returns the delta for a given metric at a normalized location.
+    pub fn delta(&self, value_tag: Tag, coords: &[f32]) -> f32 {
+        match (&self.item_variation_store, self.records.get(&value_tag)) {
+            (Some(store), Some(&var_idx)) => store.get_delta(var_idx, coords),
+            _ => 0.0,
+        }
+    }
+}
+
+impl Deserialize for MVAR {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let start = c.ptr;
+        let core: MVARcore = c.de()?;
+        if core.valueRecordSize < VALUE_RECORD_SIZE && core.valueRecordCount > 0 {
+            return Err(DeserializationError(format!(
+                "Bad MVAR value record size {}",
+                core.valueRecordSize
+            )));
+        }
+        let mut records = BTreeMap::new();
+        for _ in 0..core.valueRecordCount {
+            let record: ValueRecord = c.de()?;
+            c.skip((core.valueRecordSize - VALUE_RECORD_SIZE).into());
+            records.insert(
+                record.valueTag,
+                ((record.deltaSetOuterIndex as u32) << 16) | record.deltaSetInnerIndex as u32,
+            );
+        }
+        let item_variation_store = if core.itemVariationStoreOffset > 0 {
+            c.ptr = start + core.itemVariationStoreOffset as usize;
+            Some(c.de()?)
+        } else {
+            None
+        };
+        Ok(MVAR {
+            item_variation_store,
+            records,
+        })
+    }
+}
+
+impl Serialize for MVAR {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let ivs_offset = if self.item_variation_store.is_some() {
+            12 + VALUE_RECORD_SIZE * self.records.len() as uint16
+        } else {
+            0
+        };
+        MVARcore {
+            majorVersion: 1,
+            minorVersion: 0,
+            reserved: 0,
+            valueRecordSize: VALUE_RECORD_SIZE,
+            valueRecordCount: self.records.len() as uint16,
+            itemVariationStoreOffset: ivs_offset,
+        }
+        .to_bytes(data)?;
+        for (&tag, &var_idx) in &self.records {
+            data.put(ValueRecord {
+                valueTag: tag,
+                deltaSetOuterIndex: (var_idx >> 16) as uint16,
+                deltaSetInnerIndex: (var_idx & 0xFFFF) as uint16,
+            })?;
+        }
+        if let Some(store) = &self.item_variation_store {
+            data.put(store)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otvar::RegionAxisCoordinates;
+    use crate::tag;
+    use otspec::btreemap;
+
+    #[test]
+    fn mvar_serde() {
+        let mvar = MVAR {
+            item_variation_store: Some(ItemVariationStore::new(
+                1,
+                vec![vec![RegionAxisCoordinates {
+                    startCoord: 0.0,
+                    peakCoord: 1.0,
+                    endCoord: 1.0,
+                }]],
+                vec![vec![10], vec![-20]],
+            )),
+            records: btreemap!(tag!("xhgt") => 1, tag!("cpht") => 0),
+        };
+        let binary_mvar = otspec::ser::to_bytes(&mvar).unwrap();
+        assert_eq!(
+            binary_mvar[0..28],
+            [
+                0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x1c, 0x63, 0x70,
+                0x68, 0x74, 0x00, 0x00, 0x00, 0x00, 0x78, 0x68, 0x67, 0x74, 0x00, 0x00, 0x00, 0x01
+            ]
+        );
+        let deserialized: MVAR = otspec::de::from_bytes(&binary_mvar).unwrap();
+        assert_eq!(deserialized, mvar);
+        assert_eq!(deserialized.delta(tag!("xhgt"), &[0.5]), -10.0);
+        assert_eq!(deserialized.delta(tag!("undo"), &[0.5]), 0.0);
+    }
+}
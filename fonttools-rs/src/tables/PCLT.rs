@@ -0,0 +1,111 @@
+use otspec::types::*;
+use otspec::Deserializer;
+use otspec_macros::{Deserialize, Serialize};
+
+/// The 'PCLT' OpenType tag.
+pub const TAG: Tag = crate::tag!("PCLT");
+
+/// The PCL 5 table
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_snake_case, non_camel_case_types)]
+pub struct PCLT {
+    /// Major version number (1)
+    pub majorVersion: uint16,
+    /// Minor version number (0)
+    pub minorVersion: uint16,
+    /// A unique identifier for the font
+    pub fontNumber: uint32,
+    /// The width of the space in font units
+    pub pitch: uint16,
+    /// The height of the lowercase x in font units
+    pub xHeight: uint16,
+    /// The PCL style word (posture, width and structure)
+    pub style: uint16,
+    /// The PCL typeface family and vendor code
+    pub typeFamily: uint16,
+    /// The height of the uppercase H in font units
+    pub capHeight: uint16,
+    /// The PCL symbol set the font is designed for
+    pub symbolSet: uint16,
+    /// The font's name, in ASCII and padded with spaces
+    pub typeface: [u8; 16],
+    /// A bit field of the symbol collections supported by the font
+    pub characterComplement: [u8; 8],
+    /// A six-character file name for the font, in ASCII
+    pub fileName: [u8; 6],
+    /// The stroke weight, from -7 (ultra thin) to 7 (ultra black)
+    pub strokeWeight: i8,
+    /// The width type, from -5 (ultra compressed) to 5 (ultra expanded)
+    pub widthType: i8,
+    /// The serif style
+    pub serifStyle: uint8,
+    /// Reserved; set to zero
+    pub reserved: uint8,
+}
+
+impl Default for PCLT {
+    fn default() -> Self {
+        PCLT {
+            majorVersion: 1,
+            minorVersion: 0,
+            fontNumber: 0,
+            pitch: 0,
+            xHeight: 0,
+            style: 0,
+            typeFamily: 0,
+            capHeight: 0,
+            symbolSet: 0,
+            typeface: [b' '; 16],
+            characterComplement: [0; 8],
+            fileName: [b' '; 6],
+            strokeWeight: 0,
+            widthType: 0,
+            serifStyle: 0,
+            reserved: 0,
+        }
+    }
+}
+
+impl PCLT {
+    /// The typeface name, with any padding removed
+    pub fn typeface_name(&self) -> String {
+        String::from_utf8_lossy(&self.typeface)
+            .trim_end_matches([' ', '\0'])
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn pclt_serde() {
+        let binary_pclt = vec![
+            0x00, 0x01, 0x00, 0x00, // version
+            0x80, 0x00, 0x00, 0x2a, // fontNumber
+            0x02, 0x26, // pitch
+            0x04, 0x1e, // xHeight
+            0x00, 0x00, // style
+            0x10, 0x01, // typeFamily
+            0x05, 0xb4, // capHeight
+            0x00, 0x0e, // symbolSet
+            b'T', b'e', b's', b't', b' ', b'S', b'a', b'n', b's', b' ', b' ', b' ', b' ', b' ',
+            b' ', b' ', // typeface
+            0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0xfe, // characterComplement
+            b'T', b'S', b'R', b'0', b'0', b' ', // fileName
+            0x00, // strokeWeight
+            0xfe, // widthType
+            0x40, // serifStyle
+            0x00, // reserved
+        ];
+        let fpclt: super::PCLT = otspec::de::from_bytes(&binary_pclt).unwrap();
+        assert_eq!(fpclt.fontNumber, 0x8000002a);
+        assert_eq!(fpclt.capHeight, 1460);
+        assert_eq!(fpclt.widthType, -2);
+        assert_eq!(fpclt.serifStyle, 0x40);
+        assert_eq!(fpclt.typeface_name(), "Test Sans");
+        assert_eq!(&fpclt.fileName, b"TSR00 ");
+        let serialized = otspec::ser::to_bytes(&fpclt).unwrap();
+        assert_eq!(serialized, binary_pclt);
+    }
+}
@@ -20,7 +20,7 @@ tables!(STATcore {
     uint32 offsetToAxisValueOffsets
 }
 
-AxisRecord {
+AxisRecord [serde] {
     Tag axisTag
     uint16 axisNameID
     uint16 axisOrdering
@@ -68,6 +68,11 @@ AxisValueFormat4AxisValue {
 
 bitflags! {
     #[derive(Serialize, Deserialize)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
     /// The following axis value table flags are defined:
     pub struct AxisValueFlags: u16 {
         /// If set, this axis value table provides axis value information that is applicable to other fonts within the same font family.
@@ -84,6 +89,7 @@ bitflags! {
 // hence the new_format_... functions below, but this allows for maximum flexibility.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An axis value table (underlying format resolved on write)
 pub struct AxisValue {
     /// Zero-base index into the axis record array identifying the axis of design variation to which the axis value table applies.
@@ -103,6 +109,7 @@ pub struct AxisValue {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::upper_case_acronyms)]
 /// The Style Attributes table
 pub struct STAT {
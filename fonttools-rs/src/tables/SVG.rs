@@ -0,0 +1,220 @@
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use std::io::{Read, Write};
+
+/// The 'SVG ' OpenType tag.
+pub const TAG: Tag = crate::tag!("SVG ");
+
+/// An SVG document providing the images for a range of glyphs
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgDocument {
+    /// The first glyph ID in the range
+    pub start_glyph_id: GlyphID,
+    /// The last glyph ID in the range
+    pub end_glyph_id: GlyphID,
+    /// The SVG document, uncompressed
+    pub document: String,
+    /// Whether the document is stored gzip-compressed in the font
+    pub compressed: bool,
+}
+
+/// Scalable Vector Graphics table
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SVG {
+    /// The documents in the table, ordered by glyph ID. Glyph ranges must
+    /// not overlap.
+    pub documents: Vec<SvgDocument>,
+}
+
+impl SVG {
+    /// Returns the document containing the image for a glyph.
+    pub fn document(&self, glyph_id: GlyphID) -> Option<&SvgDocument> {
+        self.documents
+            .iter()
+            .find(|d| (d.start_glyph_id..=d.end_glyph_id).contains(&glyph_id))
+    }
+
+    /// Replaces the image for a glyph with the given document.
+    ///
+    /// If the glyph was part of a range sharing a document, the other glyphs
+    /// in the range continue to use the existing document.
+    pub fn set_document(&mut self, glyph_id: GlyphID, document: String) {
+        self.remove_document(glyph_id);
+        let position = self
+            .documents
+            .partition_point(|d| d.start_glyph_id < glyph_id);
+        self.documents.insert(
+            position,
+            SvgDocument {
+                start_glyph_id: glyph_id,
+                end_glyph_id: glyph_id,
+                document,
+                compressed: false,
+            },
+        );
+    }
+
+    /// Removes the image for a glyph, returning its document if it had one.
+    pub fn remove_document(&mut self, glyph_id: GlyphID) -> Option<String> {
+        let ix = self
+            .documents
+            .iter()
+            .position(|d| (d.start_glyph_id..=d.end_glyph_id).contains(&glyph_id))?;
+        let record = self.documents.remove(ix);
+        if glyph_id < record.end_glyph_id {
+            self.documents.insert(
+                ix,
+                SvgDocument {
+                    start_glyph_id: glyph_id + 1,
+                    ..record.clone()
+                },
+            );
+        }
+        if record.start_glyph_id < glyph_id {
+            self.documents.insert(
+                ix,
+                SvgDocument {
+                    end_glyph_id: glyph_id - 1,
+                    ..record.clone()
+                },
+            );
+        }
+        Some(record.document)
+    }
+}
+
+impl Deserialize for SVG {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let _version: uint16 = c.de()?;
+        let list_offset: uint32 = c.de()?;
+        let _reserved: uint32 = c.de()?;
+        c.ptr = list_offset as usize;
+        let num_entries: uint16 = c.de()?;
+        let mut documents = Vec::with_capacity(num_entries as usize);
+        for ix in 0..num_entries as usize {
+            c.ptr = list_offset as usize + 2 + 12 * ix;
+            let start_glyph_id: uint16 = c.de()?;
+            let end_glyph_id: uint16 = c.de()?;
+            let offset: uint32 = c.de()?;
+            let length: uint32 = c.de()?;
+            let start = list_offset as usize + offset as usize;
+            let data = c
+                .input
+                .get(start..start + length as usize)
+                .ok_or_else(|| DeserializationError("SVG document out of bounds".to_string()))?;
+            let compressed = data.starts_with(&[0x1f, 0x8b]);
+            let document = if compressed {
+                let mut document = String::new();
+                GzDecoder::new(data)
+                    .read_to_string(&mut document)
+                    .map(|_| document)
+                    .map_err(|e| e.to_string())
+            } else {
+                String::from_utf8(data.to_vec()).map_err(|e| e.to_string())
+            }
+            .map_err(|e| DeserializationError(format!("Bad SVG document: {}", e)))?;
+            documents.push(SvgDocument {
+                start_glyph_id,
+                end_glyph_id,
+                document,
+                compressed,
+            });
+        }
+        Ok(SVG { documents })
+    }
+}
+
+impl Serialize for SVG {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let mut records: Vec<&SvgDocument> = self.documents.iter().collect();
+        records.sort_by_key(|d| d.start_glyph_id);
+
+        // Ranges which share a document share its data
+        let mut blobs: Vec<(&SvgDocument, Vec<u8>)> = vec![];
+        let mut entries: Vec<(GlyphID, GlyphID, usize)> = vec![];
+        for record in records {
+            let existing = blobs.iter().position(|(d, _)| {
+                d.document == record.document && d.compressed == record.compressed
+            });
+            let blob = match existing {
+                Some(ix) => ix,
+                None => {
+                    let bytes = if record.compressed {
+                        let mut encoder = GzEncoder::new(vec![], Compression::best());
+                        encoder
+                            .write_all(record.document.as_bytes())
+                            .and_then(|_| encoder.finish())
+                            .map_err(|e| SerializationError(e.to_string()))?
+                    } else {
+                        record.document.as_bytes().to_vec()
+                    };
+                    blobs.push((record, bytes));
+                    blobs.len() - 1
+                }
+            };
+            entries.push((record.start_glyph_id, record.end_glyph_id, blob));
+        }
+
+        let mut blob_offsets = vec![];
+        let mut offset = 2 + 12 * entries.len();
+        for (_, bytes) in &blobs {
+            blob_offsets.push(offset as uint32);
+            offset += bytes.len();
+        }
+
+        0_u16.to_bytes(data)?;
+        10_u32.to_bytes(data)?;
+        0_u32.to_bytes(data)?;
+        (entries.len() as uint16).to_bytes(data)?;
+        for (start, end, blob) in entries {
+            start.to_bytes(data)?;
+            end.to_bytes(data)?;
+            blob_offsets[blob].to_bytes(data)?;
+            (blobs[blob].1.len() as uint32).to_bytes(data)?;
+        }
+        for (_, bytes) in blobs {
+            data.extend(bytes);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_serde() {
+        let binary_svg = vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x03,
+            0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x06, 0x3c, 0x73, 0x76, 0x67, 0x2f, 0x3e,
+        ];
+        let mut table: SVG = otspec::de::from_bytes(&binary_svg).unwrap();
+        assert_eq!(table.documents.len(), 2);
+        assert_eq!(table.document(2).unwrap().document, "<svg/>");
+        assert!(table.document(4).is_none());
+        assert_eq!(otspec::ser::to_bytes(&table).unwrap(), binary_svg);
+
+        table.remove_document(2);
+        table.set_document(4, "<svg id=\"glyph4\"/>".to_string());
+        table.documents[2].compressed = true;
+        let ranges: Vec<_> = table
+            .documents
+            .iter()
+            .map(|d| (d.start_glyph_id, d.end_glyph_id))
+            .collect();
+        assert_eq!(ranges, vec![(1, 1), (3, 3), (4, 4)]);
+
+        let binary_svg = otspec::ser::to_bytes(&table).unwrap();
+        let deserialized: SVG = otspec::de::from_bytes(&binary_svg).unwrap();
+        assert_eq!(deserialized, table);
+    }
+}
@@ -0,0 +1,192 @@
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::tables;
+
+/// The 'VDMX' OpenType tag.
+pub const TAG: Tag = crate::tag!("VDMX");
+
+tables!(
+RatioRange [serde] {
+    uint8 bCharSet
+    uint8 xRatio
+    uint8 yStartRatio
+    uint8 yEndRatio
+}
+
+VTable [serde] {
+    uint16 yPelHeight
+    int16 yMax
+    int16 yMin
+}
+);
+
+impl RatioRange {
+    /// Whether this range covers a device with the given x and y resolutions
+    pub fn matches(&self, x_resolution: u16, y_resolution: u16) -> bool {
+        if self.xRatio == 0 {
+            return true;
+        }
+        let scaled_y = y_resolution as u32 * self.xRatio as u32;
+        let x = x_resolution as u32;
+        self.yStartRatio as u32 * x <= scaled_y && scaled_y <= self.yEndRatio as u32 * x
+    }
+}
+
+/// The vertical extents of the font at a range of sizes
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VdmxGroup {
+    /// The smallest pixel height covered by this group
+    pub startsz: uint8,
+    /// The largest pixel height covered by this group
+    pub endsz: uint8,
+    /// The extents at each pixel height, in increasing order of height
+    pub entries: Vec<VTable>,
+}
+
+impl VdmxGroup {
+    /// The extents at the given pixel height, if recorded
+    pub fn extents(&self, y_pel_height: uint16) -> Option<&VTable> {
+        self.entries.iter().find(|e| e.yPelHeight == y_pel_height)
+    }
+}
+
+/// A range of device aspect ratios and the extents to use for it
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VdmxRecord {
+    /// The aspect ratios covered by this record
+    pub ratio: RatioRange,
+    /// The extents to use for these aspect ratios
+    pub group: VdmxGroup,
+}
+
+/// Vertical Device Metrics Table
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct VDMX {
+    /// Table version (0 or 1)
+    pub version: uint16,
+    /// The records, in the order they should be matched
+    pub records: Vec<VdmxRecord>,
+}
+
+impl VDMX {
+    /// The extents to use for a device with the given resolutions; the first
+    /// record whose ratio range matches the device is used.
+    pub fn group_for_device(&self, x_resolution: u16, y_resolution: u16) -> Option<&VdmxGroup> {
+        self.records
+            .iter()
+            .find(|r| r.ratio.matches(x_resolution, y_resolution))
+            .map(|r| &r.group)
+    }
+}
+
+impl Deserialize for VDMX {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let version: uint16 = c.de()?;
+        let _num_recs: uint16 = c.de()?;
+        let num_ratios: uint16 = c.de()?;
+        let ratios: Vec<RatioRange> = c.de_counted(num_ratios as usize)?;
+        let offsets: Vec<uint16> = c.de_counted(num_ratios as usize)?;
+        let mut records = Vec::with_capacity(ratios.len());
+        for (ratio, offset) in ratios.into_iter().zip(offsets) {
+            c.ptr = offset as usize;
+            let recs: uint16 = c.de()?;
+            let startsz: uint8 = c.de()?;
+            let endsz: uint8 = c.de()?;
+            let entries: Vec<VTable> = c.de_counted(recs as usize)?;
+            records.push(VdmxRecord {
+                ratio,
+                group: VdmxGroup {
+                    startsz,
+                    endsz,
+                    entries,
+                },
+            });
+        }
+        Ok(VDMX { version, records })
+    }
+}
+
+impl Serialize for VDMX {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        // Records may share a group
+        let mut groups: Vec<&VdmxGroup> = vec![];
+        let mut group_indices = vec![];
+        for record in &self.records {
+            let index = groups
+                .iter()
+                .position(|g| *g == &record.group)
+                .unwrap_or_else(|| {
+                    groups.push(&record.group);
+                    groups.len() - 1
+                });
+            group_indices.push(index);
+        }
+        let mut group_offsets = vec![];
+        let mut offset = 6 + 6 * self.records.len();
+        for group in &groups {
+            group_offsets.push(offset);
+            offset += 4 + 6 * group.entries.len();
+        }
+        if offset > u16::MAX as usize {
+            return Err(SerializationError("VDMX table too large".to_string()));
+        }
+
+        self.version.to_bytes(data)?;
+        (groups.len() as uint16).to_bytes(data)?;
+        (self.records.len() as uint16).to_bytes(data)?;
+        for record in &self.records {
+            record.ratio.to_bytes(data)?;
+        }
+        for index in group_indices {
+            (group_offsets[index] as uint16).to_bytes(data)?;
+        }
+        for group in groups {
+            (group.entries.len() as uint16).to_bytes(data)?;
+            group.startsz.to_bytes(data)?;
+            group.endsz.to_bytes(data)?;
+            group.entries.to_bytes(data)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vdmx_serde() {
+        let binary_vdmx = vec![
+            0x00, 0x01, // version
+            0x00, 0x01, // numRecs
+            0x00, 0x02, // numRatios
+            0x01, 0x01, 0x01, 0x01, // ratio 1:1
+            0x00, 0x00, 0x00, 0x00, // ratio (all)
+            0x00, 0x12, 0x00, 0x12, // offsets
+            0x00, 0x02, 0x08, 0x09, // group: recs, startsz, endsz
+            0x00, 0x08, 0x00, 0x08, 0xff, 0xfe, // 8ppem
+            0x00, 0x09, 0x00, 0x09, 0xff, 0xfe, // 9ppem
+        ];
+        let fvdmx: VDMX = otspec::de::from_bytes(&binary_vdmx).unwrap();
+        assert_eq!(fvdmx.records.len(), 2);
+        assert_eq!(fvdmx.records[0].group, fvdmx.records[1].group);
+        let group = fvdmx.group_for_device(96, 96).unwrap();
+        assert_eq!(
+            group.extents(9),
+            Some(&VTable {
+                yPelHeight: 9,
+                yMax: 9,
+                yMin: -2
+            })
+        );
+        assert!(fvdmx.records[0].ratio.matches(72, 72));
+        assert!(!fvdmx.records[0].ratio.matches(72, 144));
+        assert_eq!(otspec::ser::to_bytes(&fvdmx).unwrap(), binary_vdmx);
+    }
+}
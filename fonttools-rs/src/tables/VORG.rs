@@ -0,0 +1,62 @@
+use otspec::types::*;
+use otspec::Deserializer;
+use otspec_macros::tables;
+
+/// The 'VORG' OpenType tag.
+pub const TAG: Tag = crate::tag!("VORG");
+
+tables!(
+VertOriginYMetrics [serde] {
+    uint16 glyphIndex
+    int16 vertOriginY
+}
+
+VORG [serde] {
+    uint16 majorVersion
+    uint16 minorVersion
+    int16 defaultVertOriginY
+    Counted(VertOriginYMetrics) vertOriginYMetrics
+}
+);
+
+impl VORG {
+    /// The y coordinate of the vertical origin of the given glyph
+    pub fn vert_origin_y(&self, glyph_id: GlyphID) -> int16 {
+        self.vertOriginYMetrics
+            .iter()
+            .find(|m| m.glyphIndex == glyph_id)
+            .map_or(self.defaultVertOriginY, |m| m.vertOriginY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn vorg_serde() {
+        let binary_vorg = vec![
+            0x00, 0x01, 0x00, 0x00, 0x03, 0x70, 0x00, 0x02, 0x00, 0x05, 0x03, 0x84, 0x00, 0x07,
+            0x02, 0xbc,
+        ];
+        let fvorg: super::VORG = otspec::de::from_bytes(&binary_vorg).unwrap();
+        let expected = super::VORG {
+            majorVersion: 1,
+            minorVersion: 0,
+            defaultVertOriginY: 880,
+            vertOriginYMetrics: vec![
+                super::VertOriginYMetrics {
+                    glyphIndex: 5,
+                    vertOriginY: 900,
+                },
+                super::VertOriginYMetrics {
+                    glyphIndex: 7,
+                    vertOriginY: 700,
+                },
+            ],
+        };
+        assert_eq!(fvorg, expected);
+        assert_eq!(fvorg.vert_origin_y(7), 700);
+        assert_eq!(fvorg.vert_origin_y(1), 880);
+        let serialized = otspec::ser::to_bytes(&fvorg).unwrap();
+        assert_eq!(serialized, binary_vorg);
+    }
+}
@@ -1,21 +1,44 @@
-use crate::table_delegate;
+use crate::otvar::{DeltaSetIndexMap, ItemVariationStore};
+use otmath::{ot_round, to_f2dot14};
 use otspec::tables::avar::{avar as avar_ot, AxisValueMap, SegmentMap as SegmentMap_ot};
-use otspec::types::Tag;
-use otspec::Deserializer;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use otspec_macros::tables;
 
 /// The 'avar' OpenType tag.
 pub const TAG: Tag = crate::tag!("avar");
 
+// Version 2 adds two offsets after the segment maps
+tables!(
+    avar2 {
+        uint16 majorVersion
+        uint16 minorVersion
+        uint16 reserved
+        Counted(SegmentMap_ot) axisSegmentMaps
+        Offset32(DeltaSetIndexMap) axisIndexMap
+        Offset32(ItemVariationStore) varStore
+    }
+);
+
 /// A segment map, which specifies how an axis's values are modified by the mapping
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SegmentMap(pub Vec<(f32, f32)>);
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Axis Variations Table
 pub struct avar {
     /// A set of mappings, one for each axis in the `fvar` table.
     pub maps: Vec<SegmentMap>,
+    /// Maps axis indices to delta-set indices in the variation store (version 2).
+    /// If not present, the axis index is used as the inner delta-set index.
+    pub axis_index_map: Option<DeltaSetIndexMap>,
+    /// Cross-axis variation deltas, in F2DOT14 units (version 2).
+    pub var_store: Option<ItemVariationStore>,
 }
 
 impl From<&avar> for avar_ot {
@@ -37,11 +60,93 @@ impl From<avar_ot> for avar {
                 .iter()
                 .map(|x| x.clone().into())
                 .collect(),
+            axis_index_map: None,
+            var_store: None,
+        }
+    }
+}
+
+impl Deserialize for avar {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let core: avar_ot = c.de()?;
+        let major_version = core.majorVersion;
+        let mut table: avar = core.into();
+        if major_version > 1 {
+            let axis_index_map: Offset32<DeltaSetIndexMap> = c.de()?;
+            let var_store: Offset32<ItemVariationStore> = c.de()?;
+            table.axis_index_map = axis_index_map.link;
+            table.var_store = var_store.link;
+        }
+        Ok(table)
+    }
+}
+
+impl Serialize for avar {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        if let Some(var_store) = &self.var_store {
+            avar2 {
+                majorVersion: 2,
+                minorVersion: 0,
+                reserved: 0,
+                axisSegmentMaps: self.maps.iter().map(|x| x.into()).collect(),
+                axisIndexMap: self
+                    .axis_index_map
+                    .as_ref()
+                    .map_or_else(Offset32::to_nothing, |m| Offset32::to(m.clone())),
+                varStore: Offset32::to(var_store.clone()),
+            }
+            .to_bytes(data)
+        } else {
+            let out: avar_ot = self.into();
+            out.to_bytes(data)
         }
     }
 }
 
-table_delegate!(avar, avar_ot);
+impl avar {
+    /// Is this a version 2 table with cross-axis mappings?
+    pub fn has_cross_axis_mappings(&self) -> bool {
+        self.var_store.is_some()
+    }
+
+    /// Maps a default-normalized location (in `fvar` axis order) through the
+    /// segment maps and then through any cross-axis mappings.
+    pub fn map_location(&self, coords: &[f32]) -> Vec<f32> {
+        let mapped: Vec<f32> = coords
+            .iter()
+            .enumerate()
+            .map(|(ix, &v)| {
+                self.maps
+                    .get(ix)
+                    .map_or(v, |map| map.piecewise_linear_map(v))
+            })
+            .collect();
+        self.map_cross_axis(&mapped)
+    }
+
+    /// Applies the version 2 cross-axis mappings to a location which has
+    /// already been mapped through the segment maps. For version 1 tables,
+    /// the location is returned unchanged.
+    pub fn map_cross_axis(&self, coords: &[f32]) -> Vec<f32> {
+        let var_store = match &self.var_store {
+            Some(var_store) => var_store,
+            None => return coords.to_vec(),
+        };
+        coords
+            .iter()
+            .enumerate()
+            .map(|(ix, &v)| {
+                let var_idx = match &self.axis_index_map {
+                    Some(map) => map.get(ix).unwrap_or(0xFFFFFFFF),
+                    None => ix as u32,
+                };
+                let delta = var_store.get_delta(var_idx, coords);
+                let v = (to_f2dot14(v) + ot_round(delta)).clamp(-16384, 16384);
+                v as f32 / 16384.0
+            })
+            .collect()
+    }
+}
 
 impl From<&SegmentMap> for SegmentMap_ot {
     fn from(val: &SegmentMap) -> Self {
@@ -140,6 +245,7 @@ impl SegmentMap {
 
 #[cfg(test)]
 mod tests {
+    use crate::otvar::{ItemVariationStore, RegionAxisCoordinates};
 
     #[test]
     fn test_piecewise_linear_map() {
@@ -161,4 +267,38 @@ mod tests {
         assert!((seg.piecewise_linear_map(0.625) - 0.5).abs() < f32::EPSILON);
         assert!((seg.piecewise_linear_map(0.6) - 0.47108155).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_avar2_serde_and_map() {
+        let identity = super::SegmentMap::new(vec![(-1.0, -1.0), (0.0, 0.0), (1.0, 1.0)]);
+        let region = vec![
+            RegionAxisCoordinates {
+                startCoord: 0.0,
+                peakCoord: 1.0,
+                endCoord: 1.0,
+            },
+            RegionAxisCoordinates {
+                startCoord: 0.0,
+                peakCoord: 0.0,
+                endCoord: 0.0,
+            },
+        ];
+        let table = super::avar {
+            maps: vec![identity.clone(), identity],
+            axis_index_map: None,
+            var_store: Some(ItemVariationStore::new(
+                2,
+                vec![region],
+                vec![vec![0], vec![-4096]],
+            )),
+        };
+        let binary = otspec::ser::to_bytes(&table).unwrap();
+        assert_eq!(&binary[0..4], &[0x00, 0x02, 0x00, 0x00]);
+        let deserialized: super::avar = otspec::de::from_bytes(&binary).unwrap();
+        assert_eq!(deserialized, table);
+
+        assert_eq!(table.map_location(&[0.0, 0.0]), vec![0.0, 0.0]);
+        assert_eq!(table.map_location(&[1.0, 0.0]), vec![1.0, -0.25]);
+        assert_eq!(table.map_location(&[0.5, 0.5]), vec![0.5, 0.375]);
+    }
 }
@@ -506,6 +506,7 @@ impl cmap14 {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_snake_case)]
 /// A cmap subtable.
 ///
@@ -529,6 +530,10 @@ pub struct CmapSubtable {
     /// A mapping between Unicode codepoints and glyph IDs.
     pub mapping: BTreeMap<uint32, uint16>,
     /// A mapping of Unicode codepoints + glyph selectors to glyph IDs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::option_map_as_pairs")
+    )]
     pub uvs_mapping: Option<BTreeMap<(uint32, uint32), uint16>>,
 }
 
@@ -560,6 +565,7 @@ impl Serialize for CmapSubtable {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 /// cmap table. The cmap table is a collection of subtables, as described above.
 pub struct cmap {
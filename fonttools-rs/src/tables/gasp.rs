@@ -7,12 +7,12 @@ use otspec_macros::{tables, Deserialize, Serialize};
 pub const TAG: Tag = crate::tag!("gasp");
 
 tables!(
-GaspRecord {
+GaspRecord [serde] {
     uint16 rangeMaxPPEM
     RangeGaspBehaviorFlags rangeGaspBehavior
 }
 
-gasp {
+gasp [serde] {
     uint16 version
     Counted(GaspRecord) gaspRanges
 }
@@ -20,6 +20,11 @@ gasp {
 
 bitflags! {
     #[derive(Serialize, Deserialize)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
     /// Flags which determine how grid-fitting should be carried out
     pub struct RangeGaspBehaviorFlags: u16 {
         /// Use gridfitting
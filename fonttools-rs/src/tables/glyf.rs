@@ -23,6 +23,7 @@ pub const TAG: otspec::types::Tag = crate::tag!("glyf");
 /// The glyf table
 #[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct glyf {
     /// A list of glyph objects in the font
     pub glyphs: Vec<Glyph>,
@@ -3,6 +3,7 @@ use otspec::types::*;
 
 /// Represents a point inside a glyf::Contour
 #[derive(Debug, PartialEq, Copy, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// x-coordinate
     pub x: int16,
@@ -34,6 +34,7 @@ tables!( gvarcore {
 ///
 /// (This is the user-friendly version of what is serialized as a TupleVariation)
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeltaSet {
     /// The peak location at which this region is active.
     pub peak: Tuple,
@@ -123,28 +124,10 @@ impl DeltaSet {
         }
         TupleVariation(tvh, deltas)
     }
-
-    pub(crate) fn combine(&self, other: &Self) -> Self {
-        let mut new = self.clone();
-        if new.deltas.len() != other.deltas.len() {
-            panic!("Tried to add deltas with different lengths")
-        }
-        for (ix, (x, y)) in new.deltas.iter_mut().enumerate() {
-            *x += other.deltas[ix].0;
-            *y += other.deltas[ix].1;
-        }
-        new
-    }
-
-    pub(crate) fn scale_deltas(&mut self, factor: f32) {
-        for (x, y) in self.deltas.iter_mut() {
-            *x = (*x as f32 * factor) as i16;
-            *y = (*y as f32 * factor) as i16;
-        }
-    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A description of how an individual glyph's outline varies across the designspace.
 pub struct GlyphVariationData {
     /// A list of designsets, containing deltas at particular designspace regions.
@@ -152,6 +135,7 @@ pub struct GlyphVariationData {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 /// A Glyph Variations table, describing how glyph outlines vary across the
 /// designspace.
@@ -0,0 +1,127 @@
+use crate::tables::hmtx::hmtx;
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serialize};
+use std::collections::BTreeMap;
+
+/// The 'hdmx' OpenType tag.
+pub const TAG: Tag = crate::tag!("hdmx");
+
+/// Horizontal Device Metrics Table
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct hdmx {
+    /// Table version (0)
+    pub version: uint16,
+    /// The advance width of each glyph in pixels, keyed by pixels-per-em size
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::map_as_pairs"))]
+    pub records: BTreeMap<uint8, Vec<uint8>>,
+}
+
+/// The advance width of a glyph in pixels at a given size, if it scales linearly.
+pub(crate) fn linear_width(advance_width: uint16, ppem: uint8, units_per_em: uint16) -> uint8 {
+    let units_per_em = units_per_em.max(1) as u32;
+    let width = (2 * advance_width as u32 * ppem as u32 + units_per_em) / (2 * units_per_em);
+    width.min(u8::MAX as u32) as uint8
+}
+
+impl hdmx {
+    /// Computes device metrics from the advance widths in a `hmtx` table.
+    ///
+    /// The widths are linearly scaled from the font's design units, so any
+    /// adjustments made to the advance widths by the hinting instructions
+    /// are not taken into account.
+    pub fn from_metrics(hmtx: &hmtx, units_per_em: uint16, ppems: &[uint8]) -> hdmx {
+        let records = ppems
+            .iter()
+            .map(|&ppem| {
+                let widths = hmtx
+                    .metrics
+                    .iter()
+                    .map(|m| linear_width(m.advanceWidth, ppem, units_per_em))
+                    .collect();
+                (ppem, widths)
+            })
+            .collect();
+        hdmx {
+            version: 0,
+            records,
+        }
+    }
+
+    /// The advance width in pixels of a glyph at the given size, if recorded.
+    pub fn width(&self, ppem: uint8, glyph_id: GlyphID) -> Option<uint8> {
+        self.records.get(&ppem)?.get(glyph_id as usize).copied()
+    }
+}
+
+/// Deserializes a Horizontal Device Metrics Table given a binary vector and
+/// the number of glyphs in the font.
+pub fn from_bytes(c: &mut ReaderContext, num_glyphs: uint16) -> Result<hdmx, DeserializationError> {
+    let version: uint16 = c.de()?;
+    let num_records: int16 = c.de()?;
+    let size_device_record: i32 = c.de()?;
+    if (size_device_record as usize) < 2 + num_glyphs as usize {
+        return Err(DeserializationError(
+            "hdmx device record too short".to_string(),
+        ));
+    }
+    let mut records = BTreeMap::new();
+    for i in 0..num_records.max(0) as usize {
+        c.ptr = 8 + i * size_device_record as usize;
+        let pixel_size: uint8 = c.de()?;
+        let _max_width: uint8 = c.de()?;
+        records.insert(pixel_size, c.de_counted(num_glyphs as usize)?);
+    }
+    Ok(hdmx { version, records })
+}
+
+impl Serialize for hdmx {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let num_glyphs = self.records.values().map(|w| w.len()).max().unwrap_or(0);
+        let record_size = (2 + num_glyphs + 3) & !3;
+        self.version.to_bytes(data)?;
+        (self.records.len() as int16).to_bytes(data)?;
+        (record_size as i32).to_bytes(data)?;
+        for (ppem, widths) in &self.records {
+            ppem.to_bytes(data)?;
+            widths.iter().max().copied().unwrap_or(0).to_bytes(data)?;
+            data.extend(widths);
+            data.extend(vec![0; record_size - 2 - widths.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::hmtx::Metric;
+
+    #[test]
+    fn hdmx_serde() {
+        let binary_hdmx = vec![
+            0x00, 0x00, // version
+            0x00, 0x02, // numRecords
+            0x00, 0x00, 0x00, 0x08, // sizeDeviceRecord
+            0x0b, 0x07, 0x06, 0x00, 0x03, 0x07, 0x00, 0x00, // 11ppem
+            0x0c, 0x07, 0x06, 0x00, 0x03, 0x07, 0x00, 0x00, // 12ppem
+        ];
+        let fhdmx = from_bytes(&mut ReaderContext::new(binary_hdmx.clone()), 4).unwrap();
+        assert_eq!(fhdmx.records.len(), 2);
+        assert_eq!(fhdmx.width(12, 3), Some(7));
+        assert_eq!(fhdmx.width(13, 3), None);
+        assert_eq!(otspec::ser::to_bytes(&fhdmx).unwrap(), binary_hdmx);
+
+        let hmtx = hmtx {
+            metrics: [500, 0, 250, 600]
+                .iter()
+                .map(|&width| Metric {
+                    advanceWidth: width,
+                    lsb: 0,
+                })
+                .collect(),
+        };
+        assert_eq!(hdmx::from_metrics(&hmtx, 1000, &[11, 12]), fhdmx);
+    }
+}
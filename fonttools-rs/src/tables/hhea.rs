@@ -5,7 +5,7 @@ use otspec_macros::tables;
 /// The 'hhea' OpenType tag.
 pub const TAG: Tag = crate::tag!("hhea");
 
-tables!(hhea {
+tables!(hhea [serde] {
     uint16 majorVersion
     uint16 minorVersion
     FWORD ascender
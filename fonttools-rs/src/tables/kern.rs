@@ -0,0 +1,603 @@
+use crate::font::get_search_range;
+use bitflags::bitflags;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use std::collections::BTreeMap;
+
+/// The 'kern' OpenType tag.
+pub const TAG: Tag = crate::tag!("kern");
+
+/// The largest number of pairs which fit in a format 0 subtable.
+///
+/// Subtable lengths in OpenType `kern` tables are 16-bit, and the binary
+/// search parameters of both formats are 16-bit, so a format 0 subtable can
+/// hold at most this many pairs.
+pub const MAX_FORMAT0_PAIRS: usize = (0xFFFF - 14) / 6;
+
+bitflags! {
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
+    /// The kind of kerning provided by a subtable
+    ///
+    /// These are laid out as in an OpenType `kern` table; the equivalent
+    /// Apple coverage bits are converted when reading and writing.
+    pub struct KernCoverage: u8 {
+        /// The subtable has horizontal (rather than vertical) kerning values
+        const HORIZONTAL = 0x01;
+        /// The subtable has minimum values rather than kerning values (OpenType only)
+        const MINIMUM = 0x02;
+        /// The subtable has cross-stream kerning values
+        const CROSS_STREAM = 0x04;
+        /// The subtable's values replace those accumulated so far (OpenType only)
+        const OVERRIDE = 0x08;
+        /// The subtable has variation kerning values (Apple only)
+        const VARIATION = 0x20;
+    }
+}
+
+impl KernCoverage {
+    fn from_apple(bits: u8) -> Self {
+        let mut coverage = KernCoverage::empty();
+        coverage.set(KernCoverage::HORIZONTAL, bits & 0x80 == 0);
+        coverage.set(KernCoverage::CROSS_STREAM, bits & 0x40 != 0);
+        coverage.set(KernCoverage::VARIATION, bits & 0x20 != 0);
+        coverage
+    }
+
+    fn to_apple(self) -> u8 {
+        let mut bits = 0;
+        if !self.contains(KernCoverage::HORIZONTAL) {
+            bits |= 0x80;
+        }
+        if self.contains(KernCoverage::CROSS_STREAM) {
+            bits |= 0x40;
+        }
+        if self.contains(KernCoverage::VARIATION) {
+            bits |= 0x20;
+        }
+        bits
+    }
+
+    /// The coverage byte as stored in a table of the given flavour.
+    pub fn to_raw(self, apple: bool) -> u8 {
+        if apple {
+            self.to_apple()
+        } else {
+            self.bits() & !KernCoverage::VARIATION.bits()
+        }
+    }
+
+    /// Decodes a coverage byte stored in a table of the given flavour.
+    pub fn from_raw(bits: u8, apple: bool) -> Self {
+        if apple {
+            KernCoverage::from_apple(bits)
+        } else {
+            KernCoverage::from_bits_truncate(bits) - KernCoverage::VARIATION
+        }
+    }
+}
+
+/// Class-based kerning (subtable format 2)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassKerning {
+    /// The left-hand class of each glyph. Glyphs not listed are in class 0.
+    pub left_classes: BTreeMap<GlyphID, uint16>,
+    /// The right-hand class of each glyph. Glyphs not listed are in class 0.
+    pub right_classes: BTreeMap<GlyphID, uint16>,
+    /// The kerning values, indexed by left class and then by right class.
+    /// Every row must be the same length.
+    pub values: Vec<Vec<int16>>,
+}
+
+/// Compact class-based kerning (subtable format 3, Apple only)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompactClassKerning {
+    /// The distinct kerning values
+    pub kern_values: Vec<int16>,
+    /// The left-hand class of each glyph in the font
+    pub left_classes: Vec<uint8>,
+    /// The right-hand class of each glyph in the font
+    pub right_classes: Vec<uint8>,
+    /// Indices into `kern_values`, indexed by left class and then by right
+    /// class. Every row must be the same length.
+    pub kern_index: Vec<Vec<uint8>>,
+}
+
+/// The kerning data of a subtable
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kerning {
+    /// Kerning values for pairs of glyphs (format 0)
+    Pairs(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::map_as_pairs"))]
+        BTreeMap<(GlyphID, GlyphID), int16>,
+    ),
+    /// Kerning values for pairs of glyph classes (format 2)
+    Classes(ClassKerning),
+    /// Kerning values for pairs of glyph classes, with compact storage (format 3)
+    CompactClasses(CompactClassKerning),
+}
+
+impl Kerning {
+    /// The subtable format used to store this kerning.
+    pub fn format(&self) -> uint8 {
+        match self {
+            Kerning::Pairs(_) => 0,
+            Kerning::Classes(_) => 2,
+            Kerning::CompactClasses(_) => 3,
+        }
+    }
+
+    /// Returns the value for a pair of glyphs.
+    ///
+    /// Pair kerning returns `None` for unlisted pairs; class kerning returns
+    /// the value for the glyphs' classes, which may be zero.
+    pub fn get(&self, left: GlyphID, right: GlyphID) -> Option<int16> {
+        match self {
+            Kerning::Pairs(pairs) => pairs.get(&(left, right)).copied(),
+            Kerning::Classes(classes) => {
+                let row = classes.left_classes.get(&left).copied().unwrap_or(0);
+                let column = classes.right_classes.get(&right).copied().unwrap_or(0);
+                classes
+                    .values
+                    .get(row as usize)?
+                    .get(column as usize)
+                    .copied()
+            }
+            Kerning::CompactClasses(classes) => {
+                let row = *classes.left_classes.get(left as usize)?;
+                let column = *classes.right_classes.get(right as usize)?;
+                let index = *classes.kern_index.get(row as usize)?.get(column as usize)?;
+                classes.kern_values.get(index as usize).copied()
+            }
+        }
+    }
+}
+
+/// A kerning subtable
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernSubtable {
+    /// The kind of kerning in this subtable
+    pub coverage: KernCoverage,
+    /// The variation tuple this subtable applies to (Apple only)
+    pub tuple_index: uint16,
+    /// The kerning data
+    pub kerning: Kerning,
+}
+
+/// Kerning table
+///
+/// This can be either an OpenType (version 0) table or an Apple (version 1)
+/// table. New fonts should use the `GPOS` table instead, but some legacy
+/// applications only read this table.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct kern {
+    /// Whether this is an Apple (version 1) table
+    pub apple: bool,
+    /// The subtables
+    pub subtables: Vec<KernSubtable>,
+}
+
+impl kern {
+    /// Creates an OpenType `kern` table of format 0 subtables holding the
+    /// given horizontal kerning pairs.
+    ///
+    /// Pairs are split into subtables of at most [`MAX_FORMAT0_PAIRS`] pairs.
+    /// Note that many applications only read the first subtable.
+    pub fn from_pairs(pairs: &BTreeMap<(GlyphID, GlyphID), int16>) -> Self {
+        let pairs: Vec<_> = pairs.iter().map(|(&k, &v)| (k, v)).collect();
+        kern {
+            apple: false,
+            subtables: pairs
+                .chunks(MAX_FORMAT0_PAIRS)
+                .map(|chunk| KernSubtable {
+                    coverage: KernCoverage::HORIZONTAL,
+                    tuple_index: 0,
+                    kerning: Kerning::Pairs(chunk.iter().copied().collect()),
+                })
+                .collect(),
+        }
+    }
+
+    /// The horizontal kerning between two glyphs, combining the subtables
+    /// as a legacy renderer would.
+    pub fn kerning(&self, left: GlyphID, right: GlyphID) -> int16 {
+        let mut total: int16 = 0;
+        for subtable in &self.subtables {
+            if subtable.coverage & !KernCoverage::OVERRIDE != KernCoverage::HORIZONTAL {
+                continue;
+            }
+            if let Some(value) = subtable.kerning.get(left, right) {
+                if subtable.coverage.contains(KernCoverage::OVERRIDE) {
+                    total = value;
+                } else {
+                    total = total.saturating_add(value);
+                }
+            }
+        }
+        total
+    }
+}
+
+impl Deserialize for kern {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let version: uint16 = c.de()?;
+        let (apple, num_tables) = match version {
+            0 => {
+                let num_tables: uint16 = c.de()?;
+                (false, num_tables as uint32)
+            }
+            1 => {
+                let _minor_version: uint16 = c.de()?;
+                let num_tables: uint32 = c.de()?;
+                (true, num_tables)
+            }
+            _ => {
+                return Err(DeserializationError(format!(
+                    "Unknown kern table version {}",
+                    version
+                )))
+            }
+        };
+        let mut subtables = Vec::with_capacity(num_tables as usize);
+        for _ in 0..num_tables {
+            let start = c.ptr;
+            let (length, coverage, format, tuple_index) = if apple {
+                let length: uint32 = c.de()?;
+                let coverage: uint8 = c.de()?;
+                let format: uint8 = c.de()?;
+                let tuple_index: uint16 = c.de()?;
+                (length as usize, coverage, format, tuple_index)
+            } else {
+                let _version: uint16 = c.de()?;
+                let length: uint16 = c.de()?;
+                let format: uint8 = c.de()?;
+                let coverage: uint8 = c.de()?;
+                (length as usize, coverage, format, 0)
+            };
+            let kerning = match format {
+                0 => read_pairs(c)?,
+                2 => read_classes(c, start)?,
+                3 => read_compact_classes(c)?,
+                _ => {
+                    return Err(DeserializationError(format!(
+                        "Unknown kern subtable format {}",
+                        format
+                    )))
+                }
+            };
+            // Format 0 subtables are often too big for their length field,
+            // so trust the number of pairs instead.
+            if format != 0 {
+                c.ptr = start + length;
+            }
+            subtables.push(KernSubtable {
+                coverage: KernCoverage::from_raw(coverage, apple),
+                tuple_index,
+                kerning,
+            });
+        }
+        Ok(kern { apple, subtables })
+    }
+}
+
+fn read_pairs(c: &mut ReaderContext) -> Result<Kerning, DeserializationError> {
+    let num_pairs: uint16 = c.de()?;
+    c.skip(6);
+    let mut pairs = BTreeMap::new();
+    for _ in 0..num_pairs {
+        let left: GlyphID = c.de()?;
+        let right: GlyphID = c.de()?;
+        let value: int16 = c.de()?;
+        pairs.insert((left, right), value);
+    }
+    Ok(Kerning::Pairs(pairs))
+}
+
+fn read_class_table(
+    c: &mut ReaderContext,
+    offset: usize,
+) -> Result<Vec<(GlyphID, uint16)>, DeserializationError> {
+    c.ptr = offset;
+    let first_glyph: GlyphID = c.de()?;
+    let num_glyphs: uint16 = c.de()?;
+    let values: Vec<uint16> = c.de_counted(num_glyphs as usize)?;
+    Ok((first_glyph..).zip(values).collect())
+}
+
+fn read_classes(c: &mut ReaderContext, start: usize) -> Result<Kerning, DeserializationError> {
+    let row_width: uint16 = c.de()?;
+    let left_offset: uint16 = c.de()?;
+    let right_offset: uint16 = c.de()?;
+    let array_offset: uint16 = c.de()?;
+    if row_width == 0 || !row_width.is_multiple_of(2) {
+        return Err(DeserializationError(format!(
+            "Bad kern format 2 row width {}",
+            row_width
+        )));
+    }
+    // Left class values are offsets to a row of the kerning array from the
+    // start of the subtable; right class values are offsets within the row.
+    let left_classes: BTreeMap<GlyphID, uint16> =
+        read_class_table(c, start + left_offset as usize)?
+            .into_iter()
+            .map(|(glyph, value)| (glyph, value.saturating_sub(array_offset) / row_width))
+            .filter(|&(_, class)| class != 0)
+            .collect();
+    let right_classes: BTreeMap<GlyphID, uint16> =
+        read_class_table(c, start + right_offset as usize)?
+            .into_iter()
+            .map(|(glyph, value)| (glyph, value / 2))
+            .filter(|&(_, class)| class != 0)
+            .collect();
+    let num_rows = left_classes
+        .values()
+        .max()
+        .map_or(1, |&max| max as usize + 1);
+    c.ptr = start + array_offset as usize;
+    let mut values = Vec::with_capacity(num_rows);
+    for _ in 0..num_rows {
+        values.push(c.de_counted(row_width as usize / 2)?);
+    }
+    Ok(Kerning::Classes(ClassKerning {
+        left_classes,
+        right_classes,
+        values,
+    }))
+}
+
+fn read_compact_classes(c: &mut ReaderContext) -> Result<Kerning, DeserializationError> {
+    let glyph_count: uint16 = c.de()?;
+    let kern_value_count: uint8 = c.de()?;
+    let left_class_count: uint8 = c.de()?;
+    let right_class_count: uint8 = c.de()?;
+    let _flags: uint8 = c.de()?;
+    let kern_values = c.de_counted(kern_value_count as usize)?;
+    let left_classes = c.de_counted(glyph_count as usize)?;
+    let right_classes = c.de_counted(glyph_count as usize)?;
+    let mut kern_index = Vec::with_capacity(left_class_count as usize);
+    for _ in 0..left_class_count {
+        kern_index.push(c.de_counted(right_class_count as usize)?);
+    }
+    Ok(Kerning::CompactClasses(CompactClassKerning {
+        kern_values,
+        left_classes,
+        right_classes,
+        kern_index,
+    }))
+}
+
+fn row_length<T>(rows: &[Vec<T>]) -> Result<usize, SerializationError> {
+    let length = rows.first().map_or(0, |r| r.len());
+    if rows.iter().any(|r| r.len() != length) {
+        return Err(SerializationError(
+            "kern class rows must all be the same length".to_string(),
+        ));
+    }
+    Ok(length)
+}
+
+fn write_class_table(
+    classes: &BTreeMap<GlyphID, uint16>,
+    value: impl Fn(uint16) -> usize,
+    data: &mut Vec<u8>,
+) -> Result<(), SerializationError> {
+    let first = classes.keys().next().copied().unwrap_or(0);
+    let last = classes.keys().next_back().copied().unwrap_or(0);
+    let count = if classes.is_empty() {
+        0
+    } else {
+        last - first + 1
+    };
+    first.to_bytes(data)?;
+    count.to_bytes(data)?;
+    for glyph in (first..).take(count as usize) {
+        let offset = value(classes.get(&glyph).copied().unwrap_or(0));
+        uint16::try_from(offset)
+            .map_err(|_| SerializationError("kern format 2 offset overflow".to_string()))?
+            .to_bytes(data)?;
+    }
+    Ok(())
+}
+
+impl Kerning {
+    /// Serializes the subtable body; `header_size` is the size of the
+    /// subtable header which precedes it.
+    fn to_bytes(&self, header_size: usize, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        match self {
+            Kerning::Pairs(pairs) => {
+                if pairs.len() > MAX_FORMAT0_PAIRS {
+                    return Err(SerializationError(format!(
+                        "Too many pairs ({}) for a kern format 0 subtable",
+                        pairs.len()
+                    )));
+                }
+                let (search_range, entry_selector, range_shift) =
+                    get_search_range(pairs.len() as uint16, 6);
+                (pairs.len() as uint16).to_bytes(data)?;
+                search_range.to_bytes(data)?;
+                entry_selector.to_bytes(data)?;
+                range_shift.to_bytes(data)?;
+                for ((left, right), value) in pairs {
+                    left.to_bytes(data)?;
+                    right.to_bytes(data)?;
+                    value.to_bytes(data)?;
+                }
+            }
+            Kerning::Classes(classes) => {
+                let row_width = 2 * row_length(&classes.values)?;
+                let left_offset = header_size + 8;
+                let mut left_table = vec![];
+                let mut right_table = vec![];
+                let class_table_size = |c: &BTreeMap<GlyphID, uint16>| {
+                    let span = match (c.keys().next(), c.keys().next_back()) {
+                        (Some(first), Some(last)) => (last - first) as usize + 1,
+                        _ => 0,
+                    };
+                    4 + 2 * span
+                };
+                let right_offset = left_offset + class_table_size(&classes.left_classes);
+                let array_offset = right_offset + class_table_size(&classes.right_classes);
+                write_class_table(
+                    &classes.left_classes,
+                    |class| array_offset + class as usize * row_width,
+                    &mut left_table,
+                )?;
+                write_class_table(
+                    &classes.right_classes,
+                    |class| 2 * class as usize,
+                    &mut right_table,
+                )?;
+                let offset = |o: usize| {
+                    uint16::try_from(o).map_err(|_| {
+                        SerializationError("kern format 2 offset overflow".to_string())
+                    })
+                };
+                offset(row_width)?.to_bytes(data)?;
+                offset(left_offset)?.to_bytes(data)?;
+                offset(right_offset)?.to_bytes(data)?;
+                offset(array_offset)?.to_bytes(data)?;
+                data.extend(left_table);
+                data.extend(right_table);
+                for row in &classes.values {
+                    row.to_bytes(data)?;
+                }
+            }
+            Kerning::CompactClasses(classes) => {
+                let right_class_count = row_length(&classes.kern_index)?;
+                if classes.left_classes.len() != classes.right_classes.len() {
+                    return Err(SerializationError(
+                        "kern format 3 needs a left and right class for every glyph".to_string(),
+                    ));
+                }
+                let count = |n: usize| {
+                    uint8::try_from(n).map_err(|_| {
+                        SerializationError(
+                            "Too many classes or values for kern format 3".to_string(),
+                        )
+                    })
+                };
+                (classes.left_classes.len() as uint16).to_bytes(data)?;
+                count(classes.kern_values.len())?.to_bytes(data)?;
+                count(classes.kern_index.len())?.to_bytes(data)?;
+                count(right_class_count)?.to_bytes(data)?;
+                0_u8.to_bytes(data)?;
+                classes.kern_values.to_bytes(data)?;
+                classes.left_classes.to_bytes(data)?;
+                classes.right_classes.to_bytes(data)?;
+                for row in &classes.kern_index {
+                    row.to_bytes(data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for kern {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        if self.apple {
+            0x00010000_u32.to_bytes(data)?;
+            (self.subtables.len() as uint32).to_bytes(data)?;
+        } else {
+            0_u16.to_bytes(data)?;
+            (self.subtables.len() as uint16).to_bytes(data)?;
+        }
+        for subtable in &self.subtables {
+            let header_size = if self.apple { 8 } else { 6 };
+            let mut body = vec![];
+            subtable.kerning.to_bytes(header_size, &mut body)?;
+            let length = header_size + body.len();
+            let format = subtable.kerning.format();
+            let coverage = subtable.coverage.to_raw(self.apple);
+            if self.apple {
+                (length as uint32).to_bytes(data)?;
+                coverage.to_bytes(data)?;
+                format.to_bytes(data)?;
+                subtable.tuple_index.to_bytes(data)?;
+            } else {
+                if format == 3 {
+                    return Err(SerializationError(
+                        "kern format 3 subtables are only allowed in Apple tables".to_string(),
+                    ));
+                }
+                0_u16.to_bytes(data)?;
+                uint16::try_from(length)
+                    .map_err(|_| SerializationError("kern subtable too long".to_string()))?
+                    .to_bytes(data)?;
+                format.to_bytes(data)?;
+                coverage.to_bytes(data)?;
+            }
+            data.extend(body);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otspec::btreemap;
+
+    #[test]
+    fn kern_serde_v0() {
+        let binary_kern = vec![
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x01, 0x00, 0x02, 0x00, 0x0c,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x05, 0xff, 0xb0, 0x00, 0x05, 0x00, 0x01,
+            0x00, 0x14,
+        ];
+        let table: kern = otspec::de::from_bytes(&binary_kern).unwrap();
+        let pairs = btreemap!((1, 5) => -80, (5, 1) => 20);
+        assert_eq!(table, kern::from_pairs(&pairs));
+        assert_eq!(table.kerning(1, 5), -80);
+        assert_eq!(table.kerning(5, 5), 0);
+        assert_eq!(otspec::ser::to_bytes(&table).unwrap(), binary_kern);
+    }
+
+    #[test]
+    fn kern_apple_classes() {
+        let table = kern {
+            apple: true,
+            subtables: vec![
+                KernSubtable {
+                    coverage: KernCoverage::HORIZONTAL,
+                    tuple_index: 0,
+                    kerning: Kerning::Classes(ClassKerning {
+                        left_classes: btreemap!(3 => 1, 4 => 1),
+                        right_classes: btreemap!(7 => 1),
+                        values: vec![vec![0, 0], vec![0, -50]],
+                    }),
+                },
+                KernSubtable {
+                    coverage: KernCoverage::HORIZONTAL | KernCoverage::CROSS_STREAM,
+                    tuple_index: 0,
+                    kerning: Kerning::CompactClasses(CompactClassKerning {
+                        kern_values: vec![0, 10],
+                        left_classes: vec![0, 1, 0],
+                        right_classes: vec![0, 0, 1],
+                        kern_index: vec![vec![0, 0], vec![0, 1]],
+                    }),
+                },
+            ],
+        };
+        let binary_kern = otspec::ser::to_bytes(&table).unwrap();
+        assert_eq!(&binary_kern[..8], &[0, 1, 0, 0, 0, 0, 0, 2]);
+        // The cross-stream subtable is 0x40 in Apple coverage
+        assert_eq!(binary_kern[8 + 38 + 4], 0x40);
+        let deserialized: kern = otspec::de::from_bytes(&binary_kern).unwrap();
+        assert_eq!(deserialized, table);
+        assert_eq!(table.kerning(4, 7), -50);
+        assert_eq!(table.kerning(5, 7), 0);
+        assert_eq!(table.subtables[1].kerning.get(1, 2), Some(10));
+    }
+}
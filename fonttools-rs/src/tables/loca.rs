@@ -8,6 +8,7 @@ pub const TAG: otspec::types::Tag = crate::tag!("loca");
 /// [`loca`]: https://docs.microsoft.com/en-us/typography/opentype/spec/loca
 #[allow(non_snake_case, non_camel_case_types)]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct loca {
     /// The offset position of each glyph in the font.
     ///
@@ -0,0 +1,144 @@
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+};
+use std::collections::BTreeMap;
+
+/// The 'meta' OpenType tag.
+pub const TAG: Tag = crate::tag!("meta");
+
+/// The metadata tag for the languages the font was designed for.
+pub const DLNG: Tag = crate::tag!("dlng");
+/// The metadata tag for the languages the font supports.
+pub const SLNG: Tag = crate::tag!("slng");
+
+/// Metadata Table
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct meta {
+    /// The metadata, keyed by tag
+    pub data: BTreeMap<Tag, Vec<u8>>,
+}
+
+fn split_languages(data: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(data)
+        .split(',')
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+impl meta {
+    /// Creates a table declaring that the font is designed for, and supports,
+    /// the given languages.
+    ///
+    /// Languages are given as BCP 47 tags, such as `"en-Latn"` or `"Cyrl"`.
+    pub fn from_languages(languages: &[&str]) -> meta {
+        let mut table = meta::default();
+        table.set_design_languages(languages);
+        table.set_supported_languages(languages);
+        table
+    }
+
+    /// The BCP 47 tags of the languages the font was designed for
+    pub fn design_languages(&self) -> Vec<String> {
+        self.data
+            .get(&DLNG)
+            .map_or_else(Vec::new, |d| split_languages(d))
+    }
+
+    /// The BCP 47 tags of the languages the font supports
+    pub fn supported_languages(&self) -> Vec<String> {
+        self.data
+            .get(&SLNG)
+            .map_or_else(Vec::new, |d| split_languages(d))
+    }
+
+    /// Sets the languages the font was designed for
+    pub fn set_design_languages(&mut self, languages: &[&str]) {
+        self.data.insert(DLNG, languages.join(", ").into_bytes());
+    }
+
+    /// Sets the languages the font supports
+    pub fn set_supported_languages(&mut self, languages: &[&str]) {
+        self.data.insert(SLNG, languages.join(", ").into_bytes());
+    }
+}
+
+impl Deserialize for meta {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let version: uint32 = c.de()?;
+        if version != 1 {
+            return Err(DeserializationError(format!(
+                "Unknown meta version {}",
+                version
+            )));
+        }
+        let _flags: uint32 = c.de()?;
+        let _reserved: uint32 = c.de()?;
+        let count: uint32 = c.de()?;
+        let mut maps = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let tag: Tag = c.de()?;
+            let offset: uint32 = c.de()?;
+            let length: uint32 = c.de()?;
+            maps.push((tag, offset, length));
+        }
+        let mut data = BTreeMap::new();
+        for (tag, offset, length) in maps {
+            c.ptr = offset as usize;
+            data.insert(tag, c.de_counted(length as usize)?);
+        }
+        Ok(meta { data })
+    }
+}
+
+impl Serialize for meta {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        1_u32.to_bytes(data)?;
+        0_u32.to_bytes(data)?;
+        0_u32.to_bytes(data)?;
+        (self.data.len() as uint32).to_bytes(data)?;
+        let mut offset = 16 + 12 * self.data.len();
+        for (tag, value) in &self.data {
+            tag.to_bytes(data)?;
+            (offset as uint32).to_bytes(data)?;
+            (value.len() as uint32).to_bytes(data)?;
+            offset += value.len();
+        }
+        for value in self.data.values() {
+            data.extend(value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_serde() {
+        let binary_meta = vec![
+            0x00, 0x00, 0x00, 0x01, // version
+            0x00, 0x00, 0x00, 0x00, // flags
+            0x00, 0x00, 0x00, 0x00, // reserved
+            0x00, 0x00, 0x00, 0x02, // dataMapsCount
+            b'd', b'l', b'n', b'g', 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x0e, // dlng
+            b's', b'l', b'n', b'g', 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x0e, // slng
+            b'e', b'n', b'-', b'L', b'a', b't', b'n', b',', b' ', b'C', b'y', b'r', b'l',
+            b' ', // dlng data
+            b'e', b'n', b'-', b'L', b'a', b't', b'n', b',', b' ', b'C', b'y', b'r', b'l',
+            b' ', // slng data
+        ];
+        let fmeta: meta = otspec::de::from_bytes(&binary_meta).unwrap();
+        assert_eq!(fmeta.design_languages(), vec!["en-Latn", "Cyrl"]);
+        assert_eq!(fmeta.supported_languages(), vec!["en-Latn", "Cyrl"]);
+        assert_eq!(otspec::ser::to_bytes(&fmeta).unwrap(), binary_meta);
+
+        let built = meta::from_languages(&["en-Latn", "Cyrl"]);
+        assert_eq!(built.design_languages(), fmeta.design_languages());
+        assert_eq!(built.data[&SLNG], b"en-Latn, Cyrl");
+    }
+}
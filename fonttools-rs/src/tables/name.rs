@@ -121,6 +121,7 @@ tables!(
 
 /// A single name record to be placed inside the name table
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_snake_case)]
 pub struct NameRecord {
     /// Platform ID (0=Unicode, 1=Macintosh, 3=Windows)
@@ -161,6 +162,7 @@ impl NameRecord {
 
 /// Represents a font's name (Naming) table
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub struct name {
     /// A set of name records.
@@ -212,7 +212,7 @@ fn glyph_unicode_range(target: &u32) -> u32 {
 }
 
 tables!(
-    Panose {
+    Panose [serde] {
         u8 panose0
         u8 panose1
         u8 panose2
@@ -277,6 +277,7 @@ tables!(
 
 /// Represents a font's OS/2 (OS/2 and Windows Metrics) table
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types, non_snake_case)]
 pub struct os2 {
     /// Table version (between 0 and 5)
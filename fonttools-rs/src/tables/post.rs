@@ -10,7 +10,7 @@ pub const TAG: Tag = crate::tag!("post");
 /// The list of 258 standard Macintosh glyph names.
 /// Names not in this list will be stored separately in the post table if
 /// version==2
-const APPLE_NAMES: &[&str] = &[
+pub(crate) const APPLE_NAMES: &[&str] = &[
     ".notdef",
     ".null",
     "nonmarkingreturn",
@@ -285,6 +285,7 @@ tables!( postcore {
 
 /// Represents the font's post (PostScript) table
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_snake_case, non_camel_case_types)]
 pub struct post {
     /// version of the post table (either 0.5 or 1.0), expressed as a Fixed::U16F16.
@@ -0,0 +1,228 @@
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serialize};
+
+/// The 'sbix' OpenType tag.
+pub const TAG: Tag = crate::tag!("sbix");
+
+/// The graphic type of a glyph which reuses the image of another glyph.
+///
+/// The data of such a glyph is the big-endian ID of the glyph to reuse.
+pub const DUPE: Tag = crate::tag!("dupe");
+
+/// A glyph image within a strike
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SbixGlyph {
+    /// The horizontal offset of the image from the glyph origin
+    pub origin_offset_x: int16,
+    /// The vertical offset of the image from the glyph origin
+    pub origin_offset_y: int16,
+    /// The format of the image data: `png `, `jpg `, `tiff` or `dupe`
+    pub graphic_type: Tag,
+    /// The image data
+    pub data: Vec<u8>,
+}
+
+/// A set of glyph images at a particular size
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Strike {
+    /// The pixels-per-em size for which the images were designed
+    pub ppem: uint16,
+    /// The device pixel density (in pixels per inch) for which the images were designed
+    pub ppi: uint16,
+    /// The image of each glyph in the font, or `None` if the glyph has no
+    /// image at this size.
+    pub glyphs: Vec<Option<SbixGlyph>>,
+}
+
+impl Strike {
+    /// Creates an empty strike for a font with the given number of glyphs.
+    pub fn new(ppem: uint16, ppi: uint16, num_glyphs: uint16) -> Self {
+        Strike {
+            ppem,
+            ppi,
+            glyphs: vec![None; num_glyphs as usize],
+        }
+    }
+
+    /// Returns the image for a glyph, following `dupe` references to the
+    /// glyph whose image is reused.
+    pub fn glyph(&self, glyph_id: GlyphID) -> Option<&SbixGlyph> {
+        let glyph = self.glyphs.get(glyph_id as usize)?.as_ref()?;
+        if glyph.graphic_type == DUPE && glyph.data.len() == 2 {
+            let target = u16::from_be_bytes([glyph.data[0], glyph.data[1]]);
+            return self.glyphs.get(target as usize)?.as_ref();
+        }
+        Some(glyph)
+    }
+
+    /// Sets (or, given `None`, removes) the image for a glyph.
+    pub fn set_glyph(&mut self, glyph_id: GlyphID, glyph: Option<SbixGlyph>) {
+        if self.glyphs.len() <= glyph_id as usize {
+            self.glyphs.resize(glyph_id as usize + 1, None);
+        }
+        self.glyphs[glyph_id as usize] = glyph;
+    }
+}
+
+/// Standard Bitmap Graphics Table
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct sbix {
+    /// Bit 0 must be set; bit 1 requests that outlines are drawn as well as
+    /// the bitmaps
+    pub flags: uint16,
+    /// The strikes, one per image size
+    pub strikes: Vec<Strike>,
+}
+
+impl Default for sbix {
+    fn default() -> Self {
+        sbix {
+            flags: 1,
+            strikes: vec![],
+        }
+    }
+}
+
+impl sbix {
+    /// Returns the strike best suited to rendering at the given size.
+    ///
+    /// This is the smallest strike at least as large as `ppem`, or the
+    /// largest strike if there is none.
+    pub fn strike_for_ppem(&self, ppem: uint16) -> Option<&Strike> {
+        self.strikes
+            .iter()
+            .filter(|s| s.ppem >= ppem)
+            .min_by_key(|s| s.ppem)
+            .or_else(|| self.strikes.iter().max_by_key(|s| s.ppem))
+    }
+}
+
+/// Deserializes a Standard Bitmap Graphics Table given a binary vector and
+/// the number of glyphs in the font.
+pub fn from_bytes(c: &mut ReaderContext, num_glyphs: uint16) -> Result<sbix, DeserializationError> {
+    let _version: uint16 = c.de()?;
+    let flags: uint16 = c.de()?;
+    let num_strikes: uint32 = c.de()?;
+    let strike_offsets: Vec<uint32> = c.de_counted(num_strikes as usize)?;
+    let mut strikes = Vec::with_capacity(strike_offsets.len());
+    for offset in strike_offsets {
+        let start = offset as usize;
+        c.ptr = start;
+        let ppem: uint16 = c.de()?;
+        let ppi: uint16 = c.de()?;
+        let glyph_offsets: Vec<uint32> = c.de_counted(num_glyphs as usize + 1)?;
+        let mut glyphs = Vec::with_capacity(num_glyphs as usize);
+        for window in glyph_offsets.windows(2) {
+            if window[1] <= window[0] {
+                glyphs.push(None);
+                continue;
+            }
+            let length = (window[1] - window[0]) as usize;
+            if length < 8 {
+                return Err(DeserializationError(
+                    "sbix glyph record too short".to_string(),
+                ));
+            }
+            c.ptr = start + window[0] as usize;
+            glyphs.push(Some(SbixGlyph {
+                origin_offset_x: c.de()?,
+                origin_offset_y: c.de()?,
+                graphic_type: c.de()?,
+                data: c.de_counted(length - 8)?,
+            }));
+        }
+        strikes.push(Strike { ppem, ppi, glyphs });
+    }
+    Ok(sbix { flags, strikes })
+}
+
+impl Serialize for sbix {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let num_glyphs = self
+            .strikes
+            .iter()
+            .map(|s| s.glyphs.len())
+            .max()
+            .unwrap_or(0);
+        let mut strike_data: Vec<Vec<u8>> = vec![];
+        for strike in &self.strikes {
+            let mut out = vec![];
+            strike.ppem.to_bytes(&mut out)?;
+            strike.ppi.to_bytes(&mut out)?;
+            let mut offset = 4 + 4 * (num_glyphs + 1);
+            let mut glyph_data = vec![];
+            for ix in 0..num_glyphs {
+                (offset as uint32).to_bytes(&mut out)?;
+                if let Some(Some(glyph)) = strike.glyphs.get(ix) {
+                    glyph.origin_offset_x.to_bytes(&mut glyph_data)?;
+                    glyph.origin_offset_y.to_bytes(&mut glyph_data)?;
+                    glyph.graphic_type.to_bytes(&mut glyph_data)?;
+                    glyph_data.extend(&glyph.data);
+                    offset += 8 + glyph.data.len();
+                }
+            }
+            (offset as uint32).to_bytes(&mut out)?;
+            out.extend(glyph_data);
+            strike_data.push(out);
+        }
+
+        1_u16.to_bytes(data)?;
+        self.flags.to_bytes(data)?;
+        (self.strikes.len() as uint32).to_bytes(data)?;
+        let mut offset = 8 + 4 * self.strikes.len();
+        for strike in &strike_data {
+            (offset as uint32).to_bytes(data)?;
+            offset += strike.len();
+        }
+        for strike in strike_data {
+            data.extend(strike);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sbix_serde() {
+        let binary_sbix = vec![
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x40,
+            0x00, 0x48, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x20,
+            0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0xff, 0xfc, 0x70, 0x6e, 0x67, 0x20, 0x89, 0x50,
+            0x4e, 0x47, 0x00, 0x00, 0x00, 0x00, 0x64, 0x75, 0x70, 0x65, 0x00, 0x01,
+        ];
+        let table = from_bytes(&mut ReaderContext::new(binary_sbix.clone()), 3).unwrap();
+        let png = SbixGlyph {
+            origin_offset_x: 0,
+            origin_offset_y: -4,
+            graphic_type: crate::tag!("png "),
+            data: vec![0x89, 0x50, 0x4e, 0x47],
+        };
+        let expected = sbix {
+            flags: 1,
+            strikes: vec![Strike {
+                ppem: 64,
+                ppi: 72,
+                glyphs: vec![
+                    None,
+                    Some(png.clone()),
+                    Some(SbixGlyph {
+                        origin_offset_x: 0,
+                        origin_offset_y: 0,
+                        graphic_type: DUPE,
+                        data: vec![0x00, 0x01],
+                    }),
+                ],
+            }],
+        };
+        assert_eq!(table, expected);
+        assert_eq!(table.strike_for_ppem(32).unwrap().glyph(2), Some(&png));
+        assert_eq!(otspec::ser::to_bytes(&table).unwrap(), binary_sbix);
+    }
+}
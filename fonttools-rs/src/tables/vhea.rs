@@ -0,0 +1,64 @@
+use otspec::types::*;
+use otspec::Deserializer;
+use otspec_macros::tables;
+
+/// The 'vhea' OpenType tag.
+pub const TAG: Tag = crate::tag!("vhea");
+
+tables!(vhea [serde] {
+    uint16 majorVersion
+    uint16 minorVersion
+    FWORD vertTypoAscender
+    FWORD vertTypoDescender
+    FWORD vertTypoLineGap
+    UFWORD  advanceHeightMax
+    FWORD   minTopSideBearing
+    FWORD   minBottomSideBearing
+    FWORD   yMaxExtent
+    int16   caretSlopeRise
+    int16   caretSlopeRun
+    int16   caretOffset
+    int16   reserved0
+    int16   reserved1
+    int16   reserved2
+    int16   reserved3
+    int16   metricDataFormat
+    uint16  numOfLongVerMetrics
+});
+
+#[cfg(test)]
+mod tests {
+    use otspec::ser;
+
+    #[test]
+    fn vhea_serde() {
+        let fvhea = super::vhea {
+            majorVersion: 1,
+            minorVersion: 0x1000,
+            vertTypoAscender: 500,
+            vertTypoDescender: -500,
+            vertTypoLineGap: 0,
+            advanceHeightMax: 1000,
+            minTopSideBearing: -12,
+            minBottomSideBearing: -88,
+            yMaxExtent: 1012,
+            caretSlopeRise: 0,
+            caretSlopeRun: 1,
+            caretOffset: 0,
+            reserved0: 0,
+            reserved1: 0,
+            reserved2: 0,
+            reserved3: 0,
+            metricDataFormat: 0,
+            numOfLongVerMetrics: 3,
+        };
+        let binary_vhea = vec![
+            0x00, 0x01, 0x10, 0x00, 0x01, 0xf4, 0xfe, 0x0c, 0x00, 0x00, 0x03, 0xe8, 0xff, 0xf4,
+            0xff, 0xa8, 0x03, 0xf4, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        ];
+        assert_eq!(ser::to_bytes(&fvhea).unwrap(), binary_vhea);
+        let deserialized: super::vhea = otspec::de::from_bytes(&binary_vhea).unwrap();
+        assert_eq!(deserialized, fvhea);
+    }
+}
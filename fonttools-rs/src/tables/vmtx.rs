@@ -0,0 +1,136 @@
+use std::convert::TryInto;
+
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, Serialize};
+use otspec_macros::{Deserialize, Serialize};
+
+/// The 'vmtx' OpenType tag.
+pub const TAG: Tag = crate::tag!("vmtx");
+
+/// A single vertical metric
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_snake_case)]
+pub struct Metric {
+    /// The full vertical advance height of the glyph
+    pub advanceHeight: u16,
+    /// The top side bearing of the glyph
+    pub tsb: int16,
+}
+
+/// The vertical metrics table
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub struct vmtx {
+    /// The list of metrics, corresponding to the glyph order
+    pub metrics: Vec<Metric>,
+}
+
+impl vmtx {
+    /// Serialize the vertical metrics table to a binary vector and a corresponding
+    /// number of vertical metrics (to be stored in the `vhea` table)
+    pub fn to_bytes(&self) -> (Vec<u8>, uint16) {
+        let num_v_metrics = self.number_of_vmetrics();
+        let mut bytes: Vec<u8> = vec![];
+
+        for (i, metric) in self.metrics.iter().enumerate() {
+            if i < num_v_metrics as usize {
+                bytes.extend(otspec::ser::to_bytes(&metric).unwrap());
+            } else {
+                bytes.extend(otspec::ser::to_bytes(&metric.tsb).unwrap());
+            }
+        }
+
+        (bytes, num_v_metrics)
+    }
+
+    /// The number of vertical metrics (to be stored in the `vhea` table)
+    pub fn number_of_vmetrics(&self) -> uint16 {
+        let last = match self.metrics.last() {
+            Some(metric) => metric.advanceHeight,
+            None => return 0,
+        };
+
+        let dupe_heights = self
+            .metrics
+            .iter()
+            .rev()
+            .skip(1)
+            .take_while(|m| m.advanceHeight == last)
+            .count();
+        (self.metrics.len() - dupe_heights).try_into().unwrap()
+    }
+}
+
+impl Serialize for vmtx {
+    fn to_bytes(
+        &self,
+        _: &mut std::vec::Vec<u8>,
+    ) -> std::result::Result<(), otspec::SerializationError> {
+        Err(otspec::SerializationError(
+            "Can't serialize vmtx directly".to_string(),
+        ))
+    }
+}
+
+/// Deserializes a Vertical Metrics Table given a binary vector and the
+/// `numOfLongVerMetrics` field of the `vhea` table.
+pub fn from_bytes(
+    c: &mut ReaderContext,
+    num_of_long_ver_metrics: uint16,
+) -> Result<vmtx, DeserializationError> {
+    let mut res = vmtx {
+        metrics: Vec::new(),
+    };
+    for _ in 0..num_of_long_ver_metrics {
+        let metric: Metric = c.de()?;
+        res.metrics.push(metric)
+    }
+    let maybe_other_metrics: Result<Vec<int16>, DeserializationError> = c.de();
+    if let Ok(other_metrics) = maybe_other_metrics {
+        let last = res
+            .metrics
+            .last()
+            .expect("Must be one advance height in vmtx!")
+            .advanceHeight;
+        res.metrics.extend(other_metrics.iter().map(|x| Metric {
+            tsb: *x,
+            advanceHeight: last,
+        }))
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vmtx_serde() {
+        let binary_vmtx = vec![
+            0x03, 0xe8, 0x00, 0x64, 0x04, 0x4c, 0xff, 0xf4, 0x03, 0xe8, 0x00, 0x0a, 0x00, 0x14,
+        ];
+        let fvmtx = super::from_bytes(&mut ReaderContext::new(binary_vmtx.clone()), 3).unwrap();
+        let metrics = [
+            Metric {
+                advanceHeight: 1000,
+                tsb: 100,
+            },
+            Metric {
+                advanceHeight: 1100,
+                tsb: -12,
+            },
+            Metric {
+                advanceHeight: 1000,
+                tsb: 10,
+            },
+            Metric {
+                advanceHeight: 1000,
+                tsb: 20,
+            },
+        ];
+        assert_eq!(fvmtx.metrics, metrics);
+        assert_eq!(fvmtx.to_bytes(), (binary_vmtx, 3));
+    }
+}
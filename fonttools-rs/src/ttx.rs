@@ -0,0 +1,833 @@
+//! Dumping fonts to, and compiling fonts from, TTX-style XML.
+//!
+//! The XML layout follows that of the `ttx` tool from the Python fontTools
+//! library, so that fonts can be inspected and diffed as text:
+//!
+//! ```no_run
+//! use fonttools::{font::Font, ttx};
+//!
+//! let mut font = Font::load("Test.ttf").expect("Could not load font");
+//! let xml = ttx::to_xml(&mut font).expect("Could not dump font");
+//! let mut roundtripped = ttx::from_xml(&xml).expect("Could not compile font");
+//! roundtripped.save("Test-roundtrip.ttf").expect("Could not save font");
+//! ```
+//!
+//! Tables without an XML representation (including the `MATH` table and
+//! any unknown tables) are written as hex data.
+use crate::font::{Font, SfntVersion};
+use crate::layout::common::FromLowlevel;
+use crate::tables;
+use otspec::types::*;
+use otspec::DeserializationError;
+use std::collections::HashMap;
+use std::error::Error;
+use xml::{attr, elements, error, hexdata, Node, XmlWriter};
+
+mod basic;
+mod cmap;
+mod color;
+mod glyf;
+mod instructions;
+mod layout;
+mod variations;
+mod xml;
+
+const TTF_TABLE_ORDER: &[&str] = &[
+    "head", "hhea", "maxp", "OS/2", "hmtx", "LTSH", "VDMX", "hdmx", "cmap", "fpgm", "prep", "cvt ",
+    "loca", "glyf", "kern", "name", "post", "gasp", "PCLT",
+];
+const OTF_TABLE_ORDER: &[&str] = &[
+    "head", "hhea", "maxp", "OS/2", "name", "cmap", "post", "CFF ",
+];
+
+/// The names of a font's glyphs, used to refer to glyphs in the XML.
+#[derive(Debug, Default)]
+pub(crate) struct GlyphOrder {
+    names: Vec<String>,
+    ids: HashMap<String, GlyphID>,
+}
+
+impl GlyphOrder {
+    fn new(names: Vec<String>) -> Self {
+        let ids = names
+            .iter()
+            .enumerate()
+            .map(|(ix, name)| (name.clone(), ix as GlyphID))
+            .collect();
+        GlyphOrder { names, ids }
+    }
+
+    /// Builds a glyph order with unique names, suffixing duplicates with
+    /// `#1`, `#2`... as fontTools does.
+    fn new_deduplicated(names: Vec<String>) -> Self {
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut unique = Vec::with_capacity(names.len());
+        for name in names {
+            let count = seen.entry(name.clone()).or_insert(0);
+            if *count == 0 {
+                unique.push(name);
+            } else {
+                unique.push(format!("{}#{}", name, count));
+            }
+            *count += 1;
+        }
+        GlyphOrder::new(unique)
+    }
+
+    /// The number of glyphs in the font.
+    pub(crate) fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// The glyph names, in glyph ID order.
+    pub(crate) fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The name of a glyph.
+    pub(crate) fn name(&self, gid: GlyphID) -> String {
+        self.names
+            .get(gid as usize)
+            .cloned()
+            .unwrap_or_else(|| format!("glyph{:05}", gid))
+    }
+
+    /// The ID of a named glyph.
+    pub(crate) fn id(&self, name: &str) -> Result<GlyphID, DeserializationError> {
+        if let Some(&gid) = self.ids.get(name) {
+            return Ok(gid);
+        }
+        name.strip_prefix("glyph")
+            .and_then(|digits| digits.parse().ok())
+            .ok_or_else(|| error(format!("Unknown glyph name '{}'", name)))
+    }
+
+    /// Parses a comma-separated list of glyph names.
+    pub(crate) fn ids(&self, names: &str) -> Result<Vec<GlyphID>, DeserializationError> {
+        names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| self.id(name))
+            .collect()
+    }
+
+    /// Formats a list of glyphs as a comma-separated list of names.
+    pub(crate) fn join(&self, gids: impl IntoIterator<Item = GlyphID>) -> String {
+        gids.into_iter()
+            .map(|gid| self.name(gid))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Works out the glyph names of a binary font, from the `post` table if it
+/// has glyph names, otherwise from the `cmap` table.
+fn font_glyph_order(font: &Font) -> Result<GlyphOrder, DeserializationError> {
+    let num_glyphs = match font.tables.maxp()? {
+        Some(maxp) => maxp.num_glyphs() as usize,
+        None => font.tables.glyf()?.map_or(0, |glyf| glyf.glyphs.len()),
+    };
+    if let Some(names) = font.tables.post()?.and_then(|post| post.glyphnames.clone()) {
+        if names.len() == num_glyphs {
+            return Ok(GlyphOrder::new_deduplicated(names));
+        }
+    }
+    let mut codepoints: HashMap<GlyphID, u32> = HashMap::new();
+    if let Some(cmap) = font.tables.cmap()? {
+        for subtable in cmap.subtables.iter().filter(|st| st.is_unicode()) {
+            for (&codepoint, &gid) in &subtable.mapping {
+                let best = codepoints.entry(gid).or_insert(codepoint);
+                *best = (*best).min(codepoint);
+            }
+        }
+    }
+    let names = (0..num_glyphs)
+        .map(|gid| match (gid, codepoints.get(&(gid as GlyphID))) {
+            (0, _) => ".notdef".to_string(),
+            (_, Some(&cp)) if cp <= 0xFFFF => format!("uni{:04X}", cp),
+            (_, Some(&cp)) => format!("u{:X}", cp),
+            (_, None) => format!("glyph{:05}", gid),
+        })
+        .collect();
+    Ok(GlyphOrder::new_deduplicated(names))
+}
+
+/// Converts a table tag to an XML element name, as fontTools does.
+fn tag_to_xml(tag: Tag) -> String {
+    let tag = tag.as_str();
+    if tag == "OS/2" {
+        return "OS_2".to_string();
+    }
+    let trimmed = tag.trim_end_matches(' ');
+    let mut chars = trimmed.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        return trimmed.to_string();
+    }
+    let mut ident: String = tag
+        .bytes()
+        .map(|c| match c {
+            b'a'..=b'z' | b'0'..=b'9' => format!("_{}", c as char),
+            b'A'..=b'Z' => format!("{}_", c as char),
+            _ => format!("{:x}", c),
+        })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Converts an XML element name back into a table tag.
+fn xml_to_tag(name: &str) -> Result<Tag, DeserializationError> {
+    if name == "OS_2" {
+        return Ok(tables::os2::TAG);
+    }
+    let tag = if name.len() == 8 || name.len() == 9 && name.starts_with('_') {
+        let ident = if name.len() == 9 { &name[1..] } else { name };
+        let mut tag = vec![];
+        for pair in ident.as_bytes().chunks(2) {
+            tag.push(match pair {
+                [b'_', c] | [c, b'_'] => *c,
+                _ => std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|s| u8::from_str_radix(s, 16).ok())
+                    .ok_or_else(|| error(format!("Bad table name '{}'", name)))?,
+            });
+        }
+        tag
+    } else {
+        name.as_bytes().to_vec()
+    };
+    let mut tag = tag;
+    tag.resize(4, b' ');
+    Tag::from_raw(&tag).map_err(|_| error(format!("Bad table name '{}'", name)))
+}
+
+/// Orders tables the way fontTools does: the conventional table order,
+/// then everything else alphabetically, with `DSIG` at the end.
+fn sorted_tags(mut tags: Vec<Tag>) -> Vec<Tag> {
+    tags.sort();
+    if let Some(pos) = tags.iter().position(|t| t == "DSIG") {
+        let dsig = tags.remove(pos);
+        tags.push(dsig);
+    }
+    let order = if tags.iter().any(|t| t == "glyf") {
+        TTF_TABLE_ORDER
+    } else {
+        OTF_TABLE_ORDER
+    };
+    let mut sorted: Vec<Tag> = order
+        .iter()
+        .filter_map(|&name| tags.iter().find(|t| *t == name).copied())
+        .collect();
+    sorted.extend(tags.into_iter().filter(|t| !order.contains(&t.as_str())));
+    sorted
+}
+
+fn sfnt_version_to_xml(version: SfntVersion) -> &'static str {
+    match version {
+        SfntVersion::TrueType => "\\x00\\x01\\x00\\x00",
+        SfntVersion::OpenType => "OTTO",
+    }
+}
+
+fn sfnt_version_from_xml(version: &str) -> Result<SfntVersion, DeserializationError> {
+    match version {
+        "\\x00\\x01\\x00\\x00" | "\u{0}\u{1}\u{0}\u{0}" | "true" => Ok(SfntVersion::TrueType),
+        "OTTO" => Ok(SfntVersion::OpenType),
+        _ => Err(error(format!("Unknown sfntVersion '{}'", version))),
+    }
+}
+
+/// Dumps a font as TTX-style XML.
+///
+/// This compiles the font first, so that the dump reflects what would be
+/// written to a binary file.
+pub fn to_xml(font: &mut Font) -> Result<String, Box<dyn Error>> {
+    font.compile();
+    let glyph_order = font_glyph_order(font)?;
+    let mut w = XmlWriter::new();
+    w.begin(
+        "ttFont",
+        &[("sfntVersion", &sfnt_version_to_xml(font.sfnt_version()))],
+    );
+    w.newline();
+    w.begin("GlyphOrder", &[]);
+    w.comment("The 'id' attribute is only for humans; it is ignored when parsed.");
+    for (ix, name) in glyph_order.names().iter().enumerate() {
+        w.simple("GlyphID", &[("id", &ix), ("name", name)]);
+    }
+    w.end("GlyphOrder");
+
+    for tag in sorted_tags(font.tables.keys().collect()) {
+        w.newline();
+        let name = tag_to_xml(tag);
+        let mut table = w.nested();
+        match dump_table(&mut table, font, tag, &glyph_order) {
+            Ok(true) => {
+                w.begin(&name, &[]);
+                w.append(table);
+                w.end(&name);
+            }
+            result => {
+                if let Err(e) = result {
+                    log::warn!("Couldn't dump {} as XML, writing binary data: {}", tag, e);
+                }
+                let mut data = vec![];
+                font.tables.write_table(tag, &mut data)?;
+                w.begin(&name, &[("raw", &"True")]);
+                w.begin("hexdata", &[]);
+                w.hex(&data);
+                w.end("hexdata");
+                w.end(&name);
+            }
+        }
+    }
+    w.newline();
+    w.end("ttFont");
+    Ok(w.finish())
+}
+
+/// Dumps a table's contents, returning `false` if the table has no XML
+/// representation.
+fn dump_table(
+    w: &mut XmlWriter,
+    font: &Font,
+    tag: Tag,
+    glyph_order: &GlyphOrder,
+) -> Result<bool, Box<dyn Error>> {
+    let t = &font.tables;
+    macro_rules! table {
+        ($getter:ident) => {
+            &*t.$getter()?
+                .ok_or_else(|| error(format!("Couldn't load {}", tag)))?
+        };
+    }
+    let axes: Vec<Tag> = t.fvar()?.map_or_else(Vec::new, |fvar| {
+        fvar.axes.iter().map(|a| a.axisTag).collect()
+    });
+    match tag.as_bytes() {
+        b"avar" => variations::dump_avar(w, table!(avar), &axes),
+        b"cmap" => cmap::dump_cmap(w, table!(cmap), glyph_order),
+        b"COLR" => color::dump_colr(w, table!(COLR), glyph_order),
+        b"CPAL" => color::dump_cpal(w, table!(CPAL)),
+        b"cvt " => basic::dump_cvt(w, table!(cvt)),
+        b"fpgm" => instructions::dump_program(w, &table!(fpgm).0),
+        b"fvar" => variations::dump_fvar(w, table!(fvar)),
+        b"gasp" => basic::dump_gasp(w, table!(gasp)),
+        b"GDEF" => layout::dump_gdef(w, table!(GDEF), glyph_order),
+        b"GPOS" | b"GSUB" => {
+            let mut data = vec![];
+            t.write_table(tag, &mut data)?;
+            layout::dump_gsub_gpos(w, tag, &data, glyph_order)?
+        }
+        b"glyf" => glyf::dump_glyf(w, table!(glyf), glyph_order),
+        b"gvar" => variations::dump_gvar(w, table!(gvar), &axes, glyph_order),
+        b"head" => basic::dump_head(w, table!(head)),
+        b"hhea" => basic::dump_hhea(w, table!(hhea)),
+        b"hmtx" => basic::dump_hmtx(w, table!(hmtx), glyph_order),
+        b"HVAR" => variations::dump_hvar(w, table!(HVAR), glyph_order),
+        b"kern" => basic::dump_kern(w, table!(kern), glyph_order)?,
+        b"loca" => w.comment("The 'loca' table will be calculated by the compiler"),
+        b"maxp" => basic::dump_maxp(w, table!(maxp)),
+        b"MVAR" => variations::dump_mvar(w, table!(MVAR)),
+        b"name" => basic::dump_name(w, table!(name)),
+        b"OS/2" => basic::dump_os2(w, table!(os2)),
+        b"post" => basic::dump_post(w, table!(post)),
+        b"prep" => instructions::dump_program(w, &table!(prep).0),
+        b"STAT" => variations::dump_stat(w, table!(STAT)),
+        b"vhea" => basic::dump_vhea(w, table!(vhea)),
+        b"vmtx" => basic::dump_vmtx(w, table!(vmtx), glyph_order),
+        b"VORG" => basic::dump_vorg(w, table!(VORG), glyph_order),
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Compiles a font from TTX-style XML.
+pub fn from_xml(xml: &str) -> Result<Font, Box<dyn Error>> {
+    let doc = roxmltree::Document::parse(xml)?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "ttFont" {
+        return Err(error("Not a TTX file").into());
+    }
+    let sfnt_version = root
+        .attribute("sfntVersion")
+        .map_or(Ok(SfntVersion::TrueType), sfnt_version_from_xml)?;
+    let glyph_order = GlyphOrder::new(
+        xml::required_child(root, "GlyphOrder")?
+            .children()
+            .filter(|n| n.has_tag_name("GlyphID"))
+            .map(|n| attr(n, "name").map(str::to_string))
+            .collect::<Result<_, _>>()?,
+    );
+    let axes: Vec<Tag> = match xml::child(root, "fvar") {
+        Some(node) => variations::load_fvar(node)?
+            .axes
+            .iter()
+            .map(|a| a.axisTag)
+            .collect(),
+        None => vec![],
+    };
+
+    let mut font = Font::new(sfnt_version);
+    for node in elements(root).filter(|n| !n.has_tag_name("GlyphOrder")) {
+        let tag = xml_to_tag(node.tag_name().name())?;
+        if let Some(data) = xml::child(node, "hexdata") {
+            font.tables.insert_raw(tag, hexdata(data)?);
+            continue;
+        }
+        load_table(&mut font, node, tag, &glyph_order, &axes)
+            .map_err(|e| error(format!("Error compiling {} table: {}", tag, e)))?;
+    }
+
+    // Fix up the counts which the XML leaves implicit.
+    if let Some(hmtx) = xml::child(root, "hmtx") {
+        let hmtx = basic::load_hmtx(hmtx, &glyph_order)?;
+        if let Some(mut hhea) = font.tables.hhea()? {
+            hhea.numberOfHMetrics = hmtx.number_of_hmetrics();
+            font.tables.insert(hhea);
+        }
+    }
+    if let Some(vmtx) = xml::child(root, "vmtx") {
+        let vmtx = basic::load_vmtx(vmtx, &glyph_order)?;
+        if let Some(mut vhea) = font.tables.vhea()? {
+            vhea.numOfLongVerMetrics = vmtx.number_of_vmetrics();
+            font.tables.insert(vhea);
+        }
+    }
+    if let Some(mut maxp) = font.tables.maxp()? {
+        maxp.set_num_glyphs(glyph_order.len() as u16);
+        font.tables.insert(maxp);
+    }
+    if let Some(mut post) = font.tables.post()? {
+        if post.glyphnames.is_some() {
+            post.glyphnames = Some(glyph_order.names().to_vec());
+            font.tables.insert(post);
+        }
+    }
+    Ok(font)
+}
+
+fn load_table(
+    font: &mut Font,
+    node: Node,
+    tag: Tag,
+    glyph_order: &GlyphOrder,
+    axes: &[Tag],
+) -> Result<(), DeserializationError> {
+    let t = &mut font.tables;
+    match tag.as_bytes() {
+        b"avar" => t.insert(variations::load_avar(node, axes)?),
+        b"cmap" => t.insert(cmap::load_cmap(node, glyph_order)?),
+        b"COLR" => t.insert(color::load_colr(node, glyph_order)?),
+        b"CPAL" => t.insert(color::load_cpal(node)?),
+        b"cvt " => t.insert(basic::load_cvt(node)?),
+        b"fpgm" => t.insert(tables::fpgm::fpgm(instructions::load_program(node)?)),
+        b"fvar" => t.insert(variations::load_fvar(node)?),
+        b"gasp" => t.insert(basic::load_gasp(node)?),
+        b"GDEF" => t.insert(layout::load_gdef(node, glyph_order)?),
+        b"GPOS" => {
+            let lowlevel = layout::load_gpos(node, glyph_order)?;
+            let max_glyph_id = glyph_order.len() as GlyphID;
+            t.insert(tables::GPOS::GPOS::from_lowlevel(lowlevel, max_glyph_id))
+        }
+        b"GSUB" => {
+            let lowlevel = layout::load_gsub(node, glyph_order)?;
+            let max_glyph_id = glyph_order.len() as GlyphID;
+            t.insert(tables::GSUB::GSUB::from_lowlevel(lowlevel, max_glyph_id))
+        }
+        b"glyf" => t.insert(glyf::load_glyf(node, glyph_order)?),
+        b"gvar" => {
+            let gvar = variations::load_gvar(node, axes, glyph_order)?;
+            t.insert_raw(tag, gvar.to_bytes(None))
+        }
+        b"head" => t.insert(basic::load_head(node)?),
+        b"hhea" => t.insert(basic::load_hhea(node)?),
+        b"hmtx" => t.insert_raw(tag, basic::load_hmtx(node, glyph_order)?.to_bytes().0),
+        b"HVAR" => t.insert(variations::load_hvar(node, glyph_order)?),
+        b"kern" => t.insert(basic::load_kern(node, glyph_order)?),
+        b"loca" => {}
+        b"maxp" => t.insert(basic::load_maxp(node)?),
+        b"MVAR" => t.insert(variations::load_mvar(node)?),
+        b"name" => t.insert(basic::load_name(node)?),
+        b"OS/2" => t.insert(basic::load_os2(node)?),
+        b"post" => t.insert(basic::load_post(node)?),
+        b"prep" => t.insert(tables::prep::prep(instructions::load_program(node)?)),
+        b"STAT" => t.insert(variations::load_stat(node)?),
+        b"vhea" => t.insert(basic::load_vhea(node)?),
+        b"vmtx" => t.insert_raw(tag, basic::load_vmtx(node, glyph_order)?.to_bytes().0),
+        b"VORG" => t.insert(basic::load_vorg(node, glyph_order)?),
+        _ => return Err(error("No XML representation for this table")),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_TTX: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<ttFont sfntVersion="\x00\x01\x00\x00" ttLibVersion="4.0">
+  <GlyphOrder>
+    <GlyphID id="0" name=".notdef"/>
+    <GlyphID id="1" name="f"/>
+    <GlyphID id="2" name="i"/>
+    <GlyphID id="3" name="f_i"/>
+    <GlyphID id="4" name="acutecomb"/>
+  </GlyphOrder>
+  <head>
+    <tableVersion value="1.0"/>
+    <fontRevision value="1.0"/>
+    <checkSumAdjustment value="0x0"/>
+    <magicNumber value="0x5f0f3cf5"/>
+    <flags value="00000000 00000011"/>
+    <unitsPerEm value="1000"/>
+    <created value="Fri Jan 15 14:37:13 2021"/>
+    <modified value="Fri Jan 15 14:37:13 2021"/>
+    <xMin value="0"/>
+    <yMin value="0"/>
+    <xMax value="500"/>
+    <yMax value="700"/>
+    <macStyle value="00000000 00000000"/>
+    <lowestRecPPEM value="6"/>
+    <fontDirectionHint value="2"/>
+    <indexToLocFormat value="0"/>
+    <glyphDataFormat value="0"/>
+  </head>
+  <hhea>
+    <tableVersion value="0x00010000"/>
+    <ascent value="800"/>
+    <descent value="-200"/>
+    <lineGap value="0"/>
+    <advanceWidthMax value="600"/>
+    <minLeftSideBearing value="0"/>
+    <minRightSideBearing value="0"/>
+    <xMaxExtent value="500"/>
+    <caretSlopeRise value="1"/>
+    <caretSlopeRun value="0"/>
+    <caretOffset value="0"/>
+    <reserved0 value="0"/>
+    <reserved1 value="0"/>
+    <reserved2 value="0"/>
+    <reserved3 value="0"/>
+    <metricDataFormat value="0"/>
+    <numberOfHMetrics value="5"/>
+  </hhea>
+  <maxp>
+    <tableVersion value="0x10000"/>
+    <numGlyphs value="5"/>
+    <maxPoints value="4"/>
+    <maxContours value="1"/>
+    <maxCompositePoints value="0"/>
+    <maxCompositeContours value="0"/>
+    <maxZones value="1"/>
+    <maxTwilightPoints value="0"/>
+    <maxStorage value="0"/>
+    <maxFunctionDefs value="0"/>
+    <maxInstructionDefs value="0"/>
+    <maxStackElements value="0"/>
+    <maxSizeOfInstructions value="0"/>
+    <maxComponentElements value="0"/>
+    <maxComponentDepth value="0"/>
+  </maxp>
+  <hmtx>
+    <mtx name=".notdef" width="500" lsb="0"/>
+    <mtx name="acutecomb" width="0" lsb="0"/>
+    <mtx name="f" width="300" lsb="0"/>
+    <mtx name="f_i" width="600" lsb="0"/>
+    <mtx name="i" width="300" lsb="0"/>
+  </hmtx>
+  <cmap>
+    <tableVersion version="0"/>
+    <cmap_format_4 platformID="3" platEncID="1" language="0">
+      <map code="0x66" name="f"/>
+      <map code="0x69" name="i"/>
+      <map code="0x301" name="acutecomb"/>
+    </cmap_format_4>
+  </cmap>
+  <glyf>
+    <TTGlyph name=".notdef"/>
+    <TTGlyph name="f">
+      <contour>
+        <pt x="0" y="0" on="1"/>
+        <pt x="0" y="700" on="1"/>
+        <pt x="300" y="700" on="1"/>
+        <pt x="300" y="0" on="1"/>
+      </contour>
+      <instructions/>
+    </TTGlyph>
+    <TTGlyph name="i"/>
+    <TTGlyph name="f_i"/>
+    <TTGlyph name="acutecomb"/>
+  </glyf>
+  <post>
+    <formatType value="2.0"/>
+    <italicAngle value="0.0"/>
+    <underlinePosition value="-75"/>
+    <underlineThickness value="50"/>
+    <isFixedPitch value="0"/>
+    <minMemType42 value="0"/>
+    <maxMemType42 value="0"/>
+    <minMemType1 value="0"/>
+    <maxMemType1 value="0"/>
+  </post>
+  <GDEF>
+    <Version value="0x00010000"/>
+    <GlyphClassDef>
+      <ClassDef glyph="acutecomb" class="3"/>
+      <ClassDef glyph="f" class="1"/>
+      <ClassDef glyph="f_i" class="2"/>
+      <ClassDef glyph="i" class="1"/>
+    </GlyphClassDef>
+    <LigCaretList>
+      <Coverage>
+        <Glyph value="f_i"/>
+      </Coverage>
+      <LigGlyph index="0">
+        <CaretValue index="0" Format="1">
+          <Coordinate value="300"/>
+        </CaretValue>
+      </LigGlyph>
+    </LigCaretList>
+  </GDEF>
+  <GPOS>
+    <Version value="0x00010000"/>
+    <ScriptList>
+      <ScriptRecord index="0">
+        <ScriptTag value="DFLT"/>
+        <Script>
+          <DefaultLangSys>
+            <ReqFeatureIndex value="65535"/>
+            <FeatureIndex index="0" value="0"/>
+          </DefaultLangSys>
+        </Script>
+      </ScriptRecord>
+    </ScriptList>
+    <FeatureList>
+      <FeatureRecord index="0">
+        <FeatureTag value="mark"/>
+        <Feature>
+          <LookupListIndex index="0" value="0"/>
+        </Feature>
+      </FeatureRecord>
+    </FeatureList>
+    <LookupList>
+      <Lookup index="0">
+        <LookupType value="4"/>
+        <LookupFlag value="0"/>
+        <MarkBasePos index="0" Format="1">
+          <MarkCoverage>
+            <Glyph value="acutecomb"/>
+          </MarkCoverage>
+          <BaseCoverage>
+            <Glyph value="f"/>
+            <Glyph value="i"/>
+          </BaseCoverage>
+          <MarkArray>
+            <MarkRecord index="0">
+              <Class value="0"/>
+              <MarkAnchor Format="1">
+                <XCoordinate value="0"/>
+                <YCoordinate value="500"/>
+              </MarkAnchor>
+            </MarkRecord>
+          </MarkArray>
+          <BaseArray>
+            <BaseRecord index="0">
+              <BaseAnchor index="0" Format="1">
+                <XCoordinate value="150"/>
+                <YCoordinate value="700"/>
+              </BaseAnchor>
+            </BaseRecord>
+            <BaseRecord index="1">
+              <BaseAnchor index="0" Format="1">
+                <XCoordinate value="150"/>
+                <YCoordinate value="500"/>
+              </BaseAnchor>
+            </BaseRecord>
+          </BaseArray>
+        </MarkBasePos>
+      </Lookup>
+    </LookupList>
+  </GPOS>
+  <GSUB>
+    <Version value="0x00010001"/>
+    <ScriptList>
+      <ScriptRecord index="0">
+        <ScriptTag value="DFLT"/>
+        <Script>
+          <DefaultLangSys>
+            <ReqFeatureIndex value="65535"/>
+            <FeatureIndex index="0" value="0"/>
+          </DefaultLangSys>
+        </Script>
+      </ScriptRecord>
+    </ScriptList>
+    <FeatureList>
+      <FeatureRecord index="0">
+        <FeatureTag value="liga"/>
+        <Feature>
+          <LookupListIndex index="0" value="0"/>
+        </Feature>
+      </FeatureRecord>
+    </FeatureList>
+    <LookupList>
+      <Lookup index="0">
+        <LookupType value="4"/>
+        <LookupFlag value="8"/>
+        <LigatureSubst index="0">
+          <LigatureSet glyph="f">
+            <Ligature components="i" glyph="f_i"/>
+          </LigatureSet>
+        </LigatureSubst>
+      </Lookup>
+      <Lookup index="1">
+        <LookupType value="1"/>
+        <LookupFlag value="0"/>
+        <SingleSubst index="0">
+          <Substitution in="i" out="f"/>
+        </SingleSubst>
+      </Lookup>
+    </LookupList>
+    <FeatureVariations>
+      <Version value="0x00010000"/>
+      <FeatureVariationRecord index="0">
+        <ConditionSet>
+          <ConditionTable index="0" Format="1">
+            <AxisIndex value="0"/>
+            <FilterRangeMinValue value="0.5"/>
+            <FilterRangeMaxValue value="1.0"/>
+          </ConditionTable>
+        </ConditionSet>
+        <FeatureTableSubstitution>
+          <Version value="0x00010000"/>
+          <SubstitutionRecord index="0">
+            <FeatureIndex value="0"/>
+            <Feature>
+              <LookupListIndex index="0" value="0"/>
+              <LookupListIndex index="1" value="1"/>
+            </Feature>
+          </SubstitutionRecord>
+        </FeatureTableSubstitution>
+      </FeatureVariationRecord>
+    </FeatureVariations>
+  </GSUB>
+  <CPAL>
+    <version value="1"/>
+    <numPaletteEntries value="2"/>
+    <palette index="0" type="1">
+      <color index="0" value="#FF0000FF"/>
+      <color index="1" value="#0000FF80"/>
+    </palette>
+  </CPAL>
+  <COLR>
+    <Version value="1"/>
+    <BaseGlyphList>
+      <BaseGlyphPaintRecord index="0">
+        <BaseGlyph value="f_i"/>
+        <Paint Format="1"><!-- PaintColrLayers -->
+          <NumLayers value="2"/>
+          <FirstLayerIndex value="0"/>
+        </Paint>
+      </BaseGlyphPaintRecord>
+    </BaseGlyphList>
+    <LayerList>
+      <Paint index="0" Format="10"><!-- PaintGlyph -->
+        <Paint Format="2"><!-- PaintSolid -->
+          <PaletteIndex value="0"/>
+          <Alpha value="1.0"/>
+        </Paint>
+        <Glyph value="f"/>
+      </Paint>
+      <Paint index="1" Format="14"><!-- PaintTranslate -->
+        <Paint Format="10"><!-- PaintGlyph -->
+          <Paint Format="8"><!-- PaintSweepGradient -->
+            <ColorLine>
+              <Extend value="reflect"/>
+              <ColorStop index="0">
+                <StopOffset value="0.0"/>
+                <PaletteIndex value="0"/>
+                <Alpha value="1.0"/>
+              </ColorStop>
+              <ColorStop index="1">
+                <StopOffset value="1.0"/>
+                <PaletteIndex value="1"/>
+                <Alpha value="0.5"/>
+              </ColorStop>
+            </ColorLine>
+            <centerX value="150"/>
+            <centerY value="350"/>
+            <startAngle value="0.0"/>
+            <endAngle value="360.0"/>
+          </Paint>
+          <Glyph value="i"/>
+        </Paint>
+        <dx value="300"/>
+        <dy value="0"/>
+      </Paint>
+    </LayerList>
+    <ClipList Format="1">
+      <Clip>
+        <Glyph value="f_i"/>
+        <ClipBox Format="1">
+          <xMin value="0"/>
+          <yMin value="0"/>
+          <xMax value="600"/>
+          <yMax value="700"/>
+        </ClipBox>
+      </Clip>
+    </ClipList>
+  </COLR>
+  <kern>
+    <version value="0"/>
+    <kernsubtable coverage="1" format="0">
+      <pair l="f" r="i" v="-20"/>
+      <pair l="i" r="f" v="15"/>
+    </kernsubtable>
+  </kern>
+</ttFont>
+"##;
+
+    #[test]
+    fn test_ttx_roundtrip() {
+        let mut font = from_xml(TEST_TTX).unwrap();
+        let mut binary = vec![];
+        font.write(&mut binary).unwrap();
+        let mut font = Font::from_bytes(&binary).unwrap();
+        let xml = to_xml(&mut font).unwrap();
+        for expected in [
+            r#"<GlyphID id="3" name="f_i"/>"#,
+            r#"<map code="0x301" name="acutecomb"/>"#,
+            r#"<pt x="300" y="700" on="1"/>"#,
+            r#"<ClassDef glyph="f_i" class="2"/>"#,
+            r#"<Coordinate value="300"/>"#,
+            r#"<LookupFlag value="8"/><!-- ignoreMarks -->"#,
+            r#"<Ligature components="i" glyph="f_i"/>"#,
+            r#"<Version value="0x00010001"/>"#,
+            r#"<ConditionTable index="0" Format="1">"#,
+            r#"<FilterRangeMinValue value="0.5"/>"#,
+            r#"<LookupListIndex index="1" value="1"/>"#,
+            r#"<MarkBasePos index="0" Format="1">"#,
+            r#"<BaseAnchor index="0" Format="1">"#,
+            r#"<palette index="0" type="1">"#,
+            r##"<color index="1" value="#0000FF80"/>"##,
+            r#"<Paint index="1" Format="14"><!-- PaintTranslate -->"#,
+            r#"<Extend value="reflect"/>"#,
+            r#"<endAngle value="360.0"/>"#,
+            r#"<Glyph value="f_i"/>"#,
+            r#"<kernsubtable coverage="1" format="0">"#,
+            r#"<pair l="i" r="f" v="15"/>"#,
+        ] {
+            assert!(xml.contains(expected), "{} not found in\n{}", expected, xml);
+        }
+
+        let mut roundtripped = from_xml(&xml).unwrap();
+        assert_eq!(to_xml(&mut roundtripped).unwrap(), xml);
+    }
+}
@@ -0,0 +1,747 @@
+//! TTX conversion for the simple, fixed-layout tables.
+use super::xml::{
+    attr, child, child_binary, child_value, elements_named, error, fl2str, num, num2binary,
+    opt_child_value, opt_num, required_child, value, Node, XmlWriter,
+};
+use super::GlyphOrder;
+use crate::tables::{cvt, gasp, head, hhea, hmtx, kern, maxp, name, os2, post, vhea, vmtx, VORG};
+use chrono::NaiveDateTime;
+use otspec::types::*;
+use otspec::DeserializationError;
+
+const DATE_FORMAT: &str = "%a %b %e %H:%M:%S %Y";
+const RECALCULATED: &str = "Most of this table will be recalculated by the compiler";
+
+fn version_to_f32(major: u16, minor: u16) -> f32 {
+    major as f32 + minor as f32 / 65536.0
+}
+
+fn date(node: Node, name: &str) -> Result<NaiveDateTime, DeserializationError> {
+    let text = attr(required_child(node, name)?, "value")?;
+    NaiveDateTime::parse_from_str(text.trim(), DATE_FORMAT)
+        .map_err(|e| error(format!("Bad date '{}': {}", text, e)))
+}
+
+pub(crate) fn dump_head(w: &mut XmlWriter, head: &head::head) {
+    w.comment(RECALCULATED);
+    w.value(
+        "tableVersion",
+        fl2str(version_to_f32(head.majorVersion, head.minorVersion), 16),
+    );
+    w.value("fontRevision", fl2str(head.fontRevision, 16));
+    w.value(
+        "checkSumAdjustment",
+        format!("{:#x}", head.checksumAdjustment),
+    );
+    w.value("magicNumber", format!("{:#x}", head.magicNumber));
+    w.value("flags", num2binary(head.flags as u32, 16));
+    w.value("unitsPerEm", head.unitsPerEm);
+    w.value("created", head.created.format(DATE_FORMAT));
+    w.value("modified", head.modified.format(DATE_FORMAT));
+    w.value("xMin", head.xMin);
+    w.value("yMin", head.yMin);
+    w.value("xMax", head.xMax);
+    w.value("yMax", head.yMax);
+    w.value("macStyle", num2binary(head.macStyle as u32, 16));
+    w.value("lowestRecPPEM", head.lowestRecPPEM);
+    w.value("fontDirectionHint", head.fontDirectionHint);
+    w.value("indexToLocFormat", head.indexToLocFormat);
+    w.value("glyphDataFormat", head.glyphDataFormat);
+}
+
+pub(crate) fn load_head(node: Node) -> Result<head::head, DeserializationError> {
+    let version: f32 = child_value(node, "tableVersion")?;
+    Ok(head::head {
+        majorVersion: version.trunc() as u16,
+        minorVersion: (version.fract() * 65536.0).round() as u16,
+        fontRevision: child_value(node, "fontRevision")?,
+        checksumAdjustment: child_value(node, "checkSumAdjustment")?,
+        magicNumber: child_value(node, "magicNumber")?,
+        flags: child_binary(node, "flags")?,
+        unitsPerEm: child_value(node, "unitsPerEm")?,
+        created: date(node, "created")?,
+        modified: date(node, "modified")?,
+        xMin: child_value(node, "xMin")?,
+        yMin: child_value(node, "yMin")?,
+        xMax: child_value(node, "xMax")?,
+        yMax: child_value(node, "yMax")?,
+        macStyle: child_binary(node, "macStyle")?,
+        lowestRecPPEM: child_value(node, "lowestRecPPEM")?,
+        fontDirectionHint: child_value(node, "fontDirectionHint")?,
+        indexToLocFormat: child_value(node, "indexToLocFormat")?,
+        glyphDataFormat: child_value(node, "glyphDataFormat")?,
+    })
+}
+
+pub(crate) fn dump_hhea(w: &mut XmlWriter, hhea: &hhea::hhea) {
+    w.value(
+        "tableVersion",
+        format!(
+            "{:#010x}",
+            (hhea.majorVersion as u32) << 16 | hhea.minorVersion as u32
+        ),
+    );
+    w.value("ascent", hhea.ascender);
+    w.value("descent", hhea.descender);
+    w.value("lineGap", hhea.lineGap);
+    w.value("advanceWidthMax", hhea.advanceWidthMax);
+    w.value("minLeftSideBearing", hhea.minLeftSideBearing);
+    w.value("minRightSideBearing", hhea.minRightSideBearing);
+    w.value("xMaxExtent", hhea.xMaxExtent);
+    w.value("caretSlopeRise", hhea.caretSlopeRise);
+    w.value("caretSlopeRun", hhea.caretSlopeRun);
+    w.value("caretOffset", hhea.caretOffset);
+    w.value("reserved0", hhea.reserved0);
+    w.value("reserved1", hhea.reserved1);
+    w.value("reserved2", hhea.reserved2);
+    w.value("reserved3", hhea.reserved3);
+    w.value("metricDataFormat", hhea.metricDataFormat);
+    w.value("numberOfHMetrics", hhea.numberOfHMetrics);
+}
+
+pub(crate) fn load_hhea(node: Node) -> Result<hhea::hhea, DeserializationError> {
+    let version: u32 = child_value(node, "tableVersion")?;
+    // Older versions of fontTools called these "ascender" and "descender"
+    let either = |a: &str, b: &str| -> Result<i16, DeserializationError> {
+        match opt_child_value(node, a)? {
+            Some(v) => Ok(v),
+            None => child_value(node, b),
+        }
+    };
+    Ok(hhea::hhea {
+        majorVersion: (version >> 16) as u16,
+        minorVersion: (version & 0xffff) as u16,
+        ascender: either("ascent", "ascender")?,
+        descender: either("descent", "descender")?,
+        lineGap: child_value(node, "lineGap")?,
+        advanceWidthMax: child_value(node, "advanceWidthMax")?,
+        minLeftSideBearing: child_value(node, "minLeftSideBearing")?,
+        minRightSideBearing: child_value(node, "minRightSideBearing")?,
+        xMaxExtent: child_value(node, "xMaxExtent")?,
+        caretSlopeRise: child_value(node, "caretSlopeRise")?,
+        caretSlopeRun: child_value(node, "caretSlopeRun")?,
+        caretOffset: child_value(node, "caretOffset")?,
+        reserved0: opt_child_value(node, "reserved0")?.unwrap_or(0),
+        reserved1: opt_child_value(node, "reserved1")?.unwrap_or(0),
+        reserved2: opt_child_value(node, "reserved2")?.unwrap_or(0),
+        reserved3: opt_child_value(node, "reserved3")?.unwrap_or(0),
+        metricDataFormat: child_value(node, "metricDataFormat")?,
+        numberOfHMetrics: opt_child_value(node, "numberOfHMetrics")?.unwrap_or(0),
+    })
+}
+
+pub(crate) fn dump_vhea(w: &mut XmlWriter, vhea: &vhea::vhea) {
+    w.value(
+        "tableVersion",
+        format!(
+            "{:#010x}",
+            (vhea.majorVersion as u32) << 16 | vhea.minorVersion as u32
+        ),
+    );
+    w.value("ascent", vhea.vertTypoAscender);
+    w.value("descent", vhea.vertTypoDescender);
+    w.value("lineGap", vhea.vertTypoLineGap);
+    w.value("advanceHeightMax", vhea.advanceHeightMax);
+    w.value("minTopSideBearing", vhea.minTopSideBearing);
+    w.value("minBottomSideBearing", vhea.minBottomSideBearing);
+    w.value("yMaxExtent", vhea.yMaxExtent);
+    w.value("caretSlopeRise", vhea.caretSlopeRise);
+    w.value("caretSlopeRun", vhea.caretSlopeRun);
+    w.value("caretOffset", vhea.caretOffset);
+    w.value("reserved1", vhea.reserved0);
+    w.value("reserved2", vhea.reserved1);
+    w.value("reserved3", vhea.reserved2);
+    w.value("reserved4", vhea.reserved3);
+    w.value("metricDataFormat", vhea.metricDataFormat);
+    w.value("numberOfVMetrics", vhea.numOfLongVerMetrics);
+}
+
+pub(crate) fn load_vhea(node: Node) -> Result<vhea::vhea, DeserializationError> {
+    let version: u32 = child_value(node, "tableVersion")?;
+    // Version 1.1 of the specification renamed these fields
+    let either = |a: &str, b: &str| -> Result<i16, DeserializationError> {
+        match opt_child_value(node, a)? {
+            Some(v) => Ok(v),
+            None => child_value(node, b),
+        }
+    };
+    Ok(vhea::vhea {
+        majorVersion: (version >> 16) as u16,
+        minorVersion: (version & 0xffff) as u16,
+        vertTypoAscender: either("ascent", "vertTypoAscender")?,
+        vertTypoDescender: either("descent", "vertTypoDescender")?,
+        vertTypoLineGap: either("lineGap", "vertTypoLineGap")?,
+        advanceHeightMax: child_value(node, "advanceHeightMax")?,
+        minTopSideBearing: child_value(node, "minTopSideBearing")?,
+        minBottomSideBearing: child_value(node, "minBottomSideBearing")?,
+        yMaxExtent: child_value(node, "yMaxExtent")?,
+        caretSlopeRise: child_value(node, "caretSlopeRise")?,
+        caretSlopeRun: child_value(node, "caretSlopeRun")?,
+        caretOffset: child_value(node, "caretOffset")?,
+        reserved0: opt_child_value(node, "reserved1")?.unwrap_or(0),
+        reserved1: opt_child_value(node, "reserved2")?.unwrap_or(0),
+        reserved2: opt_child_value(node, "reserved3")?.unwrap_or(0),
+        reserved3: opt_child_value(node, "reserved4")?.unwrap_or(0),
+        metricDataFormat: child_value(node, "metricDataFormat")?,
+        numOfLongVerMetrics: opt_child_value(node, "numberOfVMetrics")?.unwrap_or(0),
+    })
+}
+
+pub(crate) fn dump_maxp(w: &mut XmlWriter, maxp: &maxp::maxp) {
+    w.comment(RECALCULATED);
+    match &maxp.table {
+        maxp::MaxpVariant::Maxp05(t) => {
+            w.value("tableVersion", "0x5000");
+            w.value("numGlyphs", t.numGlyphs);
+        }
+        maxp::MaxpVariant::Maxp10(t) => {
+            w.value("tableVersion", "0x10000");
+            w.value("numGlyphs", t.numGlyphs);
+            w.value("maxPoints", t.maxPoints);
+            w.value("maxContours", t.maxContours);
+            w.value("maxCompositePoints", t.maxCompositePoints);
+            w.value("maxCompositeContours", t.maxCompositeContours);
+            w.value("maxZones", t.maxZones);
+            w.value("maxTwilightPoints", t.maxTwilightPoints);
+            w.value("maxStorage", t.maxStorage);
+            w.value("maxFunctionDefs", t.maxFunctionDefs);
+            w.value("maxInstructionDefs", t.maxInstructionDefs);
+            w.value("maxStackElements", t.maxStackElements);
+            w.value("maxSizeOfInstructions", t.maxSizeOfInstructions);
+            w.value("maxComponentElements", t.maxComponentElements);
+            w.value("maxComponentDepth", t.maxComponentDepth);
+        }
+    }
+}
+
+pub(crate) fn load_maxp(node: Node) -> Result<maxp::maxp, DeserializationError> {
+    let version: u32 = child_value(node, "tableVersion")?;
+    let num_glyphs = opt_child_value(node, "numGlyphs")?.unwrap_or(0);
+    if version == 0x5000 {
+        return Ok(maxp::maxp::new05(num_glyphs));
+    }
+    let mut table = maxp::maxp::new10(num_glyphs, 0, 0, 0, 0, 0, 0);
+    if let maxp::MaxpVariant::Maxp10(t) = &mut table.table {
+        t.maxPoints = child_value(node, "maxPoints")?;
+        t.maxContours = child_value(node, "maxContours")?;
+        t.maxCompositePoints = child_value(node, "maxCompositePoints")?;
+        t.maxCompositeContours = child_value(node, "maxCompositeContours")?;
+        t.maxZones = child_value(node, "maxZones")?;
+        t.maxTwilightPoints = child_value(node, "maxTwilightPoints")?;
+        t.maxStorage = child_value(node, "maxStorage")?;
+        t.maxFunctionDefs = child_value(node, "maxFunctionDefs")?;
+        t.maxInstructionDefs = child_value(node, "maxInstructionDefs")?;
+        t.maxStackElements = child_value(node, "maxStackElements")?;
+        t.maxSizeOfInstructions = child_value(node, "maxSizeOfInstructions")?;
+        t.maxComponentElements = child_value(node, "maxComponentElements")?;
+        t.maxComponentDepth = child_value(node, "maxComponentDepth")?;
+    }
+    Ok(table)
+}
+
+const PANOSE_FIELDS: [&str; 10] = [
+    "bFamilyType",
+    "bSerifStyle",
+    "bWeight",
+    "bProportion",
+    "bContrast",
+    "bStrokeVariation",
+    "bArmStyle",
+    "bLetterForm",
+    "bMidline",
+    "bXHeight",
+];
+
+pub(crate) fn dump_os2(w: &mut XmlWriter, os2: &os2::os2) {
+    w.comment(
+        "The fields 'usFirstCharIndex' and 'usLastCharIndex'\nwill be recalculated by the compiler",
+    );
+    w.value("version", os2.version);
+    w.value("xAvgCharWidth", os2.xAvgCharWidth);
+    w.value("usWeightClass", os2.usWeightClass);
+    w.value("usWidthClass", os2.usWidthClass);
+    w.value("fsType", num2binary(os2.fsType as u32, 16));
+    w.value("ySubscriptXSize", os2.ySubscriptXSize);
+    w.value("ySubscriptYSize", os2.ySubscriptYSize);
+    w.value("ySubscriptXOffset", os2.ySubscriptXOffset);
+    w.value("ySubscriptYOffset", os2.ySubscriptYOffset);
+    w.value("ySuperscriptXSize", os2.ySuperscriptXSize);
+    w.value("ySuperscriptYSize", os2.ySuperscriptYSize);
+    w.value("ySuperscriptXOffset", os2.ySuperscriptXOffset);
+    w.value("ySuperscriptYOffset", os2.ySuperscriptYOffset);
+    w.value("yStrikeoutSize", os2.yStrikeoutSize);
+    w.value("yStrikeoutPosition", os2.yStrikeoutPosition);
+    w.value("sFamilyClass", os2.sFamilyClass);
+    w.begin("panose", &[]);
+    let p = &os2.panose;
+    let panose = [
+        p.panose0, p.panose1, p.panose2, p.panose3, p.panose4, p.panose5, p.panose6, p.panose7,
+        p.panose8, p.panose9,
+    ];
+    for (field, value) in PANOSE_FIELDS.iter().zip(panose.iter()) {
+        w.value(field, value);
+    }
+    w.end("panose");
+    w.value("ulUnicodeRange1", num2binary(os2.ulUnicodeRange1, 32));
+    w.value("ulUnicodeRange2", num2binary(os2.ulUnicodeRange2, 32));
+    w.value("ulUnicodeRange3", num2binary(os2.ulUnicodeRange3, 32));
+    w.value("ulUnicodeRange4", num2binary(os2.ulUnicodeRange4, 32));
+    w.value("achVendID", os2.achVendID);
+    w.value("fsSelection", num2binary(os2.fsSelection as u32, 16));
+    w.value("usFirstCharIndex", os2.usFirstCharIndex);
+    w.value("usLastCharIndex", os2.usLastCharIndex);
+    w.value("sTypoAscender", os2.sTypoAscender);
+    w.value("sTypoDescender", os2.sTypoDescender);
+    w.value("sTypoLineGap", os2.sTypoLineGap);
+    w.value("usWinAscent", os2.usWinAscent);
+    w.value("usWinDescent", os2.usWinDescent);
+    if let (Some(range1), Some(range2)) = (os2.ulCodePageRange1, os2.ulCodePageRange2) {
+        w.value("ulCodePageRange1", num2binary(range1, 32));
+        w.value("ulCodePageRange2", num2binary(range2, 32));
+    }
+    let optional = [
+        ("sxHeight", os2.sxHeight.map(|v| v as i32)),
+        ("sCapHeight", os2.sCapHeight.map(|v| v as i32)),
+        ("usDefaultChar", os2.usDefaultChar.map(|v| v as i32)),
+        ("usBreakChar", os2.usBreakChar.map(|v| v as i32)),
+        ("usMaxContext", os2.usMaxContext.map(|v| v as i32)),
+        (
+            "usLowerOpticalPointSize",
+            os2.usLowerOpticalPointSize.map(|v| v as i32),
+        ),
+        (
+            "usUpperOpticalPointSize",
+            os2.usUpperOpticalPointSize.map(|v| v as i32),
+        ),
+    ];
+    for (field, value) in optional {
+        if let Some(value) = value {
+            w.value(field, value);
+        }
+    }
+}
+
+pub(crate) fn load_os2(node: Node) -> Result<os2::os2, DeserializationError> {
+    let panose_node = required_child(node, "panose")?;
+    let mut panose = [0u8; 10];
+    for (value, field) in panose.iter_mut().zip(PANOSE_FIELDS.iter()) {
+        *value = child_value(panose_node, field)?;
+    }
+    let opt_binary = |name: &str| -> Result<Option<u32>, DeserializationError> {
+        child(node, name)
+            .map(|_| child_binary(node, name))
+            .transpose()
+    };
+    let vendor = attr(required_child(node, "achVendID")?, "value")?;
+    Ok(os2::os2 {
+        version: child_value(node, "version")?,
+        xAvgCharWidth: child_value(node, "xAvgCharWidth")?,
+        usWeightClass: child_value(node, "usWeightClass")?,
+        usWidthClass: child_value(node, "usWidthClass")?,
+        fsType: child_binary(node, "fsType")?,
+        ySubscriptXSize: child_value(node, "ySubscriptXSize")?,
+        ySubscriptYSize: child_value(node, "ySubscriptYSize")?,
+        ySubscriptXOffset: child_value(node, "ySubscriptXOffset")?,
+        ySubscriptYOffset: child_value(node, "ySubscriptYOffset")?,
+        ySuperscriptXSize: child_value(node, "ySuperscriptXSize")?,
+        ySuperscriptYSize: child_value(node, "ySuperscriptYSize")?,
+        ySuperscriptXOffset: child_value(node, "ySuperscriptXOffset")?,
+        ySuperscriptYOffset: child_value(node, "ySuperscriptYOffset")?,
+        yStrikeoutSize: child_value(node, "yStrikeoutSize")?,
+        yStrikeoutPosition: child_value(node, "yStrikeoutPosition")?,
+        sFamilyClass: child_value(node, "sFamilyClass")?,
+        panose: os2::Panose {
+            panose0: panose[0],
+            panose1: panose[1],
+            panose2: panose[2],
+            panose3: panose[3],
+            panose4: panose[4],
+            panose5: panose[5],
+            panose6: panose[6],
+            panose7: panose[7],
+            panose8: panose[8],
+            panose9: panose[9],
+        },
+        ulUnicodeRange1: child_binary(node, "ulUnicodeRange1")?,
+        ulUnicodeRange2: child_binary(node, "ulUnicodeRange2")?,
+        ulUnicodeRange3: child_binary(node, "ulUnicodeRange3")?,
+        ulUnicodeRange4: child_binary(node, "ulUnicodeRange4")?,
+        achVendID: Tag::from_raw(format!("{:<4}", vendor))
+            .map_err(|_| error(format!("Bad vendor ID '{}'", vendor)))?,
+        fsSelection: child_binary(node, "fsSelection")?,
+        usFirstCharIndex: child_value(node, "usFirstCharIndex")?,
+        usLastCharIndex: child_value(node, "usLastCharIndex")?,
+        sTypoAscender: child_value(node, "sTypoAscender")?,
+        sTypoDescender: child_value(node, "sTypoDescender")?,
+        sTypoLineGap: child_value(node, "sTypoLineGap")?,
+        usWinAscent: child_value(node, "usWinAscent")?,
+        usWinDescent: child_value(node, "usWinDescent")?,
+        ulCodePageRange1: opt_binary("ulCodePageRange1")?,
+        ulCodePageRange2: opt_binary("ulCodePageRange2")?,
+        sxHeight: opt_child_value(node, "sxHeight")?,
+        sCapHeight: opt_child_value(node, "sCapHeight")?,
+        usDefaultChar: opt_child_value(node, "usDefaultChar")?,
+        usBreakChar: opt_child_value(node, "usBreakChar")?,
+        usMaxContext: opt_child_value(node, "usMaxContext")?,
+        usLowerOpticalPointSize: opt_child_value(node, "usLowerOpticalPointSize")?,
+        usUpperOpticalPointSize: opt_child_value(node, "usUpperOpticalPointSize")?,
+    })
+}
+
+const PS_NAMES_COMMENT: &str = "This file uses unique glyph names based on the information
+found in the 'post' table. Since these names might not be unique,
+we have to invent artificial names in case of clashes. In order to
+be able to retain the original information, we need a name to
+ps name mapping for those cases where they differ. That's what
+you see below.
+";
+
+pub(crate) fn dump_post(w: &mut XmlWriter, post: &post::post) {
+    w.value("formatType", fl2str(post.version.to_num(), 16));
+    w.value("italicAngle", fl2str(post.italicAngle, 16));
+    w.value("underlinePosition", post.underlinePosition);
+    w.value("underlineThickness", post.underlineThickness);
+    w.value("isFixedPitch", post.isFixedPitch);
+    w.value("minMemType42", post.minMemType42);
+    w.value("maxMemType42", post.maxMemType42);
+    w.value("minMemType1", post.minMemType1);
+    w.value("maxMemType1", post.maxMemType1);
+    if let Some(names) = &post.glyphnames {
+        w.begin("psNames", &[]);
+        w.comment(PS_NAMES_COMMENT);
+        w.end("psNames");
+        w.begin("extraNames", &[]);
+        w.comment("following are the name that are not taken from the standard Mac glyph order");
+        for name in names
+            .iter()
+            .filter(|name| !post::APPLE_NAMES.contains(&name.as_str()))
+        {
+            w.simple("psName", &[("name", name)]);
+        }
+        w.end("extraNames");
+    }
+}
+
+/// Loads a `post` table. Glyph names are filled in from the glyph order
+/// once the whole font has been read.
+pub(crate) fn load_post(node: Node) -> Result<post::post, DeserializationError> {
+    let version: f32 = child_value(node, "formatType")?;
+    let mut table = post::post::new(
+        version,
+        child_value(node, "italicAngle")?,
+        child_value(node, "underlinePosition")?,
+        child_value(node, "underlineThickness")?,
+        false,
+        None,
+    );
+    table.isFixedPitch = child_value(node, "isFixedPitch")?;
+    table.minMemType42 = child_value(node, "minMemType42")?;
+    table.maxMemType42 = child_value(node, "maxMemType42")?;
+    table.minMemType1 = child_value(node, "minMemType1")?;
+    table.maxMemType1 = child_value(node, "maxMemType1")?;
+    if version == 2.0 {
+        table.glyphnames = Some(vec![]);
+    }
+    Ok(table)
+}
+
+pub(crate) fn dump_hmtx(w: &mut XmlWriter, hmtx: &hmtx::hmtx, glyph_order: &GlyphOrder) {
+    let mut metrics: Vec<(String, &hmtx::Metric)> = hmtx
+        .metrics
+        .iter()
+        .enumerate()
+        .map(|(gid, metric)| (glyph_order.name(gid as GlyphID), metric))
+        .collect();
+    metrics.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, metric) in metrics {
+        w.simple(
+            "mtx",
+            &[
+                ("name", &name),
+                ("width", &metric.advanceWidth),
+                ("lsb", &metric.lsb),
+            ],
+        );
+    }
+}
+
+pub(crate) fn load_hmtx(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<hmtx::hmtx, DeserializationError> {
+    let mut metrics = vec![
+        hmtx::Metric {
+            advanceWidth: 0,
+            lsb: 0
+        };
+        glyph_order.len()
+    ];
+    for mtx in elements_named(node, "mtx") {
+        let gid = glyph_order.id(attr(mtx, "name")?)? as usize;
+        if gid >= metrics.len() {
+            metrics.resize(
+                gid + 1,
+                metrics.last().copied().unwrap_or(hmtx::Metric {
+                    advanceWidth: 0,
+                    lsb: 0,
+                }),
+            );
+        }
+        metrics[gid] = hmtx::Metric {
+            advanceWidth: num(mtx, "width")?,
+            lsb: num(mtx, "lsb")?,
+        };
+    }
+    Ok(hmtx::hmtx { metrics })
+}
+
+pub(crate) fn dump_vmtx(w: &mut XmlWriter, vmtx: &vmtx::vmtx, glyph_order: &GlyphOrder) {
+    let mut metrics: Vec<(String, &vmtx::Metric)> = vmtx
+        .metrics
+        .iter()
+        .enumerate()
+        .map(|(gid, metric)| (glyph_order.name(gid as GlyphID), metric))
+        .collect();
+    metrics.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, metric) in metrics {
+        w.simple(
+            "mtx",
+            &[
+                ("name", &name),
+                ("height", &metric.advanceHeight),
+                ("tsb", &metric.tsb),
+            ],
+        );
+    }
+}
+
+pub(crate) fn load_vmtx(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<vmtx::vmtx, DeserializationError> {
+    let mut metrics = vec![
+        vmtx::Metric {
+            advanceHeight: 0,
+            tsb: 0
+        };
+        glyph_order.len()
+    ];
+    for mtx in elements_named(node, "mtx") {
+        let gid = glyph_order.id(attr(mtx, "name")?)? as usize;
+        if gid >= metrics.len() {
+            metrics.resize(
+                gid + 1,
+                metrics.last().copied().unwrap_or(vmtx::Metric {
+                    advanceHeight: 0,
+                    tsb: 0,
+                }),
+            );
+        }
+        metrics[gid] = vmtx::Metric {
+            advanceHeight: num(mtx, "height")?,
+            tsb: num(mtx, "tsb")?,
+        };
+    }
+    Ok(vmtx::vmtx { metrics })
+}
+
+pub(crate) fn dump_vorg(w: &mut XmlWriter, vorg: &VORG::VORG, glyph_order: &GlyphOrder) {
+    w.value("majorVersion", vorg.majorVersion);
+    w.value("minorVersion", vorg.minorVersion);
+    w.value("defaultVertOriginY", vorg.defaultVertOriginY);
+    w.value("numVertOriginYMetrics", vorg.vertOriginYMetrics.len());
+    let mut records: Vec<&VORG::VertOriginYMetrics> = vorg.vertOriginYMetrics.iter().collect();
+    records.sort_by_key(|r| r.glyphIndex);
+    for record in records {
+        w.begin("VOriginRecord", &[]);
+        w.value("glyphName", glyph_order.name(record.glyphIndex));
+        w.value("vOrigin", record.vertOriginY);
+        w.end("VOriginRecord");
+    }
+}
+
+pub(crate) fn load_vorg(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<VORG::VORG, DeserializationError> {
+    let mut records = elements_named(node, "VOriginRecord")
+        .map(|record| {
+            Ok(VORG::VertOriginYMetrics {
+                glyphIndex: glyph_order.id(attr(required_child(record, "glyphName")?, "value")?)?,
+                vertOriginY: child_value(record, "vOrigin")?,
+            })
+        })
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    records.sort_by_key(|r| r.glyphIndex);
+    Ok(VORG::VORG {
+        majorVersion: opt_child_value(node, "majorVersion")?.unwrap_or(1),
+        minorVersion: opt_child_value(node, "minorVersion")?.unwrap_or(0),
+        defaultVertOriginY: child_value(node, "defaultVertOriginY")?,
+        vertOriginYMetrics: records,
+    })
+}
+
+/// Dumps a `kern` table. Only pair kerning has an XML representation, so
+/// class-based subtables make this fail and the table is written as binary.
+pub(crate) fn dump_kern(
+    w: &mut XmlWriter,
+    kern: &kern::kern,
+    glyph_order: &GlyphOrder,
+) -> Result<(), DeserializationError> {
+    w.value("version", if kern.apple { "1.0" } else { "0" });
+    for subtable in &kern.subtables {
+        let pairs = match &subtable.kerning {
+            kern::Kerning::Pairs(pairs) => pairs,
+            _ => return Err(error("Only format 0 kern subtables can be dumped")),
+        };
+        let coverage = subtable.coverage.to_raw(kern.apple);
+        let mut attrs: Vec<(&str, &dyn std::fmt::Display)> =
+            vec![("coverage", &coverage), ("format", &0)];
+        if kern.apple {
+            attrs.push(("tupleIndex", &subtable.tuple_index));
+        }
+        w.begin("kernsubtable", &attrs);
+        for ((left, right), value) in pairs {
+            w.simple(
+                "pair",
+                &[
+                    ("l", &glyph_order.name(*left)),
+                    ("r", &glyph_order.name(*right)),
+                    ("v", value),
+                ],
+            );
+        }
+        w.end("kernsubtable");
+    }
+    Ok(())
+}
+
+pub(crate) fn load_kern(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<kern::kern, DeserializationError> {
+    let version: f32 = child_value(node, "version")?;
+    let apple = version >= 1.0;
+    let subtables = elements_named(node, "kernsubtable")
+        .map(|subtable| {
+            let format: u8 = num(subtable, "format")?;
+            if format != 0 {
+                return Err(error(format!(
+                    "Can't compile format {} kern subtables from XML",
+                    format
+                )));
+            }
+            let pairs = elements_named(subtable, "pair")
+                .map(|pair| {
+                    Ok((
+                        (
+                            glyph_order.id(attr(pair, "l")?)?,
+                            glyph_order.id(attr(pair, "r")?)?,
+                        ),
+                        num(pair, "v")?,
+                    ))
+                })
+                .collect::<Result<_, DeserializationError>>()?;
+            Ok(kern::KernSubtable {
+                coverage: kern::KernCoverage::from_raw(num(subtable, "coverage")?, apple),
+                tuple_index: opt_num(subtable, "tupleIndex")?.unwrap_or(0),
+                kerning: kern::Kerning::Pairs(pairs),
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    Ok(kern::kern { apple, subtables })
+}
+
+pub(crate) fn dump_name(w: &mut XmlWriter, name: &name::name) {
+    for record in &name.records {
+        let mut attrs: Vec<(&str, &dyn std::fmt::Display)> = vec![
+            ("nameID", &record.nameID),
+            ("platformID", &record.platformID),
+            ("platEncID", &record.encodingID),
+        ];
+        let lang_id = format!("{:#x}", record.languageID);
+        attrs.push(("langID", &lang_id));
+        if record.platformID == 1 {
+            attrs.push(("unicode", &"True"));
+        }
+        w.begin("namerecord", &attrs);
+        w.text(&record.string);
+        w.newline();
+        w.end("namerecord");
+    }
+}
+
+pub(crate) fn load_name(node: Node) -> Result<name::name, DeserializationError> {
+    let records = elements_named(node, "namerecord")
+        .map(|record| {
+            let text: String = record
+                .children()
+                .filter(|n| n.is_text())
+                .filter_map(|n| n.text())
+                .collect();
+            Ok(name::NameRecord {
+                platformID: num(record, "platformID")?,
+                encodingID: num(record, "platEncID")?,
+                languageID: num(record, "langID")?,
+                nameID: num(record, "nameID")?,
+                string: text.trim().to_string(),
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    Ok(name::name { records })
+}
+
+pub(crate) fn dump_gasp(w: &mut XmlWriter, gasp: &gasp::gasp) {
+    for range in &gasp.gaspRanges {
+        w.simple(
+            "gaspRange",
+            &[
+                ("rangeMaxPPEM", &range.rangeMaxPPEM),
+                ("rangeGaspBehavior", &range.rangeGaspBehavior.bits()),
+            ],
+        );
+    }
+}
+
+pub(crate) fn load_gasp(node: Node) -> Result<gasp::gasp, DeserializationError> {
+    let gasp_ranges: Vec<gasp::GaspRecord> = elements_named(node, "gaspRange")
+        .map(|range| {
+            Ok(gasp::GaspRecord {
+                rangeMaxPPEM: num(range, "rangeMaxPPEM")?,
+                rangeGaspBehavior: gasp::RangeGaspBehaviorFlags::from_bits_truncate(num(
+                    range,
+                    "rangeGaspBehavior",
+                )?),
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    // Version 1 is only needed for the ClearType flags.
+    let version = u16::from(gasp_ranges.iter().any(|range| {
+        range.rangeGaspBehavior.intersects(
+            gasp::RangeGaspBehaviorFlags::GASP_SYMMETRIC_GRIDFIT
+                | gasp::RangeGaspBehaviorFlags::GASP_SYMMETRIC_SMOOTHING,
+        )
+    }));
+    Ok(gasp::gasp {
+        version,
+        gaspRanges: gasp_ranges,
+    })
+}
+
+pub(crate) fn dump_cvt(w: &mut XmlWriter, cvt: &cvt::cvt) {
+    for (ix, value) in cvt.0.iter().enumerate() {
+        w.simple("cv", &[("index", &ix), ("value", value)]);
+    }
+}
+
+pub(crate) fn load_cvt(node: Node) -> Result<cvt::cvt, DeserializationError> {
+    let mut values = vec![];
+    for cv in elements_named(node, "cv") {
+        let index: usize = num(cv, "index")?;
+        if index >= values.len() {
+            values.resize(index + 1, 0);
+        }
+        values[index] = value(cv)?;
+    }
+    Ok(cvt::cvt(values))
+}
@@ -0,0 +1,117 @@
+//! TTX conversion for the `cmap` table.
+use super::xml::{attr, elements, error, num, opt_num, Node, XmlWriter};
+use super::GlyphOrder;
+use crate::tables::cmap;
+use otspec::DeserializationError;
+use std::collections::BTreeMap;
+
+/// The number of runs of consecutive codepoints mapping to consecutive
+/// glyphs, which is what a format 12 subtable stores.
+fn group_count(mapping: &BTreeMap<u32, u16>) -> usize {
+    let mut groups = 0;
+    let mut last: Option<(u32, u16)> = None;
+    for (&codepoint, &gid) in mapping {
+        match last {
+            Some((lc, lg)) if lc + 1 == codepoint && lg as u32 + 1 == gid as u32 => {}
+            _ => groups += 1,
+        }
+        last = Some((codepoint, gid));
+    }
+    groups
+}
+
+pub(crate) fn dump_cmap(w: &mut XmlWriter, cmap: &cmap::cmap, glyph_order: &GlyphOrder) {
+    w.simple("tableVersion", &[("version", &0)]);
+    for subtable in &cmap.subtables {
+        let tag = format!("cmap_format_{}", subtable.format);
+        match subtable.format {
+            14 => w.begin(
+                &tag,
+                &[
+                    ("platformID", &subtable.platformID),
+                    ("platEncID", &subtable.encodingID),
+                ],
+            ),
+            12 | 13 => {
+                let groups = group_count(&subtable.mapping);
+                w.begin(
+                    &tag,
+                    &[
+                        ("platformID", &subtable.platformID),
+                        ("platEncID", &subtable.encodingID),
+                        ("format", &subtable.format),
+                        ("reserved", &0),
+                        ("length", &(16 + 12 * groups)),
+                        ("language", &subtable.languageID),
+                        ("nGroups", &groups),
+                    ],
+                )
+            }
+            _ => w.begin(
+                &tag,
+                &[
+                    ("platformID", &subtable.platformID),
+                    ("platEncID", &subtable.encodingID),
+                    ("language", &subtable.languageID),
+                ],
+            ),
+        }
+        for (codepoint, &gid) in &subtable.mapping {
+            w.simple(
+                "map",
+                &[
+                    ("code", &format!("{:#x}", codepoint)),
+                    ("name", &glyph_order.name(gid)),
+                ],
+            );
+        }
+        if let Some(uvs_mapping) = &subtable.uvs_mapping {
+            for ((codepoint, selector), &gid) in uvs_mapping {
+                w.simple(
+                    "map",
+                    &[
+                        ("uv", &format!("{:#x}", codepoint)),
+                        ("uvs", &format!("{:#x}", selector)),
+                        ("name", &glyph_order.name(gid)),
+                    ],
+                );
+            }
+        }
+        w.end(&tag);
+    }
+}
+
+pub(crate) fn load_cmap(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<cmap::cmap, DeserializationError> {
+    let mut subtables = vec![];
+    for subtable in elements(node) {
+        let format = match subtable.tag_name().name().strip_prefix("cmap_format_") {
+            Some(format) => format,
+            None => continue,
+        };
+        let format: u16 = format
+            .parse()
+            .map_err(|_| error(format!("Bad cmap subtable format '{}'", format)))?;
+        let mut mapping = BTreeMap::new();
+        let mut uvs_mapping = BTreeMap::new();
+        for map in elements(subtable).filter(|n| n.has_tag_name("map")) {
+            let gid = glyph_order.id(attr(map, "name")?)?;
+            if format == 14 {
+                uvs_mapping.insert((num(map, "uv")?, num(map, "uvs")?), gid);
+            } else {
+                mapping.insert(num(map, "code")?, gid);
+            }
+        }
+        subtables.push(cmap::CmapSubtable {
+            format,
+            platformID: num(subtable, "platformID")?,
+            encodingID: num(subtable, "platEncID")?,
+            languageID: opt_num(subtable, "language")?.unwrap_or(0),
+            mapping,
+            uvs_mapping: (format == 14).then_some(uvs_mapping),
+        });
+    }
+    Ok(cmap::cmap { subtables })
+}
@@ -0,0 +1,1034 @@
+//! TTX conversion for the color tables (`COLR` and `CPAL`).
+//!
+//! COLRv0 tables use fontTools' compact `ColorGlyph` form; COLRv1 tables are
+//! written with the element names from fontTools' `otData`.
+use super::variations::{dump_index_map, dump_var_store, load_index_map, load_var_store};
+use super::xml::{
+    attr, child, child_value, elements, elements_named, error, fl2str, num, opt_num,
+    required_child, Node, XmlWriter,
+};
+use super::GlyphOrder;
+use crate::tables::COLR::*;
+use crate::tables::CPAL::{ColorRecord, PaletteType, CPAL, NO_LABEL};
+use otspec::types::*;
+use otspec::DeserializationError;
+use std::collections::BTreeMap;
+
+const EXTEND_MODES: &[&str] = &["pad", "repeat", "reflect"];
+
+const COMPOSITE_MODES: &[&str] = &[
+    "clear",
+    "src",
+    "dest",
+    "src_over",
+    "dest_over",
+    "src_in",
+    "dest_in",
+    "src_out",
+    "dest_out",
+    "src_atop",
+    "dest_atop",
+    "xor",
+    "plus",
+    "screen",
+    "overlay",
+    "darken",
+    "lighten",
+    "color_dodge",
+    "color_burn",
+    "hard_light",
+    "soft_light",
+    "difference",
+    "exclusion",
+    "multiply",
+    "hsl_hue",
+    "hsl_saturation",
+    "hsl_color",
+    "hsl_luminosity",
+];
+
+fn enum_name(names: &[&str], value: u8) -> String {
+    names
+        .get(value as usize)
+        .map_or_else(|| value.to_string(), |s| s.to_string())
+}
+
+fn enum_value(names: &[&str], node: Node, name: &str) -> Result<u8, DeserializationError> {
+    let text = attr(required_child(node, name)?, "value")?;
+    match names.iter().position(|&n| n == text) {
+        Some(ix) => Ok(ix as u8),
+        None => super::xml::parse(text),
+    }
+}
+
+/// Angles are stored as fractions of a half turn, but written in degrees.
+/// Sweep gradient angles are stored with a bias of 1.0.
+fn angle2str(value: f32, bias: f32) -> String {
+    let degrees = ((value + bias) as f64 * 180.0 * 1e6).round() / 1e6;
+    if degrees.fract() == 0.0 {
+        format!("{:.1}", degrees)
+    } else {
+        degrees.to_string()
+    }
+}
+
+fn child_angle(node: Node, name: &str, bias: f32) -> Result<f32, DeserializationError> {
+    let degrees: f32 = child_value(node, name)?;
+    Ok(((degrees / 180.0 - bias) * 16384.0).round() / 16384.0)
+}
+
+fn child_glyph(
+    node: Node,
+    name: &str,
+    glyph_order: &GlyphOrder,
+) -> Result<GlyphID, DeserializationError> {
+    glyph_order.id(attr(required_child(node, name)?, "value")?)
+}
+
+fn dump_color_line(
+    w: &mut XmlWriter,
+    tag: &str,
+    extend: u8,
+    stops: &[(f32, u16, f32, Option<u32>)],
+) {
+    w.begin(tag, &[]);
+    w.value("Extend", enum_name(EXTEND_MODES, extend));
+    w.comment(&format!("StopCount={}", stops.len()));
+    for (ix, (offset, palette_index, alpha, var_index_base)) in stops.iter().enumerate() {
+        w.begin("ColorStop", &[("index", &ix)]);
+        w.value("StopOffset", fl2str(*offset, 14));
+        w.value("PaletteIndex", palette_index);
+        w.value("Alpha", fl2str(*alpha, 14));
+        if let Some(var_index_base) = var_index_base {
+            w.value("VarIndexBase", var_index_base);
+        }
+        w.end("ColorStop");
+    }
+    w.end(tag);
+}
+
+fn dump_plain_color_line(w: &mut XmlWriter, line: &Offset24<ColorLine>) {
+    if let Some(line) = &line.link {
+        let stops: Vec<_> = line
+            .colorStops
+            .iter()
+            .map(|s| (s.stopOffset, s.paletteIndex, s.alpha, None))
+            .collect();
+        dump_color_line(w, "ColorLine", line.extend, &stops);
+    }
+}
+
+fn dump_var_color_line(w: &mut XmlWriter, line: &Offset24<VarColorLine>) {
+    if let Some(line) = &line.link {
+        let stops: Vec<_> = line
+            .colorStops
+            .iter()
+            .map(|s| (s.stopOffset, s.paletteIndex, s.alpha, Some(s.varIndexBase)))
+            .collect();
+        dump_color_line(w, "ColorLine", line.extend, &stops);
+    }
+}
+
+fn load_color_stops(node: Node) -> Result<Vec<(f32, u16, f32, u32)>, DeserializationError> {
+    elements_named(node, "ColorStop")
+        .map(|stop| {
+            Ok((
+                child_value(stop, "StopOffset")?,
+                child_value(stop, "PaletteIndex")?,
+                child_value(stop, "Alpha")?,
+                super::xml::opt_child_value(stop, "VarIndexBase")?.unwrap_or(0xFFFFFFFF),
+            ))
+        })
+        .collect()
+}
+
+fn load_plain_color_line(node: Node) -> Result<Offset24<ColorLine>, DeserializationError> {
+    let line = required_child(node, "ColorLine")?;
+    Ok(Offset24::to(ColorLine {
+        extend: enum_value(EXTEND_MODES, line, "Extend")?,
+        colorStops: load_color_stops(line)?
+            .into_iter()
+            .map(|(offset, palette_index, alpha, _)| ColorStop {
+                stopOffset: offset,
+                paletteIndex: palette_index,
+                alpha,
+            })
+            .collect(),
+    }))
+}
+
+fn load_var_color_line(node: Node) -> Result<Offset24<VarColorLine>, DeserializationError> {
+    let line = required_child(node, "ColorLine")?;
+    Ok(Offset24::to(VarColorLine {
+        extend: enum_value(EXTEND_MODES, line, "Extend")?,
+        colorStops: load_color_stops(line)?
+            .into_iter()
+            .map(
+                |(offset, palette_index, alpha, var_index_base)| VarColorStop {
+                    stopOffset: offset,
+                    paletteIndex: palette_index,
+                    alpha,
+                    varIndexBase: var_index_base,
+                },
+            )
+            .collect(),
+    }))
+}
+
+fn dump_affine(w: &mut XmlWriter, values: [f32; 6], var_index_base: Option<u32>) {
+    w.begin("Transform", &[]);
+    for (name, value) in ["xx", "yx", "xy", "yy", "dx", "dy"].iter().zip(values) {
+        w.value(name, fl2str(value, 16));
+    }
+    if let Some(var_index_base) = var_index_base {
+        w.value("VarIndexBase", var_index_base);
+    }
+    w.end("Transform");
+}
+
+fn load_affine(node: Node) -> Result<[f32; 6], DeserializationError> {
+    let transform = required_child(node, "Transform")?;
+    let mut values = [0.0; 6];
+    for (value, name) in values.iter_mut().zip(["xx", "yx", "xy", "yy", "dx", "dy"]) {
+        *value = child_value(transform, name)?;
+    }
+    Ok(values)
+}
+
+fn paint_name(paint: &Paint) -> &'static str {
+    match paint {
+        Paint::ColrLayers(_) => "PaintColrLayers",
+        Paint::Solid(_) => "PaintSolid",
+        Paint::VarSolid(_) => "PaintVarSolid",
+        Paint::LinearGradient(_) => "PaintLinearGradient",
+        Paint::VarLinearGradient(_) => "PaintVarLinearGradient",
+        Paint::RadialGradient(_) => "PaintRadialGradient",
+        Paint::VarRadialGradient(_) => "PaintVarRadialGradient",
+        Paint::SweepGradient(_) => "PaintSweepGradient",
+        Paint::VarSweepGradient(_) => "PaintVarSweepGradient",
+        Paint::Glyph(_) => "PaintGlyph",
+        Paint::ColrGlyph(_) => "PaintColrGlyph",
+        Paint::Transform(_) => "PaintTransform",
+        Paint::VarTransform(_) => "PaintVarTransform",
+        Paint::Translate(_) => "PaintTranslate",
+        Paint::VarTranslate(_) => "PaintVarTranslate",
+        Paint::Scale(_) => "PaintScale",
+        Paint::VarScale(_) => "PaintVarScale",
+        Paint::ScaleAroundCenter(_) => "PaintScaleAroundCenter",
+        Paint::VarScaleAroundCenter(_) => "PaintVarScaleAroundCenter",
+        Paint::ScaleUniform(_) => "PaintScaleUniform",
+        Paint::VarScaleUniform(_) => "PaintVarScaleUniform",
+        Paint::ScaleUniformAroundCenter(_) => "PaintScaleUniformAroundCenter",
+        Paint::VarScaleUniformAroundCenter(_) => "PaintVarScaleUniformAroundCenter",
+        Paint::Rotate(_) => "PaintRotate",
+        Paint::VarRotate(_) => "PaintVarRotate",
+        Paint::RotateAroundCenter(_) => "PaintRotateAroundCenter",
+        Paint::VarRotateAroundCenter(_) => "PaintVarRotateAroundCenter",
+        Paint::Skew(_) => "PaintSkew",
+        Paint::VarSkew(_) => "PaintVarSkew",
+        Paint::SkewAroundCenter(_) => "PaintSkewAroundCenter",
+        Paint::VarSkewAroundCenter(_) => "PaintVarSkewAroundCenter",
+        Paint::Composite(_) => "PaintComposite",
+    }
+}
+
+fn dump_child_paint(
+    w: &mut XmlWriter,
+    tag: &str,
+    paint: &Offset24<Paint>,
+    glyph_order: &GlyphOrder,
+) {
+    if let Some(paint) = &paint.link {
+        dump_paint(w, tag, None, paint, glyph_order);
+    }
+}
+
+fn dump_paint(
+    w: &mut XmlWriter,
+    tag: &str,
+    index: Option<usize>,
+    paint: &Paint,
+    glyph_order: &GlyphOrder,
+) {
+    let format = paint.format();
+    match index {
+        Some(ix) => w.begin_commented(
+            tag,
+            &[("index", &ix), ("Format", &format)],
+            paint_name(paint),
+        ),
+        None => w.begin_commented(tag, &[("Format", &format)], paint_name(paint)),
+    }
+    let f2 = |v: f32| fl2str(v, 14);
+    match paint {
+        Paint::ColrLayers(x) => {
+            w.value("NumLayers", x.numLayers);
+            w.value("FirstLayerIndex", x.firstLayerIndex);
+        }
+        Paint::Solid(x) => {
+            w.value("PaletteIndex", x.paletteIndex);
+            w.value("Alpha", f2(x.alpha));
+        }
+        Paint::VarSolid(x) => {
+            w.value("PaletteIndex", x.paletteIndex);
+            w.value("Alpha", f2(x.alpha));
+        }
+        Paint::LinearGradient(x) => {
+            dump_plain_color_line(w, &x.colorLine);
+            for (name, value) in [
+                ("x0", x.x0),
+                ("y0", x.y0),
+                ("x1", x.x1),
+                ("y1", x.y1),
+                ("x2", x.x2),
+                ("y2", x.y2),
+            ] {
+                w.value(name, value);
+            }
+        }
+        Paint::VarLinearGradient(x) => {
+            dump_var_color_line(w, &x.colorLine);
+            for (name, value) in [
+                ("x0", x.x0),
+                ("y0", x.y0),
+                ("x1", x.x1),
+                ("y1", x.y1),
+                ("x2", x.x2),
+                ("y2", x.y2),
+            ] {
+                w.value(name, value);
+            }
+        }
+        Paint::RadialGradient(x) => {
+            dump_plain_color_line(w, &x.colorLine);
+            w.value("x0", x.x0);
+            w.value("y0", x.y0);
+            w.value("r0", x.radius0);
+            w.value("x1", x.x1);
+            w.value("y1", x.y1);
+            w.value("r1", x.radius1);
+        }
+        Paint::VarRadialGradient(x) => {
+            dump_var_color_line(w, &x.colorLine);
+            w.value("x0", x.x0);
+            w.value("y0", x.y0);
+            w.value("r0", x.radius0);
+            w.value("x1", x.x1);
+            w.value("y1", x.y1);
+            w.value("r1", x.radius1);
+        }
+        Paint::SweepGradient(x) => {
+            dump_plain_color_line(w, &x.colorLine);
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+            w.value("startAngle", angle2str(x.startAngle, 1.0));
+            w.value("endAngle", angle2str(x.endAngle, 1.0));
+        }
+        Paint::VarSweepGradient(x) => {
+            dump_var_color_line(w, &x.colorLine);
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+            w.value("startAngle", angle2str(x.startAngle, 1.0));
+            w.value("endAngle", angle2str(x.endAngle, 1.0));
+        }
+        Paint::Glyph(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("Glyph", glyph_order.name(x.glyphID));
+        }
+        Paint::ColrGlyph(x) => {
+            w.value("Glyph", glyph_order.name(x.glyphID));
+        }
+        Paint::Transform(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            if let Some(t) = &x.transform.link {
+                dump_affine(w, [t.xx, t.yx, t.xy, t.yy, t.dx, t.dy], None);
+            }
+        }
+        Paint::VarTransform(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            if let Some(t) = &x.transform.link {
+                dump_affine(
+                    w,
+                    [t.xx, t.yx, t.xy, t.yy, t.dx, t.dy],
+                    Some(t.varIndexBase),
+                );
+            }
+        }
+        Paint::Translate(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("dx", x.dx);
+            w.value("dy", x.dy);
+        }
+        Paint::VarTranslate(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("dx", x.dx);
+            w.value("dy", x.dy);
+        }
+        Paint::Scale(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scaleX", f2(x.scaleX));
+            w.value("scaleY", f2(x.scaleY));
+        }
+        Paint::VarScale(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scaleX", f2(x.scaleX));
+            w.value("scaleY", f2(x.scaleY));
+        }
+        Paint::ScaleAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scaleX", f2(x.scaleX));
+            w.value("scaleY", f2(x.scaleY));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::VarScaleAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scaleX", f2(x.scaleX));
+            w.value("scaleY", f2(x.scaleY));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::ScaleUniform(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scale", f2(x.scale));
+        }
+        Paint::VarScaleUniform(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scale", f2(x.scale));
+        }
+        Paint::ScaleUniformAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scale", f2(x.scale));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::VarScaleUniformAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("scale", f2(x.scale));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::Rotate(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("angle", angle2str(x.angle, 0.0));
+        }
+        Paint::VarRotate(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("angle", angle2str(x.angle, 0.0));
+        }
+        Paint::RotateAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("angle", angle2str(x.angle, 0.0));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::VarRotateAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("angle", angle2str(x.angle, 0.0));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::Skew(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("xSkewAngle", angle2str(x.xSkewAngle, 0.0));
+            w.value("ySkewAngle", angle2str(x.ySkewAngle, 0.0));
+        }
+        Paint::VarSkew(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("xSkewAngle", angle2str(x.xSkewAngle, 0.0));
+            w.value("ySkewAngle", angle2str(x.ySkewAngle, 0.0));
+        }
+        Paint::SkewAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("xSkewAngle", angle2str(x.xSkewAngle, 0.0));
+            w.value("ySkewAngle", angle2str(x.ySkewAngle, 0.0));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::VarSkewAroundCenter(x) => {
+            dump_child_paint(w, "Paint", &x.paint, glyph_order);
+            w.value("xSkewAngle", angle2str(x.xSkewAngle, 0.0));
+            w.value("ySkewAngle", angle2str(x.ySkewAngle, 0.0));
+            w.value("centerX", x.centerX);
+            w.value("centerY", x.centerY);
+        }
+        Paint::Composite(x) => {
+            dump_child_paint(w, "SourcePaint", &x.sourcePaint, glyph_order);
+            w.value("CompositeMode", enum_name(COMPOSITE_MODES, x.compositeMode));
+            dump_child_paint(w, "BackdropPaint", &x.backdropPaint, glyph_order);
+        }
+    }
+    if let Some(var_index_base) = paint.var_index_base() {
+        w.value("VarIndexBase", var_index_base);
+    }
+    w.end(tag);
+}
+
+fn load_child_paint(
+    node: Node,
+    tag: &str,
+    glyph_order: &GlyphOrder,
+) -> Result<Offset24<Paint>, DeserializationError> {
+    Ok(Offset24::to(load_paint(
+        required_child(node, tag)?,
+        glyph_order,
+    )?))
+}
+
+fn load_paint(node: Node, glyph_order: &GlyphOrder) -> Result<Paint, DeserializationError> {
+    let format: u8 = num(node, "Format")?;
+    let v = |name: &str| child_value::<i16>(node, name);
+    let f2 = |name: &str| child_value::<f32>(node, name);
+    let u = |name: &str| child_value::<u16>(node, name);
+    let var_index_base = || child_value::<u32>(node, "VarIndexBase");
+    let paint = || load_child_paint(node, "Paint", glyph_order);
+    Ok(match format {
+        1 => Paint::ColrLayers(Box::new(PaintColrLayers {
+            format,
+            numLayers: child_value(node, "NumLayers")?,
+            firstLayerIndex: child_value(node, "FirstLayerIndex")?,
+        })),
+        2 => Paint::Solid(Box::new(PaintSolid {
+            format,
+            paletteIndex: u("PaletteIndex")?,
+            alpha: f2("Alpha")?,
+        })),
+        3 => Paint::VarSolid(Box::new(PaintVarSolid {
+            format,
+            paletteIndex: u("PaletteIndex")?,
+            alpha: f2("Alpha")?,
+            varIndexBase: var_index_base()?,
+        })),
+        4 => Paint::LinearGradient(Box::new(PaintLinearGradient {
+            format,
+            colorLine: load_plain_color_line(node)?,
+            x0: v("x0")?,
+            y0: v("y0")?,
+            x1: v("x1")?,
+            y1: v("y1")?,
+            x2: v("x2")?,
+            y2: v("y2")?,
+        })),
+        5 => Paint::VarLinearGradient(Box::new(PaintVarLinearGradient {
+            format,
+            colorLine: load_var_color_line(node)?,
+            x0: v("x0")?,
+            y0: v("y0")?,
+            x1: v("x1")?,
+            y1: v("y1")?,
+            x2: v("x2")?,
+            y2: v("y2")?,
+            varIndexBase: var_index_base()?,
+        })),
+        6 => Paint::RadialGradient(Box::new(PaintRadialGradient {
+            format,
+            colorLine: load_plain_color_line(node)?,
+            x0: v("x0")?,
+            y0: v("y0")?,
+            radius0: u("r0")?,
+            x1: v("x1")?,
+            y1: v("y1")?,
+            radius1: u("r1")?,
+        })),
+        7 => Paint::VarRadialGradient(Box::new(PaintVarRadialGradient {
+            format,
+            colorLine: load_var_color_line(node)?,
+            x0: v("x0")?,
+            y0: v("y0")?,
+            radius0: u("r0")?,
+            x1: v("x1")?,
+            y1: v("y1")?,
+            radius1: u("r1")?,
+            varIndexBase: var_index_base()?,
+        })),
+        8 => Paint::SweepGradient(Box::new(PaintSweepGradient {
+            format,
+            colorLine: load_plain_color_line(node)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            startAngle: child_angle(node, "startAngle", 1.0)?,
+            endAngle: child_angle(node, "endAngle", 1.0)?,
+        })),
+        9 => Paint::VarSweepGradient(Box::new(PaintVarSweepGradient {
+            format,
+            colorLine: load_var_color_line(node)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            startAngle: child_angle(node, "startAngle", 1.0)?,
+            endAngle: child_angle(node, "endAngle", 1.0)?,
+            varIndexBase: var_index_base()?,
+        })),
+        10 => Paint::Glyph(Box::new(PaintGlyph {
+            format,
+            paint: paint()?,
+            glyphID: child_glyph(node, "Glyph", glyph_order)?,
+        })),
+        11 => Paint::ColrGlyph(Box::new(PaintColrGlyph {
+            format,
+            glyphID: child_glyph(node, "Glyph", glyph_order)?,
+        })),
+        12 => {
+            let [xx, yx, xy, yy, dx, dy] = load_affine(node)?;
+            Paint::Transform(Box::new(PaintTransform {
+                format,
+                paint: paint()?,
+                transform: Offset24::to(Affine2x3 {
+                    xx,
+                    yx,
+                    xy,
+                    yy,
+                    dx,
+                    dy,
+                }),
+            }))
+        }
+        13 => {
+            let [xx, yx, xy, yy, dx, dy] = load_affine(node)?;
+            Paint::VarTransform(Box::new(PaintVarTransform {
+                format,
+                paint: paint()?,
+                transform: Offset24::to(VarAffine2x3 {
+                    xx,
+                    yx,
+                    xy,
+                    yy,
+                    dx,
+                    dy,
+                    varIndexBase: child_value(required_child(node, "Transform")?, "VarIndexBase")?,
+                }),
+            }))
+        }
+        14 => Paint::Translate(Box::new(PaintTranslate {
+            format,
+            paint: paint()?,
+            dx: v("dx")?,
+            dy: v("dy")?,
+        })),
+        15 => Paint::VarTranslate(Box::new(PaintVarTranslate {
+            format,
+            paint: paint()?,
+            dx: v("dx")?,
+            dy: v("dy")?,
+            varIndexBase: var_index_base()?,
+        })),
+        16 => Paint::Scale(Box::new(PaintScale {
+            format,
+            paint: paint()?,
+            scaleX: f2("scaleX")?,
+            scaleY: f2("scaleY")?,
+        })),
+        17 => Paint::VarScale(Box::new(PaintVarScale {
+            format,
+            paint: paint()?,
+            scaleX: f2("scaleX")?,
+            scaleY: f2("scaleY")?,
+            varIndexBase: var_index_base()?,
+        })),
+        18 => Paint::ScaleAroundCenter(Box::new(PaintScaleAroundCenter {
+            format,
+            paint: paint()?,
+            scaleX: f2("scaleX")?,
+            scaleY: f2("scaleY")?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+        })),
+        19 => Paint::VarScaleAroundCenter(Box::new(PaintVarScaleAroundCenter {
+            format,
+            paint: paint()?,
+            scaleX: f2("scaleX")?,
+            scaleY: f2("scaleY")?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            varIndexBase: var_index_base()?,
+        })),
+        20 => Paint::ScaleUniform(Box::new(PaintScaleUniform {
+            format,
+            paint: paint()?,
+            scale: f2("scale")?,
+        })),
+        21 => Paint::VarScaleUniform(Box::new(PaintVarScaleUniform {
+            format,
+            paint: paint()?,
+            scale: f2("scale")?,
+            varIndexBase: var_index_base()?,
+        })),
+        22 => Paint::ScaleUniformAroundCenter(Box::new(PaintScaleUniformAroundCenter {
+            format,
+            paint: paint()?,
+            scale: f2("scale")?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+        })),
+        23 => Paint::VarScaleUniformAroundCenter(Box::new(PaintVarScaleUniformAroundCenter {
+            format,
+            paint: paint()?,
+            scale: f2("scale")?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            varIndexBase: var_index_base()?,
+        })),
+        24 => Paint::Rotate(Box::new(PaintRotate {
+            format,
+            paint: paint()?,
+            angle: child_angle(node, "angle", 0.0)?,
+        })),
+        25 => Paint::VarRotate(Box::new(PaintVarRotate {
+            format,
+            paint: paint()?,
+            angle: child_angle(node, "angle", 0.0)?,
+            varIndexBase: var_index_base()?,
+        })),
+        26 => Paint::RotateAroundCenter(Box::new(PaintRotateAroundCenter {
+            format,
+            paint: paint()?,
+            angle: child_angle(node, "angle", 0.0)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+        })),
+        27 => Paint::VarRotateAroundCenter(Box::new(PaintVarRotateAroundCenter {
+            format,
+            paint: paint()?,
+            angle: child_angle(node, "angle", 0.0)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            varIndexBase: var_index_base()?,
+        })),
+        28 => Paint::Skew(Box::new(PaintSkew {
+            format,
+            paint: paint()?,
+            xSkewAngle: child_angle(node, "xSkewAngle", 0.0)?,
+            ySkewAngle: child_angle(node, "ySkewAngle", 0.0)?,
+        })),
+        29 => Paint::VarSkew(Box::new(PaintVarSkew {
+            format,
+            paint: paint()?,
+            xSkewAngle: child_angle(node, "xSkewAngle", 0.0)?,
+            ySkewAngle: child_angle(node, "ySkewAngle", 0.0)?,
+            varIndexBase: var_index_base()?,
+        })),
+        30 => Paint::SkewAroundCenter(Box::new(PaintSkewAroundCenter {
+            format,
+            paint: paint()?,
+            xSkewAngle: child_angle(node, "xSkewAngle", 0.0)?,
+            ySkewAngle: child_angle(node, "ySkewAngle", 0.0)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+        })),
+        31 => Paint::VarSkewAroundCenter(Box::new(PaintVarSkewAroundCenter {
+            format,
+            paint: paint()?,
+            xSkewAngle: child_angle(node, "xSkewAngle", 0.0)?,
+            ySkewAngle: child_angle(node, "ySkewAngle", 0.0)?,
+            centerX: v("centerX")?,
+            centerY: v("centerY")?,
+            varIndexBase: var_index_base()?,
+        })),
+        32 => Paint::Composite(Box::new(PaintComposite {
+            format,
+            sourcePaint: load_child_paint(node, "SourcePaint", glyph_order)?,
+            compositeMode: enum_value(COMPOSITE_MODES, node, "CompositeMode")?,
+            backdropPaint: load_child_paint(node, "BackdropPaint", glyph_order)?,
+        })),
+        _ => return Err(error(format!("Invalid paint format {}", format))),
+    })
+}
+
+fn dump_clip_box(w: &mut XmlWriter, clip_box: &ClipBox) {
+    let format: u8 = if clip_box.varIndexBase.is_some() {
+        2
+    } else {
+        1
+    };
+    w.begin("ClipBox", &[("Format", &format)]);
+    w.value("xMin", clip_box.xMin);
+    w.value("yMin", clip_box.yMin);
+    w.value("xMax", clip_box.xMax);
+    w.value("yMax", clip_box.yMax);
+    if let Some(var_index_base) = clip_box.varIndexBase {
+        w.value("VarIndexBase", var_index_base);
+    }
+    w.end("ClipBox");
+}
+
+fn load_clip_box(node: Node) -> Result<ClipBox, DeserializationError> {
+    let format: u8 = num(node, "Format")?;
+    Ok(ClipBox {
+        xMin: child_value(node, "xMin")?,
+        yMin: child_value(node, "yMin")?,
+        xMax: child_value(node, "xMax")?,
+        yMax: child_value(node, "yMax")?,
+        varIndexBase: if format == 2 {
+            Some(child_value(node, "VarIndexBase")?)
+        } else {
+            None
+        },
+    })
+}
+
+fn dump_colr_v0(w: &mut XmlWriter, colr: &COLR, glyph_order: &GlyphOrder) {
+    w.value("version", 0);
+    for (&glyph, layers) in &colr.base_glyphs {
+        w.begin("ColorGlyph", &[("name", &glyph_order.name(glyph))]);
+        for layer in layers {
+            w.simple(
+                "layer",
+                &[
+                    ("colorID", &layer.paletteIndex),
+                    ("name", &glyph_order.name(layer.glyphID)),
+                ],
+            );
+        }
+        w.end("ColorGlyph");
+    }
+}
+
+pub(crate) fn dump_colr(w: &mut XmlWriter, colr: &COLR, glyph_order: &GlyphOrder) {
+    if !colr.is_v1() {
+        dump_colr_v0(w, colr, glyph_order);
+        return;
+    }
+    w.value("Version", 1);
+    w.comment(&format!("BaseGlyphRecordCount={}", colr.base_glyphs.len()));
+    let mut layer_count = 0;
+    if !colr.base_glyphs.is_empty() {
+        w.begin("BaseGlyphRecordArray", &[]);
+        for (ix, (&glyph, layers)) in colr.base_glyphs.iter().enumerate() {
+            w.begin("BaseGlyphRecord", &[("index", &ix)]);
+            w.value("BaseGlyph", glyph_order.name(glyph));
+            w.value("FirstLayerIndex", layer_count);
+            w.value("NumLayers", layers.len());
+            w.end("BaseGlyphRecord");
+            layer_count += layers.len();
+        }
+        w.end("BaseGlyphRecordArray");
+        w.begin("LayerRecordArray", &[]);
+        for (ix, layer) in colr.base_glyphs.values().flatten().enumerate() {
+            w.begin("LayerRecord", &[("index", &ix)]);
+            w.value("LayerGlyph", glyph_order.name(layer.glyphID));
+            w.value("PaletteIndex", layer.paletteIndex);
+            w.end("LayerRecord");
+        }
+        w.end("LayerRecordArray");
+    }
+    w.comment(&format!("LayerRecordCount={}", layer_count));
+    if !colr.base_glyph_paints.is_empty() {
+        w.begin("BaseGlyphList", &[]);
+        w.comment(&format!("BaseGlyphCount={}", colr.base_glyph_paints.len()));
+        for (ix, (&glyph, paint)) in colr.base_glyph_paints.iter().enumerate() {
+            w.begin("BaseGlyphPaintRecord", &[("index", &ix)]);
+            w.value("BaseGlyph", glyph_order.name(glyph));
+            dump_paint(w, "Paint", None, paint, glyph_order);
+            w.end("BaseGlyphPaintRecord");
+        }
+        w.end("BaseGlyphList");
+    }
+    if !colr.layers.is_empty() {
+        w.begin("LayerList", &[]);
+        w.comment(&format!("LayerCount={}", colr.layers.len()));
+        for (ix, paint) in colr.layers.iter().enumerate() {
+            dump_paint(w, "Paint", Some(ix), paint, glyph_order);
+        }
+        w.end("LayerList");
+    }
+    if !colr.clips.is_empty() {
+        // Glyphs sharing a clip box are listed together
+        let mut clips: Vec<(Vec<GlyphID>, &ClipBox)> = vec![];
+        for (&glyph, clip_box) in &colr.clips {
+            match clips.iter_mut().find(|(_, b)| *b == clip_box) {
+                Some((glyphs, _)) => glyphs.push(glyph),
+                None => clips.push((vec![glyph], clip_box)),
+            }
+        }
+        w.begin("ClipList", &[("Format", &1)]);
+        for (glyphs, clip_box) in clips {
+            w.begin("Clip", &[]);
+            for glyph in glyphs {
+                w.value("Glyph", glyph_order.name(glyph));
+            }
+            dump_clip_box(w, clip_box);
+            w.end("Clip");
+        }
+        w.end("ClipList");
+    }
+    if let Some(map) = &colr.var_index_map {
+        dump_index_map(w, "VarIndexMap", map, &|ix| ix.to_string(), "index");
+    }
+    if let Some(store) = &colr.item_variation_store {
+        dump_var_store(w, store);
+    }
+}
+
+pub(crate) fn load_colr(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<COLR, DeserializationError> {
+    let mut colr = COLR::default();
+    if child(node, "version").is_some() {
+        for glyph in elements_named(node, "ColorGlyph") {
+            let layers = elements_named(glyph, "layer")
+                .map(|layer| {
+                    Ok(LayerRecord {
+                        glyphID: glyph_order.id(attr(layer, "name")?)?,
+                        paletteIndex: num(layer, "colorID")?,
+                    })
+                })
+                .collect::<Result<_, DeserializationError>>()?;
+            colr.base_glyphs
+                .insert(glyph_order.id(attr(glyph, "name")?)?, layers);
+        }
+        return Ok(colr);
+    }
+
+    let layer_records: Vec<LayerRecord> = match child(node, "LayerRecordArray") {
+        Some(array) => elements_named(array, "LayerRecord")
+            .map(|record| {
+                Ok(LayerRecord {
+                    glyphID: child_glyph(record, "LayerGlyph", glyph_order)?,
+                    paletteIndex: child_value(record, "PaletteIndex")?,
+                })
+            })
+            .collect::<Result<_, DeserializationError>>()?,
+        None => vec![],
+    };
+    if let Some(array) = child(node, "BaseGlyphRecordArray") {
+        for record in elements_named(array, "BaseGlyphRecord") {
+            let first: usize = child_value(record, "FirstLayerIndex")?;
+            let count: usize = child_value(record, "NumLayers")?;
+            let layers = layer_records
+                .get(first..first + count)
+                .ok_or_else(|| error("Base glyph record runs off layer records"))?;
+            colr.base_glyphs.insert(
+                child_glyph(record, "BaseGlyph", glyph_order)?,
+                layers.to_vec(),
+            );
+        }
+    }
+    if let Some(list) = child(node, "BaseGlyphList") {
+        for record in elements_named(list, "BaseGlyphPaintRecord") {
+            colr.base_glyph_paints.insert(
+                child_glyph(record, "BaseGlyph", glyph_order)?,
+                load_paint(required_child(record, "Paint")?, glyph_order)?,
+            );
+        }
+    }
+    if let Some(list) = child(node, "LayerList") {
+        colr.layers = elements_named(list, "Paint")
+            .map(|paint| load_paint(paint, glyph_order))
+            .collect::<Result<_, _>>()?;
+    }
+    if let Some(list) = child(node, "ClipList") {
+        for clip in elements_named(list, "Clip") {
+            let clip_box = load_clip_box(required_child(clip, "ClipBox")?)?;
+            for glyph in elements_named(clip, "Glyph") {
+                colr.clips
+                    .insert(glyph_order.id(attr(glyph, "value")?)?, clip_box);
+            }
+        }
+    }
+    colr.var_index_map = child(node, "VarIndexMap")
+        .map(|map| load_index_map(map, &|s| super::xml::parse(s), "index"))
+        .transpose()?;
+    colr.item_variation_store = child(node, "VarStore").map(load_var_store).transpose()?;
+    Ok(colr)
+}
+
+fn color2str(color: &ColorRecord) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}{:02X}",
+        color.red, color.green, color.blue, color.alpha
+    )
+}
+
+fn str2color(s: &str) -> Result<ColorRecord, DeserializationError> {
+    let digits = s.trim().trim_start_matches('#');
+    let component = |ix: usize| {
+        digits
+            .get(ix * 2..ix * 2 + 2)
+            .and_then(|d| u8::from_str_radix(d, 16).ok())
+    };
+    match (component(0), component(1), component(2)) {
+        (Some(red), Some(green), Some(blue)) if digits.len() == 6 || digits.len() == 8 => Ok(
+            ColorRecord::new(red, green, blue, component(3).unwrap_or(0xFF)),
+        ),
+        _ => Err(error(format!("Bad color value '{}'", s))),
+    }
+}
+
+pub(crate) fn dump_cpal(w: &mut XmlWriter, cpal: &CPAL) {
+    let v1 = cpal.palette_types.is_some()
+        || cpal.palette_labels.is_some()
+        || cpal.palette_entry_labels.is_some();
+    w.value("version", if v1 { 1 } else { 0 });
+    w.value("numPaletteEntries", cpal.num_palette_entries());
+    for (ix, palette) in cpal.palettes.iter().enumerate() {
+        let label = cpal.palette_labels.as_ref().and_then(|l| l.get(ix));
+        let palette_type = cpal
+            .palette_types
+            .as_ref()
+            .and_then(|t| t.get(ix))
+            .map(|t| t.bits());
+        let mut attrs: Vec<(&str, &dyn std::fmt::Display)> = vec![("index", &ix)];
+        if let Some(label) = &label {
+            attrs.push(("label", label));
+        }
+        if let Some(palette_type) = &palette_type {
+            attrs.push(("type", palette_type));
+        }
+        w.begin("palette", &attrs);
+        for (color_ix, color) in palette.iter().enumerate() {
+            w.indexed_value("color", color_ix, color2str(color));
+        }
+        w.end("palette");
+    }
+    if let Some(labels) = &cpal.palette_entry_labels {
+        w.begin("paletteEntryLabels", &[]);
+        for (ix, label) in labels.iter().enumerate() {
+            w.indexed_value("label", ix, label);
+        }
+        w.end("paletteEntryLabels");
+    }
+}
+
+/// Palette attributes are optional in the XML; a table has them for every
+/// palette if it has them for any.
+fn per_palette<T: Copy>(values: BTreeMap<usize, T>, count: usize, default: T) -> Option<Vec<T>> {
+    (!values.is_empty()).then(|| {
+        (0..count)
+            .map(|ix| values.get(&ix).copied().unwrap_or(default))
+            .collect()
+    })
+}
+
+pub(crate) fn load_cpal(node: Node) -> Result<CPAL, DeserializationError> {
+    let mut palettes = vec![];
+    let mut types: BTreeMap<usize, PaletteType> = BTreeMap::new();
+    let mut labels: BTreeMap<usize, u16> = BTreeMap::new();
+    for (ix, palette) in elements_named(node, "palette").enumerate() {
+        if let Some(palette_type) = opt_num::<u32>(palette, "type")? {
+            types.insert(ix, PaletteType::from_bits_truncate(palette_type));
+        }
+        if let Some(label) = opt_num(palette, "label")? {
+            labels.insert(ix, label);
+        }
+        palettes.push(
+            elements_named(palette, "color")
+                .map(|color| str2color(attr(color, "value")?))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    let palette_types = per_palette(types, palettes.len(), PaletteType::empty());
+    let palette_labels = per_palette(labels, palettes.len(), NO_LABEL);
+    let palette_entry_labels = child(node, "paletteEntryLabels")
+        .map(|labels| {
+            elements(labels)
+                .map(|label| num(label, "value"))
+                .collect::<Result<Vec<u16>, _>>()
+        })
+        .transpose()?;
+    Ok(CPAL {
+        palettes,
+        palette_types,
+        palette_labels,
+        palette_entry_labels,
+    })
+}
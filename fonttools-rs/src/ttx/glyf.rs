@@ -0,0 +1,231 @@
+//! TTX conversion for the `glyf` table.
+use super::instructions::{dump_program, load_program};
+use super::xml::{attr, elements, fl2str, num, opt_num, Node, XmlWriter};
+use super::GlyphOrder;
+use crate::tables::glyf::{self, Component, ComponentFlags, Glyph, Point};
+use kurbo::Affine;
+use otspec::types::*;
+use otspec::DeserializationError;
+
+/// The component flags which are not recomputed on serialization.
+fn user_flags() -> ComponentFlags {
+    ComponentFlags::ROUND_XY_TO_GRID
+        | ComponentFlags::USE_MY_METRICS
+        | ComponentFlags::SCALED_COMPONENT_OFFSET
+        | ComponentFlags::UNSCALED_COMPONENT_OFFSET
+        | ComponentFlags::OVERLAP_COMPOUND
+}
+
+fn dump_component(w: &mut XmlWriter, component: &Component, glyph_order: &GlyphOrder) {
+    let [scale_x, scale01, scale10, scale_y, dx, dy] = component.transformation.as_coeffs();
+    let name = glyph_order.name(component.glyph_index);
+    let mut attrs: Vec<(&str, String)> = vec![];
+    match component.match_points {
+        Some((first, second)) => {
+            attrs.push(("firstPt", first.to_string()));
+            attrs.push(("secondPt", second.to_string()));
+        }
+        None => {
+            attrs.push(("x", (dx.round() as i16).to_string()));
+            attrs.push(("y", (dy.round() as i16).to_string()));
+        }
+    }
+    let scale = |v: f64| fl2str(v as f32, 14);
+    if scale01 != 0.0 || scale10 != 0.0 {
+        attrs.push(("scalex", scale(scale_x)));
+        attrs.push(("scale01", scale(scale01)));
+        attrs.push(("scale10", scale(scale10)));
+        attrs.push(("scaley", scale(scale_y)));
+    } else if scale_x != scale_y {
+        attrs.push(("scalex", scale(scale_x)));
+        attrs.push(("scaley", scale(scale_y)));
+    } else if scale_x != 1.0 {
+        attrs.push(("scale", scale(scale_x)));
+    }
+    attrs.push((
+        "flags",
+        format!("{:#x}", (component.flags & user_flags()).bits()),
+    ));
+    let mut all_attrs: Vec<(&str, &dyn std::fmt::Display)> = vec![("glyphName", &name)];
+    all_attrs.extend(attrs.iter().map(|(k, v)| (*k, v as &dyn std::fmt::Display)));
+    w.simple("component", &all_attrs);
+}
+
+fn dump_glyph(w: &mut XmlWriter, name: &str, glyph: &Glyph, glyph_order: &GlyphOrder) {
+    if glyph.is_empty() {
+        w.simple_inline("TTGlyph", &[("name", &name)]);
+        w.comment("contains no outline data");
+        return;
+    }
+    w.begin(
+        "TTGlyph",
+        &[
+            ("name", &name),
+            ("xMin", &glyph.xMin),
+            ("yMin", &glyph.yMin),
+            ("xMax", &glyph.xMax),
+            ("yMax", &glyph.yMax),
+        ],
+    );
+    for (ix, contour) in glyph.contours.iter().enumerate() {
+        w.begin("contour", &[]);
+        for (pt_ix, point) in contour.iter().enumerate() {
+            let on = u8::from(point.on_curve);
+            if glyph.overlap && ix == 0 && pt_ix == 0 {
+                w.simple(
+                    "pt",
+                    &[
+                        ("x", &point.x),
+                        ("y", &point.y),
+                        ("on", &on),
+                        ("overlap", &1),
+                    ],
+                );
+            } else {
+                w.simple("pt", &[("x", &point.x), ("y", &point.y), ("on", &on)]);
+            }
+        }
+        w.end("contour");
+    }
+    for component in &glyph.components {
+        dump_component(w, component, glyph_order);
+    }
+    if !glyph.instructions.is_empty() {
+        w.begin("instructions", &[]);
+        dump_program(w, &glyph.instructions);
+        w.end("instructions");
+    } else if !glyph.contours.is_empty() {
+        w.simple("instructions", &[]);
+    }
+    w.end("TTGlyph");
+}
+
+pub(crate) fn dump_glyf(w: &mut XmlWriter, glyf: &glyf::glyf, glyph_order: &GlyphOrder) {
+    w.newline();
+    w.comment("The xMin, yMin, xMax and yMax values\nwill be recalculated by the compiler.");
+    w.newline();
+    for (gid, glyph) in glyf.glyphs.iter().enumerate() {
+        dump_glyph(w, &glyph_order.name(gid as GlyphID), glyph, glyph_order);
+        w.newline();
+    }
+}
+
+fn load_component(node: Node, glyph_order: &GlyphOrder) -> Result<Component, DeserializationError> {
+    let glyph_index = glyph_order.id(attr(node, "glyphName")?)?;
+    let match_points = match opt_num::<u16>(node, "firstPt")? {
+        Some(first) => Some((first, num(node, "secondPt")?)),
+        None => None,
+    };
+    let (dx, dy) = if match_points.is_some() {
+        (0.0, 0.0)
+    } else {
+        (num::<i16>(node, "x")? as f64, num::<i16>(node, "y")? as f64)
+    };
+    let scale = |name: &str| -> Result<Option<f64>, DeserializationError> {
+        Ok(opt_num::<f32>(node, name)?.map(|v| v as f64))
+    };
+    let coeffs = if let Some(scale_x) = scale("scalex")? {
+        [
+            scale_x,
+            scale("scale01")?.unwrap_or(0.0),
+            scale("scale10")?.unwrap_or(0.0),
+            scale("scaley")?.unwrap_or(scale_x),
+            dx,
+            dy,
+        ]
+    } else {
+        let s = scale("scale")?.unwrap_or(1.0);
+        [s, 0.0, 0.0, s, dx, dy]
+    };
+    let flags: u16 = opt_num(node, "flags")?.unwrap_or(0);
+    Ok(Component {
+        glyph_index,
+        transformation: Affine::new(coeffs),
+        match_points,
+        flags: ComponentFlags::from_bits_truncate(flags) & user_flags(),
+    })
+}
+
+/// Loads a glyph, returning it along with whether its bounds were given.
+fn load_glyph(node: Node, glyph_order: &GlyphOrder) -> Result<(Glyph, bool), DeserializationError> {
+    let mut glyph = Glyph {
+        xMin: opt_num(node, "xMin")?.unwrap_or(0),
+        xMax: opt_num(node, "xMax")?.unwrap_or(0),
+        yMin: opt_num(node, "yMin")?.unwrap_or(0),
+        yMax: opt_num(node, "yMax")?.unwrap_or(0),
+        contours: vec![],
+        instructions: vec![],
+        components: vec![],
+        overlap: false,
+    };
+    for element in elements(node) {
+        match element.tag_name().name() {
+            "contour" => {
+                let contour = elements(element)
+                    .filter(|n| n.has_tag_name("pt"))
+                    .map(|pt| {
+                        if opt_num::<u8>(pt, "overlap")?.unwrap_or(0) != 0 {
+                            glyph.overlap = true;
+                        }
+                        Ok(Point {
+                            x: num(pt, "x")?,
+                            y: num(pt, "y")?,
+                            on_curve: num::<u8>(pt, "on")? & 1 == 1,
+                        })
+                    })
+                    .collect::<Result<Vec<Point>, DeserializationError>>()?;
+                glyph.contours.push(contour);
+            }
+            "component" => glyph.components.push(load_component(element, glyph_order)?),
+            "instructions" => glyph.instructions = load_program(element)?,
+            _ => {}
+        }
+    }
+    if glyph
+        .components
+        .iter()
+        .any(|c| c.flags.contains(ComponentFlags::OVERLAP_COMPOUND))
+    {
+        glyph.overlap = true;
+    }
+    let has_bounds = node.attribute("xMin").is_some() || glyph.is_empty();
+    Ok((glyph, has_bounds))
+}
+
+pub(crate) fn load_glyf(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<glyf::glyf, DeserializationError> {
+    let empty = || Glyph {
+        xMin: 0,
+        xMax: 0,
+        yMin: 0,
+        yMax: 0,
+        contours: vec![],
+        instructions: vec![],
+        components: vec![],
+        overlap: false,
+    };
+    let mut glyphs: Vec<Glyph> = (0..glyph_order.len()).map(|_| empty()).collect();
+    let mut missing_bounds = vec![];
+    for element in elements(node).filter(|n| n.has_tag_name("TTGlyph")) {
+        let gid = glyph_order.id(attr(element, "name")?)? as usize;
+        let (glyph, has_bounds) = load_glyph(element, glyph_order)?;
+        if gid >= glyphs.len() {
+            glyphs.resize_with(gid + 1, empty);
+        }
+        glyphs[gid] = glyph;
+        if !has_bounds {
+            missing_bounds.push(gid);
+        }
+    }
+    let mut table = glyf::glyf { glyphs };
+    if !missing_bounds.is_empty() {
+        let mut bounds = table.clone();
+        bounds.recalc_bounds();
+        for gid in missing_bounds {
+            table.glyphs[gid].set_bounds_rect(bounds.glyphs[gid].bounds_rect());
+        }
+    }
+    Ok(table)
+}
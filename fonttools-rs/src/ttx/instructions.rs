@@ -0,0 +1,407 @@
+//! Disassembling and assembling TrueType instructions, in the same
+//! assembly language as fontTools.
+use super::xml::{child, error, hexdata, num2binary, Node, XmlWriter};
+use otspec::DeserializationError;
+
+/// Opcode, mnemonic, number of argument bits and descriptive name.
+const INSTRUCTIONS: &[(u8, &str, u32, &str)] = &[
+    (0x7f, "AA", 0, "AdjustAngle"),
+    (0x64, "ABS", 0, "Absolute"),
+    (0x60, "ADD", 0, "Add"),
+    (0x27, "ALIGNPTS", 0, "AlignPts"),
+    (0x3c, "ALIGNRP", 0, "AlignRelativePt"),
+    (0x5a, "AND", 0, "LogicalAnd"),
+    (0x2b, "CALL", 0, "CallFunction"),
+    (0x67, "CEILING", 0, "Ceiling"),
+    (0x25, "CINDEX", 0, "CopyXToTopStack"),
+    (0x22, "CLEAR", 0, "ClearStack"),
+    (0x4f, "DEBUG", 0, "DebugCall"),
+    (0x73, "DELTAC1", 0, "DeltaExceptionC1"),
+    (0x74, "DELTAC2", 0, "DeltaExceptionC2"),
+    (0x75, "DELTAC3", 0, "DeltaExceptionC3"),
+    (0x5d, "DELTAP1", 0, "DeltaExceptionP1"),
+    (0x71, "DELTAP2", 0, "DeltaExceptionP2"),
+    (0x72, "DELTAP3", 0, "DeltaExceptionP3"),
+    (0x24, "DEPTH", 0, "GetDepthStack"),
+    (0x62, "DIV", 0, "Divide"),
+    (0x20, "DUP", 0, "DuplicateTopStack"),
+    (0x59, "EIF", 0, "EndIf"),
+    (0x1b, "ELSE", 0, "Else"),
+    (0x2d, "ENDF", 0, "EndFunctionDefinition"),
+    (0x54, "EQ", 0, "Equal"),
+    (0x57, "EVEN", 0, "Even"),
+    (0x2c, "FDEF", 0, "FunctionDefinition"),
+    (0x4e, "FLIPOFF", 0, "SetAutoFlipOff"),
+    (0x4d, "FLIPON", 0, "SetAutoFlipOn"),
+    (0x80, "FLIPPT", 0, "FlipPoint"),
+    (0x82, "FLIPRGOFF", 0, "FlipRangeOff"),
+    (0x81, "FLIPRGON", 0, "FlipRangeOn"),
+    (0x66, "FLOOR", 0, "Floor"),
+    (0x46, "GC", 1, "GetCoordOnPVector"),
+    (0x88, "GETINFO", 0, "GetInfo"),
+    (0x91, "GETVARIATION", 0, "GetVariation"),
+    (0x0d, "GFV", 0, "GetFVector"),
+    (0x0c, "GPV", 0, "GetPVector"),
+    (0x52, "GT", 0, "GreaterThan"),
+    (0x53, "GTEQ", 0, "GreaterThanOrEqual"),
+    (0x89, "IDEF", 0, "InstructionDefinition"),
+    (0x58, "IF", 0, "If"),
+    (0x8e, "INSTCTRL", 0, "SetInstrExecControl"),
+    (0x39, "IP", 0, "InterpolatePts"),
+    (0x0f, "ISECT", 0, "MovePtToIntersect"),
+    (0x30, "IUP", 1, "InterpolateUntPts"),
+    (0x1c, "JMPR", 0, "Jump"),
+    (0x79, "JROF", 0, "JumpRelativeOnFalse"),
+    (0x78, "JROT", 0, "JumpRelativeOnTrue"),
+    (0x2a, "LOOPCALL", 0, "LoopAndCallFunction"),
+    (0x50, "LT", 0, "LessThan"),
+    (0x51, "LTEQ", 0, "LessThenOrEqual"),
+    (0x8b, "MAX", 0, "Maximum"),
+    (0x49, "MD", 1, "MeasureDistance"),
+    (0x2e, "MDAP", 1, "MoveDirectAbsPt"),
+    (0xc0, "MDRP", 5, "MoveDirectRelPt"),
+    (0x3e, "MIAP", 1, "MoveIndirectAbsPt"),
+    (0x8c, "MIN", 0, "Minimum"),
+    (0x26, "MINDEX", 0, "MoveXToTopStack"),
+    (0xe0, "MIRP", 5, "MoveIndirectRelPt"),
+    (0x4b, "MPPEM", 0, "MeasurePixelPerEm"),
+    (0x4c, "MPS", 0, "MeasurePointSize"),
+    (0x3a, "MSIRP", 1, "MoveStackIndirRelPt"),
+    (0x63, "MUL", 0, "Multiply"),
+    (0x65, "NEG", 0, "Negate"),
+    (0x55, "NEQ", 0, "NotEqual"),
+    (0x5c, "NOT", 0, "LogicalNot"),
+    (0x6c, "NROUND", 2, "NoRound"),
+    (0x56, "ODD", 0, "Odd"),
+    (0x5b, "OR", 0, "LogicalOr"),
+    (0x21, "POP", 0, "PopTopStack"),
+    (0x45, "RCVT", 0, "ReadCVT"),
+    (0x7d, "RDTG", 0, "RoundDownToGrid"),
+    (0x7a, "ROFF", 0, "RoundOff"),
+    (0x8a, "ROLL", 0, "RollTopThreeStack"),
+    (0x68, "ROUND", 2, "Round"),
+    (0x43, "RS", 0, "ReadStore"),
+    (0x3d, "RTDG", 0, "RoundToDoubleGrid"),
+    (0x18, "RTG", 0, "RoundToGrid"),
+    (0x19, "RTHG", 0, "RoundToHalfGrid"),
+    (0x7c, "RUTG", 0, "RoundUpToGrid"),
+    (0x77, "S45ROUND", 0, "SuperRound45Degrees"),
+    (0x7e, "SANGW", 0, "SetAngleWeight"),
+    (0x85, "SCANCTRL", 0, "ScanConversionControl"),
+    (0x8d, "SCANTYPE", 0, "ScanType"),
+    (0x48, "SCFS", 0, "SetCoordFromStackFP"),
+    (0x1d, "SCVTCI", 0, "SetCVTCutIn"),
+    (0x5e, "SDB", 0, "SetDeltaBaseInGState"),
+    (0x86, "SDPVTL", 1, "SetDualPVectorToLine"),
+    (0x5f, "SDS", 0, "SetDeltaShiftInGState"),
+    (0x0b, "SFVFS", 0, "SetFVectorFromStack"),
+    (0x04, "SFVTCA", 1, "SetFVectorToAxis"),
+    (0x08, "SFVTL", 1, "SetFVectorToLine"),
+    (0x0e, "SFVTPV", 0, "SetFVectorToPVector"),
+    (0x34, "SHC", 1, "ShiftContourByLastPt"),
+    (0x32, "SHP", 1, "ShiftPointByLastPoint"),
+    (0x38, "SHPIX", 0, "ShiftZoneByPixel"),
+    (0x36, "SHZ", 1, "ShiftZoneByLastPoint"),
+    (0x17, "SLOOP", 0, "SetLoopVariable"),
+    (0x1a, "SMD", 0, "SetMinimumDistance"),
+    (0x0a, "SPVFS", 0, "SetPVectorFromStack"),
+    (0x02, "SPVTCA", 1, "SetPVectorToAxis"),
+    (0x06, "SPVTL", 1, "SetPVectorToLine"),
+    (0x76, "SROUND", 0, "SuperRound"),
+    (0x10, "SRP0", 0, "SetRefPoint0"),
+    (0x11, "SRP1", 0, "SetRefPoint1"),
+    (0x12, "SRP2", 0, "SetRefPoint2"),
+    (0x1f, "SSW", 0, "SetSingleWidth"),
+    (0x1e, "SSWCI", 0, "SetSingleWidthCutIn"),
+    (0x61, "SUB", 0, "Subtract"),
+    (0x00, "SVTCA", 1, "SetFPVectorToAxis"),
+    (0x23, "SWAP", 0, "SwapTopStack"),
+    (0x13, "SZP0", 0, "SetZonePointer0"),
+    (0x14, "SZP1", 0, "SetZonePointer1"),
+    (0x15, "SZP2", 0, "SetZonePointer2"),
+    (0x16, "SZPS", 0, "SetZonePointerS"),
+    (0x29, "UTP", 0, "UnTouchPt"),
+    (0x70, "WCVTF", 0, "WriteCVTInFUnits"),
+    (0x44, "WCVTP", 0, "WriteCVTInPixels"),
+    (0x42, "WS", 0, "WriteStore"),
+];
+
+const NPUSHB: u8 = 0x40;
+const NPUSHW: u8 = 0x41;
+const PUSHB: u8 = 0xb0;
+const PUSHW: u8 = 0xb8;
+const VALUES_PER_LINE: usize = 25;
+
+/// A single disassembled instruction.
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Push {
+        mnemonic: &'static str,
+        values: Vec<i32>,
+    },
+    Op {
+        mnemonic: &'static str,
+        bits: Option<String>,
+        name: &'static str,
+    },
+}
+
+fn disassemble(bytecode: &[u8]) -> Result<Vec<Instruction>, DeserializationError> {
+    let mut instructions = vec![];
+    let mut ix = 0;
+    let truncated = || error("Truncated push instruction");
+    while ix < bytecode.len() {
+        let op = bytecode[ix];
+        ix += 1;
+        let (mnemonic, count, words) = match op {
+            NPUSHB => {
+                ix += 1;
+                (
+                    "NPUSHB",
+                    *bytecode.get(ix - 1).ok_or_else(truncated)? as usize,
+                    false,
+                )
+            }
+            NPUSHW => {
+                ix += 1;
+                (
+                    "NPUSHW",
+                    *bytecode.get(ix - 1).ok_or_else(truncated)? as usize,
+                    true,
+                )
+            }
+            0xb0..=0xb7 => ("PUSHB", (op - PUSHB + 1) as usize, false),
+            0xb8..=0xbf => ("PUSHW", (op - PUSHW + 1) as usize, true),
+            _ => {
+                let &(base, mnemonic, arg_bits, name) = INSTRUCTIONS
+                    .iter()
+                    .find(|(base, _, bits, _)| (*base..=base + ((1 << bits) - 1)).contains(&op))
+                    .ok_or_else(|| error(format!("Unknown instruction {:#04x}", op)))?;
+                let bits =
+                    (arg_bits > 0).then(|| num2binary((op - base) as u32, arg_bits as usize));
+                instructions.push(Instruction::Op {
+                    mnemonic,
+                    bits,
+                    name,
+                });
+                continue;
+            }
+        };
+        let size = if words { 2 } else { 1 };
+        let data = bytecode.get(ix..ix + count * size).ok_or_else(truncated)?;
+        ix += count * size;
+        let values = if words {
+            data.chunks(2)
+                .map(|w| i16::from_be_bytes([w[0], w[1]]) as i32)
+                .collect()
+        } else {
+            data.iter().map(|&b| b as i32).collect()
+        };
+        instructions.push(Instruction::Push { mnemonic, values });
+    }
+    Ok(instructions)
+}
+
+/// Writes a TrueType program as an `<assembly>` element, or as hex
+/// `<bytecode>` if it cannot be disassembled.
+pub(crate) fn dump_program(w: &mut XmlWriter, bytecode: &[u8]) {
+    let instructions = match disassemble(bytecode) {
+        Ok(instructions) => instructions,
+        Err(e) => {
+            log::warn!("Couldn't disassemble instructions: {}", e);
+            w.begin("bytecode", &[]);
+            w.hex(bytecode);
+            w.end("bytecode");
+            return;
+        }
+    };
+    w.begin("assembly", &[]);
+    for instruction in instructions {
+        match instruction {
+            Instruction::Push { mnemonic, values } => {
+                let plural = if values.len() == 1 { "value" } else { "values" };
+                w.text(&format!(
+                    "{}[ ]\t/* {} {} pushed */",
+                    mnemonic,
+                    values.len(),
+                    plural
+                ));
+                w.newline();
+                for line in values.chunks(VALUES_PER_LINE) {
+                    let line: Vec<String> = line.iter().map(|v| v.to_string()).collect();
+                    w.text(&line.join(" "));
+                    w.newline();
+                }
+            }
+            Instruction::Op {
+                mnemonic,
+                bits,
+                name,
+            } => {
+                if matches!(mnemonic, "ELSE" | "EIF" | "ENDF") {
+                    w.dedent();
+                }
+                let bits = bits.unwrap_or_else(|| " ".to_string());
+                w.text(&format!("{}[{}]\t/* {} */", mnemonic, bits, name));
+                w.newline();
+                if matches!(mnemonic, "FDEF" | "IF" | "ELSE" | "IDEF") {
+                    w.indent();
+                }
+            }
+        }
+    }
+    w.end("assembly");
+}
+
+/// Splits assembly into mnemonics (with their bracketed arguments) and
+/// numbers, ignoring comments.
+fn tokenize(assembly: &str) -> Result<Vec<String>, DeserializationError> {
+    let mut tokens = vec![];
+    let mut rest = assembly;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment
+                .find("*/")
+                .ok_or_else(|| error("Unterminated comment in assembly"))?;
+            rest = &comment[end + 2..];
+            continue;
+        }
+        let end = if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            // A mnemonic, which may have a space inside its brackets.
+            let close = rest
+                .find(']')
+                .ok_or_else(|| error("Instruction without arguments in assembly"))?;
+            close + 1
+        } else {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        };
+        tokens.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+    Ok(tokens)
+}
+
+fn push(
+    bytecode: &mut Vec<u8>,
+    mnemonic: &str,
+    values: &[i32],
+) -> Result<(), DeserializationError> {
+    let words = mnemonic.ends_with('W')
+        || !mnemonic.starts_with('N') && values.iter().any(|v| !(0..=255).contains(v));
+    let explicit = mnemonic.starts_with('N') || values.len() > 8;
+    for chunk in values.chunks(255) {
+        match (explicit, words) {
+            (true, false) => bytecode.extend([NPUSHB, chunk.len() as u8]),
+            (true, true) => bytecode.extend([NPUSHW, chunk.len() as u8]),
+            (false, false) => bytecode.push(PUSHB + chunk.len() as u8 - 1),
+            (false, true) => bytecode.push(PUSHW + chunk.len() as u8 - 1),
+        }
+        for &value in chunk {
+            if words {
+                let word = i16::try_from(value)
+                    .map_err(|_| error(format!("Value {} too large to push", value)))?;
+                bytecode.extend(word.to_be_bytes());
+            } else {
+                let byte = u8::try_from(value)
+                    .map_err(|_| error(format!("Value {} too large to push", value)))?;
+                bytecode.push(byte);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn assemble(assembly: &str) -> Result<Vec<u8>, DeserializationError> {
+    let tokens = tokenize(assembly)?;
+    let mut bytecode = vec![];
+    let mut ix = 0;
+    while ix < tokens.len() {
+        let token = &tokens[ix];
+        ix += 1;
+        let (mnemonic, args) = token
+            .strip_suffix(']')
+            .and_then(|t| t.split_once('['))
+            .ok_or_else(|| error(format!("Unexpected '{}' in assembly", token)))?;
+        let mnemonic = mnemonic.trim();
+        if matches!(mnemonic, "NPUSHB" | "NPUSHW" | "PUSHB" | "PUSHW" | "PUSH") {
+            let mut values = vec![];
+            while let Some(value) = tokens.get(ix).and_then(|t| t.parse::<i32>().ok()) {
+                values.push(value);
+                ix += 1;
+            }
+            push(&mut bytecode, mnemonic, &values)?;
+            continue;
+        }
+        let &(base, _, arg_bits, _) = INSTRUCTIONS
+            .iter()
+            .find(|(_, m, _, _)| *m == mnemonic)
+            .ok_or_else(|| error(format!("Unknown instruction '{}'", mnemonic)))?;
+        let args = args.trim();
+        let offset = if args.is_empty() {
+            0
+        } else {
+            u8::from_str_radix(args, 2)
+                .ok()
+                .filter(|&v| (v as u32) < (1 << arg_bits))
+                .ok_or_else(|| error(format!("Bad arguments '{}' for {}", args, mnemonic)))?
+        };
+        bytecode.push(base + offset);
+    }
+    Ok(bytecode)
+}
+
+/// Reads a TrueType program from an element containing either an
+/// `<assembly>` or a `<bytecode>` element.
+pub(crate) fn load_program(node: Node) -> Result<Vec<u8>, DeserializationError> {
+    if let Some(bytecode) = child(node, "bytecode") {
+        return hexdata(bytecode);
+    }
+    match child(node, "assembly") {
+        Some(assembly) => assemble(assembly.text().unwrap_or_default()),
+        None => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assembly_roundtrip() {
+        let bytecode = vec![
+            0xb1, 0x01, 0x02, // PUSHB[001] 1 2
+            0x2c, // FDEF
+            0xb8, 0xff, 0xfe, // PUSHW[000] -2
+            0xe5, // MIRP[00101]
+            0x2d, // ENDF
+            0x40, 0x02, 0x07, 0x08, // NPUSHB 2 7 8
+            0x30, // IUP[0]
+        ];
+        let mut w = XmlWriter::default();
+        dump_program(&mut w, &bytecode);
+        let xml = w.finish();
+        assert_eq!(
+            xml,
+            "<assembly>
+  PUSHB[ ]\t/* 2 values pushed */
+  1 2
+  FDEF[ ]\t/* FunctionDefinition */
+    PUSHW[ ]\t/* 1 value pushed */
+    -2
+    MIRP[00101]\t/* MoveIndirectRelPt */
+  ENDF[ ]\t/* EndFunctionDefinition */
+  NPUSHB[ ]\t/* 2 values pushed */
+  7 8
+  IUP[0]\t/* InterpolateUntPts */
+</assembly>
+"
+        );
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let node = doc.root();
+        assert_eq!(load_program(node).unwrap(), bytecode);
+    }
+}
@@ -0,0 +1,2168 @@
+//! TTX conversion for the OpenType layout tables (`GDEF`, `GSUB` and `GPOS`).
+//!
+//! `GSUB` and `GPOS` are dumped from their low-level (binary) structure, using
+//! the element names from fontTools' `otData`, so that the XML can be compared
+//! against (and exchanged with) fontTools' TTX output. Loading them rebuilds
+//! the low-level structure, which the caller then converts to the high-level
+//! tables.
+use super::variations::{dump_var_store, load_var_store};
+use super::xml::{
+    attr, child, child_value, elements, elements_named, error, fl2str, num, opt_child_value,
+    opt_num, parse, required_child, value, Node, XmlWriter,
+};
+use super::GlyphOrder;
+use crate::tables::GDEF::{CaretValue, GlyphClass, GDEF};
+use otspec::layout::anchor::Anchor;
+use otspec::layout::classdef::ClassDef;
+use otspec::layout::common::{
+    ConditionFormat1, ConditionSet, FeatureList, FeatureRecord, FeatureTable,
+    FeatureTableSubstitution, FeatureTableSubstitutionRecord, FeatureVariationRecord,
+    FeatureVariations, LangSys, LangSysRecord, LookupFlags, MarkArray, MarkRecord, Script,
+    ScriptList, ScriptRecord,
+};
+use otspec::layout::contextual::{
+    ChainedSequenceContextFormat1, ChainedSequenceContextFormat2, ChainedSequenceContextFormat3,
+    ChainedSequenceRule, ChainedSequenceRuleSet, SequenceContextFormat1, SequenceContextFormat2,
+    SequenceContextFormat3, SequenceLookupRecord, SequenceRule, SequenceRuleSet,
+};
+use otspec::layout::coverage::Coverage;
+use otspec::layout::device::Device;
+use otspec::layout::gpos1::{SinglePosFormat1, SinglePosFormat2};
+use otspec::layout::gpos2::{
+    Class1Record, Class2Record, PairPosFormat1, PairPosFormat2, PairSet, PairValueRecord,
+};
+use otspec::layout::gpos3::{CursivePosFormat1, EntryExitRecord};
+use otspec::layout::gpos4::{BaseArray, BaseRecord, MarkBasePosFormat1};
+use otspec::layout::gpos5::{ComponentRecord, LigatureArray, LigatureAttach, MarkLigPosFormat1};
+use otspec::layout::gpos6::{Mark2Array, Mark2Record, MarkMarkPosFormat1};
+use otspec::layout::gsub1::SingleSubstFormat2;
+use otspec::layout::gsub2::{MultipleSubstFormat1, Sequence};
+use otspec::layout::gsub3::{AlternateSet, AlternateSubstFormat1};
+use otspec::layout::gsub4::{Ligature, LigatureSet, LigatureSubstFormat1};
+use otspec::layout::gsub8::ReverseChainSingleSubstFormat1;
+use otspec::layout::valuerecord::{ValueRecord, ValueRecordFlags};
+use otspec::tables::GPOS::{
+    ExtensionPosFormat1, GPOSLookup, GPOSLookupList, GPOSSubtable, GPOS10, GPOS11,
+};
+use otspec::tables::GSUB::{
+    ExtensionSubstFormat1, GSUBLookup, GSUBLookupList, GSUBSubtable, GSUB10, GSUB11,
+};
+use otspec::types::*;
+use otspec::DeserializationError;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The element names which differ between the contextual lookups of `GSUB`
+/// and those of `GPOS`.
+struct ContextNames {
+    context: &'static str,
+    chain_context: &'static str,
+    rule: &'static str,
+    lookup_record: &'static str,
+}
+
+const SUBST_NAMES: ContextNames = ContextNames {
+    context: "ContextSubst",
+    chain_context: "ChainContextSubst",
+    rule: "Sub",
+    lookup_record: "SubstLookupRecord",
+};
+
+const POS_NAMES: ContextNames = ContextNames {
+    context: "ContextPos",
+    chain_context: "ChainContextPos",
+    rule: "Pos",
+    lookup_record: "PosLookupRecord",
+};
+
+const VALUE_FIELDS: [(&str, ValueRecordFlags); 4] = [
+    ("XPlacement", ValueRecordFlags::X_PLACEMENT),
+    ("YPlacement", ValueRecordFlags::Y_PLACEMENT),
+    ("XAdvance", ValueRecordFlags::X_ADVANCE),
+    ("YAdvance", ValueRecordFlags::Y_ADVANCE),
+];
+
+const VALUE_DEVICES: [(&str, ValueRecordFlags); 4] = [
+    ("XPlaDevice", ValueRecordFlags::X_PLACEMENT_DEVICE),
+    ("YPlaDevice", ValueRecordFlags::Y_PLACEMENT_DEVICE),
+    ("XAdvDevice", ValueRecordFlags::X_ADVANCE_DEVICE),
+    ("YAdvDevice", ValueRecordFlags::Y_ADVANCE_DEVICE),
+];
+
+/// Opens an element which may be an array member, and may have a format.
+fn begin_element(w: &mut XmlWriter, tag: &str, index: Option<usize>, format: Option<u16>) {
+    match (index, format) {
+        (Some(index), Some(format)) => w.begin(tag, &[("index", &index), ("Format", &format)]),
+        (Some(index), None) => w.begin(tag, &[("index", &index)]),
+        (None, Some(format)) => w.begin(tag, &[("Format", &format)]),
+        (None, None) => w.begin(tag, &[]),
+    }
+}
+
+/// Dumps the subtable an offset points to. Null offsets are omitted, unless
+/// they are array members, in which case they are marked as empty.
+fn dump_offset<T>(
+    w: &mut XmlWriter,
+    tag: &str,
+    index: Option<usize>,
+    offset: &Offset16<T>,
+    dump: impl FnOnce(&mut XmlWriter, &T),
+) {
+    match (&offset.link, index) {
+        (Some(link), _) => dump(w, link),
+        (None, Some(index)) => w.simple(tag, &[("index", &index), ("empty", &1)]),
+        (None, None) => {}
+    }
+}
+
+fn format_list<T: ToString>(values: &[T]) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn parse_list<T: super::xml::XmlNumber>(s: &str) -> Result<Vec<T>, DeserializationError> {
+    s.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(parse)
+        .collect()
+}
+
+fn dump_coverage(
+    w: &mut XmlWriter,
+    tag: &str,
+    index: Option<usize>,
+    offset: &Offset16<Coverage>,
+    glyph_order: &GlyphOrder,
+) {
+    dump_offset(w, tag, index, offset, |w, coverage| {
+        begin_element(w, tag, index, None);
+        for &gid in &coverage.glyphs {
+            w.value("Glyph", glyph_order.name(gid));
+        }
+        w.end(tag);
+    });
+}
+
+fn dump_coverages(
+    w: &mut XmlWriter,
+    tag: &str,
+    offsets: &[Offset16<Coverage>],
+    glyph_order: &GlyphOrder,
+) {
+    for (ix, offset) in offsets.iter().enumerate() {
+        dump_coverage(w, tag, Some(ix), offset, glyph_order);
+    }
+}
+
+fn dump_class_def(w: &mut XmlWriter, tag: &str, class_def: &ClassDef, glyph_order: &GlyphOrder) {
+    w.begin(tag, &[]);
+    for (&gid, class) in &class_def.classes {
+        w.simple(
+            "ClassDef",
+            &[("glyph", &glyph_order.name(gid)), ("class", class)],
+        );
+    }
+    w.end(tag);
+}
+
+fn dump_device(w: &mut XmlWriter, tag: &str, device: &Device) {
+    w.begin(tag, &[]);
+    w.value("StartSize", device.startSize);
+    w.value("EndSize", device.endSize);
+    if let Some(format) = device.deltaFormat {
+        w.value("DeltaFormat", format);
+    }
+    if device.as_variation_index().is_none() {
+        w.value("DeltaValue", format_list(&device.deltaValues));
+    }
+    w.end(tag);
+}
+
+fn dump_anchor(w: &mut XmlWriter, tag: &str, index: Option<usize>, anchor: &Anchor) {
+    let format = if anchor.xDevice.is_some() || anchor.yDevice.is_some() {
+        3
+    } else if anchor.anchorPoint.is_some() {
+        2
+    } else {
+        1
+    };
+    begin_element(w, tag, index, Some(format));
+    w.value("XCoordinate", anchor.xCoordinate);
+    w.value("YCoordinate", anchor.yCoordinate);
+    if let Some(point) = anchor.anchorPoint {
+        w.value("AnchorPoint", point);
+    }
+    if let Some(device) = &anchor.xDevice {
+        dump_device(w, "XDeviceTable", device);
+    }
+    if let Some(device) = &anchor.yDevice {
+        dump_device(w, "YDeviceTable", device);
+    }
+    w.end(tag);
+}
+
+fn dump_anchors(w: &mut XmlWriter, tag: &str, anchors: &[Offset16<Anchor>]) {
+    for (ix, offset) in anchors.iter().enumerate() {
+        dump_offset(w, tag, Some(ix), offset, |w, anchor| {
+            dump_anchor(w, tag, Some(ix), anchor)
+        });
+    }
+}
+
+fn dump_value_record(w: &mut XmlWriter, tag: &str, index: Option<usize>, record: &ValueRecord) {
+    if !record.has_any() {
+        return;
+    }
+    let mut attrs: Vec<(&str, &dyn std::fmt::Display)> = vec![];
+    if let Some(index) = &index {
+        attrs.push(("index", index));
+    }
+    let values = [
+        &record.xPlacement,
+        &record.yPlacement,
+        &record.xAdvance,
+        &record.yAdvance,
+    ];
+    for ((name, _), value) in VALUE_FIELDS.iter().zip(values) {
+        if let Some(value) = value {
+            attrs.push((name, value));
+        }
+    }
+    let devices: Vec<(&str, &Device)> = VALUE_DEVICES
+        .iter()
+        .zip([
+            &record.xPlaDevice,
+            &record.yPlaDevice,
+            &record.xAdvDevice,
+            &record.yAdvDevice,
+        ])
+        .filter_map(|((name, _), device)| {
+            device
+                .as_ref()
+                .and_then(|d| d.link.as_ref())
+                .map(|d| (*name, d))
+        })
+        .collect();
+    if devices.is_empty() {
+        w.simple(tag, &attrs);
+    } else {
+        w.begin(tag, &attrs);
+        for (name, device) in devices {
+            dump_device(w, name, device);
+        }
+        w.end(tag);
+    }
+}
+
+fn dump_mark_array(w: &mut XmlWriter, tag: &str, offset: &Offset16<MarkArray>) {
+    dump_offset(w, tag, None, offset, |w, array| {
+        w.begin(tag, &[]);
+        w.comment(&format!("MarkCount={}", array.markRecords.len()));
+        for (ix, record) in array.markRecords.iter().enumerate() {
+            w.begin("MarkRecord", &[("index", &ix)]);
+            w.value("Class", record.markClass);
+            dump_offset(w, "MarkAnchor", None, &record.markAnchor, |w, anchor| {
+                dump_anchor(w, "MarkAnchor", None, anchor)
+            });
+            w.end("MarkRecord");
+        }
+        w.end(tag);
+    });
+}
+
+fn dump_lang_sys(w: &mut XmlWriter, tag: &str, lang_sys: &LangSys) {
+    w.begin(tag, &[]);
+    w.value("ReqFeatureIndex", lang_sys.requiredFeatureIndex);
+    w.comment(&format!("FeatureCount={}", lang_sys.featureIndices.len()));
+    for (ix, feature) in lang_sys.featureIndices.iter().enumerate() {
+        w.indexed_value("FeatureIndex", ix, feature);
+    }
+    w.end(tag);
+}
+
+fn dump_script_list(w: &mut XmlWriter, list: &ScriptList) {
+    w.begin("ScriptList", &[]);
+    w.comment(&format!("ScriptCount={}", list.scriptRecords.len()));
+    for (ix, record) in list.scriptRecords.iter().enumerate() {
+        w.begin("ScriptRecord", &[("index", &ix)]);
+        w.value("ScriptTag", record.scriptTag);
+        dump_offset(w, "Script", None, &record.script, |w, script| {
+            w.begin("Script", &[]);
+            dump_offset(
+                w,
+                "DefaultLangSys",
+                None,
+                &script.defaultLangSys,
+                |w, ls| dump_lang_sys(w, "DefaultLangSys", ls),
+            );
+            w.comment(&format!("LangSysCount={}", script.langSysRecords.len()));
+            for (ix, record) in script.langSysRecords.iter().enumerate() {
+                w.begin("LangSysRecord", &[("index", &ix)]);
+                w.value("LangSysTag", record.langSysTag);
+                dump_offset(w, "LangSys", None, &record.langSys, |w, ls| {
+                    dump_lang_sys(w, "LangSys", ls)
+                });
+                w.end("LangSysRecord");
+            }
+            w.end("Script");
+        });
+        w.end("ScriptRecord");
+    }
+    w.end("ScriptList");
+}
+
+fn dump_feature_list(w: &mut XmlWriter, list: &FeatureList) {
+    w.begin("FeatureList", &[]);
+    w.comment(&format!("FeatureCount={}", list.featureRecords.len()));
+    for (ix, record) in list.featureRecords.iter().enumerate() {
+        w.begin("FeatureRecord", &[("index", &ix)]);
+        w.value("FeatureTag", record.featureTag);
+        dump_offset(w, "Feature", None, &record.feature, dump_feature);
+        w.end("FeatureRecord");
+    }
+    w.end("FeatureList");
+}
+
+fn dump_feature(w: &mut XmlWriter, feature: &FeatureTable) {
+    w.begin("Feature", &[]);
+    w.comment(&format!("LookupCount={}", feature.lookupListIndices.len()));
+    for (ix, lookup) in feature.lookupListIndices.iter().enumerate() {
+        w.indexed_value("LookupListIndex", ix, lookup);
+    }
+    w.end("Feature");
+}
+
+fn dump_feature_variations(w: &mut XmlWriter, variations: &FeatureVariations) {
+    w.begin("FeatureVariations", &[]);
+    w.value("Version", "0x00010000");
+    w.comment(&format!(
+        "FeatureVariationCount={}",
+        variations.featureVariationRecords.len()
+    ));
+    for (ix, record) in variations.featureVariationRecords.iter().enumerate() {
+        w.begin("FeatureVariationRecord", &[("index", &ix)]);
+        if let Some(condition_set) = &record.conditionSet.link {
+            w.begin("ConditionSet", &[]);
+            w.comment(&format!(
+                "ConditionCount={}",
+                condition_set.conditions.v.len()
+            ));
+            for (ix, condition) in condition_set.conditions.v.iter().enumerate() {
+                if let Some(condition) = &condition.link {
+                    begin_element(w, "ConditionTable", Some(ix), Some(condition.format));
+                    w.value("AxisIndex", condition.axisIndex);
+                    w.value(
+                        "FilterRangeMinValue",
+                        fl2str(condition.filterRangeMinValue, 14),
+                    );
+                    w.value(
+                        "FilterRangeMaxValue",
+                        fl2str(condition.filterRangeMaxValue, 14),
+                    );
+                    w.end("ConditionTable");
+                }
+            }
+            w.end("ConditionSet");
+        }
+        if let Some(substitution) = &record.featureTableSubstitution.link {
+            w.begin("FeatureTableSubstitution", &[]);
+            w.value("Version", "0x00010000");
+            w.comment(&format!(
+                "SubstitutionCount={}",
+                substitution.substitutions.len()
+            ));
+            for (ix, record) in substitution.substitutions.iter().enumerate() {
+                w.begin("SubstitutionRecord", &[("index", &ix)]);
+                w.value("FeatureIndex", record.featureIndex);
+                if let Some(feature) = &record.alternateFeature.link {
+                    dump_feature(w, feature);
+                }
+                w.end("SubstitutionRecord");
+            }
+            w.end("FeatureTableSubstitution");
+        }
+        w.end("FeatureVariationRecord");
+    }
+    w.end("FeatureVariations");
+}
+
+fn begin_lookup(
+    w: &mut XmlWriter,
+    index: usize,
+    lookup_type: u16,
+    flags: LookupFlags,
+    subtable_count: usize,
+) {
+    w.begin("Lookup", &[("index", &index)]);
+    w.value("LookupType", lookup_type);
+    w.simple_inline("LookupFlag", &[("value", &flags.bits())]);
+    let mut names = vec![];
+    for (flag, name) in [
+        (LookupFlags::RIGHT_TO_LEFT, "rightToLeft"),
+        (LookupFlags::IGNORE_BASE_GLYPHS, "ignoreBaseGlyphs"),
+        (LookupFlags::IGNORE_LIGATURES, "ignoreLigatures"),
+        (LookupFlags::IGNORE_MARKS, "ignoreMarks"),
+        (LookupFlags::USE_MARK_FILTERING_SET, "useMarkFilteringSet"),
+    ] {
+        if flags.contains(flag) {
+            names.push(name.to_string());
+        }
+    }
+    let mark_attachment_type = flags.bits() >> 8;
+    if mark_attachment_type != 0 {
+        names.push(format!("markAttachmentType[{}]", mark_attachment_type));
+    }
+    if !names.is_empty() {
+        w.comment_inline(&names.join(" "));
+    }
+    w.newline();
+    w.comment(&format!("SubTableCount={}", subtable_count));
+}
+
+fn end_lookup(w: &mut XmlWriter, mark_filtering_set: Option<u16>) {
+    if let Some(set) = mark_filtering_set {
+        w.value("MarkFilteringSet", set);
+    }
+    w.end("Lookup");
+}
+
+/// Writes a sequence of glyphs (if a glyph order is given) or classes.
+fn dump_sequence(w: &mut XmlWriter, tag: &str, items: &[u16], glyph_order: Option<&GlyphOrder>) {
+    for (ix, &item) in items.iter().enumerate() {
+        match glyph_order {
+            Some(glyph_order) => w.indexed_value(tag, ix, glyph_order.name(item)),
+            None => w.indexed_value(tag, ix, item),
+        }
+    }
+}
+
+fn dump_lookup_records(w: &mut XmlWriter, tag: &str, records: &[SequenceLookupRecord]) {
+    for (ix, record) in records.iter().enumerate() {
+        w.begin(tag, &[("index", &ix)]);
+        w.value("SequenceIndex", record.sequenceIndex);
+        w.value("LookupListIndex", record.lookupIndex);
+        w.end(tag);
+    }
+}
+
+/// Dumps the rule sets of a format 1 (glyph-based) or format 2 (class-based)
+/// sequence context subtable.
+fn dump_rule_sets(
+    w: &mut XmlWriter,
+    set_tag: &str,
+    rule_tag: &str,
+    lookup_record: &str,
+    rule_sets: &VecOffset16<SequenceRuleSet>,
+    glyph_order: Option<&GlyphOrder>,
+) {
+    let input_tag = if glyph_order.is_some() {
+        "Input"
+    } else {
+        "Class"
+    };
+    w.comment(&format!("{}Count={}", set_tag, rule_sets.v.len()));
+    for (ix, offset) in rule_sets.v.iter().enumerate() {
+        dump_offset(w, set_tag, Some(ix), offset, |w, rule_set| {
+            w.begin(set_tag, &[("index", &ix)]);
+            w.comment(&format!(
+                "{}Count={}",
+                rule_tag,
+                rule_set.sequenceRules.v.len()
+            ));
+            for (ix, offset) in rule_set.sequenceRules.v.iter().enumerate() {
+                dump_offset(w, rule_tag, Some(ix), offset, |w, rule| {
+                    w.begin(rule_tag, &[("index", &ix)]);
+                    w.comment(&format!("GlyphCount={}", rule.glyphCount));
+                    w.comment(&format!("SubstCount={}", rule.seqLookupRecords.len()));
+                    dump_sequence(w, input_tag, &rule.inputSequence, glyph_order);
+                    dump_lookup_records(w, lookup_record, &rule.seqLookupRecords);
+                    w.end(rule_tag);
+                });
+            }
+            w.end(set_tag);
+        });
+    }
+}
+
+fn dump_chained_rule_sets(
+    w: &mut XmlWriter,
+    set_tag: &str,
+    rule_tag: &str,
+    lookup_record: &str,
+    rule_sets: &VecOffset16<ChainedSequenceRuleSet>,
+    glyph_order: Option<&GlyphOrder>,
+) {
+    w.comment(&format!("{}Count={}", set_tag, rule_sets.v.len()));
+    for (ix, offset) in rule_sets.v.iter().enumerate() {
+        dump_offset(w, set_tag, Some(ix), offset, |w, rule_set| {
+            w.begin(set_tag, &[("index", &ix)]);
+            let rules = &rule_set.chainedSequenceRules.v;
+            w.comment(&format!("{}Count={}", rule_tag, rules.len()));
+            for (ix, offset) in rules.iter().enumerate() {
+                dump_offset(w, rule_tag, Some(ix), offset, |w, rule| {
+                    w.begin(rule_tag, &[("index", &ix)]);
+                    w.comment(&format!(
+                        "BacktrackGlyphCount={}",
+                        rule.backtrackSequence.len()
+                    ));
+                    dump_sequence(w, "Backtrack", &rule.backtrackSequence, glyph_order);
+                    w.comment(&format!("InputGlyphCount={}", rule.inputGlyphCount));
+                    dump_sequence(w, "Input", &rule.inputSequence, glyph_order);
+                    w.comment(&format!(
+                        "LookAheadGlyphCount={}",
+                        rule.lookaheadSequence.len()
+                    ));
+                    dump_sequence(w, "LookAhead", &rule.lookaheadSequence, glyph_order);
+                    w.comment(&format!("SubstCount={}", rule.seqLookupRecords.len()));
+                    dump_lookup_records(w, lookup_record, &rule.seqLookupRecords);
+                    w.end(rule_tag);
+                });
+            }
+            w.end(set_tag);
+        });
+    }
+}
+
+fn dump_context1(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &SequenceContextFormat1,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.context, index, Some(1));
+    dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+    dump_rule_sets(
+        w,
+        &format!("{}RuleSet", names.rule),
+        &format!("{}Rule", names.rule),
+        names.lookup_record,
+        &st.seqRuleSets,
+        Some(glyph_order),
+    );
+    w.end(names.context);
+}
+
+fn dump_context2(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &SequenceContextFormat2,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.context, index, Some(2));
+    dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+    dump_offset(w, "ClassDef", None, &st.classDef, |w, class_def| {
+        dump_class_def(w, "ClassDef", class_def, glyph_order)
+    });
+    dump_rule_sets(
+        w,
+        &format!("{}ClassSet", names.rule),
+        &format!("{}ClassRule", names.rule),
+        names.lookup_record,
+        &st.classSeqRuleSets,
+        None,
+    );
+    w.end(names.context);
+}
+
+fn dump_context3(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &SequenceContextFormat3,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.context, index, Some(3));
+    w.comment(&format!("GlyphCount={}", st.coverages.len()));
+    w.comment(&format!("SubstCount={}", st.seqLookupRecords.len()));
+    dump_coverages(w, "Coverage", &st.coverages, glyph_order);
+    dump_lookup_records(w, names.lookup_record, &st.seqLookupRecords);
+    w.end(names.context);
+}
+
+fn dump_chain_context1(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &ChainedSequenceContextFormat1,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.chain_context, index, Some(1));
+    dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+    dump_chained_rule_sets(
+        w,
+        &format!("Chain{}RuleSet", names.rule),
+        &format!("Chain{}Rule", names.rule),
+        names.lookup_record,
+        &st.chainedSeqRuleSets,
+        Some(glyph_order),
+    );
+    w.end(names.chain_context);
+}
+
+fn dump_chain_context2(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &ChainedSequenceContextFormat2,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.chain_context, index, Some(2));
+    dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+    for (tag, offset) in [
+        ("BacktrackClassDef", &st.backtrackClassDef),
+        ("InputClassDef", &st.inputClassDef),
+        ("LookAheadClassDef", &st.lookaheadClassDef),
+    ] {
+        dump_offset(w, tag, None, offset, |w, class_def| {
+            dump_class_def(w, tag, class_def, glyph_order)
+        });
+    }
+    dump_chained_rule_sets(
+        w,
+        &format!("Chain{}ClassSet", names.rule),
+        &format!("Chain{}ClassRule", names.rule),
+        names.lookup_record,
+        &st.chainedClassSeqRuleSets,
+        None,
+    );
+    w.end(names.chain_context);
+}
+
+fn dump_chain_context3(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    st: &ChainedSequenceContextFormat3,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) {
+    begin_element(w, names.chain_context, index, Some(3));
+    w.comment(&format!(
+        "BacktrackGlyphCount={}",
+        st.backtrackCoverages.v.len()
+    ));
+    dump_coverages(
+        w,
+        "BacktrackCoverage",
+        &st.backtrackCoverages.v,
+        glyph_order,
+    );
+    w.comment(&format!("InputGlyphCount={}", st.inputCoverages.v.len()));
+    dump_coverages(w, "InputCoverage", &st.inputCoverages.v, glyph_order);
+    w.comment(&format!(
+        "LookAheadGlyphCount={}",
+        st.lookaheadCoverages.v.len()
+    ));
+    dump_coverages(
+        w,
+        "LookAheadCoverage",
+        &st.lookaheadCoverages.v,
+        glyph_order,
+    );
+    w.comment(&format!("SubstCount={}", st.seqLookupRecords.len()));
+    dump_lookup_records(w, names.lookup_record, &st.seqLookupRecords);
+    w.end(names.chain_context);
+}
+
+fn coverage_glyphs(offset: &Offset16<Coverage>) -> &[GlyphID] {
+    offset.link.as_ref().map_or(&[], |c| &c.glyphs)
+}
+
+fn dump_gsub_subtable(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    subtable: &GSUBSubtable,
+    glyph_order: &GlyphOrder,
+) {
+    let name = |gid: GlyphID| glyph_order.name(gid);
+    match subtable {
+        GSUBSubtable::GSUB1_1(_) | GSUBSubtable::GSUB1_2(_) => {
+            let mapping: Vec<(GlyphID, GlyphID)> = match subtable {
+                GSUBSubtable::GSUB1_1(st) => coverage_glyphs(&st.coverage)
+                    .iter()
+                    .map(|&gid| (gid, (gid as i32 + st.deltaGlyphID as i32) as GlyphID))
+                    .collect(),
+                GSUBSubtable::GSUB1_2(st) => coverage_glyphs(&st.coverage)
+                    .iter()
+                    .copied()
+                    .zip(st.substituteGlyphIDs.iter().copied())
+                    .collect(),
+                _ => unreachable!(),
+            };
+            begin_element(w, "SingleSubst", index, None);
+            for (input, output) in mapping {
+                w.simple(
+                    "Substitution",
+                    &[("in", &name(input)), ("out", &name(output))],
+                );
+            }
+            w.end("SingleSubst");
+        }
+        GSUBSubtable::GSUB2_1(st) => {
+            begin_element(w, "MultipleSubst", index, None);
+            for (&input, sequence) in coverage_glyphs(&st.coverage).iter().zip(&st.sequences.v) {
+                let output = sequence.link.as_ref().map_or_else(String::new, |s| {
+                    glyph_order.join(s.substituteGlyphIDs.iter().copied())
+                });
+                w.simple("Substitution", &[("in", &name(input)), ("out", &output)]);
+            }
+            w.end("MultipleSubst");
+        }
+        GSUBSubtable::GSUB3_1(st) => {
+            begin_element(w, "AlternateSubst", index, None);
+            for (&input, set) in coverage_glyphs(&st.coverage)
+                .iter()
+                .zip(&st.alternateSets.v)
+            {
+                w.begin("AlternateSet", &[("glyph", &name(input))]);
+                for &alternate in set.link.iter().flat_map(|s| &s.alternateGlyphIDs) {
+                    w.simple("Alternate", &[("glyph", &name(alternate))]);
+                }
+                w.end("AlternateSet");
+            }
+            w.end("AlternateSubst");
+        }
+        GSUBSubtable::GSUB4_1(st) => {
+            begin_element(w, "LigatureSubst", index, None);
+            for (&first, set) in coverage_glyphs(&st.coverage).iter().zip(&st.ligatureSet.v) {
+                w.begin("LigatureSet", &[("glyph", &name(first))]);
+                for ligature in set
+                    .link
+                    .iter()
+                    .flat_map(|s| &s.ligatureOffsets.v)
+                    .flat_map(|l| &l.link)
+                {
+                    w.simple(
+                        "Ligature",
+                        &[
+                            (
+                                "components",
+                                &glyph_order.join(ligature.componentGlyphIDs.iter().copied()),
+                            ),
+                            ("glyph", &name(ligature.ligatureGlyph)),
+                        ],
+                    );
+                }
+                w.end("LigatureSet");
+            }
+            w.end("LigatureSubst");
+        }
+        GSUBSubtable::GSUB5_1(st) => dump_context1(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB5_2(st) => dump_context2(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB5_3(st) => dump_context3(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB6_1(st) => dump_chain_context1(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB6_2(st) => dump_chain_context2(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB6_3(st) => dump_chain_context3(w, index, st, &SUBST_NAMES, glyph_order),
+        GSUBSubtable::GSUB7_1(st) => {
+            begin_element(w, "ExtensionSubst", index, Some(1));
+            w.value("ExtensionLookupType", st.extensionLookupType);
+            if let Some(inner) = &st.extension.link {
+                dump_gsub_subtable(w, None, inner, glyph_order);
+            }
+            w.end("ExtensionSubst");
+        }
+        GSUBSubtable::GSUB8_1(st) => {
+            begin_element(w, "ReverseChainSingleSubst", index, Some(1));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.comment(&format!(
+                "BacktrackGlyphCount={}",
+                st.backtrackCoverages.v.len()
+            ));
+            dump_coverages(
+                w,
+                "BacktrackCoverage",
+                &st.backtrackCoverages.v,
+                glyph_order,
+            );
+            w.comment(&format!(
+                "LookAheadGlyphCount={}",
+                st.lookaheadCoverages.v.len()
+            ));
+            dump_coverages(
+                w,
+                "LookAheadCoverage",
+                &st.lookaheadCoverages.v,
+                glyph_order,
+            );
+            w.comment(&format!("GlyphCount={}", st.substituteGlyphIDs.len()));
+            dump_sequence(w, "Substitute", &st.substituteGlyphIDs, Some(glyph_order));
+            w.end("ReverseChainSingleSubst");
+        }
+    }
+}
+
+fn dump_gpos_subtable(
+    w: &mut XmlWriter,
+    index: Option<usize>,
+    subtable: &GPOSSubtable,
+    glyph_order: &GlyphOrder,
+) {
+    match subtable {
+        GPOSSubtable::GPOS1_1(st) => {
+            begin_element(w, "SinglePos", index, Some(1));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.value("ValueFormat", st.valueFormat.bits());
+            dump_value_record(w, "Value", None, &st.valueRecord);
+            w.end("SinglePos");
+        }
+        GPOSSubtable::GPOS1_2(st) => {
+            begin_element(w, "SinglePos", index, Some(2));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.value("ValueFormat", st.valueFormat.bits());
+            w.comment(&format!("ValueCount={}", st.valueRecords.len()));
+            for (ix, record) in st.valueRecords.iter().enumerate() {
+                dump_value_record(w, "Value", Some(ix), record);
+            }
+            w.end("SinglePos");
+        }
+        GPOSSubtable::GPOS2_1(st) => {
+            begin_element(w, "PairPos", index, Some(1));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.value("ValueFormat1", st.valueFormat1.bits());
+            w.value("ValueFormat2", st.valueFormat2.bits());
+            w.comment(&format!("PairSetCount={}", st.pairSets.v.len()));
+            for (ix, offset) in st.pairSets.v.iter().enumerate() {
+                dump_offset(w, "PairSet", Some(ix), offset, |w, pair_set| {
+                    w.begin("PairSet", &[("index", &ix)]);
+                    w.comment(&format!(
+                        "PairValueCount={}",
+                        pair_set.pairValueRecords.len()
+                    ));
+                    for (ix, record) in pair_set.pairValueRecords.iter().enumerate() {
+                        w.begin("PairValueRecord", &[("index", &ix)]);
+                        w.value("SecondGlyph", glyph_order.name(record.secondGlyph));
+                        dump_value_record(w, "Value1", None, &record.valueRecord1);
+                        dump_value_record(w, "Value2", None, &record.valueRecord2);
+                        w.end("PairValueRecord");
+                    }
+                    w.end("PairSet");
+                });
+            }
+            w.end("PairPos");
+        }
+        GPOSSubtable::GPOS2_2(st) => {
+            begin_element(w, "PairPos", index, Some(2));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.value("ValueFormat1", st.valueFormat1.bits());
+            w.value("ValueFormat2", st.valueFormat2.bits());
+            for (tag, offset) in [("ClassDef1", &st.classDef1), ("ClassDef2", &st.classDef2)] {
+                dump_offset(w, tag, None, offset, |w, class_def| {
+                    dump_class_def(w, tag, class_def, glyph_order)
+                });
+            }
+            w.comment(&format!("Class1Count={}", st.classCount1));
+            w.comment(&format!("Class2Count={}", st.classCount2));
+            for (ix, class1) in st.class1Records.iter().enumerate() {
+                w.begin("Class1Record", &[("index", &ix)]);
+                for (ix, class2) in class1.class2Records.iter().enumerate() {
+                    w.begin("Class2Record", &[("index", &ix)]);
+                    dump_value_record(w, "Value1", None, &class2.valueRecord1);
+                    dump_value_record(w, "Value2", None, &class2.valueRecord2);
+                    w.end("Class2Record");
+                }
+                w.end("Class1Record");
+            }
+            w.end("PairPos");
+        }
+        GPOSSubtable::GPOS3_1(st) => {
+            begin_element(w, "CursivePos", index, Some(1));
+            dump_coverage(w, "Coverage", None, &st.coverage, glyph_order);
+            w.comment(&format!("EntryExitCount={}", st.entryExitRecord.len()));
+            for (ix, record) in st.entryExitRecord.iter().enumerate() {
+                w.begin("EntryExitRecord", &[("index", &ix)]);
+                for (tag, offset) in [
+                    ("EntryAnchor", &record.entryAnchor),
+                    ("ExitAnchor", &record.exitAnchor),
+                ] {
+                    dump_offset(w, tag, None, offset, |w, anchor| {
+                        dump_anchor(w, tag, None, anchor)
+                    });
+                }
+                w.end("EntryExitRecord");
+            }
+            w.end("CursivePos");
+        }
+        GPOSSubtable::GPOS4_1(st) => {
+            begin_element(w, "MarkBasePos", index, Some(1));
+            dump_coverage(w, "MarkCoverage", None, &st.markCoverage, glyph_order);
+            dump_coverage(w, "BaseCoverage", None, &st.baseCoverage, glyph_order);
+            w.comment(&format!("ClassCount={}", st.markClassCount));
+            dump_mark_array(w, "MarkArray", &st.markArray);
+            dump_offset(w, "BaseArray", None, &st.baseArray, |w, array| {
+                w.begin("BaseArray", &[]);
+                w.comment(&format!("BaseCount={}", array.baseRecords.len()));
+                for (ix, record) in array.baseRecords.iter().enumerate() {
+                    w.begin("BaseRecord", &[("index", &ix)]);
+                    dump_anchors(w, "BaseAnchor", &record.baseAnchors);
+                    w.end("BaseRecord");
+                }
+                w.end("BaseArray");
+            });
+            w.end("MarkBasePos");
+        }
+        GPOSSubtable::GPOS5_1(st) => {
+            begin_element(w, "MarkLigPos", index, Some(1));
+            dump_coverage(w, "MarkCoverage", None, &st.markCoverage, glyph_order);
+            dump_coverage(
+                w,
+                "LigatureCoverage",
+                None,
+                &st.ligatureCoverage,
+                glyph_order,
+            );
+            w.comment(&format!("ClassCount={}", st.markClassCount));
+            dump_mark_array(w, "MarkArray", &st.markArray);
+            dump_offset(w, "LigatureArray", None, &st.ligatureArray, |w, array| {
+                w.begin("LigatureArray", &[]);
+                w.comment(&format!("LigatureCount={}", array.ligatureAttach.v.len()));
+                for (ix, offset) in array.ligatureAttach.v.iter().enumerate() {
+                    dump_offset(w, "LigatureAttach", Some(ix), offset, |w, attach| {
+                        w.begin("LigatureAttach", &[("index", &ix)]);
+                        w.comment(&format!("ComponentCount={}", attach.componentRecords.len()));
+                        for (ix, record) in attach.componentRecords.iter().enumerate() {
+                            w.begin("ComponentRecord", &[("index", &ix)]);
+                            dump_anchors(w, "LigatureAnchor", &record.ligatureAnchors);
+                            w.end("ComponentRecord");
+                        }
+                        w.end("LigatureAttach");
+                    });
+                }
+                w.end("LigatureArray");
+            });
+            w.end("MarkLigPos");
+        }
+        GPOSSubtable::GPOS6_1(st) => {
+            begin_element(w, "MarkMarkPos", index, Some(1));
+            dump_coverage(w, "Mark1Coverage", None, &st.mark1Coverage, glyph_order);
+            dump_coverage(w, "Mark2Coverage", None, &st.mark2Coverage, glyph_order);
+            w.comment(&format!("ClassCount={}", st.markClassCount));
+            dump_mark_array(w, "Mark1Array", &st.mark1Array);
+            dump_offset(w, "Mark2Array", None, &st.mark2Array, |w, array| {
+                w.begin("Mark2Array", &[]);
+                w.comment(&format!("Mark2Count={}", array.mark2Records.len()));
+                for (ix, record) in array.mark2Records.iter().enumerate() {
+                    w.begin("Mark2Record", &[("index", &ix)]);
+                    dump_anchors(w, "Mark2Anchor", &record.mark2Anchors);
+                    w.end("Mark2Record");
+                }
+                w.end("Mark2Array");
+            });
+            w.end("MarkMarkPos");
+        }
+        GPOSSubtable::GPOS7_1(st) => dump_context1(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS7_2(st) => dump_context2(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS7_3(st) => dump_context3(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS8_1(st) => dump_chain_context1(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS8_2(st) => dump_chain_context2(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS8_3(st) => dump_chain_context3(w, index, st, &POS_NAMES, glyph_order),
+        GPOSSubtable::GPOS9_1(st) => {
+            begin_element(w, "ExtensionPos", index, Some(1));
+            w.value("ExtensionLookupType", st.extensionLookupType);
+            if let Some(inner) = &st.extension.link {
+                dump_gpos_subtable(w, None, inner, glyph_order);
+            }
+            w.end("ExtensionPos");
+        }
+    }
+}
+
+/// Dumps a `GSUB` or `GPOS` table from its binary representation.
+pub(crate) fn dump_gsub_gpos(
+    w: &mut XmlWriter,
+    tag: Tag,
+    data: &[u8],
+    glyph_order: &GlyphOrder,
+) -> Result<(), DeserializationError> {
+    // Version 1.1 tables add FeatureVariations, which follow the lookups
+    let has_feature_variations = match data.get(..4) {
+        Some([0x00, 0x01, 0x00, 0x00]) => false,
+        Some([0x00, 0x01, 0x00, 0x01]) => true,
+        _ => {
+            return Err(DeserializationError(format!(
+                "Unsupported {} table version",
+                tag
+            )))
+        }
+    };
+    let mut feature_variations = None;
+    if has_feature_variations {
+        w.value("Version", "0x00010001");
+    } else {
+        w.value("Version", "0x00010000");
+    }
+    if tag == "GSUB" {
+        let table = if has_feature_variations {
+            let table: GSUB11 = otspec::de::from_bytes(data)?;
+            feature_variations = table.featureVariations.link;
+            GSUB10 {
+                majorVersion: table.majorVersion,
+                minorVersion: table.minorVersion,
+                scriptList: table.scriptList,
+                featureList: table.featureList,
+                lookupList: table.lookupList,
+            }
+        } else {
+            otspec::de::from_bytes(data)?
+        };
+        dump_offset(w, "ScriptList", None, &table.scriptList, dump_script_list);
+        dump_offset(
+            w,
+            "FeatureList",
+            None,
+            &table.featureList,
+            dump_feature_list,
+        );
+        dump_offset(w, "LookupList", None, &table.lookupList, |w, list| {
+            w.begin("LookupList", &[]);
+            w.comment(&format!("LookupCount={}", list.lookups.v.len()));
+            for (ix, lookup) in list.lookups.v.iter().flat_map(|l| &l.link).enumerate() {
+                let subtables: Vec<&GSUBSubtable> =
+                    lookup.subtables.v.iter().flat_map(|s| &s.link).collect();
+                begin_lookup(w, ix, lookup.lookupType, lookup.lookupFlag, subtables.len());
+                for (ix, subtable) in subtables.into_iter().enumerate() {
+                    dump_gsub_subtable(w, Some(ix), subtable, glyph_order);
+                }
+                end_lookup(w, lookup.markFilteringSet);
+            }
+            w.end("LookupList");
+        });
+    } else {
+        let table = if has_feature_variations {
+            let table: GPOS11 = otspec::de::from_bytes(data)?;
+            feature_variations = table.featureVariations.link;
+            GPOS10 {
+                majorVersion: table.majorVersion,
+                minorVersion: table.minorVersion,
+                scriptList: table.scriptList,
+                featureList: table.featureList,
+                lookupList: table.lookupList,
+            }
+        } else {
+            otspec::de::from_bytes(data)?
+        };
+        dump_offset(w, "ScriptList", None, &table.scriptList, dump_script_list);
+        dump_offset(
+            w,
+            "FeatureList",
+            None,
+            &table.featureList,
+            dump_feature_list,
+        );
+        dump_offset(w, "LookupList", None, &table.lookupList, |w, list| {
+            w.begin("LookupList", &[]);
+            w.comment(&format!("LookupCount={}", list.lookups.v.len()));
+            for (ix, lookup) in list.lookups.v.iter().flat_map(|l| &l.link).enumerate() {
+                let subtables: Vec<&GPOSSubtable> =
+                    lookup.subtables.v.iter().flat_map(|s| &s.link).collect();
+                begin_lookup(w, ix, lookup.lookupType, lookup.lookupFlag, subtables.len());
+                for (ix, subtable) in subtables.into_iter().enumerate() {
+                    dump_gpos_subtable(w, Some(ix), subtable, glyph_order);
+                }
+                end_lookup(w, lookup.markFilteringSet);
+            }
+            w.end("LookupList");
+        });
+    }
+    if let Some(feature_variations) = &feature_variations {
+        dump_feature_variations(w, feature_variations);
+    }
+    Ok(())
+}
+
+fn dump_gdef_class_def(
+    w: &mut XmlWriter,
+    tag: &str,
+    classes: impl Iterator<Item = (GlyphID, u16)>,
+    glyph_order: &GlyphOrder,
+) {
+    let class_def = ClassDef {
+        classes: classes.collect(),
+    };
+    if !class_def.classes.is_empty() {
+        dump_class_def(w, tag, &class_def, glyph_order);
+    }
+}
+
+fn dump_gdef_coverage<'a>(
+    w: &mut XmlWriter,
+    glyphs: impl Iterator<Item = &'a GlyphID>,
+    glyph_order: &GlyphOrder,
+) {
+    let coverage = Coverage {
+        glyphs: glyphs.copied().collect(),
+    };
+    dump_coverage(w, "Coverage", None, &Offset16::to(coverage), glyph_order);
+}
+
+pub(crate) fn dump_gdef(w: &mut XmlWriter, gdef: &GDEF, glyph_order: &GlyphOrder) {
+    let version = if gdef.item_variation_store.is_some() {
+        "0x00010003"
+    } else if gdef.mark_glyph_sets.is_some() {
+        "0x00010002"
+    } else {
+        "0x00010000"
+    };
+    w.value("Version", version);
+    dump_gdef_class_def(
+        w,
+        "GlyphClassDef",
+        gdef.glyph_class.iter().map(|(&g, &c)| (g, c as u16)),
+        glyph_order,
+    );
+    if !gdef.attachment_point_list.is_empty() {
+        w.begin("AttachList", &[]);
+        dump_gdef_coverage(w, gdef.attachment_point_list.keys(), glyph_order);
+        w.comment(&format!("GlyphCount={}", gdef.attachment_point_list.len()));
+        for (ix, points) in gdef.attachment_point_list.values().enumerate() {
+            w.begin("AttachPoint", &[("index", &ix)]);
+            w.comment(&format!("PointCount={}", points.len()));
+            for (ix, point) in points.iter().enumerate() {
+                w.indexed_value("PointIndex", ix, point);
+            }
+            w.end("AttachPoint");
+        }
+        w.end("AttachList");
+    }
+    if !gdef.ligature_caret_list.is_empty() {
+        w.begin("LigCaretList", &[]);
+        dump_gdef_coverage(w, gdef.ligature_caret_list.keys(), glyph_order);
+        w.comment(&format!("LigGlyphCount={}", gdef.ligature_caret_list.len()));
+        for (ix, carets) in gdef.ligature_caret_list.values().enumerate() {
+            w.begin("LigGlyph", &[("index", &ix)]);
+            w.comment(&format!("CaretCount={}", carets.len()));
+            for (ix, caret) in carets.iter().enumerate() {
+                match caret {
+                    CaretValue::Format1 { coordinate } => {
+                        w.begin("CaretValue", &[("index", &ix), ("Format", &1)]);
+                        w.value("Coordinate", coordinate);
+                    }
+                    CaretValue::Format2 { pointIndex } => {
+                        w.begin("CaretValue", &[("index", &ix), ("Format", &2)]);
+                        w.value("CaretValuePoint", pointIndex);
+                    }
+                    CaretValue::Format3 { coordinate, device } => {
+                        w.begin("CaretValue", &[("index", &ix), ("Format", &3)]);
+                        w.value("Coordinate", coordinate);
+                        if let Some(device) = &device.link {
+                            dump_device(w, "DeviceTable", device);
+                        }
+                    }
+                }
+                w.end("CaretValue");
+            }
+            w.end("LigGlyph");
+        }
+        w.end("LigCaretList");
+    }
+    dump_gdef_class_def(
+        w,
+        "MarkAttachClassDef",
+        gdef.mark_attachment_class.iter().map(|(&g, &c)| (g, c)),
+        glyph_order,
+    );
+    if let Some(sets) = &gdef.mark_glyph_sets {
+        w.begin("MarkGlyphSetsDef", &[]);
+        w.value("MarkSetTableFormat", 1);
+        w.comment(&format!("MarkSetCount={}", sets.len()));
+        for (ix, set) in sets.iter().enumerate() {
+            let coverage = Offset16::to(Coverage {
+                glyphs: set.iter().copied().collect(),
+            });
+            dump_coverage(w, "Coverage", Some(ix), &coverage, glyph_order);
+        }
+        w.end("MarkGlyphSetsDef");
+    }
+    if let Some(store) = &gdef.item_variation_store {
+        dump_var_store(w, store);
+    }
+}
+
+/// Loads an offset to a subtable, which is null if the element is missing or
+/// marked as empty.
+fn load_offset<T>(
+    node: Option<Node>,
+    load: impl FnOnce(Node) -> Result<T, DeserializationError>,
+) -> Result<Offset16<T>, DeserializationError> {
+    match node {
+        Some(node) if node.attribute("empty").is_none() => Ok(Offset16::to(load(node)?)),
+        _ => Ok(Offset16::to_nothing()),
+    }
+}
+
+/// Loads the offsets of an array of subtables.
+fn load_offsets<T>(
+    node: Node,
+    tag: &str,
+    load: impl Fn(Node) -> Result<T, DeserializationError>,
+) -> Result<VecOffset16<T>, DeserializationError> {
+    let offsets = elements_named(node, tag)
+        .map(|n| load_offset(Some(n), &load))
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    Ok(offsets.into())
+}
+
+fn load_coverage_glyphs(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<Vec<GlyphID>, DeserializationError> {
+    elements_named(node, "Glyph")
+        .map(|n| glyph_order.id(attr(n, "value")?))
+        .collect()
+}
+
+fn load_coverage(
+    node: Node,
+    tag: &str,
+    glyph_order: &GlyphOrder,
+) -> Result<Offset16<Coverage>, DeserializationError> {
+    load_offset(child(node, tag), |n| {
+        Ok(Coverage {
+            glyphs: load_coverage_glyphs(n, glyph_order)?,
+        })
+    })
+}
+
+fn load_coverages(
+    node: Node,
+    tag: &str,
+    glyph_order: &GlyphOrder,
+) -> Result<VecOffset16<Coverage>, DeserializationError> {
+    load_offsets(node, tag, |n| {
+        Ok(Coverage {
+            glyphs: load_coverage_glyphs(n, glyph_order)?,
+        })
+    })
+}
+
+/// Makes a coverage table from glyphs in the order they are given.
+fn coverage_of(glyphs: impl IntoIterator<Item = GlyphID>) -> Offset16<Coverage> {
+    Offset16::to(Coverage {
+        glyphs: glyphs.into_iter().collect(),
+    })
+}
+
+fn load_class_def_entries(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<BTreeMap<GlyphID, u16>, DeserializationError> {
+    elements_named(node, "ClassDef")
+        .map(|n| Ok((glyph_order.id(attr(n, "glyph")?)?, num(n, "class")?)))
+        .collect()
+}
+
+fn load_class_def(
+    node: Node,
+    tag: &str,
+    glyph_order: &GlyphOrder,
+) -> Result<Offset16<ClassDef>, DeserializationError> {
+    load_offset(child(node, tag), |n| {
+        Ok(ClassDef {
+            classes: load_class_def_entries(n, glyph_order)?,
+        })
+    })
+}
+
+fn load_device(node: Node) -> Result<Device, DeserializationError> {
+    let delta_values = match child(node, "DeltaValue") {
+        Some(deltas) => parse_list(attr(deltas, "value")?)?,
+        None => vec![],
+    };
+    Ok(Device {
+        startSize: child_value(node, "StartSize")?,
+        endSize: child_value(node, "EndSize")?,
+        deltaFormat: opt_child_value(node, "DeltaFormat")?,
+        deltaValues: delta_values,
+    })
+}
+
+fn load_anchor(node: Node) -> Result<Anchor, DeserializationError> {
+    Ok(Anchor {
+        xCoordinate: child_value(node, "XCoordinate")?,
+        yCoordinate: child_value(node, "YCoordinate")?,
+        anchorPoint: opt_child_value(node, "AnchorPoint")?,
+        xDevice: child(node, "XDeviceTable").map(load_device).transpose()?,
+        yDevice: child(node, "YDeviceTable").map(load_device).transpose()?,
+    })
+}
+
+fn load_anchors(node: Node, tag: &str) -> Result<Vec<Offset16<Anchor>>, DeserializationError> {
+    Ok(load_offsets(node, tag, load_anchor)?.v)
+}
+
+/// Loads a value record with the fields given by the value format. A missing
+/// element is a record of zeros.
+fn load_value_record(
+    node: Option<Node>,
+    format: ValueRecordFlags,
+) -> Result<ValueRecord, DeserializationError> {
+    let mut values = [None; 4];
+    for (value, (name, flag)) in values.iter_mut().zip(VALUE_FIELDS.iter()) {
+        if format.contains(*flag) {
+            let v = match node {
+                Some(node) => opt_num(node, name)?,
+                None => None,
+            };
+            *value = Some(v.unwrap_or(0));
+        }
+    }
+    let mut devices = [None, None, None, None];
+    for (device, (name, flag)) in devices.iter_mut().zip(VALUE_DEVICES.iter()) {
+        if format.contains(*flag) {
+            *device = Some(load_offset(node.and_then(|n| child(n, name)), load_device)?);
+        }
+    }
+    let [x_placement, y_placement, x_advance, y_advance] = values;
+    let [x_pla_device, y_pla_device, x_adv_device, y_adv_device] = devices;
+    Ok(ValueRecord {
+        xPlacement: x_placement,
+        yPlacement: y_placement,
+        xAdvance: x_advance,
+        yAdvance: y_advance,
+        xPlaDevice: x_pla_device,
+        yPlaDevice: y_pla_device,
+        xAdvDevice: x_adv_device,
+        yAdvDevice: y_adv_device,
+    })
+}
+
+fn load_value_format(node: Node, tag: &str) -> Result<ValueRecordFlags, DeserializationError> {
+    Ok(ValueRecordFlags::from_bits_truncate(child_value(
+        node, tag,
+    )?))
+}
+
+fn load_mark_array(node: Node, tag: &str) -> Result<Offset16<MarkArray>, DeserializationError> {
+    load_offset(child(node, tag), |array| {
+        let mark_records = elements_named(array, "MarkRecord")
+            .map(|record| {
+                Ok(MarkRecord {
+                    markClass: child_value(record, "Class")?,
+                    markAnchor: load_offset(child(record, "MarkAnchor"), load_anchor)?,
+                })
+            })
+            .collect::<Result<_, DeserializationError>>()?;
+        Ok(MarkArray {
+            markRecords: mark_records,
+        })
+    })
+}
+
+fn load_lang_sys(node: Node) -> Result<LangSys, DeserializationError> {
+    Ok(LangSys {
+        lookupOrderOffset: 0,
+        requiredFeatureIndex: opt_child_value(node, "ReqFeatureIndex")?.unwrap_or(0xFFFF),
+        featureIndices: elements_named(node, "FeatureIndex")
+            .map(value)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn load_tag(node: Node, name: &str) -> Result<Tag, DeserializationError> {
+    let tag = attr(required_child(node, name)?, "value")?;
+    Tag::from_raw(format!("{:4}", tag)).map_err(|_| error(format!("Bad tag '{}'", tag)))
+}
+
+fn load_script_list(node: Node) -> Result<ScriptList, DeserializationError> {
+    let script_records = elements_named(node, "ScriptRecord")
+        .map(|record| {
+            Ok(ScriptRecord {
+                scriptTag: load_tag(record, "ScriptTag")?,
+                script: load_offset(child(record, "Script"), |script| {
+                    Ok(Script {
+                        defaultLangSys: load_offset(
+                            child(script, "DefaultLangSys"),
+                            load_lang_sys,
+                        )?,
+                        langSysRecords: elements_named(script, "LangSysRecord")
+                            .map(|record| {
+                                Ok(LangSysRecord {
+                                    langSysTag: load_tag(record, "LangSysTag")?,
+                                    langSys: load_offset(child(record, "LangSys"), load_lang_sys)?,
+                                })
+                            })
+                            .collect::<Result<_, DeserializationError>>()?,
+                    })
+                })?,
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    Ok(ScriptList {
+        scriptRecords: script_records,
+    })
+}
+
+fn load_feature_list(node: Node) -> Result<FeatureList, DeserializationError> {
+    let feature_records = elements_named(node, "FeatureRecord")
+        .map(|record| {
+            Ok(FeatureRecord {
+                featureTag: load_tag(record, "FeatureTag")?,
+                feature: load_offset(child(record, "Feature"), load_feature)?,
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    Ok(FeatureList {
+        featureRecords: feature_records,
+    })
+}
+
+fn load_feature(node: Node) -> Result<FeatureTable, DeserializationError> {
+    Ok(FeatureTable {
+        featureParamsOffset: 0,
+        lookupListIndices: elements_named(node, "LookupListIndex")
+            .map(value)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Loads a table's FeatureVariations, which are null if the element is
+/// missing.
+fn load_feature_variations(
+    node: Option<Node>,
+) -> Result<Offset32<FeatureVariations>, DeserializationError> {
+    let node = match node {
+        Some(node) => node,
+        None => return Ok(Offset32::to_nothing()),
+    };
+    let records = elements_named(node, "FeatureVariationRecord")
+        .map(|record| {
+            let conditions = match child(record, "ConditionSet") {
+                Some(set) => elements_named(set, "ConditionTable")
+                    .map(|condition| {
+                        Ok(Offset32::to(ConditionFormat1 {
+                            format: num(condition, "Format")?,
+                            axisIndex: child_value(condition, "AxisIndex")?,
+                            filterRangeMinValue: child_value(condition, "FilterRangeMinValue")?,
+                            filterRangeMaxValue: child_value(condition, "FilterRangeMaxValue")?,
+                        }))
+                    })
+                    .collect::<Result<Vec<_>, DeserializationError>>()?,
+                None => vec![],
+            };
+            let substitutions = match child(record, "FeatureTableSubstitution") {
+                Some(substitution) => elements_named(substitution, "SubstitutionRecord")
+                    .map(|record| {
+                        Ok(FeatureTableSubstitutionRecord {
+                            featureIndex: child_value(record, "FeatureIndex")?,
+                            alternateFeature: Offset32::to(load_feature(required_child(
+                                record, "Feature",
+                            )?)?),
+                        })
+                    })
+                    .collect::<Result<_, DeserializationError>>()?,
+                None => vec![],
+            };
+            Ok(FeatureVariationRecord {
+                conditionSet: Offset32::to(ConditionSet {
+                    conditions: conditions.into(),
+                }),
+                featureTableSubstitution: Offset32::to(FeatureTableSubstitution {
+                    majorVersion: 1,
+                    minorVersion: 0,
+                    substitutions,
+                }),
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    Ok(Offset32::to(FeatureVariations {
+        majorVersion: 1,
+        minorVersion: 0,
+        featureVariationRecords: records,
+    }))
+}
+
+/// Reads a sequence of glyphs (if a glyph order is given) or classes.
+fn load_sequence(
+    node: Node,
+    tag: &str,
+    glyph_order: Option<&GlyphOrder>,
+) -> Result<Vec<u16>, DeserializationError> {
+    elements_named(node, tag)
+        .map(|n| match glyph_order {
+            Some(glyph_order) => glyph_order.id(attr(n, "value")?),
+            None => value(n),
+        })
+        .collect()
+}
+
+fn load_lookup_records(
+    node: Node,
+    tag: &str,
+) -> Result<Vec<SequenceLookupRecord>, DeserializationError> {
+    elements_named(node, tag)
+        .map(|record| {
+            Ok(SequenceLookupRecord {
+                sequenceIndex: child_value(record, "SequenceIndex")?,
+                lookupIndex: child_value(record, "LookupListIndex")?,
+            })
+        })
+        .collect()
+}
+
+fn load_rule_sets(
+    node: Node,
+    set_tag: &str,
+    rule_tag: &str,
+    lookup_record: &str,
+    glyph_order: Option<&GlyphOrder>,
+) -> Result<VecOffset16<SequenceRuleSet>, DeserializationError> {
+    let input_tag = if glyph_order.is_some() {
+        "Input"
+    } else {
+        "Class"
+    };
+    load_offsets(node, set_tag, |set| {
+        Ok(SequenceRuleSet {
+            sequenceRules: load_offsets(set, rule_tag, |rule| {
+                let input_sequence = load_sequence(rule, input_tag, glyph_order)?;
+                let seq_lookup_records = load_lookup_records(rule, lookup_record)?;
+                Ok(SequenceRule {
+                    glyphCount: input_sequence.len() as u16 + 1,
+                    seqLookupCount: seq_lookup_records.len() as u16,
+                    inputSequence: input_sequence,
+                    seqLookupRecords: seq_lookup_records,
+                })
+            })?,
+        })
+    })
+}
+
+fn load_chained_rule_sets(
+    node: Node,
+    set_tag: &str,
+    rule_tag: &str,
+    lookup_record: &str,
+    glyph_order: Option<&GlyphOrder>,
+) -> Result<VecOffset16<ChainedSequenceRuleSet>, DeserializationError> {
+    load_offsets(node, set_tag, |set| {
+        Ok(ChainedSequenceRuleSet {
+            chainedSequenceRules: load_offsets(set, rule_tag, |rule| {
+                let input_sequence = load_sequence(rule, "Input", glyph_order)?;
+                Ok(ChainedSequenceRule {
+                    backtrackSequence: load_sequence(rule, "Backtrack", glyph_order)?,
+                    inputGlyphCount: input_sequence.len() as u16 + 1,
+                    inputSequence: input_sequence,
+                    lookaheadSequence: load_sequence(rule, "LookAhead", glyph_order)?,
+                    seqLookupRecords: load_lookup_records(rule, lookup_record)?,
+                })
+            })?,
+        })
+    })
+}
+
+fn load_context1(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<SequenceContextFormat1, DeserializationError> {
+    Ok(SequenceContextFormat1 {
+        format: 1,
+        coverage: load_coverage(node, "Coverage", glyph_order)?,
+        seqRuleSets: load_rule_sets(
+            node,
+            &format!("{}RuleSet", names.rule),
+            &format!("{}Rule", names.rule),
+            names.lookup_record,
+            Some(glyph_order),
+        )?,
+    })
+}
+
+fn load_context2(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<SequenceContextFormat2, DeserializationError> {
+    Ok(SequenceContextFormat2 {
+        format: 2,
+        coverage: load_coverage(node, "Coverage", glyph_order)?,
+        classDef: load_class_def(node, "ClassDef", glyph_order)?,
+        classSeqRuleSets: load_rule_sets(
+            node,
+            &format!("{}ClassSet", names.rule),
+            &format!("{}ClassRule", names.rule),
+            names.lookup_record,
+            None,
+        )?,
+    })
+}
+
+fn load_context3(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<SequenceContextFormat3, DeserializationError> {
+    let coverages = load_coverages(node, "Coverage", glyph_order)?.v;
+    let seq_lookup_records = load_lookup_records(node, names.lookup_record)?;
+    Ok(SequenceContextFormat3 {
+        format: 3,
+        glyphCount: coverages.len() as u16,
+        seqLookupCount: seq_lookup_records.len() as u16,
+        coverages,
+        seqLookupRecords: seq_lookup_records,
+    })
+}
+
+fn load_chain_context1(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<ChainedSequenceContextFormat1, DeserializationError> {
+    Ok(ChainedSequenceContextFormat1 {
+        format: 1,
+        coverage: load_coverage(node, "Coverage", glyph_order)?,
+        chainedSeqRuleSets: load_chained_rule_sets(
+            node,
+            &format!("Chain{}RuleSet", names.rule),
+            &format!("Chain{}Rule", names.rule),
+            names.lookup_record,
+            Some(glyph_order),
+        )?,
+    })
+}
+
+fn load_chain_context2(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<ChainedSequenceContextFormat2, DeserializationError> {
+    Ok(ChainedSequenceContextFormat2 {
+        format: 2,
+        coverage: load_coverage(node, "Coverage", glyph_order)?,
+        backtrackClassDef: load_class_def(node, "BacktrackClassDef", glyph_order)?,
+        inputClassDef: load_class_def(node, "InputClassDef", glyph_order)?,
+        lookaheadClassDef: load_class_def(node, "LookAheadClassDef", glyph_order)?,
+        chainedClassSeqRuleSets: load_chained_rule_sets(
+            node,
+            &format!("Chain{}ClassSet", names.rule),
+            &format!("Chain{}ClassRule", names.rule),
+            names.lookup_record,
+            None,
+        )?,
+    })
+}
+
+fn load_chain_context3(
+    node: Node,
+    names: &ContextNames,
+    glyph_order: &GlyphOrder,
+) -> Result<ChainedSequenceContextFormat3, DeserializationError> {
+    Ok(ChainedSequenceContextFormat3 {
+        format: 3,
+        backtrackCoverages: load_coverages(node, "BacktrackCoverage", glyph_order)?,
+        inputCoverages: load_coverages(node, "InputCoverage", glyph_order)?,
+        lookaheadCoverages: load_coverages(node, "LookAheadCoverage", glyph_order)?,
+        seqLookupRecords: load_lookup_records(node, names.lookup_record)?,
+    })
+}
+
+/// Finds the subtable wrapped by an extension subtable.
+fn extension_child<'a, 'input>(
+    node: Node<'a, 'input>,
+) -> Result<Node<'a, 'input>, DeserializationError> {
+    elements(node)
+        .find(|n| !n.has_tag_name("ExtensionLookupType"))
+        .ok_or_else(|| error("Extension subtable has no content"))
+}
+
+fn load_gsub_subtable(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<GSUBSubtable, DeserializationError> {
+    let format: u16 = opt_num(node, "Format")?.unwrap_or(1);
+    let id = |n: Node, name: &str| glyph_order.id(attr(n, name)?);
+    Ok(match (node.tag_name().name(), format) {
+        ("SingleSubst", _) => {
+            let mapping = elements_named(node, "Substitution")
+                .map(|n| Ok((id(n, "in")?, id(n, "out")?)))
+                .collect::<Result<BTreeMap<_, _>, DeserializationError>>()?;
+            GSUBSubtable::GSUB1_2(SingleSubstFormat2 {
+                substFormat: 2,
+                coverage: coverage_of(mapping.keys().copied()),
+                substituteGlyphIDs: mapping.values().copied().collect(),
+            })
+        }
+        ("MultipleSubst", _) => {
+            let mapping = elements_named(node, "Substitution")
+                .map(|n| Ok((id(n, "in")?, glyph_order.ids(attr(n, "out")?)?)))
+                .collect::<Result<BTreeMap<_, _>, DeserializationError>>()?;
+            GSUBSubtable::GSUB2_1(MultipleSubstFormat1 {
+                substFormat: 1,
+                coverage: coverage_of(mapping.keys().copied()),
+                sequences: mapping
+                    .into_values()
+                    .map(|glyphs| {
+                        Offset16::to(Sequence {
+                            substituteGlyphIDs: glyphs,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into(),
+            })
+        }
+        ("AlternateSubst", _) => {
+            let mapping = elements_named(node, "AlternateSet")
+                .map(|set| {
+                    let alternates = elements_named(set, "Alternate")
+                        .map(|n| id(n, "glyph"))
+                        .collect::<Result<Vec<_>, DeserializationError>>()?;
+                    Ok((id(set, "glyph")?, alternates))
+                })
+                .collect::<Result<BTreeMap<_, _>, DeserializationError>>()?;
+            GSUBSubtable::GSUB3_1(AlternateSubstFormat1 {
+                substFormat: 1,
+                coverage: coverage_of(mapping.keys().copied()),
+                alternateSets: mapping
+                    .into_values()
+                    .map(|glyphs| {
+                        Offset16::to(AlternateSet {
+                            alternateGlyphIDs: glyphs,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into(),
+            })
+        }
+        ("LigatureSubst", _) => {
+            let mut mapping: BTreeMap<GlyphID, Vec<Offset16<Ligature>>> = BTreeMap::new();
+            for set in elements_named(node, "LigatureSet") {
+                let ligatures = mapping.entry(id(set, "glyph")?).or_default();
+                for ligature in elements_named(set, "Ligature") {
+                    ligatures.push(Offset16::to(Ligature {
+                        ligatureGlyph: id(ligature, "glyph")?,
+                        componentGlyphIDs: glyph_order.ids(attr(ligature, "components")?)?,
+                    }));
+                }
+            }
+            GSUBSubtable::GSUB4_1(LigatureSubstFormat1 {
+                substFormat: 1,
+                coverage: coverage_of(mapping.keys().copied()),
+                ligatureSet: mapping
+                    .into_values()
+                    .map(|ligatures| {
+                        Offset16::to(LigatureSet {
+                            ligatureOffsets: ligatures.into(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into(),
+            })
+        }
+        ("ContextSubst", 1) => {
+            GSUBSubtable::GSUB5_1(load_context1(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ContextSubst", 2) => {
+            GSUBSubtable::GSUB5_2(load_context2(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ContextSubst", 3) => {
+            GSUBSubtable::GSUB5_3(load_context3(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ChainContextSubst", 1) => {
+            GSUBSubtable::GSUB6_1(load_chain_context1(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ChainContextSubst", 2) => {
+            GSUBSubtable::GSUB6_2(load_chain_context2(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ChainContextSubst", 3) => {
+            GSUBSubtable::GSUB6_3(load_chain_context3(node, &SUBST_NAMES, glyph_order)?)
+        }
+        ("ExtensionSubst", 1) => GSUBSubtable::GSUB7_1(Box::new(ExtensionSubstFormat1 {
+            substFormat: 1,
+            extensionLookupType: child_value(node, "ExtensionLookupType")?,
+            extension: Offset32::to(load_gsub_subtable(extension_child(node)?, glyph_order)?),
+        })),
+        ("ReverseChainSingleSubst", 1) => GSUBSubtable::GSUB8_1(ReverseChainSingleSubstFormat1 {
+            substFormat: 1,
+            coverage: load_coverage(node, "Coverage", glyph_order)?,
+            backtrackCoverages: load_coverages(node, "BacktrackCoverage", glyph_order)?,
+            lookaheadCoverages: load_coverages(node, "LookAheadCoverage", glyph_order)?,
+            substituteGlyphIDs: load_sequence(node, "Substitute", Some(glyph_order))?,
+        }),
+        (name, format) => {
+            return Err(error(format!(
+                "Unknown GSUB subtable {} format {}",
+                name, format
+            )))
+        }
+    })
+}
+
+fn load_gpos_subtable(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<GPOSSubtable, DeserializationError> {
+    let format: u16 = opt_num(node, "Format")?.unwrap_or(1);
+    Ok(match (node.tag_name().name(), format) {
+        ("SinglePos", 1) => {
+            let value_format = load_value_format(node, "ValueFormat")?;
+            GPOSSubtable::GPOS1_1(SinglePosFormat1 {
+                posFormat: 1,
+                coverage: load_coverage(node, "Coverage", glyph_order)?,
+                valueFormat: value_format,
+                valueRecord: load_value_record(child(node, "Value"), value_format)?,
+            })
+        }
+        ("SinglePos", 2) => {
+            let value_format = load_value_format(node, "ValueFormat")?;
+            GPOSSubtable::GPOS1_2(SinglePosFormat2 {
+                posFormat: 2,
+                coverage: load_coverage(node, "Coverage", glyph_order)?,
+                valueFormat: value_format,
+                valueRecords: elements_named(node, "Value")
+                    .map(|n| load_value_record(Some(n), value_format))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+        ("PairPos", 1) => {
+            let value_format1 = load_value_format(node, "ValueFormat1")?;
+            let value_format2 = load_value_format(node, "ValueFormat2")?;
+            GPOSSubtable::GPOS2_1(PairPosFormat1 {
+                posFormat: 1,
+                coverage: load_coverage(node, "Coverage", glyph_order)?,
+                valueFormat1: value_format1,
+                valueFormat2: value_format2,
+                pairSets: load_offsets(node, "PairSet", |set| {
+                    let pair_value_records = elements_named(set, "PairValueRecord")
+                        .map(|record| {
+                            Ok(PairValueRecord {
+                                secondGlyph: glyph_order
+                                    .id(attr(required_child(record, "SecondGlyph")?, "value")?)?,
+                                valueRecord1: load_value_record(
+                                    child(record, "Value1"),
+                                    value_format1,
+                                )?,
+                                valueRecord2: load_value_record(
+                                    child(record, "Value2"),
+                                    value_format2,
+                                )?,
+                            })
+                        })
+                        .collect::<Result<_, DeserializationError>>()?;
+                    Ok(PairSet {
+                        pairValueRecords: pair_value_records,
+                    })
+                })?,
+            })
+        }
+        ("PairPos", 2) => {
+            let value_format1 = load_value_format(node, "ValueFormat1")?;
+            let value_format2 = load_value_format(node, "ValueFormat2")?;
+            let class1_records: Vec<Class1Record> = elements_named(node, "Class1Record")
+                .map(|class1| {
+                    let class2_records = elements_named(class1, "Class2Record")
+                        .map(|class2| {
+                            Ok(Class2Record {
+                                valueRecord1: load_value_record(
+                                    child(class2, "Value1"),
+                                    value_format1,
+                                )?,
+                                valueRecord2: load_value_record(
+                                    child(class2, "Value2"),
+                                    value_format2,
+                                )?,
+                            })
+                        })
+                        .collect::<Result<_, DeserializationError>>()?;
+                    Ok(Class1Record {
+                        class2Records: class2_records,
+                    })
+                })
+                .collect::<Result<_, DeserializationError>>()?;
+            GPOSSubtable::GPOS2_2(PairPosFormat2 {
+                posFormat: 2,
+                coverage: load_coverage(node, "Coverage", glyph_order)?,
+                valueFormat1: value_format1,
+                valueFormat2: value_format2,
+                classDef1: load_class_def(node, "ClassDef1", glyph_order)?,
+                classDef2: load_class_def(node, "ClassDef2", glyph_order)?,
+                classCount1: class1_records.len() as u16,
+                classCount2: class1_records
+                    .first()
+                    .map_or(0, |r| r.class2Records.len() as u16),
+                class1Records: class1_records,
+            })
+        }
+        ("CursivePos", 1) => GPOSSubtable::GPOS3_1(CursivePosFormat1 {
+            posFormat: 1,
+            coverage: load_coverage(node, "Coverage", glyph_order)?,
+            entryExitRecord: elements_named(node, "EntryExitRecord")
+                .map(|record| {
+                    Ok(EntryExitRecord {
+                        entryAnchor: load_offset(child(record, "EntryAnchor"), load_anchor)?,
+                        exitAnchor: load_offset(child(record, "ExitAnchor"), load_anchor)?,
+                    })
+                })
+                .collect::<Result<_, DeserializationError>>()?,
+        }),
+        ("MarkBasePos", 1) => GPOSSubtable::GPOS4_1(MarkBasePosFormat1 {
+            posFormat: 1,
+            markCoverage: load_coverage(node, "MarkCoverage", glyph_order)?,
+            baseCoverage: load_coverage(node, "BaseCoverage", glyph_order)?,
+            markClassCount: mark_class_count(node)?,
+            markArray: load_mark_array(node, "MarkArray")?,
+            baseArray: load_offset(child(node, "BaseArray"), |array| {
+                let base_records = elements_named(array, "BaseRecord")
+                    .map(|record| {
+                        Ok(BaseRecord {
+                            baseAnchors: load_anchors(record, "BaseAnchor")?,
+                        })
+                    })
+                    .collect::<Result<_, DeserializationError>>()?;
+                Ok(BaseArray {
+                    baseRecords: base_records,
+                })
+            })?,
+        }),
+        ("MarkLigPos", 1) => GPOSSubtable::GPOS5_1(MarkLigPosFormat1 {
+            posFormat: 1,
+            markCoverage: load_coverage(node, "MarkCoverage", glyph_order)?,
+            ligatureCoverage: load_coverage(node, "LigatureCoverage", glyph_order)?,
+            markClassCount: mark_class_count(node)?,
+            markArray: load_mark_array(node, "MarkArray")?,
+            ligatureArray: load_offset(child(node, "LigatureArray"), |array| {
+                Ok(LigatureArray {
+                    ligatureAttach: load_offsets(array, "LigatureAttach", |attach| {
+                        let component_records = elements_named(attach, "ComponentRecord")
+                            .map(|record| {
+                                Ok(ComponentRecord {
+                                    ligatureAnchors: load_anchors(record, "LigatureAnchor")?,
+                                })
+                            })
+                            .collect::<Result<_, DeserializationError>>()?;
+                        Ok(LigatureAttach {
+                            componentRecords: component_records,
+                        })
+                    })?,
+                })
+            })?,
+        }),
+        ("MarkMarkPos", 1) => GPOSSubtable::GPOS6_1(MarkMarkPosFormat1 {
+            posFormat: 1,
+            mark1Coverage: load_coverage(node, "Mark1Coverage", glyph_order)?,
+            mark2Coverage: load_coverage(node, "Mark2Coverage", glyph_order)?,
+            markClassCount: mark_class_count(node)?,
+            mark1Array: load_mark_array(node, "Mark1Array")?,
+            mark2Array: load_offset(child(node, "Mark2Array"), |array| {
+                let mark2_records = elements_named(array, "Mark2Record")
+                    .map(|record| {
+                        Ok(Mark2Record {
+                            mark2Anchors: load_anchors(record, "Mark2Anchor")?,
+                        })
+                    })
+                    .collect::<Result<_, DeserializationError>>()?;
+                Ok(Mark2Array {
+                    mark2Records: mark2_records,
+                })
+            })?,
+        }),
+        ("ContextPos", 1) => GPOSSubtable::GPOS7_1(load_context1(node, &POS_NAMES, glyph_order)?),
+        ("ContextPos", 2) => GPOSSubtable::GPOS7_2(load_context2(node, &POS_NAMES, glyph_order)?),
+        ("ContextPos", 3) => GPOSSubtable::GPOS7_3(load_context3(node, &POS_NAMES, glyph_order)?),
+        ("ChainContextPos", 1) => {
+            GPOSSubtable::GPOS8_1(load_chain_context1(node, &POS_NAMES, glyph_order)?)
+        }
+        ("ChainContextPos", 2) => {
+            GPOSSubtable::GPOS8_2(load_chain_context2(node, &POS_NAMES, glyph_order)?)
+        }
+        ("ChainContextPos", 3) => {
+            GPOSSubtable::GPOS8_3(load_chain_context3(node, &POS_NAMES, glyph_order)?)
+        }
+        ("ExtensionPos", 1) => GPOSSubtable::GPOS9_1(Box::new(ExtensionPosFormat1 {
+            substFormat: 1,
+            extensionLookupType: child_value(node, "ExtensionLookupType")?,
+            extension: Offset32::to(load_gpos_subtable(extension_child(node)?, glyph_order)?),
+        })),
+        (name, format) => {
+            return Err(error(format!(
+                "Unknown GPOS subtable {} format {}",
+                name, format
+            )))
+        }
+    })
+}
+
+/// The number of mark classes, which is only given as a comment, so is
+/// worked out from the mark records.
+fn mark_class_count(node: Node) -> Result<u16, DeserializationError> {
+    let mut count = 0;
+    for array in
+        elements(node).filter(|n| n.has_tag_name("MarkArray") || n.has_tag_name("Mark1Array"))
+    {
+        for record in elements_named(array, "MarkRecord") {
+            count = count.max(child_value::<u16>(record, "Class")? + 1);
+        }
+    }
+    Ok(count)
+}
+
+/// Whether an element of a lookup is one of its subtables.
+fn is_subtable(node: &Node) -> bool {
+    !matches!(
+        node.tag_name().name(),
+        "LookupType" | "LookupFlag" | "MarkFilteringSet"
+    )
+}
+
+fn load_lookup_header(node: Node) -> Result<(u16, LookupFlags, Option<u16>), DeserializationError> {
+    Ok((
+        child_value(node, "LookupType")?,
+        LookupFlags::from_bits_truncate(child_value(node, "LookupFlag")?),
+        opt_child_value(node, "MarkFilteringSet")?,
+    ))
+}
+
+fn lookup_list<'a, 'input>(node: Node<'a, 'input>) -> Vec<Node<'a, 'input>> {
+    match child(node, "LookupList") {
+        Some(list) => elements_named(list, "Lookup").collect(),
+        None => vec![],
+    }
+}
+
+pub(crate) fn load_gsub(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<GSUB11, DeserializationError> {
+    let lookups = lookup_list(node)
+        .into_iter()
+        .map(|lookup| {
+            let (lookup_type, flags, mark_filtering_set) = load_lookup_header(lookup)?;
+            let subtables = elements(lookup)
+                .filter(is_subtable)
+                .map(|n| Ok(Offset16::to(load_gsub_subtable(n, glyph_order)?)))
+                .collect::<Result<Vec<_>, DeserializationError>>()?;
+            Ok(Offset16::to(GSUBLookup {
+                lookupType: lookup_type,
+                lookupFlag: flags,
+                subtables: subtables.into(),
+                markFilteringSet: mark_filtering_set,
+            }))
+        })
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    let feature_variations = load_feature_variations(child(node, "FeatureVariations"))?;
+    Ok(GSUB11 {
+        majorVersion: 1,
+        minorVersion: feature_variations.link.is_some() as uint16,
+        scriptList: load_offset(child(node, "ScriptList"), load_script_list)?,
+        featureList: load_offset(child(node, "FeatureList"), load_feature_list)?,
+        lookupList: Offset16::to(GSUBLookupList {
+            lookups: lookups.into(),
+        }),
+        featureVariations: feature_variations,
+    })
+}
+
+pub(crate) fn load_gpos(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<GPOS11, DeserializationError> {
+    let lookups = lookup_list(node)
+        .into_iter()
+        .map(|lookup| {
+            let (lookup_type, flags, mark_filtering_set) = load_lookup_header(lookup)?;
+            let subtables = elements(lookup)
+                .filter(is_subtable)
+                .map(|n| Ok(Offset16::to(load_gpos_subtable(n, glyph_order)?)))
+                .collect::<Result<Vec<_>, DeserializationError>>()?;
+            Ok(Offset16::to(GPOSLookup {
+                lookupType: lookup_type,
+                lookupFlag: flags,
+                subtables: subtables.into(),
+                markFilteringSet: mark_filtering_set,
+            }))
+        })
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    let feature_variations = load_feature_variations(child(node, "FeatureVariations"))?;
+    Ok(GPOS11 {
+        majorVersion: 1,
+        minorVersion: feature_variations.link.is_some() as uint16,
+        scriptList: load_offset(child(node, "ScriptList"), load_script_list)?,
+        featureList: load_offset(child(node, "FeatureList"), load_feature_list)?,
+        lookupList: Offset16::to(GPOSLookupList {
+            lookups: lookups.into(),
+        }),
+        featureVariations: feature_variations,
+    })
+}
+
+/// Loads the glyphs of a GDEF subtable's coverage, which the subtable's
+/// array is indexed by.
+fn load_gdef_coverage(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<Vec<GlyphID>, DeserializationError> {
+    load_coverage_glyphs(required_child(node, "Coverage")?, glyph_order)
+}
+
+fn load_caret_value(node: Node) -> Result<CaretValue, DeserializationError> {
+    Ok(match num::<u16>(node, "Format")? {
+        1 => CaretValue::Format1 {
+            coordinate: child_value(node, "Coordinate")?,
+        },
+        2 => CaretValue::Format2 {
+            pointIndex: child_value(node, "CaretValuePoint")?,
+        },
+        3 => CaretValue::Format3 {
+            coordinate: child_value(node, "Coordinate")?,
+            device: load_offset(child(node, "DeviceTable"), load_device)?,
+        },
+        format => return Err(error(format!("Unknown caret value format {}", format))),
+    })
+}
+
+pub(crate) fn load_gdef(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<GDEF, DeserializationError> {
+    let class_def = |tag: &str| -> Result<BTreeMap<GlyphID, u16>, DeserializationError> {
+        match child(node, tag) {
+            Some(class_def) => load_class_def_entries(class_def, glyph_order),
+            None => Ok(BTreeMap::new()),
+        }
+    };
+    let mut attachment_point_list = BTreeMap::new();
+    if let Some(list) = child(node, "AttachList") {
+        let glyphs = load_gdef_coverage(list, glyph_order)?;
+        for (gid, points) in glyphs.into_iter().zip(elements_named(list, "AttachPoint")) {
+            let points = elements_named(points, "PointIndex")
+                .map(value)
+                .collect::<Result<Vec<u16>, _>>()?;
+            attachment_point_list.insert(gid, points);
+        }
+    }
+    let mut ligature_caret_list = BTreeMap::new();
+    if let Some(list) = child(node, "LigCaretList") {
+        let glyphs = load_gdef_coverage(list, glyph_order)?;
+        for (gid, carets) in glyphs.into_iter().zip(elements_named(list, "LigGlyph")) {
+            let carets = elements_named(carets, "CaretValue")
+                .map(load_caret_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            ligature_caret_list.insert(gid, carets);
+        }
+    }
+    let mark_glyph_sets = match child(node, "MarkGlyphSetsDef") {
+        Some(sets) => Some(
+            elements_named(sets, "Coverage")
+                .map(|coverage| {
+                    Ok(load_coverage_glyphs(coverage, glyph_order)?
+                        .into_iter()
+                        .collect::<BTreeSet<GlyphID>>())
+                })
+                .collect::<Result<Vec<_>, DeserializationError>>()?,
+        ),
+        None => None,
+    };
+    Ok(GDEF {
+        glyph_class: class_def("GlyphClassDef")?
+            .into_iter()
+            .map(|(gid, class)| (gid, GlyphClass::from(class)))
+            .collect(),
+        attachment_point_list,
+        ligature_caret_list,
+        mark_attachment_class: class_def("MarkAttachClassDef")?,
+        mark_glyph_sets,
+        item_variation_store: child(node, "VarStore").map(load_var_store).transpose()?,
+    })
+}
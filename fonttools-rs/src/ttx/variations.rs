@@ -0,0 +1,584 @@
+//! TTX conversion for the font variations tables.
+use super::xml::{
+    attr, child, child_value, elements_named, error, fl2str, num, opt_child_value, opt_num,
+    required_child, Node, XmlWriter,
+};
+use super::GlyphOrder;
+use crate::otvar::{
+    DeltaSetIndexMap, ItemVariationData, ItemVariationStore, RegionAxisCoordinates,
+};
+use crate::tables::{avar, fvar, gvar, HVAR, MVAR, STAT};
+use otspec::types::*;
+use otspec::DeserializationError;
+use std::collections::BTreeMap;
+
+fn tag(node: Node, name: &str) -> Result<Tag, DeserializationError> {
+    let text = attr(node, name)?;
+    Tag::from_raw(format!("{:<4}", text)).map_err(|_| error(format!("Bad tag '{}'", text)))
+}
+
+fn text_tag(node: Node) -> Result<Tag, DeserializationError> {
+    let text = node.text().unwrap_or_default().trim();
+    Tag::from_raw(format!("{:<4}", text)).map_err(|_| error(format!("Bad tag '{}'", text)))
+}
+
+fn text_num<T: super::xml::XmlNumber>(node: Node, name: &str) -> Result<T, DeserializationError> {
+    super::xml::parse(required_child(node, name)?.text().unwrap_or_default())
+}
+
+fn axis_index(axes: &[Tag], axis: Tag) -> Result<usize, DeserializationError> {
+    axes.iter()
+        .position(|&a| a == axis)
+        .ok_or_else(|| error(format!("Unknown axis '{}'", axis)))
+}
+
+fn axis_tag(axes: &[Tag], index: usize) -> String {
+    axes.get(index).map_or_else(
+        || format!("axis{}", index),
+        |t| t.as_str().trim().to_string(),
+    )
+}
+
+fn version(major: u16, minor: u16) -> String {
+    format!("{:#010x}", (major as u32) << 16 | minor as u32)
+}
+
+pub(crate) fn dump_fvar(w: &mut XmlWriter, fvar: &fvar::fvar) {
+    for axis in &fvar.axes {
+        w.begin("Axis", &[]);
+        for (name, value) in [
+            ("AxisTag", axis.axisTag.as_str().to_string()),
+            ("Flags", format!("{:#x}", axis.flags)),
+            ("MinValue", fl2str(axis.minValue, 16)),
+            ("DefaultValue", fl2str(axis.defaultValue, 16)),
+            ("MaxValue", fl2str(axis.maxValue, 16)),
+            ("AxisNameID", axis.axisNameID.to_string()),
+        ] {
+            w.begin_inline(name);
+            w.text(&value);
+            w.end_inline(name);
+        }
+        w.end("Axis");
+    }
+    for instance in &fvar.instances {
+        let flags = format!("{:#x}", instance.flags);
+        match instance.postscriptNameID {
+            Some(ps_name_id) => w.begin(
+                "NamedInstance",
+                &[
+                    ("flags", &flags),
+                    ("postscriptNameID", &ps_name_id),
+                    ("subfamilyNameID", &instance.subfamilyNameID),
+                ],
+            ),
+            None => w.begin(
+                "NamedInstance",
+                &[
+                    ("flags", &flags),
+                    ("subfamilyNameID", &instance.subfamilyNameID),
+                ],
+            ),
+        }
+        for (axis, value) in fvar.axes.iter().zip(instance.coordinates.iter()) {
+            w.simple(
+                "coord",
+                &[("axis", &axis.axisTag), ("value", &fl2str(*value, 16))],
+            );
+        }
+        w.end("NamedInstance");
+    }
+}
+
+pub(crate) fn load_fvar(node: Node) -> Result<fvar::fvar, DeserializationError> {
+    let axes = elements_named(node, "Axis")
+        .map(|axis| {
+            Ok(fvar::VariationAxisRecord {
+                axisTag: text_tag(required_child(axis, "AxisTag")?)?,
+                flags: text_num(axis, "Flags")?,
+                minValue: text_num(axis, "MinValue")?,
+                defaultValue: text_num(axis, "DefaultValue")?,
+                maxValue: text_num(axis, "MaxValue")?,
+                axisNameID: text_num(axis, "AxisNameID")?,
+            })
+        })
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    let axis_tags: Vec<Tag> = axes.iter().map(|a| a.axisTag).collect();
+    let instances = elements_named(node, "NamedInstance")
+        .map(|instance| {
+            let mut coordinates: Tuple = axes.iter().map(|a| a.defaultValue).collect();
+            for coord in elements_named(instance, "coord") {
+                coordinates[axis_index(&axis_tags, tag(coord, "axis")?)?] = num(coord, "value")?;
+            }
+            Ok(fvar::InstanceRecord {
+                subfamilyNameID: num(instance, "subfamilyNameID")?,
+                flags: opt_num(instance, "flags")?.unwrap_or(0),
+                coordinates,
+                postscriptNameID: opt_num(instance, "postscriptNameID")?,
+            })
+        })
+        .collect::<Result<Vec<_>, DeserializationError>>()?;
+    Ok(fvar::fvar { axes, instances })
+}
+
+pub(crate) fn dump_index_map(
+    w: &mut XmlWriter,
+    name: &str,
+    map: &DeltaSetIndexMap,
+    key: &dyn Fn(usize) -> String,
+    key_name: &str,
+) {
+    w.begin(name, &[]);
+    for (ix, entry) in map.entries.iter().enumerate() {
+        w.simple(
+            "Map",
+            &[
+                (key_name, &key(ix)),
+                ("outer", &(entry >> 16)),
+                ("inner", &(entry & 0xffff)),
+            ],
+        );
+    }
+    w.end(name);
+}
+
+pub(crate) fn load_index_map(
+    node: Node,
+    key: &dyn Fn(&str) -> Result<usize, DeserializationError>,
+    key_name: &str,
+) -> Result<DeltaSetIndexMap, DeserializationError> {
+    let mut entries: Vec<u32> = vec![];
+    for map in elements_named(node, "Map") {
+        let index = key(attr(map, key_name)?)?;
+        if index >= entries.len() {
+            entries.resize(index + 1, 0);
+        }
+        let outer: u32 = num(map, "outer")?;
+        let inner: u32 = num(map, "inner")?;
+        entries[index] = outer << 16 | inner;
+    }
+    Ok(DeltaSetIndexMap { entries })
+}
+
+pub(crate) fn dump_avar(w: &mut XmlWriter, avar: &avar::avar, axes: &[Tag]) {
+    if avar.var_store.is_some() {
+        w.simple("version", &[("major", &2), ("minor", &0)]);
+    }
+    for (ix, map) in avar.maps.iter().enumerate() {
+        w.begin("segment", &[("axis", &axis_tag(axes, ix))]);
+        for (from, to) in &map.0 {
+            w.simple(
+                "mapping",
+                &[("from", &fl2str(*from, 14)), ("to", &fl2str(*to, 14))],
+            );
+        }
+        w.end("segment");
+    }
+    if let Some(map) = &avar.axis_index_map {
+        dump_index_map(w, "AxisIndexMap", map, &|ix| ix.to_string(), "index");
+    }
+    if let Some(var_store) = &avar.var_store {
+        dump_var_store(w, var_store);
+    }
+}
+
+pub(crate) fn load_avar(node: Node, axes: &[Tag]) -> Result<avar::avar, DeserializationError> {
+    let mut maps = vec![avar::SegmentMap(vec![]); axes.len()];
+    for segment in elements_named(node, "segment") {
+        let ix = axis_index(axes, tag(segment, "axis")?)?;
+        maps[ix] = avar::SegmentMap(
+            elements_named(segment, "mapping")
+                .map(|m| Ok((num(m, "from")?, num(m, "to")?)))
+                .collect::<Result<_, DeserializationError>>()?,
+        );
+    }
+    let axis_index_map = child(node, "AxisIndexMap")
+        .map(|n| load_index_map(n, &|s| super::xml::parse(s), "index"))
+        .transpose()?;
+    let var_store = child(node, "VarStore").map(load_var_store).transpose()?;
+    Ok(avar::avar {
+        maps,
+        axis_index_map,
+        var_store,
+    })
+}
+
+/// The number of columns of an item variation data subtable which need
+/// 16-bit storage.
+fn num_shorts(data: &ItemVariationData) -> usize {
+    (0..data.region_indexes.len())
+        .filter(|&col| {
+            data.delta_values
+                .iter()
+                .any(|row| row.get(col).is_some_and(|&v| !(-128..=127).contains(&v)))
+        })
+        .count()
+}
+
+pub(crate) fn dump_var_store(w: &mut XmlWriter, store: &ItemVariationStore) {
+    w.begin("VarStore", &[("Format", &store.format)]);
+    w.value("Format", store.format);
+    w.begin("VarRegionList", &[]);
+    w.comment(&format!("RegionAxisCount={}", store.axisCount));
+    w.comment(&format!("RegionCount={}", store.variationRegions.len()));
+    for (ix, region) in store.variationRegions.iter().enumerate() {
+        w.begin("Region", &[("index", &ix)]);
+        for (axis_ix, axis) in region.iter().enumerate() {
+            w.begin("VarRegionAxis", &[("index", &axis_ix)]);
+            w.value("StartCoord", fl2str(axis.startCoord, 14));
+            w.value("PeakCoord", fl2str(axis.peakCoord, 14));
+            w.value("EndCoord", fl2str(axis.endCoord, 14));
+            w.end("VarRegionAxis");
+        }
+        w.end("Region");
+    }
+    w.end("VarRegionList");
+    w.comment(&format!("VarDataCount={}", store.variationData.len()));
+    for (ix, data) in store.variationData.iter().enumerate() {
+        w.begin("VarData", &[("index", &ix)]);
+        w.comment(&format!("ItemCount={}", data.delta_values.len()));
+        w.value("NumShorts", num_shorts(data));
+        w.comment(&format!("VarRegionCount={}", data.region_indexes.len()));
+        for (region_ix, region) in data.region_indexes.iter().enumerate() {
+            w.indexed_value("VarRegionIndex", region_ix, region);
+        }
+        for (item_ix, row) in data.delta_values.iter().enumerate() {
+            let row: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            w.indexed_value("Item", item_ix, format!("[{}]", row.join(", ")));
+        }
+        w.end("VarData");
+    }
+    w.end("VarStore");
+}
+
+pub(crate) fn load_var_store(node: Node) -> Result<ItemVariationStore, DeserializationError> {
+    let region_list = required_child(node, "VarRegionList")?;
+    let variation_regions: Vec<Vec<RegionAxisCoordinates>> = elements_named(region_list, "Region")
+        .map(|region| {
+            elements_named(region, "VarRegionAxis")
+                .map(|axis| {
+                    Ok(RegionAxisCoordinates {
+                        startCoord: child_value(axis, "StartCoord")?,
+                        peakCoord: child_value(axis, "PeakCoord")?,
+                        endCoord: child_value(axis, "EndCoord")?,
+                    })
+                })
+                .collect()
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    let variation_data = elements_named(node, "VarData")
+        .map(|data| {
+            let region_indexes = elements_named(data, "VarRegionIndex")
+                .map(|n| num(n, "value"))
+                .collect::<Result<_, _>>()?;
+            let delta_values = elements_named(data, "Item")
+                .map(|item| {
+                    attr(item, "value")?
+                        .trim_matches(|c| c == '[' || c == ']')
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(super::xml::parse)
+                        .collect()
+                })
+                .collect::<Result<_, DeserializationError>>()?;
+            Ok(ItemVariationData {
+                region_indexes,
+                delta_values,
+            })
+        })
+        .collect::<Result<_, DeserializationError>>()?;
+    let axis_count = variation_regions
+        .first()
+        .map_or(0, |region: &Vec<RegionAxisCoordinates>| region.len());
+    Ok(ItemVariationStore {
+        format: opt_child_value(node, "Format")?.unwrap_or(1),
+        axisCount: axis_count as u16,
+        variationRegions: variation_regions,
+        variationData: variation_data,
+    })
+}
+
+pub(crate) fn dump_gvar(
+    w: &mut XmlWriter,
+    gvar: &gvar::gvar,
+    axes: &[Tag],
+    glyph_order: &GlyphOrder,
+) {
+    w.value("version", 1);
+    w.value("reserved", 0);
+    let mut glyphs: Vec<(String, &gvar::GlyphVariationData)> = gvar
+        .variations
+        .iter()
+        .enumerate()
+        .filter_map(|(gid, v)| v.as_ref().map(|v| (glyph_order.name(gid as GlyphID), v)))
+        .filter(|(_, v)| !v.deltasets.is_empty())
+        .collect();
+    glyphs.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, variations) in glyphs {
+        w.begin("glyphVariations", &[("glyph", &name)]);
+        for deltaset in &variations.deltasets {
+            w.begin("tuple", &[]);
+            for (ix, &peak) in deltaset.peak.iter().enumerate() {
+                let start = deltaset.start.get(ix).copied().unwrap_or(0.0);
+                let end = deltaset.end.get(ix).copied().unwrap_or(0.0);
+                let axis = axis_tag(axes, ix);
+                if peak == 0.0 {
+                    // Unused axis, whichever way its region was inferred
+                    if end == 0.0 && (start == 0.0 || start == -1.0) {
+                        continue;
+                    }
+                }
+                if start == peak.min(0.0) && end == peak.max(0.0) {
+                    w.simple("coord", &[("axis", &axis), ("value", &fl2str(peak, 14))]);
+                } else {
+                    w.simple(
+                        "coord",
+                        &[
+                            ("axis", &axis),
+                            ("min", &fl2str(start, 14)),
+                            ("value", &fl2str(peak, 14)),
+                            ("max", &fl2str(end, 14)),
+                        ],
+                    );
+                }
+            }
+            for (pt, (x, y)) in deltaset.deltas.iter().enumerate() {
+                w.simple("delta", &[("pt", &pt), ("x", x), ("y", y)]);
+            }
+            w.end("tuple");
+        }
+        w.end("glyphVariations");
+    }
+}
+
+pub(crate) fn load_gvar(
+    node: Node,
+    axes: &[Tag],
+    glyph_order: &GlyphOrder,
+) -> Result<gvar::gvar, DeserializationError> {
+    let mut variations: Vec<Option<gvar::GlyphVariationData>> = vec![None; glyph_order.len()];
+    for glyph in elements_named(node, "glyphVariations") {
+        let gid = glyph_order.id(attr(glyph, "glyph")?)? as usize;
+        let mut deltasets = vec![];
+        for tuple in elements_named(glyph, "tuple") {
+            let mut peak = vec![0.0; axes.len()];
+            let mut start = vec![0.0; axes.len()];
+            let mut end = vec![0.0; axes.len()];
+            for coord in elements_named(tuple, "coord") {
+                let ix = axis_index(axes, tag(coord, "axis")?)?;
+                let value: f32 = num(coord, "value")?;
+                peak[ix] = value;
+                start[ix] = opt_num(coord, "min")?.unwrap_or_else(|| value.min(0.0));
+                end[ix] = opt_num(coord, "max")?.unwrap_or_else(|| value.max(0.0));
+            }
+            let mut deltas = vec![];
+            for delta in elements_named(tuple, "delta") {
+                let pt: usize = num(delta, "pt")?;
+                if pt >= deltas.len() {
+                    deltas.resize(pt + 1, (0, 0));
+                }
+                deltas[pt] = (num(delta, "x")?, num(delta, "y")?);
+            }
+            deltasets.push(gvar::DeltaSet {
+                peak,
+                start,
+                end,
+                deltas,
+            });
+        }
+        if gid >= variations.len() {
+            variations.resize(gid + 1, None);
+        }
+        variations[gid] = Some(gvar::GlyphVariationData { deltasets });
+    }
+    Ok(gvar::gvar { variations })
+}
+
+pub(crate) fn dump_hvar(w: &mut XmlWriter, hvar: &HVAR::HVAR, glyph_order: &GlyphOrder) {
+    w.value("Version", version(1, 0));
+    dump_var_store(w, &hvar.item_variation_store);
+    let glyph_name = |gid: usize| glyph_order.name(gid as GlyphID);
+    for (name, map) in [
+        ("AdvWidthMap", &hvar.advance_mapping),
+        ("LsbMap", &hvar.lsb_mapping),
+        ("RsbMap", &hvar.rsb_mapping),
+    ] {
+        if let Some(map) = map {
+            dump_index_map(w, name, map, &glyph_name, "glyph");
+        }
+    }
+}
+
+pub(crate) fn load_hvar(
+    node: Node,
+    glyph_order: &GlyphOrder,
+) -> Result<HVAR::HVAR, DeserializationError> {
+    let glyph_id = |name: &str| glyph_order.id(name).map(|gid| gid as usize);
+    let map = |name: &str| {
+        child(node, name)
+            .map(|n| load_index_map(n, &glyph_id, "glyph"))
+            .transpose()
+    };
+    Ok(HVAR::HVAR {
+        item_variation_store: load_var_store(required_child(node, "VarStore")?)?,
+        advance_mapping: map("AdvWidthMap")?,
+        lsb_mapping: map("LsbMap")?,
+        rsb_mapping: map("RsbMap")?,
+    })
+}
+
+pub(crate) fn dump_mvar(w: &mut XmlWriter, mvar: &MVAR::MVAR) {
+    w.value("Version", version(1, 0));
+    w.value("Reserved", 0);
+    w.value("ValueRecordSize", 8);
+    w.comment(&format!("ValueRecordCount={}", mvar.records.len()));
+    if let Some(store) = &mvar.item_variation_store {
+        dump_var_store(w, store);
+    }
+    for (ix, (tag, var_idx)) in mvar.records.iter().enumerate() {
+        w.begin("ValueRecord", &[("index", &ix)]);
+        w.value("ValueTag", tag);
+        w.value("VarIdx", var_idx);
+        w.end("ValueRecord");
+    }
+}
+
+pub(crate) fn load_mvar(node: Node) -> Result<MVAR::MVAR, DeserializationError> {
+    let mut records = BTreeMap::new();
+    for record in elements_named(node, "ValueRecord") {
+        records.insert(
+            tag(required_child(record, "ValueTag")?, "value")?,
+            child_value(record, "VarIdx")?,
+        );
+    }
+    Ok(MVAR::MVAR {
+        item_variation_store: child(node, "VarStore").map(load_var_store).transpose()?,
+        records,
+    })
+}
+
+pub(crate) fn dump_stat(w: &mut XmlWriter, stat: &STAT::STAT) {
+    let has_format4 = stat.axis_values.iter().any(|v| v.locations.is_some());
+    w.value("Version", version(1, if has_format4 { 2 } else { 1 }));
+    w.value("DesignAxisRecordSize", 8);
+    w.comment(&format!("DesignAxisCount={}", stat.design_axes.len()));
+    w.begin("DesignAxisRecord", &[]);
+    for (ix, axis) in stat.design_axes.iter().enumerate() {
+        w.begin("Axis", &[("index", &ix)]);
+        w.value("AxisTag", axis.axisTag);
+        w.value("AxisNameID", axis.axisNameID);
+        w.value("AxisOrdering", axis.axisOrdering);
+        w.end("Axis");
+    }
+    w.end("DesignAxisRecord");
+    w.comment(&format!("AxisValueCount={}", stat.axis_values.len()));
+    w.begin("AxisValueArray", &[]);
+    for (ix, value) in stat.axis_values.iter().enumerate() {
+        let format = if value.locations.is_some() {
+            4
+        } else if value.linked_value.is_some() {
+            3
+        } else if value.range_min_max.is_some() {
+            2
+        } else {
+            1
+        };
+        w.begin("AxisValue", &[("index", &ix), ("Format", &format)]);
+        if let Some(locations) = &value.locations {
+            w.comment(&format!("AxisCount={}", locations.len()));
+            w.value("Flags", value.flags.bits());
+            w.value("ValueNameID", value.name_id);
+            for (record_ix, (axis, location)) in locations.iter().enumerate() {
+                w.begin("AxisValueRecord", &[("index", &record_ix)]);
+                w.value("AxisIndex", axis);
+                w.value("Value", fl2str(*location, 16));
+                w.end("AxisValueRecord");
+            }
+        } else {
+            w.value("AxisIndex", value.axis_index.unwrap_or(0));
+            w.value("Flags", value.flags.bits());
+            w.value("ValueNameID", value.name_id);
+            let nominal = fl2str(value.nominal_value.unwrap_or(0.0), 16);
+            if let Some((min, max)) = value.range_min_max {
+                w.value("NominalValue", nominal);
+                w.value("RangeMinValue", fl2str(min, 16));
+                w.value("RangeMaxValue", fl2str(max, 16));
+            } else {
+                w.value("Value", nominal);
+                if let Some(linked) = value.linked_value {
+                    w.value("LinkedValue", fl2str(linked, 16));
+                }
+            }
+        }
+        w.end("AxisValue");
+    }
+    w.end("AxisValueArray");
+    if let Some(elided) = stat.elided_fallback_name_id {
+        w.value("ElidedFallbackNameID", elided);
+    }
+}
+
+pub(crate) fn load_stat(node: Node) -> Result<STAT::STAT, DeserializationError> {
+    let design_axes = match child(node, "DesignAxisRecord") {
+        Some(record) => elements_named(record, "Axis")
+            .map(|axis| {
+                Ok(STAT::AxisRecord {
+                    axisTag: tag(required_child(axis, "AxisTag")?, "value")?,
+                    axisNameID: child_value(axis, "AxisNameID")?,
+                    axisOrdering: child_value(axis, "AxisOrdering")?,
+                })
+            })
+            .collect::<Result<_, DeserializationError>>()?,
+        None => vec![],
+    };
+    let axis_values = match child(node, "AxisValueArray") {
+        Some(array) => elements_named(array, "AxisValue")
+            .map(|value| {
+                let format: u16 = num(value, "Format")?;
+                let mut axis_value = STAT::AxisValue {
+                    axis_index: None,
+                    flags: STAT::AxisValueFlags::from_bits_truncate(child_value(value, "Flags")?),
+                    name_id: child_value(value, "ValueNameID")?,
+                    nominal_value: None,
+                    range_min_max: None,
+                    linked_value: None,
+                    locations: None,
+                };
+                match format {
+                    1 | 3 => {
+                        axis_value.axis_index = Some(child_value(value, "AxisIndex")?);
+                        axis_value.nominal_value = Some(child_value(value, "Value")?);
+                        if format == 3 {
+                            axis_value.linked_value = Some(child_value(value, "LinkedValue")?);
+                        }
+                    }
+                    2 => {
+                        axis_value.axis_index = Some(child_value(value, "AxisIndex")?);
+                        axis_value.nominal_value = Some(child_value(value, "NominalValue")?);
+                        axis_value.range_min_max = Some((
+                            child_value(value, "RangeMinValue")?,
+                            child_value(value, "RangeMaxValue")?,
+                        ));
+                    }
+                    4 => {
+                        axis_value.locations = Some(
+                            elements_named(value, "AxisValueRecord")
+                                .map(|r| {
+                                    Ok((child_value(r, "AxisIndex")?, child_value(r, "Value")?))
+                                })
+                                .collect::<Result<_, DeserializationError>>()?,
+                        );
+                    }
+                    _ => return Err(error(format!("Unknown AxisValue format {}", format))),
+                }
+                Ok(axis_value)
+            })
+            .collect::<Result<_, DeserializationError>>()?,
+        None => vec![],
+    };
+    Ok(STAT::STAT {
+        elided_fallback_name_id: opt_child_value(node, "ElidedFallbackNameID")?,
+        design_axes,
+        axis_values,
+    })
+}
@@ -0,0 +1,410 @@
+//! Helpers for writing and reading TTX-style XML.
+use otspec::DeserializationError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub(crate) type Node<'a, 'input> = roxmltree::Node<'a, 'input>;
+pub(crate) type Attrs<'a> = &'a [(&'a str, &'a dyn Display)];
+
+const INDENT: &str = "  ";
+
+/// An XML writer which produces the same layout as fontTools' `XMLWriter`
+#[derive(Debug, Default)]
+pub(crate) struct XmlWriter {
+    out: String,
+    indent: usize,
+    need_indent: bool,
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_attr(s: &str) -> String {
+    escape(s).replace('"', "&quot;").replace('\n', "&#10;")
+}
+
+impl XmlWriter {
+    pub(crate) fn new() -> Self {
+        XmlWriter {
+            out: "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_string(),
+            indent: 0,
+            need_indent: false,
+        }
+    }
+
+    pub(crate) fn finish(self) -> String {
+        self.out
+    }
+
+    /// Creates an empty writer at the same indentation level, whose output
+    /// can later be added to this one with [`XmlWriter::append`].
+    pub(crate) fn nested(&self) -> Self {
+        XmlWriter {
+            out: String::new(),
+            indent: self.indent + 1,
+            need_indent: true,
+        }
+    }
+
+    pub(crate) fn append(&mut self, other: XmlWriter) {
+        self.out.push_str(&other.out);
+    }
+
+    fn write_raw(&mut self, s: &str) {
+        if self.need_indent {
+            self.out.push_str(&INDENT.repeat(self.indent));
+            self.need_indent = false;
+        }
+        self.out.push_str(s);
+    }
+
+    fn tag(&mut self, tag: &str, attrs: Attrs, close: &str) {
+        let mut s = format!("<{}", tag);
+        for (k, v) in attrs {
+            s.push_str(&format!(" {}=\"{}\"", k, escape_attr(&v.to_string())));
+        }
+        s.push_str(close);
+        self.write_raw(&s);
+    }
+
+    pub(crate) fn newline(&mut self) {
+        self.out.push('\n');
+        self.need_indent = true;
+    }
+
+    /// Writes some (escaped) text at the current indentation level.
+    pub(crate) fn text(&mut self, text: &str) {
+        self.write_raw(&escape(text));
+    }
+
+    /// Writes an opening tag and starts a new, indented line.
+    pub(crate) fn begin(&mut self, tag: &str, attrs: Attrs) {
+        self.tag(tag, attrs, ">");
+        self.newline();
+        self.indent += 1;
+    }
+
+    /// Writes an opening tag followed by a comment (as fontTools does for
+    /// formatted subtables), and starts a new, indented line.
+    pub(crate) fn begin_commented(&mut self, tag: &str, attrs: Attrs, comment: &str) {
+        self.tag(tag, attrs, ">");
+        self.comment_inline(comment);
+        self.newline();
+        self.indent += 1;
+    }
+
+    /// Writes a closing tag on its own line.
+    pub(crate) fn end(&mut self, tag: &str) {
+        self.indent -= 1;
+        self.write_raw(&format!("</{}>", tag));
+        self.newline();
+    }
+
+    /// Writes an opening tag for an element whose content and closing tag
+    /// follow on the same line.
+    pub(crate) fn begin_inline(&mut self, tag: &str) {
+        self.tag(tag, &[], ">");
+    }
+
+    /// Closes an element opened with [`XmlWriter::begin_inline`].
+    pub(crate) fn end_inline(&mut self, tag: &str) {
+        self.write_raw(&format!("</{}>", tag));
+        self.newline();
+    }
+
+    /// Writes an empty element without finishing the line.
+    pub(crate) fn simple_inline(&mut self, tag: &str, attrs: Attrs) {
+        self.tag(tag, attrs, "/>");
+    }
+
+    /// Writes an empty element on its own line.
+    pub(crate) fn simple(&mut self, tag: &str, attrs: Attrs) {
+        self.simple_inline(tag, attrs);
+        self.newline();
+    }
+
+    /// Writes an element holding a single `value` attribute.
+    pub(crate) fn value(&mut self, tag: &str, value: impl Display) {
+        self.simple(tag, &[("value", &value)]);
+    }
+
+    /// Writes an element holding a single `value` attribute, and an
+    /// array `index` attribute.
+    pub(crate) fn indexed_value(&mut self, tag: &str, index: usize, value: impl Display) {
+        self.simple(tag, &[("index", &index), ("value", &value)]);
+    }
+
+    /// Increases the indentation of the following lines.
+    pub(crate) fn indent(&mut self) {
+        self.indent += 1;
+    }
+
+    /// Decreases the indentation of the following lines.
+    pub(crate) fn dedent(&mut self) {
+        self.indent = self.indent.saturating_sub(1);
+    }
+
+    /// Writes a comment without finishing the line.
+    ///
+    /// Continuation lines are lined up with the start of the comment text.
+    pub(crate) fn comment_inline(&mut self, comment: &str) {
+        let continuation = format!("\n{}     ", INDENT.repeat(self.indent));
+        self.write_raw(&format!(
+            "<!-- {} -->",
+            comment.replace('\n', &continuation)
+        ));
+    }
+
+    /// Writes a comment on its own line.
+    pub(crate) fn comment(&mut self, comment: &str) {
+        self.comment_inline(comment);
+        self.newline();
+    }
+
+    /// Writes binary data as lines of hexadecimal digits.
+    pub(crate) fn hex(&mut self, data: &[u8]) {
+        for line in data.chunks(16) {
+            let groups: Vec<String> = line
+                .chunks(4)
+                .map(|group| group.iter().map(|b| format!("{:02x}", b)).collect())
+                .collect();
+            self.write_raw(&groups.join(" "));
+            self.newline();
+        }
+    }
+}
+
+/// Formats a number as binary digits in groups of eight, as fontTools does
+/// for bit fields.
+pub(crate) fn num2binary(value: u32, bits: usize) -> String {
+    let digits = format!("{:0width$b}", value, width = bits);
+    let first = digits.len() % 8;
+    let mut groups = vec![];
+    if first > 0 {
+        groups.push(&digits[..first]);
+    }
+    groups.extend((first..digits.len()).step_by(8).map(|i| &digits[i..i + 8]));
+    groups.join(" ")
+}
+
+/// Formats a fixed-point number with the fewest decimal digits which
+/// round-trip at the given precision, as fontTools does.
+pub(crate) fn fl2str(value: f32, precision_bits: u32) -> String {
+    let scale = (1_u32 << precision_bits) as f64;
+    let fixed = (value as f64 * scale).round();
+    if fixed == 0.0 {
+        return "0.0".to_string();
+    }
+    let value = fixed / scale;
+    let eps = 0.5 / scale;
+    let (lo, hi) = (value - eps, value + eps);
+    if lo.trunc() != hi.trunc() {
+        return format!("{:.1}", value.round());
+    }
+    let (lo, hi) = (format!("{:.8}", lo), format!("{:.8}", hi));
+    let i = lo
+        .bytes()
+        .zip(hi.bytes())
+        .position(|(a, b)| a != b)
+        .unwrap_or(lo.len());
+    let period = lo.find('.').unwrap_or(0);
+    format!("{:.*}", i.saturating_sub(period).max(1), value)
+}
+
+pub(crate) fn error(msg: impl Into<String>) -> DeserializationError {
+    DeserializationError(msg.into())
+}
+
+/// Iterates over the element children of a node.
+pub(crate) fn elements<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(|n| n.is_element())
+}
+
+/// Iterates over the element children of a node with a given name.
+pub(crate) fn elements_named<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    elements(node).filter(move |n| n.tag_name().name() == name)
+}
+
+/// Returns the first element child of a node with a given name.
+pub(crate) fn child<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    elements(node).find(|n| n.tag_name().name() == name)
+}
+
+/// Returns the first element child of a node with a given name, or an error.
+pub(crate) fn required_child<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Result<Node<'a, 'input>, DeserializationError> {
+    child(node, name)
+        .ok_or_else(|| error(format!("<{}> has no <{}>", node.tag_name().name(), name)))
+}
+
+pub(crate) fn attr<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str, DeserializationError> {
+    node.attribute(name).ok_or_else(|| {
+        error(format!(
+            "<{}> has no '{}' attribute",
+            node.tag_name().name(),
+            name
+        ))
+    })
+}
+
+/// A number which may be written in TTX as decimal or hexadecimal.
+pub(crate) trait XmlNumber: Sized {
+    fn parse_xml(s: &str) -> Option<Self>;
+}
+
+macro_rules! xml_int {
+    ($($t:ty),*) => {$(
+        impl XmlNumber for $t {
+            fn parse_xml(s: &str) -> Option<Self> {
+                let s = s.trim();
+                let (negative, digits) = match s.strip_prefix('-') {
+                    Some(rest) => (true, rest),
+                    None => (false, s),
+                };
+                let value = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                    i64::from_str_radix(hex, 16).ok()?
+                } else {
+                    digits.parse::<i64>().ok()?
+                };
+                <$t>::try_from(if negative { -value } else { value }).ok()
+            }
+        }
+    )*};
+}
+xml_int!(u8, i8, u16, i16, u32, i32, usize);
+
+impl XmlNumber for f32 {
+    fn parse_xml(s: &str) -> Option<Self> {
+        f32::from_str(s.trim()).ok()
+    }
+}
+
+impl XmlNumber for bool {
+    fn parse_xml(s: &str) -> Option<Self> {
+        match s.trim() {
+            "1" | "True" | "true" => Some(true),
+            "0" | "False" | "false" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn parse<T: XmlNumber>(s: &str) -> Result<T, DeserializationError> {
+    T::parse_xml(s).ok_or_else(|| error(format!("Bad numeric value '{}'", s)))
+}
+
+/// Reads a numeric attribute.
+pub(crate) fn num<T: XmlNumber>(node: Node, name: &str) -> Result<T, DeserializationError> {
+    parse(attr(node, name)?)
+}
+
+/// Reads an optional numeric attribute.
+pub(crate) fn opt_num<T: XmlNumber>(
+    node: Node,
+    name: &str,
+) -> Result<Option<T>, DeserializationError> {
+    node.attribute(name).map(parse).transpose()
+}
+
+/// Reads the `value` attribute of a node.
+pub(crate) fn value<T: XmlNumber>(node: Node) -> Result<T, DeserializationError> {
+    num(node, "value")
+}
+
+/// Reads the `value` attribute of the named child of a node.
+pub(crate) fn child_value<T: XmlNumber>(node: Node, name: &str) -> Result<T, DeserializationError> {
+    value(required_child(node, name)?)
+}
+
+/// Reads the `value` attribute of the named child of a node, if present.
+pub(crate) fn opt_child_value<T: XmlNumber>(
+    node: Node,
+    name: &str,
+) -> Result<Option<T>, DeserializationError> {
+    child(node, name).map(value).transpose()
+}
+
+/// Reads a bit field written by [`num2binary`].
+pub(crate) fn binary<T: TryFrom<u32>>(s: &str) -> Result<T, DeserializationError> {
+    let digits: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    u32::from_str_radix(&digits, 2)
+        .ok()
+        .and_then(|v| T::try_from(v).ok())
+        .ok_or_else(|| error(format!("Bad binary value '{}'", s)))
+}
+
+/// Reads the `value` attribute of the named child of a node as a bit field.
+pub(crate) fn child_binary<T: TryFrom<u32>>(
+    node: Node,
+    name: &str,
+) -> Result<T, DeserializationError> {
+    binary(attr(required_child(node, name)?, "value")?)
+}
+
+/// Reads the hexadecimal digits inside a node.
+pub(crate) fn hexdata(node: Node) -> Result<Vec<u8>, DeserializationError> {
+    let digits: Vec<u8> = node
+        .descendants()
+        .filter(|n| n.is_text())
+        .flat_map(|n| n.text().unwrap_or("").bytes())
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    if digits.len() % 2 == 1 {
+        return Err(error("Odd number of hex digits"));
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or_else(|| error("Bad hex data"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fl2str() {
+        assert_eq!(fl2str(0.0, 14), "0.0");
+        assert_eq!(fl2str(1.0, 14), "1.0");
+        assert_eq!(fl2str(-0.5, 14), "-0.5");
+        assert_eq!(fl2str(13107.0 / 65536.0, 16), "0.2");
+        assert_eq!(fl2str(0.70001, 14), "0.7");
+        assert_eq!(fl2str(400.0, 16), "400.0");
+    }
+
+    #[test]
+    fn test_num2binary() {
+        assert_eq!(num2binary(3, 16), "00000000 00000011");
+        assert_eq!(
+            num2binary(0x80000001, 32),
+            "10000000 00000000 00000000 00000001"
+        );
+        assert_eq!(num2binary(5, 5), "00101");
+        assert_eq!(binary::<u16>("00000000 00000011").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse::<u32>("0x5f0f3cf5").unwrap(), 0x5f0f3cf5);
+        assert_eq!(parse::<i16>("-120").unwrap(), -120);
+        assert!(parse::<u8>("300").is_err());
+    }
+}
@@ -0,0 +1,259 @@
+//! Reading and writing WOFF 1.0 files.
+//!
+//! Normally you do not need to use this module directly: [`Font::load`] reads
+//! WOFF files transparently, and a font whose `flavor` is [`Flavor::Woff`] is
+//! written as WOFF by [`Font::save`].
+//!
+//! [`Font::load`]: crate::font::Font::load
+//! [`Font::save`]: crate::font::Font::save
+//! [`Flavor::Woff`]: crate::font::Flavor::Woff
+use crate::font::{compile_sfnt, read_table_directory, FlavorData};
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serializer};
+use otspec_macros::tables;
+use std::io::{Read, Write};
+
+/// The signature at the start of a WOFF file
+pub const SIGNATURE: Tag = crate::tag!("wOFF");
+
+tables!(
+    WOFFHeader {
+        Tag signature
+        uint32 flavor
+        uint32 length
+        uint16 numTables
+        uint16 reserved
+        uint32 totalSfntSize
+        uint16 majorVersion
+        uint16 minorVersion
+        uint32 metaOffset
+        uint32 metaLength
+        uint32 metaOrigLength
+        uint32 privOffset
+        uint32 privLength
+    }
+    WOFFTableDirectoryEntry {
+        Tag tag
+        uint32 offset
+        uint32 compLength
+        uint32 origLength
+        uint32 origChecksum
+    }
+);
+
+const HEADER_SIZE: usize = 44;
+const DIRECTORY_ENTRY_SIZE: usize = 20;
+
+/// Returns a block of data from a web font file, checking that it is in range.
+pub(crate) fn block(bytes: &[u8], offset: u32, length: u32) -> Result<&[u8], DeserializationError> {
+    bytes
+        .get(offset as usize..offset as usize + length as usize)
+        .ok_or_else(|| DeserializationError("Data block extends beyond end of file".to_string()))
+}
+
+/// Pads a buffer with zeros to a four-byte boundary.
+pub(crate) fn pad4(data: &mut Vec<u8>) {
+    data.resize((data.len() + 3) & !3, 0);
+}
+
+fn inflate(data: &[u8], orig_length: u32) -> Result<Vec<u8>, DeserializationError> {
+    let mut out = Vec::with_capacity(orig_length as usize);
+    ZlibDecoder::new(data)
+        .read_to_end(&mut out)
+        .map_err(|e| DeserializationError(format!("Bad zlib data: {}", e)))?;
+    if out.len() != orig_length as usize {
+        return Err(DeserializationError(format!(
+            "Decompressed data was {} bytes, expected {}",
+            out.len(),
+            orig_length
+        )));
+    }
+    Ok(out)
+}
+
+fn deflate(data: &[u8]) -> Result<Vec<u8>, SerializationError> {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::best());
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| SerializationError(format!("Couldn't compress data: {}", e)))
+}
+
+/// Decompresses a WOFF file, returning the binary sfnt font and the data
+/// from the WOFF container.
+pub fn decompress(bytes: &[u8]) -> Result<(Vec<u8>, FlavorData), DeserializationError> {
+    let mut c = ReaderContext::new(bytes.to_vec());
+    let header: WOFFHeader = c.de()?;
+    if header.signature != SIGNATURE {
+        return Err(DeserializationError("Not a WOFF file".to_string()));
+    }
+    let entries = (0..header.numTables)
+        .map(|_| c.de())
+        .collect::<Result<Vec<WOFFTableDirectoryEntry>, DeserializationError>>()?;
+    let mut tables = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let data = block(bytes, entry.offset, entry.compLength)?;
+        let data = if entry.compLength < entry.origLength {
+            inflate(data, entry.origLength)?
+        } else if entry.compLength == entry.origLength {
+            data.to_vec()
+        } else {
+            return Err(DeserializationError(format!(
+                "Compressed length of table {} is greater than its original length",
+                entry.tag
+            )));
+        };
+        tables.push((entry.tag, data));
+    }
+    let metadata = if header.metaLength > 0 {
+        let data = block(bytes, header.metaOffset, header.metaLength)?;
+        Some(inflate(data, header.metaOrigLength)?)
+    } else {
+        None
+    };
+    let private_data = if header.privLength > 0 {
+        Some(block(bytes, header.privOffset, header.privLength)?.to_vec())
+    } else {
+        None
+    };
+    Ok((
+        compile_sfnt(header.flavor, &tables),
+        FlavorData {
+            major_version: header.majorVersion,
+            minor_version: header.minorVersion,
+            metadata,
+            private_data,
+        },
+    ))
+}
+
+/// Compresses a binary sfnt font into a WOFF file.
+pub fn compress(sfnt: &[u8], flavor_data: &FlavorData) -> Result<Vec<u8>, SerializationError> {
+    let (flavor, mut records) = read_table_directory(sfnt).map_err(|e| SerializationError(e.0))?;
+    records.sort_by_key(|r| r.tag);
+
+    let data_start = HEADER_SIZE + DIRECTORY_ENTRY_SIZE * records.len();
+    let mut total_sfnt_size = 12 + 16 * records.len();
+    let mut directory = vec![];
+    let mut table_data = vec![];
+    for record in &records {
+        let data = &sfnt[record.offset as usize..(record.offset + record.length) as usize];
+        let compressed = deflate(data)?;
+        // Tables which don't get smaller are stored uncompressed
+        let stored = if compressed.len() < data.len() {
+            &compressed[..]
+        } else {
+            data
+        };
+        directory.put(WOFFTableDirectoryEntry {
+            tag: record.tag,
+            offset: (data_start + table_data.len()) as uint32,
+            compLength: stored.len() as uint32,
+            origLength: record.length,
+            origChecksum: record.checksum,
+        })?;
+        table_data.extend_from_slice(stored);
+        pad4(&mut table_data);
+        total_sfnt_size += (record.length as usize + 3) & !3;
+    }
+
+    let mut header = WOFFHeader {
+        signature: SIGNATURE,
+        flavor,
+        length: 0,
+        numTables: records.len() as uint16,
+        reserved: 0,
+        totalSfntSize: total_sfnt_size as uint32,
+        majorVersion: flavor_data.major_version,
+        minorVersion: flavor_data.minor_version,
+        metaOffset: 0,
+        metaLength: 0,
+        metaOrigLength: 0,
+        privOffset: 0,
+        privLength: 0,
+    };
+    if let Some(metadata) = &flavor_data.metadata {
+        let compressed = deflate(metadata)?;
+        header.metaOffset = (data_start + table_data.len()) as uint32;
+        header.metaLength = compressed.len() as uint32;
+        header.metaOrigLength = metadata.len() as uint32;
+        table_data.extend(compressed);
+    }
+    if let Some(private_data) = &flavor_data.private_data {
+        pad4(&mut table_data);
+        header.privOffset = (data_start + table_data.len()) as uint32;
+        header.privLength = private_data.len() as uint32;
+        table_data.extend_from_slice(private_data);
+    }
+    header.length = (data_start + table_data.len()) as uint32;
+
+    let mut out = vec![];
+    out.put(header)?;
+    out.extend(directory);
+    out.extend(table_data);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::{Flavor, Font};
+
+    fn test_font() -> Font {
+        let mut font = Font::new(crate::font::SfntVersion::TrueType);
+        font.tables
+            .insert(crate::tables::head::new(1.5, 1000, 0, 0, 0, 0));
+        font.tables.insert(crate::tables::maxp::maxp::new05(0));
+        font.tables.insert_raw(crate::tag!("TEST"), vec![0x61; 200]);
+        font
+    }
+
+    #[test]
+    fn test_woff_roundtrip() {
+        let mut font = test_font();
+        let mut sfnt = vec![];
+        font.write(&mut sfnt).unwrap();
+
+        font.flavor = Some(Flavor::Woff);
+        font.flavor_data = Some(FlavorData {
+            major_version: 1,
+            minor_version: 2,
+            metadata: Some(b"<?xml version=\"1.0\"?><metadata version=\"1.0\"/>".to_vec()),
+            private_data: Some(vec![1, 2, 3]),
+        });
+        let mut woff = vec![];
+        font.write(&mut woff).unwrap();
+        assert_eq!(&woff[0..4], b"wOFF");
+        assert_eq!(
+            woff.len(),
+            u32::from_be_bytes(woff[8..12].try_into().unwrap()) as usize
+        );
+        assert!(woff.len() < sfnt.len());
+
+        let (decompressed, flavor_data) = decompress(&woff).unwrap();
+        assert_eq!(decompressed, sfnt);
+        assert_eq!(flavor_data, font.flavor_data.clone().unwrap());
+
+        let loaded = Font::from_bytes(&woff).unwrap();
+        assert_eq!(loaded.flavor, Some(Flavor::Woff));
+        assert!(loaded.contains_table(crate::tag!("TEST")));
+    }
+
+    #[test]
+    fn test_woff_default_version() {
+        let mut font = test_font();
+        font.flavor = Some(Flavor::Woff);
+        let mut woff = vec![];
+        font.write(&mut woff).unwrap();
+        let loaded = Font::from_bytes(&woff).unwrap();
+        let flavor_data = loaded.flavor_data.unwrap();
+        assert_eq!(
+            (flavor_data.major_version, flavor_data.minor_version),
+            (1, 500)
+        );
+        assert_eq!(flavor_data.metadata, None);
+    }
+}
@@ -0,0 +1,660 @@
+//! Reading and writing WOFF 2.0 files.
+//!
+//! As with [`woff`](crate::woff), [`Font::load`] reads WOFF2 files
+//! transparently, and a font whose `flavor` is [`Flavor::Woff2`] is written
+//! as WOFF2 by [`Font::save`]. When writing, the `glyf` and `loca` tables are
+//! always transformed, and the `hmtx` table is transformed where its side
+//! bearings can be recovered from the glyph bounds.
+//!
+//! [`Font::load`]: crate::font::Font::load
+//! [`Font::save`]: crate::font::Font::save
+//! [`Flavor::Woff2`]: crate::font::Flavor::Woff2
+use crate::font::{compile_sfnt, read_table_directory, FlavorData};
+use crate::tag;
+use crate::woff::block;
+use brotli::enc::backward_references::BrotliEncoderMode;
+use brotli::enc::BrotliEncoderParams;
+use otspec::types::*;
+use otspec::{
+    DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
+    Serializer,
+};
+use otspec_macros::tables;
+use std::collections::BTreeMap;
+
+/// Transformations of the `glyf`, `loca` and `hmtx` tables
+mod transform;
+
+/// The signature at the start of a WOFF2 file
+pub const SIGNATURE: Tag = crate::tag!("wOF2");
+
+tables!(
+    WOFF2Header {
+        Tag signature
+        uint32 flavor
+        uint32 length
+        uint16 numTables
+        uint16 reserved
+        uint32 totalSfntSize
+        uint32 totalCompressedSize
+        uint16 majorVersion
+        uint16 minorVersion
+        uint32 metaOffset
+        uint32 metaLength
+        uint32 metaOrigLength
+        uint32 privOffset
+        uint32 privLength
+    }
+);
+
+const HEADER_SIZE: usize = 48;
+
+/// Tags which can be stored in the table directory as a single index
+const KNOWN_TAGS: [Tag; 63] = [
+    tag!("cmap"),
+    tag!("head"),
+    tag!("hhea"),
+    tag!("hmtx"),
+    tag!("maxp"),
+    tag!("name"),
+    tag!("OS/2"),
+    tag!("post"),
+    tag!("cvt "),
+    tag!("fpgm"),
+    tag!("glyf"),
+    tag!("loca"),
+    tag!("prep"),
+    tag!("CFF "),
+    tag!("VORG"),
+    tag!("EBDT"),
+    tag!("EBLC"),
+    tag!("gasp"),
+    tag!("hdmx"),
+    tag!("kern"),
+    tag!("LTSH"),
+    tag!("PCLT"),
+    tag!("VDMX"),
+    tag!("vhea"),
+    tag!("vmtx"),
+    tag!("BASE"),
+    tag!("GDEF"),
+    tag!("GPOS"),
+    tag!("GSUB"),
+    tag!("EBSC"),
+    tag!("JSTF"),
+    tag!("MATH"),
+    tag!("CBDT"),
+    tag!("CBLC"),
+    tag!("COLR"),
+    tag!("CPAL"),
+    tag!("SVG "),
+    tag!("sbix"),
+    tag!("acnt"),
+    tag!("avar"),
+    tag!("bdat"),
+    tag!("bloc"),
+    tag!("bsln"),
+    tag!("cvar"),
+    tag!("fdsc"),
+    tag!("feat"),
+    tag!("fmtx"),
+    tag!("fvar"),
+    tag!("gvar"),
+    tag!("hsty"),
+    tag!("just"),
+    tag!("lcar"),
+    tag!("mort"),
+    tag!("morx"),
+    tag!("opbd"),
+    tag!("prop"),
+    tag!("trak"),
+    tag!("Zapf"),
+    tag!("Silf"),
+    tag!("Glat"),
+    tag!("Gloc"),
+    tag!("Feat"),
+    tag!("Sill"),
+];
+
+const GLYF: Tag = tag!("glyf");
+const LOCA: Tag = tag!("loca");
+const HMTX: Tag = tag!("hmtx");
+
+/// An entry in the WOFF2 table directory
+#[derive(Debug, PartialEq, Eq)]
+struct TableDirectoryEntry {
+    tag: Tag,
+    transform_version: u8,
+    orig_length: uint32,
+    transform_length: Option<uint32>,
+}
+
+impl TableDirectoryEntry {
+    /// Whether the table data is stored transformed. Transform version 0 is
+    /// the null transform, except for `glyf` and `loca` where it is 3.
+    fn is_transformed(tag: Tag, transform_version: u8) -> bool {
+        if tag == GLYF || tag == LOCA {
+            transform_version != 3
+        } else {
+            transform_version != 0
+        }
+    }
+
+    /// The length of the table's data in the decompressed stream
+    fn stream_length(&self) -> uint32 {
+        self.transform_length.unwrap_or(self.orig_length)
+    }
+}
+
+impl Deserialize for TableDirectoryEntry {
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let flags: u8 = c.de()?;
+        let tag = match flags & 0x3f {
+            0x3f => c.de()?,
+            index => KNOWN_TAGS[index as usize],
+        };
+        let transform_version = flags >> 6;
+        let orig_length = read_base128(c)?;
+        let transform_length = if Self::is_transformed(tag, transform_version) {
+            Some(read_base128(c)?)
+        } else {
+            None
+        };
+        Ok(TableDirectoryEntry {
+            tag,
+            transform_version,
+            orig_length,
+            transform_length,
+        })
+    }
+}
+
+impl Serialize for TableDirectoryEntry {
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        let version = self.transform_version << 6;
+        match KNOWN_TAGS.iter().position(|&t| t == self.tag) {
+            Some(index) => data.put(version | index as u8)?,
+            None => {
+                data.put(version | 0x3f)?;
+                data.put(self.tag)?;
+            }
+        }
+        write_base128(data, self.orig_length);
+        if let Some(length) = self.transform_length {
+            write_base128(data, length);
+        }
+        Ok(())
+    }
+}
+
+/// Reads a variable-length `UIntBase128` value.
+fn read_base128(c: &mut ReaderContext) -> Result<uint32, DeserializationError> {
+    let mut accum: u32 = 0;
+    for i in 0..5 {
+        let byte: u8 = c.de()?;
+        if i == 0 && byte == 0x80 {
+            return Err(DeserializationError(
+                "UIntBase128 value has leading zeros".to_string(),
+            ));
+        }
+        if accum & 0xFE000000 != 0 {
+            return Err(DeserializationError(
+                "UIntBase128 value overflows".to_string(),
+            ));
+        }
+        accum = (accum << 7) | (byte & 0x7f) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(accum);
+        }
+    }
+    Err(DeserializationError(
+        "UIntBase128 value is too long".to_string(),
+    ))
+}
+
+/// Writes a variable-length `UIntBase128` value.
+fn write_base128(data: &mut Vec<u8>, value: uint32) {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    let mut value = value >> 7;
+    while value > 0 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    data.extend(bytes.iter().rev());
+}
+
+fn brotli_decompress(data: &[u8]) -> Result<Vec<u8>, DeserializationError> {
+    let mut out = vec![];
+    brotli::BrotliDecompress(&mut &data[..], &mut out)
+        .map_err(|e| DeserializationError(format!("Bad Brotli data: {}", e)))?;
+    Ok(out)
+}
+
+fn brotli_compress(data: &[u8], mode: BrotliEncoderMode) -> Result<Vec<u8>, SerializationError> {
+    let params = BrotliEncoderParams {
+        mode,
+        quality: 11,
+        size_hint: data.len(),
+        ..Default::default()
+    };
+    let mut out = vec![];
+    brotli::BrotliCompress(&mut &data[..], &mut out, &params)
+        .map_err(|e| SerializationError(format!("Couldn't compress data: {}", e)))?;
+    Ok(out)
+}
+
+/// Decompresses a WOFF2 file, returning the binary sfnt font and the data
+/// from the WOFF2 container.
+pub fn decompress(bytes: &[u8]) -> Result<(Vec<u8>, FlavorData), DeserializationError> {
+    let mut c = ReaderContext::new(bytes.to_vec());
+    let header: WOFF2Header = c.de()?;
+    if header.signature != SIGNATURE {
+        return Err(DeserializationError("Not a WOFF2 file".to_string()));
+    }
+    if header.flavor == u32::from_be_bytes(*b"ttcf") {
+        return Err(DeserializationError(
+            "WOFF2 font collections are not supported".to_string(),
+        ));
+    }
+    let entries = (0..header.numTables)
+        .map(|_| c.de())
+        .collect::<Result<Vec<TableDirectoryEntry>, DeserializationError>>()?;
+    for entry in &entries {
+        let supported = match (entry.tag, entry.transform_version) {
+            (GLYF, 0) | (LOCA, 0) | (HMTX, 1) => true,
+            (tag, version) => !TableDirectoryEntry::is_transformed(tag, version),
+        };
+        if !supported {
+            return Err(DeserializationError(format!(
+                "Unknown transform version {} for table {}",
+                entry.transform_version, entry.tag
+            )));
+        }
+    }
+
+    let stream = brotli_decompress(block(bytes, c.ptr as u32, header.totalCompressedSize)?)?;
+    let mut offset = 0;
+    let mut raw_tables: BTreeMap<Tag, &[u8]> = BTreeMap::new();
+    for entry in &entries {
+        raw_tables.insert(entry.tag, block(&stream, offset, entry.stream_length())?);
+        offset += entry.stream_length();
+    }
+
+    let mut tables: Vec<(Tag, Vec<u8>)> = vec![];
+    let mut x_mins = None;
+    for entry in &entries {
+        let data = raw_tables[&entry.tag];
+        match (entry.tag, entry.transform_length) {
+            (GLYF, Some(_)) => {
+                let glyf = transform::reconstruct_glyf(data)?;
+                let loca_length = entries
+                    .iter()
+                    .find(|e| e.tag == LOCA && e.transform_length.is_some())
+                    .ok_or_else(|| {
+                        DeserializationError("Transformed glyf without loca".to_string())
+                    })?
+                    .orig_length;
+                if glyf.loca.len() != loca_length as usize {
+                    return Err(DeserializationError(
+                        "Reconstructed loca table has the wrong length".to_string(),
+                    ));
+                }
+                tables.push((GLYF, glyf.glyf));
+                tables.push((LOCA, glyf.loca));
+                x_mins = Some(glyf.x_mins);
+            }
+            (LOCA, Some(_)) => {}
+            (HMTX, Some(_)) => {} // Needs the glyph bounds; see below
+            (tag, _) => tables.push((tag, data.to_vec())),
+        }
+    }
+    if let Some(entry) = entries
+        .iter()
+        .find(|e| e.tag == HMTX && e.transform_length.is_some())
+    {
+        let x_mins = x_mins.as_ref().ok_or_else(|| {
+            DeserializationError("Transformed hmtx without transformed glyf".to_string())
+        })?;
+        let num_h_metrics = read_u16_at(&raw_tables, tag!("hhea"), 34)?;
+        let hmtx = transform::reconstruct_hmtx(raw_tables[&HMTX], num_h_metrics, x_mins)?;
+        if hmtx.len() != entry.orig_length as usize {
+            return Err(DeserializationError(
+                "Reconstructed hmtx table has the wrong length".to_string(),
+            ));
+        }
+        tables.push((HMTX, hmtx));
+    }
+
+    let metadata = if header.metaLength > 0 {
+        let data = brotli_decompress(block(bytes, header.metaOffset, header.metaLength)?)?;
+        if data.len() != header.metaOrigLength as usize {
+            return Err(DeserializationError(
+                "Decompressed metadata has the wrong length".to_string(),
+            ));
+        }
+        Some(data)
+    } else {
+        None
+    };
+    let private_data = if header.privLength > 0 {
+        Some(block(bytes, header.privOffset, header.privLength)?.to_vec())
+    } else {
+        None
+    };
+    Ok((
+        compile_sfnt(header.flavor, &tables),
+        FlavorData {
+            major_version: header.majorVersion,
+            minor_version: header.minorVersion,
+            metadata,
+            private_data,
+        },
+    ))
+}
+
+/// Reads a `uint16` from a table given its byte offset within the table.
+fn read_u16_at(
+    tables: &BTreeMap<Tag, &[u8]>,
+    tag: Tag,
+    offset: usize,
+) -> Result<uint16, DeserializationError> {
+    tables
+        .get(&tag)
+        .and_then(|data| data.get(offset..offset + 2))
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| DeserializationError(format!("Couldn't read {} table", tag)))
+}
+
+/// Compresses a binary sfnt font into a WOFF2 file.
+pub fn compress(sfnt: &[u8], flavor_data: &FlavorData) -> Result<Vec<u8>, SerializationError> {
+    let (flavor, records) = read_table_directory(sfnt).map_err(|e| SerializationError(e.0))?;
+    let raw_tables: BTreeMap<Tag, &[u8]> = records
+        .iter()
+        .map(|r| {
+            (
+                r.tag,
+                &sfnt[r.offset as usize..(r.offset + r.length) as usize],
+            )
+        })
+        .collect();
+    let read_u16 = |tag, offset| read_u16_at(&raw_tables, tag, offset);
+
+    // The loca table must directly follow glyf
+    let mut tags: Vec<Tag> = raw_tables.keys().copied().filter(|&t| t != LOCA).collect();
+    if raw_tables.contains_key(&LOCA) {
+        let position = tags.iter().position(|&t| t == GLYF).map_or(0, |p| p + 1);
+        tags.insert(position, LOCA);
+    }
+
+    let mut transformed: BTreeMap<Tag, Vec<u8>> = BTreeMap::new();
+    if raw_tables.contains_key(&GLYF) && raw_tables.contains_key(&LOCA) {
+        let index_format = read_u16(tag!("head"), 50).map_err(|e| SerializationError(e.0))?;
+        let num_glyphs = read_u16(tag!("maxp"), 4).map_err(|e| SerializationError(e.0))?;
+        let (glyf, x_mins) = transform::transform_glyf(
+            raw_tables[&GLYF],
+            raw_tables[&LOCA],
+            index_format,
+            num_glyphs,
+        )?;
+        transformed.insert(GLYF, glyf);
+        transformed.insert(LOCA, vec![]);
+        if let (Some(hmtx), Ok(num_h_metrics)) = (raw_tables.get(&HMTX), read_u16(tag!("hhea"), 34))
+        {
+            if let Some(hmtx) = transform::transform_hmtx(hmtx, num_h_metrics, &x_mins) {
+                transformed.insert(HMTX, hmtx);
+            }
+        }
+    }
+
+    let mut directory = vec![];
+    let mut stream = vec![];
+    let mut total_sfnt_size = 12 + 16 * tags.len();
+    for tag in tags {
+        let data = raw_tables[&tag];
+        let entry = match transformed.get(&tag) {
+            Some(transformed_data) => {
+                stream.extend_from_slice(transformed_data);
+                TableDirectoryEntry {
+                    tag,
+                    transform_version: if tag == HMTX { 1 } else { 0 },
+                    orig_length: data.len() as uint32,
+                    transform_length: Some(transformed_data.len() as uint32),
+                }
+            }
+            None => {
+                stream.extend_from_slice(data);
+                TableDirectoryEntry {
+                    tag,
+                    transform_version: if tag == GLYF || tag == LOCA { 3 } else { 0 },
+                    orig_length: data.len() as uint32,
+                    transform_length: None,
+                }
+            }
+        };
+        directory.put(entry)?;
+        total_sfnt_size += (data.len() + 3) & !3;
+    }
+    let compressed = brotli_compress(&stream, BrotliEncoderMode::BROTLI_MODE_FONT)?;
+
+    let data_start = HEADER_SIZE + directory.len();
+    let mut data = compressed;
+    let mut header = WOFF2Header {
+        signature: SIGNATURE,
+        flavor,
+        length: 0,
+        numTables: records.len() as uint16,
+        reserved: 0,
+        totalSfntSize: total_sfnt_size as uint32,
+        totalCompressedSize: data.len() as uint32,
+        majorVersion: flavor_data.major_version,
+        minorVersion: flavor_data.minor_version,
+        metaOffset: 0,
+        metaLength: 0,
+        metaOrigLength: 0,
+        privOffset: 0,
+        privLength: 0,
+    };
+    // The font data block is padded, in its position in the file
+    while !(data_start + data.len()).is_multiple_of(4) {
+        data.push(0);
+    }
+    if let Some(metadata) = &flavor_data.metadata {
+        let compressed = brotli_compress(metadata, BrotliEncoderMode::BROTLI_MODE_TEXT)?;
+        header.metaOffset = (data_start + data.len()) as uint32;
+        header.metaLength = compressed.len() as uint32;
+        header.metaOrigLength = metadata.len() as uint32;
+        data.extend(compressed);
+    }
+    if let Some(private_data) = &flavor_data.private_data {
+        while !(data_start + data.len()).is_multiple_of(4) {
+            data.push(0);
+        }
+        header.privOffset = (data_start + data.len()) as uint32;
+        header.privLength = private_data.len() as uint32;
+        data.extend_from_slice(private_data);
+    }
+    header.length = (data_start + data.len()) as uint32;
+
+    let mut out = vec![];
+    out.put(header)?;
+    out.extend(directory);
+    out.extend(data);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::{Flavor, Font, SfntVersion};
+    use crate::tables::glyf::{glyf, Component, ComponentFlags, Glyph, Point};
+    use crate::tables::hmtx::{hmtx, Metric};
+    use crate::tables::{hhea::hhea, maxp::maxp};
+
+    fn square(x_min: int16, size: int16) -> Glyph {
+        Glyph {
+            xMin: x_min,
+            yMin: 0,
+            xMax: x_min + size,
+            yMax: size,
+            contours: vec![vec![
+                Point {
+                    x: x_min,
+                    y: 0,
+                    on_curve: true,
+                },
+                Point {
+                    x: x_min,
+                    y: size,
+                    on_curve: false,
+                },
+                Point {
+                    x: x_min + size,
+                    y: size,
+                    on_curve: true,
+                },
+                Point {
+                    x: x_min + size,
+                    y: 0,
+                    on_curve: true,
+                },
+            ]],
+            instructions: vec![],
+            components: vec![],
+            overlap: false,
+        }
+    }
+
+    fn test_font() -> Font {
+        let mut font = Font::new(SfntVersion::TrueType);
+        font.tables
+            .insert(crate::tables::head::new(1.0, 1000, 0, 0, 0, 0));
+        font.tables.insert(hhea {
+            majorVersion: 1,
+            minorVersion: 0,
+            ascender: 800,
+            descender: -200,
+            lineGap: 0,
+            advanceWidthMax: 3000,
+            minLeftSideBearing: 0,
+            minRightSideBearing: 0,
+            xMaxExtent: 2000,
+            caretSlopeRise: 1,
+            caretSlopeRun: 0,
+            caretOffset: 0,
+            reserved0: 0,
+            reserved1: 0,
+            reserved2: 0,
+            reserved3: 0,
+            metricDataFormat: 0,
+            numberOfHMetrics: 4,
+        });
+        font.tables.insert(maxp::new05(4));
+        let mut big = square(-20, 2000);
+        big.instructions = vec![0xb0, 0x01];
+        let composite = Glyph {
+            xMin: 100,
+            yMin: 0,
+            xMax: 1225,
+            yMax: 450,
+            contours: vec![],
+            instructions: vec![],
+            components: vec![
+                Component {
+                    glyph_index: 1,
+                    transformation: kurbo::Affine::translate((100.0, 0.0)),
+                    match_points: None,
+                    flags: ComponentFlags::USE_MY_METRICS,
+                },
+                Component {
+                    glyph_index: 1,
+                    transformation: kurbo::Affine::new([1.5, 0.0, 0.0, 1.5, 700.0, 0.0]),
+                    match_points: None,
+                    flags: ComponentFlags::empty(),
+                },
+            ],
+            overlap: false,
+        };
+        let empty = Glyph {
+            xMin: 0,
+            yMin: 0,
+            xMax: 0,
+            yMax: 0,
+            contours: vec![],
+            instructions: vec![],
+            components: vec![],
+            overlap: false,
+        };
+        font.tables.insert(glyf {
+            glyphs: vec![empty, square(50, 300), composite, big],
+        });
+        let (hmtx_bytes, _) = hmtx {
+            metrics: vec![
+                Metric {
+                    advanceWidth: 500,
+                    lsb: 0,
+                },
+                Metric {
+                    advanceWidth: 400,
+                    lsb: 50,
+                },
+                Metric {
+                    advanceWidth: 1400,
+                    lsb: 100,
+                },
+                // Side bearings which don't match the bounds are kept
+                Metric {
+                    advanceWidth: 3000,
+                    lsb: 10,
+                },
+            ],
+        }
+        .to_bytes();
+        font.tables.insert_raw(crate::tables::hmtx::TAG, hmtx_bytes);
+        font
+    }
+
+    #[test]
+    fn test_base128() {
+        for value in [0, 63, 127, 128, 16383, 16384, 0x0fff_ffff, u32::MAX] {
+            let mut data = vec![];
+            write_base128(&mut data, value);
+            let mut c = ReaderContext::new(data);
+            assert_eq!(read_base128(&mut c).unwrap(), value);
+        }
+        // Leading zeros are forbidden
+        let mut c = ReaderContext::new(vec![0x80, 0x3f]);
+        assert!(read_base128(&mut c).is_err());
+    }
+
+    #[test]
+    fn test_woff2_roundtrip() {
+        let mut font = test_font();
+        font.flavor = Some(Flavor::Woff2);
+        font.flavor_data = Some(FlavorData {
+            major_version: 2,
+            minor_version: 1,
+            metadata: Some(b"<?xml version=\"1.0\"?><metadata version=\"1.0\"/>".to_vec()),
+            private_data: Some(vec![1, 2, 3, 4, 5]),
+        });
+        let mut woff2 = vec![];
+        font.write(&mut woff2).unwrap();
+        assert_eq!(&woff2[0..4], b"wOF2");
+        assert_eq!(
+            woff2.len(),
+            u32::from_be_bytes(woff2[8..12].try_into().unwrap()) as usize
+        );
+
+        let loaded = Font::from_bytes(&woff2).unwrap();
+        assert_eq!(loaded.flavor, Some(Flavor::Woff2));
+        assert_eq!(loaded.flavor_data, font.flavor_data);
+        assert_eq!(
+            loaded.tables.glyf().unwrap().unwrap().glyphs,
+            font.tables.glyf().unwrap().unwrap().glyphs
+        );
+        assert_eq!(
+            loaded.tables.hmtx().unwrap().unwrap().metrics,
+            font.tables.hmtx().unwrap().unwrap().metrics
+        );
+        assert_eq!(loaded.tables.maxp().unwrap().unwrap().num_glyphs(), 4);
+    }
+}
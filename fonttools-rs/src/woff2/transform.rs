@@ -0,0 +1,648 @@
+use crate::tables::glyf::{ComponentFlags, Glyph, Point};
+use crate::woff::pad4;
+use otspec::types::*;
+use otspec::{DeserializationError, Deserializer, ReaderContext, SerializationError, Serializer};
+use otspec_macros::tables;
+
+tables!(
+    GlyfTransformHeader {
+        uint16 reserved
+        uint16 optionFlags
+        uint16 numGlyphs
+        uint16 indexFormat
+        uint32 nContourStreamSize
+        uint32 nPointsStreamSize
+        uint32 flagStreamSize
+        uint32 glyphStreamSize
+        uint32 compositeStreamSize
+        uint32 bboxStreamSize
+        uint32 instructionStreamSize
+    }
+);
+
+const HEADER_SIZE: usize = 36;
+/// `optionFlags` bit signalling that an overlapSimpleBitmap is present
+const OVERLAP_SIMPLE_BITMAP: uint16 = 1;
+/// The `OVERLAP_SIMPLE` simple glyph flag
+const OVERLAP_SIMPLE: u8 = 0x40;
+
+/// The `glyf` and `loca` tables rebuilt from a transformed `glyf` table,
+/// together with the glyphs' minimum X coordinates (needed for `hmtx`).
+pub(crate) struct ReconstructedGlyf {
+    pub(crate) glyf: Vec<u8>,
+    pub(crate) loca: Vec<u8>,
+    pub(crate) x_mins: Vec<int16>,
+}
+
+/// Reads a `255UInt16` value.
+fn read_255_u16(c: &mut ReaderContext) -> Result<uint16, DeserializationError> {
+    let code: u8 = c.de()?;
+    Ok(match code {
+        253 => c.de()?,
+        254 => {
+            let value: u8 = c.de()?;
+            value as uint16 + 506
+        }
+        255 => {
+            let value: u8 = c.de()?;
+            value as uint16 + 253
+        }
+        _ => code as uint16,
+    })
+}
+
+/// Writes a `255UInt16` value.
+fn write_255_u16(data: &mut Vec<u8>, value: uint16) {
+    match value {
+        0..=252 => data.push(value as u8),
+        253..=505 => data.extend([255, (value - 253) as u8]),
+        506..=761 => data.extend([254, (value - 506) as u8]),
+        _ => {
+            data.push(253);
+            data.extend(value.to_be_bytes());
+        }
+    }
+}
+
+fn bit_is_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap
+        .get(index >> 3)
+        .is_some_and(|b| b & (0x80 >> (index & 7)) != 0)
+}
+
+fn set_bit(bitmap: &mut [u8], index: usize) {
+    bitmap[index >> 3] |= 0x80 >> (index & 7);
+}
+
+/// Returns the length of the component records at the start of a composite
+/// glyph description, and whether the glyph has instructions.
+fn composite_size(data: &[u8]) -> Result<(usize, bool), DeserializationError> {
+    let mut offset = 0;
+    let mut have_instructions = false;
+    loop {
+        let flags = data
+            .get(offset..offset + 2)
+            .map(|b| ComponentFlags::from_bits_truncate(u16::from_be_bytes([b[0], b[1]])))
+            .ok_or_else(|| DeserializationError("Truncated composite glyph".to_string()))?;
+        offset += 4; // flags and glyph index
+        offset += if flags.contains(ComponentFlags::ARG_1_AND_2_ARE_WORDS) {
+            4
+        } else {
+            2
+        };
+        offset += if flags.contains(ComponentFlags::WE_HAVE_A_SCALE) {
+            2
+        } else if flags.contains(ComponentFlags::WE_HAVE_AN_X_AND_Y_SCALE) {
+            4
+        } else if flags.contains(ComponentFlags::WE_HAVE_A_TWO_BY_TWO) {
+            8
+        } else {
+            0
+        };
+        have_instructions |= flags.contains(ComponentFlags::WE_HAVE_INSTRUCTIONS);
+        if !flags.contains(ComponentFlags::MORE_COMPONENTS) {
+            break;
+        }
+    }
+    if offset > data.len() {
+        return Err(DeserializationError(
+            "Truncated composite glyph".to_string(),
+        ));
+    }
+    Ok((offset, have_instructions))
+}
+
+/// Decodes a point delta from the glyph stream, given its flag byte.
+fn read_triplet(flag: u8, c: &mut ReaderContext) -> Result<(i32, i32), DeserializationError> {
+    let flag = (flag & 0x7f) as i32;
+    let with_sign = |flag: i32, value: i32| if flag & 1 != 0 { value } else { -value };
+    let mut byte = || -> Result<i32, DeserializationError> {
+        let b: u8 = c.de()?;
+        Ok(b as i32)
+    };
+    Ok(if flag < 10 {
+        (0, with_sign(flag, ((flag & 14) << 7) + byte()?))
+    } else if flag < 20 {
+        (with_sign(flag, (((flag - 10) & 14) << 7) + byte()?), 0)
+    } else if flag < 84 {
+        let b0 = flag - 20;
+        let b1 = byte()?;
+        (
+            with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+            with_sign(flag >> 1, 1 + ((b0 & 0x0c) << 2) + (b1 & 0x0f)),
+        )
+    } else if flag < 120 {
+        let b0 = flag - 84;
+        let (b1, b2) = (byte()?, byte()?);
+        (
+            with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+            with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+        )
+    } else if flag < 124 {
+        let (b1, b2, b3) = (byte()?, byte()?, byte()?);
+        (
+            with_sign(flag, (b1 << 4) + (b2 >> 4)),
+            with_sign(flag >> 1, ((b2 & 0x0f) << 8) + b3),
+        )
+    } else {
+        let (b1, b2, b3, b4) = (byte()?, byte()?, byte()?, byte()?);
+        (
+            with_sign(flag, (b1 << 8) + b2),
+            with_sign(flag >> 1, (b3 << 8) + b4),
+        )
+    })
+}
+
+/// Encodes a point delta, returning its flag byte and writing its data to
+/// the glyph stream.
+fn write_triplet(dx: i32, dy: i32, on_curve: bool, data: &mut Vec<u8>) -> u8 {
+    let on_curve_bit = if on_curve { 0 } else { 128 };
+    let x_sign = if dx < 0 { 0 } else { 1 };
+    let y_sign = if dy < 0 { 0 } else { 2 };
+    let (abs_x, abs_y) = (dx.unsigned_abs(), dy.unsigned_abs());
+    let flag = if dx == 0 && abs_y < 1280 {
+        data.push(abs_y as u8);
+        ((abs_y & 0xf00) >> 7) + (y_sign >> 1)
+    } else if dy == 0 && abs_x < 1280 {
+        data.push(abs_x as u8);
+        10 + ((abs_x & 0xf00) >> 7) + x_sign
+    } else if abs_x < 65 && abs_y < 65 {
+        data.push(((((abs_x - 1) & 0xf) << 4) | ((abs_y - 1) & 0xf)) as u8);
+        20 + ((abs_x - 1) & 0x30) + (((abs_y - 1) & 0x30) >> 2) + x_sign + y_sign
+    } else if abs_x < 769 && abs_y < 769 {
+        data.push((abs_x - 1) as u8);
+        data.push((abs_y - 1) as u8);
+        84 + 12 * (((abs_x - 1) & 0x300) >> 8) + (((abs_y - 1) & 0x300) >> 6) + x_sign + y_sign
+    } else if abs_x < 4096 && abs_y < 4096 {
+        data.push((abs_x >> 4) as u8);
+        data.push((((abs_x & 0xf) << 4) | (abs_y >> 8)) as u8);
+        data.push(abs_y as u8);
+        120 + x_sign + y_sign
+    } else {
+        data.extend((abs_x as u16).to_be_bytes());
+        data.extend((abs_y as u16).to_be_bytes());
+        124 + x_sign + y_sign
+    };
+    flag as u8 + on_curve_bit
+}
+
+/// The streams of a transformed `glyf` table
+#[derive(Default)]
+struct GlyfStreams {
+    n_contour: Vec<u8>,
+    n_points: Vec<u8>,
+    flag: Vec<u8>,
+    glyph: Vec<u8>,
+    composite: Vec<u8>,
+    bbox_bitmap: Vec<u8>,
+    bbox: Vec<u8>,
+    instruction: Vec<u8>,
+    overlap_bitmap: Vec<u8>,
+}
+
+/// Applies the WOFF2 `glyf` transform, returning the transformed table and
+/// the glyphs' minimum X coordinates.
+pub(crate) fn transform_glyf(
+    glyf: &[u8],
+    loca: &[u8],
+    index_format: uint16,
+    num_glyphs: uint16,
+) -> Result<(Vec<u8>, Vec<int16>), SerializationError> {
+    let offsets: Vec<usize> = if index_format == 0 {
+        loca.chunks_exact(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize * 2)
+            .collect()
+    } else {
+        loca.chunks_exact(4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as usize)
+            .collect()
+    };
+    if offsets.len() < num_glyphs as usize + 1 {
+        return Err(SerializationError("loca table is too short".to_string()));
+    }
+    let bitmap_size = 4 * (num_glyphs as usize).div_ceil(32);
+    let mut streams = GlyfStreams {
+        bbox_bitmap: vec![0; bitmap_size],
+        overlap_bitmap: vec![0; (num_glyphs as usize).div_ceil(8)],
+        ..Default::default()
+    };
+    let mut has_overlap = false;
+    let mut x_mins = Vec::with_capacity(num_glyphs as usize);
+
+    for (index, window) in offsets.windows(2).take(num_glyphs as usize).enumerate() {
+        let data = glyf
+            .get(window[0]..window[1])
+            .ok_or_else(|| SerializationError(format!("Glyph {} is out of range", index)))?;
+        let num_contours = data
+            .get(0..2)
+            .map_or(0, |b| i16::from_be_bytes([b[0], b[1]]));
+        if num_contours == 0 || data.len() < 10 {
+            streams.n_contour.put(0_i16)?;
+            x_mins.push(0);
+        } else if num_contours < 0 {
+            // Composite glyphs keep their component records, and always
+            // store their bounding box explicitly.
+            streams.n_contour.put(-1_i16)?;
+            set_bit(&mut streams.bbox_bitmap, index);
+            streams.bbox.extend_from_slice(&data[2..10]);
+            let (size, have_instructions) =
+                composite_size(&data[10..]).map_err(|e| SerializationError(e.0))?;
+            streams.composite.extend_from_slice(&data[10..10 + size]);
+            if have_instructions {
+                let instructions = data
+                    .get(10 + size + 2..)
+                    .ok_or_else(|| SerializationError("Truncated composite glyph".to_string()))?;
+                let length = u16::from_be_bytes([data[10 + size], data[11 + size]]);
+                write_255_u16(&mut streams.glyph, length);
+                streams
+                    .instruction
+                    .extend_from_slice(&instructions[..length as usize]);
+            }
+            x_mins.push(i16::from_be_bytes([data[2], data[3]]));
+        } else {
+            let glyph: Glyph = otspec::de::from_bytes(data).map_err(|e| SerializationError(e.0))?;
+            streams.n_contour.put(num_contours)?;
+            let (mut last_x, mut last_y) = (0, 0);
+            for contour in &glyph.contours {
+                write_255_u16(&mut streams.n_points, contour.len() as uint16);
+                for point in contour {
+                    let (x, y) = (point.x as i32, point.y as i32);
+                    let flag =
+                        write_triplet(x - last_x, y - last_y, point.on_curve, &mut streams.glyph);
+                    streams.flag.push(flag);
+                    last_x = x;
+                    last_y = y;
+                }
+            }
+            write_255_u16(&mut streams.glyph, glyph.instructions.len() as uint16);
+            streams.instruction.extend(&glyph.instructions);
+            if glyph.overlap {
+                set_bit(&mut streams.overlap_bitmap, index);
+                has_overlap = true;
+            }
+            if bounds(&glyph.contours) != (glyph.xMin, glyph.yMin, glyph.xMax, glyph.yMax) {
+                set_bit(&mut streams.bbox_bitmap, index);
+                streams.bbox.extend_from_slice(&data[2..10]);
+            }
+            x_mins.push(glyph.xMin);
+        }
+    }
+
+    let mut out = vec![];
+    out.put(GlyfTransformHeader {
+        reserved: 0,
+        optionFlags: if has_overlap {
+            OVERLAP_SIMPLE_BITMAP
+        } else {
+            0
+        },
+        numGlyphs: num_glyphs,
+        indexFormat: index_format,
+        nContourStreamSize: streams.n_contour.len() as uint32,
+        nPointsStreamSize: streams.n_points.len() as uint32,
+        flagStreamSize: streams.flag.len() as uint32,
+        glyphStreamSize: streams.glyph.len() as uint32,
+        compositeStreamSize: streams.composite.len() as uint32,
+        bboxStreamSize: (streams.bbox_bitmap.len() + streams.bbox.len()) as uint32,
+        instructionStreamSize: streams.instruction.len() as uint32,
+    })?;
+    out.extend(streams.n_contour);
+    out.extend(streams.n_points);
+    out.extend(streams.flag);
+    out.extend(streams.glyph);
+    out.extend(streams.composite);
+    out.extend(streams.bbox_bitmap);
+    out.extend(streams.bbox);
+    out.extend(streams.instruction);
+    if has_overlap {
+        out.extend(streams.overlap_bitmap);
+    }
+    Ok((out, x_mins))
+}
+
+/// Computes the bounding box of a list of contours.
+fn bounds(contours: &[Vec<Point>]) -> (int16, int16, int16, int16) {
+    let mut points = contours.iter().flatten().peekable();
+    if points.peek().is_none() {
+        return (0, 0, 0, 0);
+    }
+    points.fold(
+        (i16::MAX, i16::MAX, i16::MIN, i16::MIN),
+        |(x_min, y_min, x_max, y_max), p| {
+            (
+                x_min.min(p.x),
+                y_min.min(p.y),
+                x_max.max(p.x),
+                y_max.max(p.y),
+            )
+        },
+    )
+}
+
+/// Splits off the next stream of the given size from the transformed table.
+fn next_stream(
+    data: &[u8],
+    offset: &mut usize,
+    size: usize,
+) -> Result<ReaderContext, DeserializationError> {
+    let stream = data
+        .get(*offset..*offset + size)
+        .ok_or_else(|| DeserializationError("Truncated transformed glyf table".to_string()))?;
+    *offset += size;
+    Ok(ReaderContext::new(stream.to_vec()))
+}
+
+/// Reconstructs the `glyf` and `loca` tables from a transformed `glyf` table.
+pub(crate) fn reconstruct_glyf(data: &[u8]) -> Result<ReconstructedGlyf, DeserializationError> {
+    let header: GlyfTransformHeader = otspec::de::from_bytes(&data[..HEADER_SIZE.min(data.len())])?;
+    let num_glyphs = header.numGlyphs as usize;
+    let mut offset = HEADER_SIZE;
+    let mut n_contour = next_stream(data, &mut offset, header.nContourStreamSize as usize)?;
+    let mut n_points = next_stream(data, &mut offset, header.nPointsStreamSize as usize)?;
+    let mut flag = next_stream(data, &mut offset, header.flagStreamSize as usize)?;
+    let mut glyph_stream = next_stream(data, &mut offset, header.glyphStreamSize as usize)?;
+    let composite = next_stream(data, &mut offset, header.compositeStreamSize as usize)?;
+    let mut bbox = next_stream(data, &mut offset, header.bboxStreamSize as usize)?;
+    let mut instruction = next_stream(data, &mut offset, header.instructionStreamSize as usize)?;
+    let overlap_bitmap = if header.optionFlags & OVERLAP_SIMPLE_BITMAP != 0 {
+        next_stream(data, &mut offset, num_glyphs.div_ceil(8))?.input
+    } else {
+        vec![]
+    };
+    let bbox_bitmap: Vec<u8> = bbox.de_counted(4 * num_glyphs.div_ceil(32))?;
+    let mut composite_ptr = 0;
+
+    let mut glyf: Vec<u8> = vec![];
+    let mut loca_offsets: Vec<u32> = vec![];
+    let mut x_mins = Vec::with_capacity(num_glyphs);
+    for index in 0..num_glyphs {
+        loca_offsets.push(glyf.len() as u32);
+        let num_contours: i16 = n_contour.de()?;
+        let has_bbox = bit_is_set(&bbox_bitmap, index);
+        let mut glyph_data: Vec<u8> = vec![];
+        if num_contours == 0 {
+            if has_bbox {
+                return Err(DeserializationError(format!(
+                    "Empty glyph {} has a bounding box",
+                    index
+                )));
+            }
+            x_mins.push(0);
+        } else if num_contours == -1 {
+            if !has_bbox {
+                return Err(DeserializationError(format!(
+                    "Composite glyph {} has no bounding box",
+                    index
+                )));
+            }
+            let glyph_bbox: Vec<int16> = bbox.de_counted(4)?;
+            let (size, have_instructions) = composite_size(&composite.input[composite_ptr..])?;
+            glyph_data.extend(num_contours.to_be_bytes());
+            glyph_data.extend(glyph_bbox.iter().flat_map(|v| v.to_be_bytes()));
+            glyph_data.extend_from_slice(&composite.input[composite_ptr..composite_ptr + size]);
+            composite_ptr += size;
+            if have_instructions {
+                let length = read_255_u16(&mut glyph_stream)?;
+                let instructions: Vec<u8> = instruction.de_counted(length as usize)?;
+                glyph_data.extend(length.to_be_bytes());
+                glyph_data.extend(instructions);
+            }
+            x_mins.push(glyph_bbox[0]);
+        } else if num_contours > 0 {
+            let point_counts = (0..num_contours)
+                .map(|_| read_255_u16(&mut n_points))
+                .collect::<Result<Vec<uint16>, DeserializationError>>()?;
+            let (mut x, mut y) = (0, 0);
+            let mut contours = vec![];
+            for count in point_counts {
+                let mut contour = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    let point_flag: u8 = flag.de()?;
+                    let (dx, dy) = read_triplet(point_flag, &mut glyph_stream)?;
+                    x += dx;
+                    y += dy;
+                    contour.push(Point {
+                        x: x as int16,
+                        y: y as int16,
+                        on_curve: point_flag & 0x80 == 0,
+                    });
+                }
+                contours.push(contour);
+            }
+            let instruction_length = read_255_u16(&mut glyph_stream)?;
+            let instructions: Vec<u8> = instruction.de_counted(instruction_length as usize)?;
+            let (x_min, y_min, x_max, y_max) = if has_bbox {
+                let explicit: Vec<int16> = bbox.de_counted(4)?;
+                (explicit[0], explicit[1], explicit[2], explicit[3])
+            } else {
+                bounds(&contours)
+            };
+            let glyph = Glyph {
+                xMin: x_min,
+                yMin: y_min,
+                xMax: x_max,
+                yMax: y_max,
+                contours,
+                instructions,
+                components: vec![],
+                overlap: false,
+            };
+            glyph_data = otspec::ser::to_bytes(&glyph)
+                .map_err(|e| DeserializationError(format!("Couldn't build glyph: {:?}", e)))?;
+            if bit_is_set(&overlap_bitmap, index) {
+                // The flag goes on the first point, after the end points,
+                // and the instructions with their length
+                let first_flag = 10 + 2 * num_contours as usize + 2 + instruction_length as usize;
+                glyph_data[first_flag] |= OVERLAP_SIMPLE;
+            }
+            x_mins.push(x_min);
+        } else {
+            return Err(DeserializationError(format!(
+                "Bad contour count {} for glyph {}",
+                num_contours, index
+            )));
+        }
+        glyf.extend(glyph_data);
+        pad4(&mut glyf);
+    }
+    loca_offsets.push(glyf.len() as u32);
+
+    let loca: Vec<u8> = if header.indexFormat == 0 {
+        loca_offsets
+            .iter()
+            .flat_map(|&offset| ((offset / 2) as uint16).to_be_bytes())
+            .collect()
+    } else {
+        loca_offsets.iter().flat_map(|o| o.to_be_bytes()).collect()
+    };
+    Ok(ReconstructedGlyf { glyf, loca, x_mins })
+}
+
+/// Applies the WOFF2 `hmtx` transform, which drops left side bearings that
+/// are equal to the glyphs' minimum X coordinates. Returns `None` if no side
+/// bearings can be dropped.
+pub(crate) fn transform_hmtx(
+    hmtx: &[u8],
+    num_h_metrics: uint16,
+    x_mins: &[int16],
+) -> Option<Vec<u8>> {
+    let num_h_metrics = num_h_metrics as usize;
+    let num_glyphs = x_mins.len();
+    if num_h_metrics == 0 || num_h_metrics > num_glyphs {
+        return None;
+    }
+    let mut c = ReaderContext::new(hmtx.to_vec());
+    let mut advances: Vec<uint16> = Vec::with_capacity(num_h_metrics);
+    let mut lsbs: Vec<int16> = Vec::with_capacity(num_glyphs);
+    for _ in 0..num_h_metrics {
+        advances.push(c.de().ok()?);
+        lsbs.push(c.de().ok()?);
+    }
+    for _ in num_h_metrics..num_glyphs {
+        lsbs.push(c.de().ok()?);
+    }
+    let proportional_matches = lsbs[..num_h_metrics] == x_mins[..num_h_metrics];
+    let monospace_matches = lsbs[num_h_metrics..] == x_mins[num_h_metrics..];
+    if !proportional_matches && !monospace_matches {
+        return None;
+    }
+
+    let mut out = vec![u8::from(proportional_matches) | u8::from(monospace_matches) << 1];
+    out.put(advances).ok()?;
+    if !proportional_matches {
+        out.put(lsbs[..num_h_metrics].to_vec()).ok()?;
+    }
+    if !monospace_matches {
+        out.put(lsbs[num_h_metrics..].to_vec()).ok()?;
+    }
+    Some(out)
+}
+
+/// Reconstructs the `hmtx` table from a transformed `hmtx` table.
+pub(crate) fn reconstruct_hmtx(
+    data: &[u8],
+    num_h_metrics: uint16,
+    x_mins: &[int16],
+) -> Result<Vec<u8>, DeserializationError> {
+    let num_h_metrics = num_h_metrics as usize;
+    let num_glyphs = x_mins.len();
+    if num_h_metrics == 0 || num_h_metrics > num_glyphs {
+        return Err(DeserializationError(
+            "Bad number of horizontal metrics".to_string(),
+        ));
+    }
+    let mut c = ReaderContext::new(data.to_vec());
+    let flags: u8 = c.de()?;
+    if flags & 0xfc != 0 {
+        return Err(DeserializationError(
+            "Reserved hmtx transform flags are set".to_string(),
+        ));
+    }
+    let advances: Vec<uint16> = c.de_counted(num_h_metrics)?;
+    let lsbs: Vec<int16> = if flags & 1 != 0 {
+        x_mins[..num_h_metrics].to_vec()
+    } else {
+        c.de_counted(num_h_metrics)?
+    };
+    let left_side_bearings: Vec<int16> = if flags & 2 != 0 {
+        x_mins[num_h_metrics..].to_vec()
+    } else {
+        c.de_counted(num_glyphs - num_h_metrics)?
+    };
+    let mut out = vec![];
+    for (advance, lsb) in advances.iter().zip(lsbs.iter()) {
+        out.extend(advance.to_be_bytes());
+        out.extend(lsb.to_be_bytes());
+    }
+    out.extend(left_side_bearings.iter().flat_map(|v| v.to_be_bytes()));
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_255_u16() {
+        for value in [0, 252, 253, 505, 506, 761, 762, 65535] {
+            let mut data = vec![];
+            write_255_u16(&mut data, value);
+            let mut c = ReaderContext::new(data);
+            assert_eq!(read_255_u16(&mut c).unwrap(), value);
+        }
+        let mut data = vec![];
+        write_255_u16(&mut data, 506);
+        assert_eq!(data, vec![254, 0]);
+    }
+
+    #[test]
+    fn test_triplets() {
+        for &(dx, dy) in &[
+            (0, 0),
+            (0, -1279),
+            (1000, 0),
+            (-64, 64),
+            (768, -1),
+            (-4095, 4000),
+            (30000, -30000),
+            (1, 0),
+        ] {
+            for on_curve in [true, false] {
+                let mut data = vec![];
+                let flag = write_triplet(dx, dy, on_curve, &mut data);
+                assert_eq!(flag & 0x80 == 0, on_curve);
+                let mut c = ReaderContext::new(data.clone());
+                assert_eq!(read_triplet(flag, &mut c).unwrap(), (dx, dy));
+                assert_eq!(c.ptr, data.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_glyf_transform_overlap() {
+        let glyph = Glyph {
+            xMin: 0,
+            yMin: 0,
+            xMax: 100,
+            yMax: 100,
+            contours: vec![vec![
+                Point {
+                    x: 0,
+                    y: 0,
+                    on_curve: true,
+                },
+                Point {
+                    x: 100,
+                    y: 100,
+                    on_curve: true,
+                },
+                Point {
+                    x: 100,
+                    y: 0,
+                    on_curve: true,
+                },
+            ]],
+            instructions: vec![0xb0, 0x00],
+            components: vec![],
+            overlap: false,
+        };
+        let mut glyf = otspec::ser::to_bytes(&glyph).unwrap();
+        glyf[10 + 2 + 2 + 2] |= OVERLAP_SIMPLE;
+        pad4(&mut glyf);
+        let loca: Vec<u8> = [0_u32, glyf.len() as u32]
+            .iter()
+            .flat_map(|o| o.to_be_bytes())
+            .collect();
+        let (transformed, x_mins) = transform_glyf(&glyf, &loca, 1, 1).unwrap();
+        assert_eq!(x_mins, vec![0]);
+        // The overlap bitmap is present and the bounding box is implied
+        assert_eq!(transformed[3], 1);
+        assert_eq!(transformed[transformed.len() - 1], 0x80);
+
+        let reconstructed = reconstruct_glyf(&transformed).unwrap();
+        assert_eq!(reconstructed.glyf, glyf);
+        assert_eq!(reconstructed.loca, loca);
+        let roundtrip: Glyph = otspec::de::from_bytes(&reconstructed.glyf).unwrap();
+        assert!(roundtrip.overlap);
+    }
+}
@@ -0,0 +1,396 @@
+#!/usr/bin/env python3
+"""Generates src/data.rs from the Unicode character database.
+
+The glyph names and production names of Latin, Greek and Cyrillic glyphs and
+common symbols are curated here. Those of the scripts in SUFFIXED_SCRIPTS are
+made from the character names, with Glyphs' script suffixes ("alef-ar").
+Categories come from the Unicode general category of each code point, and
+scripts from the character names. Run with the Python whose Unicode version you want to use:
+
+    python3 generate.py > src/data.rs
+"""
+import sys
+import unicodedata
+
+ASCII = [
+    "space", "exclam", "quotedbl", "numbersign", "dollar", "percent",
+    "ampersand", "quotesingle", "parenleft", "parenright", "asterisk", "plus",
+    "comma", "hyphen", "period", "slash", "zero", "one", "two", "three",
+    "four", "five", "six", "seven", "eight", "nine", "colon", "semicolon",
+    "less", "equal", "greater", "question", "at",
+] + [chr(c) for c in range(0x41, 0x5B)] + [
+    "bracketleft", "backslash", "bracketright", "asciicircum", "underscore",
+    "grave",
+] + [chr(c) for c in range(0x61, 0x7B)] + [
+    "braceleft", "bar", "braceright", "asciitilde",
+]
+
+LATIN1 = [
+    "nbspace", "exclamdown", "cent", "sterling", "currency", "yen",
+    "brokenbar", "section", "dieresis", "copyright", "ordfeminine",
+    "guillemetleft", "logicalnot", "softhyphen", "registered", "macron",
+    "degree", "plusminus", "twosuperior", "threesuperior", "acute", "micro",
+    "paragraph", "periodcentered", "cedilla", "onesuperior", "ordmasculine",
+    "guillemetright", "onequarter", "onehalf", "threequarters",
+    "questiondown", "Agrave", "Aacute", "Acircumflex", "Atilde", "Adieresis",
+    "Aring", "AE", "Ccedilla", "Egrave", "Eacute", "Ecircumflex", "Edieresis",
+    "Igrave", "Iacute", "Icircumflex", "Idieresis", "Eth", "Ntilde", "Ograve",
+    "Oacute", "Ocircumflex", "Otilde", "Odieresis", "multiply", "Oslash",
+    "Ugrave", "Uacute", "Ucircumflex", "Udieresis", "Yacute", "Thorn",
+    "germandbls", "agrave", "aacute", "acircumflex", "atilde", "adieresis",
+    "aring", "ae", "ccedilla", "egrave", "eacute", "ecircumflex", "edieresis",
+    "igrave", "iacute", "icircumflex", "idieresis", "eth", "ntilde", "ograve",
+    "oacute", "ocircumflex", "otilde", "odieresis", "divide", "oslash",
+    "ugrave", "uacute", "ucircumflex", "udieresis", "yacute", "thorn",
+    "ydieresis",
+]
+
+# Nice names whose production names differ from both the name and uniXXXX
+PRODUCTION = {
+    "guillemetleft": "guillemotleft",
+    "guillemetright": "guillemotright",
+    "sigmafinal": "sigma1",
+}
+
+# Names which are not AGL names, so get uniXXXX production names
+NOT_AGL = {"nbspace", "softhyphen", "micro", "Tcedilla", "tcedilla"}
+
+ACCENTS = {
+    "ACUTE": "acute",
+    "GRAVE": "grave",
+    "CIRCUMFLEX": "circumflex",
+    "TILDE": "tilde",
+    "DIAERESIS": "dieresis",
+    "RING ABOVE": "ring",
+    "CEDILLA": "cedilla",
+    "MACRON": "macron",
+    "BREVE": "breve",
+    "OGONEK": "ogonek",
+    "DOT ABOVE": "dotaccent",
+    "CARON": "caron",
+    "DOUBLE ACUTE": "hungarumlaut",
+    "HOOK ABOVE": "hookabove",
+    "DOT BELOW": "dotbelow",
+    "HORN": "horn",
+    "COMMA BELOW": "commaaccent",
+    "LINE BELOW": "linebelow",
+    "MIDDLE DOT": "dot",
+    "STROKE": "stroke",
+    "DOUBLE GRAVE": "dblgrave",
+    "INVERTED BREVE": "breveinverted",
+    "RING BELOW": "ringbelow",
+    "CIRCUMFLEX BELOW": "circumflexbelow",
+    "TILDE BELOW": "tildebelow",
+    "BREVE BELOW": "brevebelow",
+    "DIAERESIS BELOW": "dieresisbelow",
+    "HOOK": "hook",
+}
+
+LATIN_SPECIAL = {
+    0x0110: "Dcroat", 0x0111: "dcroat", 0x0126: "Hbar", 0x0127: "hbar",
+    0x0131: "dotlessi", 0x0132: "IJ", 0x0133: "ij", 0x0138: "kgreenlandic",
+    0x0141: "Lslash", 0x0142: "lslash", 0x0149: "napostrophe",
+    0x014A: "Eng", 0x014B: "eng", 0x0152: "OE", 0x0153: "oe",
+    0x0166: "Tbar", 0x0167: "tbar", 0x017F: "longs", 0x0237: "dotlessj",
+    0x0122: "Gcommaaccent", 0x0123: "gcommaaccent", 0x0136: "Kcommaaccent",
+    0x0137: "kcommaaccent", 0x013B: "Lcommaaccent", 0x013C: "lcommaaccent",
+    0x0145: "Ncommaaccent", 0x0146: "ncommaaccent", 0x0156: "Rcommaaccent",
+    0x0157: "rcommaaccent", 0x0218: "Scommaaccent", 0x0219: "scommaaccent",
+    0x021A: "Tcommaaccent", 0x021B: "tcommaaccent",
+}
+
+GREEK_LETTERS = {"LAMDA": "lambda", "FINAL SIGMA": "sigmafinal"}
+GREEK_ACCENTS = {
+    "TONOS": "tonos",
+    "DIALYTIKA": "dieresis",
+    "DIALYTIKA AND TONOS": "dieresistonos",
+}
+# Greek letters whose AGL names belong to other code points
+GREEK_NOT_AGL = {"Delta", "Omega", "mu"}
+
+CYRILLIC = [
+    "Iegrave", "Io", "Dje", "Gje", "E", "Dze", "I", "Yi", "Je", "Lje", "Nje",
+    "Tshe", "Kje", "Igrave", "Ushort", "Dzhe", "A", "Be", "Ve", "Ge", "De",
+    "Ie", "Zhe", "Ze", "Ii", "Iishort", "Ka", "El", "Em", "En", "O", "Pe",
+    "Er", "Es", "Te", "U", "Ef", "Ha", "Tse", "Che", "Sha", "Shcha",
+    "Hardsign", "Yeru", "Softsign", "Ereversed", "Iu", "Ia",
+]
+
+# Blocks whose characters are named after their Unicode names, as the
+# character name prefixes and the suffix of the glyph names
+SUFFIXED_SCRIPTS = [
+    (0x0590, 0x05FF, ("HEBREW",), "hb"),
+    (0x0600, 0x06FF, ("ARABIC", "ARABIC-INDIC"), "ar"),
+    (0x0900, 0x097F, ("DEVANAGARI",), "deva"),
+    (0x0980, 0x09FF, ("BENGALI",), "beng"),
+    (0x0A00, 0x0A7F, ("GURMUKHI",), "guru"),
+    (0x0A80, 0x0AFF, ("GUJARATI",), "gujr"),
+    (0x0B00, 0x0B7F, ("ORIYA",), "orya"),
+    (0x0B80, 0x0BFF, ("TAMIL",), "taml"),
+    (0x0C00, 0x0C7F, ("TELUGU",), "telu"),
+    (0x0C80, 0x0CFF, ("KANNADA",), "knda"),
+    (0x0D00, 0x0D7F, ("MALAYALAM",), "mlym"),
+    (0x0E00, 0x0E7F, ("THAI",), "thai"),
+    (0x0E80, 0x0EFF, ("LAO",), "lao"),
+]
+# Words of character names which are left out of glyph names
+DROPPED_WORDS = {
+    "LETTER", "SIGN", "POINT", "CHARACTER", "DIGIT", "PUNCTUATION",
+    "WITH",
+}
+
+MARKS = {
+    0x0300: "gravecomb", 0x0301: "acutecomb", 0x0302: "circumflexcomb",
+    0x0303: "tildecomb", 0x0304: "macroncomb", 0x0306: "brevecomb",
+    0x0307: "dotaccentcomb", 0x0308: "dieresiscomb", 0x0309: "hookabovecomb",
+    0x030A: "ringcomb", 0x030B: "hungarumlautcomb", 0x030C: "caroncomb",
+    0x030F: "dblgravecomb", 0x0311: "breveinvertedcomb",
+    0x0312: "commaturnedabovecomb", 0x031B: "horncomb",
+    0x0323: "dotbelowcomb", 0x0324: "dieresisbelowcomb",
+    0x0325: "ringbelowcomb", 0x0326: "commaaccentcomb",
+    0x0327: "cedillacomb", 0x0328: "ogonekcomb", 0x032E: "brevebelowcomb",
+    0x0330: "tildebelowcomb", 0x0331: "macronbelowcomb",
+    0x0335: "strokeshortcomb", 0x0337: "slashshortcomb",
+    0x0338: "slashlongcomb",
+}
+AGL_MARKS = {"gravecomb", "acutecomb", "tildecomb", "hookabovecomb", "dotbelowcomb"}
+
+OTHERS = {
+    0x02C6: "circumflex", 0x02C7: "caron", 0x02D8: "breve",
+    0x02D9: "dotaccent", 0x02DA: "ring", 0x02DB: "ogonek", 0x02DC: "tilde",
+    0x02DD: "hungarumlaut", 0x2013: "endash", 0x2014: "emdash",
+    0x2018: "quoteleft", 0x2019: "quoteright", 0x201A: "quotesinglbase",
+    0x201C: "quotedblleft", 0x201D: "quotedblright",
+    0x201E: "quotedblbase", 0x2020: "dagger", 0x2021: "daggerdbl",
+    0x2022: "bullet", 0x2026: "ellipsis", 0x2030: "perthousand",
+    0x2039: "guilsinglleft", 0x203A: "guilsinglright", 0x2044: "fraction",
+    0x20AC: "Euro", 0x2122: "trademark", 0x2190: "arrowleft",
+    0x2191: "arrowup", 0x2192: "arrowright", 0x2193: "arrowdown",
+    0x2202: "partialdiff", 0x220F: "product", 0x2211: "summation",
+    0x2212: "minus", 0x221A: "radical", 0x221E: "infinity",
+    0x222B: "integral", 0x2248: "approxequal", 0x2260: "notequal",
+    0x2264: "lessequal", 0x2265: "greaterequal", 0x25CA: "lozenge",
+    0xFB01: "fi", 0xFB02: "fl",
+}
+NOT_AGL_OTHERS = {0x2191, 0x2193}
+
+GENERAL_CATEGORIES = {
+    "Lu": ("Letter", "Uppercase"),
+    "Lt": ("Letter", "Uppercase"),
+    "Ll": ("Letter", "Lowercase"),
+    "Lm": ("Letter", "Modifier"),
+    "Lo": ("Letter", None),
+    "Mn": ("Mark", "Nonspacing"),
+    "Mc": ("Mark", "SpacingCombining"),
+    "Me": ("Mark", "Enclosing"),
+    "Nd": ("Number", "DecimalDigit"),
+    "Nl": ("Number", "Letter"),
+    "No": ("Number", None),
+    "Pc": ("Punctuation", "Connector"),
+    "Pd": ("Punctuation", "Dash"),
+    "Ps": ("Punctuation", "Parenthesis"),
+    "Pe": ("Punctuation", "Parenthesis"),
+    "Pi": ("Punctuation", "Quote"),
+    "Pf": ("Punctuation", "Quote"),
+    "Po": ("Punctuation", None),
+    "Sm": ("Symbol", "Math"),
+    "Sc": ("Symbol", "Currency"),
+    "Sk": ("Symbol", "Modifier"),
+    "So": ("Symbol", None),
+    "Zs": ("Separator", "Space"),
+    "Zl": ("Separator", None),
+    "Zp": ("Separator", None),
+    "Cc": ("Other", "Format"),
+    "Cf": ("Other", "Format"),
+    "Co": ("Other", None),
+}
+
+# Character name prefixes which identify a script, and the script's name
+SCRIPTS = [
+    ("LATIN", "latin"), ("GREEK", "greek"), ("COPTIC", "coptic"),
+    ("CYRILLIC", "cyrillic"), ("ARMENIAN", "armenian"), ("HEBREW", "hebrew"),
+    ("ARABIC", "arabic"), ("SYRIAC", "syriac"), ("THAANA", "thaana"),
+    ("NKO", "nko"), ("SAMARITAN", "samaritan"), ("MANDAIC", "mandaic"),
+    ("DEVANAGARI", "devanagari"), ("BENGALI", "bengali"),
+    ("GURMUKHI", "gurmukhi"), ("GUJARATI", "gujarati"), ("ORIYA", "oriya"),
+    ("TAMIL", "tamil"), ("TELUGU", "telugu"), ("KANNADA", "kannada"),
+    ("MALAYALAM", "malayalam"), ("SINHALA", "sinhala"), ("THAI", "thai"),
+    ("LAO", "lao"), ("TIBETAN", "tibetan"), ("MYANMAR", "myanmar"),
+    ("GEORGIAN", "georgian"), ("HANGUL", "korean"), ("ETHIOPIC", "ethiopic"),
+    ("CHEROKEE", "cherokee"), ("CANADIAN SYLLABICS", "canadian"),
+    ("OGHAM", "ogham"), ("RUNIC", "runic"), ("KHMER", "khmer"),
+    ("MONGOLIAN", "mongolian"), ("HIRAGANA", "hiragana"),
+    ("KATAKANA", "katakana"), ("BOPOMOFO", "bopomofo"), ("CJK", "han"),
+    ("YI", "yi"), ("TIFINAGH", "tifinagh"), ("JAVANESE", "javanese"),
+    ("BALINESE", "balinese"), ("SUNDANESE", "sundanese"),
+]
+
+
+def general_category(cp):
+    return GENERAL_CATEGORIES.get(unicodedata.category(chr(cp)))
+
+
+def script(cp):
+    name = unicodedata.name(chr(cp), "")
+    if name.startswith("CJK UNIFIED IDEOGRAPH"):
+        return "han"
+    for prefix, script in SCRIPTS:
+        if name.startswith(prefix + " "):
+            return script
+    return None
+
+
+def latin_name(cp):
+    if cp in LATIN_SPECIAL:
+        return LATIN_SPECIAL[cp]
+    name = unicodedata.name(chr(cp), "")
+    for case in ("CAPITAL", "SMALL"):
+        prefix = "LATIN %s LETTER " % case
+        if not name.startswith(prefix):
+            continue
+        rest = name[len(prefix):].split(" WITH ")
+        if len(rest) != 2 or len(rest[0]) != 1:
+            return None
+        letter = rest[0] if case == "CAPITAL" else rest[0].lower()
+        accents = []
+        for accent in rest[1].split(" AND "):
+            if accent not in ACCENTS:
+                return None
+            accents.append(ACCENTS[accent])
+        return letter + "".join(accents)
+    return None
+
+
+def greek_name(cp):
+    name = unicodedata.name(chr(cp), "")
+    for case in ("CAPITAL", "SMALL"):
+        prefix = "GREEK %s LETTER " % case
+        if not name.startswith(prefix):
+            continue
+        rest = name[len(prefix):].split(" WITH ")
+        letter = GREEK_LETTERS.get(rest[0], rest[0].lower())
+        if " " in letter:
+            return None
+        if case == "CAPITAL":
+            letter = letter.capitalize()
+        if len(rest) == 2:
+            if rest[1] not in GREEK_ACCENTS:
+                return None
+            letter += GREEK_ACCENTS[rest[1]]
+        return letter
+    return None
+
+
+def suffixed_name(cp, prefixes, suffix):
+    """A name made from the character name, e.g. "tehMarbuta-ar". Indic vowel
+    signs are named as matras, e.g. "aaMatra-deva"."""
+    name = unicodedata.name(chr(cp), "")
+    prefix = next((p for p in prefixes if name.startswith(p + " ")), None)
+    if prefix is None:
+        return None
+    rest = name[len(prefix) + 1:]
+    matra = rest.startswith("VOWEL SIGN ") and suffix not in ("ar", "hb")
+    if matra:
+        rest = rest[len("VOWEL SIGN "):]
+    words = [w for w in rest.replace("-", " ").split() if w not in DROPPED_WORDS]
+    if matra:
+        words.append("MATRA")
+    if not words:
+        return None
+    return words[0].lower() + "".join(w.capitalize() for w in words[1:]) + "-" + suffix
+
+
+def uni_name(cp):
+    return "uni%04X" % cp if cp <= 0xFFFF else "u%04X" % cp
+
+
+def glyphs():
+    """(name, codepoint, production name) of each named glyph"""
+    names = []
+    for cp, name in enumerate(ASCII, 0x20):
+        names.append((name, cp, name))
+    for cp, name in enumerate(LATIN1, 0xA0):
+        names.append((name, cp, name))
+    for cp in list(range(0x0100, 0x0250)) + list(range(0x1E00, 0x1F00)):
+        name = latin_name(cp)
+        if not name:
+            continue
+        agl = cp < 0x0180 or cp in (0x0218, 0x0219) or 0x1E80 <= cp <= 0x1E85
+        agl = agl or cp in (0x1EF2, 0x1EF3)
+        names.append((name, cp, name if agl else uni_name(cp)))
+    for cp in range(0x0386, 0x03CF):
+        name = greek_name(cp)
+        if name:
+            names.append((name, cp, uni_name(cp) if name in GREEK_NOT_AGL else name))
+    for cp, name in enumerate(CYRILLIC, 0x0400):
+        names.append((name + "-cy", cp, uni_name(cp)))
+        names.append((name.lower() + "-cy", cp + 0x50 if cp < 0x0410 else cp + 0x20, None))
+    for cp, name in list(MARKS.items()) + list(OTHERS.items()):
+        agl = name in AGL_MARKS or (cp not in MARKS and cp not in NOT_AGL_OTHERS)
+        names.append((name, cp, name if agl else uni_name(cp)))
+    for start, end, prefixes, suffix in SUFFIXED_SCRIPTS:
+        for cp in range(start, end + 1):
+            name = suffixed_name(cp, prefixes, suffix)
+            if name:
+                names.append((name, cp, uni_name(cp)))
+
+    seen = set()
+    for name, cp, production in names:
+        if name in seen:
+            continue
+        seen.add(name)
+        if production is None or name in NOT_AGL:
+            production = uni_name(cp)
+        production = PRODUCTION.get(name, production)
+        category, subcategory = general_category(cp)
+        if "LIGATURE" in unicodedata.name(chr(cp), ""):
+            subcategory = "Ligature"
+        yield name, cp, production, category, subcategory
+
+
+def ranges(values):
+    """Runs of code points with the same value. Unassigned code points have
+    no value, so end a run."""
+    run = None
+    for cp in range(0x110000):
+        value = values(cp)
+        if run and run[2] == value:
+            run[1] = cp
+            continue
+        if run and run[2] is not None:
+            yield run
+        run = [cp, cp, value]
+    if run and run[2] is not None:
+        yield run
+
+
+def subcategory(value):
+    return "Some(Subcategory::%s)" % value if value else "None"
+
+
+def main():
+    out = sys.stdout
+    out.write("// Generated by generate.py from Unicode %s; do not edit.\n" % unicodedata.unidata_version)
+    out.write("use crate::{Category, Subcategory};\n\n")
+    out.write("/// Glyph name, code point, production name, category and subcategory\n")
+    out.write("#[rustfmt::skip]\n")
+    out.write("pub(crate) static GLYPHS: &[(&str, u32, &str, Category, Option<Subcategory>)] = &[\n")
+    for name, cp, production, category, sub in sorted(glyphs()):
+        out.write('    ("%s", 0x%04X, "%s", Category::%s, %s),\n' % (name, cp, production, category, subcategory(sub)))
+    out.write("];\n\n")
+    out.write("/// Ranges of code points with the same general category\n")
+    out.write("#[rustfmt::skip]\n")
+    out.write("pub(crate) static CATEGORIES: &[(u32, u32, Category, Option<Subcategory>)] = &[\n")
+    for start, end, (category, sub) in ranges(general_category):
+        out.write("    (0x%04X, 0x%04X, Category::%s, %s),\n" % (start, end, category, subcategory(sub)))
+    out.write("];\n\n")
+    out.write("/// Ranges of code points in the same script\n")
+    out.write("#[rustfmt::skip]\n")
+    out.write("pub(crate) static SCRIPTS: &[(u32, u32, &str)] = &[\n")
+    for start, end, value in ranges(script):
+        out.write('    (0x%04X, 0x%04X, "%s"),\n' % (start, end, value))
+    out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,5843 @@
+// Generated by generate.py from Unicode 14.0.0; do not edit.
+use crate::{Category, Subcategory};
+
+/// Glyph name, code point, production name, category and subcategory
+#[rustfmt::skip]
+pub(crate) static GLYPHS: &[(&str, u32, &str, Category, Option<Subcategory>)] = &[
+    ("A", 0x0041, "A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("A-cy", 0x0410, "uni0410", Category::Letter, Some(Subcategory::Uppercase)),
+    ("AE", 0x00C6, "AE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Aacute", 0x00C1, "Aacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abreve", 0x0102, "Abreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abreveacute", 0x1EAE, "uni1EAE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abrevedotbelow", 0x1EB6, "uni1EB6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abrevegrave", 0x1EB0, "uni1EB0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abrevehookabove", 0x1EB2, "uni1EB2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abreveinverted", 0x0202, "uni0202", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Abrevetilde", 0x1EB4, "uni1EB4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acaron", 0x01CD, "uni01CD", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflex", 0x00C2, "Acircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflexacute", 0x1EA4, "uni1EA4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflexdotbelow", 0x1EAC, "uni1EAC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflexgrave", 0x1EA6, "uni1EA6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflexhookabove", 0x1EA8, "uni1EA8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Acircumflextilde", 0x1EAA, "uni1EAA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adblgrave", 0x0200, "uni0200", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adieresis", 0x00C4, "Adieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adieresismacron", 0x01DE, "uni01DE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adotaccent", 0x0226, "uni0226", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adotaccentmacron", 0x01E0, "uni01E0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Adotbelow", 0x1EA0, "uni1EA0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Agrave", 0x00C0, "Agrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ahookabove", 0x1EA2, "uni1EA2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Alpha", 0x0391, "Alpha", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Alphatonos", 0x0386, "Alphatonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Amacron", 0x0100, "Amacron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Aogonek", 0x0104, "Aogonek", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Aring", 0x00C5, "Aring", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Aringacute", 0x01FA, "uni01FA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Aringbelow", 0x1E00, "uni1E00", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Astroke", 0x023A, "uni023A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Atilde", 0x00C3, "Atilde", Category::Letter, Some(Subcategory::Uppercase)),
+    ("B", 0x0042, "B", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Bdotaccent", 0x1E02, "uni1E02", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Bdotbelow", 0x1E04, "uni1E04", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Be-cy", 0x0411, "uni0411", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Beta", 0x0392, "Beta", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Bhook", 0x0181, "uni0181", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Blinebelow", 0x1E06, "uni1E06", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Bstroke", 0x0243, "uni0243", Category::Letter, Some(Subcategory::Uppercase)),
+    ("C", 0x0043, "C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Cacute", 0x0106, "Cacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ccaron", 0x010C, "Ccaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ccedilla", 0x00C7, "Ccedilla", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ccedillaacute", 0x1E08, "uni1E08", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ccircumflex", 0x0108, "Ccircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Cdotaccent", 0x010A, "Cdotaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Che-cy", 0x0427, "uni0427", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Chi", 0x03A7, "Chi", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Chook", 0x0187, "uni0187", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Cstroke", 0x023B, "uni023B", Category::Letter, Some(Subcategory::Uppercase)),
+    ("D", 0x0044, "D", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dcaron", 0x010E, "Dcaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dcedilla", 0x1E10, "uni1E10", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dcircumflexbelow", 0x1E12, "uni1E12", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dcroat", 0x0110, "Dcroat", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ddotaccent", 0x1E0A, "uni1E0A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ddotbelow", 0x1E0C, "uni1E0C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("De-cy", 0x0414, "uni0414", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Delta", 0x0394, "uni0394", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dhook", 0x018A, "uni018A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dje-cy", 0x0402, "uni0402", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dlinebelow", 0x1E0E, "uni1E0E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dze-cy", 0x0405, "uni0405", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Dzhe-cy", 0x040F, "uni040F", Category::Letter, Some(Subcategory::Uppercase)),
+    ("E", 0x0045, "E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("E-cy", 0x0404, "uni0404", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Eacute", 0x00C9, "Eacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ebreve", 0x0114, "Ebreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ebreveinverted", 0x0206, "uni0206", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecaron", 0x011A, "Ecaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecedilla", 0x0228, "uni0228", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecedillabreve", 0x1E1C, "uni1E1C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflex", 0x00CA, "Ecircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflexacute", 0x1EBE, "uni1EBE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflexbelow", 0x1E18, "uni1E18", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflexdotbelow", 0x1EC6, "uni1EC6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflexgrave", 0x1EC0, "uni1EC0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflexhookabove", 0x1EC2, "uni1EC2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ecircumflextilde", 0x1EC4, "uni1EC4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Edblgrave", 0x0204, "uni0204", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Edieresis", 0x00CB, "Edieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Edotaccent", 0x0116, "Edotaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Edotbelow", 0x1EB8, "uni1EB8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ef-cy", 0x0424, "uni0424", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Egrave", 0x00C8, "Egrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ehookabove", 0x1EBA, "uni1EBA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("El-cy", 0x041B, "uni041B", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Em-cy", 0x041C, "uni041C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Emacron", 0x0112, "Emacron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Emacronacute", 0x1E16, "uni1E16", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Emacrongrave", 0x1E14, "uni1E14", Category::Letter, Some(Subcategory::Uppercase)),
+    ("En-cy", 0x041D, "uni041D", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Eng", 0x014A, "Eng", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Eogonek", 0x0118, "Eogonek", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Epsilon", 0x0395, "Epsilon", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Epsilontonos", 0x0388, "Epsilontonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Er-cy", 0x0420, "uni0420", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ereversed-cy", 0x042D, "uni042D", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Es-cy", 0x0421, "uni0421", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Estroke", 0x0246, "uni0246", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Eta", 0x0397, "Eta", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Etatonos", 0x0389, "Etatonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Eth", 0x00D0, "Eth", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Etilde", 0x1EBC, "uni1EBC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Etildebelow", 0x1E1A, "uni1E1A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Euro", 0x20AC, "Euro", Category::Symbol, Some(Subcategory::Currency)),
+    ("F", 0x0046, "F", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Fdotaccent", 0x1E1E, "uni1E1E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Fhook", 0x0191, "uni0191", Category::Letter, Some(Subcategory::Uppercase)),
+    ("G", 0x0047, "G", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gacute", 0x01F4, "uni01F4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gamma", 0x0393, "Gamma", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gbreve", 0x011E, "Gbreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gcaron", 0x01E6, "uni01E6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gcircumflex", 0x011C, "Gcircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gcommaaccent", 0x0122, "Gcommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gdotaccent", 0x0120, "Gdotaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ge-cy", 0x0413, "uni0413", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ghook", 0x0193, "uni0193", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gje-cy", 0x0403, "uni0403", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gmacron", 0x1E20, "uni1E20", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Gstroke", 0x01E4, "uni01E4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("H", 0x0048, "H", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ha-cy", 0x0425, "uni0425", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hardsign-cy", 0x042A, "uni042A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hbar", 0x0126, "Hbar", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hbrevebelow", 0x1E2A, "uni1E2A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hcaron", 0x021E, "uni021E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hcedilla", 0x1E28, "uni1E28", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hcircumflex", 0x0124, "Hcircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hdieresis", 0x1E26, "uni1E26", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hdotaccent", 0x1E22, "uni1E22", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Hdotbelow", 0x1E24, "uni1E24", Category::Letter, Some(Subcategory::Uppercase)),
+    ("I", 0x0049, "I", Category::Letter, Some(Subcategory::Uppercase)),
+    ("I-cy", 0x0406, "uni0406", Category::Letter, Some(Subcategory::Uppercase)),
+    ("IJ", 0x0132, "IJ", Category::Letter, Some(Subcategory::Ligature)),
+    ("Ia-cy", 0x042F, "uni042F", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iacute", 0x00CD, "Iacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ibreve", 0x012C, "Ibreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ibreveinverted", 0x020A, "uni020A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Icaron", 0x01CF, "uni01CF", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Icircumflex", 0x00CE, "Icircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Idblgrave", 0x0208, "uni0208", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Idieresis", 0x00CF, "Idieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Idieresisacute", 0x1E2E, "uni1E2E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Idotaccent", 0x0130, "Idotaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Idotbelow", 0x1ECA, "uni1ECA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ie-cy", 0x0415, "uni0415", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iegrave-cy", 0x0400, "uni0400", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Igrave", 0x00CC, "Igrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Igrave-cy", 0x040D, "uni040D", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ihookabove", 0x1EC8, "uni1EC8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ii-cy", 0x0418, "uni0418", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iishort-cy", 0x0419, "uni0419", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Imacron", 0x012A, "Imacron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Io-cy", 0x0401, "uni0401", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iogonek", 0x012E, "Iogonek", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iota", 0x0399, "Iota", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iotadieresis", 0x03AA, "Iotadieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iotatonos", 0x038A, "Iotatonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Istroke", 0x0197, "uni0197", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Itilde", 0x0128, "Itilde", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Itildebelow", 0x1E2C, "uni1E2C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Iu-cy", 0x042E, "uni042E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("J", 0x004A, "J", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Jcircumflex", 0x0134, "Jcircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Je-cy", 0x0408, "uni0408", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Jstroke", 0x0248, "uni0248", Category::Letter, Some(Subcategory::Uppercase)),
+    ("K", 0x004B, "K", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ka-cy", 0x041A, "uni041A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kacute", 0x1E30, "uni1E30", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kappa", 0x039A, "Kappa", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kcaron", 0x01E8, "uni01E8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kcommaaccent", 0x0136, "Kcommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kdotbelow", 0x1E32, "uni1E32", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Khook", 0x0198, "uni0198", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Kje-cy", 0x040C, "uni040C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Klinebelow", 0x1E34, "uni1E34", Category::Letter, Some(Subcategory::Uppercase)),
+    ("L", 0x004C, "L", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lacute", 0x0139, "Lacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lambda", 0x039B, "Lambda", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lcaron", 0x013D, "Lcaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lcircumflexbelow", 0x1E3C, "uni1E3C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lcommaaccent", 0x013B, "Lcommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ldot", 0x013F, "Ldot", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ldotbelow", 0x1E36, "uni1E36", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ldotbelowmacron", 0x1E38, "uni1E38", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lje-cy", 0x0409, "uni0409", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Llinebelow", 0x1E3A, "uni1E3A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Lslash", 0x0141, "Lslash", Category::Letter, Some(Subcategory::Uppercase)),
+    ("M", 0x004D, "M", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Macute", 0x1E3E, "uni1E3E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Mdotaccent", 0x1E40, "uni1E40", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Mdotbelow", 0x1E42, "uni1E42", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Mu", 0x039C, "Mu", Category::Letter, Some(Subcategory::Uppercase)),
+    ("N", 0x004E, "N", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Nacute", 0x0143, "Nacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ncaron", 0x0147, "Ncaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ncircumflexbelow", 0x1E4A, "uni1E4A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ncommaaccent", 0x0145, "Ncommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ndotaccent", 0x1E44, "uni1E44", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ndotbelow", 0x1E46, "uni1E46", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ngrave", 0x01F8, "uni01F8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Nje-cy", 0x040A, "uni040A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Nlinebelow", 0x1E48, "uni1E48", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ntilde", 0x00D1, "Ntilde", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Nu", 0x039D, "Nu", Category::Letter, Some(Subcategory::Uppercase)),
+    ("O", 0x004F, "O", Category::Letter, Some(Subcategory::Uppercase)),
+    ("O-cy", 0x041E, "uni041E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("OE", 0x0152, "OE", Category::Letter, Some(Subcategory::Ligature)),
+    ("Oacute", 0x00D3, "Oacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Obreve", 0x014E, "Obreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Obreveinverted", 0x020E, "uni020E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocaron", 0x01D1, "uni01D1", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflex", 0x00D4, "Ocircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflexacute", 0x1ED0, "uni1ED0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflexdotbelow", 0x1ED8, "uni1ED8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflexgrave", 0x1ED2, "uni1ED2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflexhookabove", 0x1ED4, "uni1ED4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ocircumflextilde", 0x1ED6, "uni1ED6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odblgrave", 0x020C, "uni020C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odieresis", 0x00D6, "Odieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odieresismacron", 0x022A, "uni022A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odotaccent", 0x022E, "uni022E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odotaccentmacron", 0x0230, "uni0230", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Odotbelow", 0x1ECC, "uni1ECC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ograve", 0x00D2, "Ograve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohookabove", 0x1ECE, "uni1ECE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohorn", 0x01A0, "uni01A0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohornacute", 0x1EDA, "uni1EDA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohorndotbelow", 0x1EE2, "uni1EE2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohorngrave", 0x1EDC, "uni1EDC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohornhookabove", 0x1EDE, "uni1EDE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohorntilde", 0x1EE0, "uni1EE0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ohungarumlaut", 0x0150, "Ohungarumlaut", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omacron", 0x014C, "Omacron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omacronacute", 0x1E52, "uni1E52", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omacrongrave", 0x1E50, "uni1E50", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omega", 0x03A9, "uni03A9", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omegatonos", 0x038F, "Omegatonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omicron", 0x039F, "Omicron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Omicrontonos", 0x038C, "Omicrontonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Oogonek", 0x01EA, "uni01EA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Oogonekmacron", 0x01EC, "uni01EC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Oslash", 0x00D8, "Oslash", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ostrokeacute", 0x01FE, "uni01FE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Otilde", 0x00D5, "Otilde", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Otildeacute", 0x1E4C, "uni1E4C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Otildedieresis", 0x1E4E, "uni1E4E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Otildemacron", 0x022C, "uni022C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("P", 0x0050, "P", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Pacute", 0x1E54, "uni1E54", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Pdotaccent", 0x1E56, "uni1E56", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Pe-cy", 0x041F, "uni041F", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Phi", 0x03A6, "Phi", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Phook", 0x01A4, "uni01A4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Pi", 0x03A0, "Pi", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Psi", 0x03A8, "Psi", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Q", 0x0051, "Q", Category::Letter, Some(Subcategory::Uppercase)),
+    ("R", 0x0052, "R", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Racute", 0x0154, "Racute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rbreveinverted", 0x0212, "uni0212", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rcaron", 0x0158, "Rcaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rcommaaccent", 0x0156, "Rcommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rdblgrave", 0x0210, "uni0210", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rdotaccent", 0x1E58, "uni1E58", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rdotbelow", 0x1E5A, "uni1E5A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rdotbelowmacron", 0x1E5C, "uni1E5C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rho", 0x03A1, "Rho", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rlinebelow", 0x1E5E, "uni1E5E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Rstroke", 0x024C, "uni024C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("S", 0x0053, "S", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sacute", 0x015A, "Sacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sacutedotaccent", 0x1E64, "uni1E64", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Scaron", 0x0160, "Scaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Scarondotaccent", 0x1E66, "uni1E66", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Scedilla", 0x015E, "Scedilla", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Scircumflex", 0x015C, "Scircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Scommaaccent", 0x0218, "Scommaaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sdotaccent", 0x1E60, "uni1E60", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sdotbelow", 0x1E62, "uni1E62", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sdotbelowdotaccent", 0x1E68, "uni1E68", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sha-cy", 0x0428, "uni0428", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Shcha-cy", 0x0429, "uni0429", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Sigma", 0x03A3, "Sigma", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Softsign-cy", 0x042C, "uni042C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("T", 0x0054, "T", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tau", 0x03A4, "Tau", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tbar", 0x0166, "Tbar", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tcaron", 0x0164, "Tcaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tcedilla", 0x0162, "uni0162", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tcircumflexbelow", 0x1E70, "uni1E70", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tcommaaccent", 0x021A, "uni021A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tdotaccent", 0x1E6A, "uni1E6A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tdotbelow", 0x1E6C, "uni1E6C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Te-cy", 0x0422, "uni0422", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Theta", 0x0398, "Theta", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Thook", 0x01AC, "uni01AC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Thorn", 0x00DE, "Thorn", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tlinebelow", 0x1E6E, "uni1E6E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tse-cy", 0x0426, "uni0426", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Tshe-cy", 0x040B, "uni040B", Category::Letter, Some(Subcategory::Uppercase)),
+    ("U", 0x0055, "U", Category::Letter, Some(Subcategory::Uppercase)),
+    ("U-cy", 0x0423, "uni0423", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uacute", 0x00DA, "Uacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ubreve", 0x016C, "Ubreve", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ubreveinverted", 0x0216, "uni0216", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ucaron", 0x01D3, "uni01D3", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ucircumflex", 0x00DB, "Ucircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ucircumflexbelow", 0x1E76, "uni1E76", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udblgrave", 0x0214, "uni0214", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresis", 0x00DC, "Udieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresisacute", 0x01D7, "uni01D7", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresisbelow", 0x1E72, "uni1E72", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresiscaron", 0x01D9, "uni01D9", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresisgrave", 0x01DB, "uni01DB", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udieresismacron", 0x01D5, "uni01D5", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Udotbelow", 0x1EE4, "uni1EE4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ugrave", 0x00D9, "Ugrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhookabove", 0x1EE6, "uni1EE6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhorn", 0x01AF, "uni01AF", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhornacute", 0x1EE8, "uni1EE8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhorndotbelow", 0x1EF0, "uni1EF0", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhorngrave", 0x1EEA, "uni1EEA", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhornhookabove", 0x1EEC, "uni1EEC", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhorntilde", 0x1EEE, "uni1EEE", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uhungarumlaut", 0x0170, "Uhungarumlaut", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Umacron", 0x016A, "Umacron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Umacrondieresis", 0x1E7A, "uni1E7A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uogonek", 0x0172, "Uogonek", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Upsilon", 0x03A5, "Upsilon", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Upsilondieresis", 0x03AB, "Upsilondieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Upsilontonos", 0x038E, "Upsilontonos", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Uring", 0x016E, "Uring", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ushort-cy", 0x040E, "uni040E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Utilde", 0x0168, "Utilde", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Utildeacute", 0x1E78, "uni1E78", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Utildebelow", 0x1E74, "uni1E74", Category::Letter, Some(Subcategory::Uppercase)),
+    ("V", 0x0056, "V", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Vdotbelow", 0x1E7E, "uni1E7E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ve-cy", 0x0412, "uni0412", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Vhook", 0x01B2, "uni01B2", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Vtilde", 0x1E7C, "uni1E7C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("W", 0x0057, "W", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wacute", 0x1E82, "Wacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wcircumflex", 0x0174, "Wcircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wdieresis", 0x1E84, "Wdieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wdotaccent", 0x1E86, "uni1E86", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wdotbelow", 0x1E88, "uni1E88", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Wgrave", 0x1E80, "Wgrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("X", 0x0058, "X", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Xdieresis", 0x1E8C, "uni1E8C", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Xdotaccent", 0x1E8A, "uni1E8A", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Xi", 0x039E, "Xi", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Y", 0x0059, "Y", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Yacute", 0x00DD, "Yacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ycircumflex", 0x0176, "Ycircumflex", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ydieresis", 0x0178, "Ydieresis", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ydotaccent", 0x1E8E, "uni1E8E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ydotbelow", 0x1EF4, "uni1EF4", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Yeru-cy", 0x042B, "uni042B", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ygrave", 0x1EF2, "Ygrave", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Yhook", 0x01B3, "uni01B3", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Yhookabove", 0x1EF6, "uni1EF6", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Yi-cy", 0x0407, "uni0407", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ymacron", 0x0232, "uni0232", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ystroke", 0x024E, "uni024E", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ytilde", 0x1EF8, "uni1EF8", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Z", 0x005A, "Z", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zacute", 0x0179, "Zacute", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zcaron", 0x017D, "Zcaron", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zcircumflex", 0x1E90, "uni1E90", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zdotaccent", 0x017B, "Zdotaccent", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zdotbelow", 0x1E92, "uni1E92", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Ze-cy", 0x0417, "uni0417", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zeta", 0x0396, "Zeta", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zhe-cy", 0x0416, "uni0416", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zhook", 0x0224, "uni0224", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zlinebelow", 0x1E94, "uni1E94", Category::Letter, Some(Subcategory::Uppercase)),
+    ("Zstroke", 0x01B5, "uni01B5", Category::Letter, Some(Subcategory::Uppercase)),
+    ("a", 0x0061, "a", Category::Letter, Some(Subcategory::Lowercase)),
+    ("a-beng", 0x0985, "uni0985", Category::Letter, None),
+    ("a-cy", 0x0430, "uni0430", Category::Letter, Some(Subcategory::Lowercase)),
+    ("a-deva", 0x0905, "uni0905", Category::Letter, None),
+    ("a-gujr", 0x0A85, "uni0A85", Category::Letter, None),
+    ("a-guru", 0x0A05, "uni0A05", Category::Letter, None),
+    ("a-knda", 0x0C85, "uni0C85", Category::Letter, None),
+    ("a-mlym", 0x0D05, "uni0D05", Category::Letter, None),
+    ("a-orya", 0x0B05, "uni0B05", Category::Letter, None),
+    ("a-taml", 0x0B85, "uni0B85", Category::Letter, None),
+    ("a-telu", 0x0C05, "uni0C05", Category::Letter, None),
+    ("aMatra-lao", 0x0EB0, "uni0EB0", Category::Letter, None),
+    ("aa-beng", 0x0986, "uni0986", Category::Letter, None),
+    ("aa-deva", 0x0906, "uni0906", Category::Letter, None),
+    ("aa-gujr", 0x0A86, "uni0A86", Category::Letter, None),
+    ("aa-guru", 0x0A06, "uni0A06", Category::Letter, None),
+    ("aa-knda", 0x0C86, "uni0C86", Category::Letter, None),
+    ("aa-mlym", 0x0D06, "uni0D06", Category::Letter, None),
+    ("aa-orya", 0x0B06, "uni0B06", Category::Letter, None),
+    ("aa-taml", 0x0B86, "uni0B86", Category::Letter, None),
+    ("aa-telu", 0x0C06, "uni0C06", Category::Letter, None),
+    ("aaMatra-beng", 0x09BE, "uni09BE", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-deva", 0x093E, "uni093E", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-gujr", 0x0ABE, "uni0ABE", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-guru", 0x0A3E, "uni0A3E", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-knda", 0x0CBE, "uni0CBE", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-lao", 0x0EB2, "uni0EB2", Category::Letter, None),
+    ("aaMatra-mlym", 0x0D3E, "uni0D3E", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-orya", 0x0B3E, "uni0B3E", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-taml", 0x0BBE, "uni0BBE", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aaMatra-telu", 0x0C3E, "uni0C3E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aacute", 0x00E1, "aacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abbreviation-beng", 0x09FD, "uni09FD", Category::Punctuation, None),
+    ("abbreviation-deva", 0x0970, "uni0970", Category::Punctuation, None),
+    ("abbreviation-gujr", 0x0AF0, "uni0AF0", Category::Punctuation, None),
+    ("abbreviation-guru", 0x0A76, "uni0A76", Category::Punctuation, None),
+    ("abreve", 0x0103, "abreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abreveacute", 0x1EAF, "uni1EAF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abrevedotbelow", 0x1EB7, "uni1EB7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abrevegrave", 0x1EB1, "uni1EB1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abrevehookabove", 0x1EB3, "uni1EB3", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abreveinverted", 0x0203, "uni0203", Category::Letter, Some(Subcategory::Lowercase)),
+    ("abrevetilde", 0x1EB5, "uni1EB5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acaron", 0x01CE, "uni01CE", Category::Letter, Some(Subcategory::Lowercase)),
+    ("accentAtnahHafukh-hb", 0x05A2, "uni05A2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentDarga-hb", 0x05A7, "uni05A7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentDehi-hb", 0x05AD, "uni05AD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentEtnahta-hb", 0x0591, "uni0591", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentGeresh-hb", 0x059C, "uni059C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentGereshMuqdam-hb", 0x059D, "uni059D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentGershayim-hb", 0x059E, "uni059E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentIluy-hb", 0x05AC, "uni05AC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentMahapakh-hb", 0x05A4, "uni05A4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentMerkha-hb", 0x05A5, "uni05A5", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentMerkhaKefula-hb", 0x05A6, "uni05A6", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentMunah-hb", 0x05A3, "uni05A3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentOle-hb", 0x05AB, "uni05AB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentPashta-hb", 0x0599, "uni0599", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentPazer-hb", 0x05A1, "uni05A1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentQadma-hb", 0x05A8, "uni05A8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentQarneyPara-hb", 0x059F, "uni059F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentRevia-hb", 0x0597, "uni0597", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentSegol-hb", 0x0592, "uni0592", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentShalshelet-hb", 0x0593, "uni0593", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentTelishaGedola-hb", 0x05A0, "uni05A0", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentTelishaQetana-hb", 0x05A9, "uni05A9", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentTevir-hb", 0x059B, "uni059B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentTipeha-hb", 0x0596, "uni0596", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentYerahBenYomo-hb", 0x05AA, "uni05AA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentYetiv-hb", 0x059A, "uni059A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentZaqefGadol-hb", 0x0595, "uni0595", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentZaqefQatan-hb", 0x0594, "uni0594", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentZarqa-hb", 0x0598, "uni0598", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("accentZinor-hb", 0x05AE, "uni05AE", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("acircumflex", 0x00E2, "acircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acircumflexacute", 0x1EA5, "uni1EA5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acircumflexdotbelow", 0x1EAD, "uni1EAD", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acircumflexgrave", 0x1EA7, "uni1EA7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acircumflexhookabove", 0x1EA9, "uni1EA9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acircumflextilde", 0x1EAB, "uni1EAB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("acute", 0x00B4, "acute", Category::Symbol, Some(Subcategory::Modifier)),
+    ("acuteAccent-deva", 0x0954, "uni0954", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("acutecomb", 0x0301, "acutecomb", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("adakBindi-guru", 0x0A01, "uni0A01", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("adblgrave", 0x0201, "uni0201", Category::Letter, Some(Subcategory::Lowercase)),
+    ("addak-guru", 0x0A71, "uni0A71", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("adieresis", 0x00E4, "adieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("adieresismacron", 0x01DF, "uni01DF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("adotaccent", 0x0227, "uni0227", Category::Letter, Some(Subcategory::Lowercase)),
+    ("adotaccentmacron", 0x01E1, "uni01E1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("adotbelow", 0x1EA1, "uni1EA1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ae", 0x00E6, "ae", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ae-ar", 0x06D5, "uni06D5", Category::Letter, None),
+    ("agrave", 0x00E0, "agrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ahookabove", 0x1EA3, "uni1EA3", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ai-beng", 0x0990, "uni0990", Category::Letter, None),
+    ("ai-deva", 0x0910, "uni0910", Category::Letter, None),
+    ("ai-gujr", 0x0A90, "uni0A90", Category::Letter, None),
+    ("ai-guru", 0x0A10, "uni0A10", Category::Letter, None),
+    ("ai-knda", 0x0C90, "uni0C90", Category::Letter, None),
+    ("ai-mlym", 0x0D10, "uni0D10", Category::Letter, None),
+    ("ai-orya", 0x0B10, "uni0B10", Category::Letter, None),
+    ("ai-taml", 0x0B90, "uni0B90", Category::Letter, None),
+    ("ai-telu", 0x0C10, "uni0C10", Category::Letter, None),
+    ("aiLengthMark-knda", 0x0CD6, "uni0CD6", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiLengthMark-orya", 0x0B56, "uni0B56", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aiLengthMark-telu", 0x0C56, "uni0C56", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aiMatra-beng", 0x09C8, "uni09C8", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiMatra-deva", 0x0948, "uni0948", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aiMatra-gujr", 0x0AC8, "uni0AC8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aiMatra-guru", 0x0A48, "uni0A48", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("aiMatra-knda", 0x0CC8, "uni0CC8", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiMatra-lao", 0x0EC4, "uni0EC4", Category::Letter, None),
+    ("aiMatra-mlym", 0x0D48, "uni0D48", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiMatra-orya", 0x0B48, "uni0B48", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiMatra-taml", 0x0BC8, "uni0BC8", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aiMatra-telu", 0x0C48, "uni0C48", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ain-ar", 0x0639, "uni0639", Category::Letter, None),
+    ("ainThreeDotsAbove-ar", 0x06A0, "uni06A0", Category::Letter, None),
+    ("alayheAssallam-ar", 0x0611, "uni0611", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("alef-ar", 0x0627, "uni0627", Category::Letter, None),
+    ("alef-hb", 0x05D0, "uni05D0", Category::Letter, None),
+    ("alefHamzaAbove-ar", 0x0623, "uni0623", Category::Letter, None),
+    ("alefHamzaBelow-ar", 0x0625, "uni0625", Category::Letter, None),
+    ("alefMaddaAbove-ar", 0x0622, "uni0622", Category::Letter, None),
+    ("alefMaksura-ar", 0x0649, "uni0649", Category::Letter, None),
+    ("alefWasla-ar", 0x0671, "uni0671", Category::Letter, None),
+    ("alefWavyHamzaAbove-ar", 0x0672, "uni0672", Category::Letter, None),
+    ("alefWavyHamzaBelow-ar", 0x0673, "uni0673", Category::Letter, None),
+    ("alpha", 0x03B1, "alpha", Category::Letter, Some(Subcategory::Lowercase)),
+    ("alphatonos", 0x03AC, "alphatonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("amMatra-lao", 0x0EB3, "uni0EB3", Category::Letter, None),
+    ("amacron", 0x0101, "amacron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ampersand", 0x0026, "ampersand", Category::Punctuation, None),
+    ("angkhankhu-thai", 0x0E5A, "uni0E5A", Category::Punctuation, None),
+    ("anji-beng", 0x0980, "uni0980", Category::Letter, None),
+    ("anusvara-beng", 0x0982, "uni0982", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("anusvara-deva", 0x0902, "uni0902", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("anusvara-gujr", 0x0A82, "uni0A82", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("anusvara-knda", 0x0C82, "uni0C82", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("anusvara-mlym", 0x0D02, "uni0D02", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("anusvara-orya", 0x0B02, "uni0B02", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("anusvara-taml", 0x0B82, "uni0B82", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("anusvara-telu", 0x0C02, "uni0C02", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("aogonek", 0x0105, "aogonek", Category::Letter, Some(Subcategory::Lowercase)),
+    ("approxequal", 0x2248, "approxequal", Category::Symbol, Some(Subcategory::Math)),
+    ("archaicIi-mlym", 0x0D5F, "uni0D5F", Category::Letter, None),
+    ("aring", 0x00E5, "aring", Category::Letter, Some(Subcategory::Lowercase)),
+    ("aringacute", 0x01FB, "uni01FB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("aringbelow", 0x1E01, "uni1E01", Category::Letter, Some(Subcategory::Lowercase)),
+    ("arrowdown", 0x2193, "uni2193", Category::Symbol, Some(Subcategory::Math)),
+    ("arrowleft", 0x2190, "arrowleft", Category::Symbol, Some(Subcategory::Math)),
+    ("arrowright", 0x2192, "arrowright", Category::Symbol, Some(Subcategory::Math)),
+    ("arrowup", 0x2191, "uni2191", Category::Symbol, Some(Subcategory::Math)),
+    ("asAbove-taml", 0x0BF8, "uni0BF8", Category::Symbol, None),
+    ("asciicircum", 0x005E, "asciicircum", Category::Symbol, Some(Subcategory::Modifier)),
+    ("asciitilde", 0x007E, "asciitilde", Category::Symbol, Some(Subcategory::Math)),
+    ("asterisk", 0x002A, "asterisk", Category::Punctuation, None),
+    ("at", 0x0040, "at", Category::Punctuation, None),
+    ("atilde", 0x00E3, "atilde", Category::Letter, Some(Subcategory::Lowercase)),
+    ("au-beng", 0x0994, "uni0994", Category::Letter, None),
+    ("au-deva", 0x0914, "uni0914", Category::Letter, None),
+    ("au-gujr", 0x0A94, "uni0A94", Category::Letter, None),
+    ("au-guru", 0x0A14, "uni0A14", Category::Letter, None),
+    ("au-knda", 0x0C94, "uni0C94", Category::Letter, None),
+    ("au-mlym", 0x0D14, "uni0D14", Category::Letter, None),
+    ("au-orya", 0x0B14, "uni0B14", Category::Letter, None),
+    ("au-taml", 0x0B94, "uni0B94", Category::Letter, None),
+    ("au-telu", 0x0C14, "uni0C14", Category::Letter, None),
+    ("auLengthMark-beng", 0x09D7, "uni09D7", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auLengthMark-mlym", 0x0D57, "uni0D57", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auLengthMark-orya", 0x0B57, "uni0B57", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auLengthMark-taml", 0x0BD7, "uni0BD7", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-beng", 0x09CC, "uni09CC", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-deva", 0x094C, "uni094C", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-gujr", 0x0ACC, "uni0ACC", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-guru", 0x0A4C, "uni0A4C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("auMatra-knda", 0x0CCC, "uni0CCC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("auMatra-mlym", 0x0D4C, "uni0D4C", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-orya", 0x0B4C, "uni0B4C", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-taml", 0x0BCC, "uni0BCC", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("auMatra-telu", 0x0C4C, "uni0C4C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("avagraha-beng", 0x09BD, "uni09BD", Category::Letter, None),
+    ("avagraha-deva", 0x093D, "uni093D", Category::Letter, None),
+    ("avagraha-gujr", 0x0ABD, "uni0ABD", Category::Letter, None),
+    ("avagraha-knda", 0x0CBD, "uni0CBD", Category::Letter, None),
+    ("avagraha-mlym", 0x0D3D, "uni0D3D", Category::Letter, None),
+    ("avagraha-orya", 0x0B3D, "uni0B3D", Category::Letter, None),
+    ("avagraha-telu", 0x0C3D, "uni0C3D", Category::Letter, None),
+    ("aw-deva", 0x0975, "uni0975", Category::Letter, None),
+    ("awMatra-deva", 0x094F, "uni094F", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("ayMatra-lao", 0x0EC3, "uni0EC3", Category::Letter, None),
+    ("ayin-hb", 0x05E2, "uni05E2", Category::Letter, None),
+    ("b", 0x0062, "b", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ba-beng", 0x09AC, "uni09AC", Category::Letter, None),
+    ("ba-deva", 0x092C, "uni092C", Category::Letter, None),
+    ("ba-gujr", 0x0AAC, "uni0AAC", Category::Letter, None),
+    ("ba-guru", 0x0A2C, "uni0A2C", Category::Letter, None),
+    ("ba-knda", 0x0CAC, "uni0CAC", Category::Letter, None),
+    ("ba-mlym", 0x0D2C, "uni0D2C", Category::Letter, None),
+    ("ba-orya", 0x0B2C, "uni0B2C", Category::Letter, None),
+    ("ba-telu", 0x0C2C, "uni0C2C", Category::Letter, None),
+    ("backslash", 0x005C, "backslash", Category::Punctuation, None),
+    ("bar", 0x007C, "bar", Category::Symbol, Some(Subcategory::Math)),
+    ("bba-deva", 0x097F, "uni097F", Category::Letter, None),
+    ("bdotaccent", 0x1E03, "uni1E03", Category::Letter, Some(Subcategory::Lowercase)),
+    ("bdotbelow", 0x1E05, "uni1E05", Category::Letter, Some(Subcategory::Lowercase)),
+    ("be-cy", 0x0431, "uni0431", Category::Letter, Some(Subcategory::Lowercase)),
+    ("beeh-ar", 0x067B, "uni067B", Category::Letter, None),
+    ("beh-ar", 0x0628, "uni0628", Category::Letter, None),
+    ("beheh-ar", 0x0680, "uni0680", Category::Letter, None),
+    ("bet-hb", 0x05D1, "uni05D1", Category::Letter, None),
+    ("beta", 0x03B2, "beta", Category::Letter, Some(Subcategory::Lowercase)),
+    ("bha-beng", 0x09AD, "uni09AD", Category::Letter, None),
+    ("bha-deva", 0x092D, "uni092D", Category::Letter, None),
+    ("bha-gujr", 0x0AAD, "uni0AAD", Category::Letter, None),
+    ("bha-guru", 0x0A2D, "uni0A2D", Category::Letter, None),
+    ("bha-knda", 0x0CAD, "uni0CAD", Category::Letter, None),
+    ("bha-mlym", 0x0D2D, "uni0D2D", Category::Letter, None),
+    ("bha-orya", 0x0B2D, "uni0B2D", Category::Letter, None),
+    ("bha-telu", 0x0C2D, "uni0C2D", Category::Letter, None),
+    ("bindi-guru", 0x0A02, "uni0A02", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("blinebelow", 0x1E07, "uni1E07", Category::Letter, Some(Subcategory::Lowercase)),
+    ("bo-lao", 0x0E9A, "uni0E9A", Category::Letter, None),
+    ("boBaimai-thai", 0x0E1A, "uni0E1A", Category::Letter, None),
+    ("braceleft", 0x007B, "braceleft", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("braceright", 0x007D, "braceright", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("bracketleft", 0x005B, "bracketleft", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("bracketright", 0x005D, "bracketright", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("breve", 0x02D8, "breve", Category::Symbol, Some(Subcategory::Modifier)),
+    ("brevebelowcomb", 0x032E, "uni032E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("brevecomb", 0x0306, "uni0306", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("breveinvertedcomb", 0x0311, "uni0311", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("brokenbar", 0x00A6, "brokenbar", Category::Symbol, None),
+    ("bstroke", 0x0180, "uni0180", Category::Letter, Some(Subcategory::Lowercase)),
+    ("bullet", 0x2022, "bullet", Category::Punctuation, None),
+    ("c", 0x0063, "c", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ca-beng", 0x099A, "uni099A", Category::Letter, None),
+    ("ca-deva", 0x091A, "uni091A", Category::Letter, None),
+    ("ca-gujr", 0x0A9A, "uni0A9A", Category::Letter, None),
+    ("ca-guru", 0x0A1A, "uni0A1A", Category::Letter, None),
+    ("ca-knda", 0x0C9A, "uni0C9A", Category::Letter, None),
+    ("ca-mlym", 0x0D1A, "uni0D1A", Category::Letter, None),
+    ("ca-orya", 0x0B1A, "uni0B1A", Category::Letter, None),
+    ("ca-taml", 0x0B9A, "uni0B9A", Category::Letter, None),
+    ("ca-telu", 0x0C1A, "uni0C1A", Category::Letter, None),
+    ("cacute", 0x0107, "cacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("cancellationMark-lao", 0x0ECC, "uni0ECC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candraA-deva", 0x0972, "uni0972", Category::Letter, None),
+    ("candraE-deva", 0x090D, "uni090D", Category::Letter, None),
+    ("candraEMatra-deva", 0x0945, "uni0945", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candraEMatra-gujr", 0x0AC5, "uni0AC5", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candraLongEMatra-deva", 0x0955, "uni0955", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candraO-deva", 0x0911, "uni0911", Category::Letter, None),
+    ("candraOMatra-deva", 0x0949, "uni0949", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("candraOMatra-gujr", 0x0AC9, "uni0AC9", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("candrabindu-beng", 0x0981, "uni0981", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-deva", 0x0901, "uni0901", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-gujr", 0x0A81, "uni0A81", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-knda", 0x0C81, "uni0C81", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-mlym", 0x0D01, "uni0D01", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-orya", 0x0B01, "uni0B01", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("candrabindu-telu", 0x0C01, "uni0C01", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("caron", 0x02C7, "caron", Category::Letter, Some(Subcategory::Modifier)),
+    ("caroncomb", 0x030C, "uni030C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ccaron", 0x010D, "ccaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ccedilla", 0x00E7, "ccedilla", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ccedillaacute", 0x1E09, "uni1E09", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ccircumflex", 0x0109, "ccircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("cdotaccent", 0x010B, "cdotaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("cedilla", 0x00B8, "cedilla", Category::Symbol, Some(Subcategory::Modifier)),
+    ("cedillacomb", 0x0327, "uni0327", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("cent", 0x00A2, "cent", Category::Symbol, Some(Subcategory::Currency)),
+    ("cha-beng", 0x099B, "uni099B", Category::Letter, None),
+    ("cha-deva", 0x091B, "uni091B", Category::Letter, None),
+    ("cha-gujr", 0x0A9B, "uni0A9B", Category::Letter, None),
+    ("cha-guru", 0x0A1B, "uni0A1B", Category::Letter, None),
+    ("cha-knda", 0x0C9B, "uni0C9B", Category::Letter, None),
+    ("cha-mlym", 0x0D1B, "uni0D1B", Category::Letter, None),
+    ("cha-orya", 0x0B1B, "uni0B1B", Category::Letter, None),
+    ("cha-telu", 0x0C1B, "uni0C1B", Category::Letter, None),
+    ("che-cy", 0x0447, "uni0447", Category::Letter, Some(Subcategory::Lowercase)),
+    ("chi", 0x03C7, "chi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("chilluK-mlym", 0x0D7F, "uni0D7F", Category::Letter, None),
+    ("chilluL-mlym", 0x0D7D, "uni0D7D", Category::Letter, None),
+    ("chilluLl-mlym", 0x0D7E, "uni0D7E", Category::Letter, None),
+    ("chilluLll-mlym", 0x0D56, "uni0D56", Category::Letter, None),
+    ("chilluM-mlym", 0x0D54, "uni0D54", Category::Letter, None),
+    ("chilluN-mlym", 0x0D7B, "uni0D7B", Category::Letter, None),
+    ("chilluNn-mlym", 0x0D7A, "uni0D7A", Category::Letter, None),
+    ("chilluRr-mlym", 0x0D7C, "uni0D7C", Category::Letter, None),
+    ("chilluY-mlym", 0x0D55, "uni0D55", Category::Letter, None),
+    ("choChan-thai", 0x0E08, "uni0E08", Category::Letter, None),
+    ("choChang-thai", 0x0E0A, "uni0E0A", Category::Letter, None),
+    ("choChing-thai", 0x0E09, "uni0E09", Category::Letter, None),
+    ("choChoe-thai", 0x0E0C, "uni0E0C", Category::Letter, None),
+    ("chook", 0x0188, "uni0188", Category::Letter, Some(Subcategory::Lowercase)),
+    ("circleNuktaAbove-gujr", 0x0AFE, "uni0AFE", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("circularVirama-mlym", 0x0D3C, "uni0D3C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("circumflex", 0x02C6, "circumflex", Category::Letter, Some(Subcategory::Modifier)),
+    ("circumflexcomb", 0x0302, "uni0302", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("co-lao", 0x0E88, "uni0E88", Category::Letter, None),
+    ("colon", 0x003A, "colon", Category::Punctuation, None),
+    ("combiningAnusvaraAbove-mlym", 0x0D00, "uni0D00", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("combiningAnusvaraAbove-telu", 0x0C04, "uni0C04", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("combiningCandrabinduAbove-telu", 0x0C00, "uni0C00", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("comma", 0x002C, "comma", Category::Punctuation, None),
+    ("comma-ar", 0x060C, "uni060C", Category::Punctuation, None),
+    ("commaaccentcomb", 0x0326, "uni0326", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("commaturnedabovecomb", 0x0312, "uni0312", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("copyright", 0x00A9, "copyright", Category::Symbol, None),
+    ("credit-taml", 0x0BF7, "uni0BF7", Category::Symbol, None),
+    ("cstroke", 0x023C, "uni023C", Category::Letter, Some(Subcategory::Lowercase)),
+    ("cubeRoot-ar", 0x0606, "uni0606", Category::Symbol, Some(Subcategory::Math)),
+    ("currency", 0x00A4, "currency", Category::Symbol, Some(Subcategory::Currency)),
+    ("currencyDenominatorSixteen-beng", 0x09F9, "uni09F9", Category::Number, None),
+    ("currencyNumeratorFour-beng", 0x09F7, "uni09F7", Category::Number, None),
+    ("currencyNumeratorOne-beng", 0x09F4, "uni09F4", Category::Number, None),
+    ("currencyNumeratorOneLessThanTheDenominator-beng", 0x09F8, "uni09F8", Category::Number, None),
+    ("currencyNumeratorThree-beng", 0x09F6, "uni09F6", Category::Number, None),
+    ("currencyNumeratorTwo-beng", 0x09F5, "uni09F5", Category::Number, None),
+    ("currencySymbolBaht-thai", 0x0E3F, "uni0E3F", Category::Symbol, Some(Subcategory::Currency)),
+    ("d", 0x0064, "d", Category::Letter, Some(Subcategory::Lowercase)),
+    ("da-beng", 0x09A6, "uni09A6", Category::Letter, None),
+    ("da-deva", 0x0926, "uni0926", Category::Letter, None),
+    ("da-gujr", 0x0AA6, "uni0AA6", Category::Letter, None),
+    ("da-guru", 0x0A26, "uni0A26", Category::Letter, None),
+    ("da-knda", 0x0CA6, "uni0CA6", Category::Letter, None),
+    ("da-mlym", 0x0D26, "uni0D26", Category::Letter, None),
+    ("da-orya", 0x0B26, "uni0B26", Category::Letter, None),
+    ("da-telu", 0x0C26, "uni0C26", Category::Letter, None),
+    ("dad-ar", 0x0636, "uni0636", Category::Letter, None),
+    ("dadDotBelow-ar", 0x06FB, "uni06FB", Category::Letter, None),
+    ("dageshOrMapiq-hb", 0x05BC, "uni05BC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dagger", 0x2020, "dagger", Category::Punctuation, None),
+    ("daggerdbl", 0x2021, "daggerdbl", Category::Punctuation, None),
+    ("dahal-ar", 0x068C, "uni068C", Category::Letter, None),
+    ("dal-ar", 0x062F, "uni062F", Category::Letter, None),
+    ("dalDotBelow-ar", 0x068A, "uni068A", Category::Letter, None),
+    ("dalDotBelowAndSmallTah-ar", 0x068B, "uni068B", Category::Letter, None),
+    ("dalFourDotsAbove-ar", 0x0690, "uni0690", Category::Letter, None),
+    ("dalInvertedV-ar", 0x06EE, "uni06EE", Category::Letter, None),
+    ("dalRing-ar", 0x0689, "uni0689", Category::Letter, None),
+    ("dalThreeDotsAboveDownwards-ar", 0x068F, "uni068F", Category::Letter, None),
+    ("dalet-hb", 0x05D3, "uni05D3", Category::Letter, None),
+    ("damma-ar", 0x064F, "uni064F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dammatan-ar", 0x064C, "uni064C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("danda-deva", 0x0964, "uni0964", Category::Punctuation, None),
+    ("dateMark-mlym", 0x0D79, "uni0D79", Category::Symbol, None),
+    ("dateSeparator-ar", 0x060D, "uni060D", Category::Punctuation, None),
+    ("day-taml", 0x0BF3, "uni0BF3", Category::Symbol, None),
+    ("dblgravecomb", 0x030F, "uni030F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dcaron", 0x010F, "dcaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dcedilla", 0x1E11, "uni1E11", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dcircumflexbelow", 0x1E13, "uni1E13", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dcroat", 0x0111, "dcroat", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dda-beng", 0x09A1, "uni09A1", Category::Letter, None),
+    ("dda-deva", 0x0921, "uni0921", Category::Letter, None),
+    ("dda-gujr", 0x0AA1, "uni0AA1", Category::Letter, None),
+    ("dda-guru", 0x0A21, "uni0A21", Category::Letter, None),
+    ("dda-knda", 0x0CA1, "uni0CA1", Category::Letter, None),
+    ("dda-mlym", 0x0D21, "uni0D21", Category::Letter, None),
+    ("dda-orya", 0x0B21, "uni0B21", Category::Letter, None),
+    ("dda-telu", 0x0C21, "uni0C21", Category::Letter, None),
+    ("ddahal-ar", 0x068D, "uni068D", Category::Letter, None),
+    ("ddal-ar", 0x0688, "uni0688", Category::Letter, None),
+    ("ddda-deva", 0x097E, "uni097E", Category::Letter, None),
+    ("dddha-deva", 0x095C, "uni095C", Category::Letter, None),
+    ("ddha-beng", 0x09A2, "uni09A2", Category::Letter, None),
+    ("ddha-deva", 0x0922, "uni0922", Category::Letter, None),
+    ("ddha-gujr", 0x0AA2, "uni0AA2", Category::Letter, None),
+    ("ddha-guru", 0x0A22, "uni0A22", Category::Letter, None),
+    ("ddha-knda", 0x0CA2, "uni0CA2", Category::Letter, None),
+    ("ddha-mlym", 0x0D22, "uni0D22", Category::Letter, None),
+    ("ddha-orya", 0x0B22, "uni0B22", Category::Letter, None),
+    ("ddha-telu", 0x0C22, "uni0C22", Category::Letter, None),
+    ("ddotaccent", 0x1E0B, "uni1E0B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ddotbelow", 0x1E0D, "uni1E0D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("de-cy", 0x0434, "uni0434", Category::Letter, Some(Subcategory::Lowercase)),
+    ("debit-taml", 0x0BF6, "uni0BF6", Category::Symbol, None),
+    ("decimalSeparator-ar", 0x066B, "uni066B", Category::Punctuation, None),
+    ("degree", 0x00B0, "degree", Category::Symbol, None),
+    ("delta", 0x03B4, "delta", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dha-beng", 0x09A7, "uni09A7", Category::Letter, None),
+    ("dha-deva", 0x0927, "uni0927", Category::Letter, None),
+    ("dha-gujr", 0x0AA7, "uni0AA7", Category::Letter, None),
+    ("dha-guru", 0x0A27, "uni0A27", Category::Letter, None),
+    ("dha-knda", 0x0CA7, "uni0CA7", Category::Letter, None),
+    ("dha-mlym", 0x0D27, "uni0D27", Category::Letter, None),
+    ("dha-orya", 0x0B27, "uni0B27", Category::Letter, None),
+    ("dha-telu", 0x0C27, "uni0C27", Category::Letter, None),
+    ("dieresis", 0x00A8, "dieresis", Category::Symbol, Some(Subcategory::Modifier)),
+    ("dieresisbelowcomb", 0x0324, "uni0324", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dieresiscomb", 0x0308, "uni0308", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("divide", 0x00F7, "divide", Category::Symbol, Some(Subcategory::Math)),
+    ("dje-cy", 0x0452, "uni0452", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dlinebelow", 0x1E0F, "uni1E0F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("do-lao", 0x0E94, "uni0E94", Category::Letter, None),
+    ("doChada-thai", 0x0E0E, "uni0E0E", Category::Letter, None),
+    ("doDek-thai", 0x0E14, "uni0E14", Category::Letter, None),
+    ("dollar", 0x0024, "dollar", Category::Symbol, Some(Subcategory::Currency)),
+    ("dotReph-mlym", 0x0D4E, "uni0D4E", Category::Letter, None),
+    ("dotaccent", 0x02D9, "dotaccent", Category::Symbol, Some(Subcategory::Modifier)),
+    ("dotaccentcomb", 0x0307, "uni0307", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dotbelowcomb", 0x0323, "dotbelowcomb", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("dotlessBeh-ar", 0x066E, "uni066E", Category::Letter, None),
+    ("dotlessFeh-ar", 0x06A1, "uni06A1", Category::Letter, None),
+    ("dotlessQaf-ar", 0x066F, "uni066F", Category::Letter, None),
+    ("dotlessi", 0x0131, "dotlessi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dotlessj", 0x0237, "uni0237", Category::Letter, Some(Subcategory::Lowercase)),
+    ("doubleDanda-deva", 0x0965, "uni0965", Category::Punctuation, None),
+    ("dul-ar", 0x068E, "uni068E", Category::Letter, None),
+    ("dyeh-ar", 0x0684, "uni0684", Category::Letter, None),
+    ("dza-telu", 0x0C59, "uni0C59", Category::Letter, None),
+    ("dze-cy", 0x0455, "uni0455", Category::Letter, Some(Subcategory::Lowercase)),
+    ("dzhe-cy", 0x045F, "uni045F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("e", 0x0065, "e", Category::Letter, Some(Subcategory::Lowercase)),
+    ("e-ar", 0x06D0, "uni06D0", Category::Letter, None),
+    ("e-beng", 0x098F, "uni098F", Category::Letter, None),
+    ("e-cy", 0x0454, "uni0454", Category::Letter, Some(Subcategory::Lowercase)),
+    ("e-deva", 0x090F, "uni090F", Category::Letter, None),
+    ("e-gujr", 0x0A8F, "uni0A8F", Category::Letter, None),
+    ("e-knda", 0x0C8E, "uni0C8E", Category::Letter, None),
+    ("e-mlym", 0x0D0E, "uni0D0E", Category::Letter, None),
+    ("e-orya", 0x0B0F, "uni0B0F", Category::Letter, None),
+    ("e-taml", 0x0B8E, "uni0B8E", Category::Letter, None),
+    ("e-telu", 0x0C0E, "uni0C0E", Category::Letter, None),
+    ("eMatra-beng", 0x09C7, "uni09C7", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eMatra-deva", 0x0947, "uni0947", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("eMatra-gujr", 0x0AC7, "uni0AC7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("eMatra-knda", 0x0CC6, "uni0CC6", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("eMatra-lao", 0x0EC0, "uni0EC0", Category::Letter, None),
+    ("eMatra-mlym", 0x0D46, "uni0D46", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eMatra-orya", 0x0B47, "uni0B47", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eMatra-taml", 0x0BC6, "uni0BC6", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eMatra-telu", 0x0C46, "uni0C46", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("eacute", 0x00E9, "eacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ebreve", 0x0115, "ebreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ebreveinverted", 0x0207, "uni0207", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecaron", 0x011B, "ecaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecedilla", 0x0229, "uni0229", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecedillabreve", 0x1E1D, "uni1E1D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflex", 0x00EA, "ecircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflexacute", 0x1EBF, "uni1EBF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflexbelow", 0x1E19, "uni1E19", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflexdotbelow", 0x1EC7, "uni1EC7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflexgrave", 0x1EC1, "uni1EC1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflexhookabove", 0x1EC3, "uni1EC3", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ecircumflextilde", 0x1EC5, "uni1EC5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("edblgrave", 0x0205, "uni0205", Category::Letter, Some(Subcategory::Lowercase)),
+    ("edieresis", 0x00EB, "edieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("edotaccent", 0x0117, "edotaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("edotbelow", 0x1EB9, "uni1EB9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ee-guru", 0x0A0F, "uni0A0F", Category::Letter, None),
+    ("ee-knda", 0x0C8F, "uni0C8F", Category::Letter, None),
+    ("ee-mlym", 0x0D0F, "uni0D0F", Category::Letter, None),
+    ("ee-taml", 0x0B8F, "uni0B8F", Category::Letter, None),
+    ("ee-telu", 0x0C0F, "uni0C0F", Category::Letter, None),
+    ("eeMatra-guru", 0x0A47, "uni0A47", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("eeMatra-knda", 0x0CC7, "uni0CC7", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eeMatra-mlym", 0x0D47, "uni0D47", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eeMatra-taml", 0x0BC7, "uni0BC7", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("eeMatra-telu", 0x0C47, "uni0C47", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ef-cy", 0x0444, "uni0444", Category::Letter, Some(Subcategory::Lowercase)),
+    ("egrave", 0x00E8, "egrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ehookabove", 0x1EBB, "uni1EBB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("eiMatra-lao", 0x0EC1, "uni0EC1", Category::Letter, None),
+    ("eight", 0x0038, "eight", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-ar", 0x0668, "uni0668", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-beng", 0x09EE, "uni09EE", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-deva", 0x096E, "uni096E", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-gujr", 0x0AEE, "uni0AEE", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-guru", 0x0A6E, "uni0A6E", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-knda", 0x0CEE, "uni0CEE", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-lao", 0x0ED8, "uni0ED8", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-mlym", 0x0D6E, "uni0D6E", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-orya", 0x0B6E, "uni0B6E", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-taml", 0x0BEE, "uni0BEE", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-telu", 0x0C6E, "uni0C6E", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("eight-thai", 0x0E58, "uni0E58", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("ekOnkar-guru", 0x0A74, "uni0A74", Category::Letter, None),
+    ("el-cy", 0x043B, "uni043B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ellipsis", 0x2026, "ellipsis", Category::Punctuation, None),
+    ("ellipsis-lao", 0x0EAF, "uni0EAF", Category::Letter, None),
+    ("em-cy", 0x043C, "uni043C", Category::Letter, Some(Subcategory::Lowercase)),
+    ("emacron", 0x0113, "emacron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("emacronacute", 0x1E17, "uni1E17", Category::Letter, Some(Subcategory::Lowercase)),
+    ("emacrongrave", 0x1E15, "uni1E15", Category::Letter, Some(Subcategory::Lowercase)),
+    ("emdash", 0x2014, "emdash", Category::Punctuation, Some(Subcategory::Dash)),
+    ("emptyCentreHighStop-ar", 0x06EB, "uni06EB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("emptyCentreLowStop-ar", 0x06EA, "uni06EA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("en-cy", 0x043D, "uni043D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("endOfAyah-ar", 0x06DD, "uni06DD", Category::Other, Some(Subcategory::Format)),
+    ("endOfTextMark-ar", 0x061D, "uni061D", Category::Punctuation, None),
+    ("endash", 0x2013, "endash", Category::Punctuation, Some(Subcategory::Dash)),
+    ("eng", 0x014B, "eng", Category::Letter, Some(Subcategory::Lowercase)),
+    ("eogonek", 0x0119, "eogonek", Category::Letter, Some(Subcategory::Lowercase)),
+    ("epsilon", 0x03B5, "epsilon", Category::Letter, Some(Subcategory::Lowercase)),
+    ("epsilontonos", 0x03AD, "epsilontonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("equal", 0x003D, "equal", Category::Symbol, Some(Subcategory::Math)),
+    ("er-cy", 0x0440, "uni0440", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ereversed-cy", 0x044D, "uni044D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("es-cy", 0x0441, "uni0441", Category::Letter, Some(Subcategory::Lowercase)),
+    ("estroke", 0x0247, "uni0247", Category::Letter, Some(Subcategory::Lowercase)),
+    ("eta", 0x03B7, "eta", Category::Letter, Some(Subcategory::Lowercase)),
+    ("etatonos", 0x03AE, "etatonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("eth", 0x00F0, "eth", Category::Letter, Some(Subcategory::Lowercase)),
+    ("etilde", 0x1EBD, "uni1EBD", Category::Letter, Some(Subcategory::Lowercase)),
+    ("etildebelow", 0x1E1B, "uni1E1B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("exclam", 0x0021, "exclam", Category::Punctuation, None),
+    ("exclamdown", 0x00A1, "exclamdown", Category::Punctuation, None),
+    ("f", 0x0066, "f", Category::Letter, Some(Subcategory::Lowercase)),
+    ("fa-deva", 0x095E, "uni095E", Category::Letter, None),
+    ("fa-guru", 0x0A5E, "uni0A5E", Category::Letter, None),
+    ("fa-knda", 0x0CDE, "uni0CDE", Category::Letter, None),
+    ("farsiYeh-ar", 0x06CC, "uni06CC", Category::Letter, None),
+    ("farsiYehInvertedV-ar", 0x063D, "uni063D", Category::Letter, None),
+    ("farsiYehThreeDotsAbove-ar", 0x063F, "uni063F", Category::Letter, None),
+    ("farsiYehTwoDotsAbove-ar", 0x063E, "uni063E", Category::Letter, None),
+    ("fatha-ar", 0x064E, "uni064E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("fathaTwoDots-ar", 0x065E, "uni065E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("fathatan-ar", 0x064B, "uni064B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("fdotaccent", 0x1E1F, "uni1E1F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("feh-ar", 0x0641, "uni0641", Category::Letter, None),
+    ("fehDotBelow-ar", 0x06A3, "uni06A3", Category::Letter, None),
+    ("fehDotMovedBelow-ar", 0x06A2, "uni06A2", Category::Letter, None),
+    ("fehThreeDotsBelow-ar", 0x06A5, "uni06A5", Category::Letter, None),
+    ("fhook", 0x0192, "uni0192", Category::Letter, Some(Subcategory::Lowercase)),
+    ("fi", 0xFB01, "fi", Category::Letter, Some(Subcategory::Ligature)),
+    ("finalKaf-hb", 0x05DA, "uni05DA", Category::Letter, None),
+    ("finalMem-hb", 0x05DD, "uni05DD", Category::Letter, None),
+    ("finalNun-hb", 0x05DF, "uni05DF", Category::Letter, None),
+    ("finalPe-hb", 0x05E3, "uni05E3", Category::Letter, None),
+    ("finalTsadi-hb", 0x05E5, "uni05E5", Category::Letter, None),
+    ("five", 0x0035, "five", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-ar", 0x0665, "uni0665", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-beng", 0x09EB, "uni09EB", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-deva", 0x096B, "uni096B", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-gujr", 0x0AEB, "uni0AEB", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-guru", 0x0A6B, "uni0A6B", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-knda", 0x0CEB, "uni0CEB", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-lao", 0x0ED5, "uni0ED5", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-mlym", 0x0D6B, "uni0D6B", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-orya", 0x0B6B, "uni0B6B", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-taml", 0x0BEB, "uni0BEB", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-telu", 0x0C6B, "uni0C6B", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("five-thai", 0x0E55, "uni0E55", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("fivePointedStar-ar", 0x066D, "uni066D", Category::Punctuation, None),
+    ("fl", 0xFB02, "fl", Category::Letter, Some(Subcategory::Ligature)),
+    ("foFa-thai", 0x0E1D, "uni0E1D", Category::Letter, None),
+    ("foFan-thai", 0x0E1F, "uni0E1F", Category::Letter, None),
+    ("foSung-lao", 0x0E9F, "uni0E9F", Category::Letter, None),
+    ("foTam-lao", 0x0E9D, "uni0E9D", Category::Letter, None),
+    ("fongman-thai", 0x0E4F, "uni0E4F", Category::Punctuation, None),
+    ("footnoteMarker-ar", 0x0602, "uni0602", Category::Other, Some(Subcategory::Format)),
+    ("four", 0x0034, "four", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-ar", 0x0664, "uni0664", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-beng", 0x09EA, "uni09EA", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-deva", 0x096A, "uni096A", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-gujr", 0x0AEA, "uni0AEA", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-guru", 0x0A6A, "uni0A6A", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-knda", 0x0CEA, "uni0CEA", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-lao", 0x0ED4, "uni0ED4", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-mlym", 0x0D6A, "uni0D6A", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-orya", 0x0B6A, "uni0B6A", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-taml", 0x0BEA, "uni0BEA", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-telu", 0x0C6A, "uni0C6A", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("four-thai", 0x0E54, "uni0E54", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("fourthRoot-ar", 0x0607, "uni0607", Category::Symbol, Some(Subcategory::Math)),
+    ("fraction", 0x2044, "fraction", Category::Symbol, Some(Subcategory::Math)),
+    ("fractionOneEighth-mlym", 0x0D77, "uni0D77", Category::Number, None),
+    ("fractionOneEighth-orya", 0x0B76, "uni0B76", Category::Number, None),
+    ("fractionOneFifth-mlym", 0x0D5E, "uni0D5E", Category::Number, None),
+    ("fractionOneForEvenPowersOfFour-telu", 0x0C7C, "uni0C7C", Category::Number, None),
+    ("fractionOneForOddPowersOfFour-telu", 0x0C79, "uni0C79", Category::Number, None),
+    ("fractionOneFortieth-mlym", 0x0D59, "uni0D59", Category::Number, None),
+    ("fractionOneHalf-mlym", 0x0D74, "uni0D74", Category::Number, None),
+    ("fractionOneHalf-orya", 0x0B73, "uni0B73", Category::Number, None),
+    ("fractionOneOneHundredAndSixtieth-mlym", 0x0D58, "uni0D58", Category::Number, None),
+    ("fractionOneQuarter-mlym", 0x0D73, "uni0D73", Category::Number, None),
+    ("fractionOneQuarter-orya", 0x0B72, "uni0B72", Category::Number, None),
+    ("fractionOneSixteenth-mlym", 0x0D76, "uni0D76", Category::Number, None),
+    ("fractionOneSixteenth-orya", 0x0B75, "uni0B75", Category::Number, None),
+    ("fractionOneTenth-mlym", 0x0D5C, "uni0D5C", Category::Number, None),
+    ("fractionOneTwentieth-mlym", 0x0D5B, "uni0D5B", Category::Number, None),
+    ("fractionThreeEightieths-mlym", 0x0D5A, "uni0D5A", Category::Number, None),
+    ("fractionThreeForEvenPowersOfFour-telu", 0x0C7E, "uni0C7E", Category::Number, None),
+    ("fractionThreeForOddPowersOfFour-telu", 0x0C7B, "uni0C7B", Category::Number, None),
+    ("fractionThreeQuarters-mlym", 0x0D75, "uni0D75", Category::Number, None),
+    ("fractionThreeQuarters-orya", 0x0B74, "uni0B74", Category::Number, None),
+    ("fractionThreeSixteenths-mlym", 0x0D78, "uni0D78", Category::Number, None),
+    ("fractionThreeSixteenths-orya", 0x0B77, "uni0B77", Category::Number, None),
+    ("fractionThreeTwentieths-mlym", 0x0D5D, "uni0D5D", Category::Number, None),
+    ("fractionTwoForEvenPowersOfFour-telu", 0x0C7D, "uni0C7D", Category::Number, None),
+    ("fractionTwoForOddPowersOfFour-telu", 0x0C7A, "uni0C7A", Category::Number, None),
+    ("fractionZeroForOddPowersOfFour-telu", 0x0C78, "uni0C78", Category::Number, None),
+    ("fullStop-ar", 0x06D4, "uni06D4", Category::Punctuation, None),
+    ("g", 0x0067, "g", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ga-beng", 0x0997, "uni0997", Category::Letter, None),
+    ("ga-deva", 0x0917, "uni0917", Category::Letter, None),
+    ("ga-gujr", 0x0A97, "uni0A97", Category::Letter, None),
+    ("ga-guru", 0x0A17, "uni0A17", Category::Letter, None),
+    ("ga-knda", 0x0C97, "uni0C97", Category::Letter, None),
+    ("ga-mlym", 0x0D17, "uni0D17", Category::Letter, None),
+    ("ga-orya", 0x0B17, "uni0B17", Category::Letter, None),
+    ("ga-telu", 0x0C17, "uni0C17", Category::Letter, None),
+    ("gacute", 0x01F5, "uni01F5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gaf-ar", 0x06AF, "uni06AF", Category::Letter, None),
+    ("gafRing-ar", 0x06B0, "uni06B0", Category::Letter, None),
+    ("gafThreeDotsAbove-ar", 0x06B4, "uni06B4", Category::Letter, None),
+    ("gafTwoDotsBelow-ar", 0x06B2, "uni06B2", Category::Letter, None),
+    ("gamma", 0x03B3, "gamma", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gandaMark-beng", 0x09FB, "uni09FB", Category::Symbol, Some(Subcategory::Currency)),
+    ("gbreve", 0x011F, "gbreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gcaron", 0x01E7, "uni01E7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gcircumflex", 0x011D, "gcircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gcommaaccent", 0x0123, "gcommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gdotaccent", 0x0121, "gdotaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ge-cy", 0x0433, "uni0433", Category::Letter, Some(Subcategory::Lowercase)),
+    ("geresh-hb", 0x05F3, "uni05F3", Category::Punctuation, None),
+    ("germandbls", 0x00DF, "germandbls", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gershayim-hb", 0x05F4, "uni05F4", Category::Punctuation, None),
+    ("gga-deva", 0x097B, "uni097B", Category::Letter, None),
+    ("gha-beng", 0x0998, "uni0998", Category::Letter, None),
+    ("gha-deva", 0x0918, "uni0918", Category::Letter, None),
+    ("gha-gujr", 0x0A98, "uni0A98", Category::Letter, None),
+    ("gha-guru", 0x0A18, "uni0A18", Category::Letter, None),
+    ("gha-knda", 0x0C98, "uni0C98", Category::Letter, None),
+    ("gha-mlym", 0x0D18, "uni0D18", Category::Letter, None),
+    ("gha-orya", 0x0B18, "uni0B18", Category::Letter, None),
+    ("gha-telu", 0x0C18, "uni0C18", Category::Letter, None),
+    ("ghain-ar", 0x063A, "uni063A", Category::Letter, None),
+    ("ghainDotBelow-ar", 0x06FC, "uni06FC", Category::Letter, None),
+    ("ghha-deva", 0x095A, "uni095A", Category::Letter, None),
+    ("ghha-guru", 0x0A5A, "uni0A5A", Category::Letter, None),
+    ("gimel-hb", 0x05D2, "uni05D2", Category::Letter, None),
+    ("gje-cy", 0x0453, "uni0453", Category::Letter, Some(Subcategory::Lowercase)),
+    ("glottalStop-deva", 0x097D, "uni097D", Category::Letter, None),
+    ("gmacron", 0x1E21, "uni1E21", Category::Letter, Some(Subcategory::Lowercase)),
+    ("grave", 0x0060, "grave", Category::Symbol, Some(Subcategory::Modifier)),
+    ("graveAccent-deva", 0x0953, "uni0953", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("gravecomb", 0x0300, "gravecomb", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("greater", 0x003E, "greater", Category::Symbol, Some(Subcategory::Math)),
+    ("greaterequal", 0x2265, "greaterequal", Category::Symbol, Some(Subcategory::Math)),
+    ("gstroke", 0x01E5, "uni01E5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("gueh-ar", 0x06B3, "uni06B3", Category::Letter, None),
+    ("guillemetleft", 0x00AB, "guillemotleft", Category::Punctuation, Some(Subcategory::Quote)),
+    ("guillemetright", 0x00BB, "guillemotright", Category::Punctuation, Some(Subcategory::Quote)),
+    ("guilsinglleft", 0x2039, "guilsinglleft", Category::Punctuation, Some(Subcategory::Quote)),
+    ("guilsinglright", 0x203A, "guilsinglright", Category::Punctuation, Some(Subcategory::Quote)),
+    ("h", 0x0068, "h", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ha-beng", 0x09B9, "uni09B9", Category::Letter, None),
+    ("ha-cy", 0x0445, "uni0445", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ha-deva", 0x0939, "uni0939", Category::Letter, None),
+    ("ha-gujr", 0x0AB9, "uni0AB9", Category::Letter, None),
+    ("ha-guru", 0x0A39, "uni0A39", Category::Letter, None),
+    ("ha-knda", 0x0CB9, "uni0CB9", Category::Letter, None),
+    ("ha-mlym", 0x0D39, "uni0D39", Category::Letter, None),
+    ("ha-orya", 0x0B39, "uni0B39", Category::Letter, None),
+    ("ha-taml", 0x0BB9, "uni0BB9", Category::Letter, None),
+    ("ha-telu", 0x0C39, "uni0C39", Category::Letter, None),
+    ("hah-ar", 0x062D, "uni062D", Category::Letter, None),
+    ("hahHamzaAbove-ar", 0x0681, "uni0681", Category::Letter, None),
+    ("hahThreeDotsAbove-ar", 0x0685, "uni0685", Category::Letter, None),
+    ("hahTwoDotsVerticalAbove-ar", 0x0682, "uni0682", Category::Letter, None),
+    ("hamza-ar", 0x0621, "uni0621", Category::Letter, None),
+    ("hamzaAbove-ar", 0x0654, "uni0654", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hamzaBelow-ar", 0x0655, "uni0655", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hardsign-cy", 0x044A, "uni044A", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hatafPatah-hb", 0x05B2, "uni05B2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hatafQamats-hb", 0x05B3, "uni05B3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hatafSegol-hb", 0x05B1, "uni05B1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hbar", 0x0127, "hbar", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hbrevebelow", 0x1E2B, "uni1E2B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hcaron", 0x021F, "uni021F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hcedilla", 0x1E29, "uni1E29", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hcircumflex", 0x0125, "hcircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hdieresis", 0x1E27, "uni1E27", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hdotaccent", 0x1E23, "uni1E23", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hdotbelow", 0x1E25, "uni1E25", Category::Letter, Some(Subcategory::Lowercase)),
+    ("he-hb", 0x05D4, "uni05D4", Category::Letter, None),
+    ("heavyYa-deva", 0x097A, "uni097A", Category::Letter, None),
+    ("heh-ar", 0x0647, "uni0647", Category::Letter, None),
+    ("hehDoachashmee-ar", 0x06BE, "uni06BE", Category::Letter, None),
+    ("hehGoal-ar", 0x06C1, "uni06C1", Category::Letter, None),
+    ("hehGoalHamzaAbove-ar", 0x06C2, "uni06C2", Category::Letter, None),
+    ("hehInvertedV-ar", 0x06FF, "uni06FF", Category::Letter, None),
+    ("hehYehAbove-ar", 0x06C0, "uni06C0", Category::Letter, None),
+    ("het-hb", 0x05D7, "uni05D7", Category::Letter, None),
+    ("highHamza-ar", 0x0674, "uni0674", Category::Letter, None),
+    ("highHamzaAlef-ar", 0x0675, "uni0675", Category::Letter, None),
+    ("highHamzaWaw-ar", 0x0676, "uni0676", Category::Letter, None),
+    ("highHamzaYeh-ar", 0x0678, "uni0678", Category::Letter, None),
+    ("highSpacingDot-deva", 0x0971, "uni0971", Category::Letter, Some(Subcategory::Modifier)),
+    ("hiriq-hb", 0x05B4, "uni05B4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hlinebelow", 0x1E96, "uni1E96", Category::Letter, Some(Subcategory::Lowercase)),
+    ("hoHip-thai", 0x0E2B, "uni0E2B", Category::Letter, None),
+    ("hoMo-lao", 0x0EDD, "uni0EDD", Category::Letter, None),
+    ("hoNo-lao", 0x0EDC, "uni0EDC", Category::Letter, None),
+    ("hoNokhuk-thai", 0x0E2E, "uni0E2E", Category::Letter, None),
+    ("hoSung-lao", 0x0EAB, "uni0EAB", Category::Letter, None),
+    ("hoTam-lao", 0x0EAE, "uni0EAE", Category::Letter, None),
+    ("holam-hb", 0x05B9, "uni05B9", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("holamHaserForVav-hb", 0x05BA, "uni05BA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hookabovecomb", 0x0309, "hookabovecomb", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("horncomb", 0x031B, "uni031B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hungarumlaut", 0x02DD, "hungarumlaut", Category::Symbol, Some(Subcategory::Modifier)),
+    ("hungarumlautcomb", 0x030B, "uni030B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("hyphen", 0x002D, "hyphen", Category::Punctuation, Some(Subcategory::Dash)),
+    ("i", 0x0069, "i", Category::Letter, Some(Subcategory::Lowercase)),
+    ("i-beng", 0x0987, "uni0987", Category::Letter, None),
+    ("i-cy", 0x0456, "uni0456", Category::Letter, Some(Subcategory::Lowercase)),
+    ("i-deva", 0x0907, "uni0907", Category::Letter, None),
+    ("i-gujr", 0x0A87, "uni0A87", Category::Letter, None),
+    ("i-guru", 0x0A07, "uni0A07", Category::Letter, None),
+    ("i-knda", 0x0C87, "uni0C87", Category::Letter, None),
+    ("i-mlym", 0x0D07, "uni0D07", Category::Letter, None),
+    ("i-orya", 0x0B07, "uni0B07", Category::Letter, None),
+    ("i-taml", 0x0B87, "uni0B87", Category::Letter, None),
+    ("i-telu", 0x0C07, "uni0C07", Category::Letter, None),
+    ("iMatra-beng", 0x09BF, "uni09BF", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-deva", 0x093F, "uni093F", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-gujr", 0x0ABF, "uni0ABF", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-guru", 0x0A3F, "uni0A3F", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-knda", 0x0CBF, "uni0CBF", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iMatra-lao", 0x0EB4, "uni0EB4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iMatra-mlym", 0x0D3F, "uni0D3F", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-orya", 0x0B3F, "uni0B3F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iMatra-taml", 0x0BBF, "uni0BBF", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iMatra-telu", 0x0C3F, "uni0C3F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ia-cy", 0x044F, "uni044F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iacute", 0x00ED, "iacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ibreve", 0x012D, "ibreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ibreveinverted", 0x020B, "uni020B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("icaron", 0x01D0, "uni01D0", Category::Letter, Some(Subcategory::Lowercase)),
+    ("icircumflex", 0x00EE, "icircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("idblgrave", 0x0209, "uni0209", Category::Letter, Some(Subcategory::Lowercase)),
+    ("idieresis", 0x00EF, "idieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("idieresisacute", 0x1E2F, "uni1E2F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("idotbelow", 0x1ECB, "uni1ECB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ie-cy", 0x0435, "uni0435", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iegrave-cy", 0x0450, "uni0450", Category::Letter, Some(Subcategory::Lowercase)),
+    ("igrave", 0x00EC, "igrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("igrave-cy", 0x045D, "uni045D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ihookabove", 0x1EC9, "uni1EC9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ii-beng", 0x0988, "uni0988", Category::Letter, None),
+    ("ii-cy", 0x0438, "uni0438", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ii-deva", 0x0908, "uni0908", Category::Letter, None),
+    ("ii-gujr", 0x0A88, "uni0A88", Category::Letter, None),
+    ("ii-guru", 0x0A08, "uni0A08", Category::Letter, None),
+    ("ii-knda", 0x0C88, "uni0C88", Category::Letter, None),
+    ("ii-mlym", 0x0D08, "uni0D08", Category::Letter, None),
+    ("ii-orya", 0x0B08, "uni0B08", Category::Letter, None),
+    ("ii-taml", 0x0B88, "uni0B88", Category::Letter, None),
+    ("ii-telu", 0x0C08, "uni0C08", Category::Letter, None),
+    ("iiMatra-beng", 0x09C0, "uni09C0", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-deva", 0x0940, "uni0940", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-gujr", 0x0AC0, "uni0AC0", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-guru", 0x0A40, "uni0A40", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-knda", 0x0CC0, "uni0CC0", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-lao", 0x0EB5, "uni0EB5", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iiMatra-mlym", 0x0D40, "uni0D40", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-orya", 0x0B40, "uni0B40", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("iiMatra-taml", 0x0BC0, "uni0BC0", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iiMatra-telu", 0x0C40, "uni0C40", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("iishort-cy", 0x0439, "uni0439", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ij", 0x0133, "ij", Category::Letter, Some(Subcategory::Ligature)),
+    ("imacron", 0x012B, "imacron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("infinity", 0x221E, "infinity", Category::Symbol, Some(Subcategory::Math)),
+    ("integral", 0x222B, "integral", Category::Symbol, Some(Subcategory::Math)),
+    ("invertedCandrabindu-deva", 0x0900, "uni0900", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("invertedDamma-ar", 0x0657, "uni0657", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("io-cy", 0x0451, "uni0451", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iogonek", 0x012F, "iogonek", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iota", 0x03B9, "iota", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iotadieresis", 0x03CA, "iotadieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iotadieresistonos", 0x0390, "iotadieresistonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iotatonos", 0x03AF, "iotatonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iri-guru", 0x0A72, "uni0A72", Category::Letter, None),
+    ("isshar-beng", 0x09FA, "uni09FA", Category::Symbol, None),
+    ("isshar-orya", 0x0B70, "uni0B70", Category::Symbol, None),
+    ("itilde", 0x0129, "itilde", Category::Letter, Some(Subcategory::Lowercase)),
+    ("itildebelow", 0x1E2D, "uni1E2D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("iu-cy", 0x044E, "uni044E", Category::Letter, Some(Subcategory::Lowercase)),
+    ("j", 0x006A, "j", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ja-beng", 0x099C, "uni099C", Category::Letter, None),
+    ("ja-deva", 0x091C, "uni091C", Category::Letter, None),
+    ("ja-gujr", 0x0A9C, "uni0A9C", Category::Letter, None),
+    ("ja-guru", 0x0A1C, "uni0A1C", Category::Letter, None),
+    ("ja-knda", 0x0C9C, "uni0C9C", Category::Letter, None),
+    ("ja-mlym", 0x0D1C, "uni0D1C", Category::Letter, None),
+    ("ja-orya", 0x0B1C, "uni0B1C", Category::Letter, None),
+    ("ja-taml", 0x0B9C, "uni0B9C", Category::Letter, None),
+    ("ja-telu", 0x0C1C, "uni0C1C", Category::Letter, None),
+    ("jcaron", 0x01F0, "uni01F0", Category::Letter, Some(Subcategory::Lowercase)),
+    ("jcircumflex", 0x0135, "jcircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("je-cy", 0x0458, "uni0458", Category::Letter, Some(Subcategory::Lowercase)),
+    ("jeem-ar", 0x062C, "uni062C", Category::Letter, None),
+    ("jeh-ar", 0x0698, "uni0698", Category::Letter, None),
+    ("jha-beng", 0x099D, "uni099D", Category::Letter, None),
+    ("jha-deva", 0x091D, "uni091D", Category::Letter, None),
+    ("jha-gujr", 0x0A9D, "uni0A9D", Category::Letter, None),
+    ("jha-guru", 0x0A1D, "uni0A1D", Category::Letter, None),
+    ("jha-knda", 0x0C9D, "uni0C9D", Category::Letter, None),
+    ("jha-mlym", 0x0D1D, "uni0D1D", Category::Letter, None),
+    ("jha-orya", 0x0B1D, "uni0B1D", Category::Letter, None),
+    ("jha-telu", 0x0C1D, "uni0C1D", Category::Letter, None),
+    ("jihvamuliya-knda", 0x0CF1, "uni0CF1", Category::Letter, None),
+    ("jja-deva", 0x097C, "uni097C", Category::Letter, None),
+    ("jstroke", 0x0249, "uni0249", Category::Letter, Some(Subcategory::Lowercase)),
+    ("k", 0x006B, "k", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ka-beng", 0x0995, "uni0995", Category::Letter, None),
+    ("ka-cy", 0x043A, "uni043A", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ka-deva", 0x0915, "uni0915", Category::Letter, None),
+    ("ka-gujr", 0x0A95, "uni0A95", Category::Letter, None),
+    ("ka-guru", 0x0A15, "uni0A15", Category::Letter, None),
+    ("ka-knda", 0x0C95, "uni0C95", Category::Letter, None),
+    ("ka-mlym", 0x0D15, "uni0D15", Category::Letter, None),
+    ("ka-orya", 0x0B15, "uni0B15", Category::Letter, None),
+    ("ka-taml", 0x0B95, "uni0B95", Category::Letter, None),
+    ("ka-telu", 0x0C15, "uni0C15", Category::Letter, None),
+    ("kacute", 0x1E31, "uni1E31", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kaf-ar", 0x0643, "uni0643", Category::Letter, None),
+    ("kaf-hb", 0x05DB, "uni05DB", Category::Letter, None),
+    ("kafDotAbove-ar", 0x06AC, "uni06AC", Category::Letter, None),
+    ("kafRing-ar", 0x06AB, "uni06AB", Category::Letter, None),
+    ("kafThreeDotsBelow-ar", 0x06AE, "uni06AE", Category::Letter, None),
+    ("kappa", 0x03BA, "kappa", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kashmiriYeh-ar", 0x0620, "uni0620", Category::Letter, None),
+    ("kasra-ar", 0x0650, "uni0650", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("kasratan-ar", 0x064D, "uni064D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("kcaron", 0x01E9, "uni01E9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kcommaaccent", 0x0137, "kcommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kdotbelow", 0x1E33, "uni1E33", Category::Letter, Some(Subcategory::Lowercase)),
+    ("keheh-ar", 0x06A9, "uni06A9", Category::Letter, None),
+    ("kehehThreeDotsBelow-ar", 0x063C, "uni063C", Category::Letter, None),
+    ("kehehTwoDotsAbove-ar", 0x063B, "uni063B", Category::Letter, None),
+    ("kgreenlandic", 0x0138, "kgreenlandic", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kha-beng", 0x0996, "uni0996", Category::Letter, None),
+    ("kha-deva", 0x0916, "uni0916", Category::Letter, None),
+    ("kha-gujr", 0x0A96, "uni0A96", Category::Letter, None),
+    ("kha-guru", 0x0A16, "uni0A16", Category::Letter, None),
+    ("kha-knda", 0x0C96, "uni0C96", Category::Letter, None),
+    ("kha-mlym", 0x0D16, "uni0D16", Category::Letter, None),
+    ("kha-orya", 0x0B16, "uni0B16", Category::Letter, None),
+    ("kha-telu", 0x0C16, "uni0C16", Category::Letter, None),
+    ("khah-ar", 0x062E, "uni062E", Category::Letter, None),
+    ("khandaTa-beng", 0x09CE, "uni09CE", Category::Letter, None),
+    ("khha-deva", 0x0959, "uni0959", Category::Letter, None),
+    ("khha-guru", 0x0A59, "uni0A59", Category::Letter, None),
+    ("khmuGo-lao", 0x0EDE, "uni0EDE", Category::Letter, None),
+    ("khmuNyo-lao", 0x0EDF, "uni0EDF", Category::Letter, None),
+    ("khoKhai-thai", 0x0E02, "uni0E02", Category::Letter, None),
+    ("khoKhon-thai", 0x0E05, "uni0E05", Category::Letter, None),
+    ("khoKhuat-thai", 0x0E03, "uni0E03", Category::Letter, None),
+    ("khoKhwai-thai", 0x0E04, "uni0E04", Category::Letter, None),
+    ("khoRakhang-thai", 0x0E06, "uni0E06", Category::Letter, None),
+    ("khoSung-lao", 0x0E82, "uni0E82", Category::Letter, None),
+    ("khoTam-lao", 0x0E84, "uni0E84", Category::Letter, None),
+    ("khomut-thai", 0x0E5B, "uni0E5B", Category::Punctuation, None),
+    ("khook", 0x0199, "uni0199", Category::Letter, Some(Subcategory::Lowercase)),
+    ("kirghizOe-ar", 0x06C5, "uni06C5", Category::Letter, None),
+    ("kirghizYu-ar", 0x06C9, "uni06C9", Category::Letter, None),
+    ("kje-cy", 0x045C, "uni045C", Category::Letter, Some(Subcategory::Lowercase)),
+    ("klinebelow", 0x1E35, "uni1E35", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ko-lao", 0x0E81, "uni0E81", Category::Letter, None),
+    ("koKai-thai", 0x0E01, "uni0E01", Category::Letter, None),
+    ("koLa-lao", 0x0EC6, "uni0EC6", Category::Letter, Some(Subcategory::Modifier)),
+    ("l", 0x006C, "l", Category::Letter, Some(Subcategory::Lowercase)),
+    ("la-beng", 0x09B2, "uni09B2", Category::Letter, None),
+    ("la-deva", 0x0932, "uni0932", Category::Letter, None),
+    ("la-gujr", 0x0AB2, "uni0AB2", Category::Letter, None),
+    ("la-guru", 0x0A32, "uni0A32", Category::Letter, None),
+    ("la-knda", 0x0CB2, "uni0CB2", Category::Letter, None),
+    ("la-mlym", 0x0D32, "uni0D32", Category::Letter, None),
+    ("la-orya", 0x0B32, "uni0B32", Category::Letter, None),
+    ("la-taml", 0x0BB2, "uni0BB2", Category::Letter, None),
+    ("la-telu", 0x0C32, "uni0C32", Category::Letter, None),
+    ("lacute", 0x013A, "lacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lakkhangyao-thai", 0x0E45, "uni0E45", Category::Letter, None),
+    ("lam-ar", 0x0644, "uni0644", Category::Letter, None),
+    ("lamDotAbove-ar", 0x06B6, "uni06B6", Category::Letter, None),
+    ("lamSmallV-ar", 0x06B5, "uni06B5", Category::Letter, None),
+    ("lamThreeDotsAbove-ar", 0x06B7, "uni06B7", Category::Letter, None),
+    ("lamThreeDotsBelow-ar", 0x06B8, "uni06B8", Category::Letter, None),
+    ("lambda", 0x03BB, "lambda", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lamed-hb", 0x05DC, "uni05DC", Category::Letter, None),
+    ("lcaron", 0x013E, "lcaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lcircumflexbelow", 0x1E3D, "uni1E3D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lcommaaccent", 0x013C, "lcommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ldot", 0x0140, "ldot", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ldotbelow", 0x1E37, "uni1E37", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ldotbelowmacron", 0x1E39, "uni1E39", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lengthMark-knda", 0x0CD5, "uni0CD5", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("lengthMark-telu", 0x0C55, "uni0C55", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("less", 0x003C, "less", Category::Symbol, Some(Subcategory::Math)),
+    ("lessequal", 0x2264, "lessequal", Category::Symbol, Some(Subcategory::Math)),
+    ("ligatureYiddishDoubleVav-hb", 0x05F0, "uni05F0", Category::Letter, Some(Subcategory::Ligature)),
+    ("ligatureYiddishDoubleYod-hb", 0x05F2, "uni05F2", Category::Letter, Some(Subcategory::Ligature)),
+    ("ligatureYiddishVavYod-hb", 0x05F1, "uni05F1", Category::Letter, Some(Subcategory::Ligature)),
+    ("lje-cy", 0x0459, "uni0459", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lla-deva", 0x0933, "uni0933", Category::Letter, None),
+    ("lla-gujr", 0x0AB3, "uni0AB3", Category::Letter, None),
+    ("lla-guru", 0x0A33, "uni0A33", Category::Letter, None),
+    ("lla-knda", 0x0CB3, "uni0CB3", Category::Letter, None),
+    ("lla-mlym", 0x0D33, "uni0D33", Category::Letter, None),
+    ("lla-orya", 0x0B33, "uni0B33", Category::Letter, None),
+    ("lla-taml", 0x0BB3, "uni0BB3", Category::Letter, None),
+    ("lla-telu", 0x0C33, "uni0C33", Category::Letter, None),
+    ("llinebelow", 0x1E3B, "uni1E3B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("llla-deva", 0x0934, "uni0934", Category::Letter, None),
+    ("llla-mlym", 0x0D34, "uni0D34", Category::Letter, None),
+    ("llla-taml", 0x0BB4, "uni0BB4", Category::Letter, None),
+    ("llla-telu", 0x0C34, "uni0C34", Category::Letter, None),
+    ("loChula-thai", 0x0E2C, "uni0E2C", Category::Letter, None),
+    ("loLing-lao", 0x0EA3, "uni0EA3", Category::Letter, None),
+    ("loLing-thai", 0x0E25, "uni0E25", Category::Letter, None),
+    ("loLoot-lao", 0x0EA5, "uni0EA5", Category::Letter, None),
+    ("logicalnot", 0x00AC, "logicalnot", Category::Symbol, Some(Subcategory::Math)),
+    ("longs", 0x017F, "longs", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lozenge", 0x25CA, "lozenge", Category::Symbol, None),
+    ("lslash", 0x0142, "lslash", Category::Letter, Some(Subcategory::Lowercase)),
+    ("lu-thai", 0x0E26, "uni0E26", Category::Letter, None),
+    ("m", 0x006D, "m", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ma-beng", 0x09AE, "uni09AE", Category::Letter, None),
+    ("ma-deva", 0x092E, "uni092E", Category::Letter, None),
+    ("ma-gujr", 0x0AAE, "uni0AAE", Category::Letter, None),
+    ("ma-guru", 0x0A2E, "uni0A2E", Category::Letter, None),
+    ("ma-knda", 0x0CAE, "uni0CAE", Category::Letter, None),
+    ("ma-mlym", 0x0D2E, "uni0D2E", Category::Letter, None),
+    ("ma-orya", 0x0B2E, "uni0B2E", Category::Letter, None),
+    ("ma-taml", 0x0BAE, "uni0BAE", Category::Letter, None),
+    ("ma-telu", 0x0C2E, "uni0C2E", Category::Letter, None),
+    ("macron", 0x00AF, "macron", Category::Symbol, Some(Subcategory::Modifier)),
+    ("macronbelowcomb", 0x0331, "uni0331", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("macroncomb", 0x0304, "uni0304", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("macute", 0x1E3F, "uni1E3F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("maddah-gujr", 0x0AFC, "uni0AFC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maddahAbove-ar", 0x0653, "uni0653", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiChattawa-thai", 0x0E4B, "uni0E4B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiEk-thai", 0x0E48, "uni0E48", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiHanAkat-thai", 0x0E31, "uni0E31", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiKanMatra-lao", 0x0EB1, "uni0EB1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiKonMatra-lao", 0x0EBB, "uni0EBB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiTho-thai", 0x0E49, "uni0E49", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiTri-thai", 0x0E4A, "uni0E4A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maitaikhu-thai", 0x0E47, "uni0E47", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("maiyamok-thai", 0x0E46, "uni0E46", Category::Letter, Some(Subcategory::Modifier)),
+    ("maqaf-hb", 0x05BE, "uni05BE", Category::Punctuation, Some(Subcategory::Dash)),
+    ("mark-ar", 0x061C, "uni061C", Category::Other, Some(Subcategory::Format)),
+    ("markLowerDot-hb", 0x05C5, "uni05C5", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("markMasoraCircle-hb", 0x05AF, "uni05AF", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("markNoonGhunna-ar", 0x0658, "uni0658", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("markUpperDot-hb", 0x05C4, "uni05C4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("marwariDda-deva", 0x0978, "uni0978", Category::Letter, None),
+    ("mdotaccent", 0x1E41, "uni1E41", Category::Letter, Some(Subcategory::Lowercase)),
+    ("mdotbelow", 0x1E43, "uni1E43", Category::Letter, Some(Subcategory::Lowercase)),
+    ("meem-ar", 0x0645, "uni0645", Category::Letter, None),
+    ("mem-hb", 0x05DE, "uni05DE", Category::Letter, None),
+    ("meteg-hb", 0x05BD, "uni05BD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("micro", 0x00B5, "uni00B5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("minus", 0x2212, "minus", Category::Symbol, Some(Subcategory::Math)),
+    ("misra-ar", 0x060F, "uni060F", Category::Symbol, None),
+    ("mo-lao", 0x0EA1, "uni0EA1", Category::Letter, None),
+    ("moMa-thai", 0x0E21, "uni0E21", Category::Letter, None),
+    ("month-taml", 0x0BF4, "uni0BF4", Category::Symbol, None),
+    ("mu", 0x03BC, "uni03BC", Category::Letter, Some(Subcategory::Lowercase)),
+    ("multiply", 0x00D7, "multiply", Category::Symbol, Some(Subcategory::Math)),
+    ("n", 0x006E, "n", Category::Letter, Some(Subcategory::Lowercase)),
+    ("na-beng", 0x09A8, "uni09A8", Category::Letter, None),
+    ("na-deva", 0x0928, "uni0928", Category::Letter, None),
+    ("na-gujr", 0x0AA8, "uni0AA8", Category::Letter, None),
+    ("na-guru", 0x0A28, "uni0A28", Category::Letter, None),
+    ("na-knda", 0x0CA8, "uni0CA8", Category::Letter, None),
+    ("na-mlym", 0x0D28, "uni0D28", Category::Letter, None),
+    ("na-orya", 0x0B28, "uni0B28", Category::Letter, None),
+    ("na-taml", 0x0BA8, "uni0BA8", Category::Letter, None),
+    ("na-telu", 0x0C28, "uni0C28", Category::Letter, None),
+    ("nacute", 0x0144, "nacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nakaaraPollu-knda", 0x0CDD, "uni0CDD", Category::Letter, None),
+    ("nakaaraPollu-telu", 0x0C5D, "uni0C5D", Category::Letter, None),
+    ("napostrophe", 0x0149, "napostrophe", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nbspace", 0x00A0, "uni00A0", Category::Separator, Some(Subcategory::Space)),
+    ("ncaron", 0x0148, "ncaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ncircumflexbelow", 0x1E4B, "uni1E4B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ncommaaccent", 0x0146, "ncommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ndotaccent", 0x1E45, "uni1E45", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ndotbelow", 0x1E47, "uni1E47", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ng-ar", 0x06AD, "uni06AD", Category::Letter, None),
+    ("nga-beng", 0x0999, "uni0999", Category::Letter, None),
+    ("nga-deva", 0x0919, "uni0919", Category::Letter, None),
+    ("nga-gujr", 0x0A99, "uni0A99", Category::Letter, None),
+    ("nga-guru", 0x0A19, "uni0A19", Category::Letter, None),
+    ("nga-knda", 0x0C99, "uni0C99", Category::Letter, None),
+    ("nga-mlym", 0x0D19, "uni0D19", Category::Letter, None),
+    ("nga-orya", 0x0B19, "uni0B19", Category::Letter, None),
+    ("nga-taml", 0x0B99, "uni0B99", Category::Letter, None),
+    ("nga-telu", 0x0C19, "uni0C19", Category::Letter, None),
+    ("ngo-lao", 0x0E87, "uni0E87", Category::Letter, None),
+    ("ngoNgu-thai", 0x0E07, "uni0E07", Category::Letter, None),
+    ("ngoeh-ar", 0x06B1, "uni06B1", Category::Letter, None),
+    ("ngrave", 0x01F9, "uni01F9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("niggahita-lao", 0x0ECD, "uni0ECD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nikhahit-thai", 0x0E4D, "uni0E4D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nine", 0x0039, "nine", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-ar", 0x0669, "uni0669", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-beng", 0x09EF, "uni09EF", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-deva", 0x096F, "uni096F", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-gujr", 0x0AEF, "uni0AEF", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-guru", 0x0A6F, "uni0A6F", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-knda", 0x0CEF, "uni0CEF", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-lao", 0x0ED9, "uni0ED9", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-mlym", 0x0D6F, "uni0D6F", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-orya", 0x0B6F, "uni0B6F", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-taml", 0x0BEF, "uni0BEF", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-telu", 0x0C6F, "uni0C6F", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nine-thai", 0x0E59, "uni0E59", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("nje-cy", 0x045A, "uni045A", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nlinebelow", 0x1E49, "uni1E49", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nna-beng", 0x09A3, "uni09A3", Category::Letter, None),
+    ("nna-deva", 0x0923, "uni0923", Category::Letter, None),
+    ("nna-gujr", 0x0AA3, "uni0AA3", Category::Letter, None),
+    ("nna-guru", 0x0A23, "uni0A23", Category::Letter, None),
+    ("nna-knda", 0x0CA3, "uni0CA3", Category::Letter, None),
+    ("nna-mlym", 0x0D23, "uni0D23", Category::Letter, None),
+    ("nna-orya", 0x0B23, "uni0B23", Category::Letter, None),
+    ("nna-taml", 0x0BA3, "uni0BA3", Category::Letter, None),
+    ("nna-telu", 0x0C23, "uni0C23", Category::Letter, None),
+    ("nnna-deva", 0x0929, "uni0929", Category::Letter, None),
+    ("nnna-mlym", 0x0D29, "uni0D29", Category::Letter, None),
+    ("nnna-taml", 0x0BA9, "uni0BA9", Category::Letter, None),
+    ("no-lao", 0x0E99, "uni0E99", Category::Letter, None),
+    ("noNen-thai", 0x0E13, "uni0E13", Category::Letter, None),
+    ("noNu-thai", 0x0E19, "uni0E19", Category::Letter, None),
+    ("noon-ar", 0x0646, "uni0646", Category::Letter, None),
+    ("noonDotBelow-ar", 0x06B9, "uni06B9", Category::Letter, None),
+    ("noonGhunna-ar", 0x06BA, "uni06BA", Category::Letter, None),
+    ("noonRing-ar", 0x06BC, "uni06BC", Category::Letter, None),
+    ("noonThreeDotsAbove-ar", 0x06BD, "uni06BD", Category::Letter, None),
+    ("notequal", 0x2260, "notequal", Category::Symbol, Some(Subcategory::Math)),
+    ("ntilde", 0x00F1, "ntilde", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nu", 0x03BD, "nu", Category::Letter, Some(Subcategory::Lowercase)),
+    ("nukta-beng", 0x09BC, "uni09BC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-deva", 0x093C, "uni093C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-gujr", 0x0ABC, "uni0ABC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-guru", 0x0A3C, "uni0A3C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-knda", 0x0CBC, "uni0CBC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-orya", 0x0B3C, "uni0B3C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("nukta-telu", 0x0C3C, "uni0C3C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("number-ar", 0x0600, "uni0600", Category::Other, Some(Subcategory::Format)),
+    ("number-taml", 0x0BFA, "uni0BFA", Category::Symbol, None),
+    ("numberMarkAbove-ar", 0x0605, "uni0605", Category::Other, Some(Subcategory::Format)),
+    ("numberOneHundred-mlym", 0x0D71, "uni0D71", Category::Number, None),
+    ("numberOneHundred-taml", 0x0BF1, "uni0BF1", Category::Number, None),
+    ("numberOneThousand-mlym", 0x0D72, "uni0D72", Category::Number, None),
+    ("numberOneThousand-taml", 0x0BF2, "uni0BF2", Category::Number, None),
+    ("numberTen-mlym", 0x0D70, "uni0D70", Category::Number, None),
+    ("numberTen-taml", 0x0BF0, "uni0BF0", Category::Number, None),
+    ("numbersign", 0x0023, "numbersign", Category::Punctuation, None),
+    ("nun-hb", 0x05E0, "uni05E0", Category::Letter, None),
+    ("nunHafukha-hb", 0x05C6, "uni05C6", Category::Punctuation, None),
+    ("nya-beng", 0x099E, "uni099E", Category::Letter, None),
+    ("nya-deva", 0x091E, "uni091E", Category::Letter, None),
+    ("nya-gujr", 0x0A9E, "uni0A9E", Category::Letter, None),
+    ("nya-guru", 0x0A1E, "uni0A1E", Category::Letter, None),
+    ("nya-knda", 0x0C9E, "uni0C9E", Category::Letter, None),
+    ("nya-mlym", 0x0D1E, "uni0D1E", Category::Letter, None),
+    ("nya-orya", 0x0B1E, "uni0B1E", Category::Letter, None),
+    ("nya-taml", 0x0B9E, "uni0B9E", Category::Letter, None),
+    ("nya-telu", 0x0C1E, "uni0C1E", Category::Letter, None),
+    ("nyeh-ar", 0x0683, "uni0683", Category::Letter, None),
+    ("nyo-lao", 0x0E8D, "uni0E8D", Category::Letter, None),
+    ("o", 0x006F, "o", Category::Letter, Some(Subcategory::Lowercase)),
+    ("o-beng", 0x0993, "uni0993", Category::Letter, None),
+    ("o-cy", 0x043E, "uni043E", Category::Letter, Some(Subcategory::Lowercase)),
+    ("o-deva", 0x0913, "uni0913", Category::Letter, None),
+    ("o-gujr", 0x0A93, "uni0A93", Category::Letter, None),
+    ("o-knda", 0x0C92, "uni0C92", Category::Letter, None),
+    ("o-lao", 0x0EAD, "uni0EAD", Category::Letter, None),
+    ("o-mlym", 0x0D12, "uni0D12", Category::Letter, None),
+    ("o-orya", 0x0B13, "uni0B13", Category::Letter, None),
+    ("o-taml", 0x0B92, "uni0B92", Category::Letter, None),
+    ("o-telu", 0x0C12, "uni0C12", Category::Letter, None),
+    ("oAng-thai", 0x0E2D, "uni0E2D", Category::Letter, None),
+    ("oMatra-beng", 0x09CB, "uni09CB", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-deva", 0x094B, "uni094B", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-gujr", 0x0ACB, "uni0ACB", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-knda", 0x0CCA, "uni0CCA", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-lao", 0x0EC2, "uni0EC2", Category::Letter, None),
+    ("oMatra-mlym", 0x0D4A, "uni0D4A", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-orya", 0x0B4B, "uni0B4B", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-taml", 0x0BCA, "uni0BCA", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oMatra-telu", 0x0C4A, "uni0C4A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("oacute", 0x00F3, "oacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("obreve", 0x014F, "obreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("obreveinverted", 0x020F, "uni020F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocaron", 0x01D2, "uni01D2", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflex", 0x00F4, "ocircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflexacute", 0x1ED1, "uni1ED1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflexdotbelow", 0x1ED9, "uni1ED9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflexgrave", 0x1ED3, "uni1ED3", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflexhookabove", 0x1ED5, "uni1ED5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ocircumflextilde", 0x1ED7, "uni1ED7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odblgrave", 0x020D, "uni020D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odieresis", 0x00F6, "odieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odieresismacron", 0x022B, "uni022B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odotaccent", 0x022F, "uni022F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odotaccentmacron", 0x0231, "uni0231", Category::Letter, Some(Subcategory::Lowercase)),
+    ("odotbelow", 0x1ECD, "uni1ECD", Category::Letter, Some(Subcategory::Lowercase)),
+    ("oe", 0x0153, "oe", Category::Letter, Some(Subcategory::Ligature)),
+    ("oe-ar", 0x06C6, "uni06C6", Category::Letter, None),
+    ("oe-deva", 0x0973, "uni0973", Category::Letter, None),
+    ("oeMatra-deva", 0x093A, "uni093A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ogonek", 0x02DB, "ogonek", Category::Symbol, Some(Subcategory::Modifier)),
+    ("ogonekcomb", 0x0328, "uni0328", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ograve", 0x00F2, "ograve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohookabove", 0x1ECF, "uni1ECF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohorn", 0x01A1, "uni01A1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohornacute", 0x1EDB, "uni1EDB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohorndotbelow", 0x1EE3, "uni1EE3", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohorngrave", 0x1EDD, "uni1EDD", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohornhookabove", 0x1EDF, "uni1EDF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohorntilde", 0x1EE1, "uni1EE1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ohungarumlaut", 0x0151, "ohungarumlaut", Category::Letter, Some(Subcategory::Lowercase)),
+    ("om-deva", 0x0950, "uni0950", Category::Letter, None),
+    ("om-gujr", 0x0AD0, "uni0AD0", Category::Letter, None),
+    ("om-taml", 0x0BD0, "uni0BD0", Category::Letter, None),
+    ("omacron", 0x014D, "omacron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omacronacute", 0x1E53, "uni1E53", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omacrongrave", 0x1E51, "uni1E51", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omega", 0x03C9, "omega", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omegatonos", 0x03CE, "omegatonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omicron", 0x03BF, "omicron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("omicrontonos", 0x03CC, "omicrontonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("one", 0x0031, "one", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-ar", 0x0661, "uni0661", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-beng", 0x09E7, "uni09E7", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-deva", 0x0967, "uni0967", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-gujr", 0x0AE7, "uni0AE7", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-guru", 0x0A67, "uni0A67", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-knda", 0x0CE7, "uni0CE7", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-lao", 0x0ED1, "uni0ED1", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-mlym", 0x0D67, "uni0D67", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-orya", 0x0B67, "uni0B67", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-taml", 0x0BE7, "uni0BE7", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-telu", 0x0C67, "uni0C67", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("one-thai", 0x0E51, "uni0E51", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("onehalf", 0x00BD, "onehalf", Category::Number, None),
+    ("onequarter", 0x00BC, "onequarter", Category::Number, None),
+    ("onesuperior", 0x00B9, "onesuperior", Category::Number, None),
+    ("oo-guru", 0x0A13, "uni0A13", Category::Letter, None),
+    ("oo-knda", 0x0C93, "uni0C93", Category::Letter, None),
+    ("oo-mlym", 0x0D13, "uni0D13", Category::Letter, None),
+    ("oo-taml", 0x0B93, "uni0B93", Category::Letter, None),
+    ("oo-telu", 0x0C13, "uni0C13", Category::Letter, None),
+    ("ooMatra-guru", 0x0A4B, "uni0A4B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ooMatra-knda", 0x0CCB, "uni0CCB", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("ooMatra-mlym", 0x0D4B, "uni0D4B", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("ooMatra-taml", 0x0BCB, "uni0BCB", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("ooMatra-telu", 0x0C4B, "uni0C4B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ooe-deva", 0x0974, "uni0974", Category::Letter, None),
+    ("ooeMatra-deva", 0x093B, "uni093B", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("oogonek", 0x01EB, "uni01EB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("oogonekmacron", 0x01ED, "uni01ED", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ordfeminine", 0x00AA, "ordfeminine", Category::Letter, None),
+    ("ordmasculine", 0x00BA, "ordmasculine", Category::Letter, None),
+    ("oslash", 0x00F8, "oslash", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ostrokeacute", 0x01FF, "uni01FF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("otilde", 0x00F5, "otilde", Category::Letter, Some(Subcategory::Lowercase)),
+    ("otildeacute", 0x1E4D, "uni1E4D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("otildedieresis", 0x1E4F, "uni1E4F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("otildemacron", 0x022D, "uni022D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("overline-orya", 0x0B55, "uni0B55", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("p", 0x0070, "p", Category::Letter, Some(Subcategory::Lowercase)),
+    ("pa-beng", 0x09AA, "uni09AA", Category::Letter, None),
+    ("pa-deva", 0x092A, "uni092A", Category::Letter, None),
+    ("pa-gujr", 0x0AAA, "uni0AAA", Category::Letter, None),
+    ("pa-guru", 0x0A2A, "uni0A2A", Category::Letter, None),
+    ("pa-knda", 0x0CAA, "uni0CAA", Category::Letter, None),
+    ("pa-mlym", 0x0D2A, "uni0D2A", Category::Letter, None),
+    ("pa-orya", 0x0B2A, "uni0B2A", Category::Letter, None),
+    ("pa-taml", 0x0BAA, "uni0BAA", Category::Letter, None),
+    ("pa-telu", 0x0C2A, "uni0C2A", Category::Letter, None),
+    ("pacute", 0x1E55, "uni1E55", Category::Letter, Some(Subcategory::Lowercase)),
+    ("paiyannoi-thai", 0x0E2F, "uni0E2F", Category::Letter, None),
+    ("paliBha-lao", 0x0EA0, "uni0EA0", Category::Letter, None),
+    ("paliCha-lao", 0x0E89, "uni0E89", Category::Letter, None),
+    ("paliDda-lao", 0x0E91, "uni0E91", Category::Letter, None),
+    ("paliDdha-lao", 0x0E92, "uni0E92", Category::Letter, None),
+    ("paliDha-lao", 0x0E98, "uni0E98", Category::Letter, None),
+    ("paliGha-lao", 0x0E86, "uni0E86", Category::Letter, None),
+    ("paliJha-lao", 0x0E8C, "uni0E8C", Category::Letter, None),
+    ("paliLla-lao", 0x0EAC, "uni0EAC", Category::Letter, None),
+    ("paliNna-lao", 0x0E93, "uni0E93", Category::Letter, None),
+    ("paliNya-lao", 0x0E8E, "uni0E8E", Category::Letter, None),
+    ("paliTta-lao", 0x0E8F, "uni0E8F", Category::Letter, None),
+    ("paliTtha-lao", 0x0E90, "uni0E90", Category::Letter, None),
+    ("paliVirama-lao", 0x0EBA, "uni0EBA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("para-mlym", 0x0D4F, "uni0D4F", Category::Symbol, None),
+    ("paragraph", 0x00B6, "paragraph", Category::Punctuation, None),
+    ("parenleft", 0x0028, "parenleft", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("parenright", 0x0029, "parenright", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("partialdiff", 0x2202, "partialdiff", Category::Symbol, Some(Subcategory::Math)),
+    ("paseq-hb", 0x05C0, "uni05C0", Category::Punctuation, None),
+    ("patah-hb", 0x05B7, "uni05B7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("pdotaccent", 0x1E57, "uni1E57", Category::Letter, Some(Subcategory::Lowercase)),
+    ("pe-cy", 0x043F, "uni043F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("pe-hb", 0x05E4, "uni05E4", Category::Letter, None),
+    ("peh-ar", 0x067E, "uni067E", Category::Letter, None),
+    ("peheh-ar", 0x06A6, "uni06A6", Category::Letter, None),
+    ("perMille-ar", 0x0609, "uni0609", Category::Punctuation, None),
+    ("perTenThousand-ar", 0x060A, "uni060A", Category::Punctuation, None),
+    ("percent", 0x0025, "percent", Category::Punctuation, None),
+    ("percent-ar", 0x066A, "uni066A", Category::Punctuation, None),
+    ("period", 0x002E, "period", Category::Punctuation, None),
+    ("periodcentered", 0x00B7, "periodcentered", Category::Punctuation, None),
+    ("perthousand", 0x2030, "perthousand", Category::Punctuation, None),
+    ("pha-beng", 0x09AB, "uni09AB", Category::Letter, None),
+    ("pha-deva", 0x092B, "uni092B", Category::Letter, None),
+    ("pha-gujr", 0x0AAB, "uni0AAB", Category::Letter, None),
+    ("pha-guru", 0x0A2B, "uni0A2B", Category::Letter, None),
+    ("pha-knda", 0x0CAB, "uni0CAB", Category::Letter, None),
+    ("pha-mlym", 0x0D2B, "uni0D2B", Category::Letter, None),
+    ("pha-orya", 0x0B2B, "uni0B2B", Category::Letter, None),
+    ("pha-telu", 0x0C2B, "uni0C2B", Category::Letter, None),
+    ("phi", 0x03C6, "phi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("phinthu-thai", 0x0E3A, "uni0E3A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("phoPhan-thai", 0x0E1E, "uni0E1E", Category::Letter, None),
+    ("phoPhung-thai", 0x0E1C, "uni0E1C", Category::Letter, None),
+    ("phoSamphao-thai", 0x0E20, "uni0E20", Category::Letter, None),
+    ("phoSung-lao", 0x0E9C, "uni0E9C", Category::Letter, None),
+    ("phoTam-lao", 0x0E9E, "uni0E9E", Category::Letter, None),
+    ("phook", 0x01A5, "uni01A5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("pi", 0x03C0, "pi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("placeOfSajdah-ar", 0x06E9, "uni06E9", Category::Symbol, None),
+    ("plus", 0x002B, "plus", Category::Symbol, Some(Subcategory::Math)),
+    ("plusminus", 0x00B1, "plusminus", Category::Symbol, Some(Subcategory::Math)),
+    ("po-lao", 0x0E9B, "uni0E9B", Category::Letter, None),
+    ("poPla-thai", 0x0E1B, "uni0E1B", Category::Letter, None),
+    ("poeticVerse-ar", 0x060E, "uni060E", Category::Symbol, None),
+    ("prishthamatraEMatra-deva", 0x094E, "uni094E", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("product", 0x220F, "product", Category::Symbol, Some(Subcategory::Math)),
+    ("psi", 0x03C8, "psi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("q", 0x0071, "q", Category::Letter, Some(Subcategory::Lowercase)),
+    ("qa-deva", 0x0958, "uni0958", Category::Letter, None),
+    ("qaf-ar", 0x0642, "uni0642", Category::Letter, None),
+    ("qafDotAbove-ar", 0x06A7, "uni06A7", Category::Letter, None),
+    ("qafThreeDotsAbove-ar", 0x06A8, "uni06A8", Category::Letter, None),
+    ("qamats-hb", 0x05B8, "uni05B8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("qamatsQatan-hb", 0x05C7, "uni05C7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("qof-hb", 0x05E7, "uni05E7", Category::Letter, None),
+    ("qubuts-hb", 0x05BB, "uni05BB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("question", 0x003F, "question", Category::Punctuation, None),
+    ("questionMark-ar", 0x061F, "uni061F", Category::Punctuation, None),
+    ("questiondown", 0x00BF, "questiondown", Category::Punctuation, None),
+    ("quotedbl", 0x0022, "quotedbl", Category::Punctuation, None),
+    ("quotedblbase", 0x201E, "quotedblbase", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("quotedblleft", 0x201C, "quotedblleft", Category::Punctuation, Some(Subcategory::Quote)),
+    ("quotedblright", 0x201D, "quotedblright", Category::Punctuation, Some(Subcategory::Quote)),
+    ("quoteleft", 0x2018, "quoteleft", Category::Punctuation, Some(Subcategory::Quote)),
+    ("quoteright", 0x2019, "quoteright", Category::Punctuation, Some(Subcategory::Quote)),
+    ("quotesinglbase", 0x201A, "quotesinglbase", Category::Punctuation, Some(Subcategory::Parenthesis)),
+    ("quotesingle", 0x0027, "quotesingle", Category::Punctuation, None),
+    ("r", 0x0072, "r", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ra-beng", 0x09B0, "uni09B0", Category::Letter, None),
+    ("ra-deva", 0x0930, "uni0930", Category::Letter, None),
+    ("ra-gujr", 0x0AB0, "uni0AB0", Category::Letter, None),
+    ("ra-guru", 0x0A30, "uni0A30", Category::Letter, None),
+    ("ra-knda", 0x0CB0, "uni0CB0", Category::Letter, None),
+    ("ra-mlym", 0x0D30, "uni0D30", Category::Letter, None),
+    ("ra-orya", 0x0B30, "uni0B30", Category::Letter, None),
+    ("ra-taml", 0x0BB0, "uni0BB0", Category::Letter, None),
+    ("ra-telu", 0x0C30, "uni0C30", Category::Letter, None),
+    ("raLowerDiagonal-beng", 0x09F1, "uni09F1", Category::Letter, None),
+    ("raMiddleDiagonal-beng", 0x09F0, "uni09F0", Category::Letter, None),
+    ("racute", 0x0155, "racute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("radiAllahouAnhu-ar", 0x0613, "uni0613", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("radical", 0x221A, "radical", Category::Symbol, Some(Subcategory::Math)),
+    ("rafe-hb", 0x05BF, "uni05BF", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("rahmatullahAlayhe-ar", 0x0612, "uni0612", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ray-ar", 0x0608, "uni0608", Category::Symbol, Some(Subcategory::Math)),
+    ("rbreveinverted", 0x0213, "uni0213", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rcaron", 0x0159, "rcaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rcommaaccent", 0x0157, "rcommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rdblgrave", 0x0211, "uni0211", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rdotaccent", 0x1E59, "uni1E59", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rdotbelow", 0x1E5B, "uni1E5B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rdotbelowmacron", 0x1E5D, "uni1E5D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("registered", 0x00AE, "registered", Category::Symbol, None),
+    ("reh-ar", 0x0631, "uni0631", Category::Letter, None),
+    ("rehDotBelow-ar", 0x0694, "uni0694", Category::Letter, None),
+    ("rehDotBelowAndDotAbove-ar", 0x0696, "uni0696", Category::Letter, None),
+    ("rehFourDotsAbove-ar", 0x0699, "uni0699", Category::Letter, None),
+    ("rehInvertedV-ar", 0x06EF, "uni06EF", Category::Letter, None),
+    ("rehRing-ar", 0x0693, "uni0693", Category::Letter, None),
+    ("rehSmallV-ar", 0x0692, "uni0692", Category::Letter, None),
+    ("rehSmallVBelow-ar", 0x0695, "uni0695", Category::Letter, None),
+    ("rehTwoDotsAbove-ar", 0x0697, "uni0697", Category::Letter, None),
+    ("resh-hb", 0x05E8, "uni05E8", Category::Letter, None),
+    ("reversedDamma-ar", 0x065D, "uni065D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("rha-beng", 0x09DD, "uni09DD", Category::Letter, None),
+    ("rha-deva", 0x095D, "uni095D", Category::Letter, None),
+    ("rha-orya", 0x0B5D, "uni0B5D", Category::Letter, None),
+    ("rho", 0x03C1, "rho", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ring", 0x02DA, "ring", Category::Symbol, Some(Subcategory::Modifier)),
+    ("ringbelowcomb", 0x0325, "uni0325", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ringcomb", 0x030A, "uni030A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("rlinebelow", 0x1E5F, "uni1E5F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("rnoon-ar", 0x06BB, "uni06BB", Category::Letter, None),
+    ("roRua-thai", 0x0E23, "uni0E23", Category::Letter, None),
+    ("roundedHighStopFilledCentre-ar", 0x06EC, "uni06EC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("rra-beng", 0x09DC, "uni09DC", Category::Letter, None),
+    ("rra-deva", 0x0931, "uni0931", Category::Letter, None),
+    ("rra-guru", 0x0A5C, "uni0A5C", Category::Letter, None),
+    ("rra-knda", 0x0CB1, "uni0CB1", Category::Letter, None),
+    ("rra-mlym", 0x0D31, "uni0D31", Category::Letter, None),
+    ("rra-orya", 0x0B5C, "uni0B5C", Category::Letter, None),
+    ("rra-taml", 0x0BB1, "uni0BB1", Category::Letter, None),
+    ("rra-telu", 0x0C31, "uni0C31", Category::Letter, None),
+    ("rreh-ar", 0x0691, "uni0691", Category::Letter, None),
+    ("rrra-telu", 0x0C5A, "uni0C5A", Category::Letter, None),
+    ("rstroke", 0x024D, "uni024D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ru-thai", 0x0E24, "uni0E24", Category::Letter, None),
+    ("rupee-beng", 0x09F3, "uni09F3", Category::Symbol, Some(Subcategory::Currency)),
+    ("rupee-gujr", 0x0AF1, "uni0AF1", Category::Symbol, Some(Subcategory::Currency)),
+    ("rupee-taml", 0x0BF9, "uni0BF9", Category::Symbol, Some(Subcategory::Currency)),
+    ("rupeeMark-beng", 0x09F2, "uni09F2", Category::Symbol, Some(Subcategory::Currency)),
+    ("s", 0x0073, "s", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sa-beng", 0x09B8, "uni09B8", Category::Letter, None),
+    ("sa-deva", 0x0938, "uni0938", Category::Letter, None),
+    ("sa-gujr", 0x0AB8, "uni0AB8", Category::Letter, None),
+    ("sa-guru", 0x0A38, "uni0A38", Category::Letter, None),
+    ("sa-knda", 0x0CB8, "uni0CB8", Category::Letter, None),
+    ("sa-mlym", 0x0D38, "uni0D38", Category::Letter, None),
+    ("sa-orya", 0x0B38, "uni0B38", Category::Letter, None),
+    ("sa-taml", 0x0BB8, "uni0BB8", Category::Letter, None),
+    ("sa-telu", 0x0C38, "uni0C38", Category::Letter, None),
+    ("sacute", 0x015B, "sacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sacutedotaccent", 0x1E65, "uni1E65", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sad-ar", 0x0635, "uni0635", Category::Letter, None),
+    ("sadThreeDotsAbove-ar", 0x069E, "uni069E", Category::Letter, None),
+    ("sadTwoDotsBelow-ar", 0x069D, "uni069D", Category::Letter, None),
+    ("safha-ar", 0x0603, "uni0603", Category::Other, Some(Subcategory::Format)),
+    ("sallallahouAlayheWassallam-ar", 0x0610, "uni0610", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("samekh-hb", 0x05E1, "uni05E1", Category::Letter, None),
+    ("samvat-ar", 0x0604, "uni0604", Category::Other, Some(Subcategory::Format)),
+    ("sanah-ar", 0x0601, "uni0601", Category::Other, Some(Subcategory::Format)),
+    ("sandhiMark-beng", 0x09FE, "uni09FE", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("sanskritSha-lao", 0x0EA8, "uni0EA8", Category::Letter, None),
+    ("sanskritSsa-lao", 0x0EA9, "uni0EA9", Category::Letter, None),
+    ("saraA-thai", 0x0E30, "uni0E30", Category::Letter, None),
+    ("saraAa-thai", 0x0E32, "uni0E32", Category::Letter, None),
+    ("saraAe-thai", 0x0E41, "uni0E41", Category::Letter, None),
+    ("saraAiMaimalai-thai", 0x0E44, "uni0E44", Category::Letter, None),
+    ("saraAiMaimuan-thai", 0x0E43, "uni0E43", Category::Letter, None),
+    ("saraAm-thai", 0x0E33, "uni0E33", Category::Letter, None),
+    ("saraE-thai", 0x0E40, "uni0E40", Category::Letter, None),
+    ("saraI-thai", 0x0E34, "uni0E34", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("saraIi-thai", 0x0E35, "uni0E35", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("saraO-thai", 0x0E42, "uni0E42", Category::Letter, None),
+    ("saraU-thai", 0x0E38, "uni0E38", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("saraUe-thai", 0x0E36, "uni0E36", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("saraUee-thai", 0x0E37, "uni0E37", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("saraUu-thai", 0x0E39, "uni0E39", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("scaron", 0x0161, "scaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("scarondotaccent", 0x1E67, "uni1E67", Category::Letter, Some(Subcategory::Lowercase)),
+    ("scedilla", 0x015F, "scedilla", Category::Letter, Some(Subcategory::Lowercase)),
+    ("scircumflex", 0x015D, "scircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("scommaaccent", 0x0219, "scommaaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sdotaccent", 0x1E61, "uni1E61", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sdotbelow", 0x1E63, "uni1E63", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sdotbelowdotaccent", 0x1E69, "uni1E69", Category::Letter, Some(Subcategory::Lowercase)),
+    ("section", 0x00A7, "section", Category::Punctuation, None),
+    ("seen-ar", 0x0633, "uni0633", Category::Letter, None),
+    ("seenDotBelowAndDotAbove-ar", 0x069A, "uni069A", Category::Letter, None),
+    ("seenThreeDotsBelow-ar", 0x069B, "uni069B", Category::Letter, None),
+    ("seenThreeDotsBelowAndThreeDotsAbove-ar", 0x069C, "uni069C", Category::Letter, None),
+    ("segol-hb", 0x05B6, "uni05B6", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("semicolon", 0x003B, "semicolon", Category::Punctuation, None),
+    ("semicolon-ar", 0x061B, "uni061B", Category::Punctuation, None),
+    ("semivowelLo-lao", 0x0EBC, "uni0EBC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("semivowelNyo-lao", 0x0EBD, "uni0EBD", Category::Letter, None),
+    ("seven", 0x0037, "seven", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-ar", 0x0667, "uni0667", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-beng", 0x09ED, "uni09ED", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-deva", 0x096D, "uni096D", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-gujr", 0x0AED, "uni0AED", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-guru", 0x0A6D, "uni0A6D", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-knda", 0x0CED, "uni0CED", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-lao", 0x0ED7, "uni0ED7", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-mlym", 0x0D6D, "uni0D6D", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-orya", 0x0B6D, "uni0B6D", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-taml", 0x0BED, "uni0BED", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-telu", 0x0C6D, "uni0C6D", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("seven-thai", 0x0E57, "uni0E57", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("sha-beng", 0x09B6, "uni09B6", Category::Letter, None),
+    ("sha-cy", 0x0448, "uni0448", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sha-deva", 0x0936, "uni0936", Category::Letter, None),
+    ("sha-gujr", 0x0AB6, "uni0AB6", Category::Letter, None),
+    ("sha-guru", 0x0A36, "uni0A36", Category::Letter, None),
+    ("sha-knda", 0x0CB6, "uni0CB6", Category::Letter, None),
+    ("sha-mlym", 0x0D36, "uni0D36", Category::Letter, None),
+    ("sha-orya", 0x0B36, "uni0B36", Category::Letter, None),
+    ("sha-taml", 0x0BB6, "uni0BB6", Category::Letter, None),
+    ("sha-telu", 0x0C36, "uni0C36", Category::Letter, None),
+    ("shadda-ar", 0x0651, "uni0651", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("shadda-gujr", 0x0AFB, "uni0AFB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("shcha-cy", 0x0449, "uni0449", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sheen-ar", 0x0634, "uni0634", Category::Letter, None),
+    ("sheenDotBelow-ar", 0x06FA, "uni06FA", Category::Letter, None),
+    ("sheva-hb", 0x05B0, "uni05B0", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("shin-hb", 0x05E9, "uni05E9", Category::Letter, None),
+    ("shinDot-hb", 0x05C1, "uni05C1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("shortA-deva", 0x0904, "uni0904", Category::Letter, None),
+    ("shortE-deva", 0x090E, "uni090E", Category::Letter, None),
+    ("shortEMatra-deva", 0x0946, "uni0946", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("shortO-deva", 0x0912, "uni0912", Category::Letter, None),
+    ("shortOMatra-deva", 0x094A, "uni094A", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("siddham-knda", 0x0C84, "uni0C84", Category::Punctuation, None),
+    ("siddham-telu", 0x0C77, "uni0C77", Category::Punctuation, None),
+    ("sigma", 0x03C3, "sigma", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sigmafinal", 0x03C2, "sigma1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("sinDot-hb", 0x05C2, "uni05C2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("sindhiAmpersand-ar", 0x06FD, "uni06FD", Category::Symbol, None),
+    ("sindhiPostpositionMen-ar", 0x06FE, "uni06FE", Category::Symbol, None),
+    ("six", 0x0036, "six", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-ar", 0x0666, "uni0666", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-beng", 0x09EC, "uni09EC", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-deva", 0x096C, "uni096C", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-gujr", 0x0AEC, "uni0AEC", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-guru", 0x0A6C, "uni0A6C", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-knda", 0x0CEC, "uni0CEC", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-lao", 0x0ED6, "uni0ED6", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-mlym", 0x0D6C, "uni0D6C", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-orya", 0x0B6C, "uni0B6C", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-taml", 0x0BEC, "uni0BEC", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-telu", 0x0C6C, "uni0C6C", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("six-thai", 0x0E56, "uni0E56", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("slash", 0x002F, "slash", Category::Punctuation, None),
+    ("slashlongcomb", 0x0338, "uni0338", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("slashshortcomb", 0x0337, "uni0337", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallDamma-ar", 0x0619, "uni0619", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallFatha-ar", 0x0618, "uni0618", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighDotlessHeadOfKhah-ar", 0x06E1, "uni06E1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighJeem-ar", 0x06DA, "uni06DA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighLamAlef-ar", 0x06D9, "uni06D9", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighLigatureAlefLamYeh-ar", 0x0616, "uni0616", Category::Mark, Some(Subcategory::Ligature)),
+    ("smallHighLigatureQafLamAlefMaksura-ar", 0x06D7, "uni06D7", Category::Mark, Some(Subcategory::Ligature)),
+    ("smallHighLigatureSadLamAlefMaksura-ar", 0x06D6, "uni06D6", Category::Mark, Some(Subcategory::Ligature)),
+    ("smallHighMadda-ar", 0x06E4, "uni06E4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighMeemInitialForm-ar", 0x06D8, "uni06D8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighMeemIsolatedForm-ar", 0x06E2, "uni06E2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighNoon-ar", 0x06E8, "uni06E8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighRoundedZero-ar", 0x06DF, "uni06DF", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighSeen-ar", 0x06DC, "uni06DC", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighTah-ar", 0x0615, "uni0615", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighThreeDots-ar", 0x06DB, "uni06DB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighUprightRectangularZero-ar", 0x06E0, "uni06E0", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighYeh-ar", 0x06E7, "uni06E7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallHighZain-ar", 0x0617, "uni0617", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallKasra-ar", 0x061A, "uni061A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallLowMeem-ar", 0x06ED, "uni06ED", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallLowSeen-ar", 0x06E3, "uni06E3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("smallWaw-ar", 0x06E5, "uni06E5", Category::Letter, Some(Subcategory::Modifier)),
+    ("smallYeh-ar", 0x06E6, "uni06E6", Category::Letter, Some(Subcategory::Modifier)),
+    ("soRusi-thai", 0x0E29, "uni0E29", Category::Letter, None),
+    ("soSala-thai", 0x0E28, "uni0E28", Category::Letter, None),
+    ("soSo-thai", 0x0E0B, "uni0E0B", Category::Letter, None),
+    ("soSua-thai", 0x0E2A, "uni0E2A", Category::Letter, None),
+    ("soSung-lao", 0x0EAA, "uni0EAA", Category::Letter, None),
+    ("soTam-lao", 0x0E8A, "uni0E8A", Category::Letter, None),
+    ("sofPasuq-hb", 0x05C3, "uni05C3", Category::Punctuation, None),
+    ("softhyphen", 0x00AD, "uni00AD", Category::Other, Some(Subcategory::Format)),
+    ("softsign-cy", 0x044C, "uni044C", Category::Letter, Some(Subcategory::Lowercase)),
+    ("space", 0x0020, "space", Category::Separator, Some(Subcategory::Space)),
+    ("spacingCandrabindu-knda", 0x0C80, "uni0C80", Category::Letter, None),
+    ("ssa-beng", 0x09B7, "uni09B7", Category::Letter, None),
+    ("ssa-deva", 0x0937, "uni0937", Category::Letter, None),
+    ("ssa-gujr", 0x0AB7, "uni0AB7", Category::Letter, None),
+    ("ssa-knda", 0x0CB7, "uni0CB7", Category::Letter, None),
+    ("ssa-mlym", 0x0D37, "uni0D37", Category::Letter, None),
+    ("ssa-orya", 0x0B37, "uni0B37", Category::Letter, None),
+    ("ssa-taml", 0x0BB7, "uni0BB7", Category::Letter, None),
+    ("ssa-telu", 0x0C37, "uni0C37", Category::Letter, None),
+    ("startOfRubElHizb-ar", 0x06DE, "uni06DE", Category::Symbol, None),
+    ("sterling", 0x00A3, "sterling", Category::Symbol, Some(Subcategory::Currency)),
+    ("stressAnudatta-deva", 0x0952, "uni0952", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("stressUdatta-deva", 0x0951, "uni0951", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("strokeshortcomb", 0x0335, "uni0335", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("subscriptAlef-ar", 0x0656, "uni0656", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("sukun-ar", 0x0652, "uni0652", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("sukun-gujr", 0x0AFA, "uni0AFA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("summation", 0x2211, "summation", Category::Symbol, Some(Subcategory::Math)),
+    ("superscriptAlef-ar", 0x0670, "uni0670", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("swashKaf-ar", 0x06AA, "uni06AA", Category::Letter, None),
+    ("t", 0x0074, "t", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ta-beng", 0x09A4, "uni09A4", Category::Letter, None),
+    ("ta-deva", 0x0924, "uni0924", Category::Letter, None),
+    ("ta-gujr", 0x0AA4, "uni0AA4", Category::Letter, None),
+    ("ta-guru", 0x0A24, "uni0A24", Category::Letter, None),
+    ("ta-knda", 0x0CA4, "uni0CA4", Category::Letter, None),
+    ("ta-mlym", 0x0D24, "uni0D24", Category::Letter, None),
+    ("ta-orya", 0x0B24, "uni0B24", Category::Letter, None),
+    ("ta-taml", 0x0BA4, "uni0BA4", Category::Letter, None),
+    ("ta-telu", 0x0C24, "uni0C24", Category::Letter, None),
+    ("tah-ar", 0x0637, "uni0637", Category::Letter, None),
+    ("tahThreeDotsAbove-ar", 0x069F, "uni069F", Category::Letter, None),
+    ("takhallus-ar", 0x0614, "uni0614", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("tatweel-ar", 0x0640, "uni0640", Category::Letter, Some(Subcategory::Modifier)),
+    ("tau", 0x03C4, "tau", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tav-hb", 0x05EA, "uni05EA", Category::Letter, None),
+    ("tbar", 0x0167, "tbar", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tcaron", 0x0165, "tcaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tcedilla", 0x0163, "uni0163", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tcheh-ar", 0x0686, "uni0686", Category::Letter, None),
+    ("tchehDotAbove-ar", 0x06BF, "uni06BF", Category::Letter, None),
+    ("tcheheh-ar", 0x0687, "uni0687", Category::Letter, None),
+    ("tcircumflexbelow", 0x1E71, "uni1E71", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tcommaaccent", 0x021B, "uni021B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tdieresis", 0x1E97, "uni1E97", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tdotaccent", 0x1E6B, "uni1E6B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tdotbelow", 0x1E6D, "uni1E6D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("te-cy", 0x0442, "uni0442", Category::Letter, Some(Subcategory::Lowercase)),
+    ("teh-ar", 0x062A, "uni062A", Category::Letter, None),
+    ("tehMarbuta-ar", 0x0629, "uni0629", Category::Letter, None),
+    ("tehMarbutaGoal-ar", 0x06C3, "uni06C3", Category::Letter, None),
+    ("tehRing-ar", 0x067C, "uni067C", Category::Letter, None),
+    ("tehThreeDotsAboveDownwards-ar", 0x067D, "uni067D", Category::Letter, None),
+    ("teheh-ar", 0x067F, "uni067F", Category::Letter, None),
+    ("tet-hb", 0x05D8, "uni05D8", Category::Letter, None),
+    ("tha-beng", 0x09A5, "uni09A5", Category::Letter, None),
+    ("tha-deva", 0x0925, "uni0925", Category::Letter, None),
+    ("tha-gujr", 0x0AA5, "uni0AA5", Category::Letter, None),
+    ("tha-guru", 0x0A25, "uni0A25", Category::Letter, None),
+    ("tha-knda", 0x0CA5, "uni0CA5", Category::Letter, None),
+    ("tha-mlym", 0x0D25, "uni0D25", Category::Letter, None),
+    ("tha-orya", 0x0B25, "uni0B25", Category::Letter, None),
+    ("tha-telu", 0x0C25, "uni0C25", Category::Letter, None),
+    ("thal-ar", 0x0630, "uni0630", Category::Letter, None),
+    ("thanthakhat-thai", 0x0E4C, "uni0E4C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("theh-ar", 0x062B, "uni062B", Category::Letter, None),
+    ("theta", 0x03B8, "theta", Category::Letter, Some(Subcategory::Lowercase)),
+    ("thoNangmontho-thai", 0x0E11, "uni0E11", Category::Letter, None),
+    ("thoPhuthao-thai", 0x0E12, "uni0E12", Category::Letter, None),
+    ("thoSung-lao", 0x0E96, "uni0E96", Category::Letter, None),
+    ("thoTam-lao", 0x0E97, "uni0E97", Category::Letter, None),
+    ("thoThahan-thai", 0x0E17, "uni0E17", Category::Letter, None),
+    ("thoThan-thai", 0x0E10, "uni0E10", Category::Letter, None),
+    ("thoThong-thai", 0x0E18, "uni0E18", Category::Letter, None),
+    ("thoThung-thai", 0x0E16, "uni0E16", Category::Letter, None),
+    ("thook", 0x01AD, "uni01AD", Category::Letter, Some(Subcategory::Lowercase)),
+    ("thorn", 0x00FE, "thorn", Category::Letter, Some(Subcategory::Lowercase)),
+    ("thousandsSeparator-ar", 0x066C, "uni066C", Category::Punctuation, None),
+    ("three", 0x0033, "three", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-ar", 0x0663, "uni0663", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-beng", 0x09E9, "uni09E9", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-deva", 0x0969, "uni0969", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-gujr", 0x0AE9, "uni0AE9", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-guru", 0x0A69, "uni0A69", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-knda", 0x0CE9, "uni0CE9", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-lao", 0x0ED3, "uni0ED3", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-mlym", 0x0D69, "uni0D69", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-orya", 0x0B69, "uni0B69", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-taml", 0x0BE9, "uni0BE9", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-telu", 0x0C69, "uni0C69", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("three-thai", 0x0E53, "uni0E53", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("threeDotNuktaAbove-gujr", 0x0AFD, "uni0AFD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("threequarters", 0x00BE, "threequarters", Category::Number, None),
+    ("threesuperior", 0x00B3, "threesuperior", Category::Number, None),
+    ("tilde", 0x02DC, "tilde", Category::Symbol, Some(Subcategory::Modifier)),
+    ("tildebelowcomb", 0x0330, "uni0330", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("tildecomb", 0x0303, "tildecomb", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("tippi-guru", 0x0A70, "uni0A70", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("tlinebelow", 0x1E6F, "uni1E6F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("to-lao", 0x0E95, "uni0E95", Category::Letter, None),
+    ("toPatak-thai", 0x0E0F, "uni0E0F", Category::Letter, None),
+    ("toTao-thai", 0x0E15, "uni0E15", Category::Letter, None),
+    ("toneMaiCatawa-lao", 0x0ECB, "uni0ECB", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("toneMaiEk-lao", 0x0EC8, "uni0EC8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("toneMaiTho-lao", 0x0EC9, "uni0EC9", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("toneMaiTi-lao", 0x0ECA, "uni0ECA", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("trademark", 0x2122, "trademark", Category::Symbol, None),
+    ("tripleDotMark-ar", 0x061E, "uni061E", Category::Punctuation, None),
+    ("tsa-telu", 0x0C58, "uni0C58", Category::Letter, None),
+    ("tsadi-hb", 0x05E6, "uni05E6", Category::Letter, None),
+    ("tse-cy", 0x0446, "uni0446", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tsere-hb", 0x05B5, "uni05B5", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("tshe-cy", 0x045B, "uni045B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("tta-beng", 0x099F, "uni099F", Category::Letter, None),
+    ("tta-deva", 0x091F, "uni091F", Category::Letter, None),
+    ("tta-gujr", 0x0A9F, "uni0A9F", Category::Letter, None),
+    ("tta-guru", 0x0A1F, "uni0A1F", Category::Letter, None),
+    ("tta-knda", 0x0C9F, "uni0C9F", Category::Letter, None),
+    ("tta-mlym", 0x0D1F, "uni0D1F", Category::Letter, None),
+    ("tta-orya", 0x0B1F, "uni0B1F", Category::Letter, None),
+    ("tta-taml", 0x0B9F, "uni0B9F", Category::Letter, None),
+    ("tta-telu", 0x0C1F, "uni0C1F", Category::Letter, None),
+    ("tteh-ar", 0x0679, "uni0679", Category::Letter, None),
+    ("tteheh-ar", 0x067A, "uni067A", Category::Letter, None),
+    ("ttha-beng", 0x09A0, "uni09A0", Category::Letter, None),
+    ("ttha-deva", 0x0920, "uni0920", Category::Letter, None),
+    ("ttha-gujr", 0x0AA0, "uni0AA0", Category::Letter, None),
+    ("ttha-guru", 0x0A20, "uni0A20", Category::Letter, None),
+    ("ttha-knda", 0x0CA0, "uni0CA0", Category::Letter, None),
+    ("ttha-mlym", 0x0D20, "uni0D20", Category::Letter, None),
+    ("ttha-orya", 0x0B20, "uni0B20", Category::Letter, None),
+    ("ttha-telu", 0x0C20, "uni0C20", Category::Letter, None),
+    ("ttta-mlym", 0x0D3A, "uni0D3A", Category::Letter, None),
+    ("tuumu-telu", 0x0C7F, "uni0C7F", Category::Symbol, None),
+    ("two", 0x0032, "two", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-ar", 0x0662, "uni0662", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-beng", 0x09E8, "uni09E8", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-deva", 0x0968, "uni0968", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-gujr", 0x0AE8, "uni0AE8", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-guru", 0x0A68, "uni0A68", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-knda", 0x0CE8, "uni0CE8", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-lao", 0x0ED2, "uni0ED2", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-mlym", 0x0D68, "uni0D68", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-orya", 0x0B68, "uni0B68", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-taml", 0x0BE8, "uni0BE8", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-telu", 0x0C68, "uni0C68", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("two-thai", 0x0E52, "uni0E52", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("twoCircleNuktaAbove-gujr", 0x0AFF, "uni0AFF", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("twosuperior", 0x00B2, "twosuperior", Category::Number, None),
+    ("u", 0x0075, "u", Category::Letter, Some(Subcategory::Lowercase)),
+    ("u-ar", 0x06C7, "uni06C7", Category::Letter, None),
+    ("u-beng", 0x0989, "uni0989", Category::Letter, None),
+    ("u-cy", 0x0443, "uni0443", Category::Letter, Some(Subcategory::Lowercase)),
+    ("u-deva", 0x0909, "uni0909", Category::Letter, None),
+    ("u-gujr", 0x0A89, "uni0A89", Category::Letter, None),
+    ("u-guru", 0x0A09, "uni0A09", Category::Letter, None),
+    ("u-knda", 0x0C89, "uni0C89", Category::Letter, None),
+    ("u-mlym", 0x0D09, "uni0D09", Category::Letter, None),
+    ("u-orya", 0x0B09, "uni0B09", Category::Letter, None),
+    ("u-taml", 0x0B89, "uni0B89", Category::Letter, None),
+    ("u-telu", 0x0C09, "uni0C09", Category::Letter, None),
+    ("uHamzaAbove-ar", 0x0677, "uni0677", Category::Letter, None),
+    ("uMatra-beng", 0x09C1, "uni09C1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-deva", 0x0941, "uni0941", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-gujr", 0x0AC1, "uni0AC1", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-guru", 0x0A41, "uni0A41", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-knda", 0x0CC1, "uni0CC1", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uMatra-lao", 0x0EB8, "uni0EB8", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-mlym", 0x0D41, "uni0D41", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-orya", 0x0B41, "uni0B41", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uMatra-taml", 0x0BC1, "uni0BC1", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uMatra-telu", 0x0C41, "uni0C41", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uacute", 0x00FA, "uacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ubreve", 0x016D, "ubreve", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ubreveinverted", 0x0217, "uni0217", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ucaron", 0x01D4, "uni01D4", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ucircumflex", 0x00FB, "ucircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ucircumflexbelow", 0x1E77, "uni1E77", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udaat-guru", 0x0A51, "uni0A51", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("udblgrave", 0x0215, "uni0215", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresis", 0x00FC, "udieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresisacute", 0x01D8, "uni01D8", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresisbelow", 0x1E73, "uni1E73", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresiscaron", 0x01DA, "uni01DA", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresisgrave", 0x01DC, "uni01DC", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udieresismacron", 0x01D6, "uni01D6", Category::Letter, Some(Subcategory::Lowercase)),
+    ("udotbelow", 0x1EE5, "uni1EE5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ue-deva", 0x0976, "uni0976", Category::Letter, None),
+    ("ueMatra-deva", 0x0956, "uni0956", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ugrave", 0x00F9, "ugrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhookabove", 0x1EE7, "uni1EE7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhorn", 0x01B0, "uni01B0", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhornacute", 0x1EE9, "uni1EE9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhorndotbelow", 0x1EF1, "uni1EF1", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhorngrave", 0x1EEB, "uni1EEB", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhornhookabove", 0x1EED, "uni1EED", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhorntilde", 0x1EEF, "uni1EEF", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uhungarumlaut", 0x0171, "uhungarumlaut", Category::Letter, Some(Subcategory::Lowercase)),
+    ("umacron", 0x016B, "umacron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("umacrondieresis", 0x1E7B, "uni1E7B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("underscore", 0x005F, "underscore", Category::Punctuation, Some(Subcategory::Connector)),
+    ("uogonek", 0x0173, "uogonek", Category::Letter, Some(Subcategory::Lowercase)),
+    ("upadhmaniya-knda", 0x0CF2, "uni0CF2", Category::Letter, None),
+    ("upsilon", 0x03C5, "upsilon", Category::Letter, Some(Subcategory::Lowercase)),
+    ("upsilondieresis", 0x03CB, "upsilondieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("upsilondieresistonos", 0x03B0, "upsilondieresistonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("upsilontonos", 0x03CD, "upsilontonos", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ura-guru", 0x0A73, "uni0A73", Category::Letter, None),
+    ("uring", 0x016F, "uring", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ushort-cy", 0x045E, "uni045E", Category::Letter, Some(Subcategory::Lowercase)),
+    ("utilde", 0x0169, "utilde", Category::Letter, Some(Subcategory::Lowercase)),
+    ("utildeacute", 0x1E79, "uni1E79", Category::Letter, Some(Subcategory::Lowercase)),
+    ("utildebelow", 0x1E75, "uni1E75", Category::Letter, Some(Subcategory::Lowercase)),
+    ("uu-beng", 0x098A, "uni098A", Category::Letter, None),
+    ("uu-deva", 0x090A, "uni090A", Category::Letter, None),
+    ("uu-gujr", 0x0A8A, "uni0A8A", Category::Letter, None),
+    ("uu-guru", 0x0A0A, "uni0A0A", Category::Letter, None),
+    ("uu-knda", 0x0C8A, "uni0C8A", Category::Letter, None),
+    ("uu-mlym", 0x0D0A, "uni0D0A", Category::Letter, None),
+    ("uu-orya", 0x0B0A, "uni0B0A", Category::Letter, None),
+    ("uu-taml", 0x0B8A, "uni0B8A", Category::Letter, None),
+    ("uu-telu", 0x0C0A, "uni0C0A", Category::Letter, None),
+    ("uuMatra-beng", 0x09C2, "uni09C2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-deva", 0x0942, "uni0942", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-gujr", 0x0AC2, "uni0AC2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-guru", 0x0A42, "uni0A42", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-knda", 0x0CC2, "uni0CC2", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uuMatra-lao", 0x0EB9, "uni0EB9", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-mlym", 0x0D42, "uni0D42", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-orya", 0x0B42, "uni0B42", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("uuMatra-taml", 0x0BC2, "uni0BC2", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uuMatra-telu", 0x0C42, "uni0C42", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("uue-deva", 0x0977, "uni0977", Category::Letter, None),
+    ("uueMatra-deva", 0x0957, "uni0957", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("v", 0x0076, "v", Category::Letter, Some(Subcategory::Lowercase)),
+    ("va-deva", 0x0935, "uni0935", Category::Letter, None),
+    ("va-gujr", 0x0AB5, "uni0AB5", Category::Letter, None),
+    ("va-guru", 0x0A35, "uni0A35", Category::Letter, None),
+    ("va-knda", 0x0CB5, "uni0CB5", Category::Letter, None),
+    ("va-mlym", 0x0D35, "uni0D35", Category::Letter, None),
+    ("va-orya", 0x0B35, "uni0B35", Category::Letter, None),
+    ("va-taml", 0x0BB5, "uni0BB5", Category::Letter, None),
+    ("va-telu", 0x0C35, "uni0C35", Category::Letter, None),
+    ("vav-hb", 0x05D5, "uni05D5", Category::Letter, None),
+    ("vdotbelow", 0x1E7F, "uni1E7F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ve-ar", 0x06CB, "uni06CB", Category::Letter, None),
+    ("ve-cy", 0x0432, "uni0432", Category::Letter, Some(Subcategory::Lowercase)),
+    ("vedicAnusvara-beng", 0x09FC, "uni09FC", Category::Letter, None),
+    ("vedicAnusvara-mlym", 0x0D04, "uni0D04", Category::Letter, None),
+    ("veh-ar", 0x06A4, "uni06A4", Category::Letter, None),
+    ("verticalBarVirama-mlym", 0x0D3B, "uni0D3B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-beng", 0x09CD, "uni09CD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-deva", 0x094D, "uni094D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-gujr", 0x0ACD, "uni0ACD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-guru", 0x0A4D, "uni0A4D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-knda", 0x0CCD, "uni0CCD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-mlym", 0x0D4D, "uni0D4D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-orya", 0x0B4D, "uni0B4D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-taml", 0x0BCD, "uni0BCD", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("virama-telu", 0x0C4D, "uni0C4D", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("visarga-beng", 0x0983, "uni0983", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-deva", 0x0903, "uni0903", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-gujr", 0x0A83, "uni0A83", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-guru", 0x0A03, "uni0A03", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-knda", 0x0C83, "uni0C83", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-mlym", 0x0D03, "uni0D03", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-orya", 0x0B03, "uni0B03", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("visarga-taml", 0x0B83, "uni0B83", Category::Letter, None),
+    ("visarga-telu", 0x0C03, "uni0C03", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("vocalicL-beng", 0x098C, "uni098C", Category::Letter, None),
+    ("vocalicL-deva", 0x090C, "uni090C", Category::Letter, None),
+    ("vocalicL-gujr", 0x0A8C, "uni0A8C", Category::Letter, None),
+    ("vocalicL-knda", 0x0C8C, "uni0C8C", Category::Letter, None),
+    ("vocalicL-mlym", 0x0D0C, "uni0D0C", Category::Letter, None),
+    ("vocalicL-orya", 0x0B0C, "uni0B0C", Category::Letter, None),
+    ("vocalicL-telu", 0x0C0C, "uni0C0C", Category::Letter, None),
+    ("vocalicLMatra-beng", 0x09E2, "uni09E2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-deva", 0x0962, "uni0962", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-gujr", 0x0AE2, "uni0AE2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-knda", 0x0CE2, "uni0CE2", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-mlym", 0x0D62, "uni0D62", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-orya", 0x0B62, "uni0B62", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLMatra-telu", 0x0C62, "uni0C62", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLl-beng", 0x09E1, "uni09E1", Category::Letter, None),
+    ("vocalicLl-deva", 0x0961, "uni0961", Category::Letter, None),
+    ("vocalicLl-gujr", 0x0AE1, "uni0AE1", Category::Letter, None),
+    ("vocalicLl-knda", 0x0CE1, "uni0CE1", Category::Letter, None),
+    ("vocalicLl-mlym", 0x0D61, "uni0D61", Category::Letter, None),
+    ("vocalicLl-orya", 0x0B61, "uni0B61", Category::Letter, None),
+    ("vocalicLl-telu", 0x0C61, "uni0C61", Category::Letter, None),
+    ("vocalicLlMatra-beng", 0x09E3, "uni09E3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-deva", 0x0963, "uni0963", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-gujr", 0x0AE3, "uni0AE3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-knda", 0x0CE3, "uni0CE3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-mlym", 0x0D63, "uni0D63", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-orya", 0x0B63, "uni0B63", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicLlMatra-telu", 0x0C63, "uni0C63", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicR-beng", 0x098B, "uni098B", Category::Letter, None),
+    ("vocalicR-deva", 0x090B, "uni090B", Category::Letter, None),
+    ("vocalicR-gujr", 0x0A8B, "uni0A8B", Category::Letter, None),
+    ("vocalicR-knda", 0x0C8B, "uni0C8B", Category::Letter, None),
+    ("vocalicR-mlym", 0x0D0B, "uni0D0B", Category::Letter, None),
+    ("vocalicR-orya", 0x0B0B, "uni0B0B", Category::Letter, None),
+    ("vocalicR-telu", 0x0C0B, "uni0C0B", Category::Letter, None),
+    ("vocalicRMatra-beng", 0x09C3, "uni09C3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRMatra-deva", 0x0943, "uni0943", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRMatra-gujr", 0x0AC3, "uni0AC3", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRMatra-knda", 0x0CC3, "uni0CC3", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("vocalicRMatra-mlym", 0x0D43, "uni0D43", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRMatra-orya", 0x0B43, "uni0B43", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRMatra-telu", 0x0C43, "uni0C43", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("vocalicRr-beng", 0x09E0, "uni09E0", Category::Letter, None),
+    ("vocalicRr-deva", 0x0960, "uni0960", Category::Letter, None),
+    ("vocalicRr-gujr", 0x0AE0, "uni0AE0", Category::Letter, None),
+    ("vocalicRr-knda", 0x0CE0, "uni0CE0", Category::Letter, None),
+    ("vocalicRr-mlym", 0x0D60, "uni0D60", Category::Letter, None),
+    ("vocalicRr-orya", 0x0B60, "uni0B60", Category::Letter, None),
+    ("vocalicRr-telu", 0x0C60, "uni0C60", Category::Letter, None),
+    ("vocalicRrMatra-beng", 0x09C4, "uni09C4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRrMatra-deva", 0x0944, "uni0944", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRrMatra-gujr", 0x0AC4, "uni0AC4", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRrMatra-knda", 0x0CC4, "uni0CC4", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("vocalicRrMatra-mlym", 0x0D44, "uni0D44", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRrMatra-orya", 0x0B44, "uni0B44", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vocalicRrMatra-telu", 0x0C44, "uni0C44", Category::Mark, Some(Subcategory::SpacingCombining)),
+    ("vowelCandraE-gujr", 0x0A8D, "uni0A8D", Category::Letter, None),
+    ("vowelCandraO-gujr", 0x0A91, "uni0A91", Category::Letter, None),
+    ("vowelDotBelow-ar", 0x065C, "uni065C", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vowelInvertedSmallVAbove-ar", 0x065B, "uni065B", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vowelSmallVAbove-ar", 0x065A, "uni065A", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("vtilde", 0x1E7D, "uni1E7D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("w", 0x0077, "w", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wa-orya", 0x0B71, "uni0B71", Category::Letter, None),
+    ("wacute", 0x1E83, "wacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wavyHamzaBelow-ar", 0x065F, "uni065F", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("waw-ar", 0x0648, "uni0648", Category::Letter, None),
+    ("wawDotAbove-ar", 0x06CF, "uni06CF", Category::Letter, None),
+    ("wawHamzaAbove-ar", 0x0624, "uni0624", Category::Letter, None),
+    ("wawRing-ar", 0x06C4, "uni06C4", Category::Letter, None),
+    ("wawTwoDotsAbove-ar", 0x06CA, "uni06CA", Category::Letter, None),
+    ("wcircumflex", 0x0175, "wcircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wdieresis", 0x1E85, "wdieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wdotaccent", 0x1E87, "uni1E87", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wdotbelow", 0x1E89, "uni1E89", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wgrave", 0x1E81, "wgrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("wo-lao", 0x0EA7, "uni0EA7", Category::Letter, None),
+    ("woWaen-thai", 0x0E27, "uni0E27", Category::Letter, None),
+    ("wring", 0x1E98, "uni1E98", Category::Letter, Some(Subcategory::Lowercase)),
+    ("x", 0x0078, "x", Category::Letter, Some(Subcategory::Lowercase)),
+    ("xdieresis", 0x1E8D, "uni1E8D", Category::Letter, Some(Subcategory::Lowercase)),
+    ("xdotaccent", 0x1E8B, "uni1E8B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("xi", 0x03BE, "xi", Category::Letter, Some(Subcategory::Lowercase)),
+    ("y", 0x0079, "y", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yMatra-lao", 0x0EB6, "uni0EB6", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ya-beng", 0x09AF, "uni09AF", Category::Letter, None),
+    ("ya-deva", 0x092F, "uni092F", Category::Letter, None),
+    ("ya-gujr", 0x0AAF, "uni0AAF", Category::Letter, None),
+    ("ya-guru", 0x0A2F, "uni0A2F", Category::Letter, None),
+    ("ya-knda", 0x0CAF, "uni0CAF", Category::Letter, None),
+    ("ya-mlym", 0x0D2F, "uni0D2F", Category::Letter, None),
+    ("ya-orya", 0x0B2F, "uni0B2F", Category::Letter, None),
+    ("ya-taml", 0x0BAF, "uni0BAF", Category::Letter, None),
+    ("ya-telu", 0x0C2F, "uni0C2F", Category::Letter, None),
+    ("yacute", 0x00FD, "yacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yakash-guru", 0x0A75, "uni0A75", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("yamakkan-thai", 0x0E4E, "uni0E4E", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("ycircumflex", 0x0177, "ycircumflex", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ydieresis", 0x00FF, "ydieresis", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ydotaccent", 0x1E8F, "uni1E8F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ydotbelow", 0x1EF5, "uni1EF5", Category::Letter, Some(Subcategory::Lowercase)),
+    ("year-taml", 0x0BF5, "uni0BF5", Category::Symbol, None),
+    ("yeh-ar", 0x064A, "uni064A", Category::Letter, None),
+    ("yehBarree-ar", 0x06D2, "uni06D2", Category::Letter, None),
+    ("yehBarreeHamzaAbove-ar", 0x06D3, "uni06D3", Category::Letter, None),
+    ("yehHamzaAbove-ar", 0x0626, "uni0626", Category::Letter, None),
+    ("yehSmallV-ar", 0x06CE, "uni06CE", Category::Letter, None),
+    ("yehTail-ar", 0x06CD, "uni06CD", Category::Letter, None),
+    ("yehThreeDotsBelow-ar", 0x06D1, "uni06D1", Category::Letter, None),
+    ("yen", 0x00A5, "yen", Category::Symbol, Some(Subcategory::Currency)),
+    ("yeru-cy", 0x044B, "uni044B", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ygrave", 0x1EF3, "ygrave", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yhook", 0x01B4, "uni01B4", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yhookabove", 0x1EF7, "uni1EF7", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yi-cy", 0x0457, "uni0457", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ymacron", 0x0233, "uni0233", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yo-lao", 0x0EA2, "uni0EA2", Category::Letter, None),
+    ("yoYak-thai", 0x0E22, "uni0E22", Category::Letter, None),
+    ("yoYing-thai", 0x0E0D, "uni0E0D", Category::Letter, None),
+    ("yod-hb", 0x05D9, "uni05D9", Category::Letter, None),
+    ("yodTriangle-hb", 0x05EF, "uni05EF", Category::Letter, None),
+    ("yring", 0x1E99, "uni1E99", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ystroke", 0x024F, "uni024F", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ytilde", 0x1EF9, "uni1EF9", Category::Letter, Some(Subcategory::Lowercase)),
+    ("yu-ar", 0x06C8, "uni06C8", Category::Letter, None),
+    ("yyMatra-lao", 0x0EB7, "uni0EB7", Category::Mark, Some(Subcategory::Nonspacing)),
+    ("yya-beng", 0x09DF, "uni09DF", Category::Letter, None),
+    ("yya-deva", 0x095F, "uni095F", Category::Letter, None),
+    ("yya-orya", 0x0B5F, "uni0B5F", Category::Letter, None),
+    ("z", 0x007A, "z", Category::Letter, Some(Subcategory::Lowercase)),
+    ("za-deva", 0x095B, "uni095B", Category::Letter, None),
+    ("za-guru", 0x0A5B, "uni0A5B", Category::Letter, None),
+    ("zacute", 0x017A, "zacute", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zah-ar", 0x0638, "uni0638", Category::Letter, None),
+    ("zain-ar", 0x0632, "uni0632", Category::Letter, None),
+    ("zayin-hb", 0x05D6, "uni05D6", Category::Letter, None),
+    ("zcaron", 0x017E, "zcaron", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zcircumflex", 0x1E91, "uni1E91", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zdotaccent", 0x017C, "zdotaccent", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zdotbelow", 0x1E93, "uni1E93", Category::Letter, Some(Subcategory::Lowercase)),
+    ("ze-cy", 0x0437, "uni0437", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zero", 0x0030, "zero", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-ar", 0x0660, "uni0660", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-beng", 0x09E6, "uni09E6", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-deva", 0x0966, "uni0966", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-gujr", 0x0AE6, "uni0AE6", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-guru", 0x0A66, "uni0A66", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-knda", 0x0CE6, "uni0CE6", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-lao", 0x0ED0, "uni0ED0", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-mlym", 0x0D66, "uni0D66", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-orya", 0x0B66, "uni0B66", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-taml", 0x0BE6, "uni0BE6", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-telu", 0x0C66, "uni0C66", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zero-thai", 0x0E50, "uni0E50", Category::Number, Some(Subcategory::DecimalDigit)),
+    ("zeta", 0x03B6, "zeta", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zha-deva", 0x0979, "uni0979", Category::Letter, None),
+    ("zha-gujr", 0x0AF9, "uni0AF9", Category::Letter, None),
+    ("zhe-cy", 0x0436, "uni0436", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zhook", 0x0225, "uni0225", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zlinebelow", 0x1E95, "uni1E95", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zstroke", 0x01B6, "uni01B6", Category::Letter, Some(Subcategory::Lowercase)),
+    ("zwarakay-ar", 0x0659, "uni0659", Category::Mark, Some(Subcategory::Nonspacing)),
+];
+
+/// Ranges of code points with the same general category
+#[rustfmt::skip]
+pub(crate) static CATEGORIES: &[(u32, u32, Category, Option<Subcategory>)] = &[
+    (0x0000, 0x001F, Category::Other, Some(Subcategory::Format)),
+    (0x0020, 0x0020, Category::Separator, Some(Subcategory::Space)),
+    (0x0021, 0x0023, Category::Punctuation, None),
+    (0x0024, 0x0024, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0025, 0x0027, Category::Punctuation, None),
+    (0x0028, 0x0029, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x002A, 0x002A, Category::Punctuation, None),
+    (0x002B, 0x002B, Category::Symbol, Some(Subcategory::Math)),
+    (0x002C, 0x002C, Category::Punctuation, None),
+    (0x002D, 0x002D, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x002E, 0x002F, Category::Punctuation, None),
+    (0x0030, 0x0039, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x003A, 0x003B, Category::Punctuation, None),
+    (0x003C, 0x003E, Category::Symbol, Some(Subcategory::Math)),
+    (0x003F, 0x0040, Category::Punctuation, None),
+    (0x0041, 0x005A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x005B, 0x005B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x005C, 0x005C, Category::Punctuation, None),
+    (0x005D, 0x005D, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x005E, 0x005E, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x005F, 0x005F, Category::Punctuation, Some(Subcategory::Connector)),
+    (0x0060, 0x0060, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x0061, 0x007A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x007B, 0x007B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x007C, 0x007C, Category::Symbol, Some(Subcategory::Math)),
+    (0x007D, 0x007D, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x007E, 0x007E, Category::Symbol, Some(Subcategory::Math)),
+    (0x007F, 0x009F, Category::Other, Some(Subcategory::Format)),
+    (0x00A0, 0x00A0, Category::Separator, Some(Subcategory::Space)),
+    (0x00A1, 0x00A1, Category::Punctuation, None),
+    (0x00A2, 0x00A5, Category::Symbol, Some(Subcategory::Currency)),
+    (0x00A6, 0x00A6, Category::Symbol, None),
+    (0x00A7, 0x00A7, Category::Punctuation, None),
+    (0x00A8, 0x00A8, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x00A9, 0x00A9, Category::Symbol, None),
+    (0x00AA, 0x00AA, Category::Letter, None),
+    (0x00AB, 0x00AB, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x00AC, 0x00AC, Category::Symbol, Some(Subcategory::Math)),
+    (0x00AD, 0x00AD, Category::Other, Some(Subcategory::Format)),
+    (0x00AE, 0x00AE, Category::Symbol, None),
+    (0x00AF, 0x00AF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x00B0, 0x00B0, Category::Symbol, None),
+    (0x00B1, 0x00B1, Category::Symbol, Some(Subcategory::Math)),
+    (0x00B2, 0x00B3, Category::Number, None),
+    (0x00B4, 0x00B4, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x00B5, 0x00B5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x00B6, 0x00B7, Category::Punctuation, None),
+    (0x00B8, 0x00B8, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x00B9, 0x00B9, Category::Number, None),
+    (0x00BA, 0x00BA, Category::Letter, None),
+    (0x00BB, 0x00BB, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x00BC, 0x00BE, Category::Number, None),
+    (0x00BF, 0x00BF, Category::Punctuation, None),
+    (0x00C0, 0x00D6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x00D7, 0x00D7, Category::Symbol, Some(Subcategory::Math)),
+    (0x00D8, 0x00DE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x00DF, 0x00F6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x00F7, 0x00F7, Category::Symbol, Some(Subcategory::Math)),
+    (0x00F8, 0x00FF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0100, 0x0100, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0101, 0x0101, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0102, 0x0102, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0103, 0x0103, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0104, 0x0104, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0105, 0x0105, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0106, 0x0106, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0107, 0x0107, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0108, 0x0108, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0109, 0x0109, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x010A, 0x010A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x010B, 0x010B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x010C, 0x010C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x010D, 0x010D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x010E, 0x010E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x010F, 0x010F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0110, 0x0110, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0111, 0x0111, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0112, 0x0112, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0113, 0x0113, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0114, 0x0114, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0115, 0x0115, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0116, 0x0116, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0117, 0x0117, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0118, 0x0118, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0119, 0x0119, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x011A, 0x011A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x011B, 0x011B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x011C, 0x011C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x011D, 0x011D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x011E, 0x011E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x011F, 0x011F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0120, 0x0120, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0121, 0x0121, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0122, 0x0122, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0123, 0x0123, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0124, 0x0124, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0125, 0x0125, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0126, 0x0126, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0127, 0x0127, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0128, 0x0128, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0129, 0x0129, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x012A, 0x012A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x012B, 0x012B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x012C, 0x012C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x012D, 0x012D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x012E, 0x012E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x012F, 0x012F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0130, 0x0130, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0131, 0x0131, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0132, 0x0132, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0133, 0x0133, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0134, 0x0134, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0135, 0x0135, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0136, 0x0136, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0137, 0x0138, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0139, 0x0139, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x013A, 0x013A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x013B, 0x013B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x013C, 0x013C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x013D, 0x013D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x013E, 0x013E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x013F, 0x013F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0140, 0x0140, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0141, 0x0141, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0142, 0x0142, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0143, 0x0143, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0144, 0x0144, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0145, 0x0145, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0146, 0x0146, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0147, 0x0147, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0148, 0x0149, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x014A, 0x014A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x014B, 0x014B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x014C, 0x014C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x014D, 0x014D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x014E, 0x014E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x014F, 0x014F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0150, 0x0150, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0151, 0x0151, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0152, 0x0152, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0153, 0x0153, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0154, 0x0154, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0155, 0x0155, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0156, 0x0156, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0157, 0x0157, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0158, 0x0158, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0159, 0x0159, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x015A, 0x015A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x015B, 0x015B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x015C, 0x015C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x015D, 0x015D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x015E, 0x015E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x015F, 0x015F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0160, 0x0160, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0161, 0x0161, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0162, 0x0162, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0163, 0x0163, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0164, 0x0164, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0165, 0x0165, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0166, 0x0166, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0167, 0x0167, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0168, 0x0168, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0169, 0x0169, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x016A, 0x016A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x016B, 0x016B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x016C, 0x016C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x016D, 0x016D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x016E, 0x016E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x016F, 0x016F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0170, 0x0170, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0171, 0x0171, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0172, 0x0172, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0173, 0x0173, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0174, 0x0174, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0175, 0x0175, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0176, 0x0176, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0177, 0x0177, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0178, 0x0179, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x017A, 0x017A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x017B, 0x017B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x017C, 0x017C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x017D, 0x017D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x017E, 0x0180, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0181, 0x0182, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0183, 0x0183, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0184, 0x0184, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0185, 0x0185, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0186, 0x0187, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0188, 0x0188, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0189, 0x018B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x018C, 0x018D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x018E, 0x0191, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0192, 0x0192, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0193, 0x0194, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0195, 0x0195, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0196, 0x0198, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0199, 0x019B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x019C, 0x019D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x019E, 0x019E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x019F, 0x01A0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01A1, 0x01A1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01A2, 0x01A2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01A3, 0x01A3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01A4, 0x01A4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01A5, 0x01A5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01A6, 0x01A7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01A8, 0x01A8, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01A9, 0x01A9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01AA, 0x01AB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01AC, 0x01AC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01AD, 0x01AD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01AE, 0x01AF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01B0, 0x01B0, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01B1, 0x01B3, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01B4, 0x01B4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01B5, 0x01B5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01B6, 0x01B6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01B7, 0x01B8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01B9, 0x01BA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01BB, 0x01BB, Category::Letter, None),
+    (0x01BC, 0x01BC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01BD, 0x01BF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01C0, 0x01C3, Category::Letter, None),
+    (0x01C4, 0x01C5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01C6, 0x01C6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01C7, 0x01C8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01C9, 0x01C9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01CA, 0x01CB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01CC, 0x01CC, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01CD, 0x01CD, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01CE, 0x01CE, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01CF, 0x01CF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01D0, 0x01D0, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01D1, 0x01D1, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01D2, 0x01D2, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01D3, 0x01D3, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01D4, 0x01D4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01D5, 0x01D5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01D6, 0x01D6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01D7, 0x01D7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01D8, 0x01D8, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01D9, 0x01D9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01DA, 0x01DA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01DB, 0x01DB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01DC, 0x01DD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01DE, 0x01DE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01DF, 0x01DF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01E0, 0x01E0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01E1, 0x01E1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01E2, 0x01E2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01E3, 0x01E3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01E4, 0x01E4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01E5, 0x01E5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01E6, 0x01E6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01E7, 0x01E7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01E8, 0x01E8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01E9, 0x01E9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01EA, 0x01EA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01EB, 0x01EB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01EC, 0x01EC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01ED, 0x01ED, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01EE, 0x01EE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01EF, 0x01F0, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01F1, 0x01F2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01F3, 0x01F3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01F4, 0x01F4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01F5, 0x01F5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01F6, 0x01F8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01F9, 0x01F9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01FA, 0x01FA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01FB, 0x01FB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01FC, 0x01FC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01FD, 0x01FD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x01FE, 0x01FE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x01FF, 0x01FF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0200, 0x0200, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0201, 0x0201, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0202, 0x0202, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0203, 0x0203, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0204, 0x0204, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0205, 0x0205, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0206, 0x0206, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0207, 0x0207, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0208, 0x0208, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0209, 0x0209, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x020A, 0x020A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x020B, 0x020B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x020C, 0x020C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x020D, 0x020D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x020E, 0x020E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x020F, 0x020F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0210, 0x0210, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0211, 0x0211, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0212, 0x0212, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0213, 0x0213, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0214, 0x0214, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0215, 0x0215, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0216, 0x0216, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0217, 0x0217, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0218, 0x0218, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0219, 0x0219, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x021A, 0x021A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x021B, 0x021B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x021C, 0x021C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x021D, 0x021D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x021E, 0x021E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x021F, 0x021F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0220, 0x0220, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0221, 0x0221, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0222, 0x0222, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0223, 0x0223, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0224, 0x0224, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0225, 0x0225, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0226, 0x0226, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0227, 0x0227, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0228, 0x0228, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0229, 0x0229, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x022A, 0x022A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x022B, 0x022B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x022C, 0x022C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x022D, 0x022D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x022E, 0x022E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x022F, 0x022F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0230, 0x0230, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0231, 0x0231, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0232, 0x0232, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0233, 0x0239, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x023A, 0x023B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x023C, 0x023C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x023D, 0x023E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x023F, 0x0240, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0241, 0x0241, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0242, 0x0242, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0243, 0x0246, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0247, 0x0247, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0248, 0x0248, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0249, 0x0249, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x024A, 0x024A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x024B, 0x024B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x024C, 0x024C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x024D, 0x024D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x024E, 0x024E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x024F, 0x0293, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0294, 0x0294, Category::Letter, None),
+    (0x0295, 0x02AF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x02B0, 0x02C1, Category::Letter, Some(Subcategory::Modifier)),
+    (0x02C2, 0x02C5, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x02C6, 0x02D1, Category::Letter, Some(Subcategory::Modifier)),
+    (0x02D2, 0x02DF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x02E0, 0x02E4, Category::Letter, Some(Subcategory::Modifier)),
+    (0x02E5, 0x02EB, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x02EC, 0x02EC, Category::Letter, Some(Subcategory::Modifier)),
+    (0x02ED, 0x02ED, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x02EE, 0x02EE, Category::Letter, Some(Subcategory::Modifier)),
+    (0x02EF, 0x02FF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x0300, 0x036F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0370, 0x0370, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0371, 0x0371, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0372, 0x0372, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0373, 0x0373, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0374, 0x0374, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0375, 0x0375, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x0376, 0x0376, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0377, 0x0377, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x037A, 0x037A, Category::Letter, Some(Subcategory::Modifier)),
+    (0x037B, 0x037D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x037E, 0x037E, Category::Punctuation, None),
+    (0x037F, 0x037F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0384, 0x0385, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x0386, 0x0386, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0387, 0x0387, Category::Punctuation, None),
+    (0x0388, 0x038A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x038C, 0x038C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x038E, 0x038F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0390, 0x0390, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0391, 0x03A1, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03A3, 0x03AB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03AC, 0x03CE, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03CF, 0x03CF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03D0, 0x03D1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03D2, 0x03D4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03D5, 0x03D7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03D8, 0x03D8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03D9, 0x03D9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03DA, 0x03DA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03DB, 0x03DB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03DC, 0x03DC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03DD, 0x03DD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03DE, 0x03DE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03DF, 0x03DF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03E0, 0x03E0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03E1, 0x03E1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03E2, 0x03E2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03E3, 0x03E3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03E4, 0x03E4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03E5, 0x03E5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03E6, 0x03E6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03E7, 0x03E7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03E8, 0x03E8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03E9, 0x03E9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03EA, 0x03EA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03EB, 0x03EB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03EC, 0x03EC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03ED, 0x03ED, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03EE, 0x03EE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03EF, 0x03F3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03F4, 0x03F4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03F5, 0x03F5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03F6, 0x03F6, Category::Symbol, Some(Subcategory::Math)),
+    (0x03F7, 0x03F7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03F8, 0x03F8, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03F9, 0x03FA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x03FB, 0x03FC, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x03FD, 0x042F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0430, 0x045F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0460, 0x0460, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0461, 0x0461, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0462, 0x0462, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0463, 0x0463, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0464, 0x0464, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0465, 0x0465, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0466, 0x0466, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0467, 0x0467, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0468, 0x0468, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0469, 0x0469, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x046A, 0x046A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x046B, 0x046B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x046C, 0x046C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x046D, 0x046D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x046E, 0x046E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x046F, 0x046F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0470, 0x0470, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0471, 0x0471, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0472, 0x0472, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0473, 0x0473, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0474, 0x0474, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0475, 0x0475, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0476, 0x0476, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0477, 0x0477, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0478, 0x0478, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0479, 0x0479, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x047A, 0x047A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x047B, 0x047B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x047C, 0x047C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x047D, 0x047D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x047E, 0x047E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x047F, 0x047F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0480, 0x0480, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0481, 0x0481, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0482, 0x0482, Category::Symbol, None),
+    (0x0483, 0x0487, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0488, 0x0489, Category::Mark, Some(Subcategory::Enclosing)),
+    (0x048A, 0x048A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x048B, 0x048B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x048C, 0x048C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x048D, 0x048D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x048E, 0x048E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x048F, 0x048F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0490, 0x0490, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0491, 0x0491, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0492, 0x0492, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0493, 0x0493, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0494, 0x0494, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0495, 0x0495, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0496, 0x0496, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0497, 0x0497, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0498, 0x0498, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0499, 0x0499, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x049A, 0x049A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x049B, 0x049B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x049C, 0x049C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x049D, 0x049D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x049E, 0x049E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x049F, 0x049F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04A0, 0x04A0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04A1, 0x04A1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04A2, 0x04A2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04A3, 0x04A3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04A4, 0x04A4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04A5, 0x04A5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04A6, 0x04A6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04A7, 0x04A7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04A8, 0x04A8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04A9, 0x04A9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04AA, 0x04AA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04AB, 0x04AB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04AC, 0x04AC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04AD, 0x04AD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04AE, 0x04AE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04AF, 0x04AF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04B0, 0x04B0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04B1, 0x04B1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04B2, 0x04B2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04B3, 0x04B3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04B4, 0x04B4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04B5, 0x04B5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04B6, 0x04B6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04B7, 0x04B7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04B8, 0x04B8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04B9, 0x04B9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04BA, 0x04BA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04BB, 0x04BB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04BC, 0x04BC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04BD, 0x04BD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04BE, 0x04BE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04BF, 0x04BF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04C0, 0x04C1, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04C2, 0x04C2, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04C3, 0x04C3, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04C4, 0x04C4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04C5, 0x04C5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04C6, 0x04C6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04C7, 0x04C7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04C8, 0x04C8, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04C9, 0x04C9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04CA, 0x04CA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04CB, 0x04CB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04CC, 0x04CC, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04CD, 0x04CD, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04CE, 0x04CF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04D0, 0x04D0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04D1, 0x04D1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04D2, 0x04D2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04D3, 0x04D3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04D4, 0x04D4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04D5, 0x04D5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04D6, 0x04D6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04D7, 0x04D7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04D8, 0x04D8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04D9, 0x04D9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04DA, 0x04DA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04DB, 0x04DB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04DC, 0x04DC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04DD, 0x04DD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04DE, 0x04DE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04DF, 0x04DF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04E0, 0x04E0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04E1, 0x04E1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04E2, 0x04E2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04E3, 0x04E3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04E4, 0x04E4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04E5, 0x04E5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04E6, 0x04E6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04E7, 0x04E7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04E8, 0x04E8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04E9, 0x04E9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04EA, 0x04EA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04EB, 0x04EB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04EC, 0x04EC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04ED, 0x04ED, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04EE, 0x04EE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04EF, 0x04EF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04F0, 0x04F0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04F1, 0x04F1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04F2, 0x04F2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04F3, 0x04F3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04F4, 0x04F4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04F5, 0x04F5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04F6, 0x04F6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04F7, 0x04F7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04F8, 0x04F8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04F9, 0x04F9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04FA, 0x04FA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04FB, 0x04FB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04FC, 0x04FC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04FD, 0x04FD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x04FE, 0x04FE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x04FF, 0x04FF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0500, 0x0500, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0501, 0x0501, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0502, 0x0502, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0503, 0x0503, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0504, 0x0504, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0505, 0x0505, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0506, 0x0506, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0507, 0x0507, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0508, 0x0508, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0509, 0x0509, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x050A, 0x050A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x050B, 0x050B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x050C, 0x050C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x050D, 0x050D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x050E, 0x050E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x050F, 0x050F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0510, 0x0510, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0511, 0x0511, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0512, 0x0512, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0513, 0x0513, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0514, 0x0514, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0515, 0x0515, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0516, 0x0516, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0517, 0x0517, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0518, 0x0518, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0519, 0x0519, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x051A, 0x051A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x051B, 0x051B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x051C, 0x051C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x051D, 0x051D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x051E, 0x051E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x051F, 0x051F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0520, 0x0520, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0521, 0x0521, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0522, 0x0522, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0523, 0x0523, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0524, 0x0524, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0525, 0x0525, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0526, 0x0526, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0527, 0x0527, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0528, 0x0528, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0529, 0x0529, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x052A, 0x052A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x052B, 0x052B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x052C, 0x052C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x052D, 0x052D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x052E, 0x052E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x052F, 0x052F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0531, 0x0556, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x0559, 0x0559, Category::Letter, Some(Subcategory::Modifier)),
+    (0x055A, 0x055F, Category::Punctuation, None),
+    (0x0560, 0x0588, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x0589, 0x0589, Category::Punctuation, None),
+    (0x058A, 0x058A, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x058D, 0x058E, Category::Symbol, None),
+    (0x058F, 0x058F, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0591, 0x05BD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x05BE, 0x05BE, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x05BF, 0x05BF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x05C0, 0x05C0, Category::Punctuation, None),
+    (0x05C1, 0x05C2, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x05C3, 0x05C3, Category::Punctuation, None),
+    (0x05C4, 0x05C5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x05C6, 0x05C6, Category::Punctuation, None),
+    (0x05C7, 0x05C7, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x05D0, 0x05EA, Category::Letter, None),
+    (0x05EF, 0x05F2, Category::Letter, None),
+    (0x05F3, 0x05F4, Category::Punctuation, None),
+    (0x0600, 0x0605, Category::Other, Some(Subcategory::Format)),
+    (0x0606, 0x0608, Category::Symbol, Some(Subcategory::Math)),
+    (0x0609, 0x060A, Category::Punctuation, None),
+    (0x060B, 0x060B, Category::Symbol, Some(Subcategory::Currency)),
+    (0x060C, 0x060D, Category::Punctuation, None),
+    (0x060E, 0x060F, Category::Symbol, None),
+    (0x0610, 0x061A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x061B, 0x061B, Category::Punctuation, None),
+    (0x061C, 0x061C, Category::Other, Some(Subcategory::Format)),
+    (0x061D, 0x061F, Category::Punctuation, None),
+    (0x0620, 0x063F, Category::Letter, None),
+    (0x0640, 0x0640, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0641, 0x064A, Category::Letter, None),
+    (0x064B, 0x065F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0660, 0x0669, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x066A, 0x066D, Category::Punctuation, None),
+    (0x066E, 0x066F, Category::Letter, None),
+    (0x0670, 0x0670, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0671, 0x06D3, Category::Letter, None),
+    (0x06D4, 0x06D4, Category::Punctuation, None),
+    (0x06D5, 0x06D5, Category::Letter, None),
+    (0x06D6, 0x06DC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x06DD, 0x06DD, Category::Other, Some(Subcategory::Format)),
+    (0x06DE, 0x06DE, Category::Symbol, None),
+    (0x06DF, 0x06E4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x06E5, 0x06E6, Category::Letter, Some(Subcategory::Modifier)),
+    (0x06E7, 0x06E8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x06E9, 0x06E9, Category::Symbol, None),
+    (0x06EA, 0x06ED, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x06EE, 0x06EF, Category::Letter, None),
+    (0x06F0, 0x06F9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x06FA, 0x06FC, Category::Letter, None),
+    (0x06FD, 0x06FE, Category::Symbol, None),
+    (0x06FF, 0x06FF, Category::Letter, None),
+    (0x0700, 0x070D, Category::Punctuation, None),
+    (0x070F, 0x070F, Category::Other, Some(Subcategory::Format)),
+    (0x0710, 0x0710, Category::Letter, None),
+    (0x0711, 0x0711, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0712, 0x072F, Category::Letter, None),
+    (0x0730, 0x074A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x074D, 0x07A5, Category::Letter, None),
+    (0x07A6, 0x07B0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x07B1, 0x07B1, Category::Letter, None),
+    (0x07C0, 0x07C9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x07CA, 0x07EA, Category::Letter, None),
+    (0x07EB, 0x07F3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x07F4, 0x07F5, Category::Letter, Some(Subcategory::Modifier)),
+    (0x07F6, 0x07F6, Category::Symbol, None),
+    (0x07F7, 0x07F9, Category::Punctuation, None),
+    (0x07FA, 0x07FA, Category::Letter, Some(Subcategory::Modifier)),
+    (0x07FD, 0x07FD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x07FE, 0x07FF, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0800, 0x0815, Category::Letter, None),
+    (0x0816, 0x0819, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x081A, 0x081A, Category::Letter, Some(Subcategory::Modifier)),
+    (0x081B, 0x0823, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0824, 0x0824, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0825, 0x0827, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0828, 0x0828, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0829, 0x082D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0830, 0x083E, Category::Punctuation, None),
+    (0x0840, 0x0858, Category::Letter, None),
+    (0x0859, 0x085B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x085E, 0x085E, Category::Punctuation, None),
+    (0x0860, 0x086A, Category::Letter, None),
+    (0x0870, 0x0887, Category::Letter, None),
+    (0x0888, 0x0888, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x0889, 0x088E, Category::Letter, None),
+    (0x0890, 0x0891, Category::Other, Some(Subcategory::Format)),
+    (0x0898, 0x089F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x08A0, 0x08C8, Category::Letter, None),
+    (0x08C9, 0x08C9, Category::Letter, Some(Subcategory::Modifier)),
+    (0x08CA, 0x08E1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x08E2, 0x08E2, Category::Other, Some(Subcategory::Format)),
+    (0x08E3, 0x0902, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0903, 0x0903, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0904, 0x0939, Category::Letter, None),
+    (0x093A, 0x093A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x093B, 0x093B, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x093C, 0x093C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x093D, 0x093D, Category::Letter, None),
+    (0x093E, 0x0940, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0941, 0x0948, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0949, 0x094C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x094D, 0x094D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x094E, 0x094F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0950, 0x0950, Category::Letter, None),
+    (0x0951, 0x0957, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0958, 0x0961, Category::Letter, None),
+    (0x0962, 0x0963, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0964, 0x0965, Category::Punctuation, None),
+    (0x0966, 0x096F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0970, 0x0970, Category::Punctuation, None),
+    (0x0971, 0x0971, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0972, 0x0980, Category::Letter, None),
+    (0x0981, 0x0981, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0982, 0x0983, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0985, 0x098C, Category::Letter, None),
+    (0x098F, 0x0990, Category::Letter, None),
+    (0x0993, 0x09A8, Category::Letter, None),
+    (0x09AA, 0x09B0, Category::Letter, None),
+    (0x09B2, 0x09B2, Category::Letter, None),
+    (0x09B6, 0x09B9, Category::Letter, None),
+    (0x09BC, 0x09BC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x09BD, 0x09BD, Category::Letter, None),
+    (0x09BE, 0x09C0, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x09C1, 0x09C4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x09C7, 0x09C8, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x09CB, 0x09CC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x09CD, 0x09CD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x09CE, 0x09CE, Category::Letter, None),
+    (0x09D7, 0x09D7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x09DC, 0x09DD, Category::Letter, None),
+    (0x09DF, 0x09E1, Category::Letter, None),
+    (0x09E2, 0x09E3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x09E6, 0x09EF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x09F0, 0x09F1, Category::Letter, None),
+    (0x09F2, 0x09F3, Category::Symbol, Some(Subcategory::Currency)),
+    (0x09F4, 0x09F9, Category::Number, None),
+    (0x09FA, 0x09FA, Category::Symbol, None),
+    (0x09FB, 0x09FB, Category::Symbol, Some(Subcategory::Currency)),
+    (0x09FC, 0x09FC, Category::Letter, None),
+    (0x09FD, 0x09FD, Category::Punctuation, None),
+    (0x09FE, 0x09FE, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A01, 0x0A02, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A03, 0x0A03, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0A05, 0x0A0A, Category::Letter, None),
+    (0x0A0F, 0x0A10, Category::Letter, None),
+    (0x0A13, 0x0A28, Category::Letter, None),
+    (0x0A2A, 0x0A30, Category::Letter, None),
+    (0x0A32, 0x0A33, Category::Letter, None),
+    (0x0A35, 0x0A36, Category::Letter, None),
+    (0x0A38, 0x0A39, Category::Letter, None),
+    (0x0A3C, 0x0A3C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A3E, 0x0A40, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0A41, 0x0A42, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A47, 0x0A48, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A4B, 0x0A4D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A51, 0x0A51, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A59, 0x0A5C, Category::Letter, None),
+    (0x0A5E, 0x0A5E, Category::Letter, None),
+    (0x0A66, 0x0A6F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0A70, 0x0A71, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A72, 0x0A74, Category::Letter, None),
+    (0x0A75, 0x0A75, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A76, 0x0A76, Category::Punctuation, None),
+    (0x0A81, 0x0A82, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0A83, 0x0A83, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0A85, 0x0A8D, Category::Letter, None),
+    (0x0A8F, 0x0A91, Category::Letter, None),
+    (0x0A93, 0x0AA8, Category::Letter, None),
+    (0x0AAA, 0x0AB0, Category::Letter, None),
+    (0x0AB2, 0x0AB3, Category::Letter, None),
+    (0x0AB5, 0x0AB9, Category::Letter, None),
+    (0x0ABC, 0x0ABC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0ABD, 0x0ABD, Category::Letter, None),
+    (0x0ABE, 0x0AC0, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0AC1, 0x0AC5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0AC7, 0x0AC8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0AC9, 0x0AC9, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0ACB, 0x0ACC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0ACD, 0x0ACD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0AD0, 0x0AD0, Category::Letter, None),
+    (0x0AE0, 0x0AE1, Category::Letter, None),
+    (0x0AE2, 0x0AE3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0AE6, 0x0AEF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0AF0, 0x0AF0, Category::Punctuation, None),
+    (0x0AF1, 0x0AF1, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0AF9, 0x0AF9, Category::Letter, None),
+    (0x0AFA, 0x0AFF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B01, 0x0B01, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B02, 0x0B03, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B05, 0x0B0C, Category::Letter, None),
+    (0x0B0F, 0x0B10, Category::Letter, None),
+    (0x0B13, 0x0B28, Category::Letter, None),
+    (0x0B2A, 0x0B30, Category::Letter, None),
+    (0x0B32, 0x0B33, Category::Letter, None),
+    (0x0B35, 0x0B39, Category::Letter, None),
+    (0x0B3C, 0x0B3C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B3D, 0x0B3D, Category::Letter, None),
+    (0x0B3E, 0x0B3E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B3F, 0x0B3F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B40, 0x0B40, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B41, 0x0B44, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B47, 0x0B48, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B4B, 0x0B4C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B4D, 0x0B4D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B55, 0x0B56, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B57, 0x0B57, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0B5C, 0x0B5D, Category::Letter, None),
+    (0x0B5F, 0x0B61, Category::Letter, None),
+    (0x0B62, 0x0B63, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B66, 0x0B6F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0B70, 0x0B70, Category::Symbol, None),
+    (0x0B71, 0x0B71, Category::Letter, None),
+    (0x0B72, 0x0B77, Category::Number, None),
+    (0x0B82, 0x0B82, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0B83, 0x0B83, Category::Letter, None),
+    (0x0B85, 0x0B8A, Category::Letter, None),
+    (0x0B8E, 0x0B90, Category::Letter, None),
+    (0x0B92, 0x0B95, Category::Letter, None),
+    (0x0B99, 0x0B9A, Category::Letter, None),
+    (0x0B9C, 0x0B9C, Category::Letter, None),
+    (0x0B9E, 0x0B9F, Category::Letter, None),
+    (0x0BA3, 0x0BA4, Category::Letter, None),
+    (0x0BA8, 0x0BAA, Category::Letter, None),
+    (0x0BAE, 0x0BB9, Category::Letter, None),
+    (0x0BBE, 0x0BBF, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0BC0, 0x0BC0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0BC1, 0x0BC2, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0BC6, 0x0BC8, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0BCA, 0x0BCC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0BCD, 0x0BCD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0BD0, 0x0BD0, Category::Letter, None),
+    (0x0BD7, 0x0BD7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0BE6, 0x0BEF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0BF0, 0x0BF2, Category::Number, None),
+    (0x0BF3, 0x0BF8, Category::Symbol, None),
+    (0x0BF9, 0x0BF9, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0BFA, 0x0BFA, Category::Symbol, None),
+    (0x0C00, 0x0C00, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C01, 0x0C03, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0C04, 0x0C04, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C05, 0x0C0C, Category::Letter, None),
+    (0x0C0E, 0x0C10, Category::Letter, None),
+    (0x0C12, 0x0C28, Category::Letter, None),
+    (0x0C2A, 0x0C39, Category::Letter, None),
+    (0x0C3C, 0x0C3C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C3D, 0x0C3D, Category::Letter, None),
+    (0x0C3E, 0x0C40, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C41, 0x0C44, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0C46, 0x0C48, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C4A, 0x0C4D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C55, 0x0C56, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C58, 0x0C5A, Category::Letter, None),
+    (0x0C5D, 0x0C5D, Category::Letter, None),
+    (0x0C60, 0x0C61, Category::Letter, None),
+    (0x0C62, 0x0C63, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C66, 0x0C6F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0C77, 0x0C77, Category::Punctuation, None),
+    (0x0C78, 0x0C7E, Category::Number, None),
+    (0x0C7F, 0x0C7F, Category::Symbol, None),
+    (0x0C80, 0x0C80, Category::Letter, None),
+    (0x0C81, 0x0C81, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0C82, 0x0C83, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0C84, 0x0C84, Category::Punctuation, None),
+    (0x0C85, 0x0C8C, Category::Letter, None),
+    (0x0C8E, 0x0C90, Category::Letter, None),
+    (0x0C92, 0x0CA8, Category::Letter, None),
+    (0x0CAA, 0x0CB3, Category::Letter, None),
+    (0x0CB5, 0x0CB9, Category::Letter, None),
+    (0x0CBC, 0x0CBC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0CBD, 0x0CBD, Category::Letter, None),
+    (0x0CBE, 0x0CBE, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0CBF, 0x0CBF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0CC0, 0x0CC4, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0CC6, 0x0CC6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0CC7, 0x0CC8, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0CCA, 0x0CCB, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0CCC, 0x0CCD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0CD5, 0x0CD6, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0CDD, 0x0CDE, Category::Letter, None),
+    (0x0CE0, 0x0CE1, Category::Letter, None),
+    (0x0CE2, 0x0CE3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0CE6, 0x0CEF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0CF1, 0x0CF2, Category::Letter, None),
+    (0x0D00, 0x0D01, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D02, 0x0D03, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D04, 0x0D0C, Category::Letter, None),
+    (0x0D0E, 0x0D10, Category::Letter, None),
+    (0x0D12, 0x0D3A, Category::Letter, None),
+    (0x0D3B, 0x0D3C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D3D, 0x0D3D, Category::Letter, None),
+    (0x0D3E, 0x0D40, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D41, 0x0D44, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D46, 0x0D48, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D4A, 0x0D4C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D4D, 0x0D4D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D4E, 0x0D4E, Category::Letter, None),
+    (0x0D4F, 0x0D4F, Category::Symbol, None),
+    (0x0D54, 0x0D56, Category::Letter, None),
+    (0x0D57, 0x0D57, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D58, 0x0D5E, Category::Number, None),
+    (0x0D5F, 0x0D61, Category::Letter, None),
+    (0x0D62, 0x0D63, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D66, 0x0D6F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0D70, 0x0D78, Category::Number, None),
+    (0x0D79, 0x0D79, Category::Symbol, None),
+    (0x0D7A, 0x0D7F, Category::Letter, None),
+    (0x0D81, 0x0D81, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0D82, 0x0D83, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0D85, 0x0D96, Category::Letter, None),
+    (0x0D9A, 0x0DB1, Category::Letter, None),
+    (0x0DB3, 0x0DBB, Category::Letter, None),
+    (0x0DBD, 0x0DBD, Category::Letter, None),
+    (0x0DC0, 0x0DC6, Category::Letter, None),
+    (0x0DCA, 0x0DCA, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0DCF, 0x0DD1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0DD2, 0x0DD4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0DD6, 0x0DD6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0DD8, 0x0DDF, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0DE6, 0x0DEF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0DF2, 0x0DF3, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0DF4, 0x0DF4, Category::Punctuation, None),
+    (0x0E01, 0x0E30, Category::Letter, None),
+    (0x0E31, 0x0E31, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0E32, 0x0E33, Category::Letter, None),
+    (0x0E34, 0x0E3A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0E3F, 0x0E3F, Category::Symbol, Some(Subcategory::Currency)),
+    (0x0E40, 0x0E45, Category::Letter, None),
+    (0x0E46, 0x0E46, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0E47, 0x0E4E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0E4F, 0x0E4F, Category::Punctuation, None),
+    (0x0E50, 0x0E59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0E5A, 0x0E5B, Category::Punctuation, None),
+    (0x0E81, 0x0E82, Category::Letter, None),
+    (0x0E84, 0x0E84, Category::Letter, None),
+    (0x0E86, 0x0E8A, Category::Letter, None),
+    (0x0E8C, 0x0EA3, Category::Letter, None),
+    (0x0EA5, 0x0EA5, Category::Letter, None),
+    (0x0EA7, 0x0EB0, Category::Letter, None),
+    (0x0EB1, 0x0EB1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0EB2, 0x0EB3, Category::Letter, None),
+    (0x0EB4, 0x0EBC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0EBD, 0x0EBD, Category::Letter, None),
+    (0x0EC0, 0x0EC4, Category::Letter, None),
+    (0x0EC6, 0x0EC6, Category::Letter, Some(Subcategory::Modifier)),
+    (0x0EC8, 0x0ECD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0ED0, 0x0ED9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0EDC, 0x0EDF, Category::Letter, None),
+    (0x0F00, 0x0F00, Category::Letter, None),
+    (0x0F01, 0x0F03, Category::Symbol, None),
+    (0x0F04, 0x0F12, Category::Punctuation, None),
+    (0x0F13, 0x0F13, Category::Symbol, None),
+    (0x0F14, 0x0F14, Category::Punctuation, None),
+    (0x0F15, 0x0F17, Category::Symbol, None),
+    (0x0F18, 0x0F19, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F1A, 0x0F1F, Category::Symbol, None),
+    (0x0F20, 0x0F29, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x0F2A, 0x0F33, Category::Number, None),
+    (0x0F34, 0x0F34, Category::Symbol, None),
+    (0x0F35, 0x0F35, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F36, 0x0F36, Category::Symbol, None),
+    (0x0F37, 0x0F37, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F38, 0x0F38, Category::Symbol, None),
+    (0x0F39, 0x0F39, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F3A, 0x0F3D, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x0F3E, 0x0F3F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0F40, 0x0F47, Category::Letter, None),
+    (0x0F49, 0x0F6C, Category::Letter, None),
+    (0x0F71, 0x0F7E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F7F, 0x0F7F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x0F80, 0x0F84, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F85, 0x0F85, Category::Punctuation, None),
+    (0x0F86, 0x0F87, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F88, 0x0F8C, Category::Letter, None),
+    (0x0F8D, 0x0F97, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0F99, 0x0FBC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0FBE, 0x0FC5, Category::Symbol, None),
+    (0x0FC6, 0x0FC6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x0FC7, 0x0FCC, Category::Symbol, None),
+    (0x0FCE, 0x0FCF, Category::Symbol, None),
+    (0x0FD0, 0x0FD4, Category::Punctuation, None),
+    (0x0FD5, 0x0FD8, Category::Symbol, None),
+    (0x0FD9, 0x0FDA, Category::Punctuation, None),
+    (0x1000, 0x102A, Category::Letter, None),
+    (0x102B, 0x102C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x102D, 0x1030, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1031, 0x1031, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1032, 0x1037, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1038, 0x1038, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1039, 0x103A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x103B, 0x103C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x103D, 0x103E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x103F, 0x103F, Category::Letter, None),
+    (0x1040, 0x1049, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x104A, 0x104F, Category::Punctuation, None),
+    (0x1050, 0x1055, Category::Letter, None),
+    (0x1056, 0x1057, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1058, 0x1059, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x105A, 0x105D, Category::Letter, None),
+    (0x105E, 0x1060, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1061, 0x1061, Category::Letter, None),
+    (0x1062, 0x1064, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1065, 0x1066, Category::Letter, None),
+    (0x1067, 0x106D, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x106E, 0x1070, Category::Letter, None),
+    (0x1071, 0x1074, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1075, 0x1081, Category::Letter, None),
+    (0x1082, 0x1082, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1083, 0x1084, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1085, 0x1086, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1087, 0x108C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x108D, 0x108D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x108E, 0x108E, Category::Letter, None),
+    (0x108F, 0x108F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1090, 0x1099, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x109A, 0x109C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x109D, 0x109D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x109E, 0x109F, Category::Symbol, None),
+    (0x10A0, 0x10C5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10C7, 0x10C7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10CD, 0x10CD, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10D0, 0x10FA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x10FB, 0x10FB, Category::Punctuation, None),
+    (0x10FC, 0x10FC, Category::Letter, Some(Subcategory::Modifier)),
+    (0x10FD, 0x10FF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1100, 0x1248, Category::Letter, None),
+    (0x124A, 0x124D, Category::Letter, None),
+    (0x1250, 0x1256, Category::Letter, None),
+    (0x1258, 0x1258, Category::Letter, None),
+    (0x125A, 0x125D, Category::Letter, None),
+    (0x1260, 0x1288, Category::Letter, None),
+    (0x128A, 0x128D, Category::Letter, None),
+    (0x1290, 0x12B0, Category::Letter, None),
+    (0x12B2, 0x12B5, Category::Letter, None),
+    (0x12B8, 0x12BE, Category::Letter, None),
+    (0x12C0, 0x12C0, Category::Letter, None),
+    (0x12C2, 0x12C5, Category::Letter, None),
+    (0x12C8, 0x12D6, Category::Letter, None),
+    (0x12D8, 0x1310, Category::Letter, None),
+    (0x1312, 0x1315, Category::Letter, None),
+    (0x1318, 0x135A, Category::Letter, None),
+    (0x135D, 0x135F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1360, 0x1368, Category::Punctuation, None),
+    (0x1369, 0x137C, Category::Number, None),
+    (0x1380, 0x138F, Category::Letter, None),
+    (0x1390, 0x1399, Category::Symbol, None),
+    (0x13A0, 0x13F5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x13F8, 0x13FD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1400, 0x1400, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x1401, 0x166C, Category::Letter, None),
+    (0x166D, 0x166D, Category::Symbol, None),
+    (0x166E, 0x166E, Category::Punctuation, None),
+    (0x166F, 0x167F, Category::Letter, None),
+    (0x1680, 0x1680, Category::Separator, Some(Subcategory::Space)),
+    (0x1681, 0x169A, Category::Letter, None),
+    (0x169B, 0x169C, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x16A0, 0x16EA, Category::Letter, None),
+    (0x16EB, 0x16ED, Category::Punctuation, None),
+    (0x16EE, 0x16F0, Category::Number, Some(Subcategory::Letter)),
+    (0x16F1, 0x16F8, Category::Letter, None),
+    (0x1700, 0x1711, Category::Letter, None),
+    (0x1712, 0x1714, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1715, 0x1715, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x171F, 0x1731, Category::Letter, None),
+    (0x1732, 0x1733, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1734, 0x1734, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1735, 0x1736, Category::Punctuation, None),
+    (0x1740, 0x1751, Category::Letter, None),
+    (0x1752, 0x1753, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1760, 0x176C, Category::Letter, None),
+    (0x176E, 0x1770, Category::Letter, None),
+    (0x1772, 0x1773, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1780, 0x17B3, Category::Letter, None),
+    (0x17B4, 0x17B5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x17B6, 0x17B6, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x17B7, 0x17BD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x17BE, 0x17C5, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x17C6, 0x17C6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x17C7, 0x17C8, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x17C9, 0x17D3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x17D4, 0x17D6, Category::Punctuation, None),
+    (0x17D7, 0x17D7, Category::Letter, Some(Subcategory::Modifier)),
+    (0x17D8, 0x17DA, Category::Punctuation, None),
+    (0x17DB, 0x17DB, Category::Symbol, Some(Subcategory::Currency)),
+    (0x17DC, 0x17DC, Category::Letter, None),
+    (0x17DD, 0x17DD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x17E0, 0x17E9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x17F0, 0x17F9, Category::Number, None),
+    (0x1800, 0x1805, Category::Punctuation, None),
+    (0x1806, 0x1806, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x1807, 0x180A, Category::Punctuation, None),
+    (0x180B, 0x180D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x180E, 0x180E, Category::Other, Some(Subcategory::Format)),
+    (0x180F, 0x180F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1810, 0x1819, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1820, 0x1842, Category::Letter, None),
+    (0x1843, 0x1843, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1844, 0x1878, Category::Letter, None),
+    (0x1880, 0x1884, Category::Letter, None),
+    (0x1885, 0x1886, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1887, 0x18A8, Category::Letter, None),
+    (0x18A9, 0x18A9, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x18AA, 0x18AA, Category::Letter, None),
+    (0x18B0, 0x18F5, Category::Letter, None),
+    (0x1900, 0x191E, Category::Letter, None),
+    (0x1920, 0x1922, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1923, 0x1926, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1927, 0x1928, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1929, 0x192B, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1930, 0x1931, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1932, 0x1932, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1933, 0x1938, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1939, 0x193B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1940, 0x1940, Category::Symbol, None),
+    (0x1944, 0x1945, Category::Punctuation, None),
+    (0x1946, 0x194F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1950, 0x196D, Category::Letter, None),
+    (0x1970, 0x1974, Category::Letter, None),
+    (0x1980, 0x19AB, Category::Letter, None),
+    (0x19B0, 0x19C9, Category::Letter, None),
+    (0x19D0, 0x19D9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x19DA, 0x19DA, Category::Number, None),
+    (0x19DE, 0x19FF, Category::Symbol, None),
+    (0x1A00, 0x1A16, Category::Letter, None),
+    (0x1A17, 0x1A18, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A19, 0x1A1A, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A1B, 0x1A1B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A1E, 0x1A1F, Category::Punctuation, None),
+    (0x1A20, 0x1A54, Category::Letter, None),
+    (0x1A55, 0x1A55, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A56, 0x1A56, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A57, 0x1A57, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A58, 0x1A5E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A60, 0x1A60, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A61, 0x1A61, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A62, 0x1A62, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A63, 0x1A64, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A65, 0x1A6C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A6D, 0x1A72, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1A73, 0x1A7C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A7F, 0x1A7F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1A80, 0x1A89, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1A90, 0x1A99, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1AA0, 0x1AA6, Category::Punctuation, None),
+    (0x1AA7, 0x1AA7, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1AA8, 0x1AAD, Category::Punctuation, None),
+    (0x1AB0, 0x1ABD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1ABE, 0x1ABE, Category::Mark, Some(Subcategory::Enclosing)),
+    (0x1ABF, 0x1ACE, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B00, 0x1B03, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B04, 0x1B04, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B05, 0x1B33, Category::Letter, None),
+    (0x1B34, 0x1B34, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B35, 0x1B35, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B36, 0x1B3A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B3B, 0x1B3B, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B3C, 0x1B3C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B3D, 0x1B41, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B42, 0x1B42, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B43, 0x1B44, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B45, 0x1B4C, Category::Letter, None),
+    (0x1B50, 0x1B59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1B5A, 0x1B60, Category::Punctuation, None),
+    (0x1B61, 0x1B6A, Category::Symbol, None),
+    (0x1B6B, 0x1B73, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B74, 0x1B7C, Category::Symbol, None),
+    (0x1B7D, 0x1B7E, Category::Punctuation, None),
+    (0x1B80, 0x1B81, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1B82, 0x1B82, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1B83, 0x1BA0, Category::Letter, None),
+    (0x1BA1, 0x1BA1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BA2, 0x1BA5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BA6, 0x1BA7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BA8, 0x1BA9, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BAA, 0x1BAA, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BAB, 0x1BAD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BAE, 0x1BAF, Category::Letter, None),
+    (0x1BB0, 0x1BB9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1BBA, 0x1BE5, Category::Letter, None),
+    (0x1BE6, 0x1BE6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BE7, 0x1BE7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BE8, 0x1BE9, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BEA, 0x1BEC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BED, 0x1BED, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BEE, 0x1BEE, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BEF, 0x1BF1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BF2, 0x1BF3, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1BFC, 0x1BFF, Category::Punctuation, None),
+    (0x1C00, 0x1C23, Category::Letter, None),
+    (0x1C24, 0x1C2B, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1C2C, 0x1C33, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1C34, 0x1C35, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1C36, 0x1C37, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1C3B, 0x1C3F, Category::Punctuation, None),
+    (0x1C40, 0x1C49, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1C4D, 0x1C4F, Category::Letter, None),
+    (0x1C50, 0x1C59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1C5A, 0x1C77, Category::Letter, None),
+    (0x1C78, 0x1C7D, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1C7E, 0x1C7F, Category::Punctuation, None),
+    (0x1C80, 0x1C88, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1C90, 0x1CBA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1CBD, 0x1CBF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1CC0, 0x1CC7, Category::Punctuation, None),
+    (0x1CD0, 0x1CD2, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CD3, 0x1CD3, Category::Punctuation, None),
+    (0x1CD4, 0x1CE0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CE1, 0x1CE1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1CE2, 0x1CE8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CE9, 0x1CEC, Category::Letter, None),
+    (0x1CED, 0x1CED, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CEE, 0x1CF3, Category::Letter, None),
+    (0x1CF4, 0x1CF4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CF5, 0x1CF6, Category::Letter, None),
+    (0x1CF7, 0x1CF7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1CF8, 0x1CF9, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CFA, 0x1CFA, Category::Letter, None),
+    (0x1D00, 0x1D2B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D2C, 0x1D6A, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1D6B, 0x1D77, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D78, 0x1D78, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1D79, 0x1D9A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D9B, 0x1DBF, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1DC0, 0x1DFF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E00, 0x1E00, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E01, 0x1E01, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E02, 0x1E02, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E03, 0x1E03, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E04, 0x1E04, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E05, 0x1E05, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E06, 0x1E06, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E07, 0x1E07, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E08, 0x1E08, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E09, 0x1E09, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E0A, 0x1E0A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E0B, 0x1E0B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E0C, 0x1E0C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E0D, 0x1E0D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E0E, 0x1E0E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E0F, 0x1E0F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E10, 0x1E10, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E11, 0x1E11, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E12, 0x1E12, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E13, 0x1E13, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E14, 0x1E14, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E15, 0x1E15, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E16, 0x1E16, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E17, 0x1E17, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E18, 0x1E18, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E19, 0x1E19, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E1A, 0x1E1A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E1B, 0x1E1B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E1C, 0x1E1C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E1D, 0x1E1D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E1E, 0x1E1E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E1F, 0x1E1F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E20, 0x1E20, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E21, 0x1E21, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E22, 0x1E22, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E23, 0x1E23, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E24, 0x1E24, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E25, 0x1E25, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E26, 0x1E26, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E27, 0x1E27, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E28, 0x1E28, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E29, 0x1E29, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E2A, 0x1E2A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E2B, 0x1E2B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E2C, 0x1E2C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E2D, 0x1E2D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E2E, 0x1E2E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E2F, 0x1E2F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E30, 0x1E30, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E31, 0x1E31, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E32, 0x1E32, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E33, 0x1E33, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E34, 0x1E34, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E35, 0x1E35, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E36, 0x1E36, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E37, 0x1E37, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E38, 0x1E38, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E39, 0x1E39, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E3A, 0x1E3A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E3B, 0x1E3B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E3C, 0x1E3C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E3D, 0x1E3D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E3E, 0x1E3E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E3F, 0x1E3F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E40, 0x1E40, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E41, 0x1E41, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E42, 0x1E42, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E43, 0x1E43, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E44, 0x1E44, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E45, 0x1E45, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E46, 0x1E46, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E47, 0x1E47, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E48, 0x1E48, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E49, 0x1E49, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E4A, 0x1E4A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E4B, 0x1E4B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E4C, 0x1E4C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E4D, 0x1E4D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E4E, 0x1E4E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E4F, 0x1E4F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E50, 0x1E50, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E51, 0x1E51, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E52, 0x1E52, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E53, 0x1E53, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E54, 0x1E54, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E55, 0x1E55, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E56, 0x1E56, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E57, 0x1E57, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E58, 0x1E58, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E59, 0x1E59, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E5A, 0x1E5A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E5B, 0x1E5B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E5C, 0x1E5C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E5D, 0x1E5D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E5E, 0x1E5E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E5F, 0x1E5F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E60, 0x1E60, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E61, 0x1E61, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E62, 0x1E62, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E63, 0x1E63, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E64, 0x1E64, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E65, 0x1E65, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E66, 0x1E66, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E67, 0x1E67, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E68, 0x1E68, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E69, 0x1E69, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E6A, 0x1E6A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E6B, 0x1E6B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E6C, 0x1E6C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E6D, 0x1E6D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E6E, 0x1E6E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E6F, 0x1E6F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E70, 0x1E70, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E71, 0x1E71, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E72, 0x1E72, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E73, 0x1E73, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E74, 0x1E74, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E75, 0x1E75, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E76, 0x1E76, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E77, 0x1E77, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E78, 0x1E78, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E79, 0x1E79, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E7A, 0x1E7A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E7B, 0x1E7B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E7C, 0x1E7C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E7D, 0x1E7D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E7E, 0x1E7E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E7F, 0x1E7F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E80, 0x1E80, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E81, 0x1E81, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E82, 0x1E82, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E83, 0x1E83, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E84, 0x1E84, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E85, 0x1E85, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E86, 0x1E86, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E87, 0x1E87, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E88, 0x1E88, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E89, 0x1E89, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E8A, 0x1E8A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E8B, 0x1E8B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E8C, 0x1E8C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E8D, 0x1E8D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E8E, 0x1E8E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E8F, 0x1E8F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E90, 0x1E90, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E91, 0x1E91, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E92, 0x1E92, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E93, 0x1E93, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E94, 0x1E94, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E95, 0x1E9D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E9E, 0x1E9E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E9F, 0x1E9F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EA0, 0x1EA0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EA1, 0x1EA1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EA2, 0x1EA2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EA3, 0x1EA3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EA4, 0x1EA4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EA5, 0x1EA5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EA6, 0x1EA6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EA7, 0x1EA7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EA8, 0x1EA8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EA9, 0x1EA9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EAA, 0x1EAA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EAB, 0x1EAB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EAC, 0x1EAC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EAD, 0x1EAD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EAE, 0x1EAE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EAF, 0x1EAF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EB0, 0x1EB0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EB1, 0x1EB1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EB2, 0x1EB2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EB3, 0x1EB3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EB4, 0x1EB4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EB5, 0x1EB5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EB6, 0x1EB6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EB7, 0x1EB7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EB8, 0x1EB8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EB9, 0x1EB9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EBA, 0x1EBA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EBB, 0x1EBB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EBC, 0x1EBC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EBD, 0x1EBD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EBE, 0x1EBE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EBF, 0x1EBF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EC0, 0x1EC0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EC1, 0x1EC1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EC2, 0x1EC2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EC3, 0x1EC3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EC4, 0x1EC4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EC5, 0x1EC5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EC6, 0x1EC6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EC7, 0x1EC7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EC8, 0x1EC8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EC9, 0x1EC9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ECA, 0x1ECA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ECB, 0x1ECB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ECC, 0x1ECC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ECD, 0x1ECD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ECE, 0x1ECE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ECF, 0x1ECF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ED0, 0x1ED0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ED1, 0x1ED1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ED2, 0x1ED2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ED3, 0x1ED3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ED4, 0x1ED4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ED5, 0x1ED5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ED6, 0x1ED6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ED7, 0x1ED7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1ED8, 0x1ED8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1ED9, 0x1ED9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EDA, 0x1EDA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EDB, 0x1EDB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EDC, 0x1EDC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EDD, 0x1EDD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EDE, 0x1EDE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EDF, 0x1EDF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EE0, 0x1EE0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EE1, 0x1EE1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EE2, 0x1EE2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EE3, 0x1EE3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EE4, 0x1EE4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EE5, 0x1EE5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EE6, 0x1EE6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EE7, 0x1EE7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EE8, 0x1EE8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EE9, 0x1EE9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EEA, 0x1EEA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EEB, 0x1EEB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EEC, 0x1EEC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EED, 0x1EED, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EEE, 0x1EEE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EEF, 0x1EEF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EF0, 0x1EF0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EF1, 0x1EF1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EF2, 0x1EF2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EF3, 0x1EF3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EF4, 0x1EF4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EF5, 0x1EF5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EF6, 0x1EF6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EF7, 0x1EF7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EF8, 0x1EF8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EF9, 0x1EF9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EFA, 0x1EFA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EFB, 0x1EFB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EFC, 0x1EFC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EFD, 0x1EFD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1EFE, 0x1EFE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1EFF, 0x1F07, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F08, 0x1F0F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F10, 0x1F15, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F18, 0x1F1D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F20, 0x1F27, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F28, 0x1F2F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F30, 0x1F37, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F38, 0x1F3F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F40, 0x1F45, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F48, 0x1F4D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F50, 0x1F57, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F59, 0x1F59, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F5B, 0x1F5B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F5D, 0x1F5D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F5F, 0x1F5F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F60, 0x1F67, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F68, 0x1F6F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F70, 0x1F7D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F80, 0x1F87, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F88, 0x1F8F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1F90, 0x1F97, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1F98, 0x1F9F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FA0, 0x1FA7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FA8, 0x1FAF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FB0, 0x1FB4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FB6, 0x1FB7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FB8, 0x1FBC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FBD, 0x1FBD, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1FBE, 0x1FBE, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FBF, 0x1FC1, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1FC2, 0x1FC4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FC6, 0x1FC7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FC8, 0x1FCC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FCD, 0x1FCF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1FD0, 0x1FD3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FD6, 0x1FD7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FD8, 0x1FDB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FDD, 0x1FDF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1FE0, 0x1FE7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FE8, 0x1FEC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FED, 0x1FEF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1FF2, 0x1FF4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FF6, 0x1FF7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1FF8, 0x1FFC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1FFD, 0x1FFE, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x2000, 0x200A, Category::Separator, Some(Subcategory::Space)),
+    (0x200B, 0x200F, Category::Other, Some(Subcategory::Format)),
+    (0x2010, 0x2015, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2016, 0x2017, Category::Punctuation, None),
+    (0x2018, 0x2019, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x201A, 0x201A, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x201B, 0x201D, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x201E, 0x201E, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x201F, 0x201F, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2020, 0x2027, Category::Punctuation, None),
+    (0x2028, 0x2029, Category::Separator, None),
+    (0x202A, 0x202E, Category::Other, Some(Subcategory::Format)),
+    (0x202F, 0x202F, Category::Separator, Some(Subcategory::Space)),
+    (0x2030, 0x2038, Category::Punctuation, None),
+    (0x2039, 0x203A, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x203B, 0x203E, Category::Punctuation, None),
+    (0x203F, 0x2040, Category::Punctuation, Some(Subcategory::Connector)),
+    (0x2041, 0x2043, Category::Punctuation, None),
+    (0x2044, 0x2044, Category::Symbol, Some(Subcategory::Math)),
+    (0x2045, 0x2046, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2047, 0x2051, Category::Punctuation, None),
+    (0x2052, 0x2052, Category::Symbol, Some(Subcategory::Math)),
+    (0x2053, 0x2053, Category::Punctuation, None),
+    (0x2054, 0x2054, Category::Punctuation, Some(Subcategory::Connector)),
+    (0x2055, 0x205E, Category::Punctuation, None),
+    (0x205F, 0x205F, Category::Separator, Some(Subcategory::Space)),
+    (0x2060, 0x2064, Category::Other, Some(Subcategory::Format)),
+    (0x2066, 0x206F, Category::Other, Some(Subcategory::Format)),
+    (0x2070, 0x2070, Category::Number, None),
+    (0x2071, 0x2071, Category::Letter, Some(Subcategory::Modifier)),
+    (0x2074, 0x2079, Category::Number, None),
+    (0x207A, 0x207C, Category::Symbol, Some(Subcategory::Math)),
+    (0x207D, 0x207E, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x207F, 0x207F, Category::Letter, Some(Subcategory::Modifier)),
+    (0x2080, 0x2089, Category::Number, None),
+    (0x208A, 0x208C, Category::Symbol, Some(Subcategory::Math)),
+    (0x208D, 0x208E, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2090, 0x209C, Category::Letter, Some(Subcategory::Modifier)),
+    (0x20A0, 0x20C0, Category::Symbol, Some(Subcategory::Currency)),
+    (0x20D0, 0x20DC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x20DD, 0x20E0, Category::Mark, Some(Subcategory::Enclosing)),
+    (0x20E1, 0x20E1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x20E2, 0x20E4, Category::Mark, Some(Subcategory::Enclosing)),
+    (0x20E5, 0x20F0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x2100, 0x2101, Category::Symbol, None),
+    (0x2102, 0x2102, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2103, 0x2106, Category::Symbol, None),
+    (0x2107, 0x2107, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2108, 0x2109, Category::Symbol, None),
+    (0x210A, 0x210A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x210B, 0x210D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x210E, 0x210F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2110, 0x2112, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2113, 0x2113, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2114, 0x2114, Category::Symbol, None),
+    (0x2115, 0x2115, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2116, 0x2117, Category::Symbol, None),
+    (0x2118, 0x2118, Category::Symbol, Some(Subcategory::Math)),
+    (0x2119, 0x211D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x211E, 0x2123, Category::Symbol, None),
+    (0x2124, 0x2124, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2125, 0x2125, Category::Symbol, None),
+    (0x2126, 0x2126, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2127, 0x2127, Category::Symbol, None),
+    (0x2128, 0x2128, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2129, 0x2129, Category::Symbol, None),
+    (0x212A, 0x212D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x212E, 0x212E, Category::Symbol, None),
+    (0x212F, 0x212F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2130, 0x2133, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2134, 0x2134, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2135, 0x2138, Category::Letter, None),
+    (0x2139, 0x2139, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x213A, 0x213B, Category::Symbol, None),
+    (0x213C, 0x213D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x213E, 0x213F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2140, 0x2144, Category::Symbol, Some(Subcategory::Math)),
+    (0x2145, 0x2145, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2146, 0x2149, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x214A, 0x214A, Category::Symbol, None),
+    (0x214B, 0x214B, Category::Symbol, Some(Subcategory::Math)),
+    (0x214C, 0x214D, Category::Symbol, None),
+    (0x214E, 0x214E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x214F, 0x214F, Category::Symbol, None),
+    (0x2150, 0x215F, Category::Number, None),
+    (0x2160, 0x2182, Category::Number, Some(Subcategory::Letter)),
+    (0x2183, 0x2183, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2184, 0x2184, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2185, 0x2188, Category::Number, Some(Subcategory::Letter)),
+    (0x2189, 0x2189, Category::Number, None),
+    (0x218A, 0x218B, Category::Symbol, None),
+    (0x2190, 0x2194, Category::Symbol, Some(Subcategory::Math)),
+    (0x2195, 0x2199, Category::Symbol, None),
+    (0x219A, 0x219B, Category::Symbol, Some(Subcategory::Math)),
+    (0x219C, 0x219F, Category::Symbol, None),
+    (0x21A0, 0x21A0, Category::Symbol, Some(Subcategory::Math)),
+    (0x21A1, 0x21A2, Category::Symbol, None),
+    (0x21A3, 0x21A3, Category::Symbol, Some(Subcategory::Math)),
+    (0x21A4, 0x21A5, Category::Symbol, None),
+    (0x21A6, 0x21A6, Category::Symbol, Some(Subcategory::Math)),
+    (0x21A7, 0x21AD, Category::Symbol, None),
+    (0x21AE, 0x21AE, Category::Symbol, Some(Subcategory::Math)),
+    (0x21AF, 0x21CD, Category::Symbol, None),
+    (0x21CE, 0x21CF, Category::Symbol, Some(Subcategory::Math)),
+    (0x21D0, 0x21D1, Category::Symbol, None),
+    (0x21D2, 0x21D2, Category::Symbol, Some(Subcategory::Math)),
+    (0x21D3, 0x21D3, Category::Symbol, None),
+    (0x21D4, 0x21D4, Category::Symbol, Some(Subcategory::Math)),
+    (0x21D5, 0x21F3, Category::Symbol, None),
+    (0x21F4, 0x22FF, Category::Symbol, Some(Subcategory::Math)),
+    (0x2300, 0x2307, Category::Symbol, None),
+    (0x2308, 0x230B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x230C, 0x231F, Category::Symbol, None),
+    (0x2320, 0x2321, Category::Symbol, Some(Subcategory::Math)),
+    (0x2322, 0x2328, Category::Symbol, None),
+    (0x2329, 0x232A, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x232B, 0x237B, Category::Symbol, None),
+    (0x237C, 0x237C, Category::Symbol, Some(Subcategory::Math)),
+    (0x237D, 0x239A, Category::Symbol, None),
+    (0x239B, 0x23B3, Category::Symbol, Some(Subcategory::Math)),
+    (0x23B4, 0x23DB, Category::Symbol, None),
+    (0x23DC, 0x23E1, Category::Symbol, Some(Subcategory::Math)),
+    (0x23E2, 0x2426, Category::Symbol, None),
+    (0x2440, 0x244A, Category::Symbol, None),
+    (0x2460, 0x249B, Category::Number, None),
+    (0x249C, 0x24E9, Category::Symbol, None),
+    (0x24EA, 0x24FF, Category::Number, None),
+    (0x2500, 0x25B6, Category::Symbol, None),
+    (0x25B7, 0x25B7, Category::Symbol, Some(Subcategory::Math)),
+    (0x25B8, 0x25C0, Category::Symbol, None),
+    (0x25C1, 0x25C1, Category::Symbol, Some(Subcategory::Math)),
+    (0x25C2, 0x25F7, Category::Symbol, None),
+    (0x25F8, 0x25FF, Category::Symbol, Some(Subcategory::Math)),
+    (0x2600, 0x266E, Category::Symbol, None),
+    (0x266F, 0x266F, Category::Symbol, Some(Subcategory::Math)),
+    (0x2670, 0x2767, Category::Symbol, None),
+    (0x2768, 0x2775, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2776, 0x2793, Category::Number, None),
+    (0x2794, 0x27BF, Category::Symbol, None),
+    (0x27C0, 0x27C4, Category::Symbol, Some(Subcategory::Math)),
+    (0x27C5, 0x27C6, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x27C7, 0x27E5, Category::Symbol, Some(Subcategory::Math)),
+    (0x27E6, 0x27EF, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x27F0, 0x27FF, Category::Symbol, Some(Subcategory::Math)),
+    (0x2800, 0x28FF, Category::Symbol, None),
+    (0x2900, 0x2982, Category::Symbol, Some(Subcategory::Math)),
+    (0x2983, 0x2998, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2999, 0x29D7, Category::Symbol, Some(Subcategory::Math)),
+    (0x29D8, 0x29DB, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x29DC, 0x29FB, Category::Symbol, Some(Subcategory::Math)),
+    (0x29FC, 0x29FD, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x29FE, 0x2AFF, Category::Symbol, Some(Subcategory::Math)),
+    (0x2B00, 0x2B2F, Category::Symbol, None),
+    (0x2B30, 0x2B44, Category::Symbol, Some(Subcategory::Math)),
+    (0x2B45, 0x2B46, Category::Symbol, None),
+    (0x2B47, 0x2B4C, Category::Symbol, Some(Subcategory::Math)),
+    (0x2B4D, 0x2B73, Category::Symbol, None),
+    (0x2B76, 0x2B95, Category::Symbol, None),
+    (0x2B97, 0x2BFF, Category::Symbol, None),
+    (0x2C00, 0x2C2F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C30, 0x2C5F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C60, 0x2C60, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C61, 0x2C61, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C62, 0x2C64, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C65, 0x2C66, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C67, 0x2C67, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C68, 0x2C68, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C69, 0x2C69, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C6A, 0x2C6A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C6B, 0x2C6B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C6C, 0x2C6C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C6D, 0x2C70, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C71, 0x2C71, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C72, 0x2C72, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C73, 0x2C74, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C75, 0x2C75, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C76, 0x2C7B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C7C, 0x2C7D, Category::Letter, Some(Subcategory::Modifier)),
+    (0x2C7E, 0x2C80, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C81, 0x2C81, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C82, 0x2C82, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C83, 0x2C83, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C84, 0x2C84, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C85, 0x2C85, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C86, 0x2C86, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C87, 0x2C87, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C88, 0x2C88, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C89, 0x2C89, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C8A, 0x2C8A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C8B, 0x2C8B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C8C, 0x2C8C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C8D, 0x2C8D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C8E, 0x2C8E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C8F, 0x2C8F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C90, 0x2C90, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C91, 0x2C91, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C92, 0x2C92, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C93, 0x2C93, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C94, 0x2C94, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C95, 0x2C95, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C96, 0x2C96, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C97, 0x2C97, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C98, 0x2C98, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C99, 0x2C99, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C9A, 0x2C9A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C9B, 0x2C9B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C9C, 0x2C9C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C9D, 0x2C9D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2C9E, 0x2C9E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2C9F, 0x2C9F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CA0, 0x2CA0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CA1, 0x2CA1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CA2, 0x2CA2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CA3, 0x2CA3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CA4, 0x2CA4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CA5, 0x2CA5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CA6, 0x2CA6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CA7, 0x2CA7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CA8, 0x2CA8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CA9, 0x2CA9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CAA, 0x2CAA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CAB, 0x2CAB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CAC, 0x2CAC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CAD, 0x2CAD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CAE, 0x2CAE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CAF, 0x2CAF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CB0, 0x2CB0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CB1, 0x2CB1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CB2, 0x2CB2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CB3, 0x2CB3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CB4, 0x2CB4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CB5, 0x2CB5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CB6, 0x2CB6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CB7, 0x2CB7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CB8, 0x2CB8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CB9, 0x2CB9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CBA, 0x2CBA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CBB, 0x2CBB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CBC, 0x2CBC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CBD, 0x2CBD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CBE, 0x2CBE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CBF, 0x2CBF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CC0, 0x2CC0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CC1, 0x2CC1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CC2, 0x2CC2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CC3, 0x2CC3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CC4, 0x2CC4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CC5, 0x2CC5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CC6, 0x2CC6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CC7, 0x2CC7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CC8, 0x2CC8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CC9, 0x2CC9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CCA, 0x2CCA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CCB, 0x2CCB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CCC, 0x2CCC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CCD, 0x2CCD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CCE, 0x2CCE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CCF, 0x2CCF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CD0, 0x2CD0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CD1, 0x2CD1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CD2, 0x2CD2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CD3, 0x2CD3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CD4, 0x2CD4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CD5, 0x2CD5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CD6, 0x2CD6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CD7, 0x2CD7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CD8, 0x2CD8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CD9, 0x2CD9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CDA, 0x2CDA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CDB, 0x2CDB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CDC, 0x2CDC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CDD, 0x2CDD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CDE, 0x2CDE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CDF, 0x2CDF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CE0, 0x2CE0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CE1, 0x2CE1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CE2, 0x2CE2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CE3, 0x2CE4, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CE5, 0x2CEA, Category::Symbol, None),
+    (0x2CEB, 0x2CEB, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CEC, 0x2CEC, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CED, 0x2CED, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CEE, 0x2CEE, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CEF, 0x2CF1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x2CF2, 0x2CF2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x2CF3, 0x2CF3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2CF9, 0x2CFC, Category::Punctuation, None),
+    (0x2CFD, 0x2CFD, Category::Number, None),
+    (0x2CFE, 0x2CFF, Category::Punctuation, None),
+    (0x2D00, 0x2D25, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2D27, 0x2D27, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2D2D, 0x2D2D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x2D30, 0x2D67, Category::Letter, None),
+    (0x2D6F, 0x2D6F, Category::Letter, Some(Subcategory::Modifier)),
+    (0x2D70, 0x2D70, Category::Punctuation, None),
+    (0x2D7F, 0x2D7F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x2D80, 0x2D96, Category::Letter, None),
+    (0x2DA0, 0x2DA6, Category::Letter, None),
+    (0x2DA8, 0x2DAE, Category::Letter, None),
+    (0x2DB0, 0x2DB6, Category::Letter, None),
+    (0x2DB8, 0x2DBE, Category::Letter, None),
+    (0x2DC0, 0x2DC6, Category::Letter, None),
+    (0x2DC8, 0x2DCE, Category::Letter, None),
+    (0x2DD0, 0x2DD6, Category::Letter, None),
+    (0x2DD8, 0x2DDE, Category::Letter, None),
+    (0x2DE0, 0x2DFF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x2E00, 0x2E01, Category::Punctuation, None),
+    (0x2E02, 0x2E05, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2E06, 0x2E08, Category::Punctuation, None),
+    (0x2E09, 0x2E0A, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2E0B, 0x2E0B, Category::Punctuation, None),
+    (0x2E0C, 0x2E0D, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2E0E, 0x2E16, Category::Punctuation, None),
+    (0x2E17, 0x2E17, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2E18, 0x2E19, Category::Punctuation, None),
+    (0x2E1A, 0x2E1A, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2E1B, 0x2E1B, Category::Punctuation, None),
+    (0x2E1C, 0x2E1D, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2E1E, 0x2E1F, Category::Punctuation, None),
+    (0x2E20, 0x2E21, Category::Punctuation, Some(Subcategory::Quote)),
+    (0x2E22, 0x2E29, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2E2A, 0x2E2E, Category::Punctuation, None),
+    (0x2E2F, 0x2E2F, Category::Letter, Some(Subcategory::Modifier)),
+    (0x2E30, 0x2E39, Category::Punctuation, None),
+    (0x2E3A, 0x2E3B, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2E3C, 0x2E3F, Category::Punctuation, None),
+    (0x2E40, 0x2E40, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2E41, 0x2E41, Category::Punctuation, None),
+    (0x2E42, 0x2E42, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2E43, 0x2E4F, Category::Punctuation, None),
+    (0x2E50, 0x2E51, Category::Symbol, None),
+    (0x2E52, 0x2E54, Category::Punctuation, None),
+    (0x2E55, 0x2E5C, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x2E5D, 0x2E5D, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x2E80, 0x2E99, Category::Symbol, None),
+    (0x2E9B, 0x2EF3, Category::Symbol, None),
+    (0x2F00, 0x2FD5, Category::Symbol, None),
+    (0x2FF0, 0x2FFB, Category::Symbol, None),
+    (0x3000, 0x3000, Category::Separator, Some(Subcategory::Space)),
+    (0x3001, 0x3003, Category::Punctuation, None),
+    (0x3004, 0x3004, Category::Symbol, None),
+    (0x3005, 0x3005, Category::Letter, Some(Subcategory::Modifier)),
+    (0x3006, 0x3006, Category::Letter, None),
+    (0x3007, 0x3007, Category::Number, Some(Subcategory::Letter)),
+    (0x3008, 0x3011, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x3012, 0x3013, Category::Symbol, None),
+    (0x3014, 0x301B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x301C, 0x301C, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x301D, 0x301F, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0x3020, 0x3020, Category::Symbol, None),
+    (0x3021, 0x3029, Category::Number, Some(Subcategory::Letter)),
+    (0x302A, 0x302D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x302E, 0x302F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x3030, 0x3030, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x3031, 0x3035, Category::Letter, Some(Subcategory::Modifier)),
+    (0x3036, 0x3037, Category::Symbol, None),
+    (0x3038, 0x303A, Category::Number, Some(Subcategory::Letter)),
+    (0x303B, 0x303B, Category::Letter, Some(Subcategory::Modifier)),
+    (0x303C, 0x303C, Category::Letter, None),
+    (0x303D, 0x303D, Category::Punctuation, None),
+    (0x303E, 0x303F, Category::Symbol, None),
+    (0x3041, 0x3096, Category::Letter, None),
+    (0x3099, 0x309A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x309B, 0x309C, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x309D, 0x309E, Category::Letter, Some(Subcategory::Modifier)),
+    (0x309F, 0x309F, Category::Letter, None),
+    (0x30A0, 0x30A0, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x30A1, 0x30FA, Category::Letter, None),
+    (0x30FB, 0x30FB, Category::Punctuation, None),
+    (0x30FC, 0x30FE, Category::Letter, Some(Subcategory::Modifier)),
+    (0x30FF, 0x30FF, Category::Letter, None),
+    (0x3105, 0x312F, Category::Letter, None),
+    (0x3131, 0x318E, Category::Letter, None),
+    (0x3190, 0x3191, Category::Symbol, None),
+    (0x3192, 0x3195, Category::Number, None),
+    (0x3196, 0x319F, Category::Symbol, None),
+    (0x31A0, 0x31BF, Category::Letter, None),
+    (0x31C0, 0x31E3, Category::Symbol, None),
+    (0x31F0, 0x31FF, Category::Letter, None),
+    (0x3200, 0x321E, Category::Symbol, None),
+    (0x3220, 0x3229, Category::Number, None),
+    (0x322A, 0x3247, Category::Symbol, None),
+    (0x3248, 0x324F, Category::Number, None),
+    (0x3250, 0x3250, Category::Symbol, None),
+    (0x3251, 0x325F, Category::Number, None),
+    (0x3260, 0x327F, Category::Symbol, None),
+    (0x3280, 0x3289, Category::Number, None),
+    (0x328A, 0x32B0, Category::Symbol, None),
+    (0x32B1, 0x32BF, Category::Number, None),
+    (0x32C0, 0x33FF, Category::Symbol, None),
+    (0x3400, 0x4DBF, Category::Letter, None),
+    (0x4DC0, 0x4DFF, Category::Symbol, None),
+    (0x4E00, 0xA014, Category::Letter, None),
+    (0xA015, 0xA015, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA016, 0xA48C, Category::Letter, None),
+    (0xA490, 0xA4C6, Category::Symbol, None),
+    (0xA4D0, 0xA4F7, Category::Letter, None),
+    (0xA4F8, 0xA4FD, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA4FE, 0xA4FF, Category::Punctuation, None),
+    (0xA500, 0xA60B, Category::Letter, None),
+    (0xA60C, 0xA60C, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA60D, 0xA60F, Category::Punctuation, None),
+    (0xA610, 0xA61F, Category::Letter, None),
+    (0xA620, 0xA629, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xA62A, 0xA62B, Category::Letter, None),
+    (0xA640, 0xA640, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA641, 0xA641, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA642, 0xA642, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA643, 0xA643, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA644, 0xA644, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA645, 0xA645, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA646, 0xA646, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA647, 0xA647, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA648, 0xA648, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA649, 0xA649, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA64A, 0xA64A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA64B, 0xA64B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA64C, 0xA64C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA64D, 0xA64D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA64E, 0xA64E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA64F, 0xA64F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA650, 0xA650, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA651, 0xA651, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA652, 0xA652, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA653, 0xA653, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA654, 0xA654, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA655, 0xA655, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA656, 0xA656, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA657, 0xA657, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA658, 0xA658, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA659, 0xA659, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA65A, 0xA65A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA65B, 0xA65B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA65C, 0xA65C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA65D, 0xA65D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA65E, 0xA65E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA65F, 0xA65F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA660, 0xA660, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA661, 0xA661, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA662, 0xA662, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA663, 0xA663, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA664, 0xA664, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA665, 0xA665, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA666, 0xA666, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA667, 0xA667, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA668, 0xA668, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA669, 0xA669, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA66A, 0xA66A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA66B, 0xA66B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA66C, 0xA66C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA66D, 0xA66D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA66E, 0xA66E, Category::Letter, None),
+    (0xA66F, 0xA66F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA670, 0xA672, Category::Mark, Some(Subcategory::Enclosing)),
+    (0xA673, 0xA673, Category::Punctuation, None),
+    (0xA674, 0xA67D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA67E, 0xA67E, Category::Punctuation, None),
+    (0xA67F, 0xA67F, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA680, 0xA680, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA681, 0xA681, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA682, 0xA682, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA683, 0xA683, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA684, 0xA684, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA685, 0xA685, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA686, 0xA686, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA687, 0xA687, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA688, 0xA688, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA689, 0xA689, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA68A, 0xA68A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA68B, 0xA68B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA68C, 0xA68C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA68D, 0xA68D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA68E, 0xA68E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA68F, 0xA68F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA690, 0xA690, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA691, 0xA691, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA692, 0xA692, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA693, 0xA693, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA694, 0xA694, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA695, 0xA695, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA696, 0xA696, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA697, 0xA697, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA698, 0xA698, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA699, 0xA699, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA69A, 0xA69A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA69B, 0xA69B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA69C, 0xA69D, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA69E, 0xA69F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA6A0, 0xA6E5, Category::Letter, None),
+    (0xA6E6, 0xA6EF, Category::Number, Some(Subcategory::Letter)),
+    (0xA6F0, 0xA6F1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA6F2, 0xA6F7, Category::Punctuation, None),
+    (0xA700, 0xA716, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xA717, 0xA71F, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA720, 0xA721, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xA722, 0xA722, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA723, 0xA723, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA724, 0xA724, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA725, 0xA725, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA726, 0xA726, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA727, 0xA727, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA728, 0xA728, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA729, 0xA729, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA72A, 0xA72A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA72B, 0xA72B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA72C, 0xA72C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA72D, 0xA72D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA72E, 0xA72E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA72F, 0xA731, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA732, 0xA732, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA733, 0xA733, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA734, 0xA734, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA735, 0xA735, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA736, 0xA736, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA737, 0xA737, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA738, 0xA738, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA739, 0xA739, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA73A, 0xA73A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA73B, 0xA73B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA73C, 0xA73C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA73D, 0xA73D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA73E, 0xA73E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA73F, 0xA73F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA740, 0xA740, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA741, 0xA741, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA742, 0xA742, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA743, 0xA743, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA744, 0xA744, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA745, 0xA745, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA746, 0xA746, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA747, 0xA747, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA748, 0xA748, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA749, 0xA749, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA74A, 0xA74A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA74B, 0xA74B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA74C, 0xA74C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA74D, 0xA74D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA74E, 0xA74E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA74F, 0xA74F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA750, 0xA750, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA751, 0xA751, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA752, 0xA752, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA753, 0xA753, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA754, 0xA754, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA755, 0xA755, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA756, 0xA756, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA757, 0xA757, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA758, 0xA758, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA759, 0xA759, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA75A, 0xA75A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA75B, 0xA75B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA75C, 0xA75C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA75D, 0xA75D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA75E, 0xA75E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA75F, 0xA75F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA760, 0xA760, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA761, 0xA761, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA762, 0xA762, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA763, 0xA763, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA764, 0xA764, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA765, 0xA765, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA766, 0xA766, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA767, 0xA767, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA768, 0xA768, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA769, 0xA769, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA76A, 0xA76A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA76B, 0xA76B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA76C, 0xA76C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA76D, 0xA76D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA76E, 0xA76E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA76F, 0xA76F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA770, 0xA770, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA771, 0xA778, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA779, 0xA779, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA77A, 0xA77A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA77B, 0xA77B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA77C, 0xA77C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA77D, 0xA77E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA77F, 0xA77F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA780, 0xA780, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA781, 0xA781, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA782, 0xA782, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA783, 0xA783, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA784, 0xA784, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA785, 0xA785, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA786, 0xA786, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA787, 0xA787, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA788, 0xA788, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA789, 0xA78A, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xA78B, 0xA78B, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA78C, 0xA78C, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA78D, 0xA78D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA78E, 0xA78E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA78F, 0xA78F, Category::Letter, None),
+    (0xA790, 0xA790, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA791, 0xA791, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA792, 0xA792, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA793, 0xA795, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA796, 0xA796, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA797, 0xA797, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA798, 0xA798, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA799, 0xA799, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA79A, 0xA79A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA79B, 0xA79B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA79C, 0xA79C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA79D, 0xA79D, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA79E, 0xA79E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA79F, 0xA79F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7A0, 0xA7A0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7A1, 0xA7A1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7A2, 0xA7A2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7A3, 0xA7A3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7A4, 0xA7A4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7A5, 0xA7A5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7A6, 0xA7A6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7A7, 0xA7A7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7A8, 0xA7A8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7A9, 0xA7A9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7AA, 0xA7AE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7AF, 0xA7AF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7B0, 0xA7B4, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7B5, 0xA7B5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7B6, 0xA7B6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7B7, 0xA7B7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7B8, 0xA7B8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7B9, 0xA7B9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7BA, 0xA7BA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7BB, 0xA7BB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7BC, 0xA7BC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7BD, 0xA7BD, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7BE, 0xA7BE, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7BF, 0xA7BF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7C0, 0xA7C0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7C1, 0xA7C1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7C2, 0xA7C2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7C3, 0xA7C3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7C4, 0xA7C7, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7C8, 0xA7C8, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7C9, 0xA7C9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7CA, 0xA7CA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7D0, 0xA7D0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7D1, 0xA7D1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7D3, 0xA7D3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7D5, 0xA7D5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7D6, 0xA7D6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7D7, 0xA7D7, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7D8, 0xA7D8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7D9, 0xA7D9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7F2, 0xA7F4, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA7F5, 0xA7F5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xA7F6, 0xA7F6, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7F7, 0xA7F7, Category::Letter, None),
+    (0xA7F8, 0xA7F9, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA7FA, 0xA7FA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xA7FB, 0xA801, Category::Letter, None),
+    (0xA802, 0xA802, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA803, 0xA805, Category::Letter, None),
+    (0xA806, 0xA806, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA807, 0xA80A, Category::Letter, None),
+    (0xA80B, 0xA80B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA80C, 0xA822, Category::Letter, None),
+    (0xA823, 0xA824, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA825, 0xA826, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA827, 0xA827, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA828, 0xA82B, Category::Symbol, None),
+    (0xA82C, 0xA82C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA830, 0xA835, Category::Number, None),
+    (0xA836, 0xA837, Category::Symbol, None),
+    (0xA838, 0xA838, Category::Symbol, Some(Subcategory::Currency)),
+    (0xA839, 0xA839, Category::Symbol, None),
+    (0xA840, 0xA873, Category::Letter, None),
+    (0xA874, 0xA877, Category::Punctuation, None),
+    (0xA880, 0xA881, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA882, 0xA8B3, Category::Letter, None),
+    (0xA8B4, 0xA8C3, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA8C4, 0xA8C5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA8CE, 0xA8CF, Category::Punctuation, None),
+    (0xA8D0, 0xA8D9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xA8E0, 0xA8F1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA8F2, 0xA8F7, Category::Letter, None),
+    (0xA8F8, 0xA8FA, Category::Punctuation, None),
+    (0xA8FB, 0xA8FB, Category::Letter, None),
+    (0xA8FC, 0xA8FC, Category::Punctuation, None),
+    (0xA8FD, 0xA8FE, Category::Letter, None),
+    (0xA8FF, 0xA8FF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA900, 0xA909, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xA90A, 0xA925, Category::Letter, None),
+    (0xA926, 0xA92D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA92E, 0xA92F, Category::Punctuation, None),
+    (0xA930, 0xA946, Category::Letter, None),
+    (0xA947, 0xA951, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA952, 0xA953, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA95F, 0xA95F, Category::Punctuation, None),
+    (0xA960, 0xA97C, Category::Letter, None),
+    (0xA980, 0xA982, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA983, 0xA983, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA984, 0xA9B2, Category::Letter, None),
+    (0xA9B3, 0xA9B3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA9B4, 0xA9B5, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA9B6, 0xA9B9, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA9BA, 0xA9BB, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA9BC, 0xA9BD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA9BE, 0xA9C0, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xA9C1, 0xA9CD, Category::Punctuation, None),
+    (0xA9CF, 0xA9CF, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA9D0, 0xA9D9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xA9DE, 0xA9DF, Category::Punctuation, None),
+    (0xA9E0, 0xA9E4, Category::Letter, None),
+    (0xA9E5, 0xA9E5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xA9E6, 0xA9E6, Category::Letter, Some(Subcategory::Modifier)),
+    (0xA9E7, 0xA9EF, Category::Letter, None),
+    (0xA9F0, 0xA9F9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xA9FA, 0xA9FE, Category::Letter, None),
+    (0xAA00, 0xAA28, Category::Letter, None),
+    (0xAA29, 0xAA2E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA2F, 0xAA30, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAA31, 0xAA32, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA33, 0xAA34, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAA35, 0xAA36, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA40, 0xAA42, Category::Letter, None),
+    (0xAA43, 0xAA43, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA44, 0xAA4B, Category::Letter, None),
+    (0xAA4C, 0xAA4C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA4D, 0xAA4D, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAA50, 0xAA59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xAA5C, 0xAA5F, Category::Punctuation, None),
+    (0xAA60, 0xAA6F, Category::Letter, None),
+    (0xAA70, 0xAA70, Category::Letter, Some(Subcategory::Modifier)),
+    (0xAA71, 0xAA76, Category::Letter, None),
+    (0xAA77, 0xAA79, Category::Symbol, None),
+    (0xAA7A, 0xAA7A, Category::Letter, None),
+    (0xAA7B, 0xAA7B, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAA7C, 0xAA7C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAA7D, 0xAA7D, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAA7E, 0xAAAF, Category::Letter, None),
+    (0xAAB0, 0xAAB0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAB1, 0xAAB1, Category::Letter, None),
+    (0xAAB2, 0xAAB4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAB5, 0xAAB6, Category::Letter, None),
+    (0xAAB7, 0xAAB8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAB9, 0xAABD, Category::Letter, None),
+    (0xAABE, 0xAABF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAC0, 0xAAC0, Category::Letter, None),
+    (0xAAC1, 0xAAC1, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAC2, 0xAAC2, Category::Letter, None),
+    (0xAADB, 0xAADC, Category::Letter, None),
+    (0xAADD, 0xAADD, Category::Letter, Some(Subcategory::Modifier)),
+    (0xAADE, 0xAADF, Category::Punctuation, None),
+    (0xAAE0, 0xAAEA, Category::Letter, None),
+    (0xAAEB, 0xAAEB, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAAEC, 0xAAED, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAAEE, 0xAAEF, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAAF0, 0xAAF1, Category::Punctuation, None),
+    (0xAAF2, 0xAAF2, Category::Letter, None),
+    (0xAAF3, 0xAAF4, Category::Letter, Some(Subcategory::Modifier)),
+    (0xAAF5, 0xAAF5, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xAAF6, 0xAAF6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xAB01, 0xAB06, Category::Letter, None),
+    (0xAB09, 0xAB0E, Category::Letter, None),
+    (0xAB11, 0xAB16, Category::Letter, None),
+    (0xAB20, 0xAB26, Category::Letter, None),
+    (0xAB28, 0xAB2E, Category::Letter, None),
+    (0xAB30, 0xAB5A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xAB5B, 0xAB5B, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xAB5C, 0xAB5F, Category::Letter, Some(Subcategory::Modifier)),
+    (0xAB60, 0xAB68, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xAB69, 0xAB69, Category::Letter, Some(Subcategory::Modifier)),
+    (0xAB6A, 0xAB6B, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xAB70, 0xABBF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xABC0, 0xABE2, Category::Letter, None),
+    (0xABE3, 0xABE4, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xABE5, 0xABE5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xABE6, 0xABE7, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xABE8, 0xABE8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xABE9, 0xABEA, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xABEB, 0xABEB, Category::Punctuation, None),
+    (0xABEC, 0xABEC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0xABED, 0xABED, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xABF0, 0xABF9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xAC00, 0xD7A3, Category::Letter, None),
+    (0xD7B0, 0xD7C6, Category::Letter, None),
+    (0xD7CB, 0xD7FB, Category::Letter, None),
+    (0xE000, 0xF8FF, Category::Other, None),
+    (0xF900, 0xFA6D, Category::Letter, None),
+    (0xFA70, 0xFAD9, Category::Letter, None),
+    (0xFB00, 0xFB06, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xFB13, 0xFB17, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xFB1D, 0xFB1D, Category::Letter, None),
+    (0xFB1E, 0xFB1E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xFB1F, 0xFB28, Category::Letter, None),
+    (0xFB29, 0xFB29, Category::Symbol, Some(Subcategory::Math)),
+    (0xFB2A, 0xFB36, Category::Letter, None),
+    (0xFB38, 0xFB3C, Category::Letter, None),
+    (0xFB3E, 0xFB3E, Category::Letter, None),
+    (0xFB40, 0xFB41, Category::Letter, None),
+    (0xFB43, 0xFB44, Category::Letter, None),
+    (0xFB46, 0xFBB1, Category::Letter, None),
+    (0xFBB2, 0xFBC2, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xFBD3, 0xFD3D, Category::Letter, None),
+    (0xFD3E, 0xFD3F, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFD40, 0xFD4F, Category::Symbol, None),
+    (0xFD50, 0xFD8F, Category::Letter, None),
+    (0xFD92, 0xFDC7, Category::Letter, None),
+    (0xFDCF, 0xFDCF, Category::Symbol, None),
+    (0xFDF0, 0xFDFB, Category::Letter, None),
+    (0xFDFC, 0xFDFC, Category::Symbol, Some(Subcategory::Currency)),
+    (0xFDFD, 0xFDFF, Category::Symbol, None),
+    (0xFE00, 0xFE0F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xFE10, 0xFE16, Category::Punctuation, None),
+    (0xFE17, 0xFE18, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFE19, 0xFE19, Category::Punctuation, None),
+    (0xFE20, 0xFE2F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xFE30, 0xFE30, Category::Punctuation, None),
+    (0xFE31, 0xFE32, Category::Punctuation, Some(Subcategory::Dash)),
+    (0xFE33, 0xFE34, Category::Punctuation, Some(Subcategory::Connector)),
+    (0xFE35, 0xFE44, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFE45, 0xFE46, Category::Punctuation, None),
+    (0xFE47, 0xFE48, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFE49, 0xFE4C, Category::Punctuation, None),
+    (0xFE4D, 0xFE4F, Category::Punctuation, Some(Subcategory::Connector)),
+    (0xFE50, 0xFE52, Category::Punctuation, None),
+    (0xFE54, 0xFE57, Category::Punctuation, None),
+    (0xFE58, 0xFE58, Category::Punctuation, Some(Subcategory::Dash)),
+    (0xFE59, 0xFE5E, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFE5F, 0xFE61, Category::Punctuation, None),
+    (0xFE62, 0xFE62, Category::Symbol, Some(Subcategory::Math)),
+    (0xFE63, 0xFE63, Category::Punctuation, Some(Subcategory::Dash)),
+    (0xFE64, 0xFE66, Category::Symbol, Some(Subcategory::Math)),
+    (0xFE68, 0xFE68, Category::Punctuation, None),
+    (0xFE69, 0xFE69, Category::Symbol, Some(Subcategory::Currency)),
+    (0xFE6A, 0xFE6B, Category::Punctuation, None),
+    (0xFE70, 0xFE74, Category::Letter, None),
+    (0xFE76, 0xFEFC, Category::Letter, None),
+    (0xFEFF, 0xFEFF, Category::Other, Some(Subcategory::Format)),
+    (0xFF01, 0xFF03, Category::Punctuation, None),
+    (0xFF04, 0xFF04, Category::Symbol, Some(Subcategory::Currency)),
+    (0xFF05, 0xFF07, Category::Punctuation, None),
+    (0xFF08, 0xFF09, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF0A, 0xFF0A, Category::Punctuation, None),
+    (0xFF0B, 0xFF0B, Category::Symbol, Some(Subcategory::Math)),
+    (0xFF0C, 0xFF0C, Category::Punctuation, None),
+    (0xFF0D, 0xFF0D, Category::Punctuation, Some(Subcategory::Dash)),
+    (0xFF0E, 0xFF0F, Category::Punctuation, None),
+    (0xFF10, 0xFF19, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0xFF1A, 0xFF1B, Category::Punctuation, None),
+    (0xFF1C, 0xFF1E, Category::Symbol, Some(Subcategory::Math)),
+    (0xFF1F, 0xFF20, Category::Punctuation, None),
+    (0xFF21, 0xFF3A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0xFF3B, 0xFF3B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF3C, 0xFF3C, Category::Punctuation, None),
+    (0xFF3D, 0xFF3D, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF3E, 0xFF3E, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xFF3F, 0xFF3F, Category::Punctuation, Some(Subcategory::Connector)),
+    (0xFF40, 0xFF40, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xFF41, 0xFF5A, Category::Letter, Some(Subcategory::Lowercase)),
+    (0xFF5B, 0xFF5B, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF5C, 0xFF5C, Category::Symbol, Some(Subcategory::Math)),
+    (0xFF5D, 0xFF5D, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF5E, 0xFF5E, Category::Symbol, Some(Subcategory::Math)),
+    (0xFF5F, 0xFF60, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF61, 0xFF61, Category::Punctuation, None),
+    (0xFF62, 0xFF63, Category::Punctuation, Some(Subcategory::Parenthesis)),
+    (0xFF64, 0xFF65, Category::Punctuation, None),
+    (0xFF66, 0xFF6F, Category::Letter, None),
+    (0xFF70, 0xFF70, Category::Letter, Some(Subcategory::Modifier)),
+    (0xFF71, 0xFF9D, Category::Letter, None),
+    (0xFF9E, 0xFF9F, Category::Letter, Some(Subcategory::Modifier)),
+    (0xFFA0, 0xFFBE, Category::Letter, None),
+    (0xFFC2, 0xFFC7, Category::Letter, None),
+    (0xFFCA, 0xFFCF, Category::Letter, None),
+    (0xFFD2, 0xFFD7, Category::Letter, None),
+    (0xFFDA, 0xFFDC, Category::Letter, None),
+    (0xFFE0, 0xFFE1, Category::Symbol, Some(Subcategory::Currency)),
+    (0xFFE2, 0xFFE2, Category::Symbol, Some(Subcategory::Math)),
+    (0xFFE3, 0xFFE3, Category::Symbol, Some(Subcategory::Modifier)),
+    (0xFFE4, 0xFFE4, Category::Symbol, None),
+    (0xFFE5, 0xFFE6, Category::Symbol, Some(Subcategory::Currency)),
+    (0xFFE8, 0xFFE8, Category::Symbol, None),
+    (0xFFE9, 0xFFEC, Category::Symbol, Some(Subcategory::Math)),
+    (0xFFED, 0xFFEE, Category::Symbol, None),
+    (0xFFF9, 0xFFFB, Category::Other, Some(Subcategory::Format)),
+    (0xFFFC, 0xFFFD, Category::Symbol, None),
+    (0x10000, 0x1000B, Category::Letter, None),
+    (0x1000D, 0x10026, Category::Letter, None),
+    (0x10028, 0x1003A, Category::Letter, None),
+    (0x1003C, 0x1003D, Category::Letter, None),
+    (0x1003F, 0x1004D, Category::Letter, None),
+    (0x10050, 0x1005D, Category::Letter, None),
+    (0x10080, 0x100FA, Category::Letter, None),
+    (0x10100, 0x10102, Category::Punctuation, None),
+    (0x10107, 0x10133, Category::Number, None),
+    (0x10137, 0x1013F, Category::Symbol, None),
+    (0x10140, 0x10174, Category::Number, Some(Subcategory::Letter)),
+    (0x10175, 0x10178, Category::Number, None),
+    (0x10179, 0x10189, Category::Symbol, None),
+    (0x1018A, 0x1018B, Category::Number, None),
+    (0x1018C, 0x1018E, Category::Symbol, None),
+    (0x10190, 0x1019C, Category::Symbol, None),
+    (0x101A0, 0x101A0, Category::Symbol, None),
+    (0x101D0, 0x101FC, Category::Symbol, None),
+    (0x101FD, 0x101FD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10280, 0x1029C, Category::Letter, None),
+    (0x102A0, 0x102D0, Category::Letter, None),
+    (0x102E0, 0x102E0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x102E1, 0x102FB, Category::Number, None),
+    (0x10300, 0x1031F, Category::Letter, None),
+    (0x10320, 0x10323, Category::Number, None),
+    (0x1032D, 0x10340, Category::Letter, None),
+    (0x10341, 0x10341, Category::Number, Some(Subcategory::Letter)),
+    (0x10342, 0x10349, Category::Letter, None),
+    (0x1034A, 0x1034A, Category::Number, Some(Subcategory::Letter)),
+    (0x10350, 0x10375, Category::Letter, None),
+    (0x10376, 0x1037A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10380, 0x1039D, Category::Letter, None),
+    (0x1039F, 0x1039F, Category::Punctuation, None),
+    (0x103A0, 0x103C3, Category::Letter, None),
+    (0x103C8, 0x103CF, Category::Letter, None),
+    (0x103D0, 0x103D0, Category::Punctuation, None),
+    (0x103D1, 0x103D5, Category::Number, Some(Subcategory::Letter)),
+    (0x10400, 0x10427, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10428, 0x1044F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x10450, 0x1049D, Category::Letter, None),
+    (0x104A0, 0x104A9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x104B0, 0x104D3, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x104D8, 0x104FB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x10500, 0x10527, Category::Letter, None),
+    (0x10530, 0x10563, Category::Letter, None),
+    (0x1056F, 0x1056F, Category::Punctuation, None),
+    (0x10570, 0x1057A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1057C, 0x1058A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1058C, 0x10592, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10594, 0x10595, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10597, 0x105A1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x105A3, 0x105B1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x105B3, 0x105B9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x105BB, 0x105BC, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x10600, 0x10736, Category::Letter, None),
+    (0x10740, 0x10755, Category::Letter, None),
+    (0x10760, 0x10767, Category::Letter, None),
+    (0x10780, 0x10785, Category::Letter, Some(Subcategory::Modifier)),
+    (0x10787, 0x107B0, Category::Letter, Some(Subcategory::Modifier)),
+    (0x107B2, 0x107BA, Category::Letter, Some(Subcategory::Modifier)),
+    (0x10800, 0x10805, Category::Letter, None),
+    (0x10808, 0x10808, Category::Letter, None),
+    (0x1080A, 0x10835, Category::Letter, None),
+    (0x10837, 0x10838, Category::Letter, None),
+    (0x1083C, 0x1083C, Category::Letter, None),
+    (0x1083F, 0x10855, Category::Letter, None),
+    (0x10857, 0x10857, Category::Punctuation, None),
+    (0x10858, 0x1085F, Category::Number, None),
+    (0x10860, 0x10876, Category::Letter, None),
+    (0x10877, 0x10878, Category::Symbol, None),
+    (0x10879, 0x1087F, Category::Number, None),
+    (0x10880, 0x1089E, Category::Letter, None),
+    (0x108A7, 0x108AF, Category::Number, None),
+    (0x108E0, 0x108F2, Category::Letter, None),
+    (0x108F4, 0x108F5, Category::Letter, None),
+    (0x108FB, 0x108FF, Category::Number, None),
+    (0x10900, 0x10915, Category::Letter, None),
+    (0x10916, 0x1091B, Category::Number, None),
+    (0x1091F, 0x1091F, Category::Punctuation, None),
+    (0x10920, 0x10939, Category::Letter, None),
+    (0x1093F, 0x1093F, Category::Punctuation, None),
+    (0x10980, 0x109B7, Category::Letter, None),
+    (0x109BC, 0x109BD, Category::Number, None),
+    (0x109BE, 0x109BF, Category::Letter, None),
+    (0x109C0, 0x109CF, Category::Number, None),
+    (0x109D2, 0x109FF, Category::Number, None),
+    (0x10A00, 0x10A00, Category::Letter, None),
+    (0x10A01, 0x10A03, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10A05, 0x10A06, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10A0C, 0x10A0F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10A10, 0x10A13, Category::Letter, None),
+    (0x10A15, 0x10A17, Category::Letter, None),
+    (0x10A19, 0x10A35, Category::Letter, None),
+    (0x10A38, 0x10A3A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10A3F, 0x10A3F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10A40, 0x10A48, Category::Number, None),
+    (0x10A50, 0x10A58, Category::Punctuation, None),
+    (0x10A60, 0x10A7C, Category::Letter, None),
+    (0x10A7D, 0x10A7E, Category::Number, None),
+    (0x10A7F, 0x10A7F, Category::Punctuation, None),
+    (0x10A80, 0x10A9C, Category::Letter, None),
+    (0x10A9D, 0x10A9F, Category::Number, None),
+    (0x10AC0, 0x10AC7, Category::Letter, None),
+    (0x10AC8, 0x10AC8, Category::Symbol, None),
+    (0x10AC9, 0x10AE4, Category::Letter, None),
+    (0x10AE5, 0x10AE6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10AEB, 0x10AEF, Category::Number, None),
+    (0x10AF0, 0x10AF6, Category::Punctuation, None),
+    (0x10B00, 0x10B35, Category::Letter, None),
+    (0x10B39, 0x10B3F, Category::Punctuation, None),
+    (0x10B40, 0x10B55, Category::Letter, None),
+    (0x10B58, 0x10B5F, Category::Number, None),
+    (0x10B60, 0x10B72, Category::Letter, None),
+    (0x10B78, 0x10B7F, Category::Number, None),
+    (0x10B80, 0x10B91, Category::Letter, None),
+    (0x10B99, 0x10B9C, Category::Punctuation, None),
+    (0x10BA9, 0x10BAF, Category::Number, None),
+    (0x10C00, 0x10C48, Category::Letter, None),
+    (0x10C80, 0x10CB2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x10CC0, 0x10CF2, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x10CFA, 0x10CFF, Category::Number, None),
+    (0x10D00, 0x10D23, Category::Letter, None),
+    (0x10D24, 0x10D27, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10D30, 0x10D39, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x10E60, 0x10E7E, Category::Number, None),
+    (0x10E80, 0x10EA9, Category::Letter, None),
+    (0x10EAB, 0x10EAC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10EAD, 0x10EAD, Category::Punctuation, Some(Subcategory::Dash)),
+    (0x10EB0, 0x10EB1, Category::Letter, None),
+    (0x10F00, 0x10F1C, Category::Letter, None),
+    (0x10F1D, 0x10F26, Category::Number, None),
+    (0x10F27, 0x10F27, Category::Letter, None),
+    (0x10F30, 0x10F45, Category::Letter, None),
+    (0x10F46, 0x10F50, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10F51, 0x10F54, Category::Number, None),
+    (0x10F55, 0x10F59, Category::Punctuation, None),
+    (0x10F70, 0x10F81, Category::Letter, None),
+    (0x10F82, 0x10F85, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x10F86, 0x10F89, Category::Punctuation, None),
+    (0x10FB0, 0x10FC4, Category::Letter, None),
+    (0x10FC5, 0x10FCB, Category::Number, None),
+    (0x10FE0, 0x10FF6, Category::Letter, None),
+    (0x11000, 0x11000, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11001, 0x11001, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11002, 0x11002, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11003, 0x11037, Category::Letter, None),
+    (0x11038, 0x11046, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11047, 0x1104D, Category::Punctuation, None),
+    (0x11052, 0x11065, Category::Number, None),
+    (0x11066, 0x1106F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11070, 0x11070, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11071, 0x11072, Category::Letter, None),
+    (0x11073, 0x11074, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11075, 0x11075, Category::Letter, None),
+    (0x1107F, 0x11081, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11082, 0x11082, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11083, 0x110AF, Category::Letter, None),
+    (0x110B0, 0x110B2, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x110B3, 0x110B6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x110B7, 0x110B8, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x110B9, 0x110BA, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x110BB, 0x110BC, Category::Punctuation, None),
+    (0x110BD, 0x110BD, Category::Other, Some(Subcategory::Format)),
+    (0x110BE, 0x110C1, Category::Punctuation, None),
+    (0x110C2, 0x110C2, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x110CD, 0x110CD, Category::Other, Some(Subcategory::Format)),
+    (0x110D0, 0x110E8, Category::Letter, None),
+    (0x110F0, 0x110F9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11100, 0x11102, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11103, 0x11126, Category::Letter, None),
+    (0x11127, 0x1112B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1112C, 0x1112C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1112D, 0x11134, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11136, 0x1113F, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11140, 0x11143, Category::Punctuation, None),
+    (0x11144, 0x11144, Category::Letter, None),
+    (0x11145, 0x11146, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11147, 0x11147, Category::Letter, None),
+    (0x11150, 0x11172, Category::Letter, None),
+    (0x11173, 0x11173, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11174, 0x11175, Category::Punctuation, None),
+    (0x11176, 0x11176, Category::Letter, None),
+    (0x11180, 0x11181, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11182, 0x11182, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11183, 0x111B2, Category::Letter, None),
+    (0x111B3, 0x111B5, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x111B6, 0x111BE, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x111BF, 0x111C0, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x111C1, 0x111C4, Category::Letter, None),
+    (0x111C5, 0x111C8, Category::Punctuation, None),
+    (0x111C9, 0x111CC, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x111CD, 0x111CD, Category::Punctuation, None),
+    (0x111CE, 0x111CE, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x111CF, 0x111CF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x111D0, 0x111D9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x111DA, 0x111DA, Category::Letter, None),
+    (0x111DB, 0x111DB, Category::Punctuation, None),
+    (0x111DC, 0x111DC, Category::Letter, None),
+    (0x111DD, 0x111DF, Category::Punctuation, None),
+    (0x111E1, 0x111F4, Category::Number, None),
+    (0x11200, 0x11211, Category::Letter, None),
+    (0x11213, 0x1122B, Category::Letter, None),
+    (0x1122C, 0x1122E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1122F, 0x11231, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11232, 0x11233, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11234, 0x11234, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11235, 0x11235, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11236, 0x11237, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11238, 0x1123D, Category::Punctuation, None),
+    (0x1123E, 0x1123E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11280, 0x11286, Category::Letter, None),
+    (0x11288, 0x11288, Category::Letter, None),
+    (0x1128A, 0x1128D, Category::Letter, None),
+    (0x1128F, 0x1129D, Category::Letter, None),
+    (0x1129F, 0x112A8, Category::Letter, None),
+    (0x112A9, 0x112A9, Category::Punctuation, None),
+    (0x112B0, 0x112DE, Category::Letter, None),
+    (0x112DF, 0x112DF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x112E0, 0x112E2, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x112E3, 0x112EA, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x112F0, 0x112F9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11300, 0x11301, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11302, 0x11303, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11305, 0x1130C, Category::Letter, None),
+    (0x1130F, 0x11310, Category::Letter, None),
+    (0x11313, 0x11328, Category::Letter, None),
+    (0x1132A, 0x11330, Category::Letter, None),
+    (0x11332, 0x11333, Category::Letter, None),
+    (0x11335, 0x11339, Category::Letter, None),
+    (0x1133B, 0x1133C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1133D, 0x1133D, Category::Letter, None),
+    (0x1133E, 0x1133F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11340, 0x11340, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11341, 0x11344, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11347, 0x11348, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1134B, 0x1134D, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11350, 0x11350, Category::Letter, None),
+    (0x11357, 0x11357, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1135D, 0x11361, Category::Letter, None),
+    (0x11362, 0x11363, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11366, 0x1136C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11370, 0x11374, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11400, 0x11434, Category::Letter, None),
+    (0x11435, 0x11437, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11438, 0x1143F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11440, 0x11441, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11442, 0x11444, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11445, 0x11445, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11446, 0x11446, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11447, 0x1144A, Category::Letter, None),
+    (0x1144B, 0x1144F, Category::Punctuation, None),
+    (0x11450, 0x11459, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1145A, 0x1145B, Category::Punctuation, None),
+    (0x1145D, 0x1145D, Category::Punctuation, None),
+    (0x1145E, 0x1145E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1145F, 0x11461, Category::Letter, None),
+    (0x11480, 0x114AF, Category::Letter, None),
+    (0x114B0, 0x114B2, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x114B3, 0x114B8, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x114B9, 0x114B9, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x114BA, 0x114BA, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x114BB, 0x114BE, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x114BF, 0x114C0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x114C1, 0x114C1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x114C2, 0x114C3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x114C4, 0x114C5, Category::Letter, None),
+    (0x114C6, 0x114C6, Category::Punctuation, None),
+    (0x114C7, 0x114C7, Category::Letter, None),
+    (0x114D0, 0x114D9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11580, 0x115AE, Category::Letter, None),
+    (0x115AF, 0x115B1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x115B2, 0x115B5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x115B8, 0x115BB, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x115BC, 0x115BD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x115BE, 0x115BE, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x115BF, 0x115C0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x115C1, 0x115D7, Category::Punctuation, None),
+    (0x115D8, 0x115DB, Category::Letter, None),
+    (0x115DC, 0x115DD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11600, 0x1162F, Category::Letter, None),
+    (0x11630, 0x11632, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11633, 0x1163A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1163B, 0x1163C, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1163D, 0x1163D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1163E, 0x1163E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1163F, 0x11640, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11641, 0x11643, Category::Punctuation, None),
+    (0x11644, 0x11644, Category::Letter, None),
+    (0x11650, 0x11659, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11660, 0x1166C, Category::Punctuation, None),
+    (0x11680, 0x116AA, Category::Letter, None),
+    (0x116AB, 0x116AB, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x116AC, 0x116AC, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x116AD, 0x116AD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x116AE, 0x116AF, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x116B0, 0x116B5, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x116B6, 0x116B6, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x116B7, 0x116B7, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x116B8, 0x116B8, Category::Letter, None),
+    (0x116B9, 0x116B9, Category::Punctuation, None),
+    (0x116C0, 0x116C9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11700, 0x1171A, Category::Letter, None),
+    (0x1171D, 0x1171F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11720, 0x11721, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11722, 0x11725, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11726, 0x11726, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11727, 0x1172B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11730, 0x11739, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1173A, 0x1173B, Category::Number, None),
+    (0x1173C, 0x1173E, Category::Punctuation, None),
+    (0x1173F, 0x1173F, Category::Symbol, None),
+    (0x11740, 0x11746, Category::Letter, None),
+    (0x11800, 0x1182B, Category::Letter, None),
+    (0x1182C, 0x1182E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1182F, 0x11837, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11838, 0x11838, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11839, 0x1183A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1183B, 0x1183B, Category::Punctuation, None),
+    (0x118A0, 0x118BF, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x118C0, 0x118DF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x118E0, 0x118E9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x118EA, 0x118F2, Category::Number, None),
+    (0x118FF, 0x11906, Category::Letter, None),
+    (0x11909, 0x11909, Category::Letter, None),
+    (0x1190C, 0x11913, Category::Letter, None),
+    (0x11915, 0x11916, Category::Letter, None),
+    (0x11918, 0x1192F, Category::Letter, None),
+    (0x11930, 0x11935, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11937, 0x11938, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1193B, 0x1193C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1193D, 0x1193D, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1193E, 0x1193E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1193F, 0x1193F, Category::Letter, None),
+    (0x11940, 0x11940, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11941, 0x11941, Category::Letter, None),
+    (0x11942, 0x11942, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11943, 0x11943, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11944, 0x11946, Category::Punctuation, None),
+    (0x11950, 0x11959, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x119A0, 0x119A7, Category::Letter, None),
+    (0x119AA, 0x119D0, Category::Letter, None),
+    (0x119D1, 0x119D3, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x119D4, 0x119D7, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x119DA, 0x119DB, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x119DC, 0x119DF, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x119E0, 0x119E0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x119E1, 0x119E1, Category::Letter, None),
+    (0x119E2, 0x119E2, Category::Punctuation, None),
+    (0x119E3, 0x119E3, Category::Letter, None),
+    (0x119E4, 0x119E4, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11A00, 0x11A00, Category::Letter, None),
+    (0x11A01, 0x11A0A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A0B, 0x11A32, Category::Letter, None),
+    (0x11A33, 0x11A38, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A39, 0x11A39, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11A3A, 0x11A3A, Category::Letter, None),
+    (0x11A3B, 0x11A3E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A3F, 0x11A46, Category::Punctuation, None),
+    (0x11A47, 0x11A47, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A50, 0x11A50, Category::Letter, None),
+    (0x11A51, 0x11A56, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A57, 0x11A58, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11A59, 0x11A5B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A5C, 0x11A89, Category::Letter, None),
+    (0x11A8A, 0x11A96, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A97, 0x11A97, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11A98, 0x11A99, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11A9A, 0x11A9C, Category::Punctuation, None),
+    (0x11A9D, 0x11A9D, Category::Letter, None),
+    (0x11A9E, 0x11AA2, Category::Punctuation, None),
+    (0x11AB0, 0x11AF8, Category::Letter, None),
+    (0x11C00, 0x11C08, Category::Letter, None),
+    (0x11C0A, 0x11C2E, Category::Letter, None),
+    (0x11C2F, 0x11C2F, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11C30, 0x11C36, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11C38, 0x11C3D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11C3E, 0x11C3E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11C3F, 0x11C3F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11C40, 0x11C40, Category::Letter, None),
+    (0x11C41, 0x11C45, Category::Punctuation, None),
+    (0x11C50, 0x11C59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11C5A, 0x11C6C, Category::Number, None),
+    (0x11C70, 0x11C71, Category::Punctuation, None),
+    (0x11C72, 0x11C8F, Category::Letter, None),
+    (0x11C92, 0x11CA7, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11CA9, 0x11CA9, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11CAA, 0x11CB0, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11CB1, 0x11CB1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11CB2, 0x11CB3, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11CB4, 0x11CB4, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11CB5, 0x11CB6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D00, 0x11D06, Category::Letter, None),
+    (0x11D08, 0x11D09, Category::Letter, None),
+    (0x11D0B, 0x11D30, Category::Letter, None),
+    (0x11D31, 0x11D36, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D3A, 0x11D3A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D3C, 0x11D3D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D3F, 0x11D45, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D46, 0x11D46, Category::Letter, None),
+    (0x11D47, 0x11D47, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D50, 0x11D59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11D60, 0x11D65, Category::Letter, None),
+    (0x11D67, 0x11D68, Category::Letter, None),
+    (0x11D6A, 0x11D89, Category::Letter, None),
+    (0x11D8A, 0x11D8E, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11D90, 0x11D91, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D93, 0x11D94, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11D95, 0x11D95, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D96, 0x11D96, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11D97, 0x11D97, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11D98, 0x11D98, Category::Letter, None),
+    (0x11DA0, 0x11DA9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x11EE0, 0x11EF2, Category::Letter, None),
+    (0x11EF3, 0x11EF4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x11EF5, 0x11EF6, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x11EF7, 0x11EF8, Category::Punctuation, None),
+    (0x11FB0, 0x11FB0, Category::Letter, None),
+    (0x11FC0, 0x11FD4, Category::Number, None),
+    (0x11FD5, 0x11FDC, Category::Symbol, None),
+    (0x11FDD, 0x11FE0, Category::Symbol, Some(Subcategory::Currency)),
+    (0x11FE1, 0x11FF1, Category::Symbol, None),
+    (0x11FFF, 0x11FFF, Category::Punctuation, None),
+    (0x12000, 0x12399, Category::Letter, None),
+    (0x12400, 0x1246E, Category::Number, Some(Subcategory::Letter)),
+    (0x12470, 0x12474, Category::Punctuation, None),
+    (0x12480, 0x12543, Category::Letter, None),
+    (0x12F90, 0x12FF0, Category::Letter, None),
+    (0x12FF1, 0x12FF2, Category::Punctuation, None),
+    (0x13000, 0x1342E, Category::Letter, None),
+    (0x13430, 0x13438, Category::Other, Some(Subcategory::Format)),
+    (0x14400, 0x14646, Category::Letter, None),
+    (0x16800, 0x16A38, Category::Letter, None),
+    (0x16A40, 0x16A5E, Category::Letter, None),
+    (0x16A60, 0x16A69, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x16A6E, 0x16A6F, Category::Punctuation, None),
+    (0x16A70, 0x16ABE, Category::Letter, None),
+    (0x16AC0, 0x16AC9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x16AD0, 0x16AED, Category::Letter, None),
+    (0x16AF0, 0x16AF4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x16AF5, 0x16AF5, Category::Punctuation, None),
+    (0x16B00, 0x16B2F, Category::Letter, None),
+    (0x16B30, 0x16B36, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x16B37, 0x16B3B, Category::Punctuation, None),
+    (0x16B3C, 0x16B3F, Category::Symbol, None),
+    (0x16B40, 0x16B43, Category::Letter, Some(Subcategory::Modifier)),
+    (0x16B44, 0x16B44, Category::Punctuation, None),
+    (0x16B45, 0x16B45, Category::Symbol, None),
+    (0x16B50, 0x16B59, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x16B5B, 0x16B61, Category::Number, None),
+    (0x16B63, 0x16B77, Category::Letter, None),
+    (0x16B7D, 0x16B8F, Category::Letter, None),
+    (0x16E40, 0x16E5F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x16E60, 0x16E7F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x16E80, 0x16E96, Category::Number, None),
+    (0x16E97, 0x16E9A, Category::Punctuation, None),
+    (0x16F00, 0x16F4A, Category::Letter, None),
+    (0x16F4F, 0x16F4F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x16F50, 0x16F50, Category::Letter, None),
+    (0x16F51, 0x16F87, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x16F8F, 0x16F92, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x16F93, 0x16F9F, Category::Letter, Some(Subcategory::Modifier)),
+    (0x16FE0, 0x16FE1, Category::Letter, Some(Subcategory::Modifier)),
+    (0x16FE2, 0x16FE2, Category::Punctuation, None),
+    (0x16FE3, 0x16FE3, Category::Letter, Some(Subcategory::Modifier)),
+    (0x16FE4, 0x16FE4, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x16FF0, 0x16FF1, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x17000, 0x187F7, Category::Letter, None),
+    (0x18800, 0x18CD5, Category::Letter, None),
+    (0x18D00, 0x18D08, Category::Letter, None),
+    (0x1AFF0, 0x1AFF3, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1AFF5, 0x1AFFB, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1AFFD, 0x1AFFE, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1B000, 0x1B122, Category::Letter, None),
+    (0x1B150, 0x1B152, Category::Letter, None),
+    (0x1B164, 0x1B167, Category::Letter, None),
+    (0x1B170, 0x1B2FB, Category::Letter, None),
+    (0x1BC00, 0x1BC6A, Category::Letter, None),
+    (0x1BC70, 0x1BC7C, Category::Letter, None),
+    (0x1BC80, 0x1BC88, Category::Letter, None),
+    (0x1BC90, 0x1BC99, Category::Letter, None),
+    (0x1BC9C, 0x1BC9C, Category::Symbol, None),
+    (0x1BC9D, 0x1BC9E, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1BC9F, 0x1BC9F, Category::Punctuation, None),
+    (0x1BCA0, 0x1BCA3, Category::Other, Some(Subcategory::Format)),
+    (0x1CF00, 0x1CF2D, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CF30, 0x1CF46, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1CF50, 0x1CFC3, Category::Symbol, None),
+    (0x1D000, 0x1D0F5, Category::Symbol, None),
+    (0x1D100, 0x1D126, Category::Symbol, None),
+    (0x1D129, 0x1D164, Category::Symbol, None),
+    (0x1D165, 0x1D166, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1D167, 0x1D169, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1D16A, 0x1D16C, Category::Symbol, None),
+    (0x1D16D, 0x1D172, Category::Mark, Some(Subcategory::SpacingCombining)),
+    (0x1D173, 0x1D17A, Category::Other, Some(Subcategory::Format)),
+    (0x1D17B, 0x1D182, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1D183, 0x1D184, Category::Symbol, None),
+    (0x1D185, 0x1D18B, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1D18C, 0x1D1A9, Category::Symbol, None),
+    (0x1D1AA, 0x1D1AD, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1D1AE, 0x1D1EA, Category::Symbol, None),
+    (0x1D200, 0x1D241, Category::Symbol, None),
+    (0x1D242, 0x1D244, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1D245, 0x1D245, Category::Symbol, None),
+    (0x1D2E0, 0x1D2F3, Category::Number, None),
+    (0x1D300, 0x1D356, Category::Symbol, None),
+    (0x1D360, 0x1D378, Category::Number, None),
+    (0x1D400, 0x1D419, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D41A, 0x1D433, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D434, 0x1D44D, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D44E, 0x1D454, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D456, 0x1D467, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D468, 0x1D481, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D482, 0x1D49B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D49C, 0x1D49C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D49E, 0x1D49F, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4A2, 0x1D4A2, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4A5, 0x1D4A6, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4A9, 0x1D4AC, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4AE, 0x1D4B5, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4B6, 0x1D4B9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D4BB, 0x1D4BB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D4BD, 0x1D4C3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D4C5, 0x1D4CF, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D4D0, 0x1D4E9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D4EA, 0x1D503, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D504, 0x1D505, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D507, 0x1D50A, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D50D, 0x1D514, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D516, 0x1D51C, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D51E, 0x1D537, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D538, 0x1D539, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D53B, 0x1D53E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D540, 0x1D544, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D546, 0x1D546, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D54A, 0x1D550, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D552, 0x1D56B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D56C, 0x1D585, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D586, 0x1D59F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D5A0, 0x1D5B9, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D5BA, 0x1D5D3, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D5D4, 0x1D5ED, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D5EE, 0x1D607, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D608, 0x1D621, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D622, 0x1D63B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D63C, 0x1D655, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D656, 0x1D66F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D670, 0x1D689, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D68A, 0x1D6A5, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D6A8, 0x1D6C0, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D6C1, 0x1D6C1, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D6C2, 0x1D6DA, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D6DB, 0x1D6DB, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D6DC, 0x1D6E1, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D6E2, 0x1D6FA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D6FB, 0x1D6FB, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D6FC, 0x1D714, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D715, 0x1D715, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D716, 0x1D71B, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D71C, 0x1D734, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D735, 0x1D735, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D736, 0x1D74E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D74F, 0x1D74F, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D750, 0x1D755, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D756, 0x1D76E, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D76F, 0x1D76F, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D770, 0x1D788, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D789, 0x1D789, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D78A, 0x1D78F, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D790, 0x1D7A8, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D7A9, 0x1D7A9, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D7AA, 0x1D7C2, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D7C3, 0x1D7C3, Category::Symbol, Some(Subcategory::Math)),
+    (0x1D7C4, 0x1D7C9, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D7CA, 0x1D7CA, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1D7CB, 0x1D7CB, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1D7CE, 0x1D7FF, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1D800, 0x1D9FF, Category::Symbol, None),
+    (0x1DA00, 0x1DA36, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DA37, 0x1DA3A, Category::Symbol, None),
+    (0x1DA3B, 0x1DA6C, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DA6D, 0x1DA74, Category::Symbol, None),
+    (0x1DA75, 0x1DA75, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DA76, 0x1DA83, Category::Symbol, None),
+    (0x1DA84, 0x1DA84, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DA85, 0x1DA86, Category::Symbol, None),
+    (0x1DA87, 0x1DA8B, Category::Punctuation, None),
+    (0x1DA9B, 0x1DA9F, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DAA1, 0x1DAAF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1DF00, 0x1DF09, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1DF0A, 0x1DF0A, Category::Letter, None),
+    (0x1DF0B, 0x1DF1E, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E000, 0x1E006, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E008, 0x1E018, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E01B, 0x1E021, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E023, 0x1E024, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E026, 0x1E02A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E100, 0x1E12C, Category::Letter, None),
+    (0x1E130, 0x1E136, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E137, 0x1E13D, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1E140, 0x1E149, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1E14E, 0x1E14E, Category::Letter, None),
+    (0x1E14F, 0x1E14F, Category::Symbol, None),
+    (0x1E290, 0x1E2AD, Category::Letter, None),
+    (0x1E2AE, 0x1E2AE, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E2C0, 0x1E2EB, Category::Letter, None),
+    (0x1E2EC, 0x1E2EF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E2F0, 0x1E2F9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1E2FF, 0x1E2FF, Category::Symbol, Some(Subcategory::Currency)),
+    (0x1E7E0, 0x1E7E6, Category::Letter, None),
+    (0x1E7E8, 0x1E7EB, Category::Letter, None),
+    (0x1E7ED, 0x1E7EE, Category::Letter, None),
+    (0x1E7F0, 0x1E7FE, Category::Letter, None),
+    (0x1E800, 0x1E8C4, Category::Letter, None),
+    (0x1E8C7, 0x1E8CF, Category::Number, None),
+    (0x1E8D0, 0x1E8D6, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E900, 0x1E921, Category::Letter, Some(Subcategory::Uppercase)),
+    (0x1E922, 0x1E943, Category::Letter, Some(Subcategory::Lowercase)),
+    (0x1E944, 0x1E94A, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0x1E94B, 0x1E94B, Category::Letter, Some(Subcategory::Modifier)),
+    (0x1E950, 0x1E959, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x1E95E, 0x1E95F, Category::Punctuation, None),
+    (0x1EC71, 0x1ECAB, Category::Number, None),
+    (0x1ECAC, 0x1ECAC, Category::Symbol, None),
+    (0x1ECAD, 0x1ECAF, Category::Number, None),
+    (0x1ECB0, 0x1ECB0, Category::Symbol, Some(Subcategory::Currency)),
+    (0x1ECB1, 0x1ECB4, Category::Number, None),
+    (0x1ED01, 0x1ED2D, Category::Number, None),
+    (0x1ED2E, 0x1ED2E, Category::Symbol, None),
+    (0x1ED2F, 0x1ED3D, Category::Number, None),
+    (0x1EE00, 0x1EE03, Category::Letter, None),
+    (0x1EE05, 0x1EE1F, Category::Letter, None),
+    (0x1EE21, 0x1EE22, Category::Letter, None),
+    (0x1EE24, 0x1EE24, Category::Letter, None),
+    (0x1EE27, 0x1EE27, Category::Letter, None),
+    (0x1EE29, 0x1EE32, Category::Letter, None),
+    (0x1EE34, 0x1EE37, Category::Letter, None),
+    (0x1EE39, 0x1EE39, Category::Letter, None),
+    (0x1EE3B, 0x1EE3B, Category::Letter, None),
+    (0x1EE42, 0x1EE42, Category::Letter, None),
+    (0x1EE47, 0x1EE47, Category::Letter, None),
+    (0x1EE49, 0x1EE49, Category::Letter, None),
+    (0x1EE4B, 0x1EE4B, Category::Letter, None),
+    (0x1EE4D, 0x1EE4F, Category::Letter, None),
+    (0x1EE51, 0x1EE52, Category::Letter, None),
+    (0x1EE54, 0x1EE54, Category::Letter, None),
+    (0x1EE57, 0x1EE57, Category::Letter, None),
+    (0x1EE59, 0x1EE59, Category::Letter, None),
+    (0x1EE5B, 0x1EE5B, Category::Letter, None),
+    (0x1EE5D, 0x1EE5D, Category::Letter, None),
+    (0x1EE5F, 0x1EE5F, Category::Letter, None),
+    (0x1EE61, 0x1EE62, Category::Letter, None),
+    (0x1EE64, 0x1EE64, Category::Letter, None),
+    (0x1EE67, 0x1EE6A, Category::Letter, None),
+    (0x1EE6C, 0x1EE72, Category::Letter, None),
+    (0x1EE74, 0x1EE77, Category::Letter, None),
+    (0x1EE79, 0x1EE7C, Category::Letter, None),
+    (0x1EE7E, 0x1EE7E, Category::Letter, None),
+    (0x1EE80, 0x1EE89, Category::Letter, None),
+    (0x1EE8B, 0x1EE9B, Category::Letter, None),
+    (0x1EEA1, 0x1EEA3, Category::Letter, None),
+    (0x1EEA5, 0x1EEA9, Category::Letter, None),
+    (0x1EEAB, 0x1EEBB, Category::Letter, None),
+    (0x1EEF0, 0x1EEF1, Category::Symbol, Some(Subcategory::Math)),
+    (0x1F000, 0x1F02B, Category::Symbol, None),
+    (0x1F030, 0x1F093, Category::Symbol, None),
+    (0x1F0A0, 0x1F0AE, Category::Symbol, None),
+    (0x1F0B1, 0x1F0BF, Category::Symbol, None),
+    (0x1F0C1, 0x1F0CF, Category::Symbol, None),
+    (0x1F0D1, 0x1F0F5, Category::Symbol, None),
+    (0x1F100, 0x1F10C, Category::Number, None),
+    (0x1F10D, 0x1F1AD, Category::Symbol, None),
+    (0x1F1E6, 0x1F202, Category::Symbol, None),
+    (0x1F210, 0x1F23B, Category::Symbol, None),
+    (0x1F240, 0x1F248, Category::Symbol, None),
+    (0x1F250, 0x1F251, Category::Symbol, None),
+    (0x1F260, 0x1F265, Category::Symbol, None),
+    (0x1F300, 0x1F3FA, Category::Symbol, None),
+    (0x1F3FB, 0x1F3FF, Category::Symbol, Some(Subcategory::Modifier)),
+    (0x1F400, 0x1F6D7, Category::Symbol, None),
+    (0x1F6DD, 0x1F6EC, Category::Symbol, None),
+    (0x1F6F0, 0x1F6FC, Category::Symbol, None),
+    (0x1F700, 0x1F773, Category::Symbol, None),
+    (0x1F780, 0x1F7D8, Category::Symbol, None),
+    (0x1F7E0, 0x1F7EB, Category::Symbol, None),
+    (0x1F7F0, 0x1F7F0, Category::Symbol, None),
+    (0x1F800, 0x1F80B, Category::Symbol, None),
+    (0x1F810, 0x1F847, Category::Symbol, None),
+    (0x1F850, 0x1F859, Category::Symbol, None),
+    (0x1F860, 0x1F887, Category::Symbol, None),
+    (0x1F890, 0x1F8AD, Category::Symbol, None),
+    (0x1F8B0, 0x1F8B1, Category::Symbol, None),
+    (0x1F900, 0x1FA53, Category::Symbol, None),
+    (0x1FA60, 0x1FA6D, Category::Symbol, None),
+    (0x1FA70, 0x1FA74, Category::Symbol, None),
+    (0x1FA78, 0x1FA7C, Category::Symbol, None),
+    (0x1FA80, 0x1FA86, Category::Symbol, None),
+    (0x1FA90, 0x1FAAC, Category::Symbol, None),
+    (0x1FAB0, 0x1FABA, Category::Symbol, None),
+    (0x1FAC0, 0x1FAC5, Category::Symbol, None),
+    (0x1FAD0, 0x1FAD9, Category::Symbol, None),
+    (0x1FAE0, 0x1FAE7, Category::Symbol, None),
+    (0x1FAF0, 0x1FAF6, Category::Symbol, None),
+    (0x1FB00, 0x1FB92, Category::Symbol, None),
+    (0x1FB94, 0x1FBCA, Category::Symbol, None),
+    (0x1FBF0, 0x1FBF9, Category::Number, Some(Subcategory::DecimalDigit)),
+    (0x20000, 0x2A6DF, Category::Letter, None),
+    (0x2A700, 0x2B738, Category::Letter, None),
+    (0x2B740, 0x2B81D, Category::Letter, None),
+    (0x2B820, 0x2CEA1, Category::Letter, None),
+    (0x2CEB0, 0x2EBE0, Category::Letter, None),
+    (0x2F800, 0x2FA1D, Category::Letter, None),
+    (0x30000, 0x3134A, Category::Letter, None),
+    (0xE0001, 0xE0001, Category::Other, Some(Subcategory::Format)),
+    (0xE0020, 0xE007F, Category::Other, Some(Subcategory::Format)),
+    (0xE0100, 0xE01EF, Category::Mark, Some(Subcategory::Nonspacing)),
+    (0xF0000, 0xFFFFD, Category::Other, None),
+    (0x100000, 0x10FFFD, Category::Other, None),
+];
+
+/// Ranges of code points in the same script
+#[rustfmt::skip]
+pub(crate) static SCRIPTS: &[(u32, u32, &str)] = &[
+    (0x0041, 0x005A, "latin"),
+    (0x0061, 0x007A, "latin"),
+    (0x00C0, 0x00D6, "latin"),
+    (0x00D8, 0x00F6, "latin"),
+    (0x00F8, 0x02AF, "latin"),
+    (0x0370, 0x0377, "greek"),
+    (0x037A, 0x037F, "greek"),
+    (0x0384, 0x038A, "greek"),
+    (0x038C, 0x038C, "greek"),
+    (0x038E, 0x03A1, "greek"),
+    (0x03A3, 0x03E1, "greek"),
+    (0x03E2, 0x03EF, "coptic"),
+    (0x03F0, 0x03FF, "greek"),
+    (0x0400, 0x0482, "cyrillic"),
+    (0x048A, 0x052F, "cyrillic"),
+    (0x0531, 0x0556, "armenian"),
+    (0x0559, 0x058A, "armenian"),
+    (0x058F, 0x058F, "armenian"),
+    (0x0591, 0x05C7, "hebrew"),
+    (0x05D0, 0x05EA, "hebrew"),
+    (0x05EF, 0x05F4, "hebrew"),
+    (0x0600, 0x0605, "arabic"),
+    (0x0608, 0x0608, "arabic"),
+    (0x060C, 0x065F, "arabic"),
+    (0x066A, 0x06EF, "arabic"),
+    (0x06FA, 0x06FF, "arabic"),
+    (0x0700, 0x070D, "syriac"),
+    (0x070F, 0x074A, "syriac"),
+    (0x074D, 0x074F, "syriac"),
+    (0x0750, 0x077F, "arabic"),
+    (0x0780, 0x07B1, "thaana"),
+    (0x07C0, 0x07FA, "nko"),
+    (0x07FD, 0x07FF, "nko"),
+    (0x0800, 0x082D, "samaritan"),
+    (0x0830, 0x083E, "samaritan"),
+    (0x0840, 0x085B, "mandaic"),
+    (0x085E, 0x085E, "mandaic"),
+    (0x0860, 0x086A, "syriac"),
+    (0x0870, 0x088E, "arabic"),
+    (0x0890, 0x0891, "arabic"),
+    (0x0898, 0x08FF, "arabic"),
+    (0x0900, 0x097F, "devanagari"),
+    (0x0980, 0x0983, "bengali"),
+    (0x0985, 0x098C, "bengali"),
+    (0x098F, 0x0990, "bengali"),
+    (0x0993, 0x09A8, "bengali"),
+    (0x09AA, 0x09B0, "bengali"),
+    (0x09B2, 0x09B2, "bengali"),
+    (0x09B6, 0x09B9, "bengali"),
+    (0x09BC, 0x09C4, "bengali"),
+    (0x09C7, 0x09C8, "bengali"),
+    (0x09CB, 0x09CE, "bengali"),
+    (0x09D7, 0x09D7, "bengali"),
+    (0x09DC, 0x09DD, "bengali"),
+    (0x09DF, 0x09E3, "bengali"),
+    (0x09E6, 0x09FE, "bengali"),
+    (0x0A01, 0x0A03, "gurmukhi"),
+    (0x0A05, 0x0A0A, "gurmukhi"),
+    (0x0A0F, 0x0A10, "gurmukhi"),
+    (0x0A13, 0x0A28, "gurmukhi"),
+    (0x0A2A, 0x0A30, "gurmukhi"),
+    (0x0A32, 0x0A33, "gurmukhi"),
+    (0x0A35, 0x0A36, "gurmukhi"),
+    (0x0A38, 0x0A39, "gurmukhi"),
+    (0x0A3C, 0x0A3C, "gurmukhi"),
+    (0x0A3E, 0x0A42, "gurmukhi"),
+    (0x0A47, 0x0A48, "gurmukhi"),
+    (0x0A4B, 0x0A4D, "gurmukhi"),
+    (0x0A51, 0x0A51, "gurmukhi"),
+    (0x0A59, 0x0A5C, "gurmukhi"),
+    (0x0A5E, 0x0A5E, "gurmukhi"),
+    (0x0A66, 0x0A76, "gurmukhi"),
+    (0x0A81, 0x0A83, "gujarati"),
+    (0x0A85, 0x0A8D, "gujarati"),
+    (0x0A8F, 0x0A91, "gujarati"),
+    (0x0A93, 0x0AA8, "gujarati"),
+    (0x0AAA, 0x0AB0, "gujarati"),
+    (0x0AB2, 0x0AB3, "gujarati"),
+    (0x0AB5, 0x0AB9, "gujarati"),
+    (0x0ABC, 0x0AC5, "gujarati"),
+    (0x0AC7, 0x0AC9, "gujarati"),
+    (0x0ACB, 0x0ACD, "gujarati"),
+    (0x0AD0, 0x0AD0, "gujarati"),
+    (0x0AE0, 0x0AE3, "gujarati"),
+    (0x0AE6, 0x0AF1, "gujarati"),
+    (0x0AF9, 0x0AFF, "gujarati"),
+    (0x0B01, 0x0B03, "oriya"),
+    (0x0B05, 0x0B0C, "oriya"),
+    (0x0B0F, 0x0B10, "oriya"),
+    (0x0B13, 0x0B28, "oriya"),
+    (0x0B2A, 0x0B30, "oriya"),
+    (0x0B32, 0x0B33, "oriya"),
+    (0x0B35, 0x0B39, "oriya"),
+    (0x0B3C, 0x0B44, "oriya"),
+    (0x0B47, 0x0B48, "oriya"),
+    (0x0B4B, 0x0B4D, "oriya"),
+    (0x0B55, 0x0B57, "oriya"),
+    (0x0B5C, 0x0B5D, "oriya"),
+    (0x0B5F, 0x0B63, "oriya"),
+    (0x0B66, 0x0B77, "oriya"),
+    (0x0B82, 0x0B83, "tamil"),
+    (0x0B85, 0x0B8A, "tamil"),
+    (0x0B8E, 0x0B90, "tamil"),
+    (0x0B92, 0x0B95, "tamil"),
+    (0x0B99, 0x0B9A, "tamil"),
+    (0x0B9C, 0x0B9C, "tamil"),
+    (0x0B9E, 0x0B9F, "tamil"),
+    (0x0BA3, 0x0BA4, "tamil"),
+    (0x0BA8, 0x0BAA, "tamil"),
+    (0x0BAE, 0x0BB9, "tamil"),
+    (0x0BBE, 0x0BC2, "tamil"),
+    (0x0BC6, 0x0BC8, "tamil"),
+    (0x0BCA, 0x0BCD, "tamil"),
+    (0x0BD0, 0x0BD0, "tamil"),
+    (0x0BD7, 0x0BD7, "tamil"),
+    (0x0BE6, 0x0BFA, "tamil"),
+    (0x0C00, 0x0C0C, "telugu"),
+    (0x0C0E, 0x0C10, "telugu"),
+    (0x0C12, 0x0C28, "telugu"),
+    (0x0C2A, 0x0C39, "telugu"),
+    (0x0C3C, 0x0C44, "telugu"),
+    (0x0C46, 0x0C48, "telugu"),
+    (0x0C4A, 0x0C4D, "telugu"),
+    (0x0C55, 0x0C56, "telugu"),
+    (0x0C58, 0x0C5A, "telugu"),
+    (0x0C5D, 0x0C5D, "telugu"),
+    (0x0C60, 0x0C63, "telugu"),
+    (0x0C66, 0x0C6F, "telugu"),
+    (0x0C77, 0x0C7F, "telugu"),
+    (0x0C80, 0x0C8C, "kannada"),
+    (0x0C8E, 0x0C90, "kannada"),
+    (0x0C92, 0x0CA8, "kannada"),
+    (0x0CAA, 0x0CB3, "kannada"),
+    (0x0CB5, 0x0CB9, "kannada"),
+    (0x0CBC, 0x0CC4, "kannada"),
+    (0x0CC6, 0x0CC8, "kannada"),
+    (0x0CCA, 0x0CCD, "kannada"),
+    (0x0CD5, 0x0CD6, "kannada"),
+    (0x0CDD, 0x0CDE, "kannada"),
+    (0x0CE0, 0x0CE3, "kannada"),
+    (0x0CE6, 0x0CEF, "kannada"),
+    (0x0CF1, 0x0CF2, "kannada"),
+    (0x0D00, 0x0D0C, "malayalam"),
+    (0x0D0E, 0x0D10, "malayalam"),
+    (0x0D12, 0x0D44, "malayalam"),
+    (0x0D46, 0x0D48, "malayalam"),
+    (0x0D4A, 0x0D4F, "malayalam"),
+    (0x0D54, 0x0D63, "malayalam"),
+    (0x0D66, 0x0D7F, "malayalam"),
+    (0x0D81, 0x0D83, "sinhala"),
+    (0x0D85, 0x0D96, "sinhala"),
+    (0x0D9A, 0x0DB1, "sinhala"),
+    (0x0DB3, 0x0DBB, "sinhala"),
+    (0x0DBD, 0x0DBD, "sinhala"),
+    (0x0DC0, 0x0DC6, "sinhala"),
+    (0x0DCA, 0x0DCA, "sinhala"),
+    (0x0DCF, 0x0DD4, "sinhala"),
+    (0x0DD6, 0x0DD6, "sinhala"),
+    (0x0DD8, 0x0DDF, "sinhala"),
+    (0x0DE6, 0x0DEF, "sinhala"),
+    (0x0DF2, 0x0DF4, "sinhala"),
+    (0x0E01, 0x0E3A, "thai"),
+    (0x0E3F, 0x0E5B, "thai"),
+    (0x0E81, 0x0E82, "lao"),
+    (0x0E84, 0x0E84, "lao"),
+    (0x0E86, 0x0E8A, "lao"),
+    (0x0E8C, 0x0EA3, "lao"),
+    (0x0EA5, 0x0EA5, "lao"),
+    (0x0EA7, 0x0EBD, "lao"),
+    (0x0EC0, 0x0EC4, "lao"),
+    (0x0EC6, 0x0EC6, "lao"),
+    (0x0EC8, 0x0ECD, "lao"),
+    (0x0ED0, 0x0ED9, "lao"),
+    (0x0EDC, 0x0EDF, "lao"),
+    (0x0F00, 0x0F47, "tibetan"),
+    (0x0F49, 0x0F6C, "tibetan"),
+    (0x0F71, 0x0F97, "tibetan"),
+    (0x0F99, 0x0FBC, "tibetan"),
+    (0x0FBE, 0x0FCC, "tibetan"),
+    (0x0FCE, 0x0FD4, "tibetan"),
+    (0x0FD9, 0x0FDA, "tibetan"),
+    (0x1000, 0x109F, "myanmar"),
+    (0x10A0, 0x10C5, "georgian"),
+    (0x10C7, 0x10C7, "georgian"),
+    (0x10CD, 0x10CD, "georgian"),
+    (0x10D0, 0x10FB, "georgian"),
+    (0x10FD, 0x10FF, "georgian"),
+    (0x1100, 0x11FF, "korean"),
+    (0x1200, 0x1248, "ethiopic"),
+    (0x124A, 0x124D, "ethiopic"),
+    (0x1250, 0x1256, "ethiopic"),
+    (0x1258, 0x1258, "ethiopic"),
+    (0x125A, 0x125D, "ethiopic"),
+    (0x1260, 0x1288, "ethiopic"),
+    (0x128A, 0x128D, "ethiopic"),
+    (0x1290, 0x12B0, "ethiopic"),
+    (0x12B2, 0x12B5, "ethiopic"),
+    (0x12B8, 0x12BE, "ethiopic"),
+    (0x12C0, 0x12C0, "ethiopic"),
+    (0x12C2, 0x12C5, "ethiopic"),
+    (0x12C8, 0x12D6, "ethiopic"),
+    (0x12D8, 0x1310, "ethiopic"),
+    (0x1312, 0x1315, "ethiopic"),
+    (0x1318, 0x135A, "ethiopic"),
+    (0x135D, 0x137C, "ethiopic"),
+    (0x1380, 0x1399, "ethiopic"),
+    (0x13A0, 0x13F5, "cherokee"),
+    (0x13F8, 0x13FD, "cherokee"),
+    (0x1400, 0x167F, "canadian"),
+    (0x1680, 0x169C, "ogham"),
+    (0x16A0, 0x16F8, "runic"),
+    (0x1780, 0x17DD, "khmer"),
+    (0x17E0, 0x17E9, "khmer"),
+    (0x17F0, 0x17F9, "khmer"),
+    (0x1800, 0x1819, "mongolian"),
+    (0x1820, 0x1878, "mongolian"),
+    (0x1880, 0x18AA, "mongolian"),
+    (0x18B0, 0x18F5, "canadian"),
+    (0x19E0, 0x19FF, "khmer"),
+    (0x1B00, 0x1B4C, "balinese"),
+    (0x1B50, 0x1B7E, "balinese"),
+    (0x1B80, 0x1BBF, "sundanese"),
+    (0x1C80, 0x1C88, "cyrillic"),
+    (0x1C90, 0x1CBA, "georgian"),
+    (0x1CBD, 0x1CBF, "georgian"),
+    (0x1CC0, 0x1CC7, "sundanese"),
+    (0x1D00, 0x1D25, "latin"),
+    (0x1D26, 0x1D2A, "greek"),
+    (0x1D2B, 0x1D2B, "cyrillic"),
+    (0x1D62, 0x1D65, "latin"),
+    (0x1D66, 0x1D6A, "greek"),
+    (0x1D6B, 0x1D77, "latin"),
+    (0x1D79, 0x1D9A, "latin"),
+    (0x1E00, 0x1EFF, "latin"),
+    (0x1F00, 0x1F15, "greek"),
+    (0x1F18, 0x1F1D, "greek"),
+    (0x1F20, 0x1F45, "greek"),
+    (0x1F48, 0x1F4D, "greek"),
+    (0x1F50, 0x1F57, "greek"),
+    (0x1F59, 0x1F59, "greek"),
+    (0x1F5B, 0x1F5B, "greek"),
+    (0x1F5D, 0x1F5D, "greek"),
+    (0x1F5F, 0x1F7D, "greek"),
+    (0x1F80, 0x1FB4, "greek"),
+    (0x1FB6, 0x1FC4, "greek"),
+    (0x1FC6, 0x1FD3, "greek"),
+    (0x1FD6, 0x1FDB, "greek"),
+    (0x1FDD, 0x1FEF, "greek"),
+    (0x1FF2, 0x1FF4, "greek"),
+    (0x1FF6, 0x1FFE, "greek"),
+    (0x2090, 0x209C, "latin"),
+    (0x2184, 0x2184, "latin"),
+    (0x271D, 0x271D, "latin"),
+    (0x2C60, 0x2C7C, "latin"),
+    (0x2C7E, 0x2C7F, "latin"),
+    (0x2C80, 0x2CF3, "coptic"),
+    (0x2CF9, 0x2CFF, "coptic"),
+    (0x2D00, 0x2D25, "georgian"),
+    (0x2D27, 0x2D27, "georgian"),
+    (0x2D2D, 0x2D2D, "georgian"),
+    (0x2D30, 0x2D67, "tifinagh"),
+    (0x2D6F, 0x2D70, "tifinagh"),
+    (0x2D7F, 0x2D7F, "tifinagh"),
+    (0x2D80, 0x2D96, "ethiopic"),
+    (0x2DA0, 0x2DA6, "ethiopic"),
+    (0x2DA8, 0x2DAE, "ethiopic"),
+    (0x2DB0, 0x2DB6, "ethiopic"),
+    (0x2DB8, 0x2DBE, "ethiopic"),
+    (0x2DC0, 0x2DC6, "ethiopic"),
+    (0x2DC8, 0x2DCE, "ethiopic"),
+    (0x2DD0, 0x2DD6, "ethiopic"),
+    (0x2DD8, 0x2DDE, "ethiopic"),
+    (0x2E80, 0x2E99, "han"),
+    (0x2E9B, 0x2EF3, "han"),
+    (0x302E, 0x302F, "korean"),
+    (0x3041, 0x3096, "hiragana"),
+    (0x309D, 0x309F, "hiragana"),
+    (0x30A1, 0x30FB, "katakana"),
+    (0x30FD, 0x30FF, "katakana"),
+    (0x3105, 0x312F, "bopomofo"),
+    (0x3131, 0x318E, "korean"),
+    (0x31A0, 0x31BF, "bopomofo"),
+    (0x31C0, 0x31E3, "han"),
+    (0x31F0, 0x31FF, "katakana"),
+    (0x3400, 0x4DBF, "han"),
+    (0x4E00, 0x9FFF, "han"),
+    (0xA000, 0xA48C, "yi"),
+    (0xA490, 0xA4C6, "yi"),
+    (0xA640, 0xA66E, "cyrillic"),
+    (0xA67E, 0xA69B, "cyrillic"),
+    (0xA722, 0xA76F, "latin"),
+    (0xA771, 0xA787, "latin"),
+    (0xA78B, 0xA7CA, "latin"),
+    (0xA7D0, 0xA7D1, "latin"),
+    (0xA7D3, 0xA7D3, "latin"),
+    (0xA7D5, 0xA7D9, "latin"),
+    (0xA7F5, 0xA7F7, "latin"),
+    (0xA7FA, 0xA7FF, "latin"),
+    (0xA8F2, 0xA8FF, "devanagari"),
+    (0xA960, 0xA97C, "korean"),
+    (0xA980, 0xA9CD, "javanese"),
+    (0xA9CF, 0xA9D9, "javanese"),
+    (0xA9DE, 0xA9DF, "javanese"),
+    (0xA9E0, 0xA9FE, "myanmar"),
+    (0xAA60, 0xAA7F, "myanmar"),
+    (0xAB01, 0xAB06, "ethiopic"),
+    (0xAB09, 0xAB0E, "ethiopic"),
+    (0xAB11, 0xAB16, "ethiopic"),
+    (0xAB20, 0xAB26, "ethiopic"),
+    (0xAB28, 0xAB2E, "ethiopic"),
+    (0xAB30, 0xAB5A, "latin"),
+    (0xAB60, 0xAB64, "latin"),
+    (0xAB65, 0xAB65, "greek"),
+    (0xAB66, 0xAB68, "latin"),
+    (0xAB70, 0xABBF, "cherokee"),
+    (0xAC00, 0xD7A3, "korean"),
+    (0xD7B0, 0xD7C6, "korean"),
+    (0xD7CB, 0xD7FB, "korean"),
+    (0xF900, 0xFA6D, "han"),
+    (0xFA70, 0xFAD9, "han"),
+    (0xFB00, 0xFB06, "latin"),
+    (0xFB13, 0xFB17, "armenian"),
+    (0xFB1D, 0xFB36, "hebrew"),
+    (0xFB38, 0xFB3C, "hebrew"),
+    (0xFB3E, 0xFB3E, "hebrew"),
+    (0xFB40, 0xFB41, "hebrew"),
+    (0xFB43, 0xFB44, "hebrew"),
+    (0xFB46, 0xFB4F, "hebrew"),
+    (0xFB50, 0xFBC2, "arabic"),
+    (0xFBD3, 0xFD3D, "arabic"),
+    (0xFD40, 0xFD8F, "arabic"),
+    (0xFD92, 0xFDC7, "arabic"),
+    (0xFDCF, 0xFDCF, "arabic"),
+    (0xFDF0, 0xFDFB, "arabic"),
+    (0xFDFD, 0xFDFF, "arabic"),
+    (0xFE70, 0xFE74, "arabic"),
+    (0xFE76, 0xFEFC, "arabic"),
+    (0x10140, 0x1018D, "greek"),
+    (0x101A0, 0x101A0, "greek"),
+    (0x102E0, 0x102FB, "coptic"),
+    (0x111E1, 0x111F4, "sinhala"),
+    (0x11660, 0x1166C, "mongolian"),
+    (0x11AB0, 0x11ABF, "canadian"),
+    (0x11FC0, 0x11FF1, "tamil"),
+    (0x11FFF, 0x11FFF, "tamil"),
+    (0x1AFF0, 0x1AFF3, "katakana"),
+    (0x1AFF5, 0x1AFFB, "katakana"),
+    (0x1AFFD, 0x1AFFE, "katakana"),
+    (0x1B000, 0x1B000, "katakana"),
+    (0x1B001, 0x1B001, "hiragana"),
+    (0x1B11F, 0x1B11F, "hiragana"),
+    (0x1B120, 0x1B122, "katakana"),
+    (0x1B150, 0x1B152, "hiragana"),
+    (0x1B164, 0x1B167, "katakana"),
+    (0x1D200, 0x1D241, "greek"),
+    (0x1D245, 0x1D245, "greek"),
+    (0x1DF00, 0x1DF1E, "latin"),
+    (0x1E7E0, 0x1E7E6, "ethiopic"),
+    (0x1E7E8, 0x1E7EB, "ethiopic"),
+    (0x1E7ED, 0x1E7EE, "ethiopic"),
+    (0x1E7F0, 0x1E7FE, "ethiopic"),
+    (0x1EE00, 0x1EE03, "arabic"),
+    (0x1EE05, 0x1EE1F, "arabic"),
+    (0x1EE21, 0x1EE22, "arabic"),
+    (0x1EE24, 0x1EE24, "arabic"),
+    (0x1EE27, 0x1EE27, "arabic"),
+    (0x1EE29, 0x1EE32, "arabic"),
+    (0x1EE34, 0x1EE37, "arabic"),
+    (0x1EE39, 0x1EE39, "arabic"),
+    (0x1EE3B, 0x1EE3B, "arabic"),
+    (0x1EE42, 0x1EE42, "arabic"),
+    (0x1EE47, 0x1EE47, "arabic"),
+    (0x1EE49, 0x1EE49, "arabic"),
+    (0x1EE4B, 0x1EE4B, "arabic"),
+    (0x1EE4D, 0x1EE4F, "arabic"),
+    (0x1EE51, 0x1EE52, "arabic"),
+    (0x1EE54, 0x1EE54, "arabic"),
+    (0x1EE57, 0x1EE57, "arabic"),
+    (0x1EE59, 0x1EE59, "arabic"),
+    (0x1EE5B, 0x1EE5B, "arabic"),
+    (0x1EE5D, 0x1EE5D, "arabic"),
+    (0x1EE5F, 0x1EE5F, "arabic"),
+    (0x1EE61, 0x1EE62, "arabic"),
+    (0x1EE64, 0x1EE64, "arabic"),
+    (0x1EE67, 0x1EE6A, "arabic"),
+    (0x1EE6C, 0x1EE72, "arabic"),
+    (0x1EE74, 0x1EE77, "arabic"),
+    (0x1EE79, 0x1EE7C, "arabic"),
+    (0x1EE7E, 0x1EE7E, "arabic"),
+    (0x1EE80, 0x1EE89, "arabic"),
+    (0x1EE8B, 0x1EE9B, "arabic"),
+    (0x1EEA1, 0x1EEA3, "arabic"),
+    (0x1EEA5, 0x1EEA9, "arabic"),
+    (0x1EEAB, 0x1EEBB, "arabic"),
+    (0x1EEF0, 0x1EEF1, "arabic"),
+    (0x20000, 0x2A6DF, "han"),
+    (0x2A700, 0x2B738, "han"),
+    (0x2B740, 0x2B81D, "han"),
+    (0x2B820, 0x2CEA1, "han"),
+    (0x2CEB0, 0x2EBE0, "han"),
+    (0x2F800, 0x2FA1D, "han"),
+    (0x30000, 0x3134A, "han"),
+];
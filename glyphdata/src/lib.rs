@@ -0,0 +1,423 @@
+//! A database of glyph information, in the manner of Glyphs' GlyphData and
+//! the Adobe Glyph List For New Fonts.
+//!
+//! Given a glyph name, this determines the glyph's category, subcategory,
+//! script, Unicode code point and production name. Names which are not in
+//! the database are understood if they are `uniXXXX`/`uXXXXX` names, variants
+//! of known glyphs with a `.suffix`, or ligatures of known glyphs joined by
+//! underscores (`f_f_i`).
+//!
+//! Latin, Greek and Cyrillic glyphs and common symbols have curated names.
+//! Hebrew, Arabic, Indic, Thai and Lao glyphs are named after their Unicode
+//! character names with Glyphs' script suffixes (`alef-ar`, `kaf-hb`,
+//! `aaMatra-deva`), which match Glyphs' own names for most, but not all,
+//! of them. Other scripts are only known by `uniXXXX` names.
+#![warn(missing_docs, rustdoc::missing_crate_level_docs)]
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+mod data;
+
+use data::{CATEGORIES, GLYPHS, SCRIPTS};
+
+/// The general category of a glyph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    /// Letters
+    Letter,
+    /// Combining marks
+    Mark,
+    /// Digits and other numbers
+    Number,
+    /// Punctuation
+    Punctuation,
+    /// Symbols
+    Symbol,
+    /// Spaces and other separators
+    Separator,
+    /// Control characters and everything else
+    Other,
+}
+
+/// A more specific category of a glyph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subcategory {
+    /// An uppercase letter
+    Uppercase,
+    /// A lowercase letter
+    Lowercase,
+    /// A small capital letter
+    Smallcaps,
+    /// A modifier letter or symbol
+    Modifier,
+    /// A ligature of several glyphs
+    Ligature,
+    /// A mark which takes up no space
+    Nonspacing,
+    /// A combining mark which takes up space
+    SpacingCombining,
+    /// A mark which encloses its base
+    Enclosing,
+    /// A decimal digit
+    DecimalDigit,
+    /// A number made from letters
+    Letter,
+    /// Connecting punctuation
+    Connector,
+    /// A dash
+    Dash,
+    /// A parenthesis or bracket
+    Parenthesis,
+    /// A quotation mark
+    Quote,
+    /// A mathematical symbol
+    Math,
+    /// A currency symbol
+    Currency,
+    /// A space
+    Space,
+    /// A format control character
+    Format,
+}
+
+/// Information about a glyph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlyphInfo {
+    /// The glyph's name
+    pub name: String,
+    /// The name the glyph should have in a compiled font
+    pub production_name: String,
+    /// The code point the glyph represents, if it represents one
+    pub unicode: Option<u32>,
+    /// The glyph's category
+    pub category: Category,
+    /// The glyph's subcategory
+    pub subcategory: Option<Subcategory>,
+    /// The script the glyph belongs to, if any
+    pub script: Option<&'static str>,
+}
+
+impl GlyphInfo {
+    /// Whether the glyph is a combining mark, which would be a mark glyph in
+    /// OpenType layout
+    pub fn is_mark(&self) -> bool {
+        self.category == Category::Mark && self.subcategory != Some(Subcategory::SpacingCombining)
+    }
+
+    /// Whether the glyph is a ligature
+    pub fn is_ligature(&self) -> bool {
+        self.subcategory == Some(Subcategory::Ligature)
+    }
+}
+
+lazy_static! {
+    static ref BY_CODEPOINT: HashMap<u32, usize> = {
+        let mut map = HashMap::new();
+        for (ix, record) in GLYPHS.iter().enumerate() {
+            map.entry(record.1).or_insert(ix);
+        }
+        map
+    };
+}
+
+/// Suffixes of small capital variants
+const SMALLCAPS_SUFFIXES: [&str; 3] = ["sc", "smcp", "c2sc"];
+
+/// Looks up information about a glyph by its name.
+///
+/// Returns `None` if the name is not known and cannot be understood as a
+/// `uniXXXX` name, a variant or a ligature of known glyphs.
+pub fn glyph_info(name: &str) -> Option<GlyphInfo> {
+    if name.is_empty() || name.starts_with('.') || name.starts_with('_') {
+        return None;
+    }
+    if let Ok(ix) = GLYPHS.binary_search_by(|record| record.0.cmp(name)) {
+        return Some(record_info(ix));
+    }
+    if let Some((base, suffix)) = name.split_once('.') {
+        return glyph_info(base).map(|info| variant_info(info, name, suffix));
+    }
+    if name.contains('_') {
+        let parts: Option<Vec<GlyphInfo>> = name.split('_').map(glyph_info).collect();
+        return parts.and_then(|parts| ligature_info(name, parts));
+    }
+    let codepoints = parse_uni_name(name)?;
+    if codepoints.len() == 1 {
+        let mut info = info_for_codepoint(codepoints[0])?;
+        info.name = name.to_string();
+        Some(info)
+    } else {
+        let parts: Option<Vec<GlyphInfo>> =
+            codepoints.into_iter().map(info_for_codepoint).collect();
+        ligature_info(name, parts?)
+    }
+}
+
+/// Looks up information about the glyph for a code point.
+///
+/// Code points without a name in the database get a `uniXXXX` name. Returns
+/// `None` for code points which are not assigned.
+pub fn info_for_codepoint(codepoint: u32) -> Option<GlyphInfo> {
+    if let Some(&ix) = BY_CODEPOINT.get(&codepoint) {
+        return Some(record_info(ix));
+    }
+    let (category, subcategory) = category_of(codepoint)?;
+    Some(GlyphInfo {
+        name: uni_name(codepoint),
+        production_name: uni_name(codepoint),
+        unicode: Some(codepoint),
+        category,
+        subcategory,
+        script: script_of(codepoint),
+    })
+}
+
+/// The production name of a glyph, or its own name if it is not known.
+pub fn production_name(name: &str) -> String {
+    glyph_info(name)
+        .map(|info| info.production_name)
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// The `uniXXXX` (or for code points outside the BMP, `uXXXXX`) name of a
+/// code point.
+pub fn uni_name(codepoint: u32) -> String {
+    if codepoint > 0xFFFF {
+        format!("u{:04X}", codepoint)
+    } else {
+        format!("uni{:04X}", codepoint)
+    }
+}
+
+fn record_info(ix: usize) -> GlyphInfo {
+    let (name, codepoint, production_name, category, subcategory) = GLYPHS[ix];
+    GlyphInfo {
+        name: name.to_string(),
+        production_name: production_name.to_string(),
+        unicode: Some(codepoint),
+        category,
+        subcategory,
+        script: script_of(codepoint),
+    }
+}
+
+fn variant_info(base: GlyphInfo, name: &str, suffix: &str) -> GlyphInfo {
+    let smallcaps = base.category == Category::Letter
+        && suffix.split('.').any(|s| SMALLCAPS_SUFFIXES.contains(&s));
+    GlyphInfo {
+        name: name.to_string(),
+        production_name: format!("{}.{}", base.production_name, suffix),
+        unicode: None,
+        subcategory: if smallcaps {
+            Some(Subcategory::Smallcaps)
+        } else {
+            base.subcategory
+        },
+        ..base
+    }
+}
+
+/// Ligatures take the category of their first part. Their production name
+/// joins those of their parts, as a single `uniXXXXYYYY` name if possible.
+fn ligature_info(name: &str, parts: Vec<GlyphInfo>) -> Option<GlyphInfo> {
+    let first = parts.first()?;
+    let uni_parts: Option<Vec<&str>> = parts
+        .iter()
+        .map(|p| {
+            p.production_name
+                .strip_prefix("uni")
+                .filter(|hex| hex.len() == 4)
+        })
+        .collect();
+    let production_name = match uni_parts {
+        Some(hex) => format!("uni{}", hex.concat()),
+        None => parts
+            .iter()
+            .map(|p| p.production_name.as_str())
+            .collect::<Vec<&str>>()
+            .join("_"),
+    };
+    Some(GlyphInfo {
+        name: name.to_string(),
+        production_name,
+        unicode: None,
+        category: first.category,
+        subcategory: Some(Subcategory::Ligature),
+        script: parts.iter().find_map(|p| p.script),
+    })
+}
+
+/// Parses `uniXXXX` (with one or more code points) and `uXXXX[XX]` names.
+fn parse_uni_name(name: &str) -> Option<Vec<u32>> {
+    let is_hex = |s: &str| {
+        s.chars()
+            .all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c))
+    };
+    if let Some(hex) = name.strip_prefix("uni") {
+        if hex.is_empty() || hex.len() % 4 != 0 || !is_hex(hex) {
+            return None;
+        }
+        let codepoints: Vec<u32> = (0..hex.len())
+            .step_by(4)
+            .filter_map(|i| u32::from_str_radix(&hex[i..i + 4], 16).ok())
+            .collect();
+        if codepoints.iter().any(|cp| (0xD800..=0xDFFF).contains(cp)) {
+            return None;
+        }
+        return Some(codepoints);
+    }
+    let hex = name.strip_prefix('u')?;
+    if !(4..=6).contains(&hex.len()) || !is_hex(hex) {
+        return None;
+    }
+    let codepoint = u32::from_str_radix(hex, 16).ok()?;
+    if codepoint > 0x10FFFF || (0xD800..=0xDFFF).contains(&codepoint) {
+        return None;
+    }
+    Some(vec![codepoint])
+}
+
+fn category_of(codepoint: u32) -> Option<(Category, Option<Subcategory>)> {
+    let ix = CATEGORIES
+        .binary_search_by(|&(start, end, _, _)| {
+            if end < codepoint {
+                std::cmp::Ordering::Less
+            } else if start > codepoint {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+    let (_, _, category, subcategory) = CATEGORIES[ix];
+    Some((category, subcategory))
+}
+
+fn script_of(codepoint: u32) -> Option<&'static str> {
+    let ix = SCRIPTS
+        .binary_search_by(|&(start, end, _)| {
+            if end < codepoint {
+                std::cmp::Ordering::Less
+            } else if start > codepoint {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+    Some(SCRIPTS[ix].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_glyphs() {
+        let a = glyph_info("A").unwrap();
+        assert_eq!(a.unicode, Some(0x41));
+        assert_eq!(a.category, Category::Letter);
+        assert_eq!(a.subcategory, Some(Subcategory::Uppercase));
+        assert_eq!(a.script, Some("latin"));
+        assert_eq!(a.production_name, "A");
+
+        let acutecomb = glyph_info("acutecomb").unwrap();
+        assert!(acutecomb.is_mark());
+        assert_eq!(glyph_info("caroncomb").unwrap().production_name, "uni030C");
+        assert_eq!(
+            glyph_info("guillemetleft").unwrap().production_name,
+            "guillemotleft"
+        );
+
+        let zhe = glyph_info("zhe-cy").unwrap();
+        assert_eq!(zhe.unicode, Some(0x0436));
+        assert_eq!(zhe.script, Some("cyrillic"));
+        assert_eq!(zhe.production_name, "uni0436");
+
+        assert_eq!(
+            glyph_info("fi").unwrap().subcategory,
+            Some(Subcategory::Ligature)
+        );
+        assert_eq!(glyph_info(".notdef"), None);
+        assert_eq!(glyph_info("nonsense"), None);
+    }
+
+    #[test]
+    fn test_uni_names() {
+        let info = glyph_info("uni0301").unwrap();
+        assert!(info.is_mark());
+        assert_eq!(info.production_name, "acutecomb");
+
+        let info = glyph_info("uni0915").unwrap();
+        assert_eq!(info.category, Category::Letter);
+        assert_eq!(info.script, Some("devanagari"));
+        assert_eq!(info.production_name, "uni0915");
+
+        let info = glyph_info("u1F600").unwrap();
+        assert_eq!(info.category, Category::Symbol);
+        assert_eq!(info.production_name, "u1F600");
+
+        assert!(glyph_info("uni0041004C").unwrap().is_ligature());
+        assert_eq!(glyph_info("uniD800"), None);
+        assert_eq!(glyph_info("uni00e9"), None);
+        assert_eq!(info_for_codepoint(0x00E9).unwrap().name, "eacute");
+    }
+
+    #[test]
+    fn test_variants_and_ligatures() {
+        let info = glyph_info("a.sc").unwrap();
+        assert_eq!(info.subcategory, Some(Subcategory::Smallcaps));
+        assert_eq!(info.unicode, None);
+        assert_eq!(info.production_name, "a.sc");
+
+        let info = glyph_info("a-cy.ss01").unwrap();
+        assert_eq!(info.production_name, "uni0430.ss01");
+        assert_eq!(info.subcategory, Some(Subcategory::Lowercase));
+
+        let info = glyph_info("f_f_i").unwrap();
+        assert!(info.is_ligature());
+        assert_eq!(info.category, Category::Letter);
+        assert_eq!(info.production_name, "f_f_i");
+
+        let info = glyph_info("be-cy_ve-cy.alt").unwrap();
+        assert!(info.is_ligature());
+        assert_eq!(info.production_name, "uni04310432.alt");
+
+        assert_eq!(glyph_info("f_nonsense"), None);
+        assert_eq!(production_name("nonsense.alt"), "nonsense.alt");
+    }
+
+    #[test]
+    fn test_script_suffixed_names() {
+        let alef = glyph_info("alef-ar").unwrap();
+        assert_eq!(alef.unicode, Some(0x0627));
+        assert_eq!(alef.script, Some("arabic"));
+        assert_eq!(alef.production_name, "uni0627");
+        assert!(glyph_info("fatha-ar").unwrap().is_mark());
+        assert!(glyph_info("sheva-hb").unwrap().is_mark());
+        assert_eq!(glyph_info("kaf-hb").unwrap().category, Category::Letter);
+        assert_eq!(glyph_info("ka-deva").unwrap().unicode, Some(0x0915));
+        assert!(glyph_info("virama-deva").unwrap().is_mark());
+        // Spacing vowel signs are not marks in OpenType layout
+        let matra = glyph_info("aaMatra-deva").unwrap();
+        assert_eq!(matra.category, Category::Mark);
+        assert!(!matra.is_mark());
+        assert_eq!(glyph_info("one-ar").unwrap().unicode, Some(0x0661));
+        assert_eq!(info_for_codepoint(0x0E01).unwrap().name, "koKai-thai");
+        assert_eq!(
+            glyph_info("lam-ar_alef-ar").unwrap().production_name,
+            "uni06440627"
+        );
+    }
+
+    #[test]
+    fn test_unassigned_codepoints() {
+        assert_eq!(info_for_codepoint(0x2065), None);
+        assert_eq!(info_for_codepoint(0x1F7FF), None);
+        assert_eq!(glyph_info("uni0378"), None);
+        assert_eq!(
+            info_for_codepoint(0x2066).unwrap().subcategory,
+            Some(Subcategory::Format)
+        );
+    }
+}
@@ -1,15 +1,19 @@
+use indexmap::IndexMap;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write as _; // import without risk of name clashing
 
 /// An enum representing a property list.
+///
+/// Dictionaries keep their keys in the order they were read or inserted,
+/// and are written back out in that order.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Plist {
-    Dictionary(HashMap<String, Plist>),
+    Dictionary(IndexMap<String, Plist>),
     Array(Vec<Plist>),
     String(String),
     Binary(Vec<u8>),
-    Node((i64, i64, String)),
+    Node((f64, f64, String)),
     Integer(i64),
     Float(f64),
 }
@@ -83,7 +87,11 @@ fn skip_ws(s: &str, mut ix: usize) -> usize {
 
 fn escape_string(buf: &mut String, s: &str) {
     buf.reserve(s.len());
-    if !s.is_empty() && s.as_bytes().iter().all(|&b| is_alnum_strict(b)) {
+    // Strings which look like numbers must be quoted to be read back as strings
+    if !s.is_empty()
+        && s.as_bytes().iter().all(|&b| is_alnum_strict(b))
+        && !matches!(Plist::parse_atom(s), Plist::Integer(_) | Plist::Float(_))
+    {
         buf.push_str(s);
     } else {
         buf.push('"');
@@ -97,6 +105,11 @@ fn escape_string(buf: &mut String, s: &str) {
                     buf.push('\\');
                     start = ix;
                 }
+                b'\n' => {
+                    buf.push_str(&s[start..ix]);
+                    buf.push_str("\\012");
+                    start = ix + 1;
+                }
                 _ => (),
             }
             ix += 1;
@@ -114,7 +127,7 @@ impl Plist {
     }
 
     #[allow(unused)]
-    pub fn as_dict(&self) -> Option<&HashMap<String, Plist>> {
+    pub fn as_dict(&self) -> Option<&IndexMap<String, Plist>> {
         match self {
             Plist::Dictionary(d) => Some(d),
             _ => None,
@@ -138,7 +151,7 @@ impl Plist {
     }
 
     #[allow(unused)]
-    pub fn as_node(&self) -> Option<&(i64, i64, String)> {
+    pub fn as_node(&self) -> Option<&(f64, f64, String)> {
         match self {
             Plist::Node(a) => Some(a),
             _ => None,
@@ -198,7 +211,7 @@ impl Plist {
 
     pub fn into_hashmap(self) -> HashMap<String, Plist> {
         match self {
-            Plist::Dictionary(d) => d,
+            Plist::Dictionary(d) => d.into_iter().collect(),
             _ => panic!("expected dictionary"),
         }
     }
@@ -208,10 +221,8 @@ impl Plist {
         let y;
         let t;
         if let Ok((Token::Atom(s), next_ix)) = Token::lex(s, ix) {
-            if let Plist::Integer(t1) = Plist::parse_atom(s) {
+            if let Some(t1) = Plist::parse_atom(s).as_f64() {
                 x = t1;
-            } else if let Plist::Float(t1) = Plist::parse_atom(s) {
-                x = t1 as i64;
             } else {
                 return None;
             }
@@ -226,10 +237,8 @@ impl Plist {
         }
 
         if let Ok((Token::Atom(s), next_ix)) = Token::lex(s, ix) {
-            if let Plist::Integer(t2) = Plist::parse_atom(s) {
+            if let Some(t2) = Plist::parse_atom(s).as_f64() {
                 y = t2;
-            } else if let Plist::Float(t2) = Plist::parse_atom(s) {
-                y = t2 as i64;
             } else {
                 return None;
             }
@@ -265,7 +274,7 @@ impl Plist {
             Token::String(s) => Ok((Plist::String(s.into()), ix)),
             Token::Binary(s) => Ok((Plist::Binary(s), ix)),
             Token::OpenBrace => {
-                let mut dict = HashMap::new();
+                let mut dict = IndexMap::new();
                 loop {
                     if let Some(ix) = Token::expect(s, ix, b'}') {
                         return Ok((Plist::Dictionary(dict), ix));
@@ -328,33 +337,45 @@ impl Plist {
 
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
+        self.to_string_with_inline_keys(&[])
+    }
+
+    /// Serializes the property list, writing arrays stored under any of the
+    /// given dictionary keys on a single line, as Glyphs does for points.
+    pub fn to_string_with_inline_keys(&self, inline_keys: &[&str]) -> String {
         let mut s = String::new();
-        self.push_to_string(&mut s);
+        self.push_to_string(&mut s, inline_keys, false);
         s
     }
 
-    fn push_to_string(&self, s: &mut String) {
+    fn push_to_string(&self, s: &mut String, inline_keys: &[&str], inline: bool) {
         match self {
+            Plist::Array(a) if inline => {
+                s.push('(');
+                let mut delim = "";
+                for el in a {
+                    s.push_str(delim);
+                    el.push_to_string(s, inline_keys, true);
+                    delim = ",";
+                }
+                s.push(')');
+            }
             Plist::Array(a) => {
                 s.push('(');
                 let mut delim = "\n";
                 for el in a {
                     s.push_str(delim);
-                    el.push_to_string(s);
+                    el.push_to_string(s, inline_keys, false);
                     delim = ",\n";
                 }
                 s.push_str("\n)");
             }
             Plist::Dictionary(a) => {
                 s.push_str("{\n");
-                let mut keys: Vec<_> = a.keys().collect();
-                keys.sort();
-                for k in keys {
-                    let el = &a[k];
-                    // TODO: quote if needed?
+                for (k, el) in a {
                     escape_string(s, k);
                     s.push_str(" = ");
-                    el.push_to_string(s);
+                    el.push_to_string(s, inline_keys, inline_keys.contains(&k.as_str()));
                     s.push_str(";\n");
                 }
                 s.push('}');
@@ -574,6 +595,12 @@ impl From<Vec<Plist>> for Plist {
 
 impl From<HashMap<String, Plist>> for Plist {
     fn from(x: HashMap<String, Plist>) -> Plist {
+        Plist::Dictionary(x.into_iter().collect())
+    }
+}
+
+impl From<IndexMap<String, Plist>> for Plist {
+    fn from(x: IndexMap<String, Plist>) -> Plist {
         Plist::Dictionary(x)
     }
 }
@@ -618,29 +645,29 @@ mod tests {
         let res = Plist::parse(&input).expect("Whatever");
         assert_eq!(
             res,
-            Plist::Dictionary(hashmap!("a".to_string() => Plist::String("x123".to_string())))
+            Plist::from(hashmap!("a".to_string() => Plist::String("x123".to_string())))
         );
 
         let t_e = vec![
             (
                 "{a=1;}",
-                Plist::Dictionary(hashmap!("a".to_string() => Plist::Integer(1))),
+                Plist::from(hashmap!("a".to_string() => Plist::Integer(1))),
             ),
             (
                 "{\"a\"=\"1\";}",
-                Plist::Dictionary(hashmap!("a".to_string() => Plist::String("1".to_string()))),
+                Plist::from(hashmap!("a".to_string() => Plist::String("1".to_string()))),
             ),
             // (
             //     "{'a'='1';}",
-            //     Plist::Dictionary(hashmap!("a".to_string() => Plist::String("1".to_string()))),
+            //     Plist::from(hashmap!("a".to_string() => Plist::String("1".to_string()))),
             // ),
             (
                 "{\na = 1;\n}",
-                Plist::Dictionary(hashmap!("a".to_string() => Plist::Integer(1))),
+                Plist::from(hashmap!("a".to_string() => Plist::Integer(1))),
             ),
             (
                 "{\na\n=\n1;\n}",
-                Plist::Dictionary(hashmap!("a".to_string() => Plist::Integer(1))),
+                Plist::from(hashmap!("a".to_string() => Plist::Integer(1))),
             ),
             // (
             //     "{a=1;b;}",
@@ -662,9 +689,22 @@ mod tests {
         let res = Plist::parse(&input).expect("Whatever");
         assert_eq!(
             res,
-            Plist::Dictionary(
+            Plist::from(
                 hashmap!("de.kutilek.scrawl.data".to_string() => Plist::Binary(vec![0x89, 0x50, 0x4e]))
             )
         );
     }
+
+    #[test]
+    fn test_roundtrip() {
+        let input = "{\nzebra = \"3109\";\napple = (\n(1.5,2,l),\n(3,4,o)\n);\npos = (10,-20);\nnote = \"a \\\"b\\\"\\012c\";\n}";
+        let res = Plist::parse(input).expect("Whatever");
+        assert_eq!(res.get("zebra"), Some(&Plist::String("3109".to_string())));
+        assert_eq!(
+            res.get("apple").and_then(|a| a.as_array()).unwrap()[0],
+            Plist::Node((1.5, 2.0, "l".to_string()))
+        );
+        assert_eq!(res.get("note").and_then(|n| n.as_str()), Some("a \"b\"\nc"));
+        assert_eq!(res.to_string_with_inline_keys(&["pos"]), input);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::layout::device::Device;
 use crate::types::*;
 use crate::{
     DeserializationError, Deserialize, Deserializer, ReaderContext, SerializationError, Serialize,
@@ -6,14 +7,16 @@ use crate::{
 
 // These things have to be serialized/deserialized by hand because of annoying
 // format switching things.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
 pub struct Anchor {
     pub xCoordinate: int16,
     pub yCoordinate: int16,
     pub anchorPoint: Option<uint16>,
-    // xDeviceOffset: Option<Offset16<Device>>,
-    // yDeviceOffset: Option<Offset16<Device>>,
+    // Device or VariationIndex tables, stored inline rather than as offsets
+    pub xDevice: Option<Device>,
+    pub yDevice: Option<Device>,
 }
 
 impl Anchor {
@@ -23,39 +26,89 @@ impl Anchor {
             xCoordinate: x,
             yCoordinate: y,
             anchorPoint: None,
+            xDevice: None,
+            yDevice: None,
         }
     }
 }
+
+fn device_at(
+    c: &mut ReaderContext,
+    start: usize,
+    offset: uint16,
+) -> Result<Option<Device>, DeserializationError> {
+    if offset == 0 {
+        return Ok(None);
+    }
+    let oldptr = c.ptr;
+    c.ptr = start + offset as usize;
+    let device: Device = c.de()?;
+    c.ptr = oldptr;
+    Ok(Some(device))
+}
+
 impl Deserialize for Anchor {
     #[allow(non_snake_case)]
     fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let start = c.ptr;
         let format: uint16 = c.de()?;
         let xCoordinate: int16 = c.de()?;
         let yCoordinate: int16 = c.de()?;
-        if format == 1 {
-            Ok(Anchor {
-                xCoordinate,
-                yCoordinate,
-                anchorPoint: None,
-            })
-        } else if format == 2 {
-            let anchorPoint: uint16 = c.de()?;
-            Ok(Anchor {
-                xCoordinate,
-                yCoordinate,
-                anchorPoint: Some(anchorPoint),
-            })
-        } else {
-            Err(DeserializationError(format!(
-                "Invalid anchor format {:}",
-                format
-            )))
+        let mut anchor = Anchor::new(xCoordinate, yCoordinate);
+        match format {
+            1 => {}
+            2 => {
+                let anchorPoint: uint16 = c.de()?;
+                anchor.anchorPoint = Some(anchorPoint);
+            }
+            3 => {
+                let x_device_offset: uint16 = c.de()?;
+                let y_device_offset: uint16 = c.de()?;
+                anchor.xDevice = device_at(c, start, x_device_offset)?;
+                anchor.yDevice = device_at(c, start, y_device_offset)?;
+            }
+            _ => {
+                return Err(DeserializationError(format!(
+                    "Invalid anchor format {:}",
+                    format
+                )))
+            }
         }
+        Ok(anchor)
     }
 }
 
 impl Serialize for Anchor {
     fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        if self.xDevice.is_some() || self.yDevice.is_some() {
+            let x_device = self
+                .xDevice
+                .as_ref()
+                .map(otspec::ser::to_bytes)
+                .transpose()?
+                .unwrap_or_default();
+            let y_device = self
+                .yDevice
+                .as_ref()
+                .map(otspec::ser::to_bytes)
+                .transpose()?
+                .unwrap_or_default();
+            // Device tables follow the 10-byte format 3 header
+            let x_offset: uint16 = if x_device.is_empty() { 0 } else { 10 };
+            let y_offset: uint16 = if y_device.is_empty() {
+                0
+            } else {
+                10 + x_device.len() as uint16
+            };
+            data.put(3_u16)?;
+            data.put(self.xCoordinate)?;
+            data.put(self.yCoordinate)?;
+            data.put(x_offset)?;
+            data.put(y_offset)?;
+            data.extend(x_device);
+            data.extend(y_device);
+            return Ok(());
+        }
         let format: uint16 = if self.anchorPoint.is_some() { 2 } else { 1 };
         data.put(format)?;
         data.put(self.xCoordinate)?;
@@ -66,3 +119,23 @@ impl Serialize for Anchor {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anchor_format3_serde() {
+        let binary_anchor = vec![
+            0x00, 0x03, 0x00, 0x64, 0x01, 0xf4, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+            0x80, 0x00,
+        ];
+        let anchor = Anchor {
+            xDevice: Some(Device::variation_index(0, 2)),
+            ..Anchor::new(100, 500)
+        };
+        assert_eq!(otspec::ser::to_bytes(&anchor).unwrap(), binary_anchor);
+        let deserialized: Anchor = otspec::de::from_bytes(&binary_anchor).unwrap();
+        assert_eq!(deserialized, anchor);
+    }
+}
@@ -44,7 +44,7 @@ tables!(
             uint16	featureParamsOffset
             Counted(uint16) lookupListIndices
     }
-    cvFeatureParams {
+    cvFeatureParams [serde] {
         uint16 format
         uint16  featUiLabelNameId
         uint16  featUiTooltipTextNameId
@@ -54,7 +54,7 @@ tables!(
         // everything is horrible
         // Counted(uint24) character
     }
-    sizeFeatureParams {
+    sizeFeatureParams [serde] {
         uint16 designSize
         uint16 subfamilyIdentifier
         uint16 subfamilyNameID
@@ -73,15 +73,18 @@ tables!(
         Offset16(Anchor) markAnchor
     }
     FeatureVariations {
+        [offset_base]
         uint16 majorVersion
         uint16 minorVersion
+        [embed]
         Counted32(FeatureVariationRecord) featureVariationRecords
     }
-    FeatureVariationRecord {
+    FeatureVariationRecord [embedded] {
         Offset32(ConditionSet) conditionSet
         Offset32(FeatureTableSubstitution) featureTableSubstitution
     }
     ConditionSet {
+        [offset_base]
         CountedOffset32(ConditionFormat1) conditions
     }
     ConditionFormat1 {
@@ -91,11 +94,13 @@ tables!(
         F2DOT14 filterRangeMaxValue
     }
     FeatureTableSubstitution {
+        [offset_base]
         uint16 majorVersion
         uint16 minorVersion
+        [embed]
         Counted(FeatureTableSubstitutionRecord) substitutions
     }
-    FeatureTableSubstitutionRecord {
+    FeatureTableSubstitutionRecord [embedded] {
         uint16  featureIndex
         Offset32(FeatureTable) alternateFeature
     }
@@ -129,6 +134,7 @@ impl ScriptRecord {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Feature parameter data.
 ///
 /// Certain OpenType features may have various ancillary data attached to them.
@@ -151,6 +157,11 @@ pub enum FeatureParams {
 bitflags! {
     /// Lookup qualifiers
     #[derive(Serialize, Deserialize)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
     pub struct LookupFlags: u16 {
         /// Position the last glyph of a cursive positioning sequence on the baseline
         const RIGHT_TO_LEFT = 0x0001;
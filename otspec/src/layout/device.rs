@@ -7,6 +7,7 @@ use crate::{
 // These have to be serialized/deserialized by hand because of annoying
 // bit-packing things.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
 pub struct Device {
     pub startSize: uint16,
@@ -22,7 +23,7 @@ impl Deserialize for Device {
         let endSize: uint16 = c.de()?;
         let format: uint16 = c.de()?;
         let mut values: Vec<i8> = vec![];
-        if format != 0x8000 {
+        if format != VARIATION_INDEX_FORMAT {
             let mut count = endSize - startSize + 1;
             let num_bits = 1 << format;
             let minus_offset: i16 = 1 << num_bits;
@@ -53,7 +54,30 @@ impl Deserialize for Device {
     }
 }
 
+/// The delta format which marks a device table as a VariationIndex table
+pub const VARIATION_INDEX_FORMAT: uint16 = 0x8000;
+
 impl Device {
+    /// Creates a VariationIndex table, which points to a delta-set in the
+    /// GDEF item variation store. (It shares the layout of a Device table.)
+    pub fn variation_index(outer: uint16, inner: uint16) -> Device {
+        Device {
+            startSize: outer,
+            endSize: inner,
+            deltaFormat: Some(VARIATION_INDEX_FORMAT),
+            deltaValues: vec![],
+        }
+    }
+
+    /// If this is a VariationIndex table, returns its (outer, inner) delta-set index.
+    pub fn as_variation_index(&self) -> Option<(uint16, uint16)> {
+        if self.deltaFormat == Some(VARIATION_INDEX_FORMAT) {
+            Some((self.startSize, self.endSize))
+        } else {
+            None
+        }
+    }
+
     fn suggest_format(&self) -> uint16 {
         for &val in &self.deltaValues {
             if !(-9..=8).contains(&val) {
@@ -72,6 +96,9 @@ impl Serialize for Device {
         data.put(self.endSize)?;
         let format = self.deltaFormat.unwrap_or_else(|| self.suggest_format());
         data.put(format)?;
+        if format == VARIATION_INDEX_FORMAT {
+            return Ok(());
+        }
         // Horrible bit-packing time
         let num_bits = 1 << format;
         let mask: i16 = (1 << num_bits) - 1;
@@ -121,4 +148,14 @@ mod tests {
         let binary_device = vec![0x00, 0x0b, 0x00, 0x0f, 0x00, 0x01, 0xf5, 0x40];
         assert_eq!(otspec::ser::to_bytes(&device).unwrap(), binary_device);
     }
+
+    #[test]
+    fn variation_index_serde() {
+        let binary_device = vec![0x00, 0x01, 0x00, 0x05, 0x80, 0x00];
+        let device = Device::variation_index(1, 5);
+        assert_eq!(otspec::ser::to_bytes(&device).unwrap(), binary_device);
+        let deserialized: Device = otspec::de::from_bytes(&binary_device).unwrap();
+        assert_eq!(deserialized, device);
+        assert_eq!(deserialized.as_variation_index(), Some((1, 5)));
+    }
 }
@@ -23,6 +23,7 @@ pub struct SinglePosFormat2 {
     pub posFormat: uint16,
     pub coverage: Offset16<Coverage>,
     pub valueFormat: ValueRecordFlags,
+    #[otspec(embed)]
     #[otspec(with = "Counted")]
     pub valueRecords: Vec<ValueRecord>,
 }
@@ -22,12 +22,14 @@ pub struct PairPosFormat1 {
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
 pub struct PairSet {
     #[otspec(offset_base)]
+    #[otspec(embed)]
     #[otspec(with = "Counted")]
     pub pairValueRecords: Vec<PairValueRecord>,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize)]
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
+#[otspec(embedded)]
 pub struct PairValueRecord {
     pub secondGlyph: GlyphID,
     #[otspec(embed)]
@@ -59,6 +61,7 @@ pub struct Class1Record {
 
 #[derive(Debug, PartialEq, Clone, Serialize)]
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
+#[otspec(embedded)]
 pub struct Class2Record {
     #[otspec(embed)]
     pub valueRecord1: ValueRecord,
@@ -84,6 +87,8 @@ pub(crate) fn deserialize_gpos2(
                 coverage.as_ref().unwrap().glyphs.iter().zip(offsets.iter())
             {
                 c.ptr = c.top_of_table() + offset as usize;
+                // Device offsets in the value records are from the start of the pair set
+                c.push();
                 let pair_vr_count: uint16 = c.de()?;
                 let mut pair_value_records = vec![];
                 for _ in 0..pair_vr_count {
@@ -96,6 +101,7 @@ pub(crate) fn deserialize_gpos2(
                         valueRecord2: vr2,
                     })
                 }
+                c.pop();
                 pair_sets.push(Offset16::new(
                     offset,
                     PairSet {
@@ -158,6 +164,7 @@ mod tests {
         FeatureList, FeatureRecord, FeatureTable, LookupFlags, ScriptList, ScriptRecord,
     };
     use crate::layout::coverage::Coverage;
+    use crate::layout::device::Device;
     use crate::layout::valuerecord::{ValueRecord, ValueRecordFlags};
     use crate::offsetmanager::OffsetManager;
     use crate::tables::GPOS::{GPOSLookup, GPOSLookupList, GPOS10};
@@ -354,4 +361,42 @@ mod tests {
         mgr.dump_graph();
         assert_eq!(gpos_ser, binary_gpos);
     }
+
+    #[test]
+    fn test_gpos_21_device_serde() {
+        let pairpos1 = PairPosFormat1 {
+            posFormat: 1,
+            coverage: Offset16::to(Coverage { glyphs: vec![34] }),
+            valueFormat1: ValueRecordFlags::X_ADVANCE | ValueRecordFlags::X_ADVANCE_DEVICE,
+            valueFormat2: ValueRecordFlags::empty(),
+            pairSets: vec![Offset16::to(PairSet {
+                pairValueRecords: vec![PairValueRecord {
+                    secondGlyph: 35,
+                    valueRecord1: ValueRecord {
+                        xAdvance: Some(-20),
+                        xAdvDevice: Some(Offset16::to(Device::variation_index(0, 1))),
+                        ..ValueRecord::new()
+                    },
+                    valueRecord2: valuerecord!(),
+                }],
+            })]
+            .into(),
+        };
+        let binary_pairpos = vec![
+            0x00, 0x01, // format
+            0x00, 0x0c, // offset to coverage
+            0x00, 0x44, // ValueFormat1
+            0x00, 0x00, // ValueFormat2
+            0x00, 0x01, // pairSetCount
+            0x00, 0x12, // pairSetOffsets[0]
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x22, // coverage
+            0x00, 0x01, // pairValueCount
+            0x00, 0x23, // secondGlyph
+            0xff, 0xec, // xAdvance
+            0x00, 0x08, // xAdvDeviceOffset, from start of PairSet
+            0x00, 0x00, 0x00, 0x01, 0x80, 0x00, // VariationIndex
+        ];
+        let pairpos1_ser = otspec::ser::to_bytes(&pairpos1).unwrap();
+        assert_eq!(pairpos1_ser, binary_pairpos);
+    }
 }
@@ -75,7 +75,7 @@ impl Deserialize for MarkLigPosFormat1 {
 
         let ligature_array_offset: uint16 = c.de()?;
         let mut ligature_array: LigatureArray = LigatureArray::default();
-        c.follow_offset::<LigatureArray>(ligature_array_offset)?;
+        c.follow_offset::<LigatureArray>(ligature_array_offset.into())?;
         {
             // We are now at the start of the ligature array table
             c.push();
@@ -85,7 +85,7 @@ impl Deserialize for MarkLigPosFormat1 {
                 let mut component_records: Vec<ComponentRecord> = vec![];
 
                 let ligature_attach_offset: uint16 = c.de()?;
-                c.follow_offset::<LigatureAttach>(ligature_attach_offset)?;
+                c.follow_offset::<LigatureAttach>(ligature_attach_offset.into())?;
                 {
                     // We are now at the start of the ligature attach table
                     c.push();
@@ -215,19 +215,11 @@ mod tests {
                 markRecords: vec![
                     MarkRecord {
                         markClass: 0,
-                        markAnchor: Offset16::to(Anchor {
-                            xCoordinate: 346,
-                            yCoordinate: -98,
-                            anchorPoint: None,
-                        }),
+                        markAnchor: Offset16::to(Anchor::new(346, -98)),
                     },
                     MarkRecord {
                         markClass: 1,
-                        markAnchor: Offset16::to(Anchor {
-                            xCoordinate: 261,
-                            yCoordinate: 488,
-                            anchorPoint: None,
-                        }),
+                        markAnchor: Offset16::to(Anchor::new(261, 488)),
                     },
                 ],
             }),
@@ -236,22 +228,14 @@ mod tests {
                     componentRecords: vec![
                         ComponentRecord {
                             ligatureAnchors: vec![
-                                Offset16::to(Anchor {
-                                    xCoordinate: 625,
-                                    yCoordinate: 1800,
-                                    anchorPoint: None,
-                                }),
+                                Offset16::to(Anchor::new(625, 1800)),
                                 Offset16::to_nothing(),
                             ],
                         },
                         ComponentRecord {
                             ligatureAnchors: vec![
                                 Offset16::to_nothing(),
-                                Offset16::to(Anchor {
-                                    xCoordinate: 376,
-                                    yCoordinate: -368,
-                                    anchorPoint: None,
-                                }),
+                                Offset16::to(Anchor::new(376, -368)),
                             ],
                         },
                         ComponentRecord {
@@ -13,6 +13,7 @@ use crate::utils::is_all_the_same;
 // responsibility to set the Options to reflect the flags they
 // have serialized elsewhere.
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs, non_snake_case, non_camel_case_types)]
 #[otspec(embedded)]
 pub struct ValueRecord {
@@ -34,6 +35,11 @@ bitflags! {
     ///
     /// These are computed automatically, so you don't need to worry about them.
     #[derive(Serialize, Deserialize)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(transparent)
+    )]
     pub struct ValueRecordFlags: u16 {
             ///	Includes horizontal adjustment for placement
             const X_PLACEMENT = 0x0001;
@@ -90,7 +96,7 @@ impl ValueRecord {
         if self.xPlaDevice.is_some() {
             f |= ValueRecordFlags::X_PLACEMENT_DEVICE
         }
-        if self.xPlaDevice.is_some() {
+        if self.yPlaDevice.is_some() {
             f |= ValueRecordFlags::Y_PLACEMENT_DEVICE
         }
         if self.xAdvDevice.is_some() {
@@ -136,8 +142,9 @@ impl ValueRecord {
         Ok(vr)
     }
 
-    // Only goes "up", never "down"!
-    fn coerce_to_format(&mut self, flags: ValueRecordFlags) {
+    /// Adds empty fields so that this value record has (at least) the given
+    /// format. Only goes "up", never "down"!
+    pub fn coerce_to_format(&mut self, flags: ValueRecordFlags) {
         if flags.contains(ValueRecordFlags::X_PLACEMENT) && self.xPlacement.is_none() {
             self.xPlacement = Some(0);
         }
@@ -150,6 +157,18 @@ impl ValueRecord {
         if flags.contains(ValueRecordFlags::Y_ADVANCE) && self.yAdvance.is_none() {
             self.yAdvance = Some(0);
         }
+        if flags.contains(ValueRecordFlags::X_PLACEMENT_DEVICE) && self.xPlaDevice.is_none() {
+            self.xPlaDevice = Some(Offset16::to_nothing());
+        }
+        if flags.contains(ValueRecordFlags::Y_PLACEMENT_DEVICE) && self.yPlaDevice.is_none() {
+            self.yPlaDevice = Some(Offset16::to_nothing());
+        }
+        if flags.contains(ValueRecordFlags::X_ADVANCE_DEVICE) && self.xAdvDevice.is_none() {
+            self.xAdvDevice = Some(Offset16::to_nothing());
+        }
+        if flags.contains(ValueRecordFlags::Y_ADVANCE_DEVICE) && self.yAdvDevice.is_none() {
+            self.yAdvDevice = Some(Offset16::to_nothing());
+        }
     }
 
     /// Replaces Some(0) fields with None fields to provide a compact representation of a value record
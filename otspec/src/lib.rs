@@ -77,8 +77,8 @@ impl ReaderContext {
         self.ptr += bytes;
     }
 
-    pub fn follow_offset<T>(&mut self, offset: uint16) -> Result<(), DeserializationError> {
-        let destination = self.top_of_table() + offset as usize;
+    pub fn follow_offset<T>(&mut self, offset: usize) -> Result<(), DeserializationError> {
+        let destination = self.top_of_table() + offset;
         if destination > self.input.len() {
             return Err(DeserializationError(
                 format!("Offset fell off end of data trying to deserialize a {:?} (probably a missing [offset_base])",
@@ -264,6 +264,33 @@ where
     }
 }
 
+impl<T, const N: usize> Serialize for [T; N]
+where
+    T: Serialize,
+{
+    fn to_bytes(&self, data: &mut Vec<u8>) -> Result<(), SerializationError> {
+        self.as_slice().to_bytes(data)
+    }
+    fn ot_binary_size(&self) -> usize {
+        self.as_slice().ot_binary_size()
+    }
+    fn offset_fields(&self) -> Vec<&dyn types::OffsetMarkerTrait> {
+        self.as_slice().offset_fields()
+    }
+}
+
+impl<T, const N: usize> Deserialize for [T; N]
+where
+    T: Deserialize,
+{
+    fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
+        let items: Vec<T> = c.de_counted(N)?;
+        items
+            .try_into()
+            .map_err(|_| DeserializationError(format!("Expected an array of {} items", N)))
+    }
+}
+
 impl<T> Deserialize for Vec<T>
 where
     T: Deserialize,
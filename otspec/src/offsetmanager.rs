@@ -4,9 +4,16 @@ use crate::{SerializationError, Serialize};
 use petgraph::dot::Dot;
 use petgraph::graph::{Graph, NodeIndex};
 use petgraph::visit::{EdgeRef, Topo};
+use std::collections::HashMap;
+
+/// Subtables which serialize to the same bytes and point to identical
+/// subtables are identical, and are only written once.
+type SubtableKey = (Vec<u8>, Vec<NodeIndex<u32>>);
 
 pub struct OffsetManager<'a> {
-    dag: Graph<&'a dyn OffsetMarkerTrait, Option<usize>>,
+    // Nodes are the subtables to be written; each edge is the offset in the
+    // parent subtable which points to the child.
+    dag: Graph<&'a dyn OffsetMarkerTrait, &'a dyn OffsetMarkerTrait>,
     resolved: bool,
 }
 
@@ -19,17 +26,57 @@ impl<'a> OffsetManager<'a> {
             dag: Graph::new(),
             resolved: false,
         };
-        mgr.add_object_graph(obj);
+        // The root is often a reference to the table in the middle of being
+        // serialized, and is never shared, so its contents aren't compared.
+        let mut seen = HashMap::new();
+        let children = mgr.add_children(obj, &mut seen);
+        mgr.add_node(obj, children);
         mgr
     }
-    fn add_object_graph(&mut self, obj: &'a dyn OffsetMarkerTrait) -> NodeIndex<u32> {
-        let mut children = vec![];
-        for f in obj.children() {
-            children.push(self.add_object_graph(f));
-        }
+    fn add_children(
+        &mut self,
+        obj: &'a dyn OffsetMarkerTrait,
+        seen: &mut HashMap<SubtableKey, NodeIndex<u32>>,
+    ) -> Vec<(&'a dyn OffsetMarkerTrait, NodeIndex<u32>)> {
+        obj.children()
+            .into_iter()
+            .map(|f| (f, self.add_object_graph(f, seen)))
+            .collect()
+    }
+    fn add_node(
+        &mut self,
+        obj: &'a dyn OffsetMarkerTrait,
+        children: Vec<(&'a dyn OffsetMarkerTrait, NodeIndex<u32>)>,
+    ) -> NodeIndex<u32> {
         let node = self.dag.add_node(obj);
-        for child in children {
-            self.dag.add_edge(node, child, None);
+        for (f, child) in children {
+            self.dag.add_edge(node, child, f);
+        }
+        node
+    }
+    fn add_object_graph(
+        &mut self,
+        obj: &'a dyn OffsetMarkerTrait,
+        seen: &mut HashMap<SubtableKey, NodeIndex<u32>>,
+    ) -> NodeIndex<u32> {
+        let children = self.add_children(obj, seen);
+        // The offsets aren't known yet, so zero them to compare contents
+        for (f, _) in &children {
+            if !f.is_explicitly_zero() {
+                f.set(0);
+            }
+        }
+        let mut contents = vec![];
+        let key = obj.serialize_contents(&mut contents).ok().map(|_| {
+            let child_nodes = children.iter().map(|(_, node)| *node).collect();
+            (contents, child_nodes)
+        });
+        if let Some(&node) = key.as_ref().and_then(|k| seen.get(k)) {
+            return node;
+        }
+        let node = self.add_node(obj, children);
+        if let Some(key) = key {
+            seen.insert(key, node);
         }
         node
     }
@@ -39,53 +86,24 @@ impl<'a> OffsetManager<'a> {
 
     pub fn resolve(&mut self) {
         // First pass over the graph works out where everything's going to go.
+        // Subtables come after all of the subtables which point to them.
+        let mut positions = vec![0; self.dag.node_count()];
         let mut topo = Topo::new(&self.dag);
-        let mut node = topo.next(&self.dag);
         let mut offset_counter = 0;
-
-        while node.is_some() {
-            let this_offset = self.dag.node_weight(node.unwrap()).unwrap();
-            // Set the edge to this
-            let size = this_offset.object_size();
-            if offset_counter > 0 {
-                let parent = self
-                    .dag
-                    .edges_directed(node.unwrap(), petgraph::Direction::Incoming)
-                    .next()
-                    .unwrap()
-                    .source();
-                self.dag
-                    .update_edge(parent, node.unwrap(), Some(offset_counter));
-            }
-            offset_counter += size; // Pad to multiple of 4 or whatever
-            node = topo.next(&self.dag);
+        while let Some(node) = topo.next(&self.dag) {
+            positions[node.index()] = offset_counter;
+            offset_counter += self.dag[node].object_size(); // Pad to multiple of 4 or whatever
         }
 
-        // Second pass over the graph works out the offsets, resetting to zero
-        // at the top of each subtable.
-        let mut topo = Topo::new(&self.dag);
-        let mut node = topo.next(&self.dag);
-        let mut base = 0;
-        while node.is_some() {
-            let children_edges = self
-                .dag
-                .edges_directed(node.unwrap(), petgraph::Direction::Outgoing);
-            let mut parent_edges = self
-                .dag
-                .edges_directed(node.unwrap(), petgraph::Direction::Incoming);
-            if let Some(p) = parent_edges.next() {
-                base = p.weight().unwrap();
-            }
-
-            for c in children_edges {
-                let offset = c.weight().unwrap() - base;
-                let target_id = c.target();
-                let target_node = self.dag.node_weight(target_id).unwrap();
-                if !target_node.is_explicitly_zero() {
-                    target_node.set(offset as u32);
-                }
+        // Second pass sets each offset relative to the top of the subtable
+        // containing it.
+        for edge in self.dag.edge_references() {
+            let offset = *edge.weight();
+            if !offset.is_explicitly_zero() {
+                offset.set(
+                    (positions[edge.target().index()] - positions[edge.source().index()]) as u32,
+                );
             }
-            node = topo.next(&self.dag);
         }
 
         // self.dump_graph();
@@ -250,6 +268,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialize_shared_subtable() {
+        let one = One {
+            thing: 0x01,
+            anoffset: Offset16::to(Two {
+                test1: 0x0a,
+                deep: Offset16::to(Three { blah: 0x2020 }),
+                test2: 0x0b,
+            }),
+            other: 0xaabb,
+            asecondoffset: Offset16::to(Three { blah: 0x2020 }),
+        };
+        let mut output = vec![];
+        resolve_offsets_and_serialize(one, &mut output, true).unwrap();
+        assert_eq!(
+            output,
+            vec![
+                0x0, 0x1, // thing = 0x1
+                0x0, 0x8, // offset 8 to Two
+                0xaa, 0xbb, // other = 0xaabb
+                0x0, 0xe, // offset 14 to Three=0x2020
+                // Two
+                0x00, 0x0a, // test1
+                0x00, 0x06, // offset 6 to the same Three
+                0x00, 0x0b, // test2
+                0x20, 0x20, // Three, written once
+            ]
+        );
+    }
+
     #[test]
     fn test_serialize_magically() {
         let one = One {
@@ -1,17 +1,18 @@
 use crate::{
-    uint16, uint32, Counted, DeserializationError, Deserialize, Deserializer, ReaderContext,
-    SerializationError, Serialize,
+    uint16, uint24, uint32, Counted, Counted32, DeserializationError, Deserialize, Deserializer,
+    ReaderContext, SerializationError, Serialize,
 };
 use core::convert::TryFrom;
 use fmt::{Debug, Display};
 use num::cast::AsPrimitive;
-use num::traits::Unsigned;
 use num::FromPrimitive;
+use num::Zero;
 use std::cell::RefCell;
 use std::convert::TryInto;
 
 pub trait OffsetType:
-    Unsigned
+    Zero
+    + PartialEq
     + Display
     + Copy
     + Serialize
@@ -23,7 +24,8 @@ pub trait OffsetType:
 {
 }
 impl<
-        T: Unsigned
+        T: Zero
+            + PartialEq
             + Display
             + Copy
             + Serialize
@@ -45,6 +47,7 @@ pub struct Offset<T, U: OffsetType> {
 }
 
 pub type Offset16<T> = Offset<T, uint16>;
+pub type Offset24<T> = Offset<T, uint24>;
 pub type Offset32<T> = Offset<T, uint32>;
 
 // This is purely internal but we need to make it pub because it's shared
@@ -180,7 +183,7 @@ where
     }
 
     fn ot_binary_size(&self) -> usize {
-        ::std::mem::size_of::<U>()
+        U::zero().ot_binary_size()
     }
     fn offset_fields(&self) -> Vec<&dyn OffsetMarkerTrait> {
         vec![] // Maybe?
@@ -197,7 +200,7 @@ impl<T: Deserialize + Debug, U: OffsetType> Deserialize for Offset<T, U> {
             });
         }
         let oldptr = c.ptr;
-        c.follow_offset::<T>(off.as_().try_into().unwrap())?;
+        c.follow_offset::<T>(off.as_())?;
         let obj: T = c.de()?;
         c.ptr = oldptr;
         Ok(Self::new(off, obj))
@@ -235,6 +238,25 @@ where
     }
 }
 
+// Offsets are represented in serde formats by their target; the offset
+// value itself is recomputed on serialization.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, U: OffsetType> serde::Serialize for Offset<T, U> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.link.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, U: OffsetType> serde::Deserialize<'de> for Offset<T, U> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(thing) => Offset::to(thing),
+            None => Offset::to_nothing(),
+        })
+    }
+}
+
 // Vector of offsets
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -244,6 +266,22 @@ pub struct VecOffset<T, U: OffsetType> {
 pub type VecOffset16<T> = VecOffset<T, u16>;
 pub type VecOffset32<T> = VecOffset<T, u32>;
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, U: OffsetType> serde::Serialize for VecOffset<T, U> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.v.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, U: OffsetType> serde::Deserialize<'de> for VecOffset<T, U> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(VecOffset {
+            v: Vec::deserialize(deserializer)?,
+        })
+    }
+}
+
 impl<T, U> VecOffset<T, U>
 where
     U: OffsetType,
@@ -265,7 +303,7 @@ where
         Ok(())
     }
     fn ot_binary_size(&self) -> usize {
-        std::mem::size_of::<U>() * self.v.len()
+        U::zero().ot_binary_size() * self.v.len()
     }
     fn offset_fields(&self) -> Vec<&dyn OffsetMarkerTrait> {
         let mut v: Vec<&dyn OffsetMarkerTrait> = vec![];
@@ -300,6 +338,18 @@ impl<T, U: OffsetType> From<Counted<Offset<T, U>>> for VecOffset<T, U> {
     }
 }
 
+impl<T, U: OffsetType> From<VecOffset<T, U>> for Counted32<Offset<T, U>> {
+    fn from(v: VecOffset<T, U>) -> Self {
+        Counted32(v.v)
+    }
+}
+
+impl<T, U: OffsetType> From<Counted32<Offset<T, U>>> for VecOffset<T, U> {
+    fn from(v: Counted32<Offset<T, U>>) -> Self {
+        VecOffset { v: v.0 }
+    }
+}
+
 impl<T, U> TryInto<Vec<T>> for Counted<Offset<T, U>>
 where
     T: Clone,
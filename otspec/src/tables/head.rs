@@ -2,7 +2,7 @@ use otspec::types::*;
 use otspec::Deserializer;
 use otspec_macros::tables;
 
-tables!(head {
+tables!(head [serde] {
     uint16 majorVersion
     uint16 minorVersion
     Fixed fontRevision
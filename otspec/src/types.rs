@@ -4,6 +4,7 @@ use crate::{
 };
 use otmath::ot_round;
 use std::convert::TryInto;
+use std::fmt;
 
 #[allow(non_camel_case_types)]
 pub type uint16 = u16;
@@ -51,6 +52,50 @@ impl From<uint24> for u32 {
     }
 }
 
+impl fmt::Display for uint24 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::ops::Add for uint24 {
+    type Output = uint24;
+    fn add(self, rhs: uint24) -> uint24 {
+        uint24(self.0 + rhs.0)
+    }
+}
+
+impl num::Zero for uint24 {
+    fn zero() -> Self {
+        uint24(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl num::FromPrimitive for uint24 {
+    fn from_i64(n: i64) -> Option<Self> {
+        u32::try_from(n).ok().and_then(Self::from_u32)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        u32::try_from(n).ok().and_then(Self::from_u32)
+    }
+    fn from_u32(n: u32) -> Option<Self> {
+        if n > (1 << 24) - 1 {
+            None
+        } else {
+            Some(uint24(n))
+        }
+    }
+}
+
+impl num::traits::AsPrimitive<usize> for uint24 {
+    fn as_(self) -> usize {
+        self.0 as usize
+    }
+}
+
 impl Deserialize for uint24 {
     fn from_bytes(c: &mut ReaderContext) -> Result<Self, DeserializationError> {
         let bytes: Vec<u8> = c.de_counted(3)?;
@@ -272,7 +317,7 @@ impl From<LONGDATETIME> for chrono::NaiveDateTime {
     }
 }
 
-pub use crate::offsets::{Offset16, Offset32, VecOffset, VecOffset16, VecOffset32};
+pub use crate::offsets::{Offset16, Offset24, Offset32, VecOffset, VecOffset16, VecOffset32};
 // OK, the offset type is going to be terrifying.
 
 #[cfg(test)]
@@ -150,7 +150,10 @@ fn serialize_offset_fields(fields: &[Field]) -> Vec<TokenStream> {
             let mut result = quote! {};
             if let syn::Type::Path(path) = ty {
                 let first = path.path.segments.first().unwrap();
-                if first.ident == "Offset16" || first.ident == "Offset32" {
+                if first.ident == "Offset16"
+                    || first.ident == "Offset24"
+                    || first.ident == "Offset32"
+                {
                     result = quote! { &self.#name, };
                 } else if first.ident == "Option" {
                     if let syn::PathArguments::AngleBracketed(args) = &first.arguments {
@@ -158,7 +161,7 @@ fn serialize_offset_fields(fields: &[Field]) -> Vec<TokenStream> {
                             args.args.first().unwrap()
                         {
                             let ident = &tp.path.segments.first().unwrap().ident;
-                            if ident == "Offset16" || ident == "Offset32" {
+                            if ident == "Offset16" || ident == "Offset24" || ident == "Offset32" {
                                 result = quote! { &self.#name, };
                             }
                         }
@@ -122,6 +122,7 @@ pub fn expand_tables(item: TokenStream) -> TokenStream {
         let mut do_serialize = "otspec_macros::Serialize,";
         let mut do_deserialize = "otspec_macros::Deserialize,";
         let mut embed_attr = "";
+        let mut serde_attr = "";
         // First parse table name
         let maybe_table_name = iter.next();
         if maybe_table_name.is_none() {
@@ -143,6 +144,8 @@ pub fn expand_tables(item: TokenStream) -> TokenStream {
                     do_default = "Default,";
                 } else if pragma == "[nodeserialize]" {
                     do_deserialize = "";
+                } else if pragma == "[serde]" {
+                    serde_attr = "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]";
                 } else {
                     panic!("Unknown pragma '{:?}'", pragma);
                 }
@@ -158,8 +161,15 @@ pub fn expand_tables(item: TokenStream) -> TokenStream {
             #[allow(missing_docs, non_snake_case, non_camel_case_types, clippy::derive_partial_eq_without_eq)]\n\
             #[derive({} {} {} {} PartialEq, Clone)]\n\
             {}\n\
+            {}\n\
             pub struct {} {{",
-            do_serialize, do_deserialize, do_debug, do_default, embed_attr, table_name
+            do_serialize,
+            do_deserialize,
+            do_debug,
+            do_default,
+            embed_attr,
+            serde_attr,
+            table_name
         )
         .unwrap();
 
@@ -223,6 +233,14 @@ pub fn expand_tables(item: TokenStream) -> TokenStream {
                     .to_string();
                 let name = expect_ident(table_def.next());
                 writeln!(out_s, "pub {} : Offset32<{}>,", name, subtype).unwrap();
+            } else if t == "Offset24" {
+                let subtype = expect_group(table_def.next(), Delimiter::Parenthesis)
+                    .into_iter()
+                    .next()
+                    .unwrap()
+                    .to_string();
+                let name = expect_ident(table_def.next());
+                writeln!(out_s, "pub {} : Offset24<{}>,", name, subtype).unwrap();
             } else if t == "CountedOffset16" {
                 let subtype = expect_group(table_def.next(), Delimiter::Parenthesis)
                     .into_iter()
@@ -241,6 +259,15 @@ pub fn expand_tables(item: TokenStream) -> TokenStream {
                 let name = expect_ident(table_def.next());
                 out_s.push_str("#[otspec(with = \"Counted\")]\n");
                 writeln!(out_s, "pub {} : VecOffset32<{}>,", name, subtype).unwrap();
+            } else if t == "Counted32Offset32" {
+                let subtype = expect_group(table_def.next(), Delimiter::Parenthesis)
+                    .into_iter()
+                    .next()
+                    .unwrap()
+                    .to_string();
+                let name = expect_ident(table_def.next());
+                out_s.push_str("#[otspec(with = \"Counted32\")]\n");
+                writeln!(out_s, "pub {} : VecOffset32<{}>,", name, subtype).unwrap();
             } else if let Some(nonspecial_type) = special_type(&t) {
                 writeln!(out_s, "#[otspec(with = \"{}\")]", t).unwrap();
                 let name = expect_ident(table_def.next());